@@ -0,0 +1,5 @@
+mod settings;
+mod state;
+
+pub use self::settings::AudioSettings;
+pub use self::state::{SoundEvent, SoundMaterial, SoundState};
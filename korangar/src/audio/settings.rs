@@ -0,0 +1,86 @@
+use korangar_audio::{AudioChannel, AudioEngine};
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use serde::{Deserialize, Serialize};
+
+use crate::system::SettingsFile;
+
+#[derive(Serialize, Deserialize)]
+pub struct AudioSettings {
+    pub master_volume: f32,
+    pub background_music_volume: f32,
+    pub effects_volume: f32,
+    pub ambient_volume: f32,
+    pub interface_volume: f32,
+    /// Lower the volume while the game window is unfocused.
+    #[serde(default)]
+    pub reduce_volume_unfocused: bool,
+    /// Volume relative to the master volume while the game window is
+    /// unfocused.
+    #[serde(default)]
+    pub unfocused_volume: f32,
+    /// `None` to use the default output device of the system.
+    #[serde(default)]
+    pub output_device: Option<String>,
+    /// Shift the pitch of moving sounds depending on their speed relative to
+    /// the player.
+    #[serde(default)]
+    pub doppler_effect: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            background_music_volume: 0.5,
+            effects_volume: 1.0,
+            ambient_volume: 1.0,
+            interface_volume: 1.0,
+            reduce_volume_unfocused: false,
+            unfocused_volume: 0.0,
+            output_device: None,
+            doppler_effect: false,
+        }
+    }
+}
+
+impl AudioSettings {
+    const LEGACY_FILE_NAME: &'static str = "client/audio_settings.ron";
+    const SECTION: &'static str = "audio";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load audio settings");
+
+            Default::default()
+        })
+    }
+
+    /// Apply the output device, the volume of every channel and the spatial
+    /// audio options to the audio engine.
+    pub fn apply(&self, audio_engine: &mut AudioEngine) {
+        audio_engine.set_output_device(self.output_device.clone());
+        audio_engine.set_volume(AudioChannel::Master, self.master_volume);
+        audio_engine.set_volume(AudioChannel::BackgroundMusic, self.background_music_volume);
+        audio_engine.set_volume(AudioChannel::Effects, self.effects_volume);
+        audio_engine.set_volume(AudioChannel::Ambient, self.ambient_volume);
+        audio_engine.set_volume(AudioChannel::Interface, self.interface_volume);
+        audio_engine.set_unfocused_volume(self.reduce_volume_unfocused.then_some(self.unfocused_volume));
+        audio_engine.set_doppler_effect(self.doppler_effect);
+    }
+
+    pub fn load() -> Option<Self> {
+        SettingsFile::load_section(Self::SECTION).or_else(|| SettingsFile::load_legacy(Self::LEGACY_FILE_NAME))
+    }
+
+    pub fn save(&self) {
+        SettingsFile::save_section(Self::SECTION, self);
+    }
+}
+
+impl Drop for AudioSettings {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
@@ -0,0 +1,110 @@
+use cgmath::{Vector3, Zero};
+use korangar_audio::{AttenuationCurve, AudioChannel, AudioEngine, SoundBank, SpatialSettings};
+
+use crate::loaders::{GameFileLoader, SoundLoader};
+
+/// Events that play a random sample from a sound bank.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SoundEvent {
+    Footstep,
+    Hit,
+    /// The client does not display items on the ground yet, so this is played
+    /// when an item drops into the inventory of the player.
+    ItemDrop,
+}
+
+impl SoundEvent {
+    fn get_volume(self) -> f32 {
+        match self {
+            SoundEvent::Footstep => 0.4,
+            SoundEvent::Hit => 1.0,
+            SoundEvent::ItemDrop => 0.8,
+        }
+    }
+
+    fn get_range(self) -> f32 {
+        match self {
+            SoundEvent::Footstep => 50.0,
+            SoundEvent::Hit => 100.0,
+            SoundEvent::ItemDrop => 50.0,
+        }
+    }
+}
+
+/// Surface an event happens on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SoundMaterial {
+    Ground,
+    Water,
+}
+
+/// Samples of every event and material together with the maximum pitch
+/// variation. Missing files are skipped.
+const SOUND_BANK: &[(SoundEvent, SoundMaterial, &[&str], f32)] = &[
+    (
+        SoundEvent::Footstep,
+        SoundMaterial::Ground,
+        &[
+            "footstep\\ground1.wav",
+            "footstep\\ground2.wav",
+            "footstep\\ground3.wav",
+            "footstep\\ground4.wav",
+        ],
+        0.1,
+    ),
+    (
+        SoundEvent::Footstep,
+        SoundMaterial::Water,
+        &["footstep\\water1.wav", "footstep\\water2.wav", "footstep\\water3.wav"],
+        0.1,
+    ),
+    (
+        SoundEvent::Hit,
+        SoundMaterial::Ground,
+        &["_hit_fist1.wav", "_hit_fist2.wav", "_hit_fist3.wav", "_hit_fist4.wav"],
+        0.05,
+    ),
+    (
+        SoundEvent::Hit,
+        SoundMaterial::Water,
+        &["_hit_fist1.wav", "_hit_fist2.wav", "_hit_fist3.wav", "_hit_fist4.wav"],
+        0.05,
+    ),
+    (SoundEvent::ItemDrop, SoundMaterial::Ground, &["_drop_item.wav"], 0.15),
+    (SoundEvent::ItemDrop, SoundMaterial::Water, &["_drop_item.wav"], 0.15),
+];
+
+/// Sound banks for game events such as footsteps and hits.
+pub struct SoundState {
+    bank: SoundBank<(SoundEvent, SoundMaterial)>,
+}
+
+impl SoundState {
+    pub fn new(sound_loader: &mut SoundLoader, game_file_loader: &mut GameFileLoader) -> Self {
+        let mut bank = SoundBank::default();
+
+        for (event, material, paths, pitch_variation) in SOUND_BANK {
+            let samples = paths
+                .iter()
+                .filter_map(|path| sound_loader.get(path, game_file_loader).ok())
+                .collect();
+
+            bank.insert((*event, *material), samples, *pitch_variation);
+        }
+
+        Self { bank }
+    }
+
+    pub fn play(&mut self, audio_engine: &mut AudioEngine, event: SoundEvent, material: SoundMaterial, position: Vector3<f32>) {
+        let spatial = SpatialSettings {
+            position,
+            velocity: Vector3::zero(),
+            volume: event.get_volume(),
+            pitch: 1.0,
+            range: event.get_range(),
+            attenuation: AttenuationCurve::Linear,
+        };
+
+        self.bank.play(audio_engine, AudioChannel::Effects, &(event, material), spatial);
+    }
+}
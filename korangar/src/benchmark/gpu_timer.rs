@@ -0,0 +1,108 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use vulkano::device::Device;
+use vulkano::query::{QueryPool, QueryPoolCreateInfo, QueryResultFlags, QueryType};
+use vulkano::sync::PipelineStage;
+
+use crate::graphics::CommandBuilder;
+
+pub const PASS_COUNT: usize = 3;
+
+/// Render targets that the GPU time is measured for.
+#[derive(Clone, Copy)]
+pub enum MeasuredPass {
+    Picker,
+    Shadow,
+    Deferred,
+}
+
+impl MeasuredPass {
+    pub fn all() -> [Self; PASS_COUNT] {
+        [Self::Picker, Self::Shadow, Self::Deferred]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Picker => "picker",
+            Self::Shadow => "shadow",
+            Self::Deferred => "deferred",
+        }
+    }
+}
+
+/// Measures the time the GPU spends on the command buffers of the render
+/// targets using timestamp queries. Every swapchain image has its own queries,
+/// so they can be read once the frame of that image is finished.
+pub struct GpuTimer {
+    query_pools: Vec<Arc<QueryPool>>,
+    /// Nanoseconds per timestamp tick.
+    timestamp_period: f32,
+}
+
+impl GpuTimer {
+    /// Returns `None` if the queue doesn't support timestamps or the device
+    /// can't reset queries from the CPU.
+    pub fn new(device: Arc<Device>, queue_family_index: u32, image_count: usize) -> Option<Self> {
+        let physical_device = device.physical_device();
+        let queue_family_properties = &physical_device.queue_family_properties()[queue_family_index as usize];
+
+        if queue_family_properties.timestamp_valid_bits.is_none() || !device.enabled_features().host_query_reset {
+            return None;
+        }
+
+        let query_pools = (0..image_count)
+            .map(|_| {
+                let query_pool = QueryPool::new(device.clone(), QueryPoolCreateInfo {
+                    query_count: PASS_COUNT as u32 * 2,
+                    ..QueryPoolCreateInfo::query_type(QueryType::Timestamp)
+                })
+                .ok()?;
+
+                // Queries have to be reset before they are written for the first time.
+                unsafe { query_pool.reset(0..query_pool.query_count()) }.ok()?;
+
+                Some(query_pool)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            query_pools,
+            timestamp_period: physical_device.properties().timestamp_period,
+        })
+    }
+
+    pub fn begin(&self, builder: &mut CommandBuilder, image_number: usize, pass: MeasuredPass) {
+        self.write_timestamp(builder, image_number, pass as u32 * 2, PipelineStage::TopOfPipe);
+    }
+
+    pub fn end(&self, builder: &mut CommandBuilder, image_number: usize, pass: MeasuredPass) {
+        self.write_timestamp(builder, image_number, pass as u32 * 2 + 1, PipelineStage::BottomOfPipe);
+    }
+
+    fn write_timestamp(&self, builder: &mut CommandBuilder, image_number: usize, query: u32, stage: PipelineStage) {
+        // SAFETY: Every query is written once per frame and reset after it was read.
+        unsafe { builder.write_timestamp(self.query_pools[image_number].clone(), query, stage) }.unwrap();
+    }
+
+    /// Read the timings of the last frame that was rendered to an image and
+    /// reset the queries for the next one. The frame has to be finished.
+    pub fn read(&self, image_number: usize) -> Option<[Duration; PASS_COUNT]> {
+        let query_pool = &self.query_pools[image_number];
+        let mut timestamps = [0_u64; PASS_COUNT * 2];
+
+        // Queries that weren't written since the last reset are not available.
+        let available = query_pool
+            .get_results(0..query_pool.query_count(), &mut timestamps, QueryResultFlags::empty())
+            .unwrap_or(false);
+
+        unsafe { query_pool.reset(0..query_pool.query_count()) }.ok()?;
+
+        available.then(|| {
+            std::array::from_fn(|pass| {
+                let ticks = timestamps[pass * 2 + 1].saturating_sub(timestamps[pass * 2]);
+                Duration::from_nanos((ticks as f64 * self.timestamp_period as f64) as u64)
+            })
+        })
+    }
+}
@@ -0,0 +1,341 @@
+//! A deterministic benchmark, so the performance impact of graphics changes
+//! can be compared across machines. The benchmark loads a map, places a fixed
+//! set of monsters on it and moves the camera along a fixed path. Afterwards,
+//! the frame times and GPU timings of the render passes are written to a
+//! report in the benchmark directory, together with the time it takes to find
+//! long paths on the map.
+
+mod gpu_timer;
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use cgmath::Vector2;
+use korangar_networking::{EntityData, EntityLook};
+use ragnarok_packets::{EntityId, Sex, WorldPosition};
+
+pub use self::gpu_timer::{GpuTimer, MeasuredPass, PASS_COUNT};
+use crate::world::{find_tile_path, Map};
+
+const REPORT_DIRECTORY: &str = "benchmarks";
+pub const DEFAULT_DURATION: Duration = Duration::from_secs(30);
+/// Frames that are rendered before the measurement starts, so pipelines and
+/// caches are warmed up.
+const WARMUP_FRAMES: usize = 60;
+/// Time the camera takes for one circle around the center of the map.
+const ORBIT_TIME: f32 = 20.0;
+/// Distance of the camera path from the center of the map, relative to the
+/// size of the map.
+const ORBIT_RADIUS: f32 = 0.3;
+const ENTITY_COUNT: u32 = 200;
+/// Monsters that are placed along the camera path, in order.
+const ENTITY_JOBS: [u16; 6] = [1002, 1007, 1063, 1113, 1031, 1049];
+/// Entity ids that no server would assign to a monster.
+const FIRST_ENTITY_ID: u32 = 0x7000_0000;
+/// Paths that are found across the map to measure path finding.
+const PATH_COUNT: usize = 20;
+
+/// Point that the camera looks at and the angle it looks at it from, after
+/// `elapsed` seconds of the benchmark. The position is in tiles.
+pub fn camera_path(elapsed: f32, map_size: Vector2<usize>) -> (Vector2<f32>, f32) {
+    let center = Vector2::new(map_size.x as f32, map_size.y as f32) / 2.0;
+    let radius = map_size.x.min(map_size.y) as f32 * ORBIT_RADIUS;
+    let angle = elapsed / ORBIT_TIME * TAU;
+
+    let focus_point = center + Vector2::new(angle.cos(), angle.sin()) * radius;
+
+    // Looking along the path shows more of the map than looking at the center.
+    (focus_point, angle + FRAC_PI_2)
+}
+
+/// Monsters placed around the camera path, so they are in view for the whole
+/// benchmark.
+pub fn synthetic_entities(map_size: Vector2<usize>) -> Vec<EntityData> {
+    let center = Vector2::new(map_size.x as f32, map_size.y as f32) / 2.0;
+    let radius = map_size.x.min(map_size.y) as f32 * ORBIT_RADIUS;
+
+    (0..ENTITY_COUNT)
+        .map(|index| {
+            let angle = index as f32 / ENTITY_COUNT as f32 * TAU;
+            // Spread the entities over a few lanes on both sides of the path.
+            let distance = radius + ((index % 5) as f32 - 2.0) * 3.0;
+            let position = center + Vector2::new(angle.cos(), angle.sin()) * distance;
+
+            EntityData {
+                entity_id: EntityId(FIRST_ENTITY_ID + index),
+                movement_speed: 150,
+                job: ENTITY_JOBS[index as usize % ENTITY_JOBS.len()],
+                position: WorldPosition {
+                    x: (position.x.max(0.0) as usize).min(map_size.x.saturating_sub(1)),
+                    y: (position.y.max(0.0) as usize).min(map_size.y.saturating_sub(1)),
+                },
+                destination: None,
+                health_points: 100,
+                maximum_health_points: 100,
+                head_direction: 0,
+                sex: Sex::Male,
+                look: EntityLook::default(),
+                sitting: false,
+                guild_emblem: None,
+            }
+        })
+        .collect()
+}
+
+/// Start and goal of paths across the map, from one side of the camera path to
+/// the opposite side.
+fn path_queries(map_size: Vector2<usize>) -> Vec<(Vector2<usize>, Vector2<usize>)> {
+    let center = Vector2::new(map_size.x as f32, map_size.y as f32) / 2.0;
+    let radius = map_size.x.min(map_size.y) as f32 * ORBIT_RADIUS;
+    let to_tile = |position: Vector2<f32>| {
+        Vector2::new(
+            (position.x.max(0.0) as usize).min(map_size.x.saturating_sub(1)),
+            (position.y.max(0.0) as usize).min(map_size.y.saturating_sub(1)),
+        )
+    };
+
+    (0..PATH_COUNT)
+        .map(|index| {
+            let angle = index as f32 / PATH_COUNT as f32 * TAU;
+            let offset = Vector2::new(angle.cos(), angle.sin()) * radius;
+
+            (to_tile(center + offset), to_tile(center - offset))
+        })
+        .collect()
+}
+
+/// Average time it takes to find a path, searching tile by tile and on the
+/// path graph of the map.
+struct PathFindingTimes {
+    tile: Duration,
+    hierarchical: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+struct Summary {
+    average: Duration,
+    /// Average of the slowest percent of the samples.
+    one_percent_low: Duration,
+}
+
+fn summarize(samples: &[Duration]) -> Option<Summary> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable_by(|left, right| right.cmp(left));
+
+    let slowest = &sorted[..sorted.len().div_ceil(100)];
+    let average = |samples: &[Duration]| samples.iter().sum::<Duration>() / samples.len() as u32;
+
+    Some(Summary {
+        average: average(&sorted),
+        one_percent_low: average(slowest),
+    })
+}
+
+fn milliseconds(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+pub struct Benchmark {
+    map_name: String,
+    duration: Duration,
+    warmup_frames: usize,
+    elapsed: Duration,
+    frame_times: Vec<Duration>,
+    pass_times: [Vec<Duration>; PASS_COUNT],
+    path_finding: Option<PathFindingTimes>,
+}
+
+impl Benchmark {
+    pub fn new(map_name: String, duration: Duration) -> Self {
+        Self {
+            map_name,
+            duration,
+            warmup_frames: WARMUP_FRAMES,
+            elapsed: Duration::ZERO,
+            frame_times: Vec::new(),
+            pass_times: Default::default(),
+            path_finding: None,
+        }
+    }
+
+    /// Find the same paths across the map tile by tile and on the path graph.
+    pub fn measure_path_finding(&mut self, map: &Map) {
+        let queries = path_queries(map.get_size());
+        let average_time = |find_path: &dyn Fn(Vector2<usize>, Vector2<usize>) -> Option<Vec<Vector2<usize>>>| {
+            let start = Instant::now();
+
+            for &(from, to) in &queries {
+                let _ = find_path(from, to);
+            }
+
+            start.elapsed() / queries.len() as u32
+        };
+
+        self.path_finding = Some(PathFindingTimes {
+            tile: average_time(&|from, to| find_tile_path(map, from, to, 0)),
+            hierarchical: average_time(&|from, to| map.get_path_graph().find_path(from, to, 0)),
+        });
+    }
+
+    pub fn map_name(&self) -> &str {
+        &self.map_name
+    }
+
+    /// Time since the measurement started. The camera stays at the start of
+    /// its path while warming up.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn is_measuring(&self) -> bool {
+        self.warmup_frames == 0
+    }
+
+    /// Record the time of a frame. Returns `true` once the benchmark is done.
+    pub fn record_frame(&mut self, frame_time: Duration) -> bool {
+        if !self.is_measuring() {
+            self.warmup_frames -= 1;
+            return false;
+        }
+
+        self.elapsed += frame_time;
+        self.frame_times.push(frame_time);
+        self.elapsed >= self.duration
+    }
+
+    pub fn record_passes(&mut self, pass_times: [Duration; PASS_COUNT]) {
+        if !self.is_measuring() {
+            return;
+        }
+
+        for (samples, pass_time) in self.pass_times.iter_mut().zip(pass_times) {
+            samples.push(pass_time);
+        }
+    }
+
+    fn format_report(&self, adapter: &str) -> String {
+        let mut report = String::new();
+
+        let _ = writeln!(report, "korangar {}", env!("CARGO_PKG_VERSION"));
+        let _ = writeln!(report, "adapter: {adapter}");
+        let _ = writeln!(report, "map: {}", self.map_name);
+        let _ = writeln!(report, "frames: {}", self.frame_times.len());
+
+        if let Some(summary) = summarize(&self.frame_times) {
+            let _ = writeln!(report, "\nframe time");
+            let _ = writeln!(report, "  average: {}", milliseconds(summary.average));
+            let _ = writeln!(report, "  1% low: {}", milliseconds(summary.one_percent_low));
+        }
+
+        if let Some(path_finding) = &self.path_finding {
+            let _ = writeln!(report, "\npath finding time per path");
+            let _ = writeln!(report, "  tile by tile: {}", milliseconds(path_finding.tile));
+            let _ = writeln!(report, "  hierarchical: {}", milliseconds(path_finding.hierarchical));
+        }
+
+        let _ = writeln!(report, "\ngpu time per pass");
+
+        for (pass, samples) in MeasuredPass::all().into_iter().zip(&self.pass_times) {
+            match summarize(samples) {
+                Some(summary) => {
+                    let _ = writeln!(
+                        report,
+                        "  {}: {} average, {} 1% low",
+                        pass.name(),
+                        milliseconds(summary.average),
+                        milliseconds(summary.one_percent_low)
+                    );
+                }
+                None => {
+                    let _ = writeln!(report, "  {}: not available", pass.name());
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Write the report and return its path.
+    pub fn write_report(&self, adapter: &str) -> std::io::Result<PathBuf> {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let path = PathBuf::from(format!("{REPORT_DIRECTORY}/{}-{timestamp}.txt", self.map_name));
+
+        std::fs::create_dir_all(REPORT_DIRECTORY)?;
+        std::fs::write(&path, self.format_report(adapter))?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod report {
+    use std::time::Duration;
+
+    use cgmath::Vector2;
+
+    use super::{camera_path, path_queries, summarize, synthetic_entities, Benchmark, Summary, ENTITY_COUNT, PATH_COUNT, WARMUP_FRAMES};
+
+    #[test]
+    fn summary() {
+        let mut samples = vec![Duration::from_millis(10); 198];
+        samples.extend([Duration::from_millis(40), Duration::from_millis(30)]);
+
+        assert_eq!(
+            summarize(&samples),
+            Some(Summary {
+                average: Duration::from_micros(10_250),
+                one_percent_low: Duration::from_millis(35),
+            })
+        );
+        assert_eq!(summarize(&[]), None);
+    }
+
+    #[test]
+    fn deterministic_camera_path() {
+        let map_size = Vector2::new(200, 100);
+        let (focus_point, _) = camera_path(7.5, map_size);
+
+        assert_eq!(camera_path(7.5, map_size), camera_path(7.5, map_size));
+        assert!((0.0..200.0).contains(&focus_point.x));
+        assert!((0.0..100.0).contains(&focus_point.y));
+    }
+
+    #[test]
+    fn entities_on_map() {
+        let entities = synthetic_entities(Vector2::new(40, 30));
+
+        assert_eq!(entities.len(), ENTITY_COUNT as usize);
+        assert!(entities.iter().all(|entity| entity.position.x < 40 && entity.position.y < 30));
+    }
+
+    #[test]
+    fn paths_on_map() {
+        let queries = path_queries(Vector2::new(40, 30));
+
+        assert_eq!(queries.len(), PATH_COUNT);
+        assert!(queries
+            .iter()
+            .all(|(from, to)| from.x < 40 && from.y < 30 && to.x < 40 && to.y < 30));
+    }
+
+    #[test]
+    fn warmup() {
+        let mut benchmark = Benchmark::new("prontera".to_owned(), Duration::from_millis(50));
+
+        for _ in 0..WARMUP_FRAMES {
+            assert!(!benchmark.record_frame(Duration::from_millis(100)));
+        }
+
+        assert_eq!(benchmark.elapsed(), Duration::ZERO);
+        assert!(!benchmark.record_frame(Duration::from_millis(20)));
+        assert!(!benchmark.record_frame(Duration::from_millis(20)));
+        assert!(benchmark.record_frame(Duration::from_millis(20)));
+        assert!(benchmark.format_report("test").contains("frames: 3"));
+    }
+}
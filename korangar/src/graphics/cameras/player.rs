@@ -1,6 +1,8 @@
 use std::f32::consts::FRAC_PI_2;
 
-use cgmath::{Array, EuclideanSpace, InnerSpace, Matrix4, MetricSpace, Point3, Rad, SquareMatrix, Vector2, Vector3, Vector4};
+use cgmath::{
+    Array, EuclideanSpace, InnerSpace, Matrix3, Matrix4, MetricSpace, Point3, Quaternion, Rad, SquareMatrix, Vector2, Vector3, Vector4,
+};
 use ragnarok_formats::transform::Transform;
 
 use super::{Camera, SmoothedValue};
@@ -59,6 +61,18 @@ impl PlayerCamera {
         self.focus_point.z.set_desired(position.z);
     }
 
+    /// Rotation from view space to world space, used to orient the audio
+    /// listener.
+    pub fn get_orientation(&self) -> Quaternion<f32> {
+        let view_rotation = Matrix3::from_cols(
+            self.view_matrix.x.truncate(),
+            self.view_matrix.y.truncate(),
+            self.view_matrix.z.truncate(),
+        );
+
+        Quaternion::from(view_rotation).conjugate()
+    }
+
     pub fn soft_zoom(&mut self, zoom_factor: f32) {
         self.zoom.move_desired_clamp(zoom_factor * ZOOM_SPEED, MINIMUM_ZOOM, MAXIMUM_ZOOM);
     }
@@ -67,6 +81,17 @@ impl PlayerCamera {
         self.view_angle.move_desired(rotation * ROTATION_SPEED);
     }
 
+    /// Direction on the ground for an input relative to the camera, where
+    /// positive y is away from the camera. X and y of the result map to the x
+    /// and y of the tile grid.
+    pub fn ground_direction(&self, input: Vector2<f32>) -> Vector2<f32> {
+        let view_angle = self.view_angle.get_current();
+        let forward = Vector2::new(-view_angle.cos(), view_angle.sin());
+        let right = Vector2::new(view_angle.sin(), view_angle.cos());
+
+        forward * input.y + right * input.x
+    }
+
     pub fn update(&mut self, delta_time: f64) {
         self.focus_point.x.update(delta_time);
         self.focus_point.y.update(delta_time);
@@ -43,6 +43,10 @@ impl StartCamera {
         self.focus_point = focus_point;
     }
 
+    pub fn set_view_angle(&mut self, view_angle: f32) {
+        self.view_angle = view_angle;
+    }
+
     pub fn update(&mut self, delta_time: f64) {
         self.view_angle += delta_time as f32 * ROTATION_SPEED;
     }
@@ -78,7 +78,6 @@ impl Color {
         (self.alpha * 255.0) as u8
     }
 
-    #[cfg(feature = "debug")]
     pub fn multiply_alpha(mut self, alpha: f32) -> Self {
         self.alpha *= alpha;
         self
@@ -12,7 +12,7 @@ use vulkano::descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet};
 use vulkano::device::{Device, DeviceOwned, Queue};
 use vulkano::memory::allocator::{AllocationCreateInfo, AllocationType, MemoryTypeFilter, StandardMemoryAllocator};
 use vulkano::memory::{DedicatedAllocation, MemoryRequirements};
-use vulkano::pipeline::{GraphicsPipeline, Pipeline, PipelineLayout};
+use vulkano::pipeline::{Pipeline, PipelineLayout};
 use vulkano::sync::future::FenceSignalFuture;
 use vulkano::sync::GpuFuture;
 use vulkano::{DeviceSize, VulkanError};
@@ -133,8 +133,8 @@ unsafe impl CommandBufferAllocator for MemoryAllocator {
 }
 
 #[cfg_attr(feature = "debug", korangar_debug::profile)]
-pub(super) fn allocate_descriptor_set(
-    pipeline: &Arc<GraphicsPipeline>,
+pub(super) fn allocate_descriptor_set<P: Pipeline>(
+    pipeline: &Arc<P>,
     memory_allocator: &Arc<MemoryAllocator>,
     set_id: u32,
     write_descriptor_sets: impl IntoIterator<Item = WriteDescriptorSet>,
@@ -205,6 +205,15 @@ impl BufferAllocator {
         self.allocate(data, BufferUsage::INDEX_BUFFER)
     }
 
+    pub fn allocate_storage_buffer<T, I>(&mut self, data: I) -> Subbuffer<[T]>
+    where
+        T: BufferContents,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        self.allocate(data, BufferUsage::STORAGE_BUFFER)
+    }
+
     fn allocate<T, I>(&mut self, data: I, usage: BufferUsage) -> Subbuffer<[T]>
     where
         T: BufferContents,
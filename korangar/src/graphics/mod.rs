@@ -2,6 +2,7 @@ mod cameras;
 mod color;
 mod memory;
 mod particles;
+mod render_scale;
 mod renderers;
 mod settings;
 mod smoothed;
@@ -18,6 +19,7 @@ pub use self::color::*;
 use self::memory::{allocate_descriptor_set, MatrixAllocator};
 pub use self::memory::{BufferAllocator, MemoryAllocator};
 pub use self::particles::*;
+pub use self::render_scale::{scaled_dimensions, DynamicRenderScale, MAXIMUM_RENDER_SCALE, MINIMUM_RENDER_SCALE};
 pub use self::renderers::*;
 pub use self::settings::GraphicsSettings;
 pub use self::smoothed::SmoothedValue;
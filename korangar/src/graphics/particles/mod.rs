@@ -1,132 +1,14 @@
 use std::collections::HashMap;
 
 use cgmath::{Vector2, Vector3};
-use derive_new::new;
 use korangar_interface::application::ClipTraitExt;
 use ragnarok_packets::{EntityId, QuestColor, QuestEffectPacket};
-use rand::{thread_rng, Rng};
 
 use crate::graphics::*;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::loaders::{GameFileLoader, TextureLoader};
 use crate::world::*;
 
-pub trait Particle {
-    fn update(&mut self, delta_time: f32) -> bool;
-
-    fn render(
-        &self,
-        render_target: &mut <DeferredRenderer as Renderer>::Target,
-        renderer: &DeferredRenderer,
-        camera: &dyn Camera,
-        window_size: ScreenSize,
-    );
-}
-
-#[derive(new)]
-pub struct DamageNumber {
-    position: Vector3<f32>,
-    damage_amount: String,
-    #[new(value = "50.0")]
-    velocity_y: f32,
-    #[new(value = "thread_rng().gen_range(-20.0..20.0)")]
-    velocity_x: f32,
-    #[new(value = "thread_rng().gen_range(-20.0..20.0)")]
-    velocity_z: f32,
-    #[new(value = "0.6")]
-    timer: f32,
-}
-
-impl Particle for DamageNumber {
-    fn update(&mut self, delta_time: f32) -> bool {
-        self.velocity_y -= 200.0 * delta_time;
-
-        self.position.y += self.velocity_y * delta_time;
-        self.position.x += self.velocity_x * delta_time;
-        self.position.z += self.velocity_z * delta_time;
-
-        self.timer -= delta_time;
-        self.timer > 0.0
-    }
-
-    fn render(
-        &self,
-        render_target: &mut <DeferredRenderer as Renderer>::Target,
-        renderer: &DeferredRenderer,
-        camera: &dyn Camera,
-        window_size: ScreenSize,
-    ) {
-        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
-        let clip_space_position = (projection_matrix * view_matrix) * self.position.extend(1.0);
-        let screen_position = Vector2::new(
-            clip_space_position.x / clip_space_position.w + 1.0,
-            clip_space_position.y / clip_space_position.w + 1.0,
-        );
-        let screen_position = screen_position / 2.0;
-        let final_position = ScreenPosition {
-            left: screen_position.x * window_size.width,
-            top: screen_position.y * window_size.height,
-        };
-
-        renderer.render_damage_text(
-            render_target,
-            &self.damage_amount,
-            final_position,
-            Color::monochrome_u8(255),
-            16.0,
-        );
-    }
-}
-
-#[derive(new)]
-pub struct HealNumber {
-    position: Vector3<f32>,
-    heal_amount: String,
-    #[new(value = "50.0")]
-    velocity_y: f32,
-    #[new(value = "1.0")]
-    timer: f32,
-}
-
-impl Particle for HealNumber {
-    fn update(&mut self, delta_time: f32) -> bool {
-        self.velocity_y -= 50.0 * delta_time;
-
-        self.position.y += self.velocity_y * delta_time;
-
-        self.timer -= delta_time;
-        self.timer > 0.0
-    }
-
-    fn render(
-        &self,
-        render_target: &mut <DeferredRenderer as Renderer>::Target,
-        renderer: &DeferredRenderer,
-        camera: &dyn Camera,
-        window_size: ScreenSize,
-    ) {
-        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
-        let clip_space_position = (projection_matrix * view_matrix) * self.position.extend(1.0);
-        let screen_position = Vector2::new(
-            clip_space_position.x / clip_space_position.w + 1.0,
-            clip_space_position.y / clip_space_position.w + 1.0,
-        );
-        let screen_position = screen_position / 2.0;
-        let final_position = ScreenPosition {
-            left: screen_position.x * window_size.width,
-            top: screen_position.y * window_size.height,
-        };
-
-        renderer.render_damage_text(
-            render_target,
-            &self.heal_amount,
-            final_position,
-            Color::rgb_u8(30, 255, 30),
-            16.0,
-        );
-    }
-}
-
 pub struct QuestIcon {
     position: Vector3<f32>,
     texture: Arc<ImageView>,
@@ -192,15 +74,10 @@ impl QuestIcon {
 
 #[derive(Default)]
 pub struct ParticleHolder {
-    particles: Vec<Box<dyn Particle + Send + Sync>>,
     quest_icons: HashMap<EntityId, QuestIcon>,
 }
 
 impl ParticleHolder {
-    pub fn spawn_particle(&mut self, particle: Box<dyn Particle + Send + Sync>) {
-        self.particles.push(particle);
-    }
-
     pub fn add_quest_icon(
         &mut self,
         game_file_loader: &mut GameFileLoader,
@@ -219,15 +96,9 @@ impl ParticleHolder {
     }
 
     pub fn clear(&mut self) {
-        self.particles.clear();
         self.quest_icons.clear();
     }
 
-    #[cfg_attr(feature = "debug", korangar_debug::profile("update particles"))]
-    pub fn update(&mut self, delta_time: f32) {
-        self.particles.retain_mut(|particle| particle.update(delta_time));
-    }
-
     #[cfg_attr(feature = "debug", korangar_debug::profile("render particles"))]
     pub fn render(
         &self,
@@ -236,10 +107,11 @@ impl ParticleHolder {
         camera: &dyn Camera,
         window_size: ScreenSize,
         entities: &[Entity],
+        render_quest_icons: bool,
     ) {
-        self.particles
-            .iter()
-            .for_each(|particle| particle.render(render_target, renderer, camera, window_size));
+        if !render_quest_icons {
+            return;
+        }
 
         entities
             .iter()
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+/// Smallest fraction of the window resolution that the scene is rendered at.
+pub const MINIMUM_RENDER_SCALE: f32 = 0.5;
+/// Largest multiple of the window resolution that the scene is rendered at.
+pub const MAXIMUM_RENDER_SCALE: f32 = 2.0;
+/// Amount that the dynamic render scale changes by at once.
+const RENDER_SCALE_STEP: f32 = 0.1;
+/// Number of frames that are averaged before the render scale is adjusted, so
+/// a single slow frame doesn't change the resolution.
+const SAMPLED_FRAMES: u32 = 30;
+/// The render scale is lowered once the work of a frame takes this much longer
+/// than the frame budget on average.
+const OVER_BUDGET: f64 = 1.05;
+/// The render scale is raised again once the work of a frame finishes this much
+/// earlier than the frame budget on average.
+const UNDER_BUDGET: f64 = 0.8;
+
+/// Size of the image that the scene is rendered to.
+pub fn scaled_dimensions(dimensions: [u32; 2], render_scale: f32) -> [u32; 2] {
+    dimensions.map(|component| ((component as f32 * render_scale).round() as u32).max(1))
+}
+
+/// Lowers the render scale while the work of a frame takes longer than the
+/// frame budget, and raises it again up to the configured render scale once
+/// there is time to spare.
+///
+/// The work time leaves out the time spent waiting for the swapchain, since
+/// waiting for the display would otherwise count as work when the frame rate
+/// is limited by it.
+pub struct DynamicRenderScale {
+    render_scale: f32,
+    work_time_sum: Duration,
+    frame_count: u32,
+}
+
+impl DynamicRenderScale {
+    pub fn new(render_scale: f32) -> Self {
+        Self {
+            render_scale,
+            work_time_sum: Duration::ZERO,
+            frame_count: 0,
+        }
+    }
+
+    /// Record how long the CPU and GPU worked on the last frame. Returns the
+    /// new render scale if it changed. `maximum_render_scale` is the render
+    /// scale from the settings.
+    pub fn record_frame(&mut self, work_time: Duration, frame_budget: Duration, maximum_render_scale: f32) -> Option<f32> {
+        self.work_time_sum += work_time;
+        self.frame_count += 1;
+
+        if self.frame_count < SAMPLED_FRAMES {
+            return None;
+        }
+
+        let average_work_time = self.work_time_sum.as_secs_f64() / self.frame_count as f64;
+        let frame_budget = frame_budget.as_secs_f64();
+
+        self.work_time_sum = Duration::ZERO;
+        self.frame_count = 0;
+
+        let minimum_render_scale = MINIMUM_RENDER_SCALE.min(maximum_render_scale);
+        let render_scale = match average_work_time {
+            time if time > frame_budget * OVER_BUDGET => self.render_scale - RENDER_SCALE_STEP,
+            time if time < frame_budget * UNDER_BUDGET => self.render_scale + RENDER_SCALE_STEP,
+            _ => self.render_scale,
+        }
+        .clamp(minimum_render_scale, maximum_render_scale);
+
+        if (render_scale - self.render_scale).abs() < f32::EPSILON {
+            return None;
+        }
+
+        self.render_scale = render_scale;
+        Some(render_scale)
+    }
+}
+
+#[cfg(test)]
+mod dynamic_resolution {
+    use std::time::Duration;
+
+    use super::{scaled_dimensions, DynamicRenderScale, SAMPLED_FRAMES};
+
+    const BUDGET: Duration = Duration::from_millis(16);
+
+    fn record_frames(render_scale: &mut DynamicRenderScale, work_time: Duration, maximum_render_scale: f32) -> Option<f32> {
+        (0..SAMPLED_FRAMES)
+            .filter_map(|_| render_scale.record_frame(work_time, BUDGET, maximum_render_scale))
+            .last()
+    }
+
+    #[test]
+    fn dimensions() {
+        assert_eq!(scaled_dimensions([1920, 1080], 1.0), [1920, 1080]);
+        assert_eq!(scaled_dimensions([1920, 1080], 0.5), [960, 540]);
+        assert_eq!(scaled_dimensions([1920, 1080], 2.0), [3840, 2160]);
+        assert_eq!(scaled_dimensions([1, 1], 0.5), [1, 1]);
+    }
+
+    #[test]
+    fn lower_when_slow() {
+        let mut render_scale = DynamicRenderScale::new(1.0);
+
+        assert!(record_frames(&mut render_scale, Duration::from_millis(25), 1.0).is_some_and(|scale| scale < 1.0));
+    }
+
+    #[test]
+    fn hold_within_budget() {
+        let mut render_scale = DynamicRenderScale::new(1.0);
+
+        assert_eq!(record_frames(&mut render_scale, Duration::from_millis(15), 1.0), None);
+    }
+
+    #[test]
+    fn raise_up_to_maximum() {
+        let mut render_scale = DynamicRenderScale::new(0.9);
+
+        assert_eq!(record_frames(&mut render_scale, Duration::from_millis(5), 1.0), Some(1.0));
+        assert_eq!(record_frames(&mut render_scale, Duration::from_millis(5), 1.0), None);
+    }
+
+    #[test]
+    fn stop_at_minimum() {
+        let mut render_scale = DynamicRenderScale::new(0.5);
+
+        assert_eq!(record_frames(&mut render_scale, Duration::from_millis(50), 1.0), None);
+    }
+
+    #[test]
+    fn single_slow_frame() {
+        let mut render_scale = DynamicRenderScale::new(1.0);
+
+        assert_eq!(render_scale.record_frame(Duration::from_millis(100), BUDGET, 1.0), None);
+    }
+}
@@ -0,0 +1,70 @@
+#version 450
+
+layout(local_size_x = 64) in;
+
+// The smallest and the biggest corner of the bounding box of every object.
+layout(set = 0, binding = 0) readonly buffer BoundingBoxes {
+    vec4 corners[];
+} bounding_boxes;
+
+// The object index and the vertex count of every node that is drawn.
+layout(set = 0, binding = 1) readonly buffer Draws {
+    uvec2 draws[];
+} draws;
+
+struct DrawCommand {
+    uint vertex_count;
+    uint instance_count;
+    uint first_vertex;
+    uint first_instance;
+};
+
+layout(set = 0, binding = 2) writeonly buffer DrawCommands {
+    DrawCommand commands[];
+} draw_commands;
+
+layout(push_constant) uniform Constants {
+    mat4 view_projection;
+    uint draw_count;
+} constants;
+
+bool is_outside(vec4 plane, vec3 smallest, vec3 biggest) {
+    // The corner that is the farthest along the normal of the plane.
+    vec3 corner = mix(smallest, biggest, step(0.0, plane.xyz));
+    return dot(plane.xyz, corner) + plane.w < 0.0;
+}
+
+void main() {
+    uint index = gl_GlobalInvocationID.x;
+
+    if (index >= constants.draw_count) {
+        return;
+    }
+
+    uint object_index = draws.draws[index].x;
+    vec3 smallest = bounding_boxes.corners[object_index * 2].xyz;
+    vec3 biggest = bounding_boxes.corners[object_index * 2 + 1].xyz;
+
+    mat4 matrix = transpose(constants.view_projection);
+    vec4 planes[6] = vec4[6](
+        matrix[3] + matrix[0],
+        matrix[3] - matrix[0],
+        matrix[3] + matrix[1],
+        matrix[3] - matrix[1],
+        // The near plane of a depth range from -1 to 1 keeps everything that a
+        // range from 0 to 1 keeps, so it is safe for either convention.
+        matrix[3] + matrix[2],
+        matrix[3] - matrix[2]
+    );
+
+    bool visible = true;
+
+    for (int plane = 0; plane < 6; plane++) {
+        if (is_outside(planes[plane], smallest, biggest)) {
+            visible = false;
+            break;
+        }
+    }
+
+    draw_commands.commands[index] = DrawCommand(draws.draws[index].y, visible ? 1u : 0u, 0u, 0u);
+}
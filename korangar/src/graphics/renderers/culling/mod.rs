@@ -0,0 +1,76 @@
+compute_shader!("src/graphics/renderers/culling/compute_shader.glsl");
+
+use std::sync::Arc;
+
+use vulkano::buffer::Subbuffer;
+use vulkano::command_buffer::{AutoCommandBufferBuilder, DrawIndirectCommand, PrimaryAutoCommandBuffer};
+use vulkano::descriptor_set::WriteDescriptorSet;
+use vulkano::device::DeviceOwned;
+use vulkano::pipeline::{ComputePipeline, PipelineBindPoint};
+
+use self::compute_shader::Constants;
+use crate::graphics::renderers::pipeline::build_compute_pipeline;
+use crate::graphics::{allocate_descriptor_set, *};
+
+/// Number of draws tested by one work group. Has to match the local size of
+/// the compute shader.
+const WORK_GROUP_SIZE: u32 = 64;
+
+/// Tests the bounding boxes of the objects on the map against the view
+/// frustum of a camera and writes an indirect draw command for every node of
+/// every object. Nodes of objects outside the frustum are drawn with zero
+/// instances.
+pub struct CullingRenderer {
+    memory_allocator: Arc<MemoryAllocator>,
+    pipeline: Arc<ComputePipeline>,
+}
+
+impl CullingRenderer {
+    pub fn new(memory_allocator: Arc<MemoryAllocator>) -> Self {
+        let device = memory_allocator.device().clone();
+        let compute_shader = compute_shader::entry_point(&device);
+        let pipeline = build_compute_pipeline(device, &compute_shader);
+
+        Self {
+            memory_allocator,
+            pipeline,
+        }
+    }
+
+    /// Has to be recorded outside of a render pass. `bounding_boxes` holds the
+    /// smallest and the biggest corner of every object and `draws` the object
+    /// index and the vertex count of every node.
+    #[cfg_attr(feature = "debug", korangar_debug::profile("cull objects"))]
+    pub fn render(
+        &self,
+        builder: &mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer<MemoryAllocator>, MemoryAllocator>,
+        camera: &dyn Camera,
+        bounding_boxes: Subbuffer<[[f32; 4]]>,
+        draws: Subbuffer<[[u32; 2]]>,
+        draw_commands: Subbuffer<[DrawIndirectCommand]>,
+    ) {
+        let draw_count = draws.len() as u32;
+
+        let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 0, [
+            WriteDescriptorSet::buffer(0, bounding_boxes),
+            WriteDescriptorSet::buffer(1, draws),
+            WriteDescriptorSet::buffer(2, draw_commands),
+        ]);
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let constants = Constants {
+            view_projection: (projection_matrix * view_matrix).into(),
+            draw_count,
+        };
+
+        builder
+            .bind_pipeline_compute(self.pipeline.clone())
+            .unwrap()
+            .bind_descriptor_sets(PipelineBindPoint::Compute, layout.clone(), set_id, set)
+            .unwrap()
+            .push_constants(layout, 0, constants)
+            .unwrap()
+            .dispatch([draw_count.div_ceil(WORK_GROUP_SIZE), 1, 1])
+            .unwrap();
+    }
+}
@@ -0,0 +1,59 @@
+#version 450
+
+layout(location = 0) in vec2 position;
+
+layout(location = 0) out vec4 fragment_color;
+
+layout(set = 0, binding = 0) uniform sampler2D source;
+
+layout(push_constant) uniform Constants {
+    vec2 texel_size;
+    float threshold;
+    uint mode;
+} constants;
+
+const uint MODE_EXTRACT = 0;
+const uint MODE_DOWNSAMPLE = 1;
+const uint MODE_UPSAMPLE = 2;
+
+vec3 sample_offset(vec2 uv, float x, float y) {
+    return texture(source, uv + constants.texel_size * vec2(x, y)).rgb;
+}
+
+// 13 tap filter, so that small bright spots don't flicker when the camera moves.
+vec3 downsample(vec2 uv) {
+    vec3 center = sample_offset(uv, 0.0, 0.0);
+    vec3 inner = sample_offset(uv, -1.0, -1.0) + sample_offset(uv, 1.0, -1.0) + sample_offset(uv, -1.0, 1.0) + sample_offset(uv, 1.0, 1.0);
+    vec3 corners = sample_offset(uv, -2.0, -2.0) + sample_offset(uv, 2.0, -2.0) + sample_offset(uv, -2.0, 2.0) + sample_offset(uv, 2.0, 2.0);
+    vec3 sides = sample_offset(uv, 0.0, -2.0) + sample_offset(uv, -2.0, 0.0) + sample_offset(uv, 2.0, 0.0) + sample_offset(uv, 0.0, 2.0);
+
+    return center * 0.125 + inner * 0.125 + corners * 0.03125 + sides * 0.0625;
+}
+
+// 3x3 tent filter.
+vec3 upsample(vec2 uv) {
+    vec3 center = sample_offset(uv, 0.0, 0.0);
+    vec3 corners = sample_offset(uv, -1.0, -1.0) + sample_offset(uv, 1.0, -1.0) + sample_offset(uv, -1.0, 1.0) + sample_offset(uv, 1.0, 1.0);
+    vec3 sides = sample_offset(uv, 0.0, -1.0) + sample_offset(uv, -1.0, 0.0) + sample_offset(uv, 1.0, 0.0) + sample_offset(uv, 0.0, 1.0);
+
+    return (center * 4.0 + sides * 2.0 + corners) / 16.0;
+}
+
+void main() {
+    vec2 uv = position * 0.5 + 0.5;
+    vec3 color;
+
+    if (constants.mode == MODE_EXTRACT) {
+        color = downsample(uv);
+
+        // Only keep the part of the light that is brighter than the threshold.
+        float brightness = max(color.r, max(color.g, color.b));
+        color *= max(brightness - constants.threshold, 0.0) / max(brightness, 0.0001);
+    } else if (constants.mode == MODE_DOWNSAMPLE) {
+        color = downsample(uv);
+    } else {
+        color = upsample(uv);
+    }
+
+    fragment_color = vec4(color, 1.0);
+}
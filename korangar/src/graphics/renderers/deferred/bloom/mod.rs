@@ -0,0 +1,150 @@
+vertex_shader!("src/graphics/renderers/deferred/bloom/vertex_shader.glsl");
+fragment_shader!("src/graphics/renderers/deferred/bloom/fragment_shader.glsl");
+
+use std::sync::Arc;
+
+use vulkano::command_buffer::{RenderPassBeginInfo, SubpassBeginInfo, SubpassEndInfo};
+use vulkano::descriptor_set::WriteDescriptorSet;
+use vulkano::device::DeviceOwned;
+use vulkano::image::sampler::Sampler;
+use vulkano::pipeline::graphics::viewport::Viewport;
+use vulkano::pipeline::{GraphicsPipeline, PipelineBindPoint};
+use vulkano::render_pass::{Framebuffer, Subpass};
+
+use self::fragment_shader::Constants;
+use super::DeferredRenderer;
+use crate::graphics::renderers::pipeline::PipelineBuilder;
+use crate::graphics::renderers::sampler::{create_new_sampler, SamplerType};
+use crate::graphics::{allocate_descriptor_set, *};
+
+const MODE_EXTRACT: u32 = 0;
+const MODE_DOWNSAMPLE: u32 = 1;
+const MODE_UPSAMPLE: u32 = 2;
+
+/// Blurs the parts of the lit scene that are brighter than a threshold, by
+/// downsampling them into a chain of smaller and smaller images and adding
+/// them back up again.
+pub struct BloomRenderer {
+    memory_allocator: Arc<MemoryAllocator>,
+    pipeline: Arc<GraphicsPipeline>,
+    blend_pipeline: Arc<GraphicsPipeline>,
+    linear_sampler: Arc<Sampler>,
+}
+
+impl BloomRenderer {
+    pub fn new(memory_allocator: Arc<MemoryAllocator>, subpass: Subpass) -> Self {
+        let device = memory_allocator.device().clone();
+        let vertex_shader = vertex_shader::entry_point(&device);
+        let fragment_shader = fragment_shader::entry_point(&device);
+        let linear_sampler = create_new_sampler(&device, SamplerType::Linear);
+
+        // The viewport changes with every level of the chain, so both pipelines use a
+        // dynamic viewport and never need to be recreated.
+        let pipeline = PipelineBuilder::<_, { DeferredRenderer::bloom_subpass() }>::new([&vertex_shader, &fragment_shader])
+            .build(device.clone(), subpass.clone());
+        let blend_pipeline = PipelineBuilder::<_, { DeferredRenderer::bloom_subpass() }>::new([&vertex_shader, &fragment_shader])
+            .color_blend(LIGHT_ATTACHMENT_BLEND)
+            .build(device, subpass);
+
+        Self {
+            memory_allocator,
+            pipeline,
+            blend_pipeline,
+            linear_sampler,
+        }
+    }
+
+    fn render_level(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        pipeline: &Arc<GraphicsPipeline>,
+        source: Arc<ImageView>,
+        framebuffer: Arc<Framebuffer>,
+        threshold: f32,
+        mode: u32,
+    ) {
+        let source_extent = source.image().extent();
+        let target_extent = framebuffer.extent();
+
+        let viewport = Viewport {
+            offset: [0.0, 0.0],
+            extent: [target_extent[0] as f32, target_extent[1] as f32],
+            depth_range: 0.0..=1.0,
+        };
+
+        let (layout, set, set_id) = allocate_descriptor_set(pipeline, &self.memory_allocator, 0, [WriteDescriptorSet::image_view_sampler(
+            0,
+            source,
+            self.linear_sampler.clone(),
+        )]);
+
+        let constants = Constants {
+            texel_size: [1.0 / source_extent[0] as f32, 1.0 / source_extent[1] as f32],
+            threshold,
+            mode,
+        };
+
+        let render_pass_begin_info = RenderPassBeginInfo {
+            clear_values: vec![None],
+            ..RenderPassBeginInfo::framebuffer(framebuffer)
+        };
+
+        render_target
+            .state
+            .get_builder()
+            .begin_render_pass(render_pass_begin_info, SubpassBeginInfo::default())
+            .unwrap()
+            .bind_pipeline_graphics(pipeline.clone())
+            .unwrap()
+            .set_viewport(0, std::iter::once(viewport).collect())
+            .unwrap()
+            .bind_descriptor_sets(PipelineBindPoint::Graphics, layout.clone(), set_id, set)
+            .unwrap()
+            .push_constants(layout, 0, constants)
+            .unwrap()
+            .draw(6, 1, 0, 0)
+            .unwrap()
+            .end_render_pass(SubpassEndInfo::default())
+            .unwrap();
+    }
+
+    /// Has to be called outside of a render pass. The blurred light ends up in
+    /// the first image of the bloom chain.
+    #[cfg_attr(feature = "debug", korangar_debug::profile("render bloom"))]
+    pub fn render(&self, render_target: &mut <DeferredRenderer as Renderer>::Target, threshold: f32) {
+        let images = render_target.bloom_images.clone();
+        let framebuffers = render_target.bloom_framebuffers.clone();
+        let scene_image = render_target.scene_image.clone();
+
+        self.render_level(
+            render_target,
+            &self.pipeline,
+            scene_image,
+            framebuffers[0].clone(),
+            threshold,
+            MODE_EXTRACT,
+        );
+
+        for level in 1..images.len() {
+            self.render_level(
+                render_target,
+                &self.pipeline,
+                images[level - 1].clone(),
+                framebuffers[level].clone(),
+                threshold,
+                MODE_DOWNSAMPLE,
+            );
+        }
+
+        for level in (1..images.len()).rev() {
+            self.render_level(
+                render_target,
+                &self.blend_pipeline,
+                images[level].clone(),
+                framebuffers[level - 1].clone(),
+                threshold,
+                MODE_UPSAMPLE,
+            );
+        }
+    }
+}
@@ -83,7 +83,7 @@ impl BoxRenderer {
         vertex_shader: &EntryPoint,
         fragment_shader: &EntryPoint,
     ) -> Arc<GraphicsPipeline> {
-        PipelineBuilder::<_, { DeferredRenderer::lighting_subpass() }>::new([vertex_shader, fragment_shader])
+        PipelineBuilder::<_, { DeferredRenderer::screen_subpass() }>::new([vertex_shader, fragment_shader])
             .vertex_input_state::<WaterVertex>(vertex_shader)
             .topology(PrimitiveTopology::LineList)
             .fixed_viewport(viewport)
@@ -0,0 +1,47 @@
+#version 450
+
+layout(location = 0) in vec2 position;
+
+layout(location = 0) out vec4 fragment_color;
+
+layout(set = 0, binding = 0) uniform sampler2D scene;
+layout(set = 0, binding = 1) uniform sampler2D bloom;
+
+layout(push_constant) uniform Constants {
+    float bloom_intensity;
+    float sharpness;
+} constants;
+
+// Contrast adaptive sharpening, which sharpens less where the contrast is already
+// high so that edges don't get halos.
+vec3 sharpen(vec3 color, vec2 uv) {
+    vec2 texel_size = 1.0 / vec2(textureSize(scene, 0));
+
+    vec3 north = texture(scene, uv + vec2(0.0, -texel_size.y)).rgb;
+    vec3 south = texture(scene, uv + vec2(0.0, texel_size.y)).rgb;
+    vec3 west = texture(scene, uv + vec2(-texel_size.x, 0.0)).rgb;
+    vec3 east = texture(scene, uv + vec2(texel_size.x, 0.0)).rgb;
+
+    vec3 minimum = min(color, min(min(north, south), min(west, east)));
+    vec3 maximum = max(color, max(max(north, south), max(west, east)));
+
+    vec3 amplitude = sqrt(clamp(min(minimum, 1.0 - maximum) / max(maximum, 0.0001), 0.0, 1.0));
+    vec3 weight = -amplitude / mix(8.0, 5.0, constants.sharpness);
+
+    return (color + (north + south + west + east) * weight) / (1.0 + 4.0 * weight);
+}
+
+void main() {
+    vec2 uv = position * 0.5 + 0.5;
+    vec3 color = texture(scene, uv).rgb;
+
+    if (constants.sharpness > 0.0) {
+        color = sharpen(color, uv);
+    }
+
+    if (constants.bloom_intensity > 0.0) {
+        color += texture(bloom, uv).rgb * constants.bloom_intensity;
+    }
+
+    fragment_color = vec4(color, 1.0);
+}
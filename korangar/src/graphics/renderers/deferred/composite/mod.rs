@@ -0,0 +1,113 @@
+vertex_shader!("src/graphics/renderers/deferred/composite/vertex_shader.glsl");
+fragment_shader!("src/graphics/renderers/deferred/composite/fragment_shader.glsl");
+
+use std::sync::Arc;
+
+use vulkano::descriptor_set::WriteDescriptorSet;
+use vulkano::device::{Device, DeviceOwned};
+use vulkano::image::sampler::Sampler;
+use vulkano::pipeline::graphics::viewport::Viewport;
+use vulkano::pipeline::{GraphicsPipeline, PipelineBindPoint};
+use vulkano::render_pass::Subpass;
+use vulkano::shader::EntryPoint;
+
+use self::fragment_shader::Constants;
+use super::{DeferredRenderer, DeferredSubrenderer};
+use crate::graphics::renderers::pipeline::PipelineBuilder;
+use crate::graphics::renderers::sampler::{create_new_sampler, SamplerType};
+use crate::graphics::{allocate_descriptor_set, *};
+
+/// How strongly the scene is sharpened when it is scaled up to the size of the
+/// window, from 0 to 1.
+const UPSCALING_SHARPNESS: f32 = 0.5;
+
+pub struct CompositeRenderer {
+    memory_allocator: Arc<MemoryAllocator>,
+    pipeline: Arc<GraphicsPipeline>,
+    vertex_shader: EntryPoint,
+    fragment_shader: EntryPoint,
+    linear_sampler: Arc<Sampler>,
+}
+
+impl CompositeRenderer {
+    pub fn new(memory_allocator: Arc<MemoryAllocator>, subpass: Subpass, viewport: Viewport) -> Self {
+        let device = memory_allocator.device().clone();
+        let vertex_shader = vertex_shader::entry_point(&device);
+        let fragment_shader = fragment_shader::entry_point(&device);
+        let linear_sampler = create_new_sampler(&device, SamplerType::Linear);
+        let pipeline = Self::create_pipeline(device, subpass, viewport, &vertex_shader, &fragment_shader);
+
+        Self {
+            memory_allocator,
+            pipeline,
+            vertex_shader,
+            fragment_shader,
+            linear_sampler,
+        }
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn recreate_pipeline(&mut self, device: Arc<Device>, subpass: Subpass, viewport: Viewport) {
+        self.pipeline = Self::create_pipeline(device, subpass, viewport, &self.vertex_shader, &self.fragment_shader);
+    }
+
+    fn create_pipeline(
+        device: Arc<Device>,
+        subpass: Subpass,
+        viewport: Viewport,
+        vertex_shader: &EntryPoint,
+        fragment_shader: &EntryPoint,
+    ) -> Arc<GraphicsPipeline> {
+        PipelineBuilder::<_, { DeferredRenderer::screen_subpass() }>::new([vertex_shader, fragment_shader])
+            .fixed_viewport(viewport)
+            .build(device, subpass)
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    fn bind_pipeline(&self, render_target: &mut <DeferredRenderer as Renderer>::Target) {
+        render_target
+            .state
+            .get_builder()
+            .bind_pipeline_graphics(self.pipeline.clone())
+            .unwrap();
+    }
+
+    /// Draws the lit scene to the screen, together with the bloom if
+    /// `bloom_intensity` is larger than 0.
+    #[cfg_attr(feature = "debug", korangar_debug::profile("render composite"))]
+    pub fn render(&self, render_target: &mut <DeferredRenderer as Renderer>::Target, bloom_intensity: f32, sharpen: bool) {
+        if render_target.bind_subrenderer(DeferredSubrenderer::Composite) {
+            self.bind_pipeline(render_target);
+        }
+
+        // The bloom chain is not rendered when bloom is turned off, so the scene is
+        // bound in its place.
+        let bloom_image = match bloom_intensity > 0.0 {
+            true => render_target.bloom_images[0].clone(),
+            false => render_target.scene_image.clone(),
+        };
+
+        let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 0, [
+            WriteDescriptorSet::image_view_sampler(0, render_target.scene_image.clone(), self.linear_sampler.clone()),
+            WriteDescriptorSet::image_view_sampler(1, bloom_image, self.linear_sampler.clone()),
+        ]);
+
+        let constants = Constants {
+            bloom_intensity,
+            sharpness: match sharpen {
+                true => UPSCALING_SHARPNESS,
+                false => 0.0,
+            },
+        };
+
+        render_target
+            .state
+            .get_builder()
+            .bind_descriptor_sets(PipelineBindPoint::Graphics, layout.clone(), set_id, set)
+            .unwrap()
+            .push_constants(layout, 0, constants)
+            .unwrap()
+            .draw(6, 1, 0, 0)
+            .unwrap();
+    }
+}
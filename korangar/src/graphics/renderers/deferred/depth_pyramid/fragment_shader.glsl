@@ -0,0 +1,55 @@
+#version 450
+
+layout(location = 0) out float fragment_depth;
+
+layout(set = 0, binding = 0) uniform sampler2DMS depth;
+layout(set = 0, binding = 1) uniform sampler2D previous_level;
+
+layout(push_constant) uniform Constants {
+    uint first_level;
+} constants;
+
+const int DEPTH_SAMPLES = 4;
+
+float fetch_depth(ivec2 position) {
+    if (constants.first_level == 0) {
+        return texelFetch(previous_level, position, 0).r;
+    }
+
+    float farthest = 0.0;
+
+    for (int sample_index = 0; sample_index < DEPTH_SAMPLES; sample_index++) {
+        farthest = max(farthest, texelFetch(depth, position, sample_index).r);
+    }
+
+    return farthest;
+}
+
+void main() {
+    ivec2 source_size = constants.first_level != 0 ? textureSize(depth) : textureSize(previous_level, 0);
+    ivec2 target_size = max(source_size / 2, ivec2(1));
+    ivec2 position = ivec2(gl_FragCoord.xy);
+
+    ivec2 first = position * 2;
+    ivec2 last = min(first + 1, source_size - 1);
+
+    // The size of every level is rounded down, so the last row and column also
+    // cover the texels of the source that would otherwise be skipped.
+    if (position.x == target_size.x - 1) {
+        last.x = source_size.x - 1;
+    }
+
+    if (position.y == target_size.y - 1) {
+        last.y = source_size.y - 1;
+    }
+
+    float farthest = 0.0;
+
+    for (int y = first.y; y <= last.y; y++) {
+        for (int x = first.x; x <= last.x; x++) {
+            farthest = max(farthest, fetch_depth(ivec2(x, y)));
+        }
+    }
+
+    fragment_depth = farthest;
+}
@@ -0,0 +1,112 @@
+vertex_shader!("src/graphics/renderers/deferred/depth_pyramid/vertex_shader.glsl");
+fragment_shader!("src/graphics/renderers/deferred/depth_pyramid/fragment_shader.glsl");
+
+use std::sync::Arc;
+
+use vulkano::command_buffer::{RenderPassBeginInfo, SubpassBeginInfo, SubpassEndInfo};
+use vulkano::descriptor_set::WriteDescriptorSet;
+use vulkano::device::DeviceOwned;
+use vulkano::image::sampler::Sampler;
+use vulkano::pipeline::graphics::viewport::Viewport;
+use vulkano::pipeline::{GraphicsPipeline, PipelineBindPoint};
+use vulkano::render_pass::{Framebuffer, Subpass};
+
+use self::fragment_shader::Constants;
+use super::DeferredRenderer;
+use crate::graphics::renderers::pipeline::PipelineBuilder;
+use crate::graphics::renderers::sampler::{create_new_sampler, SamplerType};
+use crate::graphics::{allocate_descriptor_set, *};
+
+/// Reduces the depth buffer to a chain of smaller and smaller images, where
+/// every texel holds the farthest depth of the area it covers. Used to quickly
+/// test if something is hidden behind what was already rendered.
+pub struct DepthPyramidRenderer {
+    memory_allocator: Arc<MemoryAllocator>,
+    pipeline: Arc<GraphicsPipeline>,
+    nearest_sampler: Arc<Sampler>,
+}
+
+impl DepthPyramidRenderer {
+    pub fn new(memory_allocator: Arc<MemoryAllocator>, subpass: Subpass) -> Self {
+        let device = memory_allocator.device().clone();
+        let vertex_shader = vertex_shader::entry_point(&device);
+        let fragment_shader = fragment_shader::entry_point(&device);
+        let nearest_sampler = create_new_sampler(&device, SamplerType::Nearest);
+
+        // The viewport changes with every level of the pyramid, so the pipeline uses a
+        // dynamic viewport and never needs to be recreated.
+        let pipeline = PipelineBuilder::<_, { DeferredRenderer::depth_pyramid_subpass() }>::new([&vertex_shader, &fragment_shader])
+            .build(device, subpass);
+
+        Self {
+            memory_allocator,
+            pipeline,
+            nearest_sampler,
+        }
+    }
+
+    fn render_level(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        previous_level: Arc<ImageView>,
+        framebuffer: Arc<Framebuffer>,
+        first_level: bool,
+    ) {
+        let extent = framebuffer.extent();
+
+        let viewport = Viewport {
+            offset: [0.0, 0.0],
+            extent: [extent[0] as f32, extent[1] as f32],
+            depth_range: 0.0..=1.0,
+        };
+
+        let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 0, [
+            WriteDescriptorSet::image_view_sampler(0, render_target.depth_image.clone(), self.nearest_sampler.clone()),
+            WriteDescriptorSet::image_view_sampler(1, previous_level, self.nearest_sampler.clone()),
+        ]);
+
+        let constants = Constants {
+            first_level: first_level as u32,
+        };
+
+        let render_pass_begin_info = RenderPassBeginInfo {
+            clear_values: vec![None],
+            ..RenderPassBeginInfo::framebuffer(framebuffer)
+        };
+
+        render_target
+            .state
+            .get_builder()
+            .begin_render_pass(render_pass_begin_info, SubpassBeginInfo::default())
+            .unwrap()
+            .bind_pipeline_graphics(self.pipeline.clone())
+            .unwrap()
+            .set_viewport(0, std::iter::once(viewport).collect())
+            .unwrap()
+            .bind_descriptor_sets(PipelineBindPoint::Graphics, layout.clone(), set_id, set)
+            .unwrap()
+            .push_constants(layout, 0, constants)
+            .unwrap()
+            .draw(6, 1, 0, 0)
+            .unwrap()
+            .end_render_pass(SubpassEndInfo::default())
+            .unwrap();
+    }
+
+    /// Has to be called outside of a render pass.
+    #[cfg_attr(feature = "debug", korangar_debug::profile("render depth pyramid"))]
+    pub fn render(&self, render_target: &mut <DeferredRenderer as Renderer>::Target) {
+        let levels = render_target.depth_pyramid_levels.clone();
+        let framebuffers = render_target.depth_pyramid_framebuffers.clone();
+
+        // The first level is read straight from the depth buffer, so the previous level
+        // is never sampled. It still needs to be bound to something, so the lit scene
+        // is bound in its place.
+        let scene_image = render_target.scene_image.clone();
+        self.render_level(render_target, scene_image, framebuffers[0].clone(), true);
+
+        for level in 1..levels.len() {
+            self.render_level(render_target, levels[level - 1].clone(), framebuffers[level].clone(), false);
+        }
+    }
+}
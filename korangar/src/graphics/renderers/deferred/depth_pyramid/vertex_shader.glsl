@@ -0,0 +1,15 @@
+#version 450
+
+const vec2 data[6] = vec2[]
+(
+    vec2(-1, -1),
+    vec2(-1, 1),
+    vec2(1, -1),
+    vec2(1, -1),
+    vec2(-1, 1),
+    vec2(1, 1)
+);
+
+void main() {
+    gl_Position = vec4(data[gl_VertexIndex], 0.0, 1.0);
+}
@@ -10,15 +10,27 @@ layout(location = 1) out vec3 fragment_normal;
 
 layout(push_constant) uniform Constants {
     mat4 world;
+    vec4 color;
     vec2 texture_position;
     vec2 texture_size;
     float depth_offset;
     float curvature;
     bool mirror;
+    float saturation;
 } constants;
 
 layout (set = 1, binding = 0) uniform sampler2D sampled_texture;
 
+// Thresholds for rendering partially transparent entities. The deferred
+// renderer can't blend, so a pattern of pixels is left out instead.
+const float dither_thresholds[16] = float[]
+(
+    0.0 / 16.0, 8.0 / 16.0, 2.0 / 16.0, 10.0 / 16.0,
+    12.0 / 16.0, 4.0 / 16.0, 14.0 / 16.0, 6.0 / 16.0,
+    3.0 / 16.0, 11.0 / 16.0, 1.0 / 16.0, 9.0 / 16.0,
+    15.0 / 16.0, 7.0 / 16.0, 13.0 / 16.0, 5.0 / 16.0
+);
+
 void main() {
 
     vec4 diffuse_color = texture(sampled_texture, texture_coordinates);
@@ -27,7 +39,16 @@ void main() {
         discard;
     }
 
-    fragment_color = diffuse_color;
+    ivec2 dither_position = ivec2(gl_FragCoord.xy) % 4;
+
+    if (constants.color.a <= dither_thresholds[dither_position.y * 4 + dither_position.x]) {
+        discard;
+    }
+
+    float luminance = dot(diffuse_color.rgb, vec3(0.299, 0.587, 0.114));
+    vec3 color = mix(vec3(luminance), diffuse_color.rgb, constants.saturation) * constants.color.rgb;
+
+    fragment_color = vec4(color, 1.0);
     fragment_normal = normalize(normal);
 
     float curvature_offset = (0.5 - pow(curvature, 2)) * constants.curvature;
@@ -99,6 +99,7 @@ impl EntityRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        tint: EntityTint,
     ) {
         if render_target.bind_subrenderer(DeferredSubrenderer::Entity) {
             self.bind_pipeline(render_target, camera);
@@ -121,11 +122,13 @@ impl EntityRenderer {
 
         let constants = Constants {
             world: world_matrix.into(),
+            color: tint.color.into(),
             texture_position: texture_position.into(),
             texture_size: texture_size.into(),
             depth_offset,
             curvature,
             mirror: mirror as u32,
+            saturation: tint.saturation,
         };
 
         render_target
@@ -4,6 +4,7 @@ fragment_shader!("src/graphics/renderers/deferred/geometry/fragment_shader.glsl"
 use std::sync::Arc;
 
 use cgmath::Matrix4;
+use vulkano::command_buffer::DrawIndirectCommand;
 use vulkano::descriptor_set::WriteDescriptorSet;
 use vulkano::device::{Device, DeviceOwned};
 use vulkano::image::sampler::Sampler;
@@ -145,6 +146,7 @@ impl GeometryRenderer {
         textures: &[Arc<ImageView>],
         world_matrix: Matrix4<f32>,
         time: f32,
+        indirect_draw: Option<Subbuffer<[DrawIndirectCommand]>>,
     ) {
         if render_target.bind_subrenderer(DeferredSubrenderer::Geometry) {
             self.bind_pipeline(render_target, camera, time);
@@ -178,7 +180,7 @@ impl GeometryRenderer {
             world: world_matrix.into(),
         };
 
-        render_target
+        let builder = render_target
             .state
             .get_builder()
             .bind_descriptor_sets(PipelineBindPoint::Graphics, layout.clone(), set_id, set)
@@ -186,8 +188,11 @@ impl GeometryRenderer {
             .push_constants(layout, 0, constants)
             .unwrap()
             .bind_vertex_buffers(0, vertex_buffer)
-            .unwrap()
-            .draw(vertex_count as u32, 1, 0, 0)
             .unwrap();
+
+        match indirect_draw {
+            Some(indirect_draw) => builder.draw_indirect(indirect_draw).unwrap(),
+            None => builder.draw(vertex_count as u32, 1, 0, 0).unwrap(),
+        };
     }
 }
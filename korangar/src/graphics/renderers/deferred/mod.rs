@@ -1,13 +1,17 @@
 mod ambient;
+mod bloom;
 #[cfg(feature = "debug")]
 mod r#box;
 #[cfg(feature = "debug")]
 mod buffer;
+mod composite;
+mod depth_pyramid;
 mod directional;
 mod effect;
 mod entity;
 mod geometry;
 mod indicator;
+mod occlusion;
 mod overlay;
 mod point;
 mod rectangle;
@@ -24,6 +28,7 @@ use korangar_interface::application::FontSizeTrait;
 #[cfg(feature = "debug")]
 use ragnarok_formats::transform::Transform;
 use ragnarok_packets::EntityId;
+use vulkano::command_buffer::DrawIndirectCommand;
 use vulkano::device::{DeviceOwned, Queue};
 use vulkano::format::Format;
 use vulkano::image::Image;
@@ -32,25 +37,30 @@ use vulkano::pipeline::graphics::viewport::Viewport;
 use vulkano::render_pass::{RenderPass, Subpass};
 
 use self::ambient::AmbientLightRenderer;
-#[cfg(feature = "debug")]
-use self::r#box::BoxRenderer;
+use self::bloom::BloomRenderer;
 #[cfg(feature = "debug")]
 use self::buffer::BufferRenderer;
+use self::composite::CompositeRenderer;
+use self::depth_pyramid::DepthPyramidRenderer;
 use self::directional::DirectionalLightRenderer;
 use self::effect::EffectRenderer;
 use self::entity::EntityRenderer;
 use self::geometry::GeometryRenderer;
 use self::indicator::IndicatorRenderer;
+use self::occlusion::OcclusionRenderer;
 use self::overlay::OverlayRenderer;
 use self::point::PointLightRenderer;
+#[cfg(feature = "debug")]
+use self::r#box::BoxRenderer;
 use self::rectangle::RectangleRenderer;
 use self::sprite::SpriteRenderer;
 use self::water::WaterRenderer;
 use self::water_light::WaterLightRenderer;
+use super::culling::CullingRenderer;
 use super::SubpassAttachments;
 use crate::graphics::{
-    EntityRenderer as EntityRendererTrait, GeometryRenderer as GeometryRendererTrait, IndicatorRenderer as IndicatorRendererTrait,
-    SpriteRenderer as SpriteRendererTrait, *,
+    CullingRenderer as CullingRendererTrait, EntityRenderer as EntityRendererTrait, GeometryRenderer as GeometryRendererTrait,
+    IndicatorRenderer as IndicatorRendererTrait, SpriteRenderer as SpriteRendererTrait, *,
 };
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::loaders::{FontSize, GameFileLoader, TextureLoader};
@@ -75,12 +85,16 @@ pub enum DeferredSubrenderer {
     Rectangle,
     Sprite,
     Effect,
+    Composite,
 }
 
 pub struct DeferredRenderer {
     memory_allocator: Arc<MemoryAllocator>,
     queue: Arc<Queue>,
     render_pass: Arc<RenderPass>,
+    bloom_render_pass: Arc<RenderPass>,
+    depth_pyramid_render_pass: Arc<RenderPass>,
+    screen_render_pass: Arc<RenderPass>,
     geometry_renderer: GeometryRenderer,
     entity_renderer: EntityRenderer,
     water_renderer: WaterRenderer,
@@ -93,6 +107,11 @@ pub struct DeferredRenderer {
     rectangle_renderer: RectangleRenderer,
     sprite_renderer: SpriteRenderer,
     effect_renderer: EffectRenderer,
+    bloom_renderer: BloomRenderer,
+    composite_renderer: CompositeRenderer,
+    depth_pyramid_renderer: DepthPyramidRenderer,
+    occlusion_renderer: OcclusionRenderer,
+    culling_renderer: CullingRenderer,
     #[cfg(feature = "debug")]
     buffer_renderer: BufferRenderer,
     #[cfg(feature = "debug")]
@@ -102,6 +121,7 @@ pub struct DeferredRenderer {
     font_map: Arc<ImageView>,
     walk_indicator: Arc<ImageView>,
     dimensions: [u32; 2],
+    render_scale: f32,
 }
 
 unsafe impl Send for DeferredRenderer {}
@@ -116,6 +136,28 @@ impl DeferredRenderer {
         SubpassAttachments { color: 1, depth: 0 }
     }
 
+    const fn bloom_subpass() -> SubpassAttachments {
+        SubpassAttachments { color: 1, depth: 0 }
+    }
+
+    const fn depth_pyramid_subpass() -> SubpassAttachments {
+        SubpassAttachments { color: 1, depth: 0 }
+    }
+
+    const fn screen_subpass() -> SubpassAttachments {
+        SubpassAttachments { color: 1, depth: 0 }
+    }
+
+    /// Viewport of the geometry and lighting passes, which render the scene at
+    /// `render_scale` times the size of the window.
+    fn scene_viewport(dimensions: [u32; 2], render_scale: f32) -> Viewport {
+        Viewport {
+            offset: [0.0, 0.0],
+            extent: scaled_dimensions(dimensions, render_scale).map(|component| component as f32),
+            depth_range: 0.0..=1.0,
+        }
+    }
+
     pub fn new(
         memory_allocator: Arc<MemoryAllocator>,
         buffer_allocator: &mut BufferAllocator,
@@ -125,12 +167,13 @@ impl DeferredRenderer {
         swapchain_format: Format,
         viewport: Viewport,
         dimensions: [u32; 2],
+        render_scale: f32,
     ) -> Self {
         let device = memory_allocator.device().clone();
         let render_pass = ordered_passes_renderpass!(device,
             attachments: {
-                output: {
-                    format: swapchain_format,
+                scene: {
+                    format: Format::R16G16B16A16_SFLOAT,
                     samples: 1,
                     load_op: Clear,
                     store_op: Store,
@@ -167,7 +210,7 @@ impl DeferredRenderer {
                     input: []
                 },
                 {
-                    color: [output],
+                    color: [scene],
                     depth_stencil: {},
                     input: [diffuse, normal, water, depth]
                 }
@@ -175,34 +218,96 @@ impl DeferredRenderer {
         )
         .unwrap();
 
+        // Every level of the bloom chain is completely overwritten, except when the
+        // upsampled light of the level below is added to it.
+        let bloom_render_pass = vulkano::single_pass_renderpass!(device,
+            attachments: {
+                bloom: {
+                    format: Format::R16G16B16A16_SFLOAT,
+                    samples: 1,
+                    load_op: Load,
+                    store_op: Store,
+                }
+            },
+            pass: {
+                color: [bloom],
+                depth_stencil: {}
+            }
+        )
+        .unwrap();
+
+        // Every level of the depth pyramid is completely overwritten.
+        let depth_pyramid_render_pass = vulkano::single_pass_renderpass!(device,
+            attachments: {
+                depth: {
+                    format: Format::R32_SFLOAT,
+                    samples: 1,
+                    load_op: DontCare,
+                    store_op: Store,
+                }
+            },
+            pass: {
+                color: [depth],
+                depth_stencil: {}
+            }
+        )
+        .unwrap();
+
+        // The lit scene is drawn over the entire swapchain image, so there is no need
+        // to clear it.
+        let screen_render_pass = vulkano::single_pass_renderpass!(device,
+            attachments: {
+                output: {
+                    format: swapchain_format,
+                    samples: 1,
+                    load_op: DontCare,
+                    store_op: Store,
+                }
+            },
+            pass: {
+                color: [output],
+                depth_stencil: {}
+            }
+        )
+        .unwrap();
+
         let geometry_subpass = Subpass::from(render_pass.clone(), 0).unwrap();
         let lighting_subpass = Subpass::from(render_pass.clone(), 1).unwrap();
-
-        let geometry_renderer = GeometryRenderer::new(memory_allocator.clone(), geometry_subpass.clone(), viewport.clone());
-        let entity_renderer = EntityRenderer::new(memory_allocator.clone(), geometry_subpass.clone(), viewport.clone());
-        let water_renderer = WaterRenderer::new(memory_allocator.clone(), geometry_subpass.clone(), viewport.clone());
-        let indicator_renderer = IndicatorRenderer::new(memory_allocator.clone(), geometry_subpass, viewport.clone());
-        let ambient_light_renderer = AmbientLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
+        let bloom_subpass = Subpass::from(bloom_render_pass.clone(), 0).unwrap();
+        let depth_pyramid_subpass = Subpass::from(depth_pyramid_render_pass.clone(), 0).unwrap();
+        let screen_subpass = Subpass::from(screen_render_pass.clone(), 0).unwrap();
+        let scene_viewport = Self::scene_viewport(dimensions, render_scale);
+
+        let geometry_renderer = GeometryRenderer::new(memory_allocator.clone(), geometry_subpass.clone(), scene_viewport.clone());
+        let entity_renderer = EntityRenderer::new(memory_allocator.clone(), geometry_subpass.clone(), scene_viewport.clone());
+        let water_renderer = WaterRenderer::new(memory_allocator.clone(), geometry_subpass.clone(), scene_viewport.clone());
+        let indicator_renderer = IndicatorRenderer::new(memory_allocator.clone(), geometry_subpass, scene_viewport.clone());
+        let ambient_light_renderer = AmbientLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), scene_viewport.clone());
         let directional_light_renderer =
-            DirectionalLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
-        let point_light_renderer = PointLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
-        let water_light_renderer = WaterLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
-        let overlay_renderer = OverlayRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
-        let rectangle_renderer = RectangleRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
+            DirectionalLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), scene_viewport.clone());
+        let point_light_renderer = PointLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), scene_viewport.clone());
+        let water_light_renderer = WaterLightRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), scene_viewport.clone());
+        let overlay_renderer = OverlayRenderer::new(memory_allocator.clone(), screen_subpass.clone(), viewport.clone());
+        let rectangle_renderer = RectangleRenderer::new(memory_allocator.clone(), screen_subpass.clone(), viewport.clone());
         let sprite_renderer = SpriteRenderer::new(
             memory_allocator.clone(),
-            lighting_subpass.clone(),
+            screen_subpass.clone(),
             viewport.clone(),
             #[cfg(feature = "debug")]
             game_file_loader,
             #[cfg(feature = "debug")]
             texture_loader,
         );
-        let effect_renderer = EffectRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
+        let effect_renderer = EffectRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), scene_viewport.clone());
         #[cfg(feature = "debug")]
-        let buffer_renderer = BufferRenderer::new(memory_allocator.clone(), lighting_subpass.clone(), viewport.clone());
+        let buffer_renderer = BufferRenderer::new(memory_allocator.clone(), lighting_subpass, scene_viewport);
+        let bloom_renderer = BloomRenderer::new(memory_allocator.clone(), bloom_subpass);
+        let composite_renderer = CompositeRenderer::new(memory_allocator.clone(), screen_subpass.clone(), viewport.clone());
+        let depth_pyramid_renderer = DepthPyramidRenderer::new(memory_allocator.clone(), depth_pyramid_subpass);
+        let occlusion_renderer = OcclusionRenderer::new(memory_allocator.clone());
+        let culling_renderer = CullingRenderer::new(memory_allocator.clone());
         #[cfg(feature = "debug")]
-        let box_renderer = BoxRenderer::new(memory_allocator.clone(), buffer_allocator, lighting_subpass, viewport);
+        let box_renderer = BoxRenderer::new(memory_allocator.clone(), buffer_allocator, screen_subpass, viewport);
 
         let font_map = texture_loader.get("font.png", game_file_loader).unwrap();
         let walk_indicator = texture_loader.get("grid.tga", game_file_loader).unwrap();
@@ -222,6 +327,9 @@ impl DeferredRenderer {
             memory_allocator,
             queue,
             render_pass,
+            bloom_render_pass,
+            depth_pyramid_render_pass,
+            screen_render_pass,
             geometry_renderer,
             entity_renderer,
             water_renderer,
@@ -234,6 +342,11 @@ impl DeferredRenderer {
             rectangle_renderer,
             sprite_renderer,
             effect_renderer,
+            bloom_renderer,
+            composite_renderer,
+            depth_pyramid_renderer,
+            occlusion_renderer,
+            culling_renderer,
             #[cfg(feature = "debug")]
             buffer_renderer,
             #[cfg(feature = "debug")]
@@ -243,50 +356,62 @@ impl DeferredRenderer {
             font_map,
             walk_indicator,
             dimensions,
+            render_scale,
         }
     }
 
     #[cfg_attr(feature = "debug", korangar_debug::profile("re-create deferred pipeline"))]
-    pub fn recreate_pipeline(&mut self, viewport: Viewport, dimensions: [u32; 2], #[cfg(feature = "debug")] wireframe: bool) {
+    pub fn recreate_pipeline(
+        &mut self,
+        viewport: Viewport,
+        dimensions: [u32; 2],
+        render_scale: f32,
+        #[cfg(feature = "debug")] wireframe: bool,
+    ) {
         let device = self.memory_allocator.device().clone();
         let geometry_subpass = Subpass::from(self.render_pass.clone(), 0).unwrap();
         let lighting_subpass = Subpass::from(self.render_pass.clone(), 1).unwrap();
+        let screen_subpass = Subpass::from(self.screen_render_pass.clone(), 0).unwrap();
+        let scene_viewport = Self::scene_viewport(dimensions, render_scale);
 
         self.geometry_renderer.recreate_pipeline(
             device.clone(),
             geometry_subpass.clone(),
-            viewport.clone(),
+            scene_viewport.clone(),
             #[cfg(feature = "debug")]
             wireframe,
         );
         self.entity_renderer
-            .recreate_pipeline(device.clone(), geometry_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), geometry_subpass.clone(), scene_viewport.clone());
         self.water_renderer
-            .recreate_pipeline(device.clone(), geometry_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), geometry_subpass.clone(), scene_viewport.clone());
         self.indicator_renderer
-            .recreate_pipeline(device.clone(), geometry_subpass, viewport.clone());
+            .recreate_pipeline(device.clone(), geometry_subpass, scene_viewport.clone());
         self.ambient_light_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), lighting_subpass.clone(), scene_viewport.clone());
         self.directional_light_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), lighting_subpass.clone(), scene_viewport.clone());
         self.point_light_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), lighting_subpass.clone(), scene_viewport.clone());
         self.water_light_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), lighting_subpass.clone(), scene_viewport.clone());
         self.overlay_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), screen_subpass.clone(), viewport.clone());
         self.rectangle_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), screen_subpass.clone(), viewport.clone());
         self.sprite_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), screen_subpass.clone(), viewport.clone());
         self.effect_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), lighting_subpass.clone(), scene_viewport.clone());
         #[cfg(feature = "debug")]
         self.buffer_renderer
-            .recreate_pipeline(device.clone(), lighting_subpass.clone(), viewport.clone());
+            .recreate_pipeline(device.clone(), lighting_subpass, scene_viewport);
         #[cfg(feature = "debug")]
-        self.box_renderer.recreate_pipeline(device, lighting_subpass, viewport);
+        self.box_renderer
+            .recreate_pipeline(device.clone(), screen_subpass.clone(), viewport.clone());
+        self.composite_renderer.recreate_pipeline(device, screen_subpass, viewport);
         self.dimensions = dimensions;
+        self.render_scale = render_scale;
     }
 
     #[cfg_attr(feature = "debug", korangar_debug::profile("create deferred render target"))]
@@ -295,8 +420,11 @@ impl DeferredRenderer {
             self.memory_allocator.clone(),
             self.queue.clone(),
             self.render_pass.clone(),
+            self.bloom_render_pass.clone(),
+            self.depth_pyramid_render_pass.clone(),
+            self.screen_render_pass.clone(),
             swapchain_image,
-            self.dimensions,
+            scaled_dimensions(self.dimensions, self.render_scale),
         )
     }
 
@@ -343,6 +471,43 @@ impl DeferredRenderer {
         self.water_light_renderer.render(render_target, camera, water_level);
     }
 
+    /// Ends the lighting pass and draws the lit scene to the screen, adding a
+    /// glow around everything that is brighter than `bloom_threshold`. If the
+    /// scene is rendered at a lower resolution than the window, it can be
+    /// sharpened while it is scaled up. Everything rendered afterwards, like
+    /// the interface, is drawn at the resolution of the window and doesn't
+    /// glow.
+    ///
+    /// If `object_bounding_boxes` are given, they are tested against the depth
+    /// buffer as seen from `camera`. The result can be read with
+    /// [`DeferredRenderTarget::visible_objects`] the next time the render
+    /// target is used.
+    pub fn post_process(
+        &self,
+        render_target: &mut <Self as Renderer>::Target,
+        camera: &dyn Camera,
+        object_bounding_boxes: Option<Subbuffer<[[f32; 4]]>>,
+        bloom_intensity: f32,
+        bloom_threshold: f32,
+        sharpen_upscaling: bool,
+    ) {
+        render_target.end_lighting_pass();
+
+        if let Some(object_bounding_boxes) = object_bounding_boxes {
+            self.depth_pyramid_renderer.render(render_target);
+            self.occlusion_renderer.render(render_target, camera, object_bounding_boxes);
+        }
+
+        if bloom_intensity > 0.0 {
+            self.bloom_renderer.render(render_target, bloom_threshold);
+        }
+
+        let sharpen = sharpen_upscaling && self.render_scale < 1.0;
+
+        render_target.screen_pass();
+        self.composite_renderer.render(render_target, bloom_intensity, sharpen);
+    }
+
     pub fn overlay_interface(&self, render_target: &mut <Self as Renderer>::Target, interface_image: Arc<ImageView>) {
         self.overlay_renderer.render(render_target, interface_image);
     }
@@ -365,6 +530,27 @@ impl DeferredRenderer {
             .render(render_target, self.get_window_size(), position, size, color);
     }
 
+    pub fn render_texture(
+        &self,
+        render_target: &mut <Self as Renderer>::Target,
+        texture: Arc<ImageView>,
+        position: ScreenPosition,
+        size: ScreenSize,
+        color: Color,
+    ) {
+        self.sprite_renderer.render_indexed(
+            render_target,
+            texture,
+            self.get_window_size(),
+            position,
+            size,
+            color,
+            1,
+            0,
+            true,
+        );
+    }
+
     pub fn render_bar(
         &self,
         render_target: &mut <Self as Renderer>::Target,
@@ -383,6 +569,15 @@ impl DeferredRenderer {
         self.render_rectangle(render_target, position - bar_offset, bar_size, color);
     }
 
+    /// Size of a text rendered with [`render_text`](Self::render_text). Every
+    /// character of the font map has the same width.
+    pub fn get_text_dimensions(&self, text: &str, font_size: FontSize) -> ScreenSize {
+        ScreenSize {
+            width: text.len() as f32 * font_size.get_value() / 2.0,
+            height: font_size.get_value(),
+        }
+    }
+
     pub fn render_text(
         &self,
         render_target: &mut <Self as Renderer>::Target,
@@ -392,6 +587,7 @@ impl DeferredRenderer {
         font_size: FontSize,
     ) {
         let window_size = self.get_window_size();
+        let character_width = self.get_text_dimensions(" ", font_size).width;
 
         for character in text.as_bytes() {
             let index = (*character as usize).saturating_sub(31);
@@ -406,7 +602,7 @@ impl DeferredRenderer {
                 index,
                 true,
             );
-            position.left += font_size.get_value() / 2.0;
+            position.left += character_width;
         }
     }
 
@@ -479,6 +675,7 @@ impl DeferredRenderer {
             &self.tile_textures,
             Matrix4::identity(),
             0.0,
+            None,
         );
     }
 
@@ -521,11 +718,44 @@ impl GeometryRendererTrait for DeferredRenderer {
         textures: &[Arc<ImageView>],
         world_matrix: Matrix4<f32>,
         time: f32,
+        indirect_draw: Option<Subbuffer<[DrawIndirectCommand]>>,
     ) where
         Self: Renderer,
     {
-        self.geometry_renderer
-            .render(render_target, camera, vertex_buffer, textures, world_matrix, time);
+        self.geometry_renderer.render(
+            render_target,
+            camera,
+            vertex_buffer,
+            textures,
+            world_matrix,
+            time,
+            indirect_draw,
+        );
+    }
+}
+
+impl CullingRendererTrait for DeferredRenderer {
+    fn cull_objects(
+        &self,
+        render_target: &mut <Self as Renderer>::Target,
+        camera: &dyn Camera,
+        bounding_boxes: Subbuffer<[[f32; 4]]>,
+        draws: Subbuffer<[[u32; 2]]>,
+    ) -> Subbuffer<[DrawIndirectCommand]>
+    where
+        Self: Renderer,
+    {
+        let culled_draws = render_target.prepare_culled_draws(draws.len() as usize);
+
+        self.culling_renderer.render(
+            render_target.state.get_builder(),
+            camera,
+            bounding_boxes,
+            draws,
+            culled_draws.clone(),
+        );
+
+        culled_draws
     }
 }
 
@@ -541,6 +771,7 @@ impl EntityRendererTrait for DeferredRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        tint: EntityTint,
         _entity_id: EntityId,
     ) where
         Self: Renderer,
@@ -555,6 +786,7 @@ impl EntityRendererTrait for DeferredRenderer {
             cell_count,
             cell_position,
             mirror,
+            tint,
         );
     }
 }
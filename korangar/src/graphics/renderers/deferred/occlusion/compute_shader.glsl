@@ -0,0 +1,80 @@
+#version 450
+
+layout(local_size_x = 64) in;
+
+layout(set = 0, binding = 0) uniform sampler2D depth_pyramid;
+
+// The smallest and the biggest corner of every bounding box.
+layout(set = 0, binding = 1) readonly buffer BoundingBoxes {
+    vec4 corners[];
+} bounding_boxes;
+
+layout(set = 0, binding = 2) writeonly buffer Visibility {
+    uint visible[];
+} visibility;
+
+layout(push_constant) uniform Constants {
+    mat4 view_projection;
+    uint object_count;
+} constants;
+
+void main() {
+    uint index = gl_GlobalInvocationID.x;
+
+    if (index >= constants.object_count) {
+        return;
+    }
+
+    vec3 smallest = bounding_boxes.corners[index * 2].xyz;
+    vec3 biggest = bounding_boxes.corners[index * 2 + 1].xyz;
+
+    vec2 screen_smallest = vec2(1.0);
+    vec2 screen_biggest = vec2(-1.0);
+    float nearest = 1.0;
+
+    for (int corner = 0; corner < 8; corner++) {
+        vec3 position = mix(smallest, biggest, vec3(corner & 1, (corner >> 1) & 1, (corner >> 2) & 1));
+        vec4 clip_position = constants.view_projection * vec4(position, 1.0);
+
+        // Boxes that reach behind the camera can't be projected onto the screen.
+        if (clip_position.w <= 0.0) {
+            visibility.visible[index] = 1;
+            return;
+        }
+
+        vec3 device_position = clip_position.xyz / clip_position.w;
+        screen_smallest = min(screen_smallest, device_position.xy);
+        screen_biggest = max(screen_biggest, device_position.xy);
+        nearest = min(nearest, device_position.z);
+    }
+
+    // Boxes that are not entirely on the screen are left to the frustum culling,
+    // since they might be in view as soon as the camera moves.
+    if (any(lessThan(screen_smallest, vec2(-1.0))) || any(greaterThan(screen_biggest, vec2(1.0)))) {
+        visibility.visible[index] = 1;
+        return;
+    }
+
+    ivec2 base_size = textureSize(depth_pyramid, 0);
+    ivec2 first_texel = clamp(ivec2((screen_smallest * 0.5 + 0.5) * vec2(base_size)), ivec2(0), base_size - 1);
+    // Rounding down can end one texel short of the box, so one more texel is
+    // included to stay conservative.
+    ivec2 last_texel = clamp(ivec2((screen_biggest * 0.5 + 0.5) * vec2(base_size)) + 1, ivec2(0), base_size - 1);
+
+    // Pick the level at which the box covers at most two by two texels.
+    ivec2 span = last_texel - first_texel + 1;
+    int level = min(int(ceil(log2(float(max(span.x, span.y))))), textureQueryLevels(depth_pyramid) - 1);
+    ivec2 level_size = textureSize(depth_pyramid, level);
+    ivec2 first = min(first_texel >> level, level_size - 1);
+    ivec2 last = min(last_texel >> level, level_size - 1);
+
+    float farthest = 0.0;
+
+    for (int y = first.y; y <= last.y; y++) {
+        for (int x = first.x; x <= last.x; x++) {
+            farthest = max(farthest, texelFetch(depth_pyramid, ivec2(x, y), level).r);
+        }
+    }
+
+    visibility.visible[index] = nearest <= farthest ? 1 : 0;
+}
@@ -0,0 +1,81 @@
+compute_shader!("src/graphics/renderers/deferred/occlusion/compute_shader.glsl");
+
+use std::sync::Arc;
+
+use vulkano::buffer::Subbuffer;
+use vulkano::descriptor_set::WriteDescriptorSet;
+use vulkano::device::DeviceOwned;
+use vulkano::image::sampler::Sampler;
+use vulkano::pipeline::{ComputePipeline, PipelineBindPoint};
+
+use self::compute_shader::Constants;
+use super::DeferredRenderer;
+use crate::graphics::renderers::pipeline::build_compute_pipeline;
+use crate::graphics::renderers::sampler::{create_new_sampler, SamplerType};
+use crate::graphics::{allocate_descriptor_set, *};
+
+/// Number of objects tested by one work group. Has to match the local size
+/// of the compute shader.
+const WORK_GROUP_SIZE: u32 = 64;
+
+/// Tests the bounding boxes of the objects on the map against the depth
+/// pyramid, to find the objects that are completely hidden behind something
+/// else.
+pub struct OcclusionRenderer {
+    memory_allocator: Arc<MemoryAllocator>,
+    pipeline: Arc<ComputePipeline>,
+    nearest_sampler: Arc<Sampler>,
+}
+
+impl OcclusionRenderer {
+    pub fn new(memory_allocator: Arc<MemoryAllocator>) -> Self {
+        let device = memory_allocator.device().clone();
+        let compute_shader = compute_shader::entry_point(&device);
+        let nearest_sampler = create_new_sampler(&device, SamplerType::Nearest);
+        let pipeline = build_compute_pipeline(device, &compute_shader);
+
+        Self {
+            memory_allocator,
+            pipeline,
+            nearest_sampler,
+        }
+    }
+
+    /// Has to be called outside of a render pass, after the depth pyramid was
+    /// rendered. `bounding_boxes` holds the smallest and the biggest corner of
+    /// every box.
+    #[cfg_attr(feature = "debug", korangar_debug::profile("test occlusion"))]
+    pub fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        camera: &dyn Camera,
+        bounding_boxes: Subbuffer<[[f32; 4]]>,
+    ) {
+        let object_count = (bounding_boxes.len() / 2) as u32;
+        let visibility_buffer = render_target.prepare_occlusion_query(bounding_boxes.clone());
+
+        let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 0, [
+            WriteDescriptorSet::image_view_sampler(0, render_target.depth_pyramid.clone(), self.nearest_sampler.clone()),
+            WriteDescriptorSet::buffer(1, bounding_boxes),
+            WriteDescriptorSet::buffer(2, visibility_buffer),
+        ]);
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let constants = Constants {
+            view_projection: (projection_matrix * view_matrix).into(),
+            object_count,
+        };
+
+        render_target
+            .state
+            .get_builder()
+            .bind_pipeline_compute(self.pipeline.clone())
+            .unwrap()
+            .bind_descriptor_sets(PipelineBindPoint::Compute, layout.clone(), set_id, set)
+            .unwrap()
+            .push_constants(layout, 0, constants)
+            .unwrap()
+            .dispatch([object_count.div_ceil(WORK_GROUP_SIZE), 1, 1])
+            .unwrap();
+    }
+}
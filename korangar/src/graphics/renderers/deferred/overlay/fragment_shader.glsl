@@ -4,14 +4,14 @@ layout(location = 0) in vec2 position;
 
 layout(location = 0) out vec4 fragment_color;
 
-layout(input_attachment_index = 0, set = 0, binding = 0) uniform subpassInputMS interface_in;
+layout(set = 0, binding = 0) uniform sampler2DMS interface_in;
 
 void main() {
 
     vec4 blended = vec4(0.0);
 
     for (int i = 0; i < 4; i++)
-        blended += subpassLoad(interface_in, i);
+        blended += texelFetch(interface_in, ivec2(gl_FragCoord.xy), i);
 
     fragment_color = blended / 4.0;
 }
@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use vulkano::descriptor_set::WriteDescriptorSet;
 use vulkano::device::{Device, DeviceOwned};
+use vulkano::image::sampler::Sampler;
 use vulkano::pipeline::graphics::viewport::Viewport;
 use vulkano::pipeline::{GraphicsPipeline, PipelineBindPoint};
 use vulkano::render_pass::Subpass;
@@ -12,6 +13,7 @@ use vulkano::shader::EntryPoint;
 
 use super::DeferredSubrenderer;
 use crate::graphics::renderers::pipeline::PipelineBuilder;
+use crate::graphics::renderers::sampler::{create_new_sampler, SamplerType};
 use crate::graphics::{allocate_descriptor_set, *};
 
 pub struct OverlayRenderer {
@@ -19,6 +21,7 @@ pub struct OverlayRenderer {
     vertex_shader: EntryPoint,
     fragment_shader: EntryPoint,
     pipeline: Arc<GraphicsPipeline>,
+    nearest_sampler: Arc<Sampler>,
 }
 
 impl OverlayRenderer {
@@ -26,6 +29,7 @@ impl OverlayRenderer {
         let device = memory_allocator.device().clone();
         let vertex_shader = vertex_shader::entry_point(&device);
         let fragment_shader = fragment_shader::entry_point(&device);
+        let nearest_sampler = create_new_sampler(&device, SamplerType::Nearest);
         let pipeline = Self::create_pipeline(device, subpass, viewport, &vertex_shader, &fragment_shader);
 
         Self {
@@ -33,6 +37,7 @@ impl OverlayRenderer {
             vertex_shader,
             fragment_shader,
             pipeline,
+            nearest_sampler,
         }
     }
 
@@ -48,7 +53,7 @@ impl OverlayRenderer {
         vertex_shader: &EntryPoint,
         fragment_shader: &EntryPoint,
     ) -> Arc<GraphicsPipeline> {
-        PipelineBuilder::<_, { DeferredRenderer::lighting_subpass() }>::new([vertex_shader, fragment_shader])
+        PipelineBuilder::<_, { DeferredRenderer::screen_subpass() }>::new([vertex_shader, fragment_shader])
             .fixed_viewport(viewport)
             .blend_alpha()
             .build(device, subpass)
@@ -69,10 +74,9 @@ impl OverlayRenderer {
             self.bind_pipeline(render_target);
         }
 
-        let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 0, [WriteDescriptorSet::image_view(
-            0,
-            interface_buffer,
-        )]);
+        let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 0, [
+            WriteDescriptorSet::image_view_sampler(0, interface_buffer, self.nearest_sampler.clone()),
+        ]);
 
         render_target
             .state
@@ -47,7 +47,7 @@ impl RectangleRenderer {
         vertex_shader: &EntryPoint,
         fragment_shader: &EntryPoint,
     ) -> Arc<GraphicsPipeline> {
-        PipelineBuilder::<_, { DeferredRenderer::lighting_subpass() }>::new([vertex_shader, fragment_shader])
+        PipelineBuilder::<_, { DeferredRenderer::screen_subpass() }>::new([vertex_shader, fragment_shader])
             .fixed_viewport(viewport)
             .blend_alpha()
             .build(device, subpass)
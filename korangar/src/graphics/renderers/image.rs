@@ -2,8 +2,8 @@ use std::sync::Arc;
 
 use derive_new::new;
 use vulkano::format::Format;
-use vulkano::image::view::ImageView;
-use vulkano::image::{Image, ImageCreateInfo, ImageUsage, SampleCount};
+use vulkano::image::view::{ImageView, ImageViewCreateInfo};
+use vulkano::image::{Image, ImageAspects, ImageCreateInfo, ImageSubresourceRange, ImageUsage, SampleCount};
 use vulkano::memory::allocator::AllocationCreateInfo;
 
 use crate::graphics::MemoryAllocator;
@@ -11,6 +11,7 @@ use crate::graphics::MemoryAllocator;
 pub(super) enum AttachmentImageType {
     InputColor,
     CopyColor,
+    SampledColor,
     InputDepth,
     Depth,
 }
@@ -27,6 +28,7 @@ impl<'a> AttachmentImageFactory<'a> {
         let usage = match attachment_image_type {
             AttachmentImageType::InputColor => ImageUsage::SAMPLED | ImageUsage::COLOR_ATTACHMENT | ImageUsage::INPUT_ATTACHMENT,
             AttachmentImageType::CopyColor => ImageUsage::SAMPLED | ImageUsage::COLOR_ATTACHMENT | ImageUsage::TRANSFER_SRC,
+            AttachmentImageType::SampledColor => ImageUsage::SAMPLED | ImageUsage::COLOR_ATTACHMENT,
             AttachmentImageType::InputDepth => ImageUsage::SAMPLED | ImageUsage::DEPTH_STENCIL_ATTACHMENT | ImageUsage::INPUT_ATTACHMENT,
             AttachmentImageType::Depth => ImageUsage::DEPTH_STENCIL_ATTACHMENT,
         };
@@ -46,4 +48,39 @@ impl<'a> AttachmentImageFactory<'a> {
 
         ImageView::new_default(image).unwrap()
     }
+
+    /// Color image with `mip_levels` levels that are rendered to one after
+    /// another. Returns a view of the entire image for sampling, and a view of
+    /// every level to render to.
+    pub(super) fn new_mipmapped_image(&self, format: Format, mip_levels: u32) -> (Arc<ImageView>, Vec<Arc<ImageView>>) {
+        let image = Image::new(
+            self.memory_allocator,
+            ImageCreateInfo {
+                format,
+                extent: [self.dimensions[0], self.dimensions[1], 1],
+                mip_levels,
+                samples: self.sample_count,
+                usage: ImageUsage::SAMPLED | ImageUsage::COLOR_ATTACHMENT,
+                ..Default::default()
+            },
+            AllocationCreateInfo::default(),
+        )
+        .unwrap();
+
+        let level_views = (0..mip_levels)
+            .map(|level| {
+                ImageView::new(image.clone(), ImageViewCreateInfo {
+                    subresource_range: ImageSubresourceRange {
+                        aspects: ImageAspects::COLOR,
+                        mip_levels: level..level + 1,
+                        array_layers: 0..1,
+                    },
+                    ..ImageViewCreateInfo::from_image(&image)
+                })
+                .unwrap()
+            })
+            .collect();
+
+        (ImageView::new_default(image).unwrap(), level_views)
+    }
 }
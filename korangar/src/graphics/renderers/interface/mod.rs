@@ -215,6 +215,23 @@ impl korangar_interface::application::InterfaceRenderer<InterfaceSettings> for I
 
         self.render_sprite(render_target, texture, position, size, clip, color, true);
     }
+
+    fn clear_area(
+        &self,
+        render_target: &mut Self::Target,
+        position: <InterfaceSettings as Application>::Position,
+        size: <InterfaceSettings as Application>::Size,
+    ) {
+        // The clear rectangle has to be inside the render target.
+        let left = (position.left.max(0.0) as u32).min(self.dimensions[0]);
+        let top = (position.top.max(0.0) as u32).min(self.dimensions[1]);
+        let right = ((position.left + size.width).max(0.0) as u32).min(self.dimensions[0]);
+        let bottom = ((position.top + size.height).max(0.0) as u32).min(self.dimensions[1]);
+
+        if right > left && bottom > top {
+            render_target.clear_area([left, top], [right - left, bottom - top]);
+        }
+    }
 }
 
 pub struct InterfaceFormat {}
@@ -93,8 +93,9 @@ impl TextRenderer {
         }
 
         let mut font_loader = self.font_loader.borrow_mut();
-        let texture = font_loader.get_font_atlas();
         let (character_layout, height) = font_loader.get(text, color, font_size, screen_clip.right - screen_position.left);
+        // Get the atlas after the glyphs are cached, since caching might grow it.
+        let texture = font_loader.get_font_atlas();
         let half_screen = window_size / 2.0;
 
         let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 0, [
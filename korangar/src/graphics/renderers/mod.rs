@@ -28,6 +28,22 @@ macro_rules! fragment_shader {
     };
 }
 
+macro_rules! compute_shader {
+    ($path:literal) => {
+        mod compute_shader {
+            vulkano_shaders::shader! {
+                ty: "compute",
+                path: $path
+            }
+
+            pub fn entry_point(device: &std::sync::Arc<vulkano::device::Device>) -> vulkano::shader::EntryPoint {
+                load(device.clone()).unwrap().entry_point("main").unwrap()
+            }
+        }
+    };
+}
+
+mod culling;
 mod deferred;
 mod image;
 mod interface;
@@ -51,8 +67,8 @@ use option_ext::OptionExt;
 use ragnarok_packets::EntityId;
 use vulkano::buffer::{Buffer, BufferUsage, Subbuffer};
 use vulkano::command_buffer::{
-    AutoCommandBufferBuilder, ClearAttachment, ClearRect, CommandBufferUsage, CopyImageToBufferInfo, PrimaryAutoCommandBuffer,
-    PrimaryCommandBufferAbstract, RenderPassBeginInfo, SubpassBeginInfo, SubpassEndInfo,
+    AutoCommandBufferBuilder, ClearAttachment, ClearRect, CommandBufferUsage, CopyImageToBufferInfo, DrawIndirectCommand,
+    PrimaryAutoCommandBuffer, PrimaryCommandBufferAbstract, RenderPassBeginInfo, SubpassBeginInfo, SubpassEndInfo,
 };
 use vulkano::device::Queue;
 use vulkano::format::{ClearColorValue, ClearValue, Format};
@@ -139,10 +155,44 @@ pub trait GeometryRenderer {
         textures: &[Arc<ImageView>],
         world_matrix: Matrix4<f32>,
         time: f32,
+        indirect_draw: Option<Subbuffer<[DrawIndirectCommand]>>,
     ) where
         Self: Renderer;
 }
 
+pub trait CullingRenderer {
+    /// Test the objects against the view frustum of `camera` and return one
+    /// indirect draw command for every entry in `draws`. Has to be called
+    /// after the render target was started and before its render pass begins.
+    fn cull_objects(
+        &self,
+        render_target: &mut <Self as Renderer>::Target,
+        camera: &dyn Camera,
+        bounding_boxes: Subbuffer<[[f32; 4]]>,
+        draws: Subbuffer<[[u32; 2]]>,
+    ) -> Subbuffer<[DrawIndirectCommand]>
+    where
+        Self: Renderer;
+}
+
+/// Color that the sprite of an entity is multiplied with, and how much of its
+/// own color it keeps. Entities with an alpha below 1 are rendered partially
+/// transparent.
+#[derive(Clone, Copy, Debug)]
+pub struct EntityTint {
+    pub color: Color,
+    pub saturation: f32,
+}
+
+impl Default for EntityTint {
+    fn default() -> Self {
+        Self {
+            color: Color::monochrome_u8(255),
+            saturation: 1.0,
+        }
+    }
+}
+
 pub trait EntityRenderer {
     fn render_entity(
         &self,
@@ -155,6 +205,7 @@ pub trait EntityRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        tint: EntityTint,
         entity_id: EntityId,
     ) where
         Self: Renderer;
@@ -261,10 +312,58 @@ impl RenderTargetState {
     }
 }
 
+/// Number of times the light is halved in resolution for bloom. Every level
+/// spreads the glow further.
+const BLOOM_LEVELS: usize = 5;
+
+/// Objects that the occlusion test last ran for on a render target, and which
+/// of them were visible.
+struct OcclusionQuery {
+    bounding_boxes: Subbuffer<[[f32; 4]]>,
+    visibility: Subbuffer<[u32]>,
+}
+
+/// Buffer that the culling pass writes the draw commands to. The buffer of the
+/// last frame is reused if it has the right size.
+fn prepare_culled_draws(
+    memory_allocator: &MemoryAllocator,
+    culled_draws: &mut Option<Subbuffer<[DrawIndirectCommand]>>,
+    draw_count: usize,
+) -> Subbuffer<[DrawIndirectCommand]> {
+    let buffer = match culled_draws.take() {
+        Some(buffer) if buffer.len() == draw_count as u64 => buffer,
+        _ => Buffer::new_slice(
+            memory_allocator,
+            vulkano::buffer::BufferCreateInfo {
+                usage: BufferUsage::STORAGE_BUFFER | BufferUsage::INDIRECT_BUFFER,
+                ..Default::default()
+            },
+            AllocationCreateInfo {
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE,
+                ..Default::default()
+            },
+            draw_count as u64,
+        )
+        .unwrap(),
+    };
+
+    *culled_draws = Some(buffer.clone());
+    buffer
+}
+
 pub struct DeferredRenderTarget {
     memory_allocator: Arc<MemoryAllocator>,
     queue: Arc<Queue>,
     framebuffer: Arc<Framebuffer>,
+    screen_framebuffer: Arc<Framebuffer>,
+    bloom_framebuffers: Vec<Arc<Framebuffer>>,
+    scene_image: Arc<ImageView>,
+    bloom_images: Vec<Arc<ImageView>>,
+    depth_pyramid_framebuffers: Vec<Arc<Framebuffer>>,
+    depth_pyramid: Arc<ImageView>,
+    depth_pyramid_levels: Vec<Arc<ImageView>>,
+    occlusion_query: Option<OcclusionQuery>,
+    culled_draws: Option<Subbuffer<[DrawIndirectCommand]>>,
     diffuse_image: Arc<ImageView>,
     normal_image: Arc<ImageView>,
     water_image: Arc<ImageView>,
@@ -278,10 +377,15 @@ impl DeferredRenderTarget {
         memory_allocator: Arc<MemoryAllocator>,
         queue: Arc<Queue>,
         render_pass: Arc<RenderPass>,
+        bloom_render_pass: Arc<RenderPass>,
+        depth_pyramid_render_pass: Arc<RenderPass>,
+        screen_render_pass: Arc<RenderPass>,
         swapchain_image: Arc<Image>,
-        dimensions: [u32; 2],
+        scene_dimensions: [u32; 2],
     ) -> Self {
-        let image_factory = AttachmentImageFactory::new(&memory_allocator, dimensions, SampleCount::Sample4);
+        let image_factory = AttachmentImageFactory::new(&memory_allocator, scene_dimensions, SampleCount::Sample4);
+        let scene_image = AttachmentImageFactory::new(&memory_allocator, scene_dimensions, SampleCount::Sample1)
+            .new_image(Format::R16G16B16A16_SFLOAT, AttachmentImageType::SampledColor);
 
         let diffuse_image = image_factory.new_image(Format::R32G32B32A32_SFLOAT, AttachmentImageType::InputColor);
         let normal_image = image_factory.new_image(Format::R16G16B16A16_SFLOAT, AttachmentImageType::InputColor);
@@ -290,7 +394,7 @@ impl DeferredRenderTarget {
 
         let framebuffer_create_info = FramebufferCreateInfo {
             attachments: vec![
-                ImageView::new_default(swapchain_image).unwrap(),
+                scene_image.clone(),
                 diffuse_image.clone(),
                 normal_image.clone(),
                 water_image.clone(),
@@ -300,6 +404,54 @@ impl DeferredRenderTarget {
         };
 
         let framebuffer = Framebuffer::new(render_pass, framebuffer_create_info).unwrap();
+
+        let screen_framebuffer = Framebuffer::new(screen_render_pass, FramebufferCreateInfo {
+            attachments: vec![ImageView::new_default(swapchain_image).unwrap()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        let bloom_images: Vec<Arc<ImageView>> = (1..=BLOOM_LEVELS)
+            .map(|level| {
+                let level_dimensions = scene_dimensions.map(|component| (component >> level).max(1));
+
+                AttachmentImageFactory::new(&memory_allocator, level_dimensions, SampleCount::Sample1)
+                    .new_image(Format::R16G16B16A16_SFLOAT, AttachmentImageType::SampledColor)
+            })
+            .collect();
+
+        let bloom_framebuffers = bloom_images
+            .iter()
+            .map(|image| {
+                Framebuffer::new(bloom_render_pass.clone(), FramebufferCreateInfo {
+                    attachments: vec![image.clone()],
+                    ..Default::default()
+                })
+                .unwrap()
+            })
+            .collect();
+
+        // The first level of the depth pyramid has half the resolution of the scene,
+        // and every following level halves it again down to a single texel.
+        let depth_pyramid_dimensions = scene_dimensions.map(|component| (component >> 1).max(1));
+        let depth_pyramid_mip_levels = u32::BITS - depth_pyramid_dimensions[0].max(depth_pyramid_dimensions[1]).leading_zeros();
+        let (depth_pyramid, depth_pyramid_levels) =
+            AttachmentImageFactory::new(&memory_allocator, depth_pyramid_dimensions, SampleCount::Sample1)
+                .new_mipmapped_image(Format::R32_SFLOAT, depth_pyramid_mip_levels);
+
+        let depth_pyramid_framebuffers = depth_pyramid_levels
+            .iter()
+            .map(|level| {
+                Framebuffer::new(depth_pyramid_render_pass.clone(), FramebufferCreateInfo {
+                    attachments: vec![level.clone()],
+                    ..Default::default()
+                })
+                .unwrap()
+            })
+            .collect();
+
+        let occlusion_query = None;
+        let culled_draws = None;
         let state = RenderTargetState::Ready;
         let bound_subrenderer = None;
 
@@ -307,6 +459,15 @@ impl DeferredRenderTarget {
             memory_allocator,
             queue,
             framebuffer,
+            screen_framebuffer,
+            bloom_framebuffers,
+            scene_image,
+            bloom_images,
+            depth_pyramid_framebuffers,
+            depth_pyramid,
+            depth_pyramid_levels,
+            occlusion_query,
+            culled_draws,
             diffuse_image,
             normal_image,
             water_image,
@@ -316,15 +477,23 @@ impl DeferredRenderTarget {
         }
     }
 
+    /// Start recording the commands of a frame. Compute passes that the
+    /// geometry pass depends on are recorded before
+    /// [`geometry_pass`](Self::geometry_pass).
     #[cfg_attr(feature = "debug", korangar_debug::profile("start frame"))]
     pub fn start(&mut self) {
-        let mut builder = AutoCommandBufferBuilder::primary(
+        let builder = AutoCommandBufferBuilder::primary(
             &*self.memory_allocator,
             self.queue.queue_family_index(),
             CommandBufferUsage::OneTimeSubmit,
         )
         .unwrap();
 
+        self.state = RenderTargetState::Rendering(builder);
+        self.bound_subrenderer = None;
+    }
+
+    pub fn geometry_pass(&mut self) {
         let render_pass_begin_info = RenderPassBeginInfo {
             clear_values: vec![
                 Some(ClearValue::Float([0.0, 0.0, 0.0, 1.0])),
@@ -336,12 +505,10 @@ impl DeferredRenderTarget {
             ..RenderPassBeginInfo::framebuffer(self.framebuffer.clone())
         };
 
-        builder
+        self.state
+            .get_builder()
             .begin_render_pass(render_pass_begin_info, SubpassBeginInfo::default())
             .unwrap();
-
-        self.state = RenderTargetState::Rendering(builder);
-        self.bound_subrenderer = None;
     }
 
     pub fn bind_subrenderer(&mut self, subrenderer: DeferredSubrenderer) -> bool {
@@ -357,6 +524,73 @@ impl DeferredRenderTarget {
             .unwrap();
     }
 
+    pub fn end_lighting_pass(&mut self) {
+        self.state.get_builder().end_render_pass(SubpassEndInfo::default()).unwrap();
+    }
+
+    /// Buffer that the occlusion test for `bounding_boxes` writes to. The
+    /// buffer of the previous test is reused if it has the right size.
+    fn prepare_occlusion_query(&mut self, bounding_boxes: Subbuffer<[[f32; 4]]>) -> Subbuffer<[u32]> {
+        let object_count = bounding_boxes.len() / 2;
+
+        let visibility = match self.occlusion_query.take() {
+            Some(query) if query.visibility.len() == object_count => query.visibility,
+            _ => Buffer::new_slice(
+                &*self.memory_allocator,
+                vulkano::buffer::BufferCreateInfo {
+                    usage: BufferUsage::STORAGE_BUFFER,
+                    ..Default::default()
+                },
+                AllocationCreateInfo {
+                    memory_type_filter: MemoryTypeFilter::PREFER_HOST | MemoryTypeFilter::HOST_RANDOM_ACCESS,
+                    ..Default::default()
+                },
+                object_count,
+            )
+            .unwrap(),
+        };
+
+        self.occlusion_query = Some(OcclusionQuery {
+            bounding_boxes,
+            visibility: visibility.clone(),
+        });
+
+        visibility
+    }
+
+    fn prepare_culled_draws(&mut self, draw_count: usize) -> Subbuffer<[DrawIndirectCommand]> {
+        prepare_culled_draws(&self.memory_allocator, &mut self.culled_draws, draw_count)
+    }
+
+    /// Which objects passed the occlusion test the last time this target was
+    /// rendered to. There is only a result if the test ran for the same
+    /// `bounding_boxes`, so objects of a different map are never hidden. The
+    /// fence of the target has to be waited for first.
+    pub fn visible_objects(&self, bounding_boxes: &Subbuffer<[[f32; 4]]>) -> Option<Vec<bool>> {
+        let query = self.occlusion_query.as_ref()?;
+
+        if !Arc::ptr_eq(query.bounding_boxes.buffer(), bounding_boxes.buffer()) {
+            return None;
+        }
+
+        let visibility = query.visibility.read().ok()?;
+        Some(visibility.iter().map(|visible| *visible != 0).collect())
+    }
+
+    pub fn screen_pass(&mut self) {
+        let render_pass_begin_info = RenderPassBeginInfo {
+            clear_values: vec![None],
+            ..RenderPassBeginInfo::framebuffer(self.screen_framebuffer.clone())
+        };
+
+        self.state
+            .get_builder()
+            .begin_render_pass(render_pass_begin_info, SubpassBeginInfo::default())
+            .unwrap();
+
+        self.bound_subrenderer = None;
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile("finish swapchain image"))]
     pub fn finish(&mut self, swapchain: Arc<Swapchain>, semaphore: Box<dyn GpuFuture>, image_number: usize) {
         let mut builder = self.state.take_builder();
@@ -526,6 +760,7 @@ pub struct SingleRenderTarget<F: IntoFormat, S: PartialEq, C> {
     pub image: Arc<ImageView>,
     pub state: RenderTargetState,
     clear_value: C,
+    culled_draws: Option<Subbuffer<[DrawIndirectCommand]>>,
     bound_subrenderer: Option<S>,
     _phantom_data: PhantomData<F>,
 }
@@ -562,6 +797,7 @@ impl<F: IntoFormat, S: PartialEq, C> SingleRenderTarget<F, S, C> {
         let framebuffer = Framebuffer::new(render_pass, framebuffer_create_info).unwrap();
 
         let state = RenderTargetState::Ready;
+        let culled_draws = None;
         let bound_subrenderer = None;
 
         Self {
@@ -571,6 +807,7 @@ impl<F: IntoFormat, S: PartialEq, C> SingleRenderTarget<F, S, C> {
             image,
             state,
             clear_value,
+            culled_draws,
             bound_subrenderer,
             _phantom_data: Default::default(),
         }
@@ -582,29 +819,39 @@ impl<F: IntoFormat, S: PartialEq, C> SingleRenderTarget<F, S, C> {
         self.bound_subrenderer = Some(subrenderer);
         !already_bound
     }
+
+    fn prepare_culled_draws(&mut self, draw_count: usize) -> Subbuffer<[DrawIndirectCommand]> {
+        prepare_culled_draws(&self.memory_allocator, &mut self.culled_draws, draw_count)
+    }
 }
 
 impl<F: IntoFormat, S: PartialEq> SingleRenderTarget<F, S, ClearValue> {
+    /// Start recording the commands of a frame. Compute passes that the render
+    /// pass depends on are recorded before
+    /// [`begin_render_pass`](Self::begin_render_pass).
     #[cfg_attr(feature = "debug", korangar_debug::profile("start frame"))]
     pub fn start(&mut self) {
-        let mut builder = AutoCommandBufferBuilder::primary(
+        let builder = AutoCommandBufferBuilder::primary(
             &*self.memory_allocator,
             self.queue.queue_family_index(),
             CommandBufferUsage::OneTimeSubmit,
         )
         .unwrap();
 
+        self.state = RenderTargetState::Rendering(builder);
+        self.bound_subrenderer = None;
+    }
+
+    pub fn begin_render_pass(&mut self) {
         let render_pass_begin_info = RenderPassBeginInfo {
             clear_values: vec![Some(self.clear_value)],
             ..RenderPassBeginInfo::framebuffer(self.framebuffer.clone())
         };
 
-        builder
+        self.state
+            .get_builder()
             .begin_render_pass(render_pass_begin_info, SubpassBeginInfo::default())
             .unwrap();
-
-        self.state = RenderTargetState::Rendering(builder);
-        self.bound_subrenderer = None;
     }
 
     #[cfg_attr(feature = "debug", korangar_debug::profile("finalize buffer"))]
@@ -670,6 +917,29 @@ impl<F: IntoFormat, S: PartialEq> SingleRenderTarget<F, S, ClearColorValue> {
         self.bound_subrenderer = None;
     }
 
+    /// Clear part of the render target while rendering.
+    #[cfg_attr(feature = "debug", korangar_debug::profile("clear area"))]
+    pub fn clear_area(&mut self, offset: [u32; 2], extent: [u32; 2]) {
+        self.state
+            .get_builder()
+            .clear_attachments(
+                [ClearAttachment::Color {
+                    color_attachment: 0,
+                    clear_value: self.clear_value,
+                }]
+                .into_iter()
+                .collect(),
+                [ClearRect {
+                    offset,
+                    extent,
+                    array_layers: 0..1,
+                }]
+                .into_iter()
+                .collect(),
+            )
+            .unwrap();
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile("finish buffer"))]
     pub fn finish(&mut self, font_future: Option<FenceSignalFuture<Box<dyn GpuFuture>>>) {
         if let Some(mut future) = font_future {
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use vulkano::device::Device;
 use vulkano::image::SampleCount;
+use vulkano::pipeline::compute::ComputePipelineCreateInfo;
 use vulkano::pipeline::graphics::color_blend::{AttachmentBlend, ColorBlendState};
 use vulkano::pipeline::graphics::depth_stencil::DepthStencilState;
 use vulkano::pipeline::graphics::input_assembly::{InputAssemblyState, PrimitiveTopology};
@@ -11,12 +12,26 @@ use vulkano::pipeline::graphics::vertex_input::{Vertex, VertexDefinition, Vertex
 use vulkano::pipeline::graphics::viewport::{Viewport, ViewportState};
 use vulkano::pipeline::graphics::GraphicsPipelineCreateInfo;
 use vulkano::pipeline::layout::PipelineDescriptorSetLayoutCreateInfo;
-use vulkano::pipeline::{GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo};
+use vulkano::pipeline::{ComputePipeline, GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo};
 use vulkano::render_pass::Subpass;
 use vulkano::shader::{EntryPoint, SpecializationConstant};
 
 use super::SubpassAttachments;
 
+pub(super) fn build_compute_pipeline(device: Arc<Device>, compute_shader: &EntryPoint) -> Arc<ComputePipeline> {
+    let stage = PipelineShaderStageCreateInfo::new(compute_shader.clone());
+
+    let layout = PipelineLayout::new(
+        device.clone(),
+        PipelineDescriptorSetLayoutCreateInfo::from_stages([&stage])
+            .into_pipeline_layout_create_info(device.clone())
+            .unwrap(),
+    )
+    .unwrap();
+
+    ComputePipeline::new(device, None, ComputePipelineCreateInfo::stage_layout(stage, layout)).unwrap()
+}
+
 pub(super) struct PipelineBuilder<'a, E, const S: SubpassAttachments>
 where
     E: IntoIterator<Item = &'a EntryPoint>,
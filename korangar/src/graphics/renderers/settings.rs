@@ -8,6 +8,8 @@ pub struct RenderSettings {
     pub show_frames_per_second: bool,
     #[new(value = "true")]
     pub frustum_culling: bool,
+    #[new(value = "true")]
+    pub occlusion_culling: bool,
     #[new(default)]
     pub show_bounding_boxes: bool,
     #[new(value = "true")]
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use cgmath::Matrix4;
 #[cfg(feature = "debug")]
 use korangar_debug::profiling::Profiler;
+use vulkano::command_buffer::DrawIndirectCommand;
 use vulkano::descriptor_set::WriteDescriptorSet;
 use vulkano::device::{Device, DeviceOwned};
 use vulkano::image::sampler::Sampler;
@@ -132,6 +133,7 @@ impl GeometryRenderer {
         textures: &[Arc<ImageView>],
         world_matrix: Matrix4<f32>,
         time: f32,
+        indirect_draw: Option<Subbuffer<[DrawIndirectCommand]>>,
     ) {
         if render_target.bind_subrenderer(ShadowSubrenderer::Geometry) {
             self.bind_pipeline(render_target, camera, time)
@@ -209,7 +211,10 @@ impl GeometryRenderer {
         #[cfg(feature = "debug")]
         let inner_measurement = Profiler::start_measurement("draw call");
 
-        builder.draw(vertex_count as u32, 1, 0, 0).unwrap();
+        match indirect_draw {
+            Some(indirect_draw) => builder.draw_indirect(indirect_draw).unwrap(),
+            None => builder.draw(vertex_count as u32, 1, 0, 0).unwrap(),
+        };
 
         #[cfg(feature = "debug")]
         inner_measurement.stop();
@@ -7,6 +7,7 @@ use std::sync::Arc;
 use cgmath::{Matrix4, Vector2, Vector3};
 use ragnarok_packets::EntityId;
 use serde::{Deserialize, Serialize};
+use vulkano::command_buffer::DrawIndirectCommand;
 use vulkano::device::{DeviceOwned, Queue};
 use vulkano::format::{ClearValue, Format};
 use vulkano::image::{ImageUsage, SampleCount};
@@ -15,9 +16,11 @@ use vulkano::render_pass::RenderPass;
 use self::entity::EntityRenderer;
 use self::geometry::GeometryRenderer;
 use self::indicator::IndicatorRenderer;
+use super::culling::CullingRenderer;
 use super::SubpassAttachments;
 use crate::graphics::{
-    EntityRenderer as EntityRendererTrait, GeometryRenderer as GeometryRendererTrait, IndicatorRenderer as IndicatorRendererTrait, *,
+    CullingRenderer as CullingRendererTrait, EntityRenderer as EntityRendererTrait, GeometryRenderer as GeometryRendererTrait,
+    IndicatorRenderer as IndicatorRendererTrait, *,
 };
 use crate::loaders::{GameFileLoader, TextureLoader};
 
@@ -54,6 +57,7 @@ pub struct ShadowRenderer {
     geometry_renderer: GeometryRenderer,
     entity_renderer: EntityRenderer,
     indicator_renderer: IndicatorRenderer,
+    culling_renderer: CullingRenderer,
     walk_indicator: Arc<ImageView>,
 }
 
@@ -93,6 +97,7 @@ impl ShadowRenderer {
         let geometry_renderer = GeometryRenderer::new(memory_allocator.clone(), subpass.clone());
         let entity_renderer = EntityRenderer::new(memory_allocator.clone(), subpass.clone());
         let indicator_renderer = IndicatorRenderer::new(memory_allocator.clone(), subpass);
+        let culling_renderer = CullingRenderer::new(memory_allocator.clone());
 
         let walk_indicator = texture_loader.get("grid.tga", game_file_loader).unwrap();
 
@@ -103,6 +108,7 @@ impl ShadowRenderer {
             geometry_renderer,
             entity_renderer,
             indicator_renderer,
+            culling_renderer,
             walk_indicator,
         }
     }
@@ -141,11 +147,44 @@ impl GeometryRendererTrait for ShadowRenderer {
         textures: &[Arc<ImageView>],
         world_matrix: Matrix4<f32>,
         time: f32,
+        indirect_draw: Option<Subbuffer<[DrawIndirectCommand]>>,
     ) where
         Self: Renderer,
     {
-        self.geometry_renderer
-            .render(render_target, camera, vertex_buffer, textures, world_matrix, time);
+        self.geometry_renderer.render(
+            render_target,
+            camera,
+            vertex_buffer,
+            textures,
+            world_matrix,
+            time,
+            indirect_draw,
+        );
+    }
+}
+
+impl CullingRendererTrait for ShadowRenderer {
+    fn cull_objects(
+        &self,
+        render_target: &mut <Self as Renderer>::Target,
+        camera: &dyn Camera,
+        bounding_boxes: Subbuffer<[[f32; 4]]>,
+        draws: Subbuffer<[[u32; 2]]>,
+    ) -> Subbuffer<[DrawIndirectCommand]>
+    where
+        Self: Renderer,
+    {
+        let culled_draws = render_target.prepare_culled_draws(draws.len() as usize);
+
+        self.culling_renderer.render(
+            render_target.state.get_builder(),
+            camera,
+            bounding_boxes,
+            draws,
+            culled_draws.clone(),
+        );
+
+        culled_draws
     }
 }
 
@@ -161,6 +200,7 @@ impl EntityRendererTrait for ShadowRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
+        _tint: EntityTint,
         _entity_id: EntityId,
     ) where
         Self: Renderer,
@@ -1,14 +1,53 @@
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{print_debug, Colorize};
-use ron::ser::PrettyConfig;
+use korangar_debug::logging::print_debug;
 use serde::{Deserialize, Serialize};
 
-use super::ShadowDetail;
+use super::{ShadowDetail, MAXIMUM_RENDER_SCALE, MINIMUM_RENDER_SCALE};
+use crate::system::SettingsFile;
+use crate::world::HealthBarVisibility;
 
 #[derive(Serialize, Deserialize)]
 pub struct GraphicsSettings {
     pub frame_limit: bool,
     pub shadow_detail: ShadowDetail,
+    /// Show the frame rate and a graph of the recent frame times.
+    #[serde(default)]
+    pub show_frame_overlay: bool,
+    /// Write the timings of every frame to a file in the metrics directory.
+    #[serde(default)]
+    pub export_metrics: bool,
+    /// When the health bars of monsters are shown.
+    #[serde(default)]
+    pub health_bar_visibility: HealthBarVisibility,
+    /// Round off the corners of walking paths instead of moving from tile to
+    /// tile in straight lines.
+    #[serde(default = "GraphicsSettings::default_smooth_movement")]
+    pub smooth_movement: bool,
+    /// Time in milliseconds that the walks of other entities are delayed by,
+    /// to smooth out walk packets that arrive late.
+    #[serde(default = "GraphicsSettings::default_interpolation_delay")]
+    pub interpolation_delay: u32,
+    /// Time in milliseconds that dead monsters stay on the map, or 0 to
+    /// remove them right away.
+    #[serde(default)]
+    pub corpse_duration: u32,
+    /// How strongly bright lights and effects glow, or 0 to turn it off.
+    #[serde(default = "GraphicsSettings::default_bloom_intensity")]
+    pub bloom_intensity: f32,
+    /// Brightness above which light starts to glow.
+    #[serde(default = "GraphicsSettings::default_bloom_threshold")]
+    pub bloom_threshold: f32,
+    /// Size of the rendered scene relative to the window. The interface is
+    /// always rendered at the size of the window.
+    #[serde(default = "GraphicsSettings::default_render_scale")]
+    pub render_scale: f32,
+    /// Lower the render scale while the frame rate can't keep up with the
+    /// display, using the render scale as the upper limit.
+    #[serde(default)]
+    pub dynamic_render_scale: bool,
+    /// Sharpen the scene when it is scaled up to the size of the window.
+    #[serde(default = "GraphicsSettings::default_sharpen_upscaling")]
+    pub sharpen_upscaling: bool,
 }
 
 impl Default for GraphicsSettings {
@@ -16,37 +55,71 @@ impl Default for GraphicsSettings {
         Self {
             frame_limit: true,
             shadow_detail: ShadowDetail::Medium,
+            show_frame_overlay: false,
+            export_metrics: false,
+            health_bar_visibility: HealthBarVisibility::AfterDamage,
+            smooth_movement: Self::default_smooth_movement(),
+            interpolation_delay: Self::default_interpolation_delay(),
+            corpse_duration: 0,
+            bloom_intensity: Self::default_bloom_intensity(),
+            bloom_threshold: Self::default_bloom_threshold(),
+            render_scale: Self::default_render_scale(),
+            dynamic_render_scale: false,
+            sharpen_upscaling: Self::default_sharpen_upscaling(),
         }
     }
 }
 
 impl GraphicsSettings {
-    const FILE_NAME: &'static str = "client/graphics_settings.ron";
+    const LEGACY_FILE_NAME: &'static str = "client/graphics_settings.ron";
+    const SECTION: &'static str = "graphics";
+
+    fn default_smooth_movement() -> bool {
+        true
+    }
+
+    fn default_interpolation_delay() -> u32 {
+        100
+    }
+
+    fn default_bloom_intensity() -> f32 {
+        0.5
+    }
+
+    fn default_bloom_threshold() -> f32 {
+        1.0
+    }
+
+    fn default_render_scale() -> f32 {
+        1.0
+    }
+
+    fn default_sharpen_upscaling() -> bool {
+        true
+    }
 
     pub fn new() -> Self {
         Self::load().unwrap_or_else(|| {
             #[cfg(feature = "debug")]
-            print_debug!("failed to load graphics settings from {}", Self::FILE_NAME.magenta());
+            print_debug!("failed to load graphics settings");
 
             Default::default()
         })
     }
 
     pub fn load() -> Option<Self> {
-        #[cfg(feature = "debug")]
-        print_debug!("loading graphics settings from {}", Self::FILE_NAME.magenta());
+        let mut settings: Self = SettingsFile::load_section(Self::SECTION).or_else(|| SettingsFile::load_legacy(Self::LEGACY_FILE_NAME))?;
+
+        // The settings file can be edited by hand, and a huge render scale would
+        // allocate render targets that don't fit into memory. Unlike `clamp`,
+        // `max` also replaces NaN.
+        settings.render_scale = settings.render_scale.max(MINIMUM_RENDER_SCALE).min(MAXIMUM_RENDER_SCALE);
 
-        std::fs::read_to_string(Self::FILE_NAME)
-            .ok()
-            .and_then(|data| ron::from_str(&data).ok())
+        Some(settings)
     }
 
     pub fn save(&self) {
-        #[cfg(feature = "debug")]
-        print_debug!("saving graphics settings to {}", Self::FILE_NAME.magenta());
-
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
-        std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
+        SettingsFile::save_section(Self::SECTION, self);
     }
 }
 
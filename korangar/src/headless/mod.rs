@@ -0,0 +1,318 @@
+//! A client without a window or graphics engine, for stress testing servers.
+//! It runs the same networking as the normal client and keeps track of the
+//! entities on the map, but is controlled by commands that are read line by
+//! line from the standard input:
+//!
+//! ```text
+//! login 127.0.0.1:6900 username password 0
+//! walk 150 180
+//! say hello
+//! status
+//! quit
+//! ```
+//!
+//! Everything that happens is printed to the standard output, so a script can
+//! start any number of clients and follow what they are doing.
+
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
+
+use korangar_networking::{EntityData, LoginServerLoginData, NetworkEvent, NetworkingSystem};
+use ragnarok_packets::handler::NoPacketCallback;
+use ragnarok_packets::{CharacterInformation, EntityId, WorldPosition};
+
+use crate::world::{EntityStore, StoredEntity};
+
+/// Time between two updates of the client. There is nothing to render, so the
+/// client only has to be fast enough to answer the server.
+const TICK: Duration = Duration::from_millis(50);
+
+#[derive(Debug)]
+pub enum HeadlessCommand {
+    LogIn {
+        address: SocketAddr,
+        username: String,
+        password: String,
+        character_slot: usize,
+    },
+    WalkTo(WorldPosition),
+    Say(String),
+    /// Print the map, the position of the player and the number of entities
+    /// around it.
+    Status,
+    Quit,
+}
+
+pub fn parse_command(line: &str) -> Result<HeadlessCommand, String> {
+    let line = line.trim();
+    let (keyword, arguments) = line.split_once(' ').unwrap_or((line, ""));
+    let mut words = arguments.split_whitespace();
+
+    match keyword {
+        "login" => {
+            let usage = || "usage: login <address> <username> <password> [character slot]".to_owned();
+
+            let address = words
+                .next()
+                .and_then(|address| address.to_socket_addrs().ok()?.next())
+                .ok_or_else(usage)?;
+            let username = words.next().ok_or_else(usage)?.to_owned();
+            let password = words.next().ok_or_else(usage)?.to_owned();
+            let character_slot = match words.next() {
+                Some(slot) => slot.parse().map_err(|_| usage())?,
+                None => 0,
+            };
+
+            Ok(HeadlessCommand::LogIn {
+                address,
+                username,
+                password,
+                character_slot,
+            })
+        }
+        "walk" => {
+            let mut coordinate = || words.next().and_then(|word| word.parse().ok()).ok_or("usage: walk <x> <y>");
+            let x = coordinate()?;
+            let y = coordinate()?;
+
+            Ok(HeadlessCommand::WalkTo(WorldPosition { x, y }))
+        }
+        "say" if !arguments.trim().is_empty() => Ok(HeadlessCommand::Say(arguments.trim().to_owned())),
+        "say" => Err("usage: say <message>".to_owned()),
+        "status" => Ok(HeadlessCommand::Status),
+        "quit" => Ok(HeadlessCommand::Quit),
+        _ => Err(format!("unknown command {keyword}")),
+    }
+}
+
+impl StoredEntity for EntityData {
+    fn stored_entity_id(&self) -> EntityId {
+        self.entity_id
+    }
+}
+
+#[derive(Default)]
+struct Session {
+    login_data: Option<LoginServerLoginData>,
+    character_slot: usize,
+    characters: Vec<CharacterInformation>,
+    player_name: String,
+    map_name: Option<String>,
+    player_position: Option<WorldPosition>,
+    entities: EntityStore<EntityData>,
+}
+
+/// Read commands from the standard input on a separate thread, so reading
+/// never blocks the networking.
+fn spawn_command_reader() -> Receiver<String> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Run the client until it receives the `quit` command or the standard input
+/// is closed.
+pub fn run() {
+    let commands = spawn_command_reader();
+    let mut networking_system = NetworkingSystem::spawn();
+    let mut session = Session::default();
+
+    println!("ready");
+
+    loop {
+        loop {
+            let line = match commands.try_recv() {
+                Ok(line) => line,
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match parse_command(&line) {
+                Ok(HeadlessCommand::Quit) => return,
+                Ok(command) => execute_command(&mut networking_system, &mut session, command),
+                Err(message) => println!("error {message}"),
+            }
+        }
+
+        for event in networking_system.get_events() {
+            handle_event(&mut networking_system, &mut session, event);
+        }
+
+        std::thread::sleep(TICK);
+    }
+}
+
+fn execute_command(networking_system: &mut NetworkingSystem<NoPacketCallback>, session: &mut Session, command: HeadlessCommand) {
+    let result = match command {
+        HeadlessCommand::LogIn {
+            address,
+            username,
+            password,
+            character_slot,
+        } => {
+            session.character_slot = character_slot;
+            networking_system.connect_to_login_server(address, username, password);
+            Ok(())
+        }
+        HeadlessCommand::WalkTo(position) => networking_system.player_move(position),
+        HeadlessCommand::Say(message) => networking_system.send_chat_message(&session.player_name, &message),
+        HeadlessCommand::Status => {
+            let map_name = session.map_name.as_deref().unwrap_or("none");
+            let position = session
+                .player_position
+                .map(|position| format!("{} {}", position.x, position.y))
+                .unwrap_or_else(|| "unknown".to_owned());
+
+            println!("status {map_name} {position} {}", session.entities.len());
+            Ok(())
+        }
+        HeadlessCommand::Quit => Ok(()),
+    };
+
+    if result.is_err() {
+        println!("error not connected to the map server");
+    }
+}
+
+fn handle_event(networking_system: &mut NetworkingSystem<NoPacketCallback>, session: &mut Session, event: NetworkEvent) {
+    match event {
+        NetworkEvent::LoginServerConnected {
+            character_servers,
+            login_data,
+        } => {
+            networking_system.disconnect_from_login_server();
+
+            match character_servers.into_iter().next() {
+                Some(character_server) => {
+                    println!("logged in");
+                    networking_system.connect_to_character_server(&login_data, character_server);
+                    session.login_data = Some(login_data);
+                }
+                None => println!("error no character server available"),
+            }
+        }
+        NetworkEvent::LoginServerConnectionFailed { message, .. }
+        | NetworkEvent::CharacterServerConnectionFailed { message, .. }
+        | NetworkEvent::CharacterSelectionFailed { message, .. } => println!("error {message}"),
+        NetworkEvent::CharacterServerConnected { .. } => {
+            let _ = networking_system.select_character(session.character_slot);
+        }
+        NetworkEvent::CharacterList { characters } => session.characters = characters,
+        NetworkEvent::CharacterSelected { login_data, map_name } => {
+            let Some(login_server_login_data) = session.login_data.as_ref() else {
+                return;
+            };
+
+            session.player_name = session
+                .characters
+                .iter()
+                .find(|character| character.character_id == login_data.character_id)
+                .map(|character| character.name.clone())
+                .unwrap_or_default();
+
+            networking_system.disconnect_from_character_server();
+            networking_system.connect_to_map_server(login_server_login_data, login_data, None);
+            let _ = networking_system.map_loaded();
+
+            println!("entered {map_name}");
+            session.map_name = Some(map_name);
+            session.entities.clear();
+        }
+        NetworkEvent::ChangeMap(map_name, position) => {
+            let _ = networking_system.map_loaded();
+
+            println!("entered {map_name}");
+            session.map_name = Some(map_name);
+            session.player_position = Some(WorldPosition {
+                x: position.x as usize,
+                y: position.y as usize,
+            });
+            session.entities.clear();
+        }
+        NetworkEvent::SetPlayerPosition(position) | NetworkEvent::PlayerMove(_, position, _) => {
+            session.player_position = Some(position);
+        }
+        NetworkEvent::AddEntity(entity_data) => session.entities.insert(entity_data),
+        NetworkEvent::RemoveEntity(entity_id) | NetworkEvent::EntityDied(entity_id) => {
+            session.entities.remove(entity_id);
+        }
+        NetworkEvent::EntityMove(entity_id, _, position_to, _) => {
+            if let Some(entity) = session.entities.get_mut(entity_id) {
+                entity.position = position_to;
+            }
+        }
+        NetworkEvent::EntityForcedMove(entity_id, position) => {
+            if let Some(entity) = session.entities.get_mut(entity_id) {
+                entity.position = WorldPosition {
+                    x: position.x as usize,
+                    y: position.y as usize,
+                };
+            }
+        }
+        NetworkEvent::ChatMessage { text, .. } => println!("chat {text}"),
+        NetworkEvent::LoginServerDisconnected { .. } | NetworkEvent::CharacterServerDisconnected { .. } => {}
+        NetworkEvent::MapServerDisconnected { reason } => {
+            println!("disconnected {reason:?}");
+            session.map_name = None;
+            session.entities.clear();
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod commands {
+    use ragnarok_packets::WorldPosition;
+
+    use super::{parse_command, HeadlessCommand};
+
+    #[test]
+    fn parse_commands() {
+        let Ok(HeadlessCommand::LogIn {
+            address,
+            username,
+            password,
+            character_slot,
+        }) = parse_command("login 127.0.0.1:6900 user secret 2")
+        else {
+            panic!("expected a login command");
+        };
+
+        assert_eq!(address, "127.0.0.1:6900".parse().unwrap());
+        assert_eq!((username.as_str(), password.as_str(), character_slot), ("user", "secret", 2));
+
+        assert!(matches!(
+            parse_command("walk 150 180"),
+            Ok(HeadlessCommand::WalkTo(WorldPosition { x: 150, y: 180 }))
+        ));
+        assert!(matches!(parse_command("say hello  there "), Ok(HeadlessCommand::Say(message)) if message == "hello  there"));
+        assert!(matches!(parse_command("status"), Ok(HeadlessCommand::Status)));
+        assert!(matches!(parse_command("quit"), Ok(HeadlessCommand::Quit)));
+    }
+
+    #[test]
+    fn reject_invalid_commands() {
+        assert!(parse_command("login 127.0.0.1:6900 user").is_err());
+        assert!(parse_command("walk 150").is_err());
+        assert!(parse_command("walk -1 2").is_err());
+        assert!(parse_command("say").is_err());
+        assert!(parse_command("fly").is_err());
+    }
+}
@@ -0,0 +1,689 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use ragnarok_packets::HotbarSlot;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use winit::event::VirtualKeyCode;
+
+use super::{MouseAction, MouseBinding, MACRO_COUNT};
+use crate::inventory::{hotbar_slot_position, HOTBAR_ROW_COUNT, HOTBAR_ROW_SIZE};
+use crate::system::SettingsFile;
+
+/// Number of emotions that can be sent with a key binding.
+pub(super) const EMOTION_COUNT: usize = 10;
+
+/// Keys that trigger the first row of the hotbar outside of battle mode.
+const HOTBAR_KEYS: [VirtualKeyCode; HOTBAR_ROW_SIZE] = [
+    VirtualKeyCode::F1,
+    VirtualKeyCode::F2,
+    VirtualKeyCode::F3,
+    VirtualKeyCode::F4,
+    VirtualKeyCode::F5,
+    VirtualKeyCode::F6,
+    VirtualKeyCode::F7,
+    VirtualKeyCode::F8,
+    VirtualKeyCode::F9,
+];
+
+/// Keys that trigger the rows of the hotbar in battle mode. Every row of the
+/// keyboard is bound to one row of the hotbar.
+const BATTLE_MODE_HOTBAR_KEYS: [[VirtualKeyCode; HOTBAR_ROW_SIZE]; HOTBAR_ROW_COUNT] = [
+    [
+        VirtualKeyCode::Q,
+        VirtualKeyCode::W,
+        VirtualKeyCode::E,
+        VirtualKeyCode::R,
+        VirtualKeyCode::T,
+        VirtualKeyCode::Y,
+        VirtualKeyCode::U,
+        VirtualKeyCode::I,
+        VirtualKeyCode::O,
+    ],
+    [
+        VirtualKeyCode::A,
+        VirtualKeyCode::S,
+        VirtualKeyCode::D,
+        VirtualKeyCode::F,
+        VirtualKeyCode::G,
+        VirtualKeyCode::H,
+        VirtualKeyCode::J,
+        VirtualKeyCode::K,
+        VirtualKeyCode::L,
+    ],
+    [
+        VirtualKeyCode::Z,
+        VirtualKeyCode::X,
+        VirtualKeyCode::C,
+        VirtualKeyCode::V,
+        VirtualKeyCode::B,
+        VirtualKeyCode::N,
+        VirtualKeyCode::M,
+        VirtualKeyCode::Comma,
+        VirtualKeyCode::Period,
+    ],
+];
+
+/// Keys that send an emotion while alt is held.
+const EMOTION_KEYS: [VirtualKeyCode; EMOTION_COUNT] = [
+    VirtualKeyCode::Key1,
+    VirtualKeyCode::Key2,
+    VirtualKeyCode::Key3,
+    VirtualKeyCode::Key4,
+    VirtualKeyCode::Key5,
+    VirtualKeyCode::Key6,
+    VirtualKeyCode::Key7,
+    VirtualKeyCode::Key8,
+    VirtualKeyCode::Key9,
+    VirtualKeyCode::Key0,
+];
+
+/// A key together with the modifiers that need to be held to trigger it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBinding {
+    pub key: VirtualKeyCode,
+    #[serde(default)]
+    pub control: bool,
+    #[serde(default)]
+    pub alt: bool,
+    /// Shift is only checked if it is part of the binding, so that keys can
+    /// still be used while shift is held (e.g. to accelerate the camera).
+    #[serde(default)]
+    pub shift: bool,
+}
+
+impl KeyBinding {
+    pub const fn new(key: VirtualKeyCode) -> Self {
+        Self {
+            key,
+            control: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn control(key: VirtualKeyCode) -> Self {
+        Self {
+            control: true,
+            ..Self::new(key)
+        }
+    }
+
+    pub const fn alt(key: VirtualKeyCode) -> Self {
+        Self {
+            alt: true,
+            ..Self::new(key)
+        }
+    }
+
+    pub fn is_modifier(key: VirtualKeyCode) -> bool {
+        matches!(
+            key,
+            VirtualKeyCode::LControl
+                | VirtualKeyCode::RControl
+                | VirtualKeyCode::LAlt
+                | VirtualKeyCode::RAlt
+                | VirtualKeyCode::LShift
+                | VirtualKeyCode::RShift
+                | VirtualKeyCode::LWin
+                | VirtualKeyCode::RWin
+        )
+    }
+
+    /// Check if the modifiers that are currently held match the binding.
+    pub fn modifiers_match(&self, control: bool, alt: bool, shift: bool) -> bool {
+        self.control == control && self.alt == alt && (!self.shift || shift)
+    }
+
+    /// Two bindings conflict if a single key press can trigger both of them.
+    /// Since shift does not need to be released for bindings without it, it
+    /// is ignored.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        self.key == other.key && self.control == other.control && self.alt == other.alt
+    }
+}
+
+impl Display for KeyBinding {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.control {
+            formatter.write_str("Ctrl+")?;
+        }
+
+        if self.alt {
+            formatter.write_str("Alt+")?;
+        }
+
+        if self.shift {
+            formatter.write_str("Shift+")?;
+        }
+
+        // Number keys are called `Key1`, `Key2`, ..., which is not very readable.
+        let key = format!("{:?}", self.key);
+        match key.strip_prefix("Key") {
+            Some(number) => formatter.write_str(number),
+            None => formatter.write_str(&key),
+        }
+    }
+}
+
+/// Situation in which an action can be triggered. Actions that are never
+/// available at the same time can share a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InputContext {
+    Always,
+    Normal,
+    BattleMode,
+    DebugCamera,
+}
+
+impl InputContext {
+    fn overlaps(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Normal, Self::BattleMode) | (Self::BattleMode, Self::Normal) => false,
+            (Self::DebugCamera, other) | (other, Self::DebugCamera) => matches!(other, Self::DebugCamera | Self::Always),
+            _ => true,
+        }
+    }
+}
+
+/// Everything that can be bound to a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InputAction {
+    OpenMenu,
+    OpenInventory,
+    OpenQuests,
+    OpenCommandPalette,
+    OpenKeyBindings,
+    CloseWindow,
+    FocusChat,
+    ToggleInterface,
+    ShowAllNames,
+    ToggleSitting,
+    SendEmotion(u8),
+    Hotbar(u16),
+    BattleHotbar(u16),
+    Macro(u8),
+    CameraMoveForward,
+    CameraMoveBackward,
+    CameraMoveLeft,
+    CameraMoveRight,
+    CameraMoveUp,
+    CameraAccelerate,
+    OpenMaps,
+    OpenRenderSettings,
+    OpenTime,
+    OpenPackets,
+}
+
+impl InputAction {
+    /// All actions in the order they are displayed.
+    pub fn all() -> Vec<Self> {
+        let mut actions = vec![
+            Self::OpenMenu,
+            Self::OpenInventory,
+            Self::OpenQuests,
+            Self::OpenCommandPalette,
+            Self::OpenKeyBindings,
+            Self::CloseWindow,
+            Self::FocusChat,
+            Self::ToggleInterface,
+            Self::ShowAllNames,
+            Self::ToggleSitting,
+        ];
+
+        actions.extend((0..EMOTION_COUNT as u8).map(Self::SendEmotion));
+        actions.extend((0..HOTBAR_ROW_SIZE as u16).map(Self::Hotbar));
+        actions.extend((0..(HOTBAR_ROW_COUNT * HOTBAR_ROW_SIZE) as u16).map(Self::BattleHotbar));
+        actions.extend((0..MACRO_COUNT as u8).map(Self::Macro));
+
+        #[cfg(feature = "debug")]
+        actions.extend([
+            Self::CameraMoveForward,
+            Self::CameraMoveBackward,
+            Self::CameraMoveLeft,
+            Self::CameraMoveRight,
+            Self::CameraMoveUp,
+            Self::CameraAccelerate,
+            Self::OpenMaps,
+            Self::OpenRenderSettings,
+            Self::OpenTime,
+            Self::OpenPackets,
+        ]);
+
+        actions
+    }
+
+    pub fn category(self) -> &'static str {
+        match self {
+            Self::OpenMenu
+            | Self::OpenInventory
+            | Self::OpenQuests
+            | Self::OpenCommandPalette
+            | Self::OpenKeyBindings
+            | Self::CloseWindow
+            | Self::FocusChat
+            | Self::ToggleInterface
+            | Self::ShowAllNames => "Interface",
+            Self::ToggleSitting => "Character",
+            Self::SendEmotion(..) => "Emotions",
+            Self::Hotbar(..) => "Hotbar",
+            Self::BattleHotbar(..) => "Battle mode hotbar",
+            Self::Macro(..) => "Macros",
+            Self::CameraMoveForward
+            | Self::CameraMoveBackward
+            | Self::CameraMoveLeft
+            | Self::CameraMoveRight
+            | Self::CameraMoveUp
+            | Self::CameraAccelerate => "Debug camera",
+            Self::OpenMaps | Self::OpenRenderSettings | Self::OpenTime | Self::OpenPackets => "Debug windows",
+        }
+    }
+
+    fn context(self) -> InputContext {
+        match self {
+            Self::Hotbar(..) => InputContext::Normal,
+            Self::BattleHotbar(..) => InputContext::BattleMode,
+            Self::CameraMoveForward
+            | Self::CameraMoveBackward
+            | Self::CameraMoveLeft
+            | Self::CameraMoveRight
+            | Self::CameraMoveUp
+            | Self::CameraAccelerate => InputContext::DebugCamera,
+            _ => InputContext::Always,
+        }
+    }
+
+    fn default_binding(self) -> Option<KeyBinding> {
+        let binding = match self {
+            Self::OpenMenu => KeyBinding::new(VirtualKeyCode::Escape),
+            Self::OpenInventory => KeyBinding::alt(VirtualKeyCode::E),
+            Self::OpenQuests => KeyBinding::alt(VirtualKeyCode::U),
+            Self::OpenCommandPalette => KeyBinding::control(VirtualKeyCode::K),
+            Self::OpenKeyBindings | Self::Macro(..) => return None,
+            Self::CloseWindow => KeyBinding::control(VirtualKeyCode::Q),
+            Self::FocusChat => KeyBinding::new(VirtualKeyCode::Return),
+            Self::ToggleInterface => KeyBinding::control(VirtualKeyCode::H),
+            Self::ShowAllNames => KeyBinding::new(VirtualKeyCode::LAlt),
+            Self::ToggleSitting => KeyBinding::new(VirtualKeyCode::Insert),
+            Self::SendEmotion(emotion) => KeyBinding::alt(*EMOTION_KEYS.get(emotion as usize)?),
+            Self::Hotbar(slot) => KeyBinding::new(*HOTBAR_KEYS.get(slot as usize)?),
+            Self::BattleHotbar(slot) => {
+                let (row, column) = hotbar_slot_position(HotbarSlot(slot));
+                KeyBinding::new(BATTLE_MODE_HOTBAR_KEYS.get(row)?[column])
+            }
+            Self::CameraMoveForward => KeyBinding::new(VirtualKeyCode::W),
+            Self::CameraMoveBackward => KeyBinding::new(VirtualKeyCode::S),
+            Self::CameraMoveLeft => KeyBinding::new(VirtualKeyCode::A),
+            Self::CameraMoveRight => KeyBinding::new(VirtualKeyCode::D),
+            Self::CameraMoveUp => KeyBinding::new(VirtualKeyCode::Space),
+            Self::CameraAccelerate => KeyBinding::new(VirtualKeyCode::LShift),
+            Self::OpenMaps => KeyBinding::control(VirtualKeyCode::M),
+            Self::OpenRenderSettings => KeyBinding::control(VirtualKeyCode::R),
+            Self::OpenTime => KeyBinding::control(VirtualKeyCode::T),
+            Self::OpenPackets => KeyBinding::control(VirtualKeyCode::P),
+        };
+
+        Some(binding)
+    }
+}
+
+impl Display for InputAction {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OpenMenu => write!(formatter, "Open menu"),
+            Self::OpenInventory => write!(formatter, "Open inventory"),
+            Self::OpenQuests => write!(formatter, "Open quests"),
+            Self::OpenCommandPalette => write!(formatter, "Open command palette"),
+            Self::OpenKeyBindings => write!(formatter, "Open key bindings"),
+            Self::CloseWindow => write!(formatter, "Close window"),
+            Self::FocusChat => write!(formatter, "Focus chat"),
+            Self::ToggleInterface => write!(formatter, "Toggle interface"),
+            Self::ShowAllNames => write!(formatter, "Show all names"),
+            Self::ToggleSitting => write!(formatter, "Sit or stand"),
+            Self::SendEmotion(emotion) => write!(formatter, "Emotion {}", emotion + 1),
+            Self::Hotbar(slot) => write!(formatter, "Slot {}", slot + 1),
+            Self::BattleHotbar(slot) => {
+                let (row, column) = hotbar_slot_position(HotbarSlot(*slot));
+                write!(formatter, "Row {} slot {}", row + 1, column + 1)
+            }
+            Self::Macro(index) => write!(formatter, "Macro {}", index + 1),
+            Self::CameraMoveForward => write!(formatter, "Move forward"),
+            Self::CameraMoveBackward => write!(formatter, "Move backward"),
+            Self::CameraMoveLeft => write!(formatter, "Move left"),
+            Self::CameraMoveRight => write!(formatter, "Move right"),
+            Self::CameraMoveUp => write!(formatter, "Move up"),
+            Self::CameraAccelerate => write!(formatter, "Accelerate"),
+            Self::OpenMaps => write!(formatter, "Open maps"),
+            Self::OpenRenderSettings => write!(formatter, "Open render settings"),
+            Self::OpenTime => write!(formatter, "Open time"),
+            Self::OpenPackets => write!(formatter, "Open packets"),
+        }
+    }
+}
+
+/// Key and mouse bindings of all actions. Unbound actions are stored as
+/// `None`, so that they are not replaced with the default binding when
+/// loading.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeyBindings {
+    bindings: BTreeMap<InputAction, Option<KeyBinding>>,
+    /// Not part of profiles, which only contain key bindings.
+    #[serde(skip)]
+    mouse_bindings: BTreeMap<MouseAction, Option<MouseBinding>>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let bindings = InputAction::all()
+            .into_iter()
+            .map(|action| (action, action.default_binding()))
+            .collect();
+
+        let mouse_bindings = MouseAction::all()
+            .into_iter()
+            .map(|action| (action, action.default_binding()))
+            .collect();
+
+        Self { bindings, mouse_bindings }
+    }
+}
+
+/// Key bindings as they are stored in the settings file. Actions can't be
+/// used as keys of a TOML table because some of them carry data, so the
+/// bindings are stored as a list instead.
+#[derive(Serialize, Deserialize)]
+struct StoredKeyBindings {
+    bindings: Vec<StoredKeyBinding>,
+    #[serde(default)]
+    mouse_bindings: Vec<StoredMouseBinding>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredKeyBinding {
+    action: InputAction,
+    /// Missing for unbound actions.
+    binding: Option<KeyBinding>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredMouseBinding {
+    action: MouseAction,
+    /// Missing for unbound actions.
+    binding: Option<MouseBinding>,
+}
+
+impl From<&KeyBindings> for StoredKeyBindings {
+    fn from(key_bindings: &KeyBindings) -> Self {
+        let bindings = key_bindings
+            .bindings
+            .iter()
+            .map(|(&action, &binding)| StoredKeyBinding { action, binding })
+            .collect();
+
+        let mouse_bindings = key_bindings
+            .mouse_bindings
+            .iter()
+            .map(|(&action, &binding)| StoredMouseBinding { action, binding })
+            .collect();
+
+        Self { bindings, mouse_bindings }
+    }
+}
+
+impl From<StoredKeyBindings> for KeyBindings {
+    fn from(stored: StoredKeyBindings) -> Self {
+        let bindings = stored
+            .bindings
+            .into_iter()
+            .map(|StoredKeyBinding { action, binding }| (action, binding))
+            .collect();
+
+        let mouse_bindings = stored
+            .mouse_bindings
+            .into_iter()
+            .map(|StoredMouseBinding { action, binding }| (action, binding))
+            .collect();
+
+        Self { bindings, mouse_bindings }
+    }
+}
+
+impl KeyBindings {
+    const LEGACY_FILE_NAME: &'static str = "client/key_bindings.ron";
+    const PROFILE_DIRECTORY: &'static str = "client/key_bindings";
+    const SECTION: &'static str = "input";
+
+    pub fn new() -> Self {
+        SettingsFile::load_section::<StoredKeyBindings>(Self::SECTION)
+            .map(|stored| Self::from(stored).with_default_bindings())
+            .or_else(|| Self::load(Self::LEGACY_FILE_NAME))
+            .unwrap_or_else(|| {
+                #[cfg(feature = "debug")]
+                print_debug!("failed to load key bindings");
+
+            Default::default()
+        })
+    }
+
+    fn load(path: &str) -> Option<Self> {
+        #[cfg(feature = "debug")]
+        print_debug!("loading key bindings from {}", path.magenta());
+
+        let key_bindings: Self = std::fs::read_to_string(path).ok().and_then(|data| ron::from_str(&data).ok())?;
+
+        Some(key_bindings.with_default_bindings())
+    }
+
+    /// Actions that were added after the bindings were saved get their default
+    /// binding.
+    fn with_default_bindings(mut self) -> Self {
+        let default = Self::default();
+
+        for (action, binding) in default.bindings {
+            self.bindings.entry(action).or_insert(binding);
+        }
+
+        for (action, binding) in default.mouse_bindings {
+            self.mouse_bindings.entry(action).or_insert(binding);
+        }
+
+        self
+    }
+
+    fn save_to(&self, path: &str) -> std::io::Result<()> {
+        #[cfg(feature = "debug")]
+        print_debug!("saving key bindings to {}", path.magenta());
+
+        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        std::fs::write(path, data)
+    }
+
+    pub fn save(&self) {
+        SettingsFile::save_section(Self::SECTION, &StoredKeyBindings::from(self));
+    }
+
+    fn profile_path(name: &str) -> Result<String, String> {
+        let is_valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|character| character.is_alphanumeric() || matches!(character, ' ' | '-' | '_'));
+
+        match is_valid {
+            true => Ok(format!("{}/{}.ron", Self::PROFILE_DIRECTORY, name)),
+            false => Err(format!("invalid profile name \"{name}\"")),
+        }
+    }
+
+    /// Save the bindings as a named profile that can be imported again later.
+    pub fn export_profile(&self, name: &str) -> Result<(), String> {
+        let path = Self::profile_path(name)?;
+
+        std::fs::create_dir_all(Self::PROFILE_DIRECTORY)
+            .and_then(|_| self.save_to(&path))
+            .map_err(|error| format!("failed to export profile \"{name}\": {error}"))
+    }
+
+    /// Load the key bindings of a profile. Mouse bindings are not part of
+    /// profiles, so the current ones are kept.
+    pub fn import_profile(&self, name: &str) -> Result<Self, String> {
+        let path = Self::profile_path(name)?;
+        let imported = Self::load(&path).ok_or_else(|| format!("failed to import profile \"{name}\""))?;
+
+        Ok(Self {
+            mouse_bindings: self.mouse_bindings.clone(),
+            ..imported
+        })
+    }
+
+    pub fn get(&self, action: InputAction) -> Option<KeyBinding> {
+        self.bindings.get(&action).copied().flatten()
+    }
+
+    pub fn set(&mut self, action: InputAction, binding: Option<KeyBinding>) {
+        self.bindings.insert(action, binding);
+    }
+
+    pub fn get_mouse(&self, action: MouseAction) -> Option<MouseBinding> {
+        self.mouse_bindings.get(&action).copied().flatten()
+    }
+
+    pub fn set_mouse(&mut self, action: MouseAction, binding: Option<MouseBinding>) {
+        self.mouse_bindings.insert(action, binding);
+    }
+
+    /// All other actions that would be triggered by the same key press as the
+    /// given action.
+    pub fn conflicts(&self, action: InputAction) -> Vec<InputAction> {
+        let Some(binding) = self.get(action) else {
+            return Vec::new();
+        };
+
+        self.bindings
+            .iter()
+            .filter(|(other_action, _)| **other_action != action && other_action.context().overlaps(action.context()))
+            .filter(|(_, other_binding)| other_binding.is_some_and(|other_binding| other_binding.conflicts_with(&binding)))
+            .map(|(other_action, _)| *other_action)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod conflicts {
+    use winit::event::VirtualKeyCode;
+
+    use super::{InputAction, KeyBinding, KeyBindings};
+
+    #[test]
+    fn defaults_have_no_conflicts() {
+        let key_bindings = KeyBindings::default();
+
+        for action in InputAction::all() {
+            assert_eq!(key_bindings.conflicts(action), Vec::new(), "{action:?} has conflicts");
+        }
+    }
+
+    #[test]
+    fn same_key_conflicts() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.set(InputAction::OpenQuests, Some(KeyBinding::alt(VirtualKeyCode::E)));
+
+        assert_eq!(key_bindings.conflicts(InputAction::OpenQuests), vec![
+            InputAction::OpenInventory
+        ]);
+        assert_eq!(key_bindings.conflicts(InputAction::OpenInventory), vec![
+            InputAction::OpenQuests
+        ]);
+    }
+
+    #[test]
+    fn different_modifiers_do_not_conflict() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.set(InputAction::OpenQuests, Some(KeyBinding::control(VirtualKeyCode::E)));
+
+        assert!(key_bindings.conflicts(InputAction::OpenQuests).is_empty());
+    }
+
+    #[test]
+    fn shift_is_ignored() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.set(
+            InputAction::OpenQuests,
+            Some(KeyBinding {
+                shift: true,
+                ..KeyBinding::alt(VirtualKeyCode::E)
+            }),
+        );
+
+        assert_eq!(key_bindings.conflicts(InputAction::OpenQuests), vec![
+            InputAction::OpenInventory
+        ]);
+    }
+
+    #[test]
+    fn hotbars_share_keys() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.set(InputAction::Hotbar(0), Some(KeyBinding::new(VirtualKeyCode::Q)));
+
+        assert!(key_bindings.conflicts(InputAction::Hotbar(0)).is_empty());
+
+        key_bindings.set(InputAction::Hotbar(1), Some(KeyBinding::new(VirtualKeyCode::Q)));
+
+        assert_eq!(key_bindings.conflicts(InputAction::Hotbar(0)), vec![InputAction::Hotbar(1)]);
+    }
+
+    #[test]
+    fn unbound_does_not_conflict() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.set(InputAction::OpenInventory, None);
+        key_bindings.set(InputAction::OpenQuests, None);
+
+        assert!(key_bindings.conflicts(InputAction::OpenQuests).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod storage {
+    use winit::event::MouseButton;
+
+    use super::{InputAction, KeyBindings, MouseAction, MouseBinding, StoredKeyBindings};
+
+    fn round_trip(key_bindings: &KeyBindings) -> KeyBindings {
+        let value = toml::Value::try_from(StoredKeyBindings::from(key_bindings)).unwrap();
+        KeyBindings::from(value.try_into::<StoredKeyBindings>().unwrap())
+    }
+
+    #[test]
+    fn keep_bindings() {
+        let key_bindings = KeyBindings::default();
+
+        assert_eq!(round_trip(&key_bindings), key_bindings);
+    }
+
+    #[test]
+    fn keep_mouse_bindings() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.set_mouse(MouseAction::RotateCamera, Some(MouseBinding::new(MouseButton::Middle)));
+        key_bindings.set_mouse(MouseAction::ForceAttack, None);
+
+        let loaded = round_trip(&key_bindings).with_default_bindings();
+
+        assert_eq!(
+            loaded.get_mouse(MouseAction::RotateCamera),
+            Some(MouseBinding::new(MouseButton::Middle))
+        );
+        assert_eq!(loaded.get_mouse(MouseAction::ForceAttack), None);
+    }
+
+    #[test]
+    fn keep_unbound_actions() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.set(InputAction::SendEmotion(3), None);
+
+        let loaded = round_trip(&key_bindings).with_default_bindings();
+
+        assert_eq!(loaded.get(InputAction::SendEmotion(3)), None);
+    }
+}
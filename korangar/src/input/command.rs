@@ -0,0 +1,202 @@
+use super::UserEvent;
+
+/// Client side chat commands together with a short description of their
+/// arguments.
+const CHAT_COMMANDS: &[(&str, &str)] = &[
+    ("sit", ""),
+    ("stand", ""),
+    ("where", ""),
+    ("w", "<name> <message>"),
+    ("memo", ""),
+    ("bm", ""),
+    ("hi", "[message]"),
+    ("guild", "<name>"),
+    ("mi", "<monster id>"),
+    ("privacy", ""),
+];
+
+const DEFAULT_GREETING: &str = "Hi!";
+
+/// Split off the first argument of a command. Names containing spaces can be
+/// passed by wrapping them in double quotes.
+fn split_argument(arguments: &str) -> Option<(&str, &str)> {
+    let arguments = arguments.trim_start();
+
+    if let Some(quoted) = arguments.strip_prefix('"') {
+        let (argument, rest) = quoted.split_once('"')?;
+        return (!argument.is_empty()).then_some((argument, rest.trim_start()));
+    }
+
+    match arguments.split_once(' ') {
+        Some((argument, rest)) => Some((argument, rest.trim_start())),
+        None => (!arguments.is_empty()).then_some((arguments, "")),
+    }
+}
+
+fn usage(command: &str) -> UserEvent {
+    let arguments = CHAT_COMMANDS
+        .iter()
+        .find(|(name, _)| *name == command)
+        .map(|(_, arguments)| *arguments)
+        .unwrap_or_default();
+
+    UserEvent::InvalidChatCommand(format!("Usage: /{} {}", command, arguments))
+}
+
+/// Turn the input of the chat window into a [`UserEvent`]. Commands starting
+/// with `/` are handled by the client, everything else (including `@` and `#`
+/// commands) is sent to the map server as a regular message.
+pub fn parse_chat_input(input: String) -> UserEvent {
+    let Some(command_line) = input.strip_prefix('/') else {
+        return UserEvent::SendMessage(input);
+    };
+
+    let (command, arguments) = command_line.split_once(' ').unwrap_or((command_line, ""));
+    let arguments = arguments.trim();
+
+    match command {
+        "sit" => UserEvent::SitDown,
+        "stand" => UserEvent::StandUp,
+        "where" => UserEvent::ShowPlayerPosition,
+        "memo" => UserEvent::RememberWarpPoint,
+        "bm" => UserEvent::ToggleBattleMode,
+        "privacy" => UserEvent::ToggleStreamerMode,
+        "hi" => match arguments.is_empty() {
+            true => UserEvent::GreetFriends(DEFAULT_GREETING.to_owned()),
+            false => UserEvent::GreetFriends(arguments.to_owned()),
+        },
+        "w" => match split_argument(arguments) {
+            Some((recipient_name, message)) if !message.is_empty() => UserEvent::SendWhisper {
+                recipient_name: recipient_name.to_owned(),
+                message: message.to_owned(),
+            },
+            _ => usage(command),
+        },
+        "guild" => match arguments.is_empty() {
+            true => usage(command),
+            false => UserEvent::CreateGuild(arguments.to_owned()),
+        },
+        "mi" => match arguments.parse() {
+            Ok(monster_id) => UserEvent::OpenMonsterInformation(monster_id),
+            Err(_) => usage(command),
+        },
+        _ => UserEvent::InvalidChatCommand(format!("Unknown command /{}", command)),
+    }
+}
+
+/// Complete a partially typed chat command. If multiple commands match, the
+/// input is completed up to their longest common prefix.
+pub fn complete_chat_command(input: &str) -> Option<String> {
+    let partial = input.strip_prefix('/')?;
+
+    if partial.contains(' ') {
+        return None;
+    }
+
+    let mut matches = CHAT_COMMANDS.iter().map(|(name, _)| *name).filter(|name| name.starts_with(partial));
+
+    let first = matches.next()?;
+    let mut common_length = first.len();
+    let mut unique = true;
+
+    for other in matches {
+        unique = false;
+        common_length = first
+            .bytes()
+            .zip(other.bytes())
+            .take(common_length)
+            .take_while(|(left, right)| left == right)
+            .count();
+    }
+
+    match unique {
+        true => Some(format!("/{} ", first)),
+        false => Some(format!("/{}", &first[..common_length])),
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use super::parse_chat_input;
+    use crate::input::UserEvent;
+
+    #[test]
+    fn plain_message() {
+        let event = parse_chat_input("hello there".to_owned());
+        assert!(matches!(event, UserEvent::SendMessage(message) if message == "hello there"));
+    }
+
+    #[test]
+    fn server_commands_are_passed_through() {
+        let event = parse_chat_input("@warp prontera".to_owned());
+        assert!(matches!(event, UserEvent::SendMessage(message) if message == "@warp prontera"));
+    }
+
+    #[test]
+    fn whisper() {
+        let event = parse_chat_input("/w Alice how are you?".to_owned());
+        assert!(matches!(
+            event,
+            UserEvent::SendWhisper { recipient_name, message } if recipient_name == "Alice" && message == "how are you?"
+        ));
+    }
+
+    #[test]
+    fn whisper_quoted_name() {
+        let event = parse_chat_input("/w \"Sir Alice\" hi".to_owned());
+        assert!(matches!(
+            event,
+            UserEvent::SendWhisper { recipient_name, message } if recipient_name == "Sir Alice" && message == "hi"
+        ));
+    }
+
+    #[test]
+    fn whisper_without_message() {
+        let event = parse_chat_input("/w Alice".to_owned());
+        assert!(matches!(event, UserEvent::InvalidChatCommand(..)));
+    }
+
+    #[test]
+    fn monster_information() {
+        let event = parse_chat_input("/mi 1002".to_owned());
+        assert!(matches!(event, UserEvent::OpenMonsterInformation(1002)));
+
+        let event = parse_chat_input("/mi poring".to_owned());
+        assert!(matches!(event, UserEvent::InvalidChatCommand(..)));
+    }
+
+    #[test]
+    fn streamer_mode() {
+        let event = parse_chat_input("/privacy".to_owned());
+        assert!(matches!(event, UserEvent::ToggleStreamerMode));
+    }
+
+    #[test]
+    fn unknown_command() {
+        let event = parse_chat_input("/dance".to_owned());
+        assert!(matches!(event, UserEvent::InvalidChatCommand(..)));
+    }
+}
+
+#[cfg(test)]
+mod complete {
+    use super::complete_chat_command;
+
+    #[test]
+    fn unique_match() {
+        assert_eq!(complete_chat_command("/gu"), Some("/guild ".to_owned()));
+    }
+
+    #[test]
+    fn common_prefix() {
+        assert_eq!(complete_chat_command("/s"), Some("/s".to_owned()));
+        assert_eq!(complete_chat_command("/st"), Some("/stand ".to_owned()));
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(complete_chat_command("/xyz"), None);
+        assert_eq!(complete_chat_command("hello"), None);
+        assert_eq!(complete_chat_command("/w Alice"), None);
+    }
+}
@@ -1,14 +1,17 @@
 use cgmath::Vector2;
 use korangar_interface::event::ClickAction;
 use korangar_interface::ElementEvent;
-use korangar_networking::ShopItem;
+use korangar_networking::{CharacterAppearance, IncomingRequest, InventoryItem, ShopItem};
 use ragnarok_packets::{
-    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HotbarSlot, ShopId, SoldItemInformation, TilePosition,
+    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HotbarSlot, InventoryIndex, ItemId, PetCommand, ShopId,
+    SoldItemInformation, TilePosition,
 };
 
+use super::{InputAction, KeyBinding, MouseAction, MouseBinding};
 use crate::interface::application::{InterfaceSettings, InternalThemeKind};
-use crate::interface::resource::Move;
-use crate::loaders::ServiceId;
+use crate::interface::chat::ChatCategory;
+use crate::interface::resource::{ItemSource, Move};
+use crate::loaders::{ResourceMetadata, ServiceId};
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
 
@@ -19,9 +22,20 @@ pub enum UserEvent {
         service_id: ServiceId,
         username: String,
         password: String,
+        /// Overrides the packet version of the service.
+        packet_version: Option<u32>,
+        window_layout: Option<String>,
     },
     SelectServer(CharacterServerInformation),
+    OpenLoginWindow,
+    OpenCrashReport(String),
+    /// Download the update that was announced in the update window.
+    DownloadUpdate,
+    OpenReplayWindow,
+    PlayReplay(String),
     LogOut,
+    /// Leave the current session and return to the login window.
+    SwitchAccount,
     Exit,
     CameraZoom(f32),
     CameraRotate(f32),
@@ -31,7 +45,58 @@ pub enum UserEvent {
     OpenSkillTreeWindow,
     OpenGraphicsSettingsWindow,
     OpenAudioSettingsWindow,
+    OpenRequestSettingsWindow,
+    OpenChatSettingsWindow,
+    OpenPrivacySettingsWindow,
+    AddChatTab(String),
+    RemoveChatTab(usize),
+    ToggleChatTabCategory {
+        tab: usize,
+        category: ChatCategory,
+    },
+    OpenKeyBindingsWindow,
+    /// Bind the next key press to the action.
+    CaptureKeyBinding(InputAction),
+    SetKeyBinding(InputAction, Option<KeyBinding>),
+    /// Bind the next mouse button press to the action.
+    CaptureMouseBinding(MouseAction),
+    SetMouseBinding(MouseAction, Option<MouseBinding>),
+    ResetKeyBindings,
+    ExportKeyBindings(String),
+    ImportKeyBindings(String),
+    SetMacro(usize, String),
+    OpenNetworkQualityWindow,
+    OpenMinimapWindow,
+    /// Walk in the direction of the left stick, relative to the camera.
+    GamepadMove(Vector2<f32>),
+    InteractWithNearestNpc,
+    OpenWorldMapWindow,
     OpenFriendsWindow,
+    OpenEmoteWindow,
+    OpenQuestWindow,
+    OpenAchievementWindow,
+    OpenAttendanceWindow,
+    OpenPetWindow,
+    OpenStatusEffectsWindow,
+    OpenGuildWindow,
+    OpenCommandPaletteWindow,
+    OpenMonsterInformation(usize),
+    OpenItemInformation(ItemId),
+    InspectEntity(EntityId),
+    OpenEntityContextMenu(EntityId),
+    OpenItemContextMenu {
+        source: ItemSource,
+        item: InventoryItem<ResourceMetadata>,
+    },
+    RequestTrade(AccountId),
+    InviteToParty(String),
+    InviteToGuild(AccountId),
+    ViewEquipment(AccountId),
+    UseItem(InventoryIndex),
+    DropItem {
+        index: InventoryIndex,
+        amount: u16,
+    },
     ToggleShowInterface,
     SetThemeFile {
         theme_file: String,
@@ -43,35 +108,83 @@ pub enum UserEvent {
     ReloadTheme {
         theme_kind: InternalThemeKind,
     },
+    /// Save the theme to a new file in the theme directory and use it.
+    SaveThemeAs {
+        theme_kind: InternalThemeKind,
+        name: String,
+    },
+    /// Load a theme from the theme directory.
+    LoadTheme {
+        theme_kind: InternalThemeKind,
+        name: String,
+    },
     SelectCharacter(usize),
     OpenCharacterCreationWindow(usize),
+    ChangeCharacterAppearance(CharacterAppearance),
     CreateCharacter(usize, String),
+    OpenCharacterRenameWindow(CharacterId),
+    RenameCharacter(CharacterId, String),
     DeleteCharacter(CharacterId),
+    OpenCharacterDeletionWindow(CharacterId),
+    ConfirmCharacterDeletion(CharacterId, String),
+    CancelCharacterDeletion(CharacterId),
     RequestSwitchCharacterSlot(usize),
     CancelSwitchCharacterSlot,
     SwitchCharacterSlot(usize),
     RequestPlayerMove(Vector2<usize>),
     RequestPlayerInteract(EntityId),
+    RequestPlayerAttack(EntityId),
     RequestWarpToMap(String, TilePosition),
     SendMessage(String),
+    /// Insert text into the input of the chat window and focus it.
+    InsertChatText(String),
+    SendEmotion(u8),
+    SendWhisper {
+        recipient_name: String,
+        message: String,
+    },
+    InvalidChatCommand(String),
+    SitDown,
+    StandUp,
+    ToggleSitting,
+    ShowPlayerPosition,
+    RememberWarpPoint,
+    ToggleBattleMode,
+    ToggleStreamerMode,
+    GreetFriends(String),
+    CreateGuild(String),
+    RequestAchievementReward(u32),
+    ChangeTitle(u32),
+    OpenBank,
+    CloseBank,
+    DepositZeny(u32),
+    WithdrawZeny(u32),
+    ClaimAttendanceReward,
+    SelectRefineItem(InventoryIndex),
+    RefineItem {
+        index: InventoryIndex,
+        material_id: ItemId,
+        use_blacksmith_blessing: bool,
+    },
+    CloseRefineWindow,
+    SendPetCommand(PetCommand),
     NextDialog(EntityId),
     CloseDialog(EntityId),
     ChooseDialogOption(EntityId, i8),
     MoveResource(Move),
     CastSkill(HotbarSlot),
+    /// Cast a skill that was waiting for a click on its target.
+    ConfirmSkill(HotbarSlot),
     StopSkill(HotbarSlot),
+    ToggleSmartCast(HotbarSlot),
     AddFriend(String),
     RemoveFriend {
         account_id: AccountId,
         character_id: CharacterId,
     },
-    RejectFriendRequest {
-        account_id: AccountId,
-        character_id: CharacterId,
-    },
-    AcceptFriendRequest {
-        account_id: AccountId,
-        character_id: CharacterId,
+    RespondToRequest {
+        request: IncomingRequest,
+        accept: bool,
     },
     BuyItems {
         items: Vec<ShopItem<u32>>,
@@ -105,8 +218,7 @@ pub enum UserEvent {
     SetDusk,
     #[cfg(feature = "debug")]
     SetMidnight,
-    #[cfg(feature = "debug")]
-    OpenThemeViewerWindow,
+    OpenThemeEditorWindow,
     #[cfg(feature = "debug")]
     OpenProfilerWindow,
     #[cfg(feature = "debug")]
@@ -0,0 +1,181 @@
+use std::f32::consts::TAU;
+
+use cgmath::{InnerSpace, Vector2, Zero};
+use gilrs::{Axis, Button, Event, EventType, GamepadId, Gilrs};
+
+use super::Key;
+
+/// Buttons that the client reacts to.
+const BUTTONS: [Button; 8] = [
+    Button::South,
+    Button::East,
+    Button::North,
+    Button::West,
+    Button::LeftTrigger,
+    Button::RightTrigger,
+    Button::Start,
+    Button::Select,
+];
+
+/// Stick movement below this is ignored, since most sticks don't rest at
+/// exactly zero.
+const DEAD_ZONE: f32 = 0.2;
+
+/// How far the stick has to be pushed to select a slot of a radial menu.
+const RADIAL_THRESHOLD: f32 = 0.5;
+
+pub enum GamepadConnection {
+    Connected(String),
+    Disconnected(String),
+}
+
+/// State of the gamepad that was used most recently.
+pub struct Gamepad {
+    /// Not available on platforms without gamepad support.
+    gilrs: Option<Gilrs>,
+    active_gamepad: Option<GamepadId>,
+    buttons: [Key; BUTTONS.len()],
+    left_stick: Vector2<f32>,
+    right_stick: Vector2<f32>,
+    /// Gamepads that were plugged in or removed since the last update.
+    connections: Vec<GamepadConnection>,
+}
+
+impl Gamepad {
+    pub fn new() -> Self {
+        Self {
+            gilrs: Gilrs::new().ok(),
+            active_gamepad: None,
+            buttons: [Key::default(); BUTTONS.len()],
+            left_stick: Vector2::zero(),
+            right_stick: Vector2::zero(),
+            connections: Vec::new(),
+        }
+    }
+
+    /// Process the events since the last update. Returns true if the gamepad
+    /// was used.
+    pub fn update(&mut self) -> bool {
+        let Some(gilrs) = &mut self.gilrs else {
+            return false;
+        };
+
+        let mut used = false;
+
+        while let Some(Event { id, event, .. }) = gilrs.next_event() {
+            match event {
+                EventType::Connected => {
+                    self.connections
+                        .push(GamepadConnection::Connected(gilrs.gamepad(id).name().to_owned()));
+                    self.active_gamepad.get_or_insert(id);
+                }
+                EventType::Disconnected => {
+                    self.connections
+                        .push(GamepadConnection::Disconnected(gilrs.gamepad(id).name().to_owned()));
+
+                    if self.active_gamepad == Some(id) {
+                        self.active_gamepad = None;
+                    }
+                }
+                EventType::ButtonPressed(..) | EventType::ButtonChanged(..) | EventType::AxisChanged(..) => {
+                    self.active_gamepad = Some(id);
+                    used = true;
+                }
+                _ => {}
+            }
+        }
+
+        match self.active_gamepad.map(|id| gilrs.gamepad(id)) {
+            Some(gamepad) => {
+                for (key, button) in self.buttons.iter_mut().zip(BUTTONS) {
+                    key.set_down(gamepad.is_pressed(button));
+                }
+
+                self.left_stick = apply_dead_zone(Vector2::new(gamepad.value(Axis::LeftStickX), gamepad.value(Axis::LeftStickY)));
+                self.right_stick = apply_dead_zone(Vector2::new(gamepad.value(Axis::RightStickX), gamepad.value(Axis::RightStickY)));
+            }
+            None => {
+                self.buttons.iter_mut().for_each(|key| key.set_down(false));
+                self.left_stick = Vector2::zero();
+                self.right_stick = Vector2::zero();
+            }
+        }
+
+        self.buttons.iter_mut().for_each(Key::update);
+
+        // Holding a stick doesn't send any events.
+        used || self.left_stick != Vector2::zero() || self.right_stick != Vector2::zero()
+    }
+
+    pub fn take_connections(&mut self) -> Vec<GamepadConnection> {
+        std::mem::take(&mut self.connections)
+    }
+
+    pub fn button(&self, button: Button) -> Key {
+        BUTTONS
+            .iter()
+            .position(|other| *other == button)
+            .map(|index| self.buttons[index])
+            .unwrap_or_default()
+    }
+
+    /// Position of the left stick, with up being positive.
+    pub fn left_stick(&self) -> Vector2<f32> {
+        self.left_stick
+    }
+
+    /// Position of the right stick, with up being positive.
+    pub fn right_stick(&self) -> Vector2<f32> {
+        self.right_stick
+    }
+}
+
+/// Ignore small movements and scale the rest so that the stick starts at zero
+/// at the edge of the dead zone.
+fn apply_dead_zone(stick: Vector2<f32>) -> Vector2<f32> {
+    let magnitude = stick.magnitude();
+
+    if magnitude < DEAD_ZONE {
+        return Vector2::zero();
+    }
+
+    let scaled_magnitude = ((magnitude - DEAD_ZONE) / (1.0 - DEAD_ZONE)).min(1.0);
+    stick * (scaled_magnitude / magnitude)
+}
+
+/// Slot of a radial menu that the stick points at. Slot zero is at the top and
+/// the following slots are placed clockwise.
+pub fn radial_slot(stick: Vector2<f32>, slot_count: usize) -> Option<usize> {
+    if slot_count == 0 || stick.magnitude() < RADIAL_THRESHOLD {
+        return None;
+    }
+
+    let angle = stick.x.atan2(stick.y).rem_euclid(TAU);
+    let slot_angle = TAU / slot_count as f32;
+
+    Some(((angle + slot_angle / 2.0) / slot_angle) as usize % slot_count)
+}
+
+#[cfg(test)]
+mod sticks {
+    use cgmath::{InnerSpace, Vector2};
+
+    use super::{apply_dead_zone, radial_slot};
+
+    #[test]
+    fn dead_zone() {
+        assert_eq!(apply_dead_zone(Vector2::new(0.1, -0.1)), Vector2::new(0.0, 0.0));
+        assert!((apply_dead_zone(Vector2::new(1.0, 0.0)).magnitude() - 1.0).abs() < f32::EPSILON);
+        assert!(apply_dead_zone(Vector2::new(0.3, 0.0)).x < 0.3);
+    }
+
+    #[test]
+    fn radial_slots() {
+        assert_eq!(radial_slot(Vector2::new(0.0, 1.0), 4), Some(0));
+        assert_eq!(radial_slot(Vector2::new(1.0, 0.0), 4), Some(1));
+        assert_eq!(radial_slot(Vector2::new(0.0, -1.0), 4), Some(2));
+        assert_eq!(radial_slot(Vector2::new(-1.0, 0.0), 4), Some(3));
+        assert_eq!(radial_slot(Vector2::new(-0.1, 1.0), 4), Some(0));
+        assert_eq!(radial_slot(Vector2::new(0.2, 0.2), 4), None);
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::HotbarSlot;
+use serde::{Deserialize, Serialize};
+
+use super::{parse_chat_input, UserEvent};
+use crate::inventory::HOTBAR_SLOT_COUNT;
+use crate::system::SettingsFile;
+
+/// Number of macros that can be bound to a key.
+pub const MACRO_COUNT: usize = 5;
+
+/// Longest wait of a single step in milliseconds, so that a typo can't stall a
+/// macro for minutes.
+const MAXIMUM_WAIT: u64 = 10_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacroStep {
+    /// Use the skill in a hotbar slot.
+    Hotbar(u16),
+    /// A chat message or command, exactly as it would be typed in the chat.
+    Chat(String),
+    /// Wait the given number of milliseconds before the next step.
+    Wait(u64),
+}
+
+/// Parse a macro. Steps are separated by `;` and are either `hotbar <slot>`,
+/// `wait <milliseconds>` or anything that could be typed in the chat, for
+/// example `hotbar 1; wait 300; hotbar 2; /sit`.
+pub fn parse_macro(script: &str) -> Result<Vec<MacroStep>, String> {
+    script
+        .split(';')
+        .map(str::trim)
+        .filter(|step| !step.is_empty())
+        .map(|step| {
+            let (keyword, argument) = step.split_once(' ').unwrap_or((step, ""));
+            let argument = argument.trim();
+
+            match keyword {
+                "hotbar" => match argument.parse::<u16>() {
+                    Ok(slot) if (1..=HOTBAR_SLOT_COUNT as u16).contains(&slot) => Ok(MacroStep::Hotbar(slot - 1)),
+                    _ => Err(format!("hotbar slot must be between 1 and {HOTBAR_SLOT_COUNT}")),
+                },
+                "wait" => match argument.parse::<u64>() {
+                    Ok(milliseconds) if milliseconds <= MAXIMUM_WAIT => Ok(MacroStep::Wait(milliseconds)),
+                    _ => Err(format!("wait must be between 0 and {MAXIMUM_WAIT} milliseconds")),
+                },
+                _ => Ok(MacroStep::Chat(step.to_owned())),
+            }
+        })
+        .collect()
+}
+
+/// Scripts of all macros, saved as they were entered.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Macros {
+    scripts: Vec<String>,
+}
+
+impl Macros {
+    const SECTION: &'static str = "macros";
+
+    pub fn new() -> Self {
+        SettingsFile::load_section(Self::SECTION).unwrap_or_default()
+    }
+
+    pub fn get(&self, index: usize) -> &str {
+        self.scripts.get(index).map(String::as_str).unwrap_or_default()
+    }
+
+    /// Replace the script of a macro and save all macros.
+    pub fn set(&mut self, index: usize, script: String) {
+        if index >= MACRO_COUNT {
+            return;
+        }
+
+        self.scripts.resize(MACRO_COUNT, String::new());
+        self.scripts[index] = script;
+
+        SettingsFile::save_section(Self::SECTION, self);
+    }
+
+    pub fn scripts(&self) -> Vec<String> {
+        (0..MACRO_COUNT).map(|index| self.get(index).to_owned()).collect()
+    }
+}
+
+/// Plays back the steps of the macro that was started most recently.
+#[derive(Default)]
+pub struct MacroPlayer {
+    steps: VecDeque<MacroStep>,
+    resume_at: Option<Instant>,
+}
+
+impl MacroPlayer {
+    /// Start a macro, replacing the one that is currently running.
+    pub fn start(&mut self, steps: Vec<MacroStep>) {
+        self.steps = steps.into();
+        self.resume_at = None;
+    }
+
+    pub fn stop(&mut self) {
+        self.steps.clear();
+        self.resume_at = None;
+    }
+
+    /// Events of all steps that are due.
+    pub fn update(&mut self, now: Instant) -> Vec<UserEvent> {
+        let mut events = Vec::new();
+
+        if self.resume_at.is_some_and(|resume_at| now < resume_at) {
+            return events;
+        }
+
+        self.resume_at = None;
+
+        while let Some(step) = self.steps.pop_front() {
+            match step {
+                MacroStep::Hotbar(slot) => {
+                    events.push(UserEvent::CastSkill(HotbarSlot(slot)));
+                    events.push(UserEvent::StopSkill(HotbarSlot(slot)));
+                }
+                MacroStep::Chat(text) => events.push(parse_chat_input(text)),
+                MacroStep::Wait(milliseconds) => {
+                    self.resume_at = Some(now + Duration::from_millis(milliseconds));
+                    break;
+                }
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod playback {
+    use std::time::{Duration, Instant};
+
+    use super::{parse_macro, MacroPlayer, MacroStep};
+    use crate::input::UserEvent;
+
+    #[test]
+    fn parse_steps() {
+        assert_eq!(
+            parse_macro("hotbar 1; wait 300;; /sit ; hello there"),
+            Ok(vec![
+                MacroStep::Hotbar(0),
+                MacroStep::Wait(300),
+                MacroStep::Chat("/sit".to_owned()),
+                MacroStep::Chat("hello there".to_owned()),
+            ])
+        );
+        assert_eq!(parse_macro(""), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn reject_invalid_steps() {
+        assert!(parse_macro("hotbar 0").is_err());
+        assert!(parse_macro("hotbar 31").is_err());
+        assert!(parse_macro("hotbar one").is_err());
+        assert!(parse_macro("wait 60000").is_err());
+    }
+
+    #[test]
+    fn wait_between_steps() {
+        let now = Instant::now();
+        let mut player = MacroPlayer::default();
+        player.start(parse_macro("/sit; wait 100; /stand").unwrap());
+
+        assert!(matches!(player.update(now).as_slice(), [UserEvent::SitDown]));
+        assert!(player.update(now + Duration::from_millis(50)).is_empty());
+        assert!(matches!(player.update(now + Duration::from_millis(100)).as_slice(), [
+            UserEvent::StandUp
+        ]));
+        assert!(player.update(now + Duration::from_millis(200)).is_empty());
+    }
+}
@@ -1,31 +1,60 @@
+mod bindings;
+mod command;
 mod event;
+mod gamepad;
 mod key;
+mod macros;
 mod mode;
+mod mouse_bindings;
+mod mouse_settings;
+mod server_command;
+mod touch;
 
 use std::mem::variant_count;
+use std::time::Instant;
 
-use cgmath::Vector2;
+use arboard::Clipboard;
+use cgmath::{Vector2, Zero};
+use gilrs::Button;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
 use korangar_interface::application::FocusState;
 use korangar_interface::elements::{ElementCell, Focus};
-use korangar_interface::event::ClickAction;
-use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::event::{ClickAction, TextEdit};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateClone};
 use korangar_interface::Interface;
 use ragnarok_packets::{ClientTick, HotbarSlot};
 use winit::dpi::PhysicalPosition;
-use winit::event::{ElementState, MouseButton, MouseScrollDelta, VirtualKeyCode};
+use winit::event::{ElementState, Ime, MouseButton, MouseScrollDelta, Touch, VirtualKeyCode};
 
+use self::bindings::EMOTION_COUNT;
+pub use self::bindings::{InputAction, KeyBinding, KeyBindings};
+pub use self::command::{complete_chat_command, parse_chat_input};
 pub use self::event::UserEvent;
+use self::gamepad::{radial_slot, Gamepad, GamepadConnection};
 pub use self::key::Key;
+pub use self::macros::{parse_macro, MACRO_COUNT};
+use self::macros::{MacroPlayer, Macros};
 pub use self::mode::{Grabbed, MouseInputMode};
+pub use self::mouse_bindings::{MouseAction, MouseBinding};
+pub use self::mouse_settings::MouseSettings;
+pub use self::server_command::ServerCommandList;
+use self::touch::{TouchAction, TouchTracker};
 #[cfg(feature = "debug")]
 use crate::graphics::RenderSettings;
 use crate::graphics::{PickerRenderTarget, PickerTarget};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
 use crate::interface::layout::{ScreenPosition, ScreenSize};
-use crate::interface::resource::PartialMove;
+use crate::interface::notifications::{NotificationKind, Notifications};
+use crate::interface::windows::ContextMenuWindow;
+use crate::inventory::{HOTBAR_ROW_COUNT, HOTBAR_ROW_SIZE};
 
 const MOUSE_SCOLL_MULTIPLIER: f32 = 30.0;
+/// Camera rotation per frame with the right stick fully pushed, comparable to
+/// the mouse moving this many pixels.
+const GAMEPAD_ROTATION_SPEED: f32 = 12.0;
+const GAMEPAD_ZOOM_SPEED: f32 = 8.0;
 const KEY_COUNT: usize = variant_count::<VirtualKeyCode>();
 
 pub struct InputSystem {
@@ -37,13 +66,61 @@ pub struct InputSystem {
     scroll_delta: f32,
     left_mouse_button: Key,
     right_mouse_button: Key,
+    middle_mouse_button: Key,
     keys: [Key; KEY_COUNT],
     mouse_input_mode: MouseInputMode,
     input_buffer: Vec<char>,
+    /// Characters that were typed while nothing was focused. They are passed to
+    /// the chat window once it is focused.
+    pending_characters: Vec<char>,
+    /// Text that is being composed with an input method, if it changed since
+    /// the last update.
+    preedit: Option<String>,
+    /// Edits from keys like the arrow keys. They are buffered so that key
+    /// repeat works the same way it does for characters.
+    text_edits: Vec<TextEdit>,
+    /// Opened on first use, since it might not be available on every system.
+    clipboard: Option<Clipboard>,
+    battle_mode: bool,
+    /// Set while the key to show the names of all entities is held.
+    show_all_names: bool,
+    key_bindings: PlainTrackedState<KeyBindings>,
+    /// Action that the next key press will be bound to.
+    key_capture: PlainTrackedState<Option<InputAction>>,
+    /// Binding that was captured since the last update.
+    captured_binding: Option<(InputAction, Option<KeyBinding>)>,
+    /// Set when a capture finished, so that the captured key does not trigger
+    /// any action.
+    key_captured: bool,
+    /// Action that the next mouse button press will be bound to.
+    mouse_capture: PlainTrackedState<Option<MouseAction>>,
+    captured_mouse_binding: Option<(MouseAction, Option<MouseBinding>)>,
+    /// Buttons that were used to capture a mouse binding. Their release is
+    /// ignored, since their press was never registered.
+    ignored_mouse_buttons: Vec<MouseButton>,
+    gamepad: Gamepad,
+    /// Set when the gamepad was used more recently than the mouse.
+    gamepad_active: bool,
+    /// Hotbar slot selected in the radial menu, if the menu is open.
+    radial_menu: Option<Option<usize>>,
+    /// Hotbar slot of a skill that is cast with the next click on the world.
+    skill_targeting: Option<HotbarSlot>,
+    touch: TouchTracker,
+    /// Set once the first touch was registered.
+    touch_active: bool,
+    touch_rotation: f32,
+    macros: Macros,
+    macro_player: MacroPlayer,
+    /// Servers can disallow macros in their client info.
+    macros_allowed: bool,
+    mouse_settings: PlainTrackedState<MouseSettings>,
+    /// Mouse movement reported by the device since the last update.
+    new_raw_mouse_delta: Vector2<f32>,
+    raw_mouse_delta: Vector2<f32>,
 }
 
 impl InputSystem {
-    pub fn new() -> Self {
+    pub fn new(key_bindings: PlainTrackedState<KeyBindings>, mouse_settings: PlainTrackedState<MouseSettings>) -> Self {
         let previous_mouse_position = ScreenPosition::default();
         let new_mouse_position = ScreenPosition::default();
         let mouse_delta = ScreenSize::default();
@@ -54,10 +131,35 @@ impl InputSystem {
 
         let left_mouse_button = Key::default();
         let right_mouse_button = Key::default();
+        let middle_mouse_button = Key::default();
         let keys = [Key::default(); KEY_COUNT];
 
         let mouse_input_mode = MouseInputMode::None;
         let input_buffer = Vec::new();
+        let pending_characters = Vec::new();
+        let preedit = None;
+        let text_edits = Vec::new();
+        let clipboard = None;
+        let battle_mode = false;
+        let show_all_names = false;
+        let key_capture = PlainTrackedState::default();
+        let captured_binding = None;
+        let key_captured = false;
+        let mouse_capture = PlainTrackedState::default();
+        let captured_mouse_binding = None;
+        let ignored_mouse_buttons = Vec::new();
+        let gamepad = Gamepad::new();
+        let gamepad_active = false;
+        let radial_menu = None;
+        let skill_targeting = None;
+        let touch = TouchTracker::default();
+        let touch_active = false;
+        let touch_rotation = 0.0;
+        let macros = Macros::new();
+        let macro_player = MacroPlayer::default();
+        let macros_allowed = true;
+        let new_raw_mouse_delta = Vector2::zero();
+        let raw_mouse_delta = Vector2::zero();
 
         Self {
             previous_mouse_position,
@@ -68,15 +170,48 @@ impl InputSystem {
             scroll_delta,
             left_mouse_button,
             right_mouse_button,
+            middle_mouse_button,
             keys,
             mouse_input_mode,
             input_buffer,
+            pending_characters,
+            preedit,
+            text_edits,
+            clipboard,
+            battle_mode,
+            show_all_names,
+            key_bindings,
+            key_capture,
+            captured_binding,
+            key_captured,
+            mouse_capture,
+            captured_mouse_binding,
+            ignored_mouse_buttons,
+            gamepad,
+            gamepad_active,
+            radial_menu,
+            skill_targeting,
+            touch,
+            touch_active,
+            touch_rotation,
+            macros,
+            macro_player,
+            macros_allowed,
+            mouse_settings,
+            new_raw_mouse_delta,
+            raw_mouse_delta,
         }
     }
 
+    /// Type text into the chat window once it is focused.
+    pub fn insert_chat_text(&mut self, text: &str) {
+        self.pending_characters.extend(text.chars());
+    }
+
     pub fn reset(&mut self) {
         self.left_mouse_button.reset();
         self.right_mouse_button.reset();
+        self.middle_mouse_button.reset();
         self.keys.iter_mut().for_each(|key| key.reset());
         self.mouse_input_mode = MouseInputMode::None;
     }
@@ -88,13 +223,37 @@ impl InputSystem {
         };
     }
 
+    pub fn update_raw_mouse_motion(&mut self, delta: (f64, f64)) {
+        self.new_raw_mouse_delta += Vector2::new(delta.0 as f32, delta.1 as f32);
+    }
+
     pub fn update_mouse_buttons(&mut self, button: MouseButton, state: ElementState) {
         let pressed = matches!(state, ElementState::Pressed);
 
+        if pressed && let Some(action) = self.mouse_capture.cloned() {
+            let binding = MouseBinding {
+                button,
+                control: self.control_down(),
+                alt: self.alt_down(),
+                shift: self.shift_down(),
+            };
+
+            self.captured_mouse_binding = Some((action, Some(binding)));
+            self.mouse_capture.set(None);
+            self.ignored_mouse_buttons.push(button);
+            return;
+        }
+
+        if !pressed && let Some(index) = self.ignored_mouse_buttons.iter().position(|ignored| *ignored == button) {
+            self.ignored_mouse_buttons.remove(index);
+            return;
+        }
+
         match button {
             MouseButton::Left => self.left_mouse_button.set_down(pressed),
             MouseButton::Right => self.right_mouse_button.set_down(pressed),
-            _ignored => {}
+            MouseButton::Middle => self.middle_mouse_button.set_down(pressed),
+            MouseButton::Other(..) => {}
         }
     }
 
@@ -105,31 +264,338 @@ impl InputSystem {
         }
     }
 
+    pub fn update_touch(&mut self, touch: Touch) {
+        let position = Vector2::new(touch.location.x as f32, touch.location.y as f32);
+
+        self.touch_active = true;
+        self.touch.update_touch(touch.id, touch.phase, position, Instant::now());
+        self.apply_touch_actions();
+    }
+
+    /// Touch gestures are applied as if they came from the mouse.
+    fn apply_touch_actions(&mut self) {
+        for action in self.touch.take_actions() {
+            match action {
+                TouchAction::MoveCursor(position) => {
+                    self.new_mouse_position = ScreenPosition {
+                        left: position.x,
+                        top: position.y,
+                    }
+                }
+                TouchAction::Press(button) => self.update_mouse_buttons(button, ElementState::Pressed),
+                TouchAction::Release(button) => self.update_mouse_buttons(button, ElementState::Released),
+                TouchAction::Zoom(zoom) => self.new_scroll_position += zoom,
+                TouchAction::Rotate(rotation) => self.touch_rotation += rotation,
+            }
+        }
+    }
+
     pub fn update_keyboard(&mut self, virtual_code: VirtualKeyCode, state: ElementState) {
         let pressed = matches!(state, ElementState::Pressed);
         self.keys[virtual_code as usize].set_down(pressed);
+
+        if self.key_capture.cloned().is_some() {
+            self.capture_key(virtual_code, pressed);
+            return;
+        }
+
+        // Escape cancels capturing a mouse binding and backspace removes it.
+        if pressed && let Some(action) = self.mouse_capture.cloned() {
+            match virtual_code {
+                VirtualKeyCode::Escape => {}
+                VirtualKeyCode::Back => self.captured_mouse_binding = Some((action, None)),
+                _ => return,
+            }
+
+            self.mouse_capture.set(None);
+            self.key_captured = true;
+            return;
+        }
+
+        if pressed {
+            let select = self.shift_down();
+            let word = self.control_down();
+
+            let text_edit = match virtual_code {
+                VirtualKeyCode::Left => TextEdit::Left { word, select },
+                VirtualKeyCode::Right => TextEdit::Right { word, select },
+                VirtualKeyCode::Home => TextEdit::Home { select },
+                VirtualKeyCode::End => TextEdit::End { select },
+                VirtualKeyCode::Delete => TextEdit::Delete { word },
+                VirtualKeyCode::Up => TextEdit::Up,
+                VirtualKeyCode::Down => TextEdit::Down,
+                _ => return,
+            };
+
+            self.text_edits.push(text_edit);
+        }
+    }
+
+    /// Bind the next key press to the given action.
+    pub fn start_key_capture(&mut self, action: InputAction) {
+        self.key_capture.set(Some(action));
+    }
+
+    pub fn get_key_capture(&self) -> PlainRemote<Option<InputAction>> {
+        self.key_capture.new_remote()
+    }
+
+    /// Bind the next mouse button press to the given action.
+    pub fn start_mouse_capture(&mut self, action: MouseAction) {
+        self.mouse_capture.set(Some(action));
+    }
+
+    pub fn get_mouse_capture(&self) -> PlainRemote<Option<MouseAction>> {
+        self.mouse_capture.new_remote()
+    }
+
+    /// Cast the skill in the given slot with the next click on the world.
+    pub fn start_skill_targeting(&mut self, slot: HotbarSlot) {
+        self.skill_targeting = Some(slot);
+    }
+
+    /// Escape cancels the capture and backspace removes the binding. Modifier
+    /// keys are only bound on their own if they are released without pressing
+    /// another key.
+    fn capture_key(&mut self, virtual_code: VirtualKeyCode, pressed: bool) {
+        let binding = match virtual_code {
+            VirtualKeyCode::Escape if pressed => {
+                self.key_capture.set(None);
+                self.key_captured = true;
+                return;
+            }
+            VirtualKeyCode::Back if pressed => None,
+            key if pressed && !KeyBinding::is_modifier(key) => Some(KeyBinding {
+                key,
+                control: self.control_down(),
+                alt: self.alt_down(),
+                shift: self.shift_down(),
+            }),
+            key if !pressed && KeyBinding::is_modifier(key) => Some(KeyBinding::new(key)),
+            _ => return,
+        };
+
+        if let Some(action) = self.key_capture.cloned() {
+            self.captured_binding = Some((action, binding));
+        }
+
+        self.key_capture.set(None);
+        self.key_captured = true;
     }
 
     pub fn buffer_character(&mut self, character: char) {
         self.input_buffer.push(character);
     }
 
+    pub fn update_ime(&mut self, ime: Ime) {
+        match ime {
+            Ime::Preedit(text, _cursor) => self.preedit = Some(text),
+            Ime::Commit(text) => {
+                self.preedit = Some(String::new());
+                self.input_buffer.extend(text.chars());
+            }
+            Ime::Disabled => self.preedit = Some(String::new()),
+            Ime::Enabled => {}
+        }
+    }
+
     pub fn update_delta(&mut self) {
+        self.touch.update(Instant::now());
+        self.apply_touch_actions();
+
         self.mouse_delta = self.new_mouse_position - self.previous_mouse_position;
         self.previous_mouse_position = self.new_mouse_position;
+        self.raw_mouse_delta = std::mem::replace(&mut self.new_raw_mouse_delta, Vector2::zero());
 
         self.scroll_delta = self.new_scroll_position - self.previous_scroll_position;
         self.previous_scroll_position = self.new_scroll_position;
 
         self.left_mouse_button.update();
         self.right_mouse_button.update();
+        self.middle_mouse_button.update();
         self.keys.iter_mut().for_each(|key| key.update());
+
+        // The input mode follows whichever device was used last.
+        if self.gamepad.update() {
+            self.gamepad_active = true;
+        } else if self.mouse_delta != ScreenSize::default() || self.left_mouse_button.pressed() {
+            self.gamepad_active = false;
+        }
     }
 
     fn get_key(&self, key_code: VirtualKeyCode) -> &Key {
         &self.keys[key_code as usize]
     }
 
+    /// Either of the control keys is held.
+    fn control_down(&self) -> bool {
+        self.get_key(VirtualKeyCode::LControl).down() || self.get_key(VirtualKeyCode::RControl).down()
+    }
+
+    /// Either of the alt keys is held.
+    fn alt_down(&self) -> bool {
+        self.get_key(VirtualKeyCode::LAlt).down() || self.get_key(VirtualKeyCode::RAlt).down()
+    }
+
+    /// Either of the shift keys is held.
+    fn shift_down(&self) -> bool {
+        self.get_key(VirtualKeyCode::LShift).down() || self.get_key(VirtualKeyCode::RShift).down()
+    }
+
+    /// Get the key of an action if the modifiers that are currently held match
+    /// its binding.
+    fn get_action_key(&self, action: InputAction) -> Option<&Key> {
+        let binding = self.key_bindings.get().get(action)?;
+
+        // Modifier keys that are bound on their own are held while they are pressed.
+        let modifiers_match =
+            KeyBinding::is_modifier(binding.key) || binding.modifiers_match(self.control_down(), self.alt_down(), self.shift_down());
+
+        modifiers_match.then(|| self.get_key(binding.key))
+    }
+
+    fn action_pressed(&self, action: InputAction) -> bool {
+        self.get_action_key(action).is_some_and(Key::pressed)
+    }
+
+    fn action_down(&self, action: InputAction) -> bool {
+        self.get_action_key(action).is_some_and(Key::down)
+    }
+
+    /// Modifiers are not checked, since they might be released before the key.
+    fn action_released(&self, action: InputAction) -> bool {
+        self.key_bindings
+            .get()
+            .get(action)
+            .is_some_and(|binding| self.get_key(binding.key).released())
+    }
+
+    fn get_mouse_button(&self, button: MouseButton) -> Option<&Key> {
+        match button {
+            MouseButton::Left => Some(&self.left_mouse_button),
+            MouseButton::Right => Some(&self.right_mouse_button),
+            MouseButton::Middle => Some(&self.middle_mouse_button),
+            MouseButton::Other(..) => None,
+        }
+    }
+
+    /// Get the mouse button of an action if the modifiers that are currently
+    /// held match its binding.
+    fn get_mouse_action_button(&self, action: MouseAction) -> Option<&Key> {
+        let binding = self.key_bindings.get().get_mouse(action)?;
+
+        let modifiers_match = binding.modifiers_match(self.control_down(), self.alt_down(), self.shift_down());
+
+        modifiers_match.then(|| self.get_mouse_button(binding.button)).flatten()
+    }
+
+    /// Actions trigger when their button is pressed, unless the button also
+    /// rotates the camera. In that case they trigger when the button is
+    /// released without having rotated the camera.
+    fn mouse_action_triggered(&self, action: MouseAction, rotated_camera: bool) -> bool {
+        let Some(button) = self.get_mouse_action_button(action) else {
+            return false;
+        };
+
+        let key_bindings = self.key_bindings.get();
+        let shares_rotation = key_bindings
+            .get_mouse(MouseAction::RotateCamera)
+            .zip(key_bindings.get_mouse(action))
+            .is_some_and(|(rotation, binding)| rotation.button == binding.button);
+
+        match shares_rotation {
+            true => button.released() && !rotated_camera,
+            false => button.pressed(),
+        }
+    }
+
+    /// Modifiers are not checked, so that releasing them doesn't stop the
+    /// action.
+    fn mouse_action_down(&self, action: MouseAction) -> bool {
+        self.key_bindings
+            .get()
+            .get_mouse(action)
+            .and_then(|binding| self.get_mouse_button(binding.button))
+            .is_some_and(Key::down)
+    }
+
+    fn get_clipboard(&mut self) -> Option<&mut Clipboard> {
+        if self.clipboard.is_none() {
+            self.clipboard = Clipboard::new().ok();
+        }
+
+        self.clipboard.as_mut()
+    }
+
+    fn copy_to_clipboard(&mut self, text: String) {
+        if let Some(clipboard) = self.get_clipboard() {
+            let _ = clipboard.set_text(text);
+        }
+    }
+
+    fn paste_from_clipboard(&mut self) -> Option<String> {
+        self.get_clipboard().and_then(|clipboard| clipboard.get_text().ok())
+    }
+
+    fn gamepad_events(&mut self, events: &mut Vec<UserEvent>, notifications: &mut Notifications, lock_actions: bool) {
+        for connection in self.gamepad.take_connections() {
+            match connection {
+                #[cfg(feature = "debug")]
+                GamepadConnection::Connected(name) => print_debug!("gamepad {} connected", name.magenta()),
+                #[cfg(not(feature = "debug"))]
+                GamepadConnection::Connected(_) => {}
+                GamepadConnection::Disconnected(name) => {
+                    notifications.push(NotificationKind::Warning, format!("Gamepad {name} disconnected"));
+                }
+            }
+        }
+
+        if lock_actions {
+            return;
+        }
+
+        let left_stick = self.gamepad.left_stick();
+        let right_stick = self.gamepad.right_stick();
+
+        if left_stick != Vector2::zero() {
+            events.push(UserEvent::GamepadMove(left_stick));
+        }
+
+        // While the left bumper is held, the right stick selects a skill from the
+        // radial menu instead of moving the camera. The skill is cast once the
+        // bumper is released.
+        if self.gamepad.button(Button::LeftTrigger).down() {
+            let selected_slot = radial_slot(right_stick, HOTBAR_ROW_SIZE);
+            let previous_slot = self.radial_menu.flatten();
+            self.radial_menu = Some(selected_slot.or(previous_slot));
+        } else {
+            if let Some(Some(slot)) = self.radial_menu.take() {
+                events.push(UserEvent::CastSkill(HotbarSlot(slot as u16)));
+                events.push(UserEvent::StopSkill(HotbarSlot(slot as u16)));
+            }
+
+            if right_stick.x != 0.0 {
+                events.push(UserEvent::CameraRotate(right_stick.x * GAMEPAD_ROTATION_SPEED));
+            }
+
+            if right_stick.y != 0.0 {
+                events.push(UserEvent::CameraZoom(right_stick.y * GAMEPAD_ZOOM_SPEED));
+            }
+        }
+
+        let button_actions = [
+            (Button::South, UserEvent::InteractWithNearestNpc),
+            (Button::Start, UserEvent::OpenMenuWindow),
+            (Button::Select, UserEvent::OpenInventoryWindow),
+        ];
+
+        for (button, event) in button_actions {
+            if self.gamepad.button(button).pressed() {
+                events.push(event);
+            }
+        }
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile("update user input"))]
     pub fn user_events(
         &mut self,
@@ -138,6 +604,7 @@ impl InputSystem {
         focus_state: &mut FocusState<InterfaceSettings>,
         picker_target: &mut PickerRenderTarget,
         mouse_cursor: &mut MouseCursor,
+        notifications: &mut Notifications,
         #[cfg(feature = "debug")] render_settings: &PlainTrackedState<RenderSettings>,
         window_size: Vector2<usize>,
         client_tick: ClientTick,
@@ -149,9 +616,21 @@ impl InputSystem {
     ) {
         let mut events = Vec::new();
         let mut mouse_target = None;
-        let (hovered_element, mut window_index) = interface.hovered_element(self.new_mouse_position, &self.mouse_input_mode);
+        let (mut hovered_element, mut window_index) = interface.hovered_element(self.new_mouse_position, &self.mouse_input_mode);
 
-        let shift_down = self.get_key(VirtualKeyCode::LShift).down();
+        // Clicking anywhere outside of a context menu closes it.
+        if self.left_mouse_button.pressed() || self.right_mouse_button.pressed() {
+            let clicked_context_menu =
+                window_index.is_some_and(|index| interface.get_window(index).get_window_class() == Some(ContextMenuWindow::WINDOW_CLASS));
+
+            if !clicked_context_menu {
+                interface.close_window_with_class(focus_state, ContextMenuWindow::WINDOW_CLASS);
+                (hovered_element, window_index) = interface.hovered_element(self.new_mouse_position, &self.mouse_input_mode);
+            }
+        }
+
+        let shift_down = self.shift_down();
+        let control_down = self.control_down();
 
         #[cfg(feature = "debug")]
         let lock_actions = render_settings.get().use_debug_camera;
@@ -162,7 +641,16 @@ impl InputSystem {
             focus_state.remove_focus();
         }
 
-        if shift_down {
+        // Shift-clicking an element like an item links it in the chat instead of moving
+        // the window.
+        let link_text = hovered_element
+            .as_ref()
+            .filter(|_| shift_down && self.left_mouse_button.pressed())
+            .and_then(|hovered_element| hovered_element.borrow().link_text());
+
+        if let Some(text) = link_text {
+            events.push(UserEvent::InsertChatText(text));
+        } else if shift_down {
             if let Some(window_index) = &mut window_index {
                 focus_state.set_focused_window(*window_index);
 
@@ -224,14 +712,9 @@ impl InputSystem {
                         }
 
                         ClickAction::Move(drop_resource) => {
-                            let input_mode = match drop_resource {
-                                PartialMove::Item { source, item } => MouseInputMode::MoveItem(source, item),
-                                PartialMove::Skill { source, skill } => MouseInputMode::MoveSkill(source, skill),
-                            };
-                            self.mouse_input_mode = input_mode;
-                            // Needs to re-render because some elements will
-                            // render differently
-                            // based on the mouse input mode.
+                            self.mouse_input_mode = MouseInputMode::MoveResource(drop_resource);
+                            // Needs to re-render because drop targets are
+                            // highlighted while a resource is being dragged.
                             interface.schedule_render();
                         }
 
@@ -247,6 +730,8 @@ impl InputSystem {
                         } => interface.open_popup(element, position_tracker, size_tracker, *window_index),
 
                         ClickAction::ClosePopup => interface.close_popup(*window_index),
+                        ClickAction::SetWindowOpacity(opacity) => interface.set_window_opacity(*window_index, opacity),
+                        ClickAction::ToggleWindowLock => interface.toggle_window_lock(*window_index),
                     }
                 }
             }
@@ -256,6 +741,7 @@ impl InputSystem {
             if let MouseInputMode::MoveInterface(identifier) = self.mouse_input_mode {
                 // We want to re-render to get rid of the anchor overlays.
                 interface.schedule_render();
+                interface.stop_moving_window(identifier);
 
                 match self.right_mouse_button.down() && !self.right_mouse_button.released() {
                     true => self.mouse_input_mode = MouseInputMode::ResizeInterface(identifier),
@@ -267,43 +753,43 @@ impl InputSystem {
                 // based on the mouse input mode.
                 interface.schedule_render();
 
-                match mouse_input_mode {
-                    MouseInputMode::MoveItem(source, item) => {
-                        if let Some(hovered_element) = &hovered_element {
-                            if let Some(resource_move) = hovered_element.borrow_mut().drop_resource(PartialMove::Item { source, item }) {
-                                events.push(UserEvent::MoveResource(resource_move));
-                            }
-                        }
-                    }
-                    MouseInputMode::MoveSkill(source, skill) => {
-                        if let Some(hovered_element) = &hovered_element {
-                            if let Some(resource_move) = hovered_element.borrow_mut().drop_resource(PartialMove::Skill { source, skill }) {
-                                events.push(UserEvent::MoveResource(resource_move));
-                            }
-                        }
-                    }
-                    _ => {}
+                if let MouseInputMode::MoveResource(drop_resource) = mouse_input_mode
+                    && let Some(hovered_element) = &hovered_element
+                    && let Some(resource_move) = hovered_element.borrow_mut().drop_resource(drop_resource)
+                {
+                    events.push(UserEvent::MoveResource(resource_move));
                 }
             }
         }
 
+        // Releasing the button after rotating the camera should not trigger any other
+        // action bound to it, like opening a context menu.
+        let rotated_camera = matches!(self.mouse_input_mode, MouseInputMode::RotateCamera);
+
+        if rotated_camera && !self.mouse_action_down(MouseAction::RotateCamera) {
+            self.mouse_input_mode = MouseInputMode::None;
+        }
+
         if self.right_mouse_button.released() {
             if let MouseInputMode::ResizeInterface(identifier) = self.mouse_input_mode {
                 match self.left_mouse_button.down() && !self.left_mouse_button.released() {
                     true => self.mouse_input_mode = MouseInputMode::MoveInterface(identifier),
                     false => self.mouse_input_mode = MouseInputMode::None,
                 }
-            } else {
+            } else if !matches!(self.mouse_input_mode, MouseInputMode::RotateCamera) {
                 self.mouse_input_mode = MouseInputMode::None;
             }
         }
 
-        if self.right_mouse_button.down()
-            && !self.right_mouse_button.pressed()
-            && self.mouse_input_mode.is_none()
-            && self.mouse_delta.width != 0.0
-            && !lock_actions
-        {
+        if self.mouse_input_mode.is_walk() && !self.mouse_action_down(MouseAction::Walk) {
+            self.mouse_input_mode = MouseInputMode::None;
+        }
+
+        let start_rotation = self
+            .get_mouse_action_button(MouseAction::RotateCamera)
+            .is_some_and(|button| button.down() && !button.pressed());
+
+        if start_rotation && window_index.is_none() && self.mouse_input_mode.is_none() && self.mouse_delta.width != 0.0 && !lock_actions {
             self.mouse_input_mode = MouseInputMode::RotateCamera;
         }
 
@@ -316,7 +802,7 @@ impl InputSystem {
             }
             MouseInputMode::MoveInterface(identifier) => {
                 if self.mouse_delta != ScreenSize::default() {
-                    interface.move_window(*identifier, ScreenPosition::from_size(self.mouse_delta));
+                    interface.move_window(application, *identifier, ScreenPosition::from_size(self.mouse_delta));
                 }
                 mouse_cursor.set_state(MouseCursorState::Grab, client_tick);
             }
@@ -326,12 +812,18 @@ impl InputSystem {
                 }
             }
             MouseInputMode::RotateCamera => {
-                events.push(UserEvent::CameraRotate(self.mouse_delta.width));
+                let mouse_settings = self.mouse_settings.get();
+                let movement = match mouse_settings.raw_input {
+                    true => self.raw_mouse_delta.x,
+                    false => self.mouse_delta.width,
+                };
+
+                events.push(UserEvent::CameraRotate(mouse_settings.camera_rotation(movement)));
                 mouse_cursor.set_state(MouseCursorState::RotateCamera, client_tick);
             }
             MouseInputMode::ClickInterface => mouse_cursor.set_state(MouseCursorState::Click, client_tick),
             MouseInputMode::None => {}
-            MouseInputMode::MoveItem(..) | MouseInputMode::MoveSkill(..) | MouseInputMode::Walk(..) => {}
+            MouseInputMode::MoveResource(..) | MouseInputMode::Walk(..) => {}
         }
 
         if self.scroll_delta != 0.0 {
@@ -344,23 +836,79 @@ impl InputSystem {
             }
         }
 
-        let characters = self.input_buffer.drain(..).collect::<Vec<_>>();
-        let mut process_keys = true;
+        let touch_rotation = std::mem::take(&mut self.touch_rotation);
 
-        if let Some((focused_element, focused_window)) = &focus_state.get_focused_element() {
+        if touch_rotation != 0.0 && window_index.is_none() && !lock_actions {
+            events.push(UserEvent::CameraRotate(touch_rotation));
+        }
+
+        let new_characters = self.input_buffer.drain(..).collect::<Vec<_>>();
+        let mut characters = std::mem::take(&mut self.pending_characters);
+        characters.extend_from_slice(&new_characters);
+        let preedit = self.preedit.take();
+        let text_edits = std::mem::take(&mut self.text_edits);
+
+        // Keys that are pressed while capturing a new key binding should not trigger
+        // anything.
+        let capturing_key = self.key_capture.cloned().is_some() || std::mem::take(&mut self.key_captured);
+        let mut process_keys = !capturing_key;
+
+        if let Some((action, binding)) = self.captured_binding.take() {
+            events.push(UserEvent::SetKeyBinding(action, binding));
+        }
+
+        if let Some((action, binding)) = self.captured_mouse_binding.take() {
+            events.push(UserEvent::SetMouseBinding(action, binding));
+        }
+
+        // Escape cancels dragging a resource before it is used to remove the focus or
+        // close a window.
+        let cancel_drag = !capturing_key
+            && self.get_key(VirtualKeyCode::Escape).pressed()
+            && matches!(self.mouse_input_mode, MouseInputMode::MoveResource(..));
+        let cancel_targeting =
+            !capturing_key && !cancel_drag && self.get_key(VirtualKeyCode::Escape).pressed() && self.skill_targeting.is_some();
+        let handle_keys = !capturing_key && !cancel_drag && !cancel_targeting;
+
+        if cancel_targeting {
+            self.skill_targeting = None;
+            process_keys = false;
+        }
+
+        if cancel_drag {
+            self.mouse_input_mode = MouseInputMode::None;
+            // Needs to re-render to get rid of the drop target highlights.
+            interface.schedule_render();
+            process_keys = false;
+        }
+
+        if handle_keys && let Some((focused_element, focused_window)) = &focus_state.get_focused_element() {
             // this will currently not affect the following statements, which is a bit
             // strange
             if self.get_key(VirtualKeyCode::Escape).pressed() {
+                // Close lists that were opened with the keyboard as well.
+                interface.close_popup(*focused_window);
                 focus_state.remove_focus();
                 process_keys = false;
             }
 
             if self.get_key(VirtualKeyCode::Tab).pressed() {
-                let new_focused_element = focused_element
-                    .borrow()
-                    .focus_next(focused_element.clone(), None, Focus::new(shift_down.into()));
+                // Give the focused element the chance to use the tab key (e.g. for completing
+                // the input) before moving the focus.
+                let (key_handled, _) = match shift_down {
+                    true => (false, Vec::new()),
+                    false => interface.input_character_element(focused_element, *focused_window, '\t'),
+                };
+
+                if !key_handled {
+                    let element_cell = focused_element.clone();
+                    let new_focused_element = focused_element
+                        .borrow()
+                        .focus_next(element_cell, None, Focus::new(shift_down.into()));
+
+                    focus_state.update_focused_element(new_focused_element, *focused_window);
+                }
 
-                focus_state.update_focused_element(new_focused_element, *focused_window);
                 process_keys = false;
             }
 
@@ -375,6 +923,12 @@ impl InputSystem {
                             interface.open_window(application, focus_state, prototype_window.as_ref())
                         }
                         ClickAction::CloseWindow => interface.close_window(focus_state, *focused_window),
+                        ClickAction::OpenPopup {
+                            element,
+                            position_tracker,
+                            size_tracker,
+                        } => interface.open_popup(element, position_tracker, size_tracker, *focused_window),
+                        ClickAction::ClosePopup => interface.close_popup(*focused_window),
                         _ => {}
                     }
                 }
@@ -383,10 +937,7 @@ impl InputSystem {
             }
         }
 
-        if self.get_key(VirtualKeyCode::LControl).down()
-            && self.get_key(VirtualKeyCode::Q).pressed()
-            && focus_state.focused_window().is_some()
-        {
+        if handle_keys && self.action_pressed(InputAction::CloseWindow) && focus_state.focused_window().is_some() {
             let window_index = focus_state.get_focused_window().unwrap();
 
             if interface.get_window(window_index).is_closable() {
@@ -397,6 +948,45 @@ impl InputSystem {
         }
 
         if let Some((focused_element, focused_window)) = &focus_state.get_focused_element() {
+            if let Some(preedit) = preedit {
+                interface.input_preedit_element(focused_element, *focused_window, &preedit);
+            }
+
+            for text_edit in text_edits {
+                interface.input_text_edit_element(focused_element, *focused_window, text_edit);
+                process_keys = false;
+            }
+
+            if control_down && self.get_key(VirtualKeyCode::A).pressed() {
+                interface.input_text_edit_element(focused_element, *focused_window, TextEdit::SelectAll);
+                process_keys = false;
+            }
+
+            let cut = self.get_key(VirtualKeyCode::X).pressed();
+            if control_down && (cut || self.get_key(VirtualKeyCode::C).pressed()) {
+                let copy_text = focused_element.borrow().copy_text();
+
+                if let Some(text) = copy_text {
+                    self.copy_to_clipboard(text);
+
+                    if cut {
+                        interface.input_text_edit_element(focused_element, *focused_window, TextEdit::Delete { word: false });
+                    }
+                }
+
+                process_keys = false;
+            }
+
+            if control_down && self.get_key(VirtualKeyCode::V).pressed() {
+                if let Some(text) = self.paste_from_clipboard() {
+                    // Line breaks would submit the input, so only the printable characters are
+                    // pasted.
+                    characters.extend(text.chars().filter(|character| !character.is_control()));
+                }
+
+                process_keys = false;
+            }
+
             for character in characters {
                 match character {
                     // ignore since we need to handle tab knowing the state of shift
@@ -443,6 +1033,8 @@ impl InputSystem {
                                     size_tracker,
                                 } => interface.open_popup(element, position_tracker, size_tracker, *focused_window),
                                 ClickAction::ClosePopup => interface.close_popup(*focused_window),
+                                ClickAction::SetWindowOpacity(opacity) => interface.set_window_opacity(*focused_window, opacity),
+                                ClickAction::ToggleWindowLock => interface.toggle_window_lock(*focused_window),
                             }
                         }
                     }
@@ -450,81 +1042,120 @@ impl InputSystem {
             }
         }
 
+        if focus_state.get_focused_element().is_none() && control_down && self.get_key(VirtualKeyCode::C).pressed() {
+            // Allow copying from elements that can't be focused, like the chat.
+            let copy_text = hovered_element.as_ref().and_then(|element| element.borrow().copy_text());
+
+            if let Some(text) = copy_text {
+                self.copy_to_clipboard(text);
+                process_keys = false;
+            }
+        }
+
+        self.show_all_names = process_keys && self.action_down(InputAction::ShowAllNames);
+
         if process_keys {
-            let alt_down = self.get_key(VirtualKeyCode::LAlt).down();
-            let control_down = self.get_key(VirtualKeyCode::LControl).down();
+            let alt_down = self.alt_down();
 
             if self.get_key(VirtualKeyCode::Tab).pressed() {
                 interface.first_focused_element(focus_state);
             }
 
-            if self.get_key(VirtualKeyCode::Escape).pressed() {
-                events.push(UserEvent::OpenMenuWindow);
-            }
-
-            if alt_down && self.get_key(VirtualKeyCode::E).pressed() {
-                events.push(UserEvent::OpenInventoryWindow);
+            let window_actions = [
+                (InputAction::OpenMenu, UserEvent::OpenMenuWindow),
+                (InputAction::OpenInventory, UserEvent::OpenInventoryWindow),
+                (InputAction::OpenQuests, UserEvent::OpenQuestWindow),
+                (InputAction::OpenCommandPalette, UserEvent::OpenCommandPaletteWindow),
+                (InputAction::OpenKeyBindings, UserEvent::OpenKeyBindingsWindow),
+                (InputAction::ToggleInterface, UserEvent::ToggleShowInterface),
+                (InputAction::ToggleSitting, UserEvent::ToggleSitting),
+            ];
+
+            for (action, event) in window_actions {
+                if self.action_pressed(action) {
+                    events.push(event);
+                }
             }
 
-            if control_down && self.get_key(VirtualKeyCode::H).pressed() {
-                events.push(UserEvent::ToggleShowInterface);
+            for emotion in 0..EMOTION_COUNT as u8 {
+                if self.action_pressed(InputAction::SendEmotion(emotion)) {
+                    events.push(UserEvent::SendEmotion(emotion));
+                }
             }
 
-            if self.get_key(VirtualKeyCode::J).pressed() {
-                events.push(UserEvent::CastSkill(HotbarSlot(0)));
-            }
+            let hotbar_actions: Vec<InputAction> = match self.battle_mode {
+                true => (0..(HOTBAR_ROW_COUNT * HOTBAR_ROW_SIZE) as u16)
+                    .map(InputAction::BattleHotbar)
+                    .collect(),
+                false => (0..HOTBAR_ROW_SIZE as u16).map(InputAction::Hotbar).collect(),
+            };
 
-            if self.get_key(VirtualKeyCode::J).released() {
-                events.push(UserEvent::StopSkill(HotbarSlot(0)));
-            }
+            for action in hotbar_actions {
+                let (InputAction::Hotbar(slot) | InputAction::BattleHotbar(slot)) = action else {
+                    continue;
+                };
 
-            if self.get_key(VirtualKeyCode::L).pressed() {
-                events.push(UserEvent::CastSkill(HotbarSlot(1)));
-            }
+                if self.action_pressed(action) {
+                    events.push(UserEvent::CastSkill(HotbarSlot(slot)));
+                }
 
-            if self.get_key(VirtualKeyCode::L).released() {
-                events.push(UserEvent::StopSkill(HotbarSlot(1)));
+                if self.action_released(action) {
+                    events.push(UserEvent::StopSkill(HotbarSlot(slot)));
+                }
             }
 
-            if self.get_key(VirtualKeyCode::U).pressed() {
-                events.push(UserEvent::CastSkill(HotbarSlot(2)));
+            for index in 0..MACRO_COUNT {
+                if self.action_pressed(InputAction::Macro(index as u8)) {
+                    self.start_macro(index, notifications);
+                }
             }
 
-            if self.get_key(VirtualKeyCode::U).released() {
-                events.push(UserEvent::StopSkill(HotbarSlot(2)));
+            // Outside of battle mode, typing while nothing is focused starts writing a
+            // chat message. In battle mode the chat has to be focused with enter first.
+            if !self.battle_mode && !alt_down && !control_down && !lock_actions && focus_state.get_focused_element().is_none() {
+                let typed_characters = new_characters
+                    .iter()
+                    .copied()
+                    .filter(|character| !character.is_control())
+                    .collect::<Vec<_>>();
+
+                if !typed_characters.is_empty() {
+                    self.pending_characters = typed_characters;
+                    events.push(UserEvent::FocusChatWindow);
+                }
             }
 
-            if self.get_key(VirtualKeyCode::Return).pressed() {
+            if self.action_pressed(InputAction::FocusChat) {
                 events.push(UserEvent::FocusChatWindow);
             }
 
             #[cfg(feature = "debug")]
-            if control_down && self.get_key(VirtualKeyCode::M).pressed() {
+            if self.action_pressed(InputAction::OpenMaps) {
                 events.push(UserEvent::OpenMapsWindow);
             }
 
             #[cfg(feature = "debug")]
-            if control_down && self.get_key(VirtualKeyCode::R).pressed() {
+            if self.action_pressed(InputAction::OpenRenderSettings) {
                 events.push(UserEvent::OpenRenderSettingsWindow);
             }
 
             #[cfg(feature = "debug")]
-            if control_down && self.get_key(VirtualKeyCode::T).pressed() {
+            if self.action_pressed(InputAction::OpenTime) {
                 events.push(UserEvent::OpenTimeWindow);
             }
 
             #[cfg(feature = "debug")]
-            if control_down && self.get_key(VirtualKeyCode::P).pressed() {
+            if self.action_pressed(InputAction::OpenPackets) {
                 events.push(UserEvent::OpenPacketWindow);
             }
 
             #[cfg(feature = "debug")]
-            if self.get_key(VirtualKeyCode::LShift).pressed() && render_settings.get().use_debug_camera {
+            if self.action_pressed(InputAction::CameraAccelerate) && render_settings.get().use_debug_camera {
                 events.push(UserEvent::CameraAccelerate);
             }
 
             #[cfg(feature = "debug")]
-            if self.get_key(VirtualKeyCode::LShift).released() && render_settings.get().use_debug_camera {
+            if self.action_released(InputAction::CameraAccelerate) && render_settings.get().use_debug_camera {
                 events.push(UserEvent::CameraDecelerate);
             }
 
@@ -541,32 +1172,45 @@ impl InputSystem {
             }
 
             #[cfg(feature = "debug")]
-            if self.get_key(VirtualKeyCode::W).down() && render_settings.get().use_debug_camera {
+            if self.action_down(InputAction::CameraMoveForward) && render_settings.get().use_debug_camera {
                 events.push(UserEvent::CameraMoveForward);
             }
 
             #[cfg(feature = "debug")]
-            if self.get_key(VirtualKeyCode::S).down() && render_settings.get().use_debug_camera {
+            if self.action_down(InputAction::CameraMoveBackward) && render_settings.get().use_debug_camera {
                 events.push(UserEvent::CameraMoveBackward);
             }
 
             #[cfg(feature = "debug")]
-            if self.get_key(VirtualKeyCode::A).down() && render_settings.get().use_debug_camera {
+            if self.action_down(InputAction::CameraMoveLeft) && render_settings.get().use_debug_camera {
                 events.push(UserEvent::CameraMoveLeft);
             }
 
             #[cfg(feature = "debug")]
-            if self.get_key(VirtualKeyCode::D).down() && render_settings.get().use_debug_camera {
+            if self.action_down(InputAction::CameraMoveRight) && render_settings.get().use_debug_camera {
                 events.push(UserEvent::CameraMoveRight);
             }
 
             #[cfg(feature = "debug")]
-            if self.get_key(VirtualKeyCode::Space).down() && render_settings.get().use_debug_camera {
+            if self.action_down(InputAction::CameraMoveUp) && render_settings.get().use_debug_camera {
                 events.push(UserEvent::CameraMoveUp);
             }
         }
 
-        if window_index.is_none() && (self.mouse_input_mode.is_none() || self.mouse_input_mode.is_walk()) {
+        if !capturing_key {
+            self.gamepad_events(&mut events, notifications, lock_actions);
+        }
+
+        events.extend(self.macro_player.update(Instant::now()));
+
+        // Notifications are drawn over the world, so clicking them shouldn't move the
+        // player.
+        let clicked_notification = window_index.is_none()
+            && self.mouse_input_mode.is_none()
+            && self.left_mouse_button.pressed()
+            && notifications.click(self.new_mouse_position, &mut events);
+
+        if window_index.is_none() && !clicked_notification && (self.mouse_input_mode.is_none() || self.mouse_input_mode.is_walk()) {
             if let Some(fence) = picker_target.state.try_take_fence() {
                 fence.wait(None).unwrap();
             }
@@ -580,28 +1224,51 @@ impl InputSystem {
                 if pixel != 0 {
                     let picker_target = PickerTarget::from(pixel);
 
-                    if self.left_mouse_button.pressed() {
-                        match picker_target {
-                            PickerTarget::Entity(entity_id) => events.push(UserEvent::RequestPlayerInteract(entity_id)),
-                            PickerTarget::Tile { x, y } => {
-                                let position = Vector2::new(x as usize, y as usize);
+                    match picker_target {
+                        // While a skill is waiting for its target, the next click casts it instead of
+                        // triggering the usual action.
+                        _ if self.skill_targeting.is_some() => {
+                            if self.mouse_action_triggered(MouseAction::Interact, rotated_camera)
+                                || self.mouse_action_triggered(MouseAction::Walk, rotated_camera)
+                            {
+                                let slot = self.skill_targeting.take().unwrap();
+                                events.push(UserEvent::ConfirmSkill(slot));
+                            } else if self.mouse_action_triggered(MouseAction::ContextMenu, rotated_camera) {
+                                self.skill_targeting = None;
+                            }
+                        }
+                        // More specific actions take precedence if they share a button.
+                        PickerTarget::Entity(entity_id) => {
+                            if self.mouse_action_triggered(MouseAction::ForceAttack, rotated_camera) {
+                                events.push(UserEvent::RequestPlayerAttack(entity_id));
+                            } else if self.mouse_action_triggered(MouseAction::InspectEntity, rotated_camera) {
+                                events.push(UserEvent::InspectEntity(entity_id));
+                            } else if self.mouse_action_triggered(MouseAction::Interact, rotated_camera) {
+                                events.push(UserEvent::RequestPlayerInteract(entity_id));
+                            } else if self.mouse_action_triggered(MouseAction::ContextMenu, rotated_camera) {
+                                events.push(UserEvent::OpenEntityContextMenu(entity_id));
+                            }
+                        }
+                        PickerTarget::Tile { x, y } => {
+                            let position = Vector2::new(x as usize, y as usize);
+
+                            if self.mouse_action_triggered(MouseAction::Walk, rotated_camera) {
                                 self.mouse_input_mode = MouseInputMode::Walk(position);
 
+                                events.push(UserEvent::RequestPlayerMove(position));
+                            } else if let MouseInputMode::Walk(requested_position) = &mut self.mouse_input_mode
+                                && position != *requested_position
+                            {
+                                *requested_position = position;
+
                                 events.push(UserEvent::RequestPlayerMove(position));
                             }
-                            #[cfg(feature = "debug")]
-                            PickerTarget::Marker(marker_identifier) => events.push(UserEvent::OpenMarkerDetails(marker_identifier)),
                         }
-                    } else if self.left_mouse_button.down()
-                        && let MouseInputMode::Walk(requested_position) = &mut self.mouse_input_mode
-                        && let PickerTarget::Tile { x, y } = picker_target
-                    {
-                        let new_position = Vector2::new(x as usize, y as usize);
-
-                        if new_position != *requested_position {
-                            *requested_position = new_position;
-
-                            events.push(UserEvent::RequestPlayerMove(new_position));
+                        #[cfg(feature = "debug")]
+                        PickerTarget::Marker(marker_identifier) => {
+                            if self.left_mouse_button.pressed() {
+                                events.push(UserEvent::OpenMarkerDetails(marker_identifier));
+                            }
                         }
                     }
 
@@ -615,7 +1282,9 @@ impl InputSystem {
         // TODO: this will fail if the user hovers over an entity that changes the
         // cursor and then immediately over a different one that doesn't,
         // because main wont set the default cursor
-        if self.mouse_input_mode.is_none() && !matches!(mouse_target, Some(PickerTarget::Entity(_))) {
+        if self.skill_targeting.is_some() {
+            mouse_cursor.set_state(MouseCursorState::Attack, client_tick);
+        } else if self.mouse_input_mode.is_none() && !matches!(mouse_target, Some(PickerTarget::Entity(_))) {
             mouse_cursor.set_state(MouseCursorState::Default, client_tick);
         }
 
@@ -648,7 +1317,63 @@ impl InputSystem {
         self.new_mouse_position
     }
 
+    /// Toggle battle mode and return the new state.
+    pub fn toggle_battle_mode(&mut self) -> bool {
+        self.battle_mode = !self.battle_mode;
+        self.battle_mode
+    }
+
+    pub fn is_battle_mode(&self) -> bool {
+        self.battle_mode
+    }
+
+    pub fn show_all_names(&self) -> bool {
+        self.show_all_names
+    }
+
     pub fn get_mouse_mode(&self) -> &MouseInputMode {
         &self.mouse_input_mode
     }
+
+    pub fn is_gamepad_active(&self) -> bool {
+        self.gamepad_active
+    }
+
+    pub fn is_touch_active(&self) -> bool {
+        self.touch_active
+    }
+
+    pub fn get_macro_scripts(&self) -> Vec<String> {
+        self.macros.scripts()
+    }
+
+    pub fn set_macro(&mut self, index: usize, script: String) {
+        self.macros.set(index, script);
+    }
+
+    fn start_macro(&mut self, index: usize, notifications: &mut Notifications) {
+        if !self.macros_allowed {
+            notifications.push(NotificationKind::Warning, "Macros are disabled on this server");
+            return;
+        }
+
+        match parse_macro(self.macros.get(index)) {
+            Ok(steps) => self.macro_player.start(steps),
+            Err(message) => notifications.push(NotificationKind::Warning, format!("Macro {}: {message}", index + 1)),
+        }
+    }
+
+    /// Stops the running macro if macros are no longer allowed.
+    pub fn set_macros_allowed(&mut self, macros_allowed: bool) {
+        self.macros_allowed = macros_allowed;
+
+        if !macros_allowed {
+            self.macro_player.stop();
+        }
+    }
+
+    /// Hotbar slot selected in the radial menu, if the menu is open.
+    pub fn get_radial_menu(&self) -> Option<Option<usize>> {
+        self.radial_menu
+    }
 }
@@ -3,18 +3,15 @@ use std::sync::Arc;
 use cgmath::Vector2;
 use korangar_interface::application::MouseInputModeTrait;
 use korangar_interface::elements::{Element, ElementCell};
-use korangar_networking::InventoryItem;
 use vulkano::image::view::ImageView;
 
 use crate::interface::application::InterfaceSettings;
-use crate::interface::resource::{ItemSource, SkillSource};
-use crate::inventory::Skill;
-use crate::loaders::{Actions, AnimationState, ResourceMetadata, Sprite};
+use crate::interface::resource::PartialMove;
+use crate::loaders::{Actions, AnimationState, Sprite};
 
 #[derive(Default)]
 pub enum MouseInputMode {
-    MoveItem(ItemSource, InventoryItem<ResourceMetadata>),
-    MoveSkill(SkillSource, Skill),
+    MoveResource(PartialMove),
     MoveInterface(usize),
     ResizeInterface(usize),
     DragElement((ElementCell<InterfaceSettings>, usize)),
@@ -41,8 +38,8 @@ impl MouseInputMode {
 
     pub fn grabbed(&self) -> Option<Grabbed> {
         match self {
-            MouseInputMode::MoveItem(_, item) => Some(Grabbed::Texture(item.metadata.texture.clone())),
-            MouseInputMode::MoveSkill(_, skill) => Some(Grabbed::Action(
+            MouseInputMode::MoveResource(PartialMove::Item { item, .. }) => Some(Grabbed::Texture(item.metadata.texture.clone())),
+            MouseInputMode::MoveResource(PartialMove::Skill { skill, .. }) => Some(Grabbed::Action(
                 skill.sprite.clone(),
                 skill.actions.clone(),
                 skill.animation_state.clone(),
@@ -64,4 +61,11 @@ impl MouseInputModeTrait<InterfaceSettings> for MouseInputMode {
     fn is_moving_window(&self, window_index: usize) -> bool {
         matches!(self, Self::MoveInterface(index) if *index == window_index)
     }
+
+    fn dragged_resource(&self) -> Option<&PartialMove> {
+        match self {
+            Self::MoveResource(resource) => Some(resource),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,125 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use winit::event::MouseButton;
+
+/// A mouse button together with the modifiers that need to be held to
+/// trigger it. Unlike key bindings, all modifiers have to match exactly, so
+/// that the same button can be used for different actions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MouseBinding {
+    pub button: MouseButton,
+    #[serde(default)]
+    pub control: bool,
+    #[serde(default)]
+    pub alt: bool,
+    #[serde(default)]
+    pub shift: bool,
+}
+
+impl MouseBinding {
+    pub const fn new(button: MouseButton) -> Self {
+        Self {
+            button,
+            control: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn control(button: MouseButton) -> Self {
+        Self {
+            control: true,
+            ..Self::new(button)
+        }
+    }
+
+    pub const fn shift(button: MouseButton) -> Self {
+        Self {
+            shift: true,
+            ..Self::new(button)
+        }
+    }
+
+    pub fn modifiers_match(&self, control: bool, alt: bool, shift: bool) -> bool {
+        self.control == control && self.alt == alt && self.shift == shift
+    }
+}
+
+impl Display for MouseBinding {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.control {
+            formatter.write_str("Ctrl+")?;
+        }
+
+        if self.alt {
+            formatter.write_str("Alt+")?;
+        }
+
+        if self.shift {
+            formatter.write_str("Shift+")?;
+        }
+
+        match self.button {
+            MouseButton::Left => formatter.write_str("Left button"),
+            MouseButton::Right => formatter.write_str("Right button"),
+            MouseButton::Middle => formatter.write_str("Middle button"),
+            MouseButton::Other(index) => write!(formatter, "Button {index}"),
+        }
+    }
+}
+
+/// Everything that can be bound to a mouse button while pointing at the
+/// world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MouseAction {
+    /// Walk to the clicked tile. Holding the button keeps walking towards the
+    /// mouse.
+    Walk,
+    /// Talk to an NPC, attack a monster or use a warp.
+    Interact,
+    /// Attack the clicked entity, even if it is not a monster.
+    ForceAttack,
+    InspectEntity,
+    ContextMenu,
+    /// Rotate the camera while the button is held. Other actions on the same
+    /// button only trigger if the camera wasn't rotated.
+    RotateCamera,
+}
+
+impl MouseAction {
+    pub fn all() -> [Self; 6] {
+        [
+            Self::Walk,
+            Self::Interact,
+            Self::ForceAttack,
+            Self::InspectEntity,
+            Self::ContextMenu,
+            Self::RotateCamera,
+        ]
+    }
+
+    pub fn default_binding(self) -> Option<MouseBinding> {
+        let binding = match self {
+            Self::Walk | Self::Interact => MouseBinding::new(MouseButton::Left),
+            Self::ForceAttack => MouseBinding::shift(MouseButton::Left),
+            Self::InspectEntity => MouseBinding::control(MouseButton::Left),
+            Self::ContextMenu | Self::RotateCamera => MouseBinding::new(MouseButton::Right),
+        };
+
+        Some(binding)
+    }
+}
+
+impl Display for MouseAction {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Walk => write!(formatter, "Walk"),
+            Self::Interact => write!(formatter, "Interact"),
+            Self::ForceAttack => write!(formatter, "Force attack"),
+            Self::InspectEntity => write!(formatter, "Inspect"),
+            Self::ContextMenu => write!(formatter, "Context menu"),
+            Self::RotateCamera => write!(formatter, "Rotate camera"),
+        }
+    }
+}
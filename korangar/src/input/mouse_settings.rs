@@ -0,0 +1,96 @@
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use serde::{Deserialize, Serialize};
+
+use crate::system::SettingsFile;
+
+/// How many times faster or slower the camera rotates at the ends of the
+/// sensitivity slider.
+const SENSITIVITY_RANGE: f32 = 4.0;
+
+#[derive(Serialize, Deserialize)]
+pub struct MouseSettings {
+    /// Rotate the camera with the movement reported by the mouse itself,
+    /// which isn't affected by the pointer acceleration of the operating
+    /// system.
+    pub raw_input: bool,
+    /// Between `0.0` and `1.0`, with `0.5` rotating the camera by one unit
+    /// per pixel.
+    pub sensitivity: f32,
+    pub invert_rotation: bool,
+    /// Start walking as soon as the ground is clicked, instead of waiting for
+    /// the server to confirm the walk.
+    #[serde(default)]
+    pub walk_prediction: bool,
+}
+
+impl Default for MouseSettings {
+    fn default() -> Self {
+        Self {
+            raw_input: true,
+            sensitivity: 0.5,
+            invert_rotation: false,
+            walk_prediction: false,
+        }
+    }
+}
+
+impl MouseSettings {
+    const SECTION: &'static str = "mouse";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load mouse settings");
+
+            Default::default()
+        })
+    }
+
+    /// Scale the horizontal mouse movement to a camera rotation.
+    pub fn camera_rotation(&self, movement: f32) -> f32 {
+        scale_rotation(movement, self.sensitivity, self.invert_rotation)
+    }
+
+    pub fn load() -> Option<Self> {
+        SettingsFile::load_section(Self::SECTION)
+    }
+
+    pub fn save(&self) {
+        SettingsFile::save_section(Self::SECTION, self);
+    }
+}
+
+impl Drop for MouseSettings {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
+
+/// The sensitivity is applied exponentially, so that both ends of the slider
+/// change the rotation speed by the same factor.
+fn scale_rotation(movement: f32, sensitivity: f32, invert: bool) -> f32 {
+    let multiplier = SENSITIVITY_RANGE.powf(sensitivity * 2.0 - 1.0);
+
+    match invert {
+        true => -movement * multiplier,
+        false => movement * multiplier,
+    }
+}
+
+#[cfg(test)]
+mod sensitivity {
+    use super::scale_rotation;
+
+    fn assert_close(value: f32, expected: f32) {
+        assert!((value - expected).abs() < 1e-4, "{value} is not {expected}");
+    }
+
+    #[test]
+    fn rotation() {
+        assert_close(scale_rotation(10.0, 0.5, false), 10.0);
+        assert_close(scale_rotation(10.0, 0.5, true), -10.0);
+        assert_close(scale_rotation(10.0, 1.0, false), 40.0);
+        assert_close(scale_rotation(10.0, 0.0, false), 2.5);
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use serde::{Deserialize, Serialize};
+
+/// Prefix of commands that are executed on the player.
+const PLAYER_PREFIX: char = '@';
+/// Prefix of commands that are executed on another character, which has to be
+/// passed as the first argument.
+const CHARACTER_PREFIX: char = '#';
+
+/// Commands that are available on most rAthena and Hercules servers, used if
+/// there is no command list in the client folder.
+const DEFAULT_COMMANDS: &[(&str, &str, &str)] = &[
+    ("warp", "<map> [x] [y]", "Warp to a map"),
+    ("jump", "[x] [y]", "Jump to a position on the current map"),
+    ("go", "<town>", "Warp to a town"),
+    ("save", "", "Set the respawn point to the current position"),
+    ("return", "", "Return to the respawn point"),
+    ("where", "<character name>", "Show the position of a character"),
+    ("who", "[name]", "List online characters"),
+    ("recall", "<character name>", "Warp a character to your position"),
+    ("kick", "<character name>", "Disconnect a character"),
+    ("item", "<item name or id> [amount]", "Create an item"),
+    ("monster", "<monster name or id> [amount]", "Spawn a monster"),
+    ("killmonster", "", "Kill all monsters on the map"),
+    ("heal", "[hp] [sp]", "Restore health and spell points"),
+    ("alive", "", "Revive yourself"),
+    ("jobchange", "<job name or id>", "Change the job"),
+    ("blvl", "<levels>", "Change the base level"),
+    ("jlvl", "<levels>", "Change the job level"),
+    ("zeny", "<amount>", "Add or remove zeny"),
+    ("speed", "<speed>", "Change the walk speed"),
+    ("hide", "", "Toggle being invisible"),
+    ("storage", "", "Open the storage"),
+    ("refine", "<position> <amount>", "Refine equipped items"),
+    ("commands", "", "List all commands you can use"),
+];
+
+/// Server side `@` and `#` command with a short description of its arguments.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ServerCommand {
+    pub name: String,
+    pub arguments: String,
+    pub description: String,
+}
+
+impl ServerCommand {
+    /// Command together with its arguments, e.g. `@warp <map> [x] [y]`.
+    pub fn hint(&self, prefix: char) -> String {
+        let character = match prefix {
+            CHARACTER_PREFIX => " <character name>",
+            _ => "",
+        };
+
+        format!("{}{}{} {}", prefix, self.name, character, self.arguments)
+            .trim_end()
+            .to_owned()
+    }
+}
+
+pub struct ServerCommandList {
+    commands: Vec<ServerCommand>,
+}
+
+impl Default for ServerCommandList {
+    fn default() -> Self {
+        let commands = DEFAULT_COMMANDS
+            .iter()
+            .map(|(name, arguments, description)| ServerCommand {
+                name: name.to_string(),
+                arguments: arguments.to_string(),
+                description: description.to_string(),
+            })
+            .collect();
+
+        Self { commands }
+    }
+}
+
+impl ServerCommandList {
+    const FILE_NAME: &'static str = "client/server_commands.ron";
+
+    pub fn load() -> Self {
+        #[cfg(feature = "debug")]
+        print_debug!("loading server commands from {}", Self::FILE_NAME.magenta());
+
+        std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())
+            .map(|commands| Self { commands })
+            .unwrap_or_else(|| {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] failed to load server commands from {}; using default",
+                    "warning".yellow(),
+                    Self::FILE_NAME.magenta(),
+                );
+
+                ServerCommandList::default()
+            })
+    }
+
+    /// Split the input into the command prefix and the typed name. Commands
+    /// without a prefix are treated as player commands.
+    fn split_prefix(input: &str) -> (char, &str) {
+        match input.strip_prefix(CHARACTER_PREFIX) {
+            Some(partial) => (CHARACTER_PREFIX, partial),
+            None => (PLAYER_PREFIX, input.strip_prefix(PLAYER_PREFIX).unwrap_or(input)),
+        }
+    }
+
+    /// All commands matching the input, together with the prefix they should
+    /// be inserted with. Commands starting with the input come first, followed
+    /// by commands containing it.
+    pub fn search(&self, input: &str) -> (char, Vec<&ServerCommand>) {
+        let (prefix, partial) = Self::split_prefix(input.trim());
+        let partial = partial.split(' ').next().unwrap_or_default();
+
+        let starting = self.commands.iter().filter(|command| command.name.starts_with(partial));
+        let containing = self
+            .commands
+            .iter()
+            .filter(|command| !command.name.starts_with(partial) && command.name.contains(partial));
+
+        (prefix, starting.chain(containing).collect())
+    }
+
+    /// Complete a partially typed command. If multiple commands match, the
+    /// input is completed up to their longest common prefix.
+    pub fn complete(&self, input: &str) -> Option<String> {
+        let (prefix, partial) = Self::split_prefix(input);
+
+        if partial.contains(' ') {
+            return None;
+        }
+
+        let mut matches = self
+            .commands
+            .iter()
+            .map(|command| command.name.as_str())
+            .filter(|name| name.starts_with(partial));
+
+        let first = matches.next()?;
+        let mut common_length = first.len();
+        let mut unique = true;
+
+        for other in matches {
+            unique = false;
+            common_length = first
+                .bytes()
+                .zip(other.bytes())
+                .take(common_length)
+                .take_while(|(left, right)| left == right)
+                .count();
+        }
+
+        match unique {
+            true => Some(format!("{}{} ", prefix, first)),
+            false => Some(format!("{}{}", prefix, &first[..common_length])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod search {
+    use super::ServerCommandList;
+
+    #[test]
+    fn prefix_matches_come_first() {
+        let commands = ServerCommandList::default();
+        let (prefix, matches) = commands.search("@mon");
+
+        assert_eq!(prefix, '@');
+        assert_eq!(matches[0].name, "monster");
+        assert!(matches.iter().any(|command| command.name == "killmonster"));
+    }
+
+    #[test]
+    fn character_commands() {
+        let commands = ServerCommandList::default();
+        let (prefix, matches) = commands.search("#warp");
+
+        assert_eq!(prefix, '#');
+        assert_eq!(matches[0].hint(prefix), "#warp <character name> <map> [x] [y]");
+    }
+
+    #[test]
+    fn without_prefix() {
+        let commands = ServerCommandList::default();
+        let (prefix, matches) = commands.search("hea");
+
+        assert_eq!(prefix, '@');
+        assert_eq!(matches[0].hint(prefix), "@heal [hp] [sp]");
+    }
+}
+
+#[cfg(test)]
+mod complete {
+    use super::ServerCommandList;
+
+    #[test]
+    fn unique_match() {
+        let commands = ServerCommandList::default();
+        assert_eq!(commands.complete("@jobc"), Some("@jobchange ".to_owned()));
+        assert_eq!(commands.complete("#rec"), Some("#recall ".to_owned()));
+    }
+
+    #[test]
+    fn common_prefix() {
+        let commands = ServerCommandList::default();
+        assert_eq!(commands.complete("@s"), Some("@s".to_owned()));
+        assert_eq!(commands.complete("@st"), Some("@storage ".to_owned()));
+    }
+
+    #[test]
+    fn no_match() {
+        let commands = ServerCommandList::default();
+        assert_eq!(commands.complete("@xyz"), None);
+        assert_eq!(commands.complete("@warp prontera"), None);
+    }
+}
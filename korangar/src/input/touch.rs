@@ -0,0 +1,244 @@
+use std::time::{Duration, Instant};
+
+use cgmath::{InnerSpace, Vector2};
+use winit::event::{MouseButton, TouchPhase};
+
+/// A finger that moves less than this many pixels is still considered a tap
+/// or a long press.
+const TAP_DISTANCE: f32 = 12.0;
+
+/// Holding a finger in place for this long opens the context menu.
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// Zoom per pixel that the fingers move apart, comparable to scrolling.
+const PINCH_ZOOM_SPEED: f32 = 1.0;
+
+/// Camera rotation per radian that the fingers rotate, comparable to the mouse
+/// moving this many pixels.
+const TWO_FINGER_ROTATION_SPEED: f32 = 200.0;
+
+/// Mouse input that a touch gesture is translated to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TouchAction {
+    MoveCursor(Vector2<f32>),
+    Press(MouseButton),
+    Release(MouseButton),
+    Zoom(f32),
+    Rotate(f32),
+}
+
+struct Finger {
+    id: u64,
+    start: Vector2<f32>,
+    position: Vector2<f32>,
+}
+
+/// Translates touches to mouse input. A tap is a left click, dragging a
+/// single finger holds the left mouse button and a long press is a right
+/// click. Two fingers zoom and rotate the camera.
+#[derive(Default)]
+pub struct TouchTracker {
+    fingers: Vec<Finger>,
+    /// Time that the first finger touched the screen, if it wasn't used for a
+    /// gesture yet.
+    pressed_at: Option<Instant>,
+    left_button_down: bool,
+    /// Set once a gesture used the current touches, so lifting the fingers
+    /// doesn't cause a tap.
+    gesture_used: bool,
+    actions: Vec<TouchAction>,
+}
+
+impl TouchTracker {
+    pub fn update_touch(&mut self, id: u64, phase: TouchPhase, position: Vector2<f32>, now: Instant) {
+        match phase {
+            TouchPhase::Started => self.start_touch(id, position, now),
+            TouchPhase::Moved => self.move_touch(id, position),
+            TouchPhase::Ended => self.end_touch(id, true),
+            TouchPhase::Cancelled => self.end_touch(id, false),
+        }
+    }
+
+    fn start_touch(&mut self, id: u64, position: Vector2<f32>, now: Instant) {
+        self.fingers.push(Finger {
+            id,
+            start: position,
+            position,
+        });
+
+        match self.fingers.len() {
+            1 => {
+                self.pressed_at = Some(now);
+                self.gesture_used = false;
+                self.actions.push(TouchAction::MoveCursor(position));
+            }
+            _ => {
+                // A second finger turns a drag into a two finger gesture.
+                self.release_left_button();
+                self.pressed_at = None;
+                self.gesture_used = true;
+            }
+        }
+    }
+
+    fn move_touch(&mut self, id: u64, position: Vector2<f32>) {
+        let Some(index) = self.fingers.iter().position(|finger| finger.id == id) else {
+            return;
+        };
+
+        let previous_offset = self.finger_offset();
+        self.fingers[index].position = position;
+
+        if let Some((previous_offset, offset)) = previous_offset.zip(self.finger_offset()) {
+            let zoom = (offset.magnitude() - previous_offset.magnitude()) * PINCH_ZOOM_SPEED;
+            let rotation = previous_offset.angle(offset).0 * TWO_FINGER_ROTATION_SPEED;
+
+            if zoom != 0.0 {
+                self.actions.push(TouchAction::Zoom(zoom));
+            }
+
+            if rotation != 0.0 {
+                self.actions.push(TouchAction::Rotate(rotation));
+            }
+
+            return;
+        }
+
+        if self.fingers.len() == 1 && !self.gesture_used {
+            if !self.left_button_down && (position - self.fingers[0].start).magnitude() > TAP_DISTANCE {
+                self.pressed_at = None;
+                self.left_button_down = true;
+                self.actions.push(TouchAction::Press(MouseButton::Left));
+            }
+
+            self.actions.push(TouchAction::MoveCursor(position));
+        }
+    }
+
+    /// Offset from the first to the second finger, if exactly two fingers
+    /// touch the screen.
+    fn finger_offset(&self) -> Option<Vector2<f32>> {
+        match self.fingers.as_slice() {
+            [first, second] => Some(second.position - first.position),
+            _ => None,
+        }
+    }
+
+    fn end_touch(&mut self, id: u64, completed: bool) {
+        self.fingers.retain(|finger| finger.id != id);
+
+        if !self.fingers.is_empty() {
+            return;
+        }
+
+        if self.left_button_down {
+            self.release_left_button();
+        } else if completed && !self.gesture_used && self.pressed_at.is_some() {
+            self.click(MouseButton::Left);
+        }
+
+        self.pressed_at = None;
+    }
+
+    /// Check for long presses, since they don't cause any touch events.
+    pub fn update(&mut self, now: Instant) {
+        let long_press = self
+            .pressed_at
+            .is_some_and(|pressed_at| now.duration_since(pressed_at) >= LONG_PRESS_DURATION);
+
+        if long_press {
+            self.pressed_at = None;
+            self.gesture_used = true;
+            self.click(MouseButton::Right);
+        }
+    }
+
+    pub fn take_actions(&mut self) -> Vec<TouchAction> {
+        std::mem::take(&mut self.actions)
+    }
+
+    fn click(&mut self, button: MouseButton) {
+        self.actions.push(TouchAction::Press(button));
+        self.actions.push(TouchAction::Release(button));
+    }
+
+    fn release_left_button(&mut self) {
+        if self.left_button_down {
+            self.left_button_down = false;
+            self.actions.push(TouchAction::Release(MouseButton::Left));
+        }
+    }
+}
+
+#[cfg(test)]
+mod gestures {
+    use std::time::{Duration, Instant};
+
+    use cgmath::Vector2;
+    use winit::event::{MouseButton, TouchPhase};
+
+    use super::{TouchAction, TouchTracker};
+
+    #[test]
+    fn tap() {
+        let now = Instant::now();
+        let mut tracker = TouchTracker::default();
+
+        tracker.update_touch(0, TouchPhase::Started, Vector2::new(10.0, 10.0), now);
+        tracker.update_touch(0, TouchPhase::Ended, Vector2::new(10.0, 10.0), now);
+
+        assert_eq!(tracker.take_actions(), vec![
+            TouchAction::MoveCursor(Vector2::new(10.0, 10.0)),
+            TouchAction::Press(MouseButton::Left),
+            TouchAction::Release(MouseButton::Left),
+        ]);
+    }
+
+    #[test]
+    fn long_press() {
+        let now = Instant::now();
+        let mut tracker = TouchTracker::default();
+
+        tracker.update_touch(0, TouchPhase::Started, Vector2::new(10.0, 10.0), now);
+        tracker.take_actions();
+        tracker.update(now + Duration::from_secs(1));
+        tracker.update_touch(0, TouchPhase::Ended, Vector2::new(10.0, 10.0), now + Duration::from_secs(1));
+
+        assert_eq!(tracker.take_actions(), vec![
+            TouchAction::Press(MouseButton::Right),
+            TouchAction::Release(MouseButton::Right),
+        ]);
+    }
+
+    #[test]
+    fn drag() {
+        let now = Instant::now();
+        let mut tracker = TouchTracker::default();
+
+        tracker.update_touch(0, TouchPhase::Started, Vector2::new(10.0, 10.0), now);
+        tracker.take_actions();
+        tracker.update_touch(0, TouchPhase::Moved, Vector2::new(50.0, 10.0), now);
+        tracker.update_touch(0, TouchPhase::Ended, Vector2::new(50.0, 10.0), now);
+
+        assert_eq!(tracker.take_actions(), vec![
+            TouchAction::Press(MouseButton::Left),
+            TouchAction::MoveCursor(Vector2::new(50.0, 10.0)),
+            TouchAction::Release(MouseButton::Left),
+        ]);
+    }
+
+    #[test]
+    fn pinch() {
+        let now = Instant::now();
+        let mut tracker = TouchTracker::default();
+
+        tracker.update_touch(0, TouchPhase::Started, Vector2::new(100.0, 100.0), now);
+        tracker.update_touch(1, TouchPhase::Started, Vector2::new(200.0, 100.0), now);
+        tracker.take_actions();
+        tracker.update_touch(1, TouchPhase::Moved, Vector2::new(250.0, 100.0), now);
+        tracker.update_touch(0, TouchPhase::Ended, Vector2::new(100.0, 100.0), now);
+        tracker.update_touch(1, TouchPhase::Ended, Vector2::new(250.0, 100.0), now);
+
+        assert_eq!(tracker.take_actions(), vec![TouchAction::Zoom(50.0)]);
+    }
+}
@@ -1,17 +1,18 @@
 use std::marker::ConstParamTy;
+use std::time::Duration;
 
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{print_debug, Colorize};
+use korangar_debug::logging::print_debug;
 use korangar_interface::application::{Application, ScalingTrait};
 use korangar_interface::dimension_bound;
 use korangar_interface::elements::{Container, ElementCell, ElementWrap, PickList, PrototypeElement, Text};
 use korangar_interface::event::ClickAction;
 use korangar_interface::state::{PlainTrackedState, TrackedStateClone};
 use korangar_interface::windows::PrototypeWindow;
-use ron::ser::PrettyConfig;
 use serde::{Deserialize, Serialize};
 use walkdir::WalkDir;
 
+use super::chat::ChatTab;
 use super::elements::MutableRange;
 use super::layout::{CornerRadius, PartialScreenSize, ScreenClip, ScreenPosition, ScreenSize};
 use super::resource::{Move, PartialMove};
@@ -20,12 +21,21 @@ use super::windows::WindowCache;
 use crate::graphics::{Color, InterfaceRenderer};
 use crate::input::{MouseInputMode, UserEvent};
 use crate::loaders::{FontLoader, FontSize, Scaling};
+use crate::system::SettingsFile;
+
+/// Additional scaling while a touch screen is used, so that elements are large
+/// enough to be hit with a finger.
+const TOUCH_SCALING: f32 = 1.5;
 
 impl korangar_interface::application::ColorTrait for Color {
     fn is_transparent(&self) -> bool {
         const TRANSPARENCY_THRESHOLD: f32 = 0.999;
         self.alpha < TRANSPARENCY_THRESHOLD
     }
+
+    fn with_opacity(self, opacity: f32) -> Self {
+        self.multiply_alpha(opacity)
+    }
 }
 
 impl korangar_interface::application::SizeTrait for ScreenSize {
@@ -176,12 +186,36 @@ impl<const KIND: InternalThemeKind> PrototypeElement<InterfaceSettings> for Them
     }
 }
 
+/// Path of a named theme in the theme directory.
+fn theme_file_path(name: &str) -> Result<String, String> {
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|character| character.is_alphanumeric() || matches!(character, ' ' | '-' | '_'));
+
+    match is_valid {
+        true => Ok(format!("client/themes/{}.ron", name)),
+        false => Err(format!("invalid theme name \"{name}\"")),
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct InterfaceSettingsStorage {
     menu_theme: String,
     main_theme: String,
     game_theme: String,
     scaling: Scaling,
+    /// Size of the grid that windows snap to. Zero disables grid snapping.
+    #[serde(default)]
+    window_grid: f32,
+    /// Multiply the scaling with the scale factor of the monitor.
+    #[serde(default = "InterfaceSettingsStorage::default_automatic_scaling")]
+    automatic_scaling: bool,
+    #[serde(default = "ChatTab::default_tabs")]
+    chat_tabs: Vec<ChatTab>,
+    /// Seconds that an element needs to be hovered before showing its tooltip.
+    #[serde(default = "InterfaceSettingsStorage::default_tooltip_delay")]
+    tooltip_delay: f32,
 }
 
 impl Default for InterfaceSettingsStorage {
@@ -190,43 +224,51 @@ impl Default for InterfaceSettingsStorage {
         let menu_theme = "client/themes/menu.ron".to_string();
         let game_theme = "client/themes/game.ron".to_string();
         let scaling = Scaling::new(1.0);
+        let window_grid = 0.0;
+        let automatic_scaling = Self::default_automatic_scaling();
+        let chat_tabs = ChatTab::default_tabs();
+        let tooltip_delay = Self::default_tooltip_delay();
 
         Self {
             main_theme,
             menu_theme,
             game_theme,
             scaling,
+            window_grid,
+            automatic_scaling,
+            chat_tabs,
+            tooltip_delay,
         }
     }
 }
 
 impl InterfaceSettingsStorage {
-    const FILE_NAME: &'static str = "client/interface_settings.ron";
+    const LEGACY_FILE_NAME: &'static str = "client/interface_settings.ron";
+    const SECTION: &'static str = "interface";
+
+    fn default_automatic_scaling() -> bool {
+        true
+    }
+
+    fn default_tooltip_delay() -> f32 {
+        0.5
+    }
 
     pub fn load_or_default() -> Self {
         Self::load().unwrap_or_else(|| {
             #[cfg(feature = "debug")]
-            print_debug!("failed to load interface settings from {}", Self::FILE_NAME.magenta());
+            print_debug!("failed to load interface settings");
 
             Default::default()
         })
     }
 
     pub fn load() -> Option<Self> {
-        #[cfg(feature = "debug")]
-        print_debug!("loading interface settings from {}", Self::FILE_NAME.magenta());
-
-        std::fs::read_to_string(Self::FILE_NAME)
-            .ok()
-            .and_then(|data| ron::from_str(&data).ok())
+        SettingsFile::load_section(Self::SECTION).or_else(|| SettingsFile::load_legacy(Self::LEGACY_FILE_NAME))
     }
 
     pub fn save(&self) {
-        #[cfg(feature = "debug")]
-        print_debug!("saving interface settings to {}", Self::FILE_NAME.magenta());
-
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
-        std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
+        SettingsFile::save_section(Self::SECTION, self);
     }
 }
 
@@ -239,6 +281,19 @@ pub struct InterfaceSettings {
     #[name("Game theme")]
     pub game_theme: ThemeSelector<{ InternalThemeKind::Game }>,
     scaling: MutableRange<Scaling, korangar_interface::event::Resolve>,
+    #[name("Window grid")]
+    window_grid: MutableRange<f32, korangar_interface::event::Nothing>,
+    #[name("Tooltip delay")]
+    tooltip_delay: MutableRange<f32, korangar_interface::event::Nothing>,
+    #[hidden_element]
+    automatic_scaling: PlainTrackedState<bool>,
+    #[hidden_element]
+    chat_tabs: PlainTrackedState<Vec<ChatTab>>,
+    /// Scale factor of the monitor that the window is on.
+    #[hidden_element]
+    monitor_scaling: f32,
+    #[hidden_element]
+    touch_input: bool,
     #[hidden_element]
     themes: Themes,
 }
@@ -250,12 +305,16 @@ impl InterfaceSettings {
             main_theme,
             game_theme,
             scaling,
+            window_grid,
+            automatic_scaling,
+            chat_tabs,
+            tooltip_delay,
         } = InterfaceSettingsStorage::load_or_default();
 
         let themes = Themes::new(
             InterfaceTheme::new::<super::theme::DefaultMenu>(&menu_theme),
             InterfaceTheme::new::<super::theme::DefaultMain>(&main_theme),
-            GameTheme::new(&menu_theme),
+            GameTheme::new(&game_theme),
         );
 
         Self {
@@ -263,13 +322,45 @@ impl InterfaceSettings {
             menu_theme: ThemeSelector(menu_theme),
             game_theme: ThemeSelector(game_theme),
             scaling: MutableRange::new(scaling, Scaling::new(0.5), Scaling::new(2.5)),
+            window_grid: MutableRange::new(window_grid, 0.0, 50.0),
+            tooltip_delay: MutableRange::new(tooltip_delay, 0.0, 3.0),
+            automatic_scaling: PlainTrackedState::new(automatic_scaling),
+            chat_tabs: PlainTrackedState::new(chat_tabs),
+            monitor_scaling: 1.0,
+            touch_input: false,
             themes,
         }
     }
 
     // TODO: Remove
     pub fn get_scaling_factor(&self) -> f32 {
-        self.scaling.get().get_factor()
+        self.get_scaling().get_factor()
+    }
+
+    pub fn get_automatic_scaling(&self) -> PlainTrackedState<bool> {
+        self.automatic_scaling.clone()
+    }
+
+    pub fn get_chat_tabs(&self) -> PlainTrackedState<Vec<ChatTab>> {
+        self.chat_tabs.clone()
+    }
+
+    pub fn get_tooltip_delay(&self) -> Duration {
+        Duration::from_secs_f32(self.tooltip_delay.get())
+    }
+
+    /// Returns `true` if the scaling of the interface changed.
+    pub fn set_monitor_scaling(&mut self, monitor_scaling: f32) -> bool {
+        let changed = self.monitor_scaling != monitor_scaling;
+        self.monitor_scaling = monitor_scaling;
+        changed && self.automatic_scaling.cloned()
+    }
+
+    /// Returns `true` if the scaling of the interface changed.
+    pub fn set_touch_input(&mut self, touch_input: bool) -> bool {
+        let changed = self.touch_input != touch_input;
+        self.touch_input = touch_input;
+        changed
     }
 
     pub fn theme_window(&self) -> &dyn PrototypeWindow<InterfaceSettings> {
@@ -300,6 +391,33 @@ impl InterfaceSettings {
         }
     }
 
+    /// Save the theme to a file in the theme directory and use that file from
+    /// now on.
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn save_theme_as(&mut self, kind: InternalThemeKind, name: &str) -> Result<(), String> {
+        let theme_file = theme_file_path(name)?;
+
+        self.set_theme_file(theme_file, kind);
+        self.save_theme(kind);
+
+        Ok(())
+    }
+
+    /// Load a theme from the theme directory.
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn load_theme(&mut self, kind: InternalThemeKind, name: &str) -> Result<(), String> {
+        let theme_file = theme_file_path(name)?;
+
+        if !std::path::Path::new(&theme_file).is_file() {
+            return Err(format!("theme \"{name}\" does not exist"));
+        }
+
+        self.set_theme_file(theme_file, kind);
+        self.reload_theme(kind);
+
+        Ok(())
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn reload_theme(&mut self, kind: InternalThemeKind) {
         match kind {
@@ -330,7 +448,21 @@ impl Application for InterfaceSettings {
     type ThemeKind = InterfaceThemeKind;
 
     fn get_scaling(&self) -> Self::Scaling {
-        self.scaling.get()
+        let scaling = self.scaling.get();
+
+        let scaling = match self.automatic_scaling.cloned() {
+            true => Scaling::new(scaling.get_factor() * self.monitor_scaling),
+            false => scaling,
+        };
+
+        match self.touch_input {
+            true => Scaling::new(scaling.get_factor() * TOUCH_SCALING),
+            false => scaling,
+        }
+    }
+
+    fn get_window_grid(&self) -> f32 {
+        self.window_grid.get()
     }
 
     fn get_theme(&self, kind: &InterfaceThemeKind) -> &InterfaceTheme {
@@ -348,6 +480,10 @@ impl Drop for InterfaceSettings {
             main_theme: self.main_theme.get_file().to_owned(),
             game_theme: self.game_theme.get_file().to_owned(),
             scaling: self.scaling.get(),
+            window_grid: self.window_grid.get(),
+            automatic_scaling: self.automatic_scaling.cloned(),
+            chat_tabs: self.chat_tabs.cloned(),
+            tooltip_delay: self.tooltip_delay.get(),
         }
         .save();
     }
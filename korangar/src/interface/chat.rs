@@ -0,0 +1,608 @@
+use std::fs::File;
+use std::io::Write;
+use std::ops::Range;
+use std::str::FromStr;
+
+use chrono::Local;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use korangar_interface::state::{PlainTrackedState, TrackedState, ValueState};
+use korangar_networking::MessageColor;
+use ragnarok_packets::ItemId;
+use serde::{Deserialize, Serialize};
+
+use crate::graphics::Color;
+use crate::interface::windows::ChatMessage;
+use crate::system::SettingsFile;
+
+/// Maximum number of sent messages that can be recalled in the chat input.
+pub const INPUT_HISTORY_LENGTH: usize = 50;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatLineSpacing {
+    #[default]
+    Compact,
+    Expanded,
+}
+
+impl ChatLineSpacing {
+    /// Space between two messages.
+    pub fn gap(self) -> f32 {
+        match self {
+            Self::Compact => 0.0,
+            Self::Expanded => 6.0,
+        }
+    }
+}
+
+/// Colors that replace the theme colors of the messages in a category. `None`
+/// keeps the color from the theme.
+#[derive(Default, Serialize, Deserialize)]
+pub struct ChatColors {
+    pub public: Option<Color>,
+    pub party: Option<Color>,
+    pub guild: Option<Color>,
+    pub whisper: Option<Color>,
+    pub battle: Option<Color>,
+    pub system: Option<Color>,
+}
+
+impl ChatColors {
+    pub fn get(&self, category: ChatCategory) -> &Option<Color> {
+        match category {
+            ChatCategory::Public => &self.public,
+            ChatCategory::Party => &self.party,
+            ChatCategory::Guild => &self.guild,
+            ChatCategory::Whisper => &self.whisper,
+            ChatCategory::Battle => &self.battle,
+            ChatCategory::System => &self.system,
+        }
+    }
+
+    /// Colors that can be picked in the chat settings.
+    pub fn options() -> Vec<(&'static str, Option<Color>)> {
+        vec![
+            ("Theme", None),
+            ("White", Some(Color::rgb_u8(255, 255, 255))),
+            ("Yellow", Some(Color::rgb_u8(255, 255, 100))),
+            ("Orange", Some(Color::rgb_u8(255, 180, 80))),
+            ("Green", Some(Color::rgb_u8(130, 255, 130))),
+            ("Cyan", Some(Color::rgb_u8(100, 230, 255))),
+            ("Blue", Some(Color::rgb_u8(120, 150, 255))),
+            ("Pink", Some(Color::rgb_u8(255, 150, 220))),
+        ]
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ChatSettings {
+    /// Write all chat messages of a session to a file in
+    /// [`ChatLog::DIRECTORY`].
+    #[serde(default)]
+    pub save_chat_log: bool,
+    /// Show the time at which a message was received in front of it.
+    #[serde(default)]
+    pub show_timestamps: bool,
+    #[serde(default)]
+    pub line_spacing: ChatLineSpacing,
+    #[serde(default)]
+    pub colors: ChatColors,
+}
+
+impl ChatSettings {
+    const LEGACY_FILE_NAME: &'static str = "client/chat_settings.ron";
+    const SECTION: &'static str = "chat";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load chat settings");
+
+            Default::default()
+        })
+    }
+
+    pub fn load() -> Option<Self> {
+        SettingsFile::load_section(Self::SECTION).or_else(|| SettingsFile::load_legacy(Self::LEGACY_FILE_NAME))
+    }
+
+    pub fn save(&self) {
+        SettingsFile::save_section(Self::SECTION, self);
+    }
+}
+
+impl Drop for ChatSettings {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatCategory {
+    Public,
+    Party,
+    Guild,
+    Whisper,
+    Battle,
+    System,
+}
+
+impl ChatCategory {
+    pub fn all() -> [Self; 6] {
+        [Self::Public, Self::Party, Self::Guild, Self::Whisper, Self::Battle, Self::System]
+    }
+
+    pub fn of(message: &ChatMessage) -> Self {
+        match message.color {
+            MessageColor::Rgb { .. } | MessageColor::Broadcast => Self::Public,
+            MessageColor::Whisper => Self::Whisper,
+            MessageColor::Server | MessageColor::Error | MessageColor::Information => Self::System,
+        }
+    }
+}
+
+impl std::fmt::Display for ChatCategory {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Public => "Public",
+            Self::Party => "Party",
+            Self::Guild => "Guild",
+            Self::Whisper => "Whisper",
+            Self::Battle => "Battle",
+            Self::System => "System",
+        };
+
+        formatter.write_str(name)
+    }
+}
+
+/// A tab of the chat window that only shows messages of some categories.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatTab {
+    pub name: String,
+    pub categories: Vec<ChatCategory>,
+    /// Number of messages that were received when the tab was last viewed.
+    #[serde(skip)]
+    pub read: usize,
+}
+
+impl ChatTab {
+    pub fn new(name: impl Into<String>, categories: impl Into<Vec<ChatCategory>>) -> Self {
+        Self {
+            name: name.into(),
+            categories: categories.into(),
+            read: 0,
+        }
+    }
+
+    pub fn default_tabs() -> Vec<Self> {
+        vec![
+            Self::new("All", ChatCategory::all()),
+            Self::new("Chat", [ChatCategory::Public, ChatCategory::Party, ChatCategory::Guild]),
+            Self::new("Whisper", [ChatCategory::Whisper]),
+        ]
+    }
+
+    pub fn shows(&self, message: &ChatMessage) -> bool {
+        self.categories.contains(&ChatCategory::of(message))
+    }
+
+    pub fn toggle_category(&mut self, category: ChatCategory) {
+        match self.categories.iter().position(|shown| *shown == category) {
+            Some(index) => {
+                self.categories.remove(index);
+            }
+            None => self.categories.push(category),
+        }
+    }
+
+    /// Number of messages shown in this tab that arrived since the tab was
+    /// last viewed.
+    pub fn unread(&self, messages: &[ChatMessage]) -> usize {
+        messages
+            .get(self.read..)
+            .unwrap_or_default()
+            .iter()
+            .filter(|message| self.shows(message))
+            .count()
+    }
+}
+
+/// Mark all messages as read in the selected tab. The state is only changed
+/// if there are new messages, so this can be called every frame.
+pub fn mark_chat_tab_read(chat_tabs: &mut PlainTrackedState<Vec<ChatTab>>, selected: usize, message_count: usize) {
+    chat_tabs.with_mut(|tabs| match tabs.get_mut(selected) {
+        Some(tab) if tab.read != message_count => {
+            tab.read = message_count;
+            ValueState::Mutated(())
+        }
+        _ => ValueState::Unchanged(()),
+    });
+}
+
+/// Move a tab to a new position and return the new index of the selected
+/// tab, so that the same tab stays selected.
+pub fn move_chat_tab(tabs: &mut Vec<ChatTab>, selected: usize, source: usize, destination: usize) -> usize {
+    if source >= tabs.len() || destination >= tabs.len() {
+        return selected;
+    }
+
+    let tab = tabs.remove(source);
+    tabs.insert(destination, tab);
+
+    match selected {
+        _ if selected == source => destination,
+        _ if source < selected && selected <= destination => selected - 1,
+        _ if destination <= selected && selected < source => selected + 1,
+        _ => selected,
+    }
+}
+
+/// Writes the chat messages of the current session to disk. The file is only
+/// created once logging is enabled, and only messages received after that
+/// point are written.
+#[derive(Default)]
+pub struct ChatLog {
+    written: usize,
+    file: Option<File>,
+}
+
+impl ChatLog {
+    const DIRECTORY: &'static str = "client/chat_logs";
+
+    pub fn update(&mut self, messages: &[ChatMessage], enabled: bool) {
+        if !enabled {
+            self.file = None;
+            self.written = messages.len();
+            return;
+        }
+
+        // The message list might have been cleared since the last update.
+        self.written = self.written.min(messages.len());
+
+        if self.file.is_none() {
+            let file_name = format!("{}/{}.txt", Self::DIRECTORY, Local::now().format("%Y-%m-%d_%H-%M-%S"));
+
+            self.file = std::fs::create_dir_all(Self::DIRECTORY)
+                .and_then(|_| File::options().create(true).append(true).open(&file_name))
+                .ok();
+
+            #[cfg(feature = "debug")]
+            if self.file.is_none() {
+                print_debug!("failed to create chat log {}", file_name.magenta());
+            }
+        }
+
+        let Some(file) = &mut self.file else {
+            return;
+        };
+
+        for message in &messages[self.written..] {
+            let timestamp = message.timestamp.format("%H:%M:%S");
+
+            if writeln!(file, "[{}] {}", timestamp, strip_color_codes(&message.text)).is_err() {
+                self.file = None;
+                return;
+            }
+        }
+
+        self.written = messages.len();
+    }
+}
+
+const ITEM_LINK_START: &str = "<ITEM>";
+const ITEM_LINK_END: &str = "</ITEM>";
+/// Color code that item links are displayed in.
+const ITEM_LINK_COLOR: &str = "^55aaff";
+const MONSTER_LINK_START: &str = "<MONSTER>";
+const MONSTER_LINK_END: &str = "</MONSTER>";
+/// Color code that monster links are displayed in.
+const MONSTER_LINK_COLOR: &str = "^ff8844";
+
+/// Item embedded in a chat message. Links are sent as `<ITEM>501</ITEM>`.
+/// Once received, the local name of the item is added as
+/// `<ITEM>501:Red Potion</ITEM>` so the chat can display it without looking
+/// it up again.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ItemLink<'a> {
+    pub range: Range<usize>,
+    pub item_id: ItemId,
+    pub name: Option<&'a str>,
+}
+
+/// Monster embedded in a chat message. Links work like item links, but are
+/// sent as `<MONSTER>1002</MONSTER>`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MonsterLink<'a> {
+    pub range: Range<usize>,
+    pub monster_id: usize,
+    pub name: Option<&'a str>,
+}
+
+/// Markup to link an item in a chat message.
+pub fn item_link_markup(item_id: ItemId) -> String {
+    format!("{ITEM_LINK_START}{}{ITEM_LINK_END}", item_id.0)
+}
+
+/// Markup to link a monster in a chat message.
+pub fn monster_link_markup(monster_id: usize) -> String {
+    format!("{MONSTER_LINK_START}{monster_id}{MONSTER_LINK_END}")
+}
+
+/// Find all links between the given tags. Links with an id that can't be
+/// parsed are ignored and stay regular text.
+fn tagged_links<'a, Id: FromStr>(text: &'a str, start_tag: &str, end_tag: &str) -> Vec<(Range<usize>, Id, Option<&'a str>)> {
+    let mut links = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start) = text[offset..].find(start_tag).map(|start| start + offset) {
+        let content_start = start + start_tag.len();
+
+        let Some(content_end) = text[content_start..].find(end_tag).map(|end| end + content_start) else {
+            break;
+        };
+
+        let content = &text[content_start..content_end];
+        let (id, name) = match content.split_once(':') {
+            Some((id, name)) => (id, Some(name)),
+            None => (content, None),
+        };
+
+        match id.parse() {
+            Ok(id) => {
+                let end = content_end + end_tag.len();
+
+                links.push((start..end, id, name));
+                offset = end;
+            }
+            Err(_) => offset = content_start,
+        }
+    }
+
+    links
+}
+
+/// Find all valid item links in a message. Malformed links are ignored and
+/// stay regular text.
+pub fn item_links(text: &str) -> Vec<ItemLink<'_>> {
+    tagged_links(text, ITEM_LINK_START, ITEM_LINK_END)
+        .into_iter()
+        .map(|(range, item_id, name)| ItemLink {
+            range,
+            item_id: ItemId(item_id),
+            name,
+        })
+        .collect()
+}
+
+/// Find all valid monster links in a message.
+pub fn monster_links(text: &str) -> Vec<MonsterLink<'_>> {
+    tagged_links(text, MONSTER_LINK_START, MONSTER_LINK_END)
+        .into_iter()
+        .map(|(range, monster_id, name)| MonsterLink { range, monster_id, name })
+        .collect()
+}
+
+/// Replace parts of the text. Replacements that overlap an earlier one are
+/// skipped.
+fn replace_ranges(text: &str, mut replacements: Vec<(Range<usize>, String)>) -> String {
+    replacements.sort_by_key(|(range, _)| range.start);
+
+    let mut replaced = String::with_capacity(text.len());
+    let mut offset = 0;
+
+    for (range, replacement) in replacements {
+        if range.start < offset {
+            continue;
+        }
+
+        replaced.push_str(&text[offset..range.start]);
+        replaced.push_str(&replacement);
+        offset = range.end;
+    }
+
+    replaced.push_str(&text[offset..]);
+    replaced
+}
+
+/// Add the local item names to all item links of a received message.
+pub fn name_item_links(text: &str, mut item_name: impl FnMut(ItemId) -> String) -> String {
+    let replacements = item_links(text)
+        .into_iter()
+        .map(|link| {
+            let markup = format!("{ITEM_LINK_START}{}:{}{ITEM_LINK_END}", link.item_id.0, item_name(link.item_id));
+            (link.range, markup)
+        })
+        .collect();
+
+    replace_ranges(text, replacements)
+}
+
+/// Add the local monster names to all monster links of a received message.
+pub fn name_monster_links(text: &str, mut monster_name: impl FnMut(usize) -> String) -> String {
+    let replacements = monster_links(text)
+        .into_iter()
+        .map(|link| {
+            let markup = format!(
+                "{MONSTER_LINK_START}{}:{}{MONSTER_LINK_END}",
+                link.monster_id,
+                monster_name(link.monster_id)
+            );
+            (link.range, markup)
+        })
+        .collect();
+
+    replace_ranges(text, replacements)
+}
+
+/// Text of a message as it is displayed, with item and monster links shown as
+/// colored names.
+pub fn display_links(text: &str) -> String {
+    let item_replacements = item_links(text).into_iter().map(|link| {
+        let name = link.name.map(str::to_owned).unwrap_or_else(|| format!("Item {}", link.item_id.0));
+        (link.range, format!("{ITEM_LINK_COLOR}[{name}]^000000"))
+    });
+
+    let monster_replacements = monster_links(text).into_iter().map(|link| {
+        let name = link
+            .name
+            .map(str::to_owned)
+            .unwrap_or_else(|| format!("Monster {}", link.monster_id));
+        (link.range, format!("{MONSTER_LINK_COLOR}[{name}]^000000"))
+    });
+
+    replace_ranges(text, item_replacements.chain(monster_replacements).collect())
+}
+
+/// Remove the `^RRGGBB` color codes used by the server from a message.
+pub fn strip_color_codes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find('^') {
+        stripped.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        match rest
+            .get(1..7)
+            .is_some_and(|code| code.chars().all(|character| character.is_ascii_hexdigit()))
+        {
+            true => rest = &rest[7..],
+            false => {
+                stripped.push('^');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+#[cfg(test)]
+mod tabs {
+    use super::{move_chat_tab, ChatCategory, ChatTab};
+
+    fn tabs() -> Vec<ChatTab> {
+        ["first", "second", "third"]
+            .into_iter()
+            .map(|name| ChatTab::new(name, [ChatCategory::Public]))
+            .collect()
+    }
+
+    fn names(tabs: &[ChatTab]) -> Vec<&str> {
+        tabs.iter().map(|tab| tab.name.as_str()).collect()
+    }
+
+    #[test]
+    fn move_selected() {
+        let mut tabs = tabs();
+
+        assert_eq!(move_chat_tab(&mut tabs, 0, 0, 2), 2);
+        assert_eq!(names(&tabs), ["second", "third", "first"]);
+    }
+
+    #[test]
+    fn keep_selection() {
+        let mut tabs = tabs();
+        assert_eq!(move_chat_tab(&mut tabs, 1, 0, 2), 0);
+
+        let mut tabs = self::tabs();
+        assert_eq!(move_chat_tab(&mut tabs, 1, 2, 0), 2);
+
+        let mut tabs = self::tabs();
+        assert_eq!(move_chat_tab(&mut tabs, 0, 1, 2), 0);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut tabs = tabs();
+
+        assert_eq!(move_chat_tab(&mut tabs, 1, 0, 3), 1);
+        assert_eq!(names(&tabs), ["first", "second", "third"]);
+    }
+
+    #[test]
+    fn toggle_category() {
+        let mut tab = ChatTab::new("tab", [ChatCategory::Public]);
+
+        tab.toggle_category(ChatCategory::Party);
+        tab.toggle_category(ChatCategory::Public);
+
+        assert_eq!(tab.categories, [ChatCategory::Party]);
+    }
+}
+
+#[cfg(test)]
+mod item_links {
+    use ragnarok_packets::ItemId;
+
+    use super::{display_links, item_link_markup, item_links, monster_link_markup, monster_links, name_item_links, name_monster_links};
+
+    #[test]
+    fn parse_links() {
+        let links = item_links("buying <ITEM>501</ITEM> and <ITEM>502:Orange Potion</ITEM>");
+
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].item_id, ItemId(501));
+        assert_eq!(links[0].name, None);
+        assert_eq!(links[1].item_id, ItemId(502));
+        assert_eq!(links[1].name, Some("Orange Potion"));
+    }
+
+    #[test]
+    fn ignore_malformed_links() {
+        assert!(item_links("<ITEM>potion</ITEM> <ITEM>501").is_empty());
+        assert_eq!(item_links("<ITEM>x</ITEM><ITEM>501</ITEM>")[0].item_id, ItemId(501));
+    }
+
+    #[test]
+    fn name_links() {
+        let text = format!("selling {}", item_link_markup(ItemId(501)));
+
+        assert_eq!(
+            name_item_links(&text, |_| "Red Potion".to_owned()),
+            "selling <ITEM>501:Red Potion</ITEM>"
+        );
+    }
+
+    #[test]
+    fn display_links() {
+        assert_eq!(
+            display_links("selling <ITEM>501:Red Potion</ITEM>!"),
+            "selling ^55aaff[Red Potion]^000000!"
+        );
+    }
+
+    #[test]
+    fn monster_links_beside_item_links() {
+        let text = format!("{} drops <ITEM>909:Jellopy</ITEM>", monster_link_markup(1002));
+        let text = name_monster_links(&text, |_| "Poring".to_owned());
+
+        assert_eq!(monster_links(&text)[0].monster_id, 1002);
+        assert_eq!(monster_links(&text)[0].name, Some("Poring"));
+        assert_eq!(item_links(&text)[0].item_id, ItemId(909));
+        assert_eq!(display_links(&text), "^ff8844[Poring]^000000 drops ^55aaff[Jellopy]^000000");
+    }
+}
+
+#[cfg(test)]
+mod color_codes {
+    use super::strip_color_codes;
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(strip_color_codes("Hello world"), "Hello world");
+    }
+
+    #[test]
+    fn strip_codes() {
+        assert_eq!(strip_color_codes("^ff8800Korangar^000000 rocks"), "Korangar rocks");
+    }
+
+    #[test]
+    fn keep_invalid_codes() {
+        assert_eq!(strip_color_codes("2^3 = 8"), "2^3 = 8");
+        assert_eq!(strip_color_codes("end^"), "end^");
+    }
+}
@@ -5,6 +5,16 @@ use ragnarok_packets::EntityId;
 use super::elements::DialogElement;
 use super::windows::DialogWindow;
 
+/// Sprites of the NPCs that give access to the bank. Every Kafra employee
+/// uses one of the numbered Kafra sprites.
+const BANKER_SPRITE_PREFIXES: &[&str] = &["4_F_KAFRA"];
+
+/// Whether an NPC with the given job name gives access to the bank.
+pub fn is_banker(job_name: &str) -> bool {
+    let job_name = job_name.to_ascii_uppercase();
+    BANKER_SPRITE_PREFIXES.iter().any(|prefix| job_name.starts_with(prefix))
+}
+
 #[derive(new)]
 struct DialogHandle {
     elements: PlainTrackedState<Vec<DialogElement>>,
@@ -18,7 +28,7 @@ pub struct DialogSystem {
 
 impl DialogSystem {
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
-    pub fn open_dialog_window(&mut self, text: String, npc_id: EntityId) -> Option<DialogWindow> {
+    pub fn open_dialog_window(&mut self, text: String, npc_id: EntityId, show_bank_button: bool) -> Option<DialogWindow> {
         if let Some(dialog_handle) = &mut self.dialog_handle {
             dialog_handle.elements.mutate(|elements| {
                 if dialog_handle.clear {
@@ -31,7 +41,7 @@ impl DialogSystem {
 
             None
         } else {
-            let (window, elements) = DialogWindow::new(text, npc_id);
+            let (window, elements) = DialogWindow::new(text, npc_id, show_bank_button);
             self.dialog_handle = Some(DialogHandle::new(elements, false));
 
             Some(window)
@@ -75,3 +85,20 @@ impl DialogSystem {
         self.dialog_handle = None;
     }
 }
+
+#[cfg(test)]
+mod bankers {
+    use super::is_banker;
+
+    #[test]
+    fn kafra_employees() {
+        assert!(is_banker("4_F_KAFRA1"));
+        assert!(is_banker("4_f_kafra9"));
+    }
+
+    #[test]
+    fn other_npcs() {
+        assert!(!is_banker("4_M_MERCHANT"));
+        assert!(!is_banker("1_ETC_01"));
+    }
+}
@@ -0,0 +1,247 @@
+use std::cell::RefCell;
+use std::rc::Weak;
+
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Text,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_networking::AchievementSummary;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ProgressBar;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::Achievement;
+use crate::loaders::ACHIEVEMENT_CATEGORIES;
+
+pub struct AchievementView {
+    achievements: PlainRemote<Vec<Achievement>>,
+    summary: PlainRemote<AchievementSummary>,
+    title_id: PlainRemote<Option<u32>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl AchievementView {
+    pub fn new(
+        achievements: PlainRemote<Vec<Achievement>>,
+        summary: PlainRemote<AchievementSummary>,
+        title_id: PlainRemote<Option<u32>>,
+    ) -> Self {
+        let elements = {
+            let achievements = achievements.get();
+            let summary = summary.get();
+
+            let title = match *title_id.get() {
+                Some(title_id) => format!("Title {title_id}"),
+                None => "No title".to_owned(),
+            };
+
+            let mut elements = vec![
+                Text::default()
+                    .with_text(format!("Level {} ({} points)", summary.level, summary.total_score))
+                    .wrap(),
+                ProgressBar::new("Experience".to_owned(), summary.experience, summary.experience_to_next_level).wrap(),
+                Text::default().with_text(title).wrap(),
+            ];
+
+            for category in ACHIEVEMENT_CATEGORIES {
+                let category_achievements: Vec<&Achievement> = achievements
+                    .iter()
+                    .filter(|achievement| achievement.metadata.category == category)
+                    .collect();
+
+                if category_achievements.is_empty() {
+                    continue;
+                }
+
+                let completed_count = category_achievements
+                    .iter()
+                    .filter(|achievement| achievement.progress.completed)
+                    .count();
+                let display = format!("{} ({}/{})", category, completed_count, category_achievements.len());
+                let category_elements = category_achievements.into_iter().map(Self::achievement_to_element).collect();
+
+                elements.push(Expandable::new(display, category_elements, false).wrap());
+            }
+
+            elements
+        };
+
+        Self {
+            achievements,
+            summary,
+            title_id,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn achievement_to_element(achievement: &Achievement) -> ElementCell<InterfaceSettings> {
+        let metadata = &achievement.metadata;
+        let progress = &achievement.progress;
+        let mut elements = Vec::new();
+
+        if !metadata.summary.is_empty() {
+            elements.push(Text::default().with_text(metadata.summary.clone()).wrap());
+        }
+
+        for (index, (text, count)) in metadata.objectives.iter().enumerate() {
+            // The server does not always report the full count for completed objectives,
+            // so the bar is filled manually.
+            let current = match progress.completed {
+                true => *count,
+                false => progress.objectives.get(index).copied().unwrap_or_default(),
+            };
+
+            elements.push(ProgressBar::new(text.clone(), current, *count).wrap());
+        }
+
+        let status = match (progress.completed, progress.rewarded) {
+            (false, _) => "In progress",
+            (true, false) => "Completed",
+            (true, true) => "Completed and rewarded",
+        };
+
+        elements.push(Text::default().with_text(format!("{} ({} points)", status, metadata.score)).wrap());
+
+        if progress.completed && !progress.rewarded {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Claim reward")
+                    .with_event(UserEvent::RequestAchievementReward(progress.achievement_id))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        if let Some(title_id) = metadata.title_id
+            && progress.rewarded
+        {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Use title")
+                    .with_event(UserEvent::ChangeTitle(title_id))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        let display = match progress.completed {
+            true => format!("{} (done)", metadata.name),
+            false => metadata.name.clone(),
+        };
+
+        Expandable::new(display, elements, false).wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for AchievementView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let achievements_changed = self.achievements.consume_changed();
+        let summary_changed = self.summary.consume_changed();
+        let title_changed = self.title_id.consume_changed();
+
+        if achievements_changed || summary_changed || title_changed {
+            let weak_self = self.state.state.self_element.take().unwrap();
+            let weak_parent = self.state.state.parent_element.clone();
+
+            *self = Self::new(self.achievements.clone(), self.summary.clone(), self.title_id.clone());
+
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.state.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
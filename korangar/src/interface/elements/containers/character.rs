@@ -1,5 +1,7 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Weak;
+use std::time::Instant;
 
 use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::{ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text};
@@ -7,20 +9,53 @@ use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::state::{PlainRemote, Remote};
 use korangar_interface::{dimension_bound, size_bound};
-use ragnarok_packets::CharacterInformation;
+use ragnarok_packets::{CharacterId, CharacterInformation};
 
 use crate::graphics::{Color, InterfaceRenderer, Renderer};
 use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::Countdown;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::privacy::{display_name, PrivacySettings};
 use crate::interface::theme::InterfaceTheme;
 use crate::loaders::FontSize;
 
+/// Number of character slots on a single page of the character selection.
+/// This matches the page size that the character server uses.
+pub const SLOTS_PER_PAGE: usize = 3;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CharacterSlots {
+    /// Slots that are available to every account.
+    pub normal: usize,
+    /// Additional slots of VIP and billing accounts.
+    pub premium: usize,
+    /// Number of pages reported by the character server.
+    pub pages: usize,
+}
+
+impl CharacterSlots {
+    pub fn total(&self) -> usize {
+        self.normal + self.premium
+    }
+
+    pub fn page_count(&self) -> usize {
+        match self.pages {
+            0 => self.total().div_ceil(SLOTS_PER_PAGE).max(1),
+            pages => pages,
+        }
+    }
+}
+
 // TODO: rework all of this
 pub struct CharacterPreview {
     characters: PlainRemote<Vec<CharacterInformation>>,
     move_request: PlainRemote<Option<usize>>,
-    slot: usize,
+    deletion_dates: PlainRemote<HashMap<CharacterId, Instant>>,
+    privacy_settings: PlainRemote<PrivacySettings>,
+    page: PlainRemote<usize>,
+    slots: CharacterSlots,
+    index: usize,
     state: ContainerState<InterfaceSettings>,
 }
 
@@ -28,29 +63,84 @@ impl CharacterPreview {
     fn get_elements(
         characters: &PlainRemote<Vec<CharacterInformation>>,
         move_request: &PlainRemote<Option<usize>>,
+        deletion_dates: &PlainRemote<HashMap<CharacterId, Instant>>,
+        privacy_settings: &PlainRemote<PrivacySettings>,
+        slots: CharacterSlots,
         slot: usize,
     ) -> Vec<ElementCell<InterfaceSettings>> {
+        if slot >= slots.total() {
+            return vec![Text::default()
+                .with_text("Unavailable")
+                .with_foreground_color(|_| Color::rgb_u8(140, 140, 140))
+                .wrap()];
+        }
+
+        let mut elements = Vec::new();
+
+        if slot >= slots.normal {
+            elements.push(
+                Text::default()
+                    .with_text("Premium slot")
+                    .with_foreground_color(|_| Color::rgb_u8(220, 190, 100))
+                    .wrap(),
+            );
+        }
+
         if let Some(origin_slot) = *move_request.get() {
             let text = match origin_slot == slot {
                 true => "Click to cancel",
                 false => "Switch",
             };
 
-            return vec![
+            elements.push(
                 Text::default()
                     .with_text(text.to_owned())
                     .with_foreground_color(|_| Color::rgb_u8(200, 140, 180))
                     .wrap(),
-            ];
+            );
+
+            return elements;
         }
 
         let characters = characters.get();
         let character_information = characters.iter().find(|character| character.character_number as usize == slot);
 
         if let Some(character_information) = character_information {
-            return vec![
+            let character_id = character_information.character_id;
+            let name = display_name(&character_information.name, privacy_settings.get().streamer_mode).into_owned();
+
+            if let Some(deletion_date) = deletion_dates.get().get(&character_id).copied() {
+                elements.extend([
+                    Text::default()
+                        .with_text(name)
+                        .with_foreground_color(|_| Color::rgb_u8(220, 210, 210))
+                        .with_font_size(|_| FontSize::new(18.0))
+                        .wrap(),
+                    Countdown::new("Deleted in".to_owned(), deletion_date).wrap(),
+                    ButtonBuilder::new()
+                        .with_text("Keep")
+                        .with_event(UserEvent::CancelCharacterDeletion(character_id))
+                        .with_background_color(|_| Color::rgb_u8(161, 141, 141))
+                        .with_width_bound(dimension_bound!(50%))
+                        .build()
+                        .wrap(),
+                    ButtonBuilder::new()
+                        .with_text("Delete")
+                        .with_disabled_selector(move || Instant::now() >= deletion_date)
+                        .with_event(UserEvent::OpenCharacterDeletionWindow(character_id))
+                        .with_background_color(|theme: &InterfaceTheme| theme.close_button.background_color.get())
+                        .with_foreground_color(|theme: &InterfaceTheme| theme.close_button.foreground_color.get())
+                        .with_width_bound(dimension_bound!(50%))
+                        .build()
+                        .wrap(),
+                ]);
+
+                return elements;
+            }
+
+            elements.extend([
                 Text::default()
-                    .with_text(character_information.name.clone())
+                    .with_text(name)
                     .with_foreground_color(|_| Color::rgb_u8(220, 210, 210))
                     .with_font_size(|_| FontSize::new(18.0))
                     .wrap(),
@@ -61,39 +151,71 @@ impl CharacterPreview {
                     .with_width_bound(dimension_bound!(50%))
                     .build()
                     .wrap(),
+                ButtonBuilder::new()
+                    .with_text("Rename")
+                    .with_event(UserEvent::OpenCharacterRenameWindow(character_id))
+                    .with_background_color(|_| Color::rgb_u8(161, 141, 141))
+                    .with_width_bound(dimension_bound!(50%))
+                    .build()
+                    .wrap(),
                 ButtonBuilder::new()
                     .with_text("Delete")
-                    .with_event(UserEvent::DeleteCharacter(character_information.character_id))
+                    .with_event(UserEvent::DeleteCharacter(character_id))
                     .with_background_color(|theme: &InterfaceTheme| theme.close_button.background_color.get())
                     .with_foreground_color(|theme: &InterfaceTheme| theme.close_button.foreground_color.get())
-                    .with_width_bound(dimension_bound!(50%))
                     .build()
                     .wrap(),
-            ];
+            ]);
+
+            return elements;
         }
 
-        vec![
+        elements.push(
             Text::default()
                 .with_text("New character")
                 .with_foreground_color(|_| Color::rgb_u8(200, 140, 180))
                 .wrap(),
-        ]
+        );
+
+        elements
     }
 
-    pub fn new(characters: PlainRemote<Vec<CharacterInformation>>, move_request: PlainRemote<Option<usize>>, slot: usize) -> Self {
-        let elements = Self::get_elements(&characters, &move_request, slot);
+    pub fn new(
+        characters: PlainRemote<Vec<CharacterInformation>>,
+        move_request: PlainRemote<Option<usize>>,
+        deletion_dates: PlainRemote<HashMap<CharacterId, Instant>>,
+        privacy_settings: PlainRemote<PrivacySettings>,
+        page: PlainRemote<usize>,
+        slots: CharacterSlots,
+        index: usize,
+    ) -> Self {
+        let slot = *page.get() * SLOTS_PER_PAGE + index;
+        let elements = Self::get_elements(&characters, &move_request, &deletion_dates, &privacy_settings, slots, slot);
         let state = ContainerState::new(elements);
 
         Self {
             characters,
             move_request,
-            slot,
+            deletion_dates,
+            privacy_settings,
+            page,
+            slots,
+            index,
             state,
         }
     }
 
+    fn get_slot(&self) -> usize {
+        *self.page.get() * SLOTS_PER_PAGE + self.index
+    }
+
     fn has_character(&self) -> bool {
-        self.state.elements.len() > 1 // TODO:
+        let slot = self.get_slot();
+
+        self.characters
+            .get()
+            .iter()
+            .any(|character| character.character_number as usize == slot)
     }
 }
 
@@ -133,7 +255,7 @@ impl Element<InterfaceSettings> for CharacterPreview {
         application: &InterfaceSettings,
         theme: &InterfaceTheme,
     ) {
-        let size_bound = &size_bound!(20%, 150);
+        let size_bound = &size_bound!(33%, 150);
         self.state
             .resolve(placement_resolver, application, theme, size_bound, ScreenSize::uniform(4.0));
     }
@@ -141,12 +263,23 @@ impl Element<InterfaceSettings> for CharacterPreview {
     fn update(&mut self) -> Option<ChangeEvent> {
         let characters_changed = self.characters.consume_changed();
         let move_request_changed = self.move_request.consume_changed();
+        let deletion_dates_changed = self.deletion_dates.consume_changed();
+        let privacy_settings_changed = self.privacy_settings.consume_changed();
+        let page_changed = self.page.consume_changed();
 
-        if characters_changed || move_request_changed {
+        if characters_changed || move_request_changed || deletion_dates_changed || privacy_settings_changed || page_changed {
             let weak_self = self.state.state.self_element.take().unwrap();
             let weak_parent = self.state.state.parent_element.clone();
 
-            *self = Self::new(self.characters.clone(), self.move_request.clone(), self.slot);
+            *self = Self::new(
+                self.characters.clone(),
+                self.move_request.clone(),
+                self.deletion_dates.clone(),
+                self.privacy_settings.clone(),
+                self.page.clone(),
+                self.slots,
+                self.index,
+            );
 
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
@@ -155,22 +288,28 @@ impl Element<InterfaceSettings> for CharacterPreview {
             return Some(ChangeEvent::RESOLVE_WINDOW);
         }
 
-        None
+        self.state.update()
     }
 
     fn left_click(&mut self, _update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        let slot = self.get_slot();
+
+        if slot >= self.slots.total() {
+            return Vec::new();
+        }
+
         if let Some(origin_slot) = *self.move_request.get() {
-            let event = match origin_slot == self.slot {
+            let event = match origin_slot == slot {
                 true => UserEvent::CancelSwitchCharacterSlot,
-                false => UserEvent::SwitchCharacterSlot(self.slot),
+                false => UserEvent::SwitchCharacterSlot(slot),
             };
 
             return vec![ClickAction::Custom(event)];
         }
 
         let event = match self.has_character() {
-            true => UserEvent::SelectCharacter(self.slot),
-            false => UserEvent::OpenCharacterCreationWindow(self.slot),
+            true => UserEvent::SelectCharacter(slot),
+            false => UserEvent::OpenCharacterCreationWindow(slot),
         };
 
         vec![ClickAction::Custom(event)]
@@ -0,0 +1,312 @@
+use std::cell::RefCell;
+use std::rc::Weak;
+
+use korangar_interface::elements::{ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::chat::{ChatCategory, ChatTab};
+use crate::interface::elements::ChatTabButton;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::ChatMessage;
+
+/// Row of chat tabs with the number of unread messages of each tab.
+pub struct ChatTabBar {
+    chat_tabs: PlainRemote<Vec<ChatTab>>,
+    selected: PlainTrackedState<usize>,
+    selected_remote: PlainRemote<usize>,
+    messages: PlainRemote<Vec<ChatMessage>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl ChatTabBar {
+    pub fn new(chat_tabs: PlainRemote<Vec<ChatTab>>, selected: PlainTrackedState<usize>, messages: PlainRemote<Vec<ChatMessage>>) -> Self {
+        let elements = {
+            let messages = messages.get();
+
+            chat_tabs
+                .get()
+                .iter()
+                .enumerate()
+                .map(|(index, tab)| ChatTabButton::new(index, &tab.name, tab.unread(&messages), selected.clone()).wrap())
+                .collect()
+        };
+
+        let selected_remote = selected.new_remote();
+
+        Self {
+            chat_tabs,
+            selected,
+            selected_remote,
+            messages,
+            state: ContainerState::new(elements),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for ChatTabBar {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let chat_tabs_changed = self.chat_tabs.consume_changed();
+        let selected_changed = self.selected_remote.consume_changed();
+        let messages_changed = self.messages.consume_changed();
+
+        if chat_tabs_changed || selected_changed || messages_changed {
+            let weak_self = self.state.state.self_element.take().unwrap();
+            let weak_parent = self.state.state.parent_element.clone();
+
+            *self = Self::new(self.chat_tabs.clone(), self.selected.clone(), self.messages.clone());
+
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.state.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None | MouseInputMode::MoveResource(..) => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+/// Editable list of all chat tabs and the message categories they show.
+pub struct ChatTabsView {
+    chat_tabs: PlainRemote<Vec<ChatTab>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl ChatTabsView {
+    pub fn new(chat_tabs: PlainRemote<Vec<ChatTab>>) -> Self {
+        let elements = {
+            let mut elements = Vec::new();
+
+            for (index, tab) in chat_tabs.get().iter().enumerate() {
+                elements.push(Text::default().with_text(tab.name.clone()).with_width(dimension_bound!(70%)).wrap());
+                elements.push(
+                    ButtonBuilder::new()
+                        .with_text("Remove")
+                        .with_event(UserEvent::RemoveChatTab(index))
+                        .with_width_bound(dimension_bound!(!))
+                        .build()
+                        .wrap(),
+                );
+
+                for category in ChatCategory::all() {
+                    let button = ButtonBuilder::new()
+                        .with_text(category.to_string())
+                        .with_event(UserEvent::ToggleChatTabCategory { tab: index, category })
+                        .with_width_bound(dimension_bound!(33%));
+
+                    // Highlight the categories that are shown in the tab.
+                    let button = match tab.categories.contains(&category) {
+                        true => button
+                            .with_foreground_color(|theme: &InterfaceTheme| theme.chat.information_color.get())
+                            .build()
+                            .wrap(),
+                        false => button.build().wrap(),
+                    };
+
+                    elements.push(button);
+                }
+            }
+
+            elements
+        };
+
+        Self {
+            chat_tabs,
+            state: ContainerState::new(elements),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for ChatTabsView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.chat_tabs.consume_changed() {
+            let weak_self = self.state.state.self_element.take().unwrap();
+            let weak_parent = self.state.state.parent_element.clone();
+
+            *self = Self::new(self.chat_tabs.clone());
+
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.state.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
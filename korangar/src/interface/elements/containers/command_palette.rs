@@ -0,0 +1,169 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use korangar_interface::elements::{ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, ServerCommandList, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+
+/// Maximum number of commands listed at once.
+const MAXIMUM_RESULTS: usize = 8;
+
+/// List of server commands matching the input of the command palette.
+pub struct CommandPaletteView {
+    commands: Rc<ServerCommandList>,
+    input: PlainRemote<String>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl CommandPaletteView {
+    pub fn new(commands: Rc<ServerCommandList>, input: PlainRemote<String>) -> Self {
+        let elements = {
+            let (prefix, matches) = commands.search(&input.get());
+
+            let mut elements: Vec<ElementCell<InterfaceSettings>> = matches
+                .into_iter()
+                .take(MAXIMUM_RESULTS)
+                .flat_map(|command| {
+                    let text = format!("{}{} ", prefix, command.name);
+
+                    [
+                        ButtonBuilder::new()
+                            .with_text(command.hint(prefix))
+                            .with_event(move || {
+                                vec![
+                                    ClickAction::Custom(UserEvent::InsertChatText(text.clone())),
+                                    ClickAction::CloseWindow,
+                                ]
+                            })
+                            .build()
+                            .wrap(),
+                        Text::default().with_text(command.description.clone()).wrap(),
+                    ]
+                })
+                .collect();
+
+            if elements.is_empty() {
+                elements.push(Text::default().with_text("No matching commands").wrap());
+            }
+
+            elements
+        };
+
+        Self {
+            commands,
+            input,
+            state: ContainerState::new(elements),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for CommandPaletteView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.input.consume_changed() {
+            let weak_self = self.state.state.self_element.take().unwrap();
+            let weak_parent = self.state.state.parent_element.clone();
+
+            *self = Self::new(self.commands.clone(), self.input.clone());
+
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.state.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -13,7 +13,7 @@ use crate::input::MouseInputMode;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::ItemBox;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
-use crate::interface::resource::ItemSource;
+use crate::interface::resource::{ItemSource, PartialMove};
 use crate::interface::theme::InterfaceTheme;
 use crate::loaders::ResourceMetadata;
 
@@ -89,9 +89,13 @@ impl EquipmentContainer {
                     let item_box = ItemBox::new(
                         item,
                         ItemSource::Equipment { position: slot },
-                        Box::new(
-                            move |mouse_mode| matches!(mouse_mode, MouseInputMode::MoveItem(_, InventoryItem { details: InventoryItemDetails::Equippable { equip_position, .. }, ..}) if equip_position.contains(slot)),
-                        ),
+                        Box::new(move |drop_resource| match drop_resource {
+                            PartialMove::Item { item, .. } => match &item.details {
+                                InventoryItemDetails::Equippable { equip_position, .. } => equip_position.contains(slot),
+                                _ => false,
+                            },
+                            _ => false,
+                        }),
                     );
 
                     Container::new(vec![item_box.wrap(), text]).wrap()
@@ -164,7 +168,7 @@ impl Element<InterfaceSettings> for EquipmentContainer {
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match mouse_mode {
-            MouseInputMode::MoveItem(..) | MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            MouseInputMode::MoveResource(..) | MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
             _ => HoverInformation::Missed,
         }
     }
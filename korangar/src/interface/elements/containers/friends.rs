@@ -13,22 +13,25 @@ use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::linked::LinkedElement;
+use crate::interface::privacy::{display_name, PrivacySettings};
 use crate::interface::theme::InterfaceTheme;
 
 pub struct FriendView {
     friends: PlainRemote<Vec<(Friend, LinkedElement)>>,
+    privacy_settings: PlainRemote<PrivacySettings>,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl FriendView {
-    pub fn new(friends: PlainRemote<Vec<(Friend, LinkedElement)>>) -> Self {
+    pub fn new(friends: PlainRemote<Vec<(Friend, LinkedElement)>>, privacy_settings: PlainRemote<PrivacySettings>) -> Self {
         let elements = {
             let friends = friends.get();
+            let streamer_mode = privacy_settings.get().streamer_mode;
 
             friends
                 .iter()
                 .map(|(friend, linked_element)| {
-                    let element = Self::friend_to_element(friend);
+                    let element = Self::friend_to_element(friend, streamer_mode);
                     linked_element.link(&element);
                     element
                 })
@@ -37,23 +40,22 @@ impl FriendView {
 
         Self {
             friends,
+            privacy_settings,
             state: ContainerState::new(elements),
         }
     }
 
-    fn friend_to_element(friend: &Friend) -> ElementCell<InterfaceSettings> {
-        let elements = vec![
-            ButtonBuilder::new()
-                .with_text("remove")
-                .with_event(UserEvent::RemoveFriend {
-                    account_id: friend.account_id,
-                    character_id: friend.character_id,
-                })
-                .build()
-                .wrap(),
-        ];
-
-        Expandable::new(friend.name.clone(), elements, false).wrap()
+    fn friend_to_element(friend: &Friend, streamer_mode: bool) -> ElementCell<InterfaceSettings> {
+        let elements = vec![ButtonBuilder::new()
+            .with_text("remove")
+            .with_event(UserEvent::RemoveFriend {
+                account_id: friend.account_id,
+                character_id: friend.character_id,
+            })
+            .build()
+            .wrap()];
+
+        Expandable::new(display_name(&friend.name, streamer_mode).into_owned(), elements, false).wrap()
     }
 }
 
@@ -109,6 +111,8 @@ impl Element<InterfaceSettings> for FriendView {
     fn update(&mut self) -> Option<ChangeEvent> {
         let mut resolve = false;
 
+        let streamer_mode = self.privacy_settings.get().streamer_mode;
+
         if self.friends.consume_changed() {
             // Remove elements of old friends from the start of the list and add new friends
             // to the list.
@@ -118,7 +122,7 @@ impl Element<InterfaceSettings> for FriendView {
                         self.state.elements.remove(index);
                     }
                 } else {
-                    let element = Self::friend_to_element(friend);
+                    let element = Self::friend_to_element(friend, streamer_mode);
                     let weak_self = self.state.state.self_element.clone();
 
                     linked_element.link(&element);
@@ -138,6 +142,26 @@ impl Element<InterfaceSettings> for FriendView {
             }
         }
 
+        // All names change when toggling streamer mode, so every element is replaced.
+        if self.privacy_settings.consume_changed() {
+            let weak_self = self.state.state.self_element.clone();
+
+            self.state.elements = self
+                .friends
+                .get()
+                .iter()
+                .map(|(friend, linked_element)| {
+                    let element = Self::friend_to_element(friend, streamer_mode);
+
+                    linked_element.link(&element);
+                    element.borrow_mut().link_back(Rc::downgrade(&element), weak_self.clone());
+
+                    element
+                })
+                .collect();
+            resolve = true;
+        }
+
         match resolve {
             true => Some(ChangeEvent::RESOLVE_WINDOW),
             false => None,
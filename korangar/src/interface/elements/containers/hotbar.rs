@@ -1,9 +1,8 @@
-use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell};
+use korangar_interface::elements::{Container, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell};
 use korangar_interface::event::{ChangeEvent, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote};
-use ragnarok_packets::HotbarSlot;
 
 use crate::graphics::{InterfaceRenderer, Renderer};
 use crate::input::MouseInputMode;
@@ -12,40 +11,48 @@ use crate::interface::elements::SkillBox;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::SkillSource;
 use crate::interface::theme::InterfaceTheme;
-use crate::inventory::Skill;
+use crate::inventory::{hotbar_slot, Skill, HOTBAR_ROW_SIZE, HOTBAR_SLOT_COUNT};
 
 pub struct HotbarContainer {
-    skills: PlainRemote<[Option<Skill>; 10]>,
+    skills: PlainRemote<[Option<Skill>; HOTBAR_SLOT_COUNT]>,
+    smart_cast: PlainRemote<[bool; HOTBAR_SLOT_COUNT]>,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl HotbarContainer {
-    pub fn new(skills: PlainRemote<[Option<Skill>; 10]>) -> Self {
+    pub fn new(skills: PlainRemote<[Option<Skill>; HOTBAR_SLOT_COUNT]>, smart_cast: PlainRemote<[bool; HOTBAR_SLOT_COUNT]>) -> Self {
         let elements = {
             let skills = skills.get();
+            let smart_cast = smart_cast.get();
 
+            // Every row of the hotbar is put in its own container, so the rows stay
+            // aligned regardless of the window width.
             skills
-                .iter()
-                .cloned()
+                .chunks(HOTBAR_ROW_SIZE)
                 .enumerate()
-                .map(|(slot, skill)| {
-                    let skill_source = SkillSource::Hotbar {
-                        slot: HotbarSlot(slot as u16),
-                    };
-                    let skill_box = SkillBox::new(
-                        skill,
-                        skill_source,
-                        Box::new(move |mouse_mode| matches!(mouse_mode, MouseInputMode::MoveSkill(source, _) if *source != skill_source)),
-                    );
-
-                    skill_box.wrap()
+                .map(|(row, row_skills)| {
+                    let row_elements = row_skills
+                        .iter()
+                        .cloned()
+                        .enumerate()
+                        .map(|(column, skill)| {
+                            let slot = hotbar_slot(row, column);
+                            let skill_source = SkillSource::Hotbar { slot };
+                            let skill_box =
+                                SkillBox::new(skill, skill_source, Box::new(|_| true)).with_smart_cast(smart_cast[slot.0 as usize]);
+
+                            skill_box.wrap()
+                        })
+                        .collect();
+
+                    Container::new(row_elements).wrap()
                 })
                 .collect()
         };
 
         let state = ContainerState::new(elements);
 
-        Self { skills, state }
+        Self { skills, smart_cast, state }
     }
 }
 
@@ -91,11 +98,14 @@ impl Element<InterfaceSettings> for HotbarContainer {
     }
 
     fn update(&mut self) -> Option<ChangeEvent> {
-        if self.skills.consume_changed() {
+        let skills_changed = self.skills.consume_changed();
+        let smart_cast_changed = self.smart_cast.consume_changed();
+
+        if skills_changed || smart_cast_changed {
             let weak_self = self.state.state.self_element.take().unwrap();
             let weak_parent = self.state.state.parent_element.take();
 
-            *self = Self::new(self.skills.clone());
+            *self = Self::new(self.skills.clone(), self.smart_cast.clone());
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
             self.link_back(weak_self, weak_parent);
@@ -108,7 +118,7 @@ impl Element<InterfaceSettings> for HotbarContainer {
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match mouse_mode {
-            MouseInputMode::MoveSkill(..) | MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            MouseInputMode::MoveResource(..) | MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
             _ => HoverInformation::Missed,
         }
     }
@@ -96,18 +96,26 @@ impl Element<InterfaceSettings> for InventoryContainer {
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match mouse_mode {
-            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::MoveResource(PartialMove::Item { .. }) => self.state.state.hovered_element(mouse_position),
             MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
             _ => HoverInformation::Missed,
         }
     }
 
+    fn accepts_drop(&self, drop_resource: &PartialMove) -> bool {
+        matches!(drop_resource, PartialMove::Item { source, .. } if *source != ItemSource::Inventory)
+    }
+
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        if !self.accepts_drop(&drop_resource) {
+            return None;
+        }
+
         let PartialMove::Item { source, item } = drop_resource else {
             return None;
         };
 
-        (source != ItemSource::Inventory).then_some(Move::Item {
+        Some(Move::Item {
             source,
             destination: ItemSource::Inventory,
             item,
@@ -142,7 +150,7 @@ impl Element<InterfaceSettings> for InventoryContainer {
             second_theme,
         );
 
-        if matches!(mouse_mode, MouseInputMode::MoveItem(..)) {
+        if self.is_drop_target(mouse_mode) {
             match self.is_element_self(hovered_element) {
                 true => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(60, 160, 160, 160)),
                 false => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(160, 160, 60, 160)),
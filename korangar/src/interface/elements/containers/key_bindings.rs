@@ -0,0 +1,212 @@
+use std::cell::RefCell;
+use std::rc::Weak;
+
+use korangar_interface::elements::{ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{InputAction, KeyBindings, MouseAction, MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+
+pub struct KeyBindingView {
+    key_bindings: PlainRemote<KeyBindings>,
+    key_capture: PlainRemote<Option<InputAction>>,
+    mouse_capture: PlainRemote<Option<MouseAction>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl KeyBindingView {
+    pub fn new(
+        key_bindings: PlainRemote<KeyBindings>,
+        key_capture: PlainRemote<Option<InputAction>>,
+        mouse_capture: PlainRemote<Option<MouseAction>>,
+    ) -> Self {
+        let elements = {
+            let key_bindings = key_bindings.get();
+            let key_capture = *key_capture.get();
+            let mouse_capture = *mouse_capture.get();
+            let mut elements = vec![Text::default().with_text("Mouse").wrap()];
+
+            for action in MouseAction::all() {
+                let binding = match (mouse_capture == Some(action), key_bindings.get_mouse(action)) {
+                    (true, _) => "Click a button".to_owned(),
+                    (false, Some(binding)) => binding.to_string(),
+                    (false, None) => "Unbound".to_owned(),
+                };
+
+                elements.push(
+                    Text::default()
+                        .with_text(action.to_string())
+                        .with_width(dimension_bound!(50%))
+                        .wrap(),
+                );
+                elements.push(
+                    ButtonBuilder::new()
+                        .with_text(binding)
+                        .with_event(UserEvent::CaptureMouseBinding(action))
+                        .with_width_bound(dimension_bound!(!))
+                        .build()
+                        .wrap(),
+                );
+            }
+
+            let mut category = "";
+
+            for action in InputAction::all() {
+                if action.category() != category {
+                    category = action.category();
+                    elements.push(Text::default().with_text(category).wrap());
+                }
+
+                let binding = match (key_capture == Some(action), key_bindings.get(action)) {
+                    (true, _) => "Press a key".to_owned(),
+                    (false, Some(binding)) => binding.to_string(),
+                    (false, None) => "Unbound".to_owned(),
+                };
+
+                let button = ButtonBuilder::new()
+                    .with_text(binding)
+                    .with_event(UserEvent::CaptureKeyBinding(action));
+
+                // Highlight bindings that trigger more than one action.
+                let button = match key_bindings.conflicts(action).is_empty() {
+                    true => button.with_width_bound(dimension_bound!(!)).build().wrap(),
+                    false => button
+                        .with_foreground_color(|theme: &InterfaceTheme| theme.chat.error_color.get())
+                        .with_width_bound(dimension_bound!(!))
+                        .build()
+                        .wrap(),
+                };
+
+                elements.push(
+                    Text::default()
+                        .with_text(action.to_string())
+                        .with_width(dimension_bound!(50%))
+                        .wrap(),
+                );
+                elements.push(button);
+            }
+
+            elements
+        };
+
+        Self {
+            key_bindings,
+            key_capture,
+            mouse_capture,
+            state: ContainerState::new(elements),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for KeyBindingView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let key_bindings_changed = self.key_bindings.consume_changed();
+        let key_capture_changed = self.key_capture.consume_changed();
+        let mouse_capture_changed = self.mouse_capture.consume_changed();
+
+        if key_bindings_changed || key_capture_changed || mouse_capture_changed {
+            let weak_self = self.state.state.self_element.take().unwrap();
+            let weak_parent = self.state.state.parent_element.clone();
+
+            *self = Self::new(self.key_bindings.clone(), self.key_capture.clone(), self.mouse_capture.clone());
+
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.state.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -1,19 +1,35 @@
+mod achievement;
 mod character;
+mod chat_tabs;
+mod command_palette;
 mod dialog;
 mod equipment;
 mod friends;
 mod hotbar;
 mod inventory;
+mod key_bindings;
 #[cfg(feature = "debug")]
 mod packet;
+mod pet;
+mod quest;
+mod refine;
 mod skill_tree;
+mod status_effect;
 
-pub use self::character::CharacterPreview;
+pub use self::achievement::AchievementView;
+pub use self::character::{CharacterPreview, CharacterSlots, SLOTS_PER_PAGE};
+pub use self::chat_tabs::{ChatTabBar, ChatTabsView};
+pub use self::command_palette::CommandPaletteView;
 pub use self::dialog::{DialogContainer, DialogElement};
 pub use self::equipment::EquipmentContainer;
 pub use self::friends::FriendView;
 pub use self::hotbar::HotbarContainer;
 pub use self::inventory::InventoryContainer;
+pub use self::key_bindings::KeyBindingView;
 #[cfg(feature = "debug")]
 pub use self::packet::{PacketHistoryCallback, PacketHistoryRemote, PacketView};
+pub use self::pet::PetView;
+pub use self::quest::QuestView;
+pub use self::refine::RefineView;
 pub use self::skill_tree::SkillTreeContainer;
+pub use self::status_effect::StatusEffectView;
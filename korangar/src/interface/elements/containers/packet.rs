@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::fmt::{Display, Formatter, Result};
 use std::rc::{Rc, Weak};
 use std::sync::{Mutex, MutexGuard};
@@ -20,6 +21,7 @@ use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::linked::LinkedElement;
 use crate::interface::theme::InterfaceTheme;
+use crate::system::{record_packet, PacketDirection};
 
 #[derive(Debug, Clone)]
 struct UnknownPacket {
@@ -129,27 +131,48 @@ impl Display for Direction {
     }
 }
 
+/// Get the header of a packet that could not be parsed.
+fn header_from_bytes(bytes: &[u8]) -> PacketHeader {
+    match bytes {
+        [low, high, ..] => PacketHeader(u16::from_le_bytes([*low, *high])),
+        _ => PacketHeader(0),
+    }
+}
+
 struct PacketEntry {
     element: Box<dyn PrototypeElement<InterfaceSettings> + Send>,
     name: &'static str,
+    header: PacketHeader,
     is_ping: bool,
     direction: Direction,
 }
 
 impl PacketEntry {
-    pub fn new_incoming(element: Box<dyn PrototypeElement<InterfaceSettings> + Send>, name: &'static str, is_ping: bool) -> Self {
+    pub fn new_incoming(
+        element: Box<dyn PrototypeElement<InterfaceSettings> + Send>,
+        name: &'static str,
+        header: PacketHeader,
+        is_ping: bool,
+    ) -> Self {
         Self {
             element,
             name,
+            header,
             is_ping,
             direction: Direction::Incoming,
         }
     }
 
-    pub fn new_outgoing(element: Box<dyn PrototypeElement<InterfaceSettings> + Send>, name: &'static str, is_ping: bool) -> Self {
+    pub fn new_outgoing(
+        element: Box<dyn PrototypeElement<InterfaceSettings> + Send>,
+        name: &'static str,
+        header: PacketHeader,
+        is_ping: bool,
+    ) -> Self {
         Self {
             element,
             name,
+            header,
             is_ping,
             direction: Direction::Outgoing,
         }
@@ -159,21 +182,61 @@ impl PacketEntry {
         self.is_ping
     }
 
+    /// Check if the name or the hexadecimal header of the packet contain the
+    /// filter. An empty filter matches every packet.
+    fn matches_filter(&self, filter: &str) -> bool {
+        let filter = filter.trim().to_lowercase();
+        let header_filter = filter.trim_start_matches("0x");
+
+        filter.is_empty() || self.name.to_lowercase().contains(&filter) || format!("{:0>4x}", self.header.0).contains(header_filter)
+    }
+
     fn to_element(&self) -> ElementCell<InterfaceSettings> {
         self.element.to_element(format!("{} {}", self.direction, self.name))
     }
 }
 
+/// Maximum number of packets that are held back while the history is paused.
+const MAXIMUM_PENDING_PACKETS: usize = 256;
+
+#[derive(Default)]
+struct PacketHistory {
+    entries: RingBuffer<(PacketEntry, LinkedElement), 256>,
+    version: usize,
+    paused: bool,
+    /// Packets received while the history is paused. They are added to the
+    /// history when stepping or resuming.
+    pending: VecDeque<PacketEntry>,
+}
+
+impl PacketHistory {
+    fn push(&mut self, entry: PacketEntry) {
+        match self.paused {
+            true => {
+                if self.pending.len() == MAXIMUM_PENDING_PACKETS {
+                    self.pending.pop_front();
+                }
+
+                self.pending.push_back(entry);
+            }
+            false => {
+                self.entries.push((entry, LinkedElement::new()));
+                self.version += 1;
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct PacketHistoryCallback {
-    buffer_pointer: &'static Mutex<(RingBuffer<(PacketEntry, LinkedElement), 256>, usize)>,
+    buffer_pointer: &'static Mutex<PacketHistory>,
 }
 
 impl PacketHistoryCallback {
     /// SAFETY: This function is unsafe because it leaks memory. It should
     /// only be called once during the lifetime of the program.
     pub unsafe fn new() -> Self {
-        let buffer_pointer = Box::leak(Box::new(Mutex::new((RingBuffer::default(), 0))));
+        let buffer_pointer = Box::leak(Box::new(Mutex::new(PacketHistory::default())));
 
         Self { buffer_pointer }
     }
@@ -188,8 +251,9 @@ impl PacketHistoryCallback {
     pub fn clear_all(&self) {
         let mut lock = self.buffer_pointer.lock().unwrap();
 
-        lock.0.clear();
-        lock.1 += 1;
+        lock.entries.clear();
+        lock.pending.clear();
+        lock.version += 1;
     }
 }
 
@@ -198,54 +262,66 @@ impl PacketCallback for PacketHistoryCallback {
     where
         Packet: ragnarok_packets::Packet,
     {
+        record_packet(PacketDirection::Incoming, Packet::HEADER);
+
         let mut lock = self.buffer_pointer.lock().unwrap();
 
         let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_incoming(prototype_element, std::any::type_name::<Packet>(), Packet::IS_PING);
+        let entry = PacketEntry::new_incoming(
+            prototype_element,
+            std::any::type_name::<Packet>(),
+            Packet::HEADER,
+            Packet::IS_PING,
+        );
 
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        lock.push(entry);
     }
 
     fn outgoing_packet<Packet>(&self, packet: &Packet)
     where
         Packet: ragnarok_packets::Packet,
     {
+        record_packet(PacketDirection::Outgoing, Packet::HEADER);
+
         let mut lock = self.buffer_pointer.lock().unwrap();
 
         let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_outgoing(prototype_element, std::any::type_name::<Packet>(), Packet::IS_PING);
+        let entry = PacketEntry::new_outgoing(
+            prototype_element,
+            std::any::type_name::<Packet>(),
+            Packet::HEADER,
+            Packet::IS_PING,
+        );
 
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        lock.push(entry);
     }
 
     fn unknown_packet(&self, bytes: Vec<u8>) {
         let mut lock = self.buffer_pointer.lock().unwrap();
 
+        let header = header_from_bytes(&bytes);
         let packet = UnknownPacket { bytes };
         let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_incoming(prototype_element, "^FF8810� Unknown �^000000", false);
+        let entry = PacketEntry::new_incoming(prototype_element, "^FF8810� Unknown �^000000", header, false);
 
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        lock.push(entry);
     }
 
     fn failed_packet(&self, bytes: Vec<u8>, error: Box<ConversionError>) {
         let mut lock = self.buffer_pointer.lock().unwrap();
 
+        let header = header_from_bytes(&bytes);
         let packet = ErrorPacket { bytes, error };
         let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_incoming(prototype_element, "^FF4444✖ Error ✖^000000", false);
+        let entry = PacketEntry::new_incoming(prototype_element, "^FF4444✖ Error ✖^000000", header, false);
 
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        lock.push(entry);
     }
 }
 
 #[derive(Clone)]
 pub struct PacketHistoryRemote {
-    buffer_pointer: &'static Mutex<(RingBuffer<(PacketEntry, LinkedElement), 256>, usize)>,
+    buffer_pointer: &'static Mutex<PacketHistory>,
     version: usize,
 }
 
@@ -253,39 +329,72 @@ impl PacketHistoryRemote {
     pub fn consume_changed(&mut self) -> bool {
         let lock = self.buffer_pointer.lock().unwrap();
 
-        let version = lock.1;
+        let version = lock.version;
         let changed = version != self.version;
         self.version = version;
 
         changed
     }
 
-    fn get(&self) -> MutexGuard<'_, (RingBuffer<(PacketEntry, LinkedElement), 256>, usize)> {
+    fn get(&self) -> MutexGuard<'_, PacketHistory> {
         self.buffer_pointer.lock().unwrap()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.buffer_pointer.lock().unwrap().0.is_empty()
+        self.buffer_pointer.lock().unwrap().entries.is_empty()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.buffer_pointer.lock().unwrap().paused
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.buffer_pointer.lock().unwrap().pending.is_empty()
+    }
+
+    /// Stop adding new packets to the history. When resuming, all packets
+    /// received in the meantime are added at once.
+    pub fn set_paused(&self, paused: bool) {
+        let mut lock = self.buffer_pointer.lock().unwrap();
+        lock.paused = paused;
+
+        if !paused {
+            while let Some(entry) = lock.pending.pop_front() {
+                lock.push(entry);
+            }
+        }
+    }
+
+    /// Add the oldest packet that was received while paused to the history.
+    pub fn step(&self) {
+        let mut lock = self.buffer_pointer.lock().unwrap();
+
+        if let Some(entry) = lock.pending.pop_front() {
+            lock.entries.push((entry, LinkedElement::new()));
+            lock.version += 1;
+        }
     }
 }
 
 pub struct PacketView {
     packets: PacketHistoryRemote,
     show_pings: PlainRemote<bool>,
+    filter: PlainRemote<String>,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl PacketView {
-    pub fn new(packets: PacketHistoryRemote, show_pings: PlainRemote<bool>) -> Self {
+    pub fn new(packets: PacketHistoryRemote, show_pings: PlainRemote<bool>, filter: PlainRemote<String>) -> Self {
         let elements = {
             let packets = packets.get();
             let show_pings = show_pings.cloned();
+            let filter = filter.get();
 
             packets
-                .0
+                .entries
                 .iter()
                 .filter_map(|(packet, linked_element)| {
-                    let show_packet = show_pings || !packet.is_ping();
+                    let show_packet = (show_pings || !packet.is_ping()) && packet.matches_filter(&filter);
 
                     match show_packet {
                         true => {
@@ -305,6 +414,7 @@ impl PacketView {
         Self {
             packets,
             show_pings,
+            filter,
             state: ContainerState::new(elements),
         }
     }
@@ -362,9 +472,15 @@ impl Element<InterfaceSettings> for PacketView {
     fn update(&mut self) -> Option<ChangeEvent> {
         let mut resolve = false;
 
-        if self.show_pings.consume_changed() | self.packets.consume_changed() {
+        if self.show_pings.consume_changed() | self.filter.consume_changed() | self.packets.consume_changed() {
             // Remove elements of packets that are no longer in the list.
-            if let Some(first_visible_packet) = self.packets.get().0.iter().find(|(_, linked_element)| !linked_element.is_hidden()) {
+            if let Some(first_visible_packet) = self
+                .packets
+                .get()
+                .entries
+                .iter()
+                .find(|(_, linked_element)| !linked_element.is_hidden())
+            {
                 for _index in 0..self.state.elements.len() {
                     if !first_visible_packet.1.is_linked_to(&self.state.elements[0]) {
                         self.state.elements.remove(0);
@@ -380,13 +496,14 @@ impl Element<InterfaceSettings> for PacketView {
             }
 
             let show_pings = self.show_pings.cloned();
+            let filter = self.filter.cloned();
             let mut index = 0;
 
             // Add or remove elements that need to be shown/hidden based on filtering. Also
             // append new elements for packets that are new.
-            self.packets.get().0.iter().for_each(|(packet, linked_element)| {
+            self.packets.get().entries.iter().for_each(|(packet, linked_element)| {
                 // Getting here means thatt the packet was already processed once.
-                let show_packet = show_pings || !packet.is_ping();
+                let show_packet = (show_pings || !packet.is_ping()) && packet.matches_filter(&filter);
 
                 if linked_element.is_linked() {
                     let was_hidden = linked_element.is_hidden();
@@ -0,0 +1,202 @@
+use std::cell::RefCell;
+use std::rc::Weak;
+
+use korangar_interface::elements::{ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::PetInformation;
+use ragnarok_packets::PetCommand;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ProgressBar;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::{hunger_description, intimacy_description, PET_AWKWARD_THRESHOLD, PET_HUNGRY_THRESHOLD};
+
+/// Maximum hunger of a pet.
+const MAXIMUM_HUNGER: u32 = 100;
+/// Maximum intimacy of a pet.
+const MAXIMUM_INTIMACY: u32 = 1000;
+
+pub struct PetView {
+    information: PlainRemote<Option<PetInformation>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl PetView {
+    pub fn new(information: PlainRemote<Option<PetInformation>>) -> Self {
+        let elements = match information.get().as_ref() {
+            Some(information) => Self::information_to_elements(information),
+            None => vec![Text::default().with_text("No pet summoned".to_owned()).wrap()],
+        };
+
+        Self {
+            information,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn status_text(text: String, warning: bool) -> ElementCell<InterfaceSettings> {
+        match warning {
+            true => Text::default()
+                .with_text(text)
+                .with_foreground_color(|_| Color::rgb_u8(255, 100, 100))
+                .wrap(),
+            false => Text::default().with_text(text).wrap(),
+        }
+    }
+
+    fn information_to_elements(information: &PetInformation) -> Vec<ElementCell<InterfaceSettings>> {
+        let mut elements = vec![
+            Text::default()
+                .with_text(format!("{} (level {})", information.name, information.level))
+                .wrap(),
+            ProgressBar::new("Hunger".to_owned(), information.hunger as u32, MAXIMUM_HUNGER).wrap(),
+            Self::status_text(
+                hunger_description(information.hunger).to_owned(),
+                information.hunger <= PET_HUNGRY_THRESHOLD,
+            ),
+            ProgressBar::new("Intimacy".to_owned(), information.intimacy as u32, MAXIMUM_INTIMACY).wrap(),
+            Self::status_text(
+                intimacy_description(information.intimacy).to_owned(),
+                information.intimacy <= PET_AWKWARD_THRESHOLD,
+            ),
+            ButtonBuilder::new()
+                .with_text("Feed")
+                .with_event(UserEvent::SendPetCommand(PetCommand::Feed))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Performance")
+                .with_event(UserEvent::SendPetCommand(PetCommand::Performance))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Return to egg")
+                .with_event(UserEvent::SendPetCommand(PetCommand::ReturnToEgg))
+                .build()
+                .wrap(),
+        ];
+
+        if information.accessory.is_some() {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Unequip accessory")
+                    .with_event(UserEvent::SendPetCommand(PetCommand::UnequipAccessory))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for PetView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.information.consume_changed() {
+            let weak_self = self.state.state.self_element.take().unwrap();
+            let weak_parent = self.state.state.parent_element.clone();
+
+            *self = Self::new(self.information.clone());
+
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.state.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
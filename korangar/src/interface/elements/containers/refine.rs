@@ -0,0 +1,239 @@
+use std::cell::RefCell;
+use std::rc::Weak;
+
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, StateButtonBuilder, Text,
+};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateBinary};
+use korangar_networking::{InventoryItem, InventoryItemDetails};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::RefineResultText;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::{RefineItem, RefineOutcome};
+use crate::loaders::ResourceMetadata;
+
+pub struct RefineView {
+    items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+    refine_item: PlainRemote<Option<RefineItem>>,
+    outcome: PlainRemote<Option<RefineOutcome>>,
+    use_blacksmith_blessing: PlainTrackedState<bool>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl RefineView {
+    pub fn new(
+        items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+        refine_item: PlainRemote<Option<RefineItem>>,
+        outcome: PlainRemote<Option<RefineOutcome>>,
+        use_blacksmith_blessing: PlainTrackedState<bool>,
+    ) -> Self {
+        let elements = {
+            let items = items.get();
+            let mut elements = Vec::new();
+
+            if let Some(outcome) = outcome.get().as_ref() {
+                elements.push(RefineResultText::new(outcome.clone()).wrap());
+            }
+
+            match refine_item.get().as_ref() {
+                Some(refine_item) => {
+                    if let Some(item) = items.iter().find(|item| item.index == refine_item.index) {
+                        elements.push(Text::default().with_text(Self::item_display(item)).wrap());
+                    }
+
+                    if refine_item.blacksmith_blessing > 0 {
+                        elements.push(
+                            StateButtonBuilder::new()
+                                .with_text(format!("Use {} Blacksmith Blessing", refine_item.blacksmith_blessing))
+                                .with_remote(use_blacksmith_blessing.new_remote())
+                                .with_event(use_blacksmith_blessing.toggle_action())
+                                .build()
+                                .wrap(),
+                        );
+                    }
+
+                    if refine_item.materials.is_empty() {
+                        elements.push(Text::default().with_text("This item can not be refined".to_owned()).wrap());
+                    }
+
+                    for material in &refine_item.materials {
+                        let index = refine_item.index;
+                        let material_id = material.item_id;
+                        let use_blacksmith_blessing = use_blacksmith_blessing.clone();
+
+                        elements.push(
+                            Text::default()
+                                .with_text(format!("{}: {}% for {} z", material.name, material.chance, material.zeny))
+                                .wrap(),
+                        );
+                        elements.push(
+                            ButtonBuilder::new()
+                                .with_text("Refine")
+                                .with_event(move || {
+                                    vec![ClickAction::Custom(UserEvent::RefineItem {
+                                        index,
+                                        material_id,
+                                        use_blacksmith_blessing: *use_blacksmith_blessing.get(),
+                                    })]
+                                })
+                                .build()
+                                .wrap(),
+                        );
+                    }
+                }
+                None => elements.push(Text::default().with_text("Select an item to refine".to_owned()).wrap()),
+            }
+
+            let refinable_items = items
+                .iter()
+                .filter(|item| item.is_identifed() && matches!(item.details, InventoryItemDetails::Equippable { .. }))
+                .map(|item| {
+                    ButtonBuilder::new()
+                        .with_text(Self::item_display(item))
+                        .with_event(UserEvent::SelectRefineItem(item.index))
+                        .build()
+                        .wrap()
+                })
+                .collect();
+
+            elements.push(Expandable::new("Equipment".to_owned(), refinable_items, true).wrap());
+            elements
+        };
+
+        Self {
+            items,
+            refine_item,
+            outcome,
+            use_blacksmith_blessing,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn item_display(item: &InventoryItem<ResourceMetadata>) -> String {
+        match item.details {
+            InventoryItemDetails::Equippable { refinement_level, .. } if refinement_level > 0 => {
+                format!("+{} {}", refinement_level, item.metadata.name)
+            }
+            _ => item.metadata.name.clone(),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for RefineView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let items_changed = self.items.consume_changed();
+        let refine_item_changed = self.refine_item.consume_changed();
+        let outcome_changed = self.outcome.consume_changed();
+
+        if items_changed || refine_item_changed || outcome_changed {
+            let weak_self = self.state.state.self_element.take().unwrap();
+            let weak_parent = self.state.state.parent_element.clone();
+
+            *self = Self::new(
+                self.items.clone(),
+                self.refine_item.clone(),
+                self.outcome.clone(),
+                self.use_blacksmith_blessing.clone(),
+            );
+
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.state.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
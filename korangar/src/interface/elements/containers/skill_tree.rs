@@ -4,11 +4,11 @@ use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote};
 
-use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
 use crate::input::MouseInputMode;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::SkillBox;
-use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::{Move, PartialMove, SkillSource};
 use crate::interface::theme::InterfaceTheme;
 use crate::inventory::Skill;
@@ -98,18 +98,26 @@ impl Element<InterfaceSettings> for SkillTreeContainer {
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match mouse_mode {
-            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::MoveResource(PartialMove::Skill { .. }) => self.state.state.hovered_element(mouse_position),
             MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
             _ => HoverInformation::Missed,
         }
     }
 
+    fn accepts_drop(&self, drop_resource: &PartialMove) -> bool {
+        matches!(drop_resource, PartialMove::Skill { source, .. } if *source != SkillSource::SkillTree)
+    }
+
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        if !self.accepts_drop(&drop_resource) {
+            return None;
+        }
+
         let PartialMove::Skill { source, skill } = drop_resource else {
             return None;
         };
 
-        (source != SkillSource::SkillTree).then_some(Move::Skill {
+        Some(Move::Skill {
             source,
             destination: SkillSource::SkillTree,
             skill,
@@ -143,5 +151,12 @@ impl Element<InterfaceSettings> for SkillTreeContainer {
             mouse_mode,
             second_theme,
         );
+
+        if self.is_drop_target(mouse_mode) {
+            match self.is_element_self(hovered_element) {
+                true => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(60, 160, 160, 160)),
+                false => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(160, 160, 60, 160)),
+            }
+        }
     }
 }
@@ -0,0 +1,137 @@
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::ChangeEvent;
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use ragnarok_packets::ClientTick;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::loaders::AnimationState;
+use crate::world::PlayerAppearance;
+
+/// Renders a player character standing still and facing the camera.
+pub struct AppearancePreview {
+    appearance: PlainRemote<Option<PlayerAppearance>>,
+    animation_state: AnimationState,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl AppearancePreview {
+    pub fn new(appearance: PlainRemote<Option<PlayerAppearance>>) -> Self {
+        Self {
+            appearance,
+            animation_state: AnimationState::new(ClientTick(0)),
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for AppearancePreview {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(100%, 150));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.appearance.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(theme.value.corner_radius.get(), theme.value.background_color.get());
+
+        let appearance = self.appearance.get();
+        let Some(appearance) = appearance.as_ref() else {
+            return;
+        };
+
+        // The feet of the character are placed slightly below the center of the
+        // preview, leaving room for the head.
+        let body_position = renderer.position
+            + ScreenSize {
+                width: self.state.cached_size.width / 2.0,
+                height: self.state.cached_size.height * 0.75,
+            };
+
+        appearance.body_actions.render2(
+            renderer.render_target,
+            renderer.renderer,
+            &appearance.body_sprite,
+            &self.animation_state,
+            body_position,
+            0,
+            Color::monochrome_u8(255),
+            application,
+        );
+
+        let body_attach_point = appearance.body_actions.get_attach_point(&self.animation_state, 0);
+        let head_attach_point = appearance.head_actions.get_attach_point(&self.animation_state, 0);
+        let head_position = match (body_attach_point, head_attach_point) {
+            (Some(body_attach_point), Some(head_attach_point)) => {
+                let offset = body_attach_point - head_attach_point;
+
+                ScreenPosition {
+                    left: body_position.left + offset.x,
+                    top: body_position.top + offset.y,
+                }
+            }
+            _ => body_position,
+        };
+
+        appearance.head_actions.render2(
+            renderer.render_target,
+            renderer.renderer,
+            &appearance.head_sprite,
+            &self.animation_state,
+            head_position,
+            0,
+            Color::monochrome_u8(255),
+            application,
+        );
+
+        renderer.render_text(
+            &format!(
+                "Hair style {}, color {}",
+                appearance.appearance.hair_style, appearance.appearance.hair_color
+            ),
+            theme.value.text_offset.get(),
+            theme.value.foreground_color.get(),
+            theme.value.font_size.get(),
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::ChangeEvent;
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition};
+use crate::interface::theme::InterfaceTheme;
+
+/// Single day of the attendance calendar.
+pub struct AttendanceDay {
+    day: u32,
+    claimed_days: PlainRemote<u32>,
+    claimed_today: PlainRemote<bool>,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl AttendanceDay {
+    pub fn new(day: u32, claimed_days: PlainRemote<u32>, claimed_today: PlainRemote<bool>) -> Self {
+        Self {
+            day,
+            claimed_days,
+            claimed_today,
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for AttendanceDay {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(20%, 40));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let days_changed = self.claimed_days.consume_changed();
+        let today_changed = self.claimed_today.consume_changed();
+
+        (days_changed || today_changed).then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let claimed_days = *self.claimed_days.get();
+        let claimable = self.day == claimed_days + 1 && !*self.claimed_today.get();
+
+        let (background_color, foreground_color) = match (self.day <= claimed_days, claimable) {
+            (true, _) => (theme.slider.knob_color.get(), theme.button.foreground_color.get()),
+            (false, true) => (
+                theme.button.hovered_background_color.get(),
+                theme.button.hovered_foreground_color.get(),
+            ),
+            (false, false) => (
+                theme.button.disabled_background_color.get(),
+                theme.button.disabled_foreground_color.get(),
+            ),
+        };
+
+        renderer.render_background(theme.button.corner_radius.get(), background_color);
+
+        renderer.render_text(
+            &format!("Day {}", self.day),
+            theme.button.text_offset.get(),
+            foreground_color,
+            theme.button.font_size.get(),
+        );
+    }
+}
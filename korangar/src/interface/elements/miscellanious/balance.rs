@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::ChangeEvent;
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition};
+use crate::interface::theme::InterfaceTheme;
+
+/// Labeled amount of zeny that updates whenever the remote changes.
+pub struct BalanceText<T>
+where
+    T: Display + 'static,
+{
+    label: &'static str,
+    balance: PlainRemote<T>,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl<T> BalanceText<T>
+where
+    T: Display + 'static,
+{
+    pub fn new(label: &'static str, balance: PlainRemote<T>) -> Self {
+        Self {
+            label,
+            balance,
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl<T> Element<InterfaceSettings> for BalanceText<T>
+where
+    T: Display + 'static,
+{
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &theme.value.size_bound);
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.balance.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(theme.value.corner_radius.get(), theme.value.background_color.get());
+
+        renderer.render_text(
+            &format!("{}: {} z", self.label, self.balance.get()),
+            theme.value.text_offset.get(),
+            theme.value.foreground_color.get(),
+            theme.value.font_size.get(),
+        );
+    }
+}
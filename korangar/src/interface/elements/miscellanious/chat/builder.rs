@@ -5,6 +5,7 @@ use korangar_interface::builder::Unset;
 use korangar_interface::state::PlainRemote;
 
 use super::Chat;
+use crate::interface::chat::{ChatSettings, ChatTab};
 use crate::interface::windows::ChatMessage;
 use crate::loaders::FontLoader;
 
@@ -15,6 +16,8 @@ use crate::loaders::FontLoader;
 pub struct ChatBuilder<Messages, Font> {
     messages: Messages,
     font_loader: Font,
+    filter: Option<(PlainRemote<Vec<ChatTab>>, PlainRemote<usize>)>,
+    settings: Option<PlainRemote<ChatSettings>>,
 }
 
 impl ChatBuilder<Unset, Unset> {
@@ -22,6 +25,8 @@ impl ChatBuilder<Unset, Unset> {
         Self {
             messages: Unset,
             font_loader: Unset,
+            filter: None,
+            settings: None,
         }
     }
 }
@@ -38,6 +43,24 @@ impl<Messages> ChatBuilder<Messages, Unset> {
     }
 }
 
+impl<Messages, Font> ChatBuilder<Messages, Font> {
+    /// Only show the messages of the selected chat tab.
+    pub fn with_tab_filter(self, chat_tabs: PlainRemote<Vec<ChatTab>>, selected: PlainRemote<usize>) -> Self {
+        Self {
+            filter: Some((chat_tabs, selected)),
+            ..self
+        }
+    }
+
+    /// Use the timestamp, spacing and color options of the chat settings.
+    pub fn with_settings(self, settings: PlainRemote<ChatSettings>) -> Self {
+        Self {
+            settings: Some(settings),
+            ..self
+        }
+    }
+}
+
 impl ChatBuilder<PlainRemote<Vec<ChatMessage>>, Rc<RefCell<FontLoader>>> {
     /// Take the builder and turn it into a [`Chat`].
     ///
@@ -46,11 +69,21 @@ impl ChatBuilder<PlainRemote<Vec<ChatMessage>>, Rc<RefCell<FontLoader>>> {
     /// and [`with_font_loader`](Self::with_font_loader) have been called on
     /// the builder.
     pub fn build(self) -> Chat {
-        let Self { messages, font_loader } = self;
+        let Self {
+            messages,
+            font_loader,
+            filter,
+            settings,
+        } = self;
 
         Chat {
             messages,
             font_loader,
+            filter,
+            settings,
+            links: Vec::new(),
+            hovered_offset: Default::default(),
+            scaling: 1.0,
             state: Default::default(),
         }
     }
@@ -1,19 +1,24 @@
 mod builder;
+mod tab;
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
 use std::rc::Rc;
 
 use korangar_interface::application::{Application, FontSizeTraitExt};
 use korangar_interface::elements::{Element, ElementState};
-use korangar_interface::event::ChangeEvent;
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::{Dimension, PlacementResolver};
 use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote};
+use korangar_networking::MessageColor;
 
 pub use self::builder::ChatBuilder;
+pub use self::tab::ChatTabButton;
 use crate::graphics::{Color, InterfaceRenderer, Renderer};
-use crate::input::MouseInputMode;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
+use crate::interface::chat::{display_links, item_links, monster_links, ChatCategory, ChatLineSpacing, ChatSettings, ChatTab};
 use crate::interface::layout::{ScreenClip, ScreenPosition};
 use crate::interface::theme::InterfaceTheme;
 use crate::interface::windows::ChatMessage;
@@ -22,9 +27,87 @@ use crate::loaders::FontLoader;
 pub struct Chat {
     messages: PlainRemote<Vec<ChatMessage>>,
     font_loader: Rc<RefCell<FontLoader>>,
+    filter: Option<(PlainRemote<Vec<ChatTab>>, PlainRemote<usize>)>,
+    settings: Option<PlainRemote<ChatSettings>>,
+    /// Vertical extent of every message that links an item or a monster, used
+    /// to open it when clicking the message.
+    links: Vec<(Range<f32>, UserEvent)>,
+    /// Vertical offset of the mouse inside the chat while it is hovered.
+    hovered_offset: Cell<f32>,
+    scaling: f32,
     state: ElementState<InterfaceSettings>,
 }
 
+impl Chat {
+    /// Messages that are shown in the selected chat tab.
+    fn visible_messages(&self) -> Vec<ChatMessage> {
+        let messages = self.messages.get();
+
+        let Some((chat_tabs, selected)) = &self.filter else {
+            return messages.clone();
+        };
+
+        let chat_tabs = chat_tabs.get();
+
+        match chat_tabs.get(*selected.get()) {
+            Some(tab) => messages.iter().filter(|message| tab.shows(message)).cloned().collect(),
+            None => messages.clone(),
+        }
+    }
+
+    /// Text of a message as it is rendered.
+    fn display_text(&self, message: &ChatMessage) -> String {
+        let text = display_links(&message.text);
+
+        match self.settings.as_ref().is_some_and(|settings| settings.get().show_timestamps) {
+            true => format!("[{}] {}", message.timestamp.format("%H:%M"), text),
+            false => text,
+        }
+    }
+
+    /// Event of the first link in a message, which is opened when clicking it.
+    fn first_link(message: &ChatMessage) -> Option<UserEvent> {
+        let item_link = item_links(&message.text).into_iter().next();
+        let monster_link = monster_links(&message.text).into_iter().next();
+
+        match (item_link, monster_link) {
+            (Some(item_link), Some(monster_link)) if monster_link.range.start < item_link.range.start => {
+                Some(UserEvent::OpenMonsterInformation(monster_link.monster_id))
+            }
+            (Some(item_link), _) => Some(UserEvent::OpenItemInformation(item_link.item_id)),
+            (None, Some(monster_link)) => Some(UserEvent::OpenMonsterInformation(monster_link.monster_id)),
+            (None, None) => None,
+        }
+    }
+
+    fn line_spacing(&self) -> ChatLineSpacing {
+        self.settings
+            .as_ref()
+            .map(|settings| settings.get().line_spacing)
+            .unwrap_or_default()
+    }
+
+    fn message_color(&self, message: &ChatMessage, theme: &InterfaceTheme) -> Color {
+        let category_color = self
+            .settings
+            .as_ref()
+            .and_then(|settings| *settings.get().colors.get(ChatCategory::of(message)));
+
+        if let Some(color) = category_color {
+            return color;
+        }
+
+        match message.color {
+            MessageColor::Rgb { red, green, blue } => Color::rgb_u8(red, green, blue),
+            MessageColor::Broadcast => theme.chat.broadcast_color.get(),
+            MessageColor::Server => theme.chat.server_color.get(),
+            MessageColor::Error => theme.chat.error_color.get(),
+            MessageColor::Information => theme.chat.information_color.get(),
+            MessageColor::Whisper => theme.chat.whisper_color.get(),
+        }
+    }
+}
+
 impl Element<InterfaceSettings> for Chat {
     fn get_state(&self) -> &ElementState<InterfaceSettings> {
         &self.state
@@ -49,19 +132,33 @@ impl Element<InterfaceSettings> for Chat {
         // padding.
         let mut height = 5.0 * application.get_scaling_factor();
 
+        let mut offset = 0.0;
+        let gap = self.line_spacing().gap();
+
+        self.scaling = application.get_scaling_factor();
+        self.links.clear();
+
         // Dividing by the scaling is done to counteract the scaling being applied
         // twice per message. It's not the cleanest solution but it works.
-        for message in self.messages.get().iter() {
-            height += self
+        for message in self.visible_messages().iter() {
+            let message_height = self
                 .font_loader
                 .borrow()
                 .get_text_dimensions(
-                    &message.text,
+                    &self.display_text(message),
                     theme.chat.font_size.get().scaled(application.get_scaling()),
                     placement_resolver.get_available().width,
                 )
                 .height
                 / application.get_scaling_factor();
+
+            // Only the first link of a message can be opened by clicking.
+            if let Some(event) = Self::first_link(message) {
+                self.links.push((offset..offset + message_height, event));
+            }
+
+            height += message_height + gap;
+            offset += message_height + gap;
         }
 
         size_bound.height = Dimension::Absolute(height);
@@ -69,7 +166,53 @@ impl Element<InterfaceSettings> for Chat {
     }
 
     fn update(&mut self) -> Option<ChangeEvent> {
-        self.messages.consume_changed().then_some(ChangeEvent::RESOLVE_WINDOW)
+        let messages_changed = self.messages.consume_changed();
+        let filter_changed = self
+            .filter
+            .as_mut()
+            .is_some_and(|(chat_tabs, selected)| chat_tabs.consume_changed() | selected.consume_changed());
+        let settings_changed = self.settings.as_mut().is_some_and(|settings| settings.consume_changed());
+
+        (messages_changed || filter_changed || settings_changed).then_some(ChangeEvent::RESOLVE_WINDOW)
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        // The chat is only hovered so that the messages can be copied and links
+        // can be opened.
+        match mouse_mode {
+            MouseInputMode::None => {
+                let relative_position = mouse_position - self.state.cached_position;
+                self.hovered_offset.set(relative_position.height / self.scaling);
+
+                self.state.hovered_element(mouse_position)
+            }
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        let hovered_offset = self.hovered_offset.get();
+
+        self.links
+            .iter()
+            .find(|(bounds, _)| bounds.contains(&hovered_offset))
+            .map(|(_, event)| vec![ClickAction::Custom(event.clone())])
+            .unwrap_or_default()
+    }
+
+    fn copy_text(&self) -> Option<String> {
+        let messages = self.visible_messages();
+
+        if messages.is_empty() {
+            return None;
+        }
+
+        let text = messages
+            .iter()
+            .map(|message| display_links(&message.text))
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(text)
     }
 
     fn render(
@@ -90,9 +233,10 @@ impl Element<InterfaceSettings> for Chat {
             .element_renderer(render_target, renderer, application, parent_position, screen_clip);
 
         let mut offset = 0.0;
+        let gap = self.line_spacing().gap();
 
-        for message in self.messages.get().iter() {
-            let text = &message.text;
+        for message in self.visible_messages().iter() {
+            let text = &self.display_text(message);
 
             renderer.render_text(
                 text,
@@ -104,13 +248,7 @@ impl Element<InterfaceSettings> for Chat {
                 theme.chat.font_size.get(),
             );
 
-            let message_color = match message.color {
-                korangar_networking::MessageColor::Rgb { red, green, blue } => Color::rgb_u8(red, green, blue),
-                korangar_networking::MessageColor::Broadcast => theme.chat.broadcast_color.get(),
-                korangar_networking::MessageColor::Server => theme.chat.server_color.get(),
-                korangar_networking::MessageColor::Error => theme.chat.error_color.get(),
-                korangar_networking::MessageColor::Information => theme.chat.information_color.get(),
-            };
+            let message_color = self.message_color(message, theme);
 
             // Dividing by the scaling is done to counteract the scaling being applied
             // twice per message. It's not the cleanest solution but it works.
@@ -119,7 +257,8 @@ impl Element<InterfaceSettings> for Chat {
                 ScreenPosition::only_top(offset),
                 message_color,
                 theme.chat.font_size.get(),
-            ) / application.get_scaling_factor();
+            ) / application.get_scaling_factor()
+                + gap;
         }
     }
 }
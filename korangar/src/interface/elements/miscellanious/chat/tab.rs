@@ -0,0 +1,130 @@
+use korangar_interface::dimension_bound;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition};
+use crate::interface::resource::{Move, PartialMove};
+use crate::interface::theme::InterfaceTheme;
+
+/// Button of a single chat tab. Clicking selects the tab and starts dragging
+/// it, so that it can be dropped onto another tab to change the order.
+pub struct ChatTabButton {
+    index: usize,
+    text: String,
+    selected: PlainTrackedState<usize>,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl ChatTabButton {
+    pub fn new(index: usize, name: &str, unread: usize, selected: PlainTrackedState<usize>) -> Self {
+        let text = match unread {
+            0 => name.to_owned(),
+            unread => format!("{name} ({unread})"),
+        };
+
+        Self {
+            index,
+            text,
+            selected,
+            state: ElementState::default(),
+        }
+    }
+
+    fn is_selected(&self) -> bool {
+        *self.selected.get() == self.index
+    }
+}
+
+impl Element<InterfaceSettings> for ChatTabButton {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = dimension_bound!(80).add_height(theme.button.height_bound);
+        self.state.resolve(placement_resolver, &size_bound);
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode.is_none() || self.is_drop_target(mouse_mode) {
+            true => self.state.hovered_element(mouse_position),
+            false => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        if !self.is_selected() {
+            self.selected.set(self.index);
+            *force_update = true;
+        }
+
+        vec![ClickAction::Move(PartialMove::ChatTab { index: self.index })]
+    }
+
+    fn accepts_drop(&self, drop_resource: &PartialMove) -> bool {
+        matches!(drop_resource, PartialMove::ChatTab { index } if *index != self.index)
+    }
+
+    fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        match drop_resource {
+            PartialMove::ChatTab { index } if index != self.index => Some(Move::ChatTab {
+                source: index,
+                destination: self.index,
+            }),
+            _ => None,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let highlighted = self.is_element_self(hovered_element) || self.is_element_self(focused_element);
+        let background_color = match self.is_drop_target(mouse_mode) {
+            true if highlighted => Color::rgba_u8(60, 160, 160, 255),
+            true => Color::rgba_u8(160, 160, 60, 255),
+            false if highlighted || self.is_selected() => theme.button.hovered_background_color.get(),
+            false => theme.button.background_color.get(),
+        };
+
+        let foreground_color = match self.is_selected() {
+            true => theme.button.hovered_foreground_color.get(),
+            false => theme.button.foreground_color.get(),
+        };
+
+        renderer.render_background(CornerRadius::uniform(5.0), background_color);
+        renderer.render_text(
+            &self.text,
+            theme.button.text_offset.get(),
+            foreground_color,
+            theme.button.font_size.get(),
+        );
+    }
+}
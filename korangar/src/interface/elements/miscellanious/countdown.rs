@@ -0,0 +1,103 @@
+use std::time::{Duration, Instant};
+
+use korangar_interface::dimension_bound;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::ChangeEvent;
+use korangar_interface::layout::PlacementResolver;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition};
+use crate::interface::theme::InterfaceTheme;
+use crate::system::format_remaining_time;
+
+/// Text followed by the time remaining until a point in time. Re-renders the
+/// window once every second.
+pub struct Countdown {
+    text: String,
+    end: Instant,
+    displayed_seconds: u64,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl Countdown {
+    pub fn new(text: String, end: Instant) -> Self {
+        Self {
+            text,
+            end,
+            displayed_seconds: 0,
+            state: ElementState::default(),
+        }
+    }
+
+    fn remaining_time(&self) -> Duration {
+        self.end.saturating_duration_since(Instant::now())
+    }
+}
+
+impl Element<InterfaceSettings> for Countdown {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = dimension_bound!(100%).add_height(theme.button.height_bound);
+        self.state.resolve(placement_resolver, &size_bound);
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let seconds = self.remaining_time().as_secs();
+
+        if seconds != self.displayed_seconds {
+            self.displayed_seconds = seconds;
+            return Some(ChangeEvent::RENDER_WINDOW);
+        }
+
+        None
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let remaining_time = self.remaining_time();
+        let foreground_color = match remaining_time.is_zero() {
+            true => Color::rgb_u8(220, 100, 100),
+            false => theme.button.foreground_color.get(),
+        };
+
+        renderer.render_text(
+            &format!("{} {}", self.text, format_remaining_time(remaining_time)),
+            theme.button.text_offset.get(),
+            foreground_color,
+            theme.button.font_size.get(),
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use ragnarok_packets::ClientTick;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition};
+use crate::interface::theme::InterfaceTheme;
+use crate::loaders::AnimationState;
+use crate::world::Emotions;
+
+/// Time into the animation that is used for the preview. Most emotions start
+/// with an empty or tiny bubble, so the first frame is not very telling.
+const PREVIEW_TIME: u32 = 400;
+
+pub struct EmotionBox {
+    emotions: Emotions,
+    emotion: u8,
+    animation_state: AnimationState,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl EmotionBox {
+    pub fn new(emotions: Emotions, emotion: u8) -> Self {
+        let mut animation_state = Emotions::animation_state(emotion, ClientTick(0));
+        animation_state.time = PREVIEW_TIME;
+
+        Self {
+            emotions,
+            emotion,
+            animation_state,
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for EmotionBox {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(40, 40));
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, _mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        self.state.hovered_element(mouse_position)
+    }
+
+    fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        vec![ClickAction::Custom(UserEvent::SendEmotion(self.emotion))]
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let background_color = match self.is_element_self(hovered_element) || self.is_element_self(focused_element) {
+            true => theme.button.hovered_background_color.get(),
+            false => theme.button.background_color.get(),
+        };
+
+        renderer.render_background(CornerRadius::uniform(5.0), background_color);
+
+        self.emotions.render(
+            renderer.render_target,
+            renderer.renderer,
+            &self.animation_state,
+            renderer.position + ScreenPosition::uniform(20.0 * application.get_scaling_factor()),
+            application,
+        );
+    }
+}
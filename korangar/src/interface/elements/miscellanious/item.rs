@@ -1,24 +1,30 @@
 use derive_new::new;
 use korangar_interface::application::{FontSizeTrait, SizeTraitExt};
 use korangar_interface::elements::{Element, ElementState};
-use korangar_interface::event::{ClickAction, HoverInformation};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
 use korangar_networking::{InventoryItem, InventoryItemDetails};
 
 use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
-use crate::input::MouseInputMode;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
+use crate::interface::chat::item_link_markup;
 use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::{ItemSource, Move, PartialMove};
 use crate::interface::theme::InterfaceTheme;
 use crate::loaders::{FontSize, ResourceMetadata, Scaling};
+use crate::system::{format_remaining_time, format_remaining_time_short};
 
 #[derive(new)]
 pub struct ItemBox {
     item: Option<InventoryItem<ResourceMetadata>>,
     source: ItemSource,
-    highlight: Box<dyn Fn(&MouseInputMode) -> bool>,
+    /// Decides if a dragged item can be dropped onto this box.
+    accepts: Box<dyn Fn(&PartialMove) -> bool>,
+    /// Remaining time of a rental item as it is currently displayed.
+    #[new(default)]
+    rental_time: Option<(String, String)>,
     #[new(default)]
     state: ElementState<InterfaceSettings>,
 }
@@ -45,8 +51,28 @@ impl Element<InterfaceSettings> for ItemBox {
         self.state.resolve(placement_resolver, &size_bound!(30, 30));
     }
 
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let rental_time = self
+            .item
+            .as_ref()
+            .and_then(InventoryItem::remaining_rental_time)
+            .map(|remaining_time| {
+                (
+                    format_remaining_time_short(remaining_time),
+                    format_remaining_time(remaining_time),
+                )
+            });
+
+        if rental_time != self.rental_time {
+            self.rental_time = rental_time;
+            return Some(ChangeEvent::RENDER_WINDOW);
+        }
+
+        None
+    }
+
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
-        match self.item.is_some() || matches!(mouse_mode, MouseInputMode::MoveItem(..)) {
+        match self.item.is_some() || self.is_drop_target(mouse_mode) {
             true => self.state.hovered_element(mouse_position),
             false => HoverInformation::Missed,
         }
@@ -63,12 +89,35 @@ impl Element<InterfaceSettings> for ItemBox {
         Vec::new()
     }
 
+    fn right_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        if let Some(item) = &self.item {
+            return vec![ClickAction::Custom(UserEvent::OpenItemContextMenu {
+                source: self.source,
+                item: item.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+
+    fn link_text(&self) -> Option<String> {
+        self.item.as_ref().map(|item| item_link_markup(item.item_id))
+    }
+
+    fn accepts_drop(&self, drop_resource: &PartialMove) -> bool {
+        matches!(drop_resource, PartialMove::Item { source, .. } if *source != self.source) && (self.accepts)(drop_resource)
+    }
+
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        if !self.accepts_drop(&drop_resource) {
+            return None;
+        }
+
         let PartialMove::Item { source, item } = drop_resource else {
             return None;
         };
 
-        (source != self.source).then_some(Move::Item {
+        Some(Move::Item {
             source,
             destination: self.source,
             item,
@@ -92,8 +141,9 @@ impl Element<InterfaceSettings> for ItemBox {
             .state
             .element_renderer(render_target, renderer, application, parent_position, screen_clip);
 
-        let highlight = (self.highlight)(mouse_mode);
-        let background_color = match self.is_element_self(hovered_element) || self.is_element_self(focused_element) {
+        let highlight = self.is_drop_target(mouse_mode);
+        let is_hovered = self.is_element_self(hovered_element);
+        let background_color = match is_hovered || self.is_element_self(focused_element) {
             true if highlight => Color::rgba_u8(60, 160, 160, 255),
             true if matches!(mouse_mode, MouseInputMode::None) => theme.button.hovered_background_color.get(),
             false if highlight => Color::rgba_u8(160, 160, 60, 255),
@@ -113,10 +163,37 @@ impl Element<InterfaceSettings> for ItemBox {
                 false,
             );
 
-            if let InventoryItemDetails::Regular { amount, .. } = &item.details {
+            match &item.details {
+                InventoryItemDetails::Regular { amount, .. } => {
+                    renderer.render_text(
+                        &format!("{}", amount),
+                        ScreenPosition::default(),
+                        theme.button.foreground_color.get(),
+                        FontSize::new(12.0),
+                    );
+                }
+                InventoryItemDetails::Equippable { refinement_level, .. } if *refinement_level > 0 => {
+                    renderer.render_text(
+                        &format!("+{}", refinement_level),
+                        ScreenPosition::default(),
+                        theme.button.foreground_color.get(),
+                        FontSize::new(12.0),
+                    );
+                }
+                _ => {}
+            }
+
+            // Show the full remaining time while hovering and only the largest unit
+            // otherwise, since the box is too small to fit everything.
+            if let Some((short_text, full_text)) = &self.rental_time {
+                let text = match is_hovered {
+                    true => full_text,
+                    false => short_text,
+                };
+
                 renderer.render_text(
-                    &format!("{}", amount),
-                    ScreenPosition::default(),
+                    text,
+                    ScreenPosition::only_top(18.0),
                     theme.button.foreground_color.get(),
                     FontSize::new(12.0),
                 );
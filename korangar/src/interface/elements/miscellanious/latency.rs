@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::ChangeEvent;
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::system::{packet_loss, LatencySample};
+
+/// Round trip time that fills the entire height of the graph. Higher round
+/// trip times scale the graph down.
+const MINIMUM_GRAPH_SCALE: Duration = Duration::from_millis(200);
+
+/// Bar graph of the round trip times and lost keep-alive packets.
+pub struct LatencyGraph {
+    samples: PlainRemote<Vec<LatencySample>>,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl LatencyGraph {
+    pub fn new(samples: PlainRemote<Vec<LatencySample>>) -> Self {
+        Self {
+            samples,
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for LatencyGraph {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(100%, 100));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.samples.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(theme.value.corner_radius.get(), theme.value.background_color.get());
+
+        let samples = self.samples.get();
+        let scale = samples
+            .iter()
+            .filter_map(|sample| sample.round_trip_time)
+            .fold(MINIMUM_GRAPH_SCALE, Duration::max);
+        let bar_width = self.state.cached_size.width / samples.len().max(1) as f32;
+
+        for (index, sample) in samples.iter().enumerate() {
+            let (bar_height, color) = match sample.round_trip_time {
+                Some(round_trip_time) => (
+                    self.state.cached_size.height * round_trip_time.div_duration_f32(scale),
+                    Color::rgb_u8(100, 200, 100),
+                ),
+                None => (self.state.cached_size.height, Color::rgb_u8(200, 60, 60)),
+            };
+
+            let bar_position = ScreenPosition {
+                left: index as f32 * bar_width,
+                top: self.state.cached_size.height - bar_height,
+            };
+            let bar_size = ScreenSize {
+                width: bar_width,
+                height: bar_height,
+            };
+
+            renderer.render_rectangle(bar_position, bar_size, CornerRadius::default(), color);
+        }
+
+        let ping = samples.iter().rev().find_map(|sample| sample.round_trip_time);
+        let text = match ping {
+            Some(ping) => format!("Ping {} ms, loss {:.0}%", ping.as_millis(), packet_loss(&samples) * 100.0),
+            None => format!("Ping unknown, loss {:.0}%", packet_loss(&samples) * 100.0),
+        };
+
+        renderer.render_text(
+            &text,
+            theme.value.text_offset.get(),
+            theme.value.foreground_color.get(),
+            theme.value.font_size.get(),
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::cell::Cell;
+
+use cgmath::Vector2;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::minimap::{facing_direction, MinimapData, MinimapMarkerKind, MinimapView, ZOOM_LEVELS};
+use crate::interface::theme::InterfaceTheme;
+
+/// Size of the player and marker dots before scaling.
+const DOT_SIZE: f32 = 6.0;
+
+/// Map of the surroundings of the player. Clicking shows the coordinates of
+/// the clicked tile.
+pub struct Minimap {
+    data: PlainRemote<MinimapData>,
+    zoom_level: PlainRemote<usize>,
+    clicked_tile: Cell<Option<Vector2<usize>>>,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl Minimap {
+    pub fn new(data: PlainRemote<MinimapData>, zoom_level: PlainRemote<usize>) -> Self {
+        Self {
+            data,
+            zoom_level,
+            clicked_tile: Cell::new(None),
+            state: ElementState::default(),
+        }
+    }
+
+    fn view(&self) -> MinimapView {
+        let data = self.data.get();
+        let zoom = ZOOM_LEVELS[(*self.zoom_level.get()).min(ZOOM_LEVELS.len() - 1)];
+
+        MinimapView::new(data.map_size, data.player_position, zoom)
+    }
+
+    fn to_element_position(&self, relative: Vector2<f32>) -> ScreenPosition {
+        ScreenPosition {
+            left: relative.x * self.state.cached_size.width,
+            top: relative.y * self.state.cached_size.height,
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for Minimap {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(100%, 200));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let data_changed = self.data.consume_changed();
+        let zoom_changed = self.zoom_level.consume_changed();
+
+        (data_changed || zoom_changed).then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        let mouse_position = self.state.mouse_position.get();
+        let relative = Vector2::new(
+            mouse_position.left / self.state.cached_size.width,
+            mouse_position.top / self.state.cached_size.height,
+        );
+        let map_size = self.data.get().map_size;
+        let tile = self.view().unproject(relative);
+
+        self.clicked_tile.set((tile.x < map_size.x && tile.y < map_size.y).then_some(tile));
+        *force_update = true;
+
+        Vec::new()
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(CornerRadius::default(), Color::rgb_u8(20, 20, 20));
+
+        let data = self.data.get();
+        let view = self.view();
+
+        if let Some(texture) = &data.texture {
+            // The whole map is rendered and cut to the visible area by the clip.
+            let map_size = data.map_size.map(|size| size as f32);
+            let scale = Vector2::new(
+                self.state.cached_size.width / view.size.x,
+                self.state.cached_size.height / view.size.y,
+            );
+            let offset = ScreenSize {
+                width: -view.origin.x * scale.x,
+                height: -(map_size.y - view.origin.y - view.size.y) * scale.y,
+            };
+            let size = ScreenSize {
+                width: map_size.x * scale.x,
+                height: map_size.y * scale.y,
+            };
+
+            renderer.renderer.render_sprite(
+                renderer.render_target,
+                texture.clone(),
+                renderer.position + offset,
+                size,
+                renderer.clip,
+                Color::monochrome_u8(255),
+                true,
+            );
+        }
+
+        let dot_size = DOT_SIZE * application.get_scaling_factor();
+        let dot = |center: ScreenPosition| center - ScreenSize::uniform(dot_size / 2.0);
+
+        for marker in &data.markers {
+            let color = match marker.kind {
+                MinimapMarkerKind::PartyMember => Color::rgb_u8(255, 140, 220),
+                MinimapMarkerKind::Npc => Color::rgb_u8(255, 210, 80),
+                MinimapMarkerKind::Warp => Color::rgb_u8(120, 180, 255),
+            };
+            let center = self.to_element_position(view.project(marker.position));
+
+            renderer.render_rectangle(
+                dot(center),
+                ScreenSize::uniform(dot_size),
+                CornerRadius::uniform(dot_size / 2.0),
+                color,
+            );
+        }
+
+        // The player is drawn as a dot with a short trail of smaller dots in the
+        // direction they are facing.
+        let player_color = Color::rgb_u8(255, 80, 80);
+        let player_center = self.to_element_position(view.project(data.player_position));
+        let facing = facing_direction(data.player_direction);
+
+        for step in 1..=3 {
+            let distance = step as f32 * dot_size * 0.6;
+            let size = dot_size * (1.0 - step as f32 * 0.2);
+            let center = player_center
+                + ScreenSize {
+                    width: facing.x * distance,
+                    height: facing.y * distance,
+                };
+
+            renderer.render_rectangle(
+                center - ScreenSize::uniform(size / 2.0),
+                ScreenSize::uniform(size),
+                CornerRadius::uniform(size / 2.0),
+                player_color,
+            );
+        }
+
+        renderer.render_rectangle(
+            dot(player_center),
+            ScreenSize::uniform(dot_size),
+            CornerRadius::uniform(dot_size / 2.0),
+            player_color,
+        );
+
+        if let Some(tile) = self.clicked_tile.get() {
+            renderer.render_text(
+                &format!("{}, {}", tile.x, tile.y),
+                theme.value.text_offset.get(),
+                theme.value.foreground_color.get(),
+                theme.value.font_size.get(),
+            );
+        }
+    }
+}
@@ -1,7 +1,31 @@
+mod appearance;
+mod attendance;
+mod balance;
 mod chat;
+mod countdown;
+mod emotion;
 mod item;
+mod latency;
+mod minimap;
+mod now_playing;
+mod progress;
+mod refine_result;
 mod skill;
+mod volume;
+mod world_map;
 
-pub use self::chat::ChatBuilder;
+pub use self::appearance::AppearancePreview;
+pub use self::attendance::AttendanceDay;
+pub use self::balance::BalanceText;
+pub use self::chat::{ChatBuilder, ChatTabButton};
+pub use self::countdown::Countdown;
+pub use self::emotion::EmotionBox;
 pub use self::item::ItemBox;
+pub use self::latency::LatencyGraph;
+pub use self::minimap::Minimap;
+pub use self::now_playing::NowPlayingText;
+pub use self::progress::ProgressBar;
+pub use self::refine_result::RefineResultText;
 pub use self::skill::SkillBox;
+pub use self::volume::VolumeSlider;
+pub use self::world_map::WorldMapView;
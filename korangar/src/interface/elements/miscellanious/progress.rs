@@ -0,0 +1,92 @@
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::layout::PlacementResolver;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+
+/// Horizontal bar displaying the progress towards a target value.
+pub struct ProgressBar {
+    text: String,
+    current: u32,
+    maximum: u32,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl ProgressBar {
+    pub fn new(text: String, current: u32, maximum: u32) -> Self {
+        Self {
+            text,
+            current,
+            maximum,
+            state: ElementState::default(),
+        }
+    }
+
+    fn get_ratio(&self) -> f32 {
+        match self.maximum {
+            0 => 1.0,
+            maximum => (self.current as f32 / maximum as f32).min(1.0),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for ProgressBar {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &theme.slider.size_bound);
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(theme.button.corner_radius.get(), theme.slider.background_color.get());
+
+        let bar_size = ScreenSize {
+            width: self.state.cached_size.width * self.get_ratio(),
+            height: self.state.cached_size.height,
+        };
+
+        renderer.render_rectangle(
+            ScreenPosition::default(),
+            bar_size,
+            theme.button.corner_radius.get(),
+            theme.slider.knob_color.get(),
+        );
+
+        renderer.render_text(
+            &format!("{} {}/{}", self.text, self.current, self.maximum),
+            theme.button.text_offset.get(),
+            theme.button.foreground_color.get(),
+            theme.button.font_size.get(),
+        );
+    }
+}
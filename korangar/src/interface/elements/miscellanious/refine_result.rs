@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+use korangar_interface::dimension_bound;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::ChangeEvent;
+use korangar_interface::layout::PlacementResolver;
+use ragnarok_packets::RefineResult;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::RefineOutcome;
+
+/// How long the result flashes after a refinement.
+const FLASH_DURATION: Duration = Duration::from_millis(1500);
+/// Number of times the background pulses while flashing.
+const PULSE_COUNT: f32 = 3.0;
+
+/// Result of the last refinement. Flashes in the color of the result when it
+/// arrives and then fades to a regular text.
+pub struct RefineResultText {
+    outcome: RefineOutcome,
+    animating: bool,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl RefineResultText {
+    pub fn new(outcome: RefineOutcome) -> Self {
+        Self {
+            outcome,
+            animating: true,
+            state: ElementState::default(),
+        }
+    }
+
+    fn result_color(&self) -> Color {
+        match self.outcome.result {
+            RefineResult::Success => Color::rgb_u8(100, 220, 100),
+            RefineResult::Failure => Color::rgb_u8(220, 100, 100),
+            RefineResult::Downgrade => Color::rgb_u8(220, 170, 80),
+        }
+    }
+
+    /// Progress of the flash from 0 to 1.
+    fn progress(&self) -> f32 {
+        (self.outcome.time.elapsed().as_secs_f32() / FLASH_DURATION.as_secs_f32()).min(1.0)
+    }
+}
+
+impl Element<InterfaceSettings> for RefineResultText {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = dimension_bound!(100%).add_height(theme.button.height_bound);
+        self.state.resolve(placement_resolver, &size_bound);
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if !self.animating {
+            return None;
+        }
+
+        // Render one last time after the flash is over to remove the background.
+        self.animating = self.progress() < 1.0;
+        Some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let progress = self.progress();
+        let result_color = self.result_color();
+
+        if progress < 1.0 {
+            let pulse = (progress * PULSE_COUNT * std::f32::consts::TAU).cos() * 0.5 + 0.5;
+            let alpha = (1.0 - progress) * (0.4 + pulse * 0.6);
+
+            renderer.render_background(theme.button.corner_radius.get(), result_color.multiply_alpha(alpha));
+        }
+
+        let foreground_color = match progress < 1.0 {
+            true => theme.button.foreground_color.get(),
+            false => result_color,
+        };
+
+        renderer.render_text(
+            &self.outcome.message,
+            theme.button.text_offset.get(),
+            foreground_color,
+            theme.button.font_size.get(),
+        );
+    }
+}
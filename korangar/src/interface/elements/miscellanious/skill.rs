@@ -6,7 +6,7 @@ use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
 
 use crate::graphics::{Color, InterfaceRenderer, Renderer};
-use crate::input::MouseInputMode;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition};
 use crate::interface::resource::{Move, PartialMove, SkillSource};
@@ -18,11 +18,22 @@ use crate::loaders::FontSize;
 pub struct SkillBox {
     skill: Option<Skill>,
     source: SkillSource,
-    highlight: Box<dyn Fn(&MouseInputMode) -> bool>,
+    /// Decides if a dragged skill can be dropped onto this box.
+    accepts: Box<dyn Fn(&PartialMove) -> bool>,
+    /// Only used for hotbar slots.
+    #[new(default)]
+    smart_cast: bool,
     #[new(default)]
     state: ElementState<InterfaceSettings>,
 }
 
+impl SkillBox {
+    pub fn with_smart_cast(mut self, smart_cast: bool) -> Self {
+        self.smart_cast = smart_cast;
+        self
+    }
+}
+
 impl Element<InterfaceSettings> for SkillBox {
     fn get_state(&self) -> &ElementState<InterfaceSettings> {
         &self.state
@@ -46,7 +57,7 @@ impl Element<InterfaceSettings> for SkillBox {
     }
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
-        match self.skill.is_some() || matches!(mouse_mode, MouseInputMode::MoveSkill(..)) {
+        match self.skill.is_some() || self.is_drop_target(mouse_mode) {
             true => self.state.hovered_element(mouse_position),
             false => HoverInformation::Missed,
         }
@@ -63,12 +74,27 @@ impl Element<InterfaceSettings> for SkillBox {
         Vec::new()
     }
 
+    fn right_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        match self.source {
+            SkillSource::Hotbar { slot } => vec![ClickAction::Custom(UserEvent::ToggleSmartCast(slot))],
+            SkillSource::SkillTree => Vec::new(),
+        }
+    }
+
+    fn accepts_drop(&self, drop_resource: &PartialMove) -> bool {
+        matches!(drop_resource, PartialMove::Skill { source, .. } if *source != self.source) && (self.accepts)(drop_resource)
+    }
+
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        if !self.accepts_drop(&drop_resource) {
+            return None;
+        }
+
         let PartialMove::Skill { source, skill } = drop_resource else {
             return None;
         };
 
-        (source != self.source).then_some(Move::Skill {
+        Some(Move::Skill {
             source,
             destination: self.source,
             skill,
@@ -92,7 +118,7 @@ impl Element<InterfaceSettings> for SkillBox {
             .state
             .element_renderer(render_target, renderer, application, parent_position, screen_clip);
 
-        let highlight = (self.highlight)(mouse_mode);
+        let highlight = self.is_drop_target(mouse_mode);
         let background_color = match self.is_element_self(hovered_element) || self.is_element_self(focused_element) {
             true if highlight => Color::rgba_u8(60, 160, 160, 255),
             true if matches!(mouse_mode, MouseInputMode::None) => theme.button.hovered_background_color.get(),
@@ -102,6 +128,15 @@ impl Element<InterfaceSettings> for SkillBox {
 
         renderer.render_background(CornerRadius::uniform(5.0), background_color);
 
+        if self.smart_cast {
+            renderer.render_text(
+                "S",
+                ScreenPosition { left: 21.0, top: 15.0 },
+                Color::rgb_u8(255, 200, 80),
+                FontSize::new(12.0),
+            );
+        }
+
         if let Some(skill) = &self.skill {
             skill.actions.render2(
                 renderer.render_target,
@@ -0,0 +1,126 @@
+use korangar_interface::application::MouseInputModeTrait;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{Remote, TrackedState};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+
+/// Horizontal bar for adjusting a volume between `0.0` and `1.0` by dragging.
+pub struct VolumeSlider<Volume>
+where
+    Volume: TrackedState<f32> + 'static,
+{
+    text: String,
+    volume: Volume,
+    remote: Volume::RemoteType,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl<Volume> VolumeSlider<Volume>
+where
+    Volume: TrackedState<f32> + 'static,
+{
+    pub fn new(text: String, volume: Volume) -> Self {
+        let remote = volume.new_remote();
+
+        Self {
+            text,
+            volume,
+            remote,
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl<Volume> Element<InterfaceSettings> for VolumeSlider<Volume>
+where
+    Volume: TrackedState<f32> + 'static,
+{
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &theme.slider.size_bound);
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.remote.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        if mouse_mode.is_none() {
+            self.state.hovered_element(mouse_position)
+        } else if mouse_mode.is_self_dragged(self) {
+            HoverInformation::Hovered
+        } else {
+            HoverInformation::Missed
+        }
+    }
+
+    fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        vec![ClickAction::DragElement]
+    }
+
+    fn drag(&mut self, mouse_delta: ScreenPosition) -> Option<ChangeEvent> {
+        let volume = (*self.volume.get() + mouse_delta.left * 0.005).clamp(0.0, 1.0);
+        self.volume.set(volume);
+
+        None
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let volume = *self.volume.get();
+
+        renderer.render_background(theme.button.corner_radius.get(), theme.slider.background_color.get());
+
+        let bar_size = ScreenSize {
+            width: self.state.cached_size.width * volume,
+            height: self.state.cached_size.height,
+        };
+
+        renderer.render_rectangle(
+            ScreenPosition::default(),
+            bar_size,
+            theme.button.corner_radius.get(),
+            theme.slider.knob_color.get(),
+        );
+
+        renderer.render_text(
+            &format!("{} {}%", self.text, (volume * 100.0).round()),
+            theme.button.text_offset.get(),
+            theme.button.foreground_color.get(),
+            theme.button.font_size.get(),
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use vulkano::image::view::ImageView;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::world_map::WorldMap;
+
+/// Size of the area markers before scaling.
+const MARKER_SIZE: f32 = 8.0;
+/// Distance between two dots of a connection before scaling.
+const CONNECTION_SPACING: f32 = 6.0;
+/// Distance from a marker in which a click selects the area, as a fraction of
+/// the world map.
+const CLICK_DISTANCE: f32 = 0.04;
+
+/// World map with every known area and the connections between them. The
+/// current map and the areas matching the search are highlighted.
+pub struct WorldMapView {
+    world_map: WorldMap,
+    texture: Option<Arc<ImageView>>,
+    current_map_name: String,
+    search: PlainRemote<String>,
+    selected_area: RefCell<Option<String>>,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl WorldMapView {
+    pub fn new(world_map: WorldMap, texture: Option<Arc<ImageView>>, current_map_name: String, search: PlainRemote<String>) -> Self {
+        Self {
+            world_map,
+            texture,
+            current_map_name,
+            search,
+            selected_area: RefCell::new(None),
+            state: ElementState::default(),
+        }
+    }
+
+    fn to_element_position(&self, left: f32, top: f32) -> ScreenPosition {
+        ScreenPosition {
+            left: left * self.state.cached_size.width,
+            top: top * self.state.cached_size.height,
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for WorldMapView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(100%, 400));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.search.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        let mouse_position = self.state.mouse_position.get();
+        let left = mouse_position.left / self.state.cached_size.width;
+        let top = mouse_position.top / self.state.cached_size.height;
+
+        *self.selected_area.borrow_mut() = self
+            .world_map
+            .area_at(left, top, CLICK_DISTANCE)
+            .map(|area| format!("{} ({})", area.display_name, area.map_name));
+        *force_update = true;
+
+        Vec::new()
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(CornerRadius::default(), Color::rgb_u8(30, 40, 50));
+
+        if let Some(texture) = &self.texture {
+            renderer.renderer.render_sprite(
+                renderer.render_target,
+                texture.clone(),
+                renderer.position,
+                self.state.cached_size,
+                renderer.clip,
+                Color::monochrome_u8(255),
+                true,
+            );
+        }
+
+        let scaling_factor = application.get_scaling_factor();
+        let search = self.search.get();
+        let matches = self.world_map.search(&search);
+
+        // Connections are drawn as dotted lines so they don't cover the world map.
+        let dot_size = 2.0 * scaling_factor;
+        let spacing = CONNECTION_SPACING * scaling_factor;
+
+        for (from, to) in self.world_map.connections() {
+            let start = self.to_element_position(from.left, from.top);
+            let end = self.to_element_position(to.left, to.top);
+            let difference = ScreenSize {
+                width: end.left - start.left,
+                height: end.top - start.top,
+            };
+            let steps = (f32::hypot(difference.width, difference.height) / spacing) as usize;
+
+            for step in 1..steps {
+                let progress = step as f32 / steps as f32;
+                let center = start
+                    + ScreenSize {
+                        width: difference.width * progress,
+                        height: difference.height * progress,
+                    };
+
+                renderer.render_rectangle(
+                    center - ScreenSize::uniform(dot_size / 2.0),
+                    ScreenSize::uniform(dot_size),
+                    CornerRadius::default(),
+                    Color::rgba_u8(255, 255, 255, 160),
+                );
+            }
+        }
+
+        let marker_size = MARKER_SIZE * scaling_factor;
+
+        for area in &self.world_map.areas {
+            let is_current = area.map_name == self.current_map_name;
+            let is_match = matches.iter().any(|matched| matched.map_name == area.map_name);
+
+            let (size, color) = match (is_current, is_match) {
+                (true, _) => (marker_size * 1.5, Color::rgb_u8(255, 80, 80)),
+                (false, true) => (marker_size * 1.5, Color::rgb_u8(255, 210, 80)),
+                (false, false) => (marker_size, Color::rgb_u8(220, 220, 220)),
+            };
+            let center = self.to_element_position(area.left, area.top);
+
+            renderer.render_rectangle(
+                center - ScreenSize::uniform(size / 2.0),
+                ScreenSize::uniform(size),
+                CornerRadius::uniform(size / scaling_factor / 2.0),
+                color,
+            );
+
+            // Text offsets are scaled by the renderer.
+            if is_current || is_match {
+                renderer.render_text(
+                    &area.display_name,
+                    ScreenPosition {
+                        left: (center.left + size) / scaling_factor,
+                        top: (center.top - size) / scaling_factor,
+                    },
+                    color,
+                    theme.value.font_size.get(),
+                );
+            }
+        }
+
+        if let Some(selected_area) = self.selected_area.borrow().as_ref() {
+            renderer.render_text(
+                selected_area,
+                theme.value.text_offset.get(),
+                theme.value.foreground_color.get(),
+                theme.value.font_size.get(),
+            );
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::input::MouseInputMode;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::{ShopEntry, ShopEntryOperation};
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::resource::PartialMove;
 use crate::interface::theme::InterfaceTheme;
 use crate::loaders::ResourceMetadata;
 
@@ -142,7 +143,7 @@ impl Element<InterfaceSettings> for BuyContainer {
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match mouse_mode {
-            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::MoveResource(PartialMove::Item { .. }) => self.state.state.hovered_element(mouse_position),
             MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
             _ => HoverInformation::Missed,
         }
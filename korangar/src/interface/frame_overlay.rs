@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+
+use korangar_interface::application::{Application, FontSizeTrait, FontSizeTraitExt, PositionTraitExt};
+
+use crate::graphics::{DeferredRenderer, Renderer};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::theme::GameTheme;
+
+/// Number of frames that the statistics and the graph are based on.
+const SAMPLE_COUNT: usize = 240;
+
+/// Frame time in seconds that fills the whole height of the graph.
+const GRAPH_MAXIMUM: f32 = 1.0 / 20.0;
+
+/// Frames that take longer than this are highlighted in the graph.
+const SLOW_FRAME_TIME: f32 = 1.0 / 30.0;
+
+/// Frame times of the most recent frames, shown in an overlay.
+#[derive(Default)]
+pub struct FrameStatistics {
+    /// Frame times in seconds, oldest first.
+    frame_times: VecDeque<f32>,
+}
+
+impl FrameStatistics {
+    pub fn add_frame(&mut self, delta_time: f64) {
+        if self.frame_times.len() == SAMPLE_COUNT {
+            self.frame_times.pop_front();
+        }
+
+        self.frame_times.push_back(delta_time as f32);
+    }
+
+    pub fn frames_per_second(&self) -> f32 {
+        let total: f32 = self.frame_times.iter().sum();
+
+        match total > 0.0 {
+            true => self.frame_times.len() as f32 / total,
+            false => 0.0,
+        }
+    }
+
+    /// Frame time in seconds that the given fraction of frames are faster
+    /// than.
+    pub fn percentile(&self, fraction: f32) -> f32 {
+        let mut sorted: Vec<f32> = self.frame_times.iter().copied().collect();
+
+        if sorted.is_empty() {
+            return 0.0;
+        }
+
+        sorted.sort_by(f32::total_cmp);
+
+        let index = ((sorted.len() - 1) as f32 * fraction.clamp(0.0, 1.0)).round() as usize;
+        sorted[index]
+    }
+
+    pub fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        application: &InterfaceSettings,
+    ) {
+        let scaling = application.get_scaling_factor();
+        let font_size = theme.frame_overlay.font_size.get().scaled(application.get_scaling());
+        let padding = theme.frame_overlay.padding.get() * scaling;
+        let graph_height = theme.frame_overlay.graph_height.get() * scaling;
+        let bar_width = theme.frame_overlay.bar_width.get() * scaling;
+        let line_height = font_size.get_value();
+
+        let position = theme.frame_overlay.position.get().scaled(application.get_scaling());
+        let size = ScreenSize {
+            width: SAMPLE_COUNT as f32 * bar_width + padding * 2.0,
+            height: line_height * 2.0 + graph_height + padding * 3.0,
+        };
+
+        let lines = [
+            format!("{:.0} FPS", self.frames_per_second()),
+            format!(
+                "{:.1} ms  p95 {:.1} ms  p99 {:.1} ms",
+                self.percentile(0.5) * 1000.0,
+                self.percentile(0.95) * 1000.0,
+                self.percentile(0.99) * 1000.0
+            ),
+        ];
+
+        renderer.render_rectangle(render_target, position, size, theme.frame_overlay.background_color.get());
+
+        for (index, line) in lines.iter().enumerate() {
+            let text_position = position
+                + ScreenPosition {
+                    left: padding,
+                    top: padding + index as f32 * line_height,
+                };
+
+            renderer.render_text(
+                render_target,
+                line,
+                text_position,
+                theme.frame_overlay.foreground_color.get(),
+                font_size,
+            );
+        }
+
+        let graph_bottom = position.top + size.height - padding;
+
+        for (index, frame_time) in self.frame_times.iter().enumerate() {
+            let height = (frame_time / GRAPH_MAXIMUM).min(1.0) * graph_height;
+            let color = match *frame_time > SLOW_FRAME_TIME {
+                true => theme.frame_overlay.slow_frame_color.get(),
+                false => theme.frame_overlay.graph_color.get(),
+            };
+
+            let bar_position = ScreenPosition {
+                left: position.left + padding + index as f32 * bar_width,
+                top: graph_bottom - height,
+            };
+
+            renderer.render_rectangle(render_target, bar_position, ScreenSize { width: bar_width, height }, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod statistics {
+    use super::{FrameStatistics, SAMPLE_COUNT};
+
+    #[test]
+    fn empty() {
+        let statistics = FrameStatistics::default();
+
+        assert_eq!(statistics.frames_per_second(), 0.0);
+        assert_eq!(statistics.percentile(0.99), 0.0);
+    }
+
+    #[test]
+    fn frames_per_second() {
+        let mut statistics = FrameStatistics::default();
+
+        (0..60).for_each(|_| statistics.add_frame(1.0 / 60.0));
+
+        assert!((statistics.frames_per_second() - 60.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn percentiles() {
+        let mut statistics = FrameStatistics::default();
+
+        (1..=100).for_each(|milliseconds| statistics.add_frame(milliseconds as f64 / 1000.0));
+
+        assert_eq!(statistics.percentile(0.0), 0.001);
+        assert_eq!(statistics.percentile(1.0), 0.1);
+        assert!((statistics.percentile(0.95) - 0.095).abs() < 0.0015);
+    }
+
+    #[test]
+    fn keep_recent_frames() {
+        let mut statistics = FrameStatistics::default();
+
+        (0..SAMPLE_COUNT).for_each(|_| statistics.add_frame(1.0));
+        (0..SAMPLE_COUNT).for_each(|_| statistics.add_frame(0.01));
+
+        assert_eq!(statistics.percentile(1.0), 0.01);
+    }
+}
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use cgmath::Vector2;
+use korangar_interface::state::{PlainTrackedState, TrackedState, ValueState};
+use vulkano::image::view::ImageView;
+
+use crate::loaders::{GameFileLoader, TextureLoader};
+use crate::world::{Entity, EntityType, Map};
+
+/// Zoom levels of the minimap. At the first level the whole map is visible.
+pub const ZOOM_LEVELS: [f32; 4] = [1.0, 2.0, 4.0, 8.0];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MinimapMarkerKind {
+    PartyMember,
+    Npc,
+    Warp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MinimapMarker {
+    pub position: Vector2<usize>,
+    pub kind: MinimapMarkerKind,
+}
+
+/// Everything that is shown on the minimap.
+#[derive(Clone, Default)]
+pub struct MinimapData {
+    pub map_size: Vector2<usize>,
+    /// Image of the map, either from the game files or generated from the
+    /// walkable tiles.
+    pub texture: Option<Arc<ImageView>>,
+    pub player_position: Vector2<usize>,
+    /// Direction that the player is facing, starting with 0 for south and
+    /// going clockwise.
+    pub player_direction: usize,
+    pub markers: Vec<MinimapMarker>,
+}
+
+/// Update the player and the markers of the minimap. The state is only changed
+/// if something moved, so this can be called every frame.
+///
+/// Party members are shown everywhere on the map, since the map server sends
+/// their positions even when they are out of sight.
+pub fn update_minimap(
+    minimap_data: &mut PlainTrackedState<MinimapData>,
+    entities: &[Entity],
+    party_member_positions: impl IntoIterator<Item = Vector2<usize>>,
+) {
+    let Some((player, others)) = entities.split_first() else {
+        return;
+    };
+
+    let party_markers = party_member_positions.into_iter().map(|position| MinimapMarker {
+        position,
+        kind: MinimapMarkerKind::PartyMember,
+    });
+
+    let markers: Vec<MinimapMarker> = others
+        .iter()
+        .filter_map(|entity| {
+            let kind = match entity.get_entity_type() {
+                EntityType::Npc => MinimapMarkerKind::Npc,
+                EntityType::Warp => MinimapMarkerKind::Warp,
+                _ => return None,
+            };
+
+            Some(MinimapMarker {
+                position: entity.get_grid_position(),
+                kind,
+            })
+        })
+        .chain(party_markers)
+        .collect();
+
+    let player_position = player.get_grid_position();
+    let player_direction = player.get_head_direction();
+
+    minimap_data.with_mut(|data| {
+        if data.player_position == player_position && data.player_direction == player_direction && data.markers == markers {
+            return ValueState::Unchanged(());
+        }
+
+        data.player_position = player_position;
+        data.player_direction = player_direction;
+        data.markers = markers;
+        ValueState::Mutated(())
+    });
+}
+
+/// Image of the map from the game files, or an image generated from the tiles
+/// of the map if there is none.
+pub fn load_minimap_texture(
+    map_name: &str,
+    map: &Map,
+    texture_loader: &mut TextureLoader,
+    game_file_loader: &mut GameFileLoader,
+) -> Arc<ImageView> {
+    let path = format!("À¯ÀúÀÎÅÍÆäÀÌ½º\\map\\{map_name}.bmp");
+
+    texture_loader
+        .get(&path, game_file_loader)
+        .unwrap_or_else(|_| texture_loader.get_or_create(&format!("generated_minimap\\{map_name}"), || map.generate_minimap_image()))
+}
+
+/// Part of the map that is visible on the minimap, in tiles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinimapView {
+    pub origin: Vector2<f32>,
+    pub size: Vector2<f32>,
+}
+
+impl MinimapView {
+    /// The view is centered on the player, but never shows anything outside
+    /// of the map.
+    pub fn new(map_size: Vector2<usize>, center: Vector2<usize>, zoom: f32) -> Self {
+        let map_size = map_size.map(|size| size as f32);
+        let size = map_size / zoom.max(1.0);
+        let center = center.map(|tile| tile as f32 + 0.5);
+
+        let origin = Vector2::new(
+            (center.x - size.x / 2.0).clamp(0.0, map_size.x - size.x),
+            (center.y - size.y / 2.0).clamp(0.0, map_size.y - size.y),
+        );
+
+        Self { origin, size }
+    }
+
+    /// Position of the center of a tile relative to the view, from 0 to 1.
+    /// North is at the top.
+    pub fn project(&self, tile: Vector2<usize>) -> Vector2<f32> {
+        let tile = tile.map(|tile| tile as f32 + 0.5);
+
+        Vector2::new(
+            (tile.x - self.origin.x) / self.size.x,
+            1.0 - (tile.y - self.origin.y) / self.size.y,
+        )
+    }
+
+    /// Tile at a position relative to the view, from 0 to 1.
+    pub fn unproject(&self, position: Vector2<f32>) -> Vector2<usize> {
+        Vector2::new(
+            (self.origin.x + position.x * self.size.x).max(0.0) as usize,
+            (self.origin.y + (1.0 - position.y) * self.size.y).max(0.0) as usize,
+        )
+    }
+}
+
+/// Direction on the screen that an entity facing the given direction looks
+/// at, with north at the top.
+pub fn facing_direction(direction: usize) -> Vector2<f32> {
+    let (x, y) = match direction % 8 {
+        0 => (0.0, 1.0),
+        1 => (-1.0, 1.0),
+        2 => (-1.0, 0.0),
+        3 => (-1.0, -1.0),
+        4 => (0.0, -1.0),
+        5 => (1.0, -1.0),
+        6 => (1.0, 0.0),
+        _ => (1.0, 1.0),
+    };
+
+    let length = f32::sqrt(x * x + y * y);
+    Vector2::new(x / length, y / length)
+}
+
+#[cfg(test)]
+mod projection {
+    use cgmath::Vector2;
+
+    use super::MinimapView;
+
+    #[test]
+    fn whole_map() {
+        let view = MinimapView::new(Vector2::new(100, 200), Vector2::new(10, 10), 1.0);
+
+        assert_eq!(view.origin, Vector2::new(0.0, 0.0));
+        assert_eq!(view.size, Vector2::new(100.0, 200.0));
+    }
+
+    #[test]
+    fn centered_on_player() {
+        let view = MinimapView::new(Vector2::new(100, 100), Vector2::new(49, 49), 4.0);
+
+        assert_eq!(view.origin, Vector2::new(37.0, 37.0));
+        assert_eq!(view.project(Vector2::new(49, 49)), Vector2::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn clamped_to_map() {
+        let view = MinimapView::new(Vector2::new(100, 100), Vector2::new(2, 98), 2.0);
+
+        assert_eq!(view.origin, Vector2::new(0.0, 50.0));
+    }
+
+    #[test]
+    fn north_at_the_top() {
+        let view = MinimapView::new(Vector2::new(100, 100), Vector2::new(0, 0), 1.0);
+
+        assert!(view.project(Vector2::new(50, 99)).y < 0.05);
+        assert!(view.project(Vector2::new(50, 0)).y > 0.95);
+    }
+
+    #[test]
+    fn unproject() {
+        let view = MinimapView::new(Vector2::new(100, 100), Vector2::new(30, 60), 2.0);
+
+        for tile in [Vector2::new(30, 60), Vector2::new(5, 26), Vector2::new(54, 99)] {
+            assert_eq!(view.unproject(view.project(tile)), tile);
+        }
+    }
+}
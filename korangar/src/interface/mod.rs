@@ -3,8 +3,17 @@ pub mod theme;
 #[macro_use]
 pub mod elements;
 pub mod application;
+pub mod chat;
 pub mod cursor;
 pub mod dialog;
+pub mod frame_overlay;
 pub mod linked;
+pub mod minimap;
+pub mod notifications;
+pub mod privacy;
+pub mod quest_tracker;
+pub mod radial_menu;
+pub mod requests;
 pub mod resource;
 pub mod windows;
+pub mod world_map;
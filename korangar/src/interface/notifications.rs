@@ -0,0 +1,237 @@
+use std::time::{Duration, Instant};
+
+use korangar_interface::application::{Application, FontSizeTraitExt};
+
+use crate::graphics::{DeferredRenderer, Renderer};
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::theme::GameTheme;
+
+/// Maximum number of notifications on screen at the same time. Further
+/// notifications wait until one of them is dismissed.
+const MAXIMUM_VISIBLE: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationKind {
+    Item,
+    Social,
+    Achievement,
+    Warning,
+}
+
+impl NotificationKind {
+    fn duration(self) -> Duration {
+        match self {
+            NotificationKind::Warning => Duration::from_secs(10),
+            _ => Duration::from_secs(5),
+        }
+    }
+}
+
+struct Notification {
+    kind: NotificationKind,
+    text: String,
+    /// Event that is triggered when clicking the notification.
+    action: Option<UserEvent>,
+    /// The timer only starts once the notification is on screen.
+    shown_since: Option<Instant>,
+}
+
+/// Queue of notifications that are stacked in the bottom right corner of
+/// the screen and dismissed automatically after a while.
+#[derive(Default)]
+pub struct Notifications {
+    queue: Vec<Notification>,
+    bounds: Vec<(ScreenPosition, ScreenSize)>,
+}
+
+impl Notifications {
+    pub fn push(&mut self, kind: NotificationKind, text: impl Into<String>) {
+        self.queue.push(Notification {
+            kind,
+            text: text.into(),
+            action: None,
+            shown_since: None,
+        });
+    }
+
+    /// Push a notification that triggers an event when clicked.
+    pub fn push_with_action(&mut self, kind: NotificationKind, text: impl Into<String>, action: UserEvent) {
+        self.queue.push(Notification {
+            kind,
+            text: text.into(),
+            action: Some(action),
+            shown_since: None,
+        });
+    }
+
+    fn advance(&mut self, now: Instant) {
+        self.queue.retain(|notification| {
+            notification
+                .shown_since
+                .map_or(true, |shown_since| now.duration_since(shown_since) < notification.kind.duration())
+        });
+
+        for notification in self.queue.iter_mut().take(MAXIMUM_VISIBLE) {
+            notification.shown_since.get_or_insert(now);
+        }
+    }
+
+    /// Dismiss expired notifications and place the visible ones on the
+    /// screen.
+    pub fn update(&mut self, renderer: &DeferredRenderer, theme: &GameTheme, application: &InterfaceSettings, window_size: ScreenSize) {
+        self.advance(Instant::now());
+
+        let scaling = application.get_scaling_factor();
+        let font_size = theme.notification.font_size.get().scaled(application.get_scaling());
+        let padding = theme.notification.padding.get() * scaling;
+
+        let sizes: Vec<ScreenSize> = self
+            .queue
+            .iter()
+            .take(MAXIMUM_VISIBLE)
+            .map(|notification| renderer.get_text_dimensions(&notification.text, font_size) + ScreenSize::uniform(padding * 2.0))
+            .collect();
+
+        let positions = stack_bottom_right(
+            &sizes,
+            window_size,
+            theme.notification.offset.get() * scaling,
+            theme.notification.spacing.get() * scaling,
+        );
+
+        self.bounds = positions.into_iter().zip(sizes).collect();
+    }
+
+    /// Dismiss the notification under the mouse and push its action, if it has
+    /// one. Returns `true` if a notification was clicked.
+    pub fn click(&mut self, mouse_position: ScreenPosition, events: &mut Vec<UserEvent>) -> bool {
+        let Some(index) = self.bounds.iter().position(|(position, size)| {
+            mouse_position.left >= position.left
+                && mouse_position.top >= position.top
+                && mouse_position.left <= position.left + size.width
+                && mouse_position.top <= position.top + size.height
+        }) else {
+            return false;
+        };
+
+        let notification = self.queue.remove(index);
+        self.bounds.remove(index);
+        events.extend(notification.action);
+
+        true
+    }
+
+    pub fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        application: &InterfaceSettings,
+    ) {
+        let scaling = application.get_scaling_factor();
+        let font_size = theme.notification.font_size.get().scaled(application.get_scaling());
+        let padding = theme.notification.padding.get() * scaling;
+
+        for (notification, (position, size)) in self.queue.iter().zip(&self.bounds) {
+            let foreground_color = match notification.kind {
+                NotificationKind::Warning => theme.notification.warning_color.get(),
+                _ => theme.notification.foreground_color.get(),
+            };
+
+            renderer.render_rectangle(render_target, *position, *size, theme.notification.background_color.get());
+            renderer.render_text(
+                render_target,
+                &notification.text,
+                *position + ScreenSize::uniform(padding),
+                foreground_color,
+                font_size,
+            );
+        }
+    }
+}
+
+/// Stack boxes upwards, starting in the bottom right corner of the window.
+fn stack_bottom_right(sizes: &[ScreenSize], window_size: ScreenSize, offset: f32, spacing: f32) -> Vec<ScreenPosition> {
+    let mut bottom = window_size.height - offset;
+
+    sizes
+        .iter()
+        .map(|size| {
+            let position = ScreenPosition {
+                left: window_size.width - offset - size.width,
+                top: bottom - size.height,
+            };
+
+            bottom = position.top - spacing;
+            position
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod queue {
+    use std::time::{Duration, Instant};
+
+    use super::{NotificationKind, Notifications, MAXIMUM_VISIBLE};
+
+    #[test]
+    fn dismissed_after_duration() {
+        let mut notifications = Notifications::default();
+        let now = Instant::now();
+
+        notifications.push(NotificationKind::Item, "item");
+        notifications.push(NotificationKind::Warning, "warning");
+        notifications.advance(now);
+        notifications.advance(now + Duration::from_secs(6));
+
+        assert_eq!(notifications.queue.len(), 1);
+        assert_eq!(notifications.queue[0].kind, NotificationKind::Warning);
+    }
+
+    #[test]
+    fn waiting_notifications_keep_their_time() {
+        let mut notifications = Notifications::default();
+        let now = Instant::now();
+
+        for _ in 0..MAXIMUM_VISIBLE + 1 {
+            notifications.push(NotificationKind::Social, "friend");
+        }
+
+        notifications.advance(now);
+        assert!(notifications.queue[MAXIMUM_VISIBLE].shown_since.is_none());
+
+        notifications.advance(now + Duration::from_secs(6));
+        assert_eq!(notifications.queue.len(), 1);
+        assert_eq!(notifications.queue[0].shown_since, Some(now + Duration::from_secs(6)));
+    }
+}
+
+#[cfg(test)]
+mod stacking {
+    use super::stack_bottom_right;
+    use crate::interface::layout::{ScreenPosition, ScreenSize};
+
+    #[test]
+    fn stack_upwards() {
+        let sizes = [
+            ScreenSize {
+                width: 100.0,
+                height: 20.0,
+            },
+            ScreenSize { width: 50.0, height: 30.0 },
+        ];
+        let window_size = ScreenSize {
+            width: 800.0,
+            height: 600.0,
+        };
+
+        let positions = stack_bottom_right(&sizes, window_size, 10.0, 5.0);
+
+        assert_eq!(positions, vec![ScreenPosition { left: 690.0, top: 570.0 }, ScreenPosition {
+            left: 740.0,
+            top: 535.0,
+        }]);
+    }
+}
@@ -0,0 +1,164 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use serde::{Deserialize, Serialize};
+
+use crate::system::SettingsFile;
+
+/// Label that replaces a name in streamer mode, like `Party 1A2B`. The label
+/// is derived from the name, so the same name keeps the same label across
+/// sessions.
+pub fn mask_label(kind: &str, name: &str) -> String {
+    // FNV-1a, since the hasher of the standard library isn't guaranteed to be
+    // stable between versions.
+    let hash = name
+        .bytes()
+        .fold(0x811C9DC5_u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193));
+
+    format!("{kind} {:04X}", hash & 0xFFFF)
+}
+
+/// Label that replaces the name of another player in streamer mode.
+pub fn mask_name(name: &str) -> String {
+    mask_label("Player", name)
+}
+
+/// Name of another player as it should be shown on screen.
+pub fn display_name(name: &str, streamer_mode: bool) -> Cow<'_, str> {
+    match streamer_mode {
+        true => Cow::Owned(mask_name(name)),
+        false => Cow::Borrowed(name),
+    }
+}
+
+/// Mask the sender of a public chat message, unless it was sent by the
+/// player.
+pub fn mask_chat_message(text: String, player_name: &str, streamer_mode: bool) -> String {
+    if !streamer_mode {
+        return text;
+    }
+
+    match text.split_once(" : ") {
+        Some((sender, message)) if sender != player_name => format!("{} : {message}", mask_name(sender)),
+        _ => text,
+    }
+}
+
+/// Mask the other player of a whisper line in the chat and leave out the
+/// contents in streamer mode.
+pub fn mask_whisper(text: String, streamer_mode: bool) -> String {
+    if !streamer_mode {
+        return text;
+    }
+
+    let masked = text
+        .strip_prefix('(')
+        .and_then(|text| text.split_once(") : "))
+        .and_then(|(partner, _)| partner.split_once(' '))
+        .map(|(direction, name)| format!("({direction} {}) : ...", mask_name(name)));
+
+    masked.unwrap_or(text)
+}
+
+/// Text of the notification for a received whisper. In streamer mode the
+/// contents of the whisper are left out.
+pub fn whisper_notification(text: &str, streamer_mode: bool) -> Option<String> {
+    let (sender, message) = text.strip_prefix("(From ")?.split_once(") : ")?;
+
+    match streamer_mode {
+        true => Some(format!("Whisper from {}", mask_name(sender))),
+        false => Some(format!("Whisper from {sender}: {message}")),
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct PrivacySettings {
+    /// Hide the names of other players, the contents of whispers and the
+    /// account name, for people broadcasting their game.
+    #[serde(default)]
+    pub streamer_mode: bool,
+}
+
+impl PrivacySettings {
+    const SECTION: &'static str = "privacy";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load privacy settings");
+
+            Default::default()
+        })
+    }
+
+    pub fn load() -> Option<Self> {
+        SettingsFile::load_section(Self::SECTION)
+    }
+
+    pub fn save(&self) {
+        SettingsFile::save_section(Self::SECTION, self);
+    }
+}
+
+impl Drop for PrivacySettings {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
+
+#[cfg(test)]
+mod streamer_mode {
+    use super::{display_name, mask_chat_message, mask_label, mask_name, mask_whisper, whisper_notification};
+
+    #[test]
+    fn stable_labels() {
+        assert_eq!(mask_name("Poring"), mask_name("Poring"));
+        assert_ne!(mask_name("Poring"), mask_name("Drops"));
+        assert!(mask_name("Poring").starts_with("Player "));
+        assert!(mask_label("Party", "Poring").starts_with("Party "));
+    }
+
+    #[test]
+    fn display_names() {
+        assert_eq!(display_name("Poring", false), "Poring");
+        assert_eq!(display_name("Poring", true), mask_name("Poring"));
+    }
+
+    #[test]
+    fn chat_messages() {
+        let message = "Poring : hello".to_owned();
+
+        assert_eq!(mask_chat_message(message.clone(), "Drops", false), "Poring : hello");
+        assert_eq!(
+            mask_chat_message(message.clone(), "Drops", true),
+            format!("{} : hello", mask_name("Poring"))
+        );
+        assert_eq!(mask_chat_message(message, "Poring", true), "Poring : hello");
+    }
+
+    #[test]
+    fn whisper_lines() {
+        let received = "(From Poring) : meet me in prontera".to_owned();
+        let sent = "(To Poring) : on my way".to_owned();
+
+        assert_eq!(mask_whisper(received.clone(), false), received);
+        assert_eq!(mask_whisper(received, true), format!("(From {}) : ...", mask_name("Poring")));
+        assert_eq!(mask_whisper(sent, true), format!("(To {}) : ...", mask_name("Poring")));
+    }
+
+    #[test]
+    fn whisper_notifications() {
+        let whisper = "(From Poring) : meet me in prontera";
+
+        assert_eq!(
+            whisper_notification(whisper, false).as_deref(),
+            Some("Whisper from Poring: meet me in prontera")
+        );
+        assert_eq!(
+            whisper_notification(whisper, true),
+            Some(format!("Whisper from {}", mask_name("Poring")))
+        );
+        assert_eq!(whisper_notification("Poring : hello", true), None);
+    }
+}
@@ -0,0 +1,52 @@
+use korangar_interface::application::{Application, FontSizeTraitExt, PositionTraitExt};
+
+use crate::graphics::{Color, DeferredRenderer, Renderer};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::theme::GameTheme;
+use crate::inventory::Quest;
+
+/// Maximum number of quests that are shown in the quest tracker.
+const MAXIMUM_TRACKED_QUESTS: usize = 5;
+
+/// Render a compact list of the active quests and their objectives in the
+/// top right corner of the screen.
+pub fn render_quest_tracker(
+    render_target: &mut <DeferredRenderer as Renderer>::Target,
+    renderer: &DeferredRenderer,
+    theme: &GameTheme,
+    application: &InterfaceSettings,
+    window_size: ScreenSize,
+    quests: &[Quest],
+) {
+    let font_size = theme.quest_tracker.font_size.get().scaled(application.get_scaling());
+    let offset = theme.quest_tracker.offset.get().scaled(application.get_scaling());
+    let mut top = offset.top;
+
+    let mut render_line = |text: &str, color: Color| {
+        let text_size = renderer.get_text_dimensions(text, font_size);
+        let position = ScreenPosition {
+            left: window_size.width - offset.left - text_size.width,
+            top,
+        };
+
+        renderer.render_text(render_target, text, position, color, font_size);
+        top += text_size.height;
+    };
+
+    for quest in quests.iter().filter(|quest| quest.entry.active).take(MAXIMUM_TRACKED_QUESTS) {
+        render_line(&quest.name, theme.quest_tracker.title_color.get());
+
+        for objective in &quest.entry.objectives {
+            let color = match objective.current_count >= objective.total_count {
+                true => theme.quest_tracker.completed_color.get(),
+                false => theme.quest_tracker.objective_color.get(),
+            };
+
+            render_line(
+                &format!("{} {}/{}", objective.mob_name, objective.current_count, objective.total_count),
+                color,
+            );
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::f32::consts::TAU;
+
+use korangar_interface::application::{Application, FontSizeTrait, FontSizeTraitExt};
+
+use crate::graphics::{DeferredRenderer, Renderer};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::theme::GameTheme;
+
+/// Render the hotbar skills in a circle around the center of the screen, with
+/// the first slot at the top.
+pub fn render_radial_menu(
+    render_target: &mut <DeferredRenderer as Renderer>::Target,
+    renderer: &DeferredRenderer,
+    theme: &GameTheme,
+    application: &InterfaceSettings,
+    window_size: ScreenSize,
+    skill_names: &[Option<String>],
+    selected_slot: Option<usize>,
+) {
+    let scaling = application.get_scaling_factor();
+    let font_size = theme.radial_menu.font_size.get().scaled(application.get_scaling());
+    let radius = theme.radial_menu.radius.get() * scaling;
+    let slot_size = ScreenSize::uniform(theme.radial_menu.slot_size.get() * scaling);
+
+    let center = ScreenPosition {
+        left: window_size.width / 2.0,
+        top: window_size.height / 2.0,
+    };
+    let slot_angle = TAU / skill_names.len().max(1) as f32;
+
+    for (slot, skill_name) in skill_names.iter().enumerate() {
+        let angle = slot as f32 * slot_angle;
+        let slot_center = center
+            + ScreenSize {
+                width: angle.sin() * radius,
+                height: -angle.cos() * radius,
+            };
+        let slot_position = slot_center - slot_size / 2.0;
+
+        let color = match selected_slot == Some(slot) {
+            true => theme.radial_menu.selected_color.get(),
+            false => theme.radial_menu.slot_color.get(),
+        };
+
+        renderer.render_rectangle(render_target, slot_position, slot_size, color);
+
+        let text = skill_name.as_deref().unwrap_or("-");
+        let text_position = slot_position
+            + ScreenSize {
+                width: 0.0,
+                height: (slot_size.height - font_size.get_value()) / 2.0,
+            };
+
+        renderer.render_text(
+            render_target,
+            text,
+            text_position,
+            theme.radial_menu.foreground_color.get(),
+            font_size,
+        );
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use korangar_networking::IncomingRequest;
+use serde::{Deserialize, Serialize};
+
+use crate::system::SettingsFile;
+
+/// Requests from other players that are rejected without asking.
+#[derive(Default, Serialize, Deserialize)]
+pub struct RequestSettings {
+    #[serde(default)]
+    pub reject_party_invites: bool,
+    #[serde(default)]
+    pub reject_guild_invites: bool,
+    #[serde(default)]
+    pub reject_trade_requests: bool,
+    #[serde(default)]
+    pub reject_friend_requests: bool,
+}
+
+impl RequestSettings {
+    const SECTION: &'static str = "requests";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load request settings");
+
+            Default::default()
+        })
+    }
+
+    pub fn load() -> Option<Self> {
+        SettingsFile::load_section(Self::SECTION)
+    }
+
+    pub fn save(&self) {
+        SettingsFile::save_section(Self::SECTION, self);
+    }
+
+    pub fn rejects(&self, request: &IncomingRequest) -> bool {
+        match request {
+            IncomingRequest::Party { .. } => self.reject_party_invites,
+            IncomingRequest::Guild { .. } => self.reject_guild_invites,
+            IncomingRequest::Trade { .. } => self.reject_trade_requests,
+            IncomingRequest::Friend { .. } => self.reject_friend_requests,
+        }
+    }
+}
+
+impl Drop for RequestSettings {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
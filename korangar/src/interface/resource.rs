@@ -40,6 +40,9 @@ pub enum PartialMove {
         source: SkillSource,
         skill: Skill,
     },
+    ChatTab {
+        index: usize,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -54,4 +57,8 @@ pub enum Move {
         destination: SkillSource,
         skill: Skill,
     },
+    ChatTab {
+        source: usize,
+        destination: usize,
+    },
 }
@@ -1,15 +1,34 @@
-use korangar_interface::elements::{ButtonBuilder, Container, ElementCell, ElementWrap, Headline, PrototypeElement};
+use korangar_interface::elements::{ButtonBuilder, Container, ElementCell, ElementWrap, Headline, InputFieldBuilder, PrototypeElement};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedStateClone};
 use korangar_interface::{dimension_bound, size_bound};
 
 use crate::input::UserEvent;
 use crate::interface::application::{InterfaceSettings, InternalThemeKind};
 
-/// Debug actions for a single theme.
+/// Actions for a single theme.
 #[derive(Default)]
 struct Actions<const KIND: InternalThemeKind>;
 
 impl<const KIND: InternalThemeKind> PrototypeElement<InterfaceSettings> for Actions<KIND> {
     fn to_element(&self, display: String) -> ElementCell<InterfaceSettings> {
+        let theme_name = PlainTrackedState::<String>::default();
+
+        let named_action = |event: fn(String) -> UserEvent| {
+            let theme_name = theme_name.clone();
+
+            Box::new(move || {
+                let name = theme_name.cloned();
+
+                (!name.is_empty())
+                    .then(|| vec![ClickAction::Custom(event(name))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let save_as = |name| UserEvent::SaveThemeAs { theme_kind: KIND, name };
+        let load = |name| UserEvent::LoadTheme { theme_kind: KIND, name };
+
         let elements = vec![
             Headline::new(display, size_bound!(33%, 12)).wrap(),
             ButtonBuilder::new()
@@ -24,13 +43,33 @@ impl<const KIND: InternalThemeKind> PrototypeElement<InterfaceSettings> for Acti
                 .with_width_bound(dimension_bound!(!))
                 .build()
                 .wrap(),
+            InputFieldBuilder::new()
+                .with_state(theme_name.clone())
+                .with_ghost_text("Theme name")
+                .with_enter_action(named_action(save_as))
+                .with_length(24)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Save as")
+                .with_event(named_action(save_as))
+                .with_width_bound(dimension_bound!(25%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Load")
+                .with_event(named_action(load))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
         ];
 
         Container::new(elements).wrap()
     }
 }
 
-/// Debug actions for all themes.
+/// Actions for all themes.
 #[derive(Default, PrototypeElement)]
 pub(super) struct ThemeActions {
     #[name("Main theme")]
@@ -9,10 +9,8 @@ use korangar_interface::{dimension_bound, size_bound};
 use ron::ser::PrettyConfig;
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "debug")]
 mod actions;
 
-#[cfg(feature = "debug")]
 use self::actions::ThemeActions;
 use super::application::InterfaceSettings;
 use super::elements::{Mutable, MutableRange};
@@ -752,6 +750,7 @@ pub struct InputTheme {
     pub text_color: Mutable<Color, Render>,
     pub ghost_text_color: Mutable<Color, Render>,
     pub focused_text_color: Mutable<Color, Render>,
+    pub selection_color: Mutable<Color, Render>,
     pub corner_radius: MutableRange<CornerRadius, Render>,
     pub font_size: MutableRange<FontSize, Render>,
     pub text_offset: MutableRange<ScreenPosition, Render>,
@@ -769,6 +768,7 @@ impl ThemeDefault<DefaultMenu> for InputTheme {
             text_color: Mutable::new(Color::monochrome_u8(200)),
             ghost_text_color: Mutable::new(Color::monochrome_u8(100)),
             focused_text_color: Mutable::new(Color::monochrome_u8(200)),
+            selection_color: Mutable::new(Color::rgba_u8(120, 100, 160, 160)),
             corner_radius: MutableRange::new(
                 CornerRadius::uniform(26.0),
                 CornerRadius::default(),
@@ -796,6 +796,7 @@ impl ThemeDefault<DefaultMain> for InputTheme {
             text_color: Mutable::new(Color::monochrome_u8(200)),
             ghost_text_color: Mutable::new(Color::monochrome_u8(100)),
             focused_text_color: Mutable::new(Color::monochrome_u8(200)),
+            selection_color: Mutable::new(Color::rgba_u8(80, 100, 160, 160)),
             corner_radius: MutableRange::new(CornerRadius::uniform(6.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
             font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(50.0)),
             text_offset: MutableRange::new(
@@ -835,6 +836,10 @@ impl korangar_interface::theme::InputTheme<InterfaceSettings> for InputTheme {
         self.focused_text_color.get()
     }
 
+    fn selection_color(&self) -> Color {
+        self.selection_color.get()
+    }
+
     fn corner_radius(&self) -> CornerRadius {
         self.corner_radius.get()
     }
@@ -868,6 +873,7 @@ pub struct ChatTheme {
     pub server_color: Mutable<Color, Render>,
     pub error_color: Mutable<Color, Render>,
     pub information_color: Mutable<Color, Render>,
+    pub whisper_color: Mutable<Color, Render>,
 }
 
 impl ThemeDefault<DefaultMenu> for ChatTheme {
@@ -879,6 +885,7 @@ impl ThemeDefault<DefaultMenu> for ChatTheme {
             server_color: Mutable::new(Color::rgb_u8(255, 255, 210)),
             error_color: Mutable::new(Color::rgb_u8(255, 150, 150)),
             information_color: Mutable::new(Color::rgb_u8(200, 255, 200)),
+            whisper_color: Mutable::new(Color::rgb_u8(255, 255, 0)),
         }
     }
 }
@@ -892,6 +899,7 @@ impl ThemeDefault<DefaultMain> for ChatTheme {
             server_color: Mutable::new(Color::rgb_u8(255, 255, 210)),
             error_color: Mutable::new(Color::rgb_u8(255, 150, 150)),
             information_color: Mutable::new(Color::rgb_u8(200, 255, 200)),
+            whisper_color: Mutable::new(Color::rgb_u8(255, 255, 0)),
         }
     }
 }
@@ -920,6 +928,10 @@ impl korangar_interface::theme::ChatTheme<InterfaceSettings> for ChatTheme {
     fn information_color(&self) -> Color {
         self.information_color.get()
     }
+
+    fn whisper_color(&self) -> Color {
+        self.whisper_color.get()
+    }
 }
 
 #[derive(Serialize, Deserialize, PrototypeElement)]
@@ -1097,12 +1109,242 @@ impl Default for StatusBarTheme {
 #[derive(Serialize, Deserialize, PrototypeElement)]
 pub struct IndicatorTheme {
     pub walking: Mutable<Color, Render>,
+    pub battle_mode: Mutable<Color, Render>,
+    pub battle_mode_offset: MutableRange<f32, Render>,
+    pub battle_mode_font_size: MutableRange<FontSize, Render>,
+    pub ping: Mutable<Color, Render>,
+    /// Offset from the bottom right corner of the screen.
+    pub ping_offset: MutableRange<f32, Render>,
+    pub ping_font_size: MutableRange<FontSize, Render>,
 }
 
 impl Default for IndicatorTheme {
     fn default() -> Self {
         Self {
             walking: Mutable::new(Color::rgba_u8(0, 255, 170, 170)),
+            battle_mode: Mutable::new(Color::rgb_u8(255, 140, 60)),
+            battle_mode_offset: MutableRange::new(10.0, 0.0, 500.0),
+            battle_mode_font_size: MutableRange::new(FontSize::new(18.0), FontSize::new(6.0), FontSize::new(50.0)),
+            ping: Mutable::new(Color::monochrome_u8(200)),
+            ping_offset: MutableRange::new(10.0, 0.0, 500.0),
+            ping_font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(50.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct QuestTrackerTheme {
+    pub title_color: Mutable<Color, Render>,
+    pub objective_color: Mutable<Color, Render>,
+    pub completed_color: Mutable<Color, Render>,
+    /// Offset from the top right corner of the screen.
+    pub offset: MutableRange<ScreenPosition, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for QuestTrackerTheme {
+    fn default() -> Self {
+        Self {
+            title_color: Mutable::new(Color::rgb_u8(255, 200, 100)),
+            objective_color: Mutable::new(Color::monochrome_u8(220)),
+            completed_color: Mutable::new(Color::rgb_u8(100, 220, 100)),
+            offset: MutableRange::new(
+                ScreenPosition { left: 20.0, top: 200.0 },
+                ScreenPosition::default(),
+                ScreenPosition { left: 1000.0, top: 1000.0 },
+            ),
+            font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(30.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct NotificationTheme {
+    pub background_color: Mutable<Color, Render>,
+    pub foreground_color: Mutable<Color, Render>,
+    pub warning_color: Mutable<Color, Render>,
+    /// Distance to the bottom right corner of the screen.
+    pub offset: MutableRange<f32, Render>,
+    pub padding: MutableRange<f32, Render>,
+    /// Space between stacked notifications.
+    pub spacing: MutableRange<f32, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for NotificationTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgba_u8(30, 30, 30, 210)),
+            foreground_color: Mutable::new(Color::monochrome_u8(230)),
+            warning_color: Mutable::new(Color::rgb_u8(255, 120, 100)),
+            offset: MutableRange::new(20.0, 0.0, 300.0),
+            padding: MutableRange::new(8.0, 0.0, 50.0),
+            spacing: MutableRange::new(6.0, 0.0, 50.0),
+            font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(40.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct FrameOverlayTheme {
+    pub background_color: Mutable<Color, Render>,
+    pub foreground_color: Mutable<Color, Render>,
+    pub graph_color: Mutable<Color, Render>,
+    /// Color of frames that took longer than 33 ms.
+    pub slow_frame_color: Mutable<Color, Render>,
+    pub position: MutableRange<ScreenPosition, Render>,
+    pub padding: MutableRange<f32, Render>,
+    pub graph_height: MutableRange<f32, Render>,
+    /// Width of the bar of a single frame in the graph.
+    pub bar_width: MutableRange<f32, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for FrameOverlayTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgba_u8(20, 20, 20, 180)),
+            foreground_color: Mutable::new(Color::monochrome_u8(220)),
+            graph_color: Mutable::new(Color::rgb_u8(120, 220, 120)),
+            slow_frame_color: Mutable::new(Color::rgb_u8(255, 110, 90)),
+            position: MutableRange::new(
+                ScreenPosition { left: 20.0, top: 40.0 },
+                ScreenPosition::default(),
+                ScreenPosition { left: 1000.0, top: 500.0 },
+            ),
+            padding: MutableRange::new(6.0, 0.0, 30.0),
+            graph_height: MutableRange::new(40.0, 10.0, 200.0),
+            bar_width: MutableRange::new(1.0, 0.5, 4.0),
+            font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(30.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct RadialMenuTheme {
+    pub slot_color: Mutable<Color, Render>,
+    pub selected_color: Mutable<Color, Render>,
+    pub foreground_color: Mutable<Color, Render>,
+    /// Distance between the center of the screen and the slots.
+    pub radius: MutableRange<f32, Render>,
+    pub slot_size: MutableRange<f32, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for RadialMenuTheme {
+    fn default() -> Self {
+        Self {
+            slot_color: Mutable::new(Color::rgba_u8(20, 20, 20, 180)),
+            selected_color: Mutable::new(Color::rgba_u8(200, 140, 60, 220)),
+            foreground_color: Mutable::new(Color::monochrome_u8(230)),
+            radius: MutableRange::new(140.0, 50.0, 400.0),
+            slot_size: MutableRange::new(70.0, 30.0, 150.0),
+            font_size: MutableRange::new(FontSize::new(12.0), FontSize::new(6.0), FontSize::new(30.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct TooltipTheme {
+    pub background_color: Mutable<Color, Render>,
+    pub foreground_color: Mutable<Color, Render>,
+    /// Distance between the mouse and the tooltip.
+    pub offset: MutableRange<f32, Render>,
+    pub padding: MutableRange<f32, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for TooltipTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgba_u8(20, 20, 20, 220)),
+            foreground_color: Mutable::new(Color::monochrome_u8(220)),
+            offset: MutableRange::new(16.0, 0.0, 100.0),
+            padding: MutableRange::new(6.0, 0.0, 30.0),
+            font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(30.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct BattlefieldTheme {
+    pub ally_color: Mutable<Color, Render>,
+    pub enemy_color: Mutable<Color, Render>,
+    /// Distance between the feet of a player and their name plate.
+    pub name_offset: MutableRange<f32, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for BattlefieldTheme {
+    fn default() -> Self {
+        Self {
+            ally_color: Mutable::new(Color::rgb_u8(100, 160, 255)),
+            enemy_color: Mutable::new(Color::rgb_u8(255, 90, 90)),
+            name_offset: MutableRange::new(25.0, 0.0, 100.0),
+            font_size: MutableRange::new(FontSize::new(12.0), FontSize::new(6.0), FontSize::new(30.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct NameLabelTheme {
+    pub name_color: Mutable<Color, Render>,
+    pub affiliation_color: Mutable<Color, Render>,
+    /// Distance between the feet of an entity and the bottom of its name
+    /// label.
+    pub offset: MutableRange<f32, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for NameLabelTheme {
+    fn default() -> Self {
+        Self {
+            name_color: Mutable::new(Color::monochrome_u8(255)),
+            affiliation_color: Mutable::new(Color::rgb_u8(190, 220, 255)),
+            offset: MutableRange::new(106.0, 0.0, 300.0),
+            font_size: MutableRange::new(FontSize::new(12.0), FontSize::new(6.0), FontSize::new(30.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct ChatBubbleTheme {
+    pub background_color: Mutable<Color, Render>,
+    pub foreground_color: Mutable<Color, Render>,
+    /// Distance between the feet of an entity and the bottom of its chat
+    /// bubble.
+    pub offset: MutableRange<f32, Render>,
+    pub padding: MutableRange<f32, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+}
+
+impl Default for ChatBubbleTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgba_u8(255, 255, 255, 210)),
+            foreground_color: Mutable::new(Color::monochrome_u8(20)),
+            offset: MutableRange::new(110.0, 0.0, 300.0),
+            padding: MutableRange::new(4.0, 0.0, 30.0),
+            font_size: MutableRange::new(FontSize::new(12.0), FontSize::new(6.0), FontSize::new(30.0)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct StatusEffectTheme {
+    pub icon_size: MutableRange<f32, Render>,
+    pub spacing: MutableRange<f32, Render>,
+    /// Distance between the feet of an entity and the bottom of its status
+    /// effect icons.
+    pub offset: MutableRange<f32, Render>,
+}
+
+impl Default for StatusEffectTheme {
+    fn default() -> Self {
+        Self {
+            icon_size: MutableRange::new(16.0, 4.0, 48.0),
+            spacing: MutableRange::new(2.0, 0.0, 10.0),
+            offset: MutableRange::new(88.0, 0.0, 300.0),
         }
     }
 }
@@ -1209,14 +1451,22 @@ pub struct GameTheme {
     pub overlay: OverlayTheme,
     pub status_bar: StatusBarTheme,
     pub indicator: IndicatorTheme,
+    pub quest_tracker: QuestTrackerTheme,
+    pub notification: NotificationTheme,
+    pub tooltip: TooltipTheme,
+    pub frame_overlay: FrameOverlayTheme,
+    pub radial_menu: RadialMenuTheme,
+    pub battlefield: BattlefieldTheme,
+    pub name_label: NameLabelTheme,
+    pub chat_bubble: ChatBubbleTheme,
+    pub status_effects: StatusEffectTheme,
     pub cursor: CursorTheme,
 }
 
 #[derive(PrototypeWindow)]
-#[window_title("Theme Viewer")]
-#[window_class("theme_viewer")]
+#[window_title("Theme Editor")]
+#[window_class("theme_editor")]
 pub struct Themes {
-    #[cfg(feature = "debug")]
     #[name("Actions")]
     theme_actions: ThemeActions,
     #[name("Menu")]
@@ -1230,7 +1480,6 @@ pub struct Themes {
 impl Themes {
     pub fn new(menu: InterfaceTheme, main: InterfaceTheme, game: GameTheme) -> Self {
         Self {
-            #[cfg(feature = "debug")]
             theme_actions: Default::default(),
             menu,
             main,
@@ -5,27 +5,72 @@ use korangar_interface::elements::{
     ButtonBuilder, Container, ElementWrap, FocusMode, InputFieldBuilder, PickList, StateButtonBuilder, Text,
 };
 use korangar_interface::event::ClickAction;
-use korangar_interface::size_bound;
 use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateBinary, TrackedStateClone, TrackedStateExt};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
 
 use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::ScreenSize;
+use crate::interface::privacy::PrivacySettings;
 use crate::interface::theme::InterfaceThemeKind;
 use crate::interface::windows::WindowCache;
-use crate::loaders::client::LoginSettings;
-use crate::loaders::ClientInfo;
+use crate::loaders::client::{ConnectionProfile, LoginSettings};
+use crate::loaders::{ClientInfo, ServiceId};
 
 #[derive(new)]
 pub struct LoginWindow<'a> {
     client_info: &'a ClientInfo,
+    login_settings: PlainTrackedState<LoginSettings>,
+    /// The username is hidden in streamer mode, since it is the name of the
+    /// account.
+    privacy_settings: PlainTrackedState<PrivacySettings>,
 }
 
 impl<'a> LoginWindow<'a> {
     pub const WINDOW_CLASS: &'static str = "login";
 }
 
+/// Remember the entered credentials and log in, using the packet version and
+/// window layout of the selected profile.
+fn log_in(
+    login_settings: &mut PlainTrackedState<LoginSettings>,
+    selected_profile: &PlainTrackedState<Option<String>>,
+    selected_service: &PlainTrackedState<ServiceId>,
+    username: &PlainTrackedState<String>,
+    password: &PlainTrackedState<String>,
+) -> Vec<ClickAction<InterfaceSettings>> {
+    let service_id = selected_service.cloned();
+    let profile_name = selected_profile.cloned();
+
+    let (packet_version, window_layout) = login_settings.mutate(|login_settings| {
+        login_settings.recent_service_id = Some(service_id);
+
+        let saved_settings = login_settings.service_settings.entry(service_id).or_default();
+        saved_settings.username = username.cloned();
+        saved_settings.password = password.cloned();
+
+        login_settings
+            .profiles
+            .iter()
+            .find(|profile| Some(&profile.name) == profile_name.as_ref())
+            .map(|profile| (profile.packet_version, profile.window_layout.clone()))
+            .unwrap_or_default()
+    });
+
+    // The settings are shared with the rest of the client and only dropped on
+    // exit, so they are saved here to keep the credentials if the client crashes.
+    login_settings.get().save();
+
+    vec![ClickAction::Custom(UserEvent::LogIn {
+        service_id,
+        username: username.cloned(),
+        password: password.cloned(),
+        packet_version,
+        window_layout,
+    })]
+}
+
 impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
     fn window_class(&self) -> Option<&str> {
         Self::WINDOW_CLASS.into()
@@ -37,7 +82,7 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let mut login_settings = LoginSettings::new();
+        let mut login_settings = self.login_settings.clone();
 
         let options = self
             .client_info
@@ -49,6 +94,7 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
         // FIX: This will panic when no services are present. What is the correct
         // behavior?
         let selected_service = login_settings
+            .get()
             .recent_service_id
             // Only use the recent server if it is still in the client info
             .filter(|&recent_service_id| {
@@ -59,13 +105,71 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
             })
             .unwrap_or_else(|| self.client_info.services[0].service_id());
 
-        let saved_settings = login_settings.service_settings.entry(selected_service).or_default();
+        let saved_settings =
+            login_settings.mutate(|login_settings| login_settings.service_settings.entry(selected_service).or_default().clone());
 
         let username = PlainTrackedState::new(saved_settings.username.clone());
         let password = PlainTrackedState::new(saved_settings.password.clone());
 
+        // Profiles of services that were removed from the client info can't be used.
+        let profile_options = std::iter::once(("No profile".to_owned(), None))
+            .chain(
+                login_settings
+                    .get()
+                    .profiles
+                    .iter()
+                    .filter(|profile| {
+                        self.client_info
+                            .services
+                            .iter()
+                            .any(|service| service.service_id() == profile.service_id)
+                    })
+                    .map(|profile| (profile.name.clone(), Some(profile.name.clone()))),
+            )
+            .collect();
+
+        let selected_profile = PlainTrackedState::new(None);
+        let profile_name = PlainTrackedState::new(String::new());
+        let packet_version = PlainTrackedState::new(String::new());
+        let window_layout = PlainTrackedState::new(String::new());
+
         let selected_service = PlainTrackedState::new(selected_service);
-        let login_settings = PlainTrackedState::new(login_settings);
+
+        let profile_changed = {
+            let mut username = username.clone();
+            let mut password = password.clone();
+            let mut login_settings = login_settings.clone();
+            let mut selected_service = selected_service.clone();
+            let selected_profile = selected_profile.clone();
+            let mut profile_name = profile_name.clone();
+            let mut packet_version = packet_version.clone();
+            let mut window_layout = window_layout.clone();
+
+            Box::new(move || {
+                let profile = login_settings
+                    .get()
+                    .profiles
+                    .iter()
+                    .find(|profile| Some(&profile.name) == selected_profile.get().as_ref())
+                    .cloned();
+
+                if let Some(profile) = profile {
+                    // The remember options of the service are shown, so they need to exist.
+                    login_settings.mutate(|login_settings| {
+                        login_settings.service_settings.entry(profile.service_id).or_default();
+                    });
+
+                    selected_service.set(profile.service_id);
+                    username.set(profile.username);
+                    password.set(profile.password);
+                    packet_version.set(profile.packet_version.map(|version| version.to_string()).unwrap_or_default());
+                    window_layout.set(profile.window_layout.unwrap_or_default());
+                    profile_name.set(profile.name);
+                }
+
+                Vec::new()
+            })
+        };
 
         let selector = {
             let username = username.clone();
@@ -100,25 +204,9 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
             let password = password.clone();
             let mut login_settings = login_settings.clone();
             let selected_service = selected_service.clone();
+            let selected_profile = selected_profile.clone();
 
-            move || {
-                // TODO: Deduplicate code
-                let service_id = selected_service.cloned();
-
-                login_settings.mutate(|login_settings| {
-                    login_settings.recent_service_id = Some(service_id);
-
-                    let saved_settings = login_settings.service_settings.entry(service_id).or_default();
-                    saved_settings.username = username.cloned();
-                    saved_settings.password = password.cloned();
-                });
-
-                vec![ClickAction::Custom(UserEvent::LogIn {
-                    service_id: selected_service.cloned(),
-                    username: username.cloned(),
-                    password: password.cloned(),
-                })]
-            }
+            move || log_in(&mut login_settings, &selected_profile, &selected_service, &username, &password)
         };
 
         let username_action = {
@@ -138,31 +226,91 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
             let password = password.clone();
             let mut login_settings = login_settings.clone();
             let selected_service = selected_service.clone();
+            let selected_profile = selected_profile.clone();
 
             Box::new(move || match password.get().is_empty() {
                 _ if username.get().is_empty() => vec![ClickAction::FocusNext(FocusMode::FocusPrevious)],
                 true => Vec::new(),
-                false => {
-                    // TODO: Deduplicate code
-                    let service_id = selected_service.cloned();
+                false => log_in(&mut login_settings, &selected_profile, &selected_service, &username, &password),
+            })
+        };
 
-                    login_settings.mutate(|login_settings| {
-                        login_settings.recent_service_id = Some(service_id);
+        let forget_credentials = {
+            let mut username = username.clone();
+            let mut password = password.clone();
+            let mut login_settings = login_settings.clone();
+            let selected_service = selected_service.clone();
 
-                        let saved_settings = login_settings.service_settings.entry(service_id).or_default();
-                        saved_settings.username = username.cloned();
-                        saved_settings.password = password.cloned();
-                    });
+            Box::new(move || {
+                let service_id = selected_service.cloned();
+
+                login_settings.mutate(|login_settings| login_settings.forget_credentials(service_id));
+                username.set(String::new());
+                password.set(String::new());
 
-                    vec![ClickAction::Custom(UserEvent::LogIn {
-                        service_id: selected_service.cloned(),
+                Vec::new()
+            })
+        };
+
+        // The window is reopened after changing the profiles, so the list of profiles
+        // is up to date.
+        let save_profile = {
+            let username = username.clone();
+            let password = password.clone();
+            let mut login_settings = login_settings.clone();
+            let selected_service = selected_service.clone();
+            let profile_name = profile_name.clone();
+            let packet_version = packet_version.clone();
+            let window_layout = window_layout.clone();
+
+            Box::new(move || {
+                let service_id = selected_service.cloned();
+                let window_layout = window_layout.get().trim().to_owned();
+
+                login_settings.mutate(|login_settings| {
+                    let remember_password = login_settings
+                        .service_settings
+                        .get(&service_id)
+                        .is_some_and(|service_settings| service_settings.remember_password);
+
+                    login_settings.save_profile(ConnectionProfile {
+                        name: profile_name.get().trim().to_owned(),
+                        service_id,
+                        packet_version: packet_version.get().trim().parse().ok(),
                         username: username.cloned(),
                         password: password.cloned(),
-                    })]
+                        remember_password,
+                        window_layout: window_layout.is_empty().not().then_some(window_layout),
+                    });
+                });
+
+                vec![ClickAction::CloseWindow, ClickAction::Custom(UserEvent::OpenLoginWindow)]
+            })
+        };
+
+        let delete_profile = {
+            let mut login_settings = login_settings.clone();
+            let selected_profile = selected_profile.clone();
+
+            Box::new(move || {
+                if let Some(name) = selected_profile.cloned() {
+                    login_settings.mutate(|login_settings| login_settings.remove_profile(&name));
                 }
+
+                vec![ClickAction::CloseWindow, ClickAction::Custom(UserEvent::OpenLoginWindow)]
             })
         };
 
+        let save_profile_selector = {
+            let profile_name = profile_name.clone();
+            move || !profile_name.get().trim().is_empty()
+        };
+
+        let delete_profile_selector = {
+            let selected_profile = selected_profile.clone();
+            move || selected_profile.get().is_some()
+        };
+
         let remember_username = {
             let service_id = selected_service.clone();
 
@@ -175,7 +323,15 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
             login_settings.mapped(move |login_settings| &login_settings.service_settings.get(&service_id.get()).unwrap().remember_password)
         };
 
+        let streamer_mode = self.privacy_settings.mapped(|settings| &settings.streamer_mode);
+
         let elements = vec![
+            Text::default().with_text("Profile").wrap(),
+            PickList::default()
+                .with_options(profile_options)
+                .with_selected(selected_profile)
+                .with_event(profile_changed)
+                .wrap(),
             Text::default().with_text("Select service").wrap(),
             PickList::default()
                 .with_options(options)
@@ -188,6 +344,7 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
                 .with_ghost_text("Username")
                 .with_enter_action(username_action)
                 .with_length(24)
+                .with_hidden_selector(streamer_mode.selector())
                 .build()
                 .wrap(),
             InputFieldBuilder::new()
@@ -217,12 +374,55 @@ impl<'a> PrototypeWindow<InterfaceSettings> for LoginWindow<'a> {
                 ]
             })
             .wrap(),
+            ButtonBuilder::new()
+                .with_text("Forget credentials")
+                .with_event(forget_credentials)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Log in")
                 .with_disabled_selector(selector)
                 .with_event(Box::new(login_action))
                 .build()
                 .wrap(),
+            Text::default().with_text("Save as profile").wrap(),
+            InputFieldBuilder::new()
+                .with_state(profile_name)
+                .with_ghost_text("Profile name")
+                .with_length(24)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(packet_version)
+                .with_ghost_text("Packet version (optional)")
+                .with_length(10)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(window_layout)
+                .with_ghost_text("Window layout (optional)")
+                .with_length(24)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Save profile")
+                .with_disabled_selector(save_profile_selector)
+                .with_event(save_profile)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Delete profile")
+                .with_disabled_selector(delete_profile_selector)
+                .with_event(delete_profile)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Replays")
+                .with_event(UserEvent::OpenReplayWindow)
+                .build()
+                .wrap(),
         ];
 
         WindowBuilder::new()
@@ -1,5 +1,7 @@
 mod login;
+mod replay;
 mod select_server;
 
 pub use self::login::LoginWindow;
+pub use self::replay::ReplayWindow;
 pub use self::select_server::SelectServerWindow;
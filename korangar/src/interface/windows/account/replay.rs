@@ -0,0 +1,69 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView, StateButtonBuilder, Text};
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateBinary};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceThemeKind;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct ReplayWindow {
+    record_replays: PlainTrackedState<bool>,
+    replays: Vec<String>,
+}
+
+impl ReplayWindow {
+    pub const WINDOW_CLASS: &'static str = "replay";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ReplayWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let replays = match self.replays.is_empty() {
+            true => vec![Text::default().with_text("No recorded sessions").wrap()],
+            false => self
+                .replays
+                .iter()
+                .map(|path| {
+                    ButtonBuilder::new()
+                        .with_text(path.clone())
+                        .with_event(UserEvent::PlayReplay(path.clone()))
+                        .build()
+                        .wrap()
+                })
+                .collect(),
+        };
+
+        let elements = vec![
+            StateButtonBuilder::new()
+                .with_text("Record sessions")
+                .with_remote(self.record_replays.new_remote())
+                .with_event(self.record_replays.toggle_action())
+                .build()
+                .wrap(),
+            Text::default().with_text("Recorded sessions").wrap(),
+            ScrollView::new(replays, size_bound!(100%, ? < super)).wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Replays".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ? < 80%))
+            .with_elements(elements)
+            .with_theme_kind(InterfaceThemeKind::Menu)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use derive_new::new;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{print_debug, Colorize};
-use korangar_interface::windows::Anchor;
+use korangar_interface::windows::{Anchor, WindowSettings};
 use ron::ser::PrettyConfig;
 use serde::{Deserialize, Serialize};
 
@@ -14,46 +14,63 @@ use crate::interface::layout::ScreenSize;
 pub struct WindowState {
     pub anchor: Anchor<InterfaceSettings>,
     pub size: ScreenSize,
+    #[new(default)]
+    #[serde(default)]
+    pub settings: WindowSettings,
 }
 
-#[derive(Default, Serialize, Deserialize)]
 pub struct WindowCache {
     entries: HashMap<String, WindowState>,
+    file_name: String,
 }
 
 impl WindowCache {
     const FILE_NAME: &'static str = "client/window_cache.ron";
 
-    fn load() -> Option<Self> {
+    /// Load the positions and sizes of a named window layout, or of the
+    /// default layout if no name is given.
+    pub fn with_layout(layout: Option<&str>) -> Self {
+        let file_name = match layout {
+            Some(layout) => {
+                // The name is used in a file path, so only a safe subset of characters is kept.
+                let layout: String = layout
+                    .chars()
+                    .filter(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_'))
+                    .collect();
+                format!("client/window_cache_{layout}.ron")
+            }
+            None => Self::FILE_NAME.to_owned(),
+        };
+
+        let entries = Self::load(&file_name).unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load window cache from {}. creating empty cache", file_name.magenta());
+
+            Default::default()
+        });
+
+        Self { entries, file_name }
+    }
+
+    fn load(file_name: &str) -> Option<HashMap<String, WindowState>> {
         #[cfg(feature = "debug")]
-        print_debug!("loading window cache from {}", Self::FILE_NAME.magenta());
+        print_debug!("loading window cache from {}", file_name.magenta());
 
-        std::fs::read_to_string("client/window_cache.ron")
-            .ok()
-            .and_then(|data| ron::from_str(&data).ok())
-            .map(|entries| Self { entries })
+        std::fs::read_to_string(file_name).ok().and_then(|data| ron::from_str(&data).ok())
     }
 
     fn save(&self) {
         #[cfg(feature = "debug")]
-        print_debug!("saving window cache to {}", Self::FILE_NAME.magenta());
+        print_debug!("saving window cache to {}", self.file_name.magenta());
 
         let data = ron::ser::to_string_pretty(&self.entries, PrettyConfig::new()).unwrap();
-        std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
+        std::fs::write(&self.file_name, data).expect("unable to write file");
     }
 }
 
 impl korangar_interface::application::WindowCache<InterfaceSettings> for WindowCache {
     fn create() -> Self {
-        Self::load().unwrap_or_else(|| {
-            #[cfg(feature = "debug")]
-            print_debug!(
-                "failed to load window cache from {}. creating empty cache",
-                Self::FILE_NAME.magenta()
-            );
-
-            Default::default()
-        })
+        Self::with_layout(None)
     }
 
     fn register_window(&mut self, identifier: &str, anchor: Anchor<InterfaceSettings>, size: ScreenSize) {
@@ -81,6 +98,16 @@ impl korangar_interface::application::WindowCache<InterfaceSettings> for WindowC
     fn get_window_state(&self, identifier: &str) -> Option<(Anchor<InterfaceSettings>, ScreenSize)> {
         self.entries.get(identifier).map(|entry| (entry.anchor.clone(), entry.size))
     }
+
+    fn update_settings(&mut self, identifier: &str, settings: WindowSettings) {
+        if let Some(entry) = self.entries.get_mut(identifier) {
+            entry.settings = settings;
+        }
+    }
+
+    fn get_window_settings(&self, identifier: &str) -> Option<WindowSettings> {
+        self.entries.get(identifier).map(|entry| entry.settings)
+    }
 }
 
 impl Drop for WindowCache {
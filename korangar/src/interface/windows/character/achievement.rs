@@ -0,0 +1,47 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::AchievementSummary;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::AchievementView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::Achievement;
+
+#[derive(new)]
+pub struct AchievementWindow {
+    achievements: PlainRemote<Vec<Achievement>>,
+    summary: PlainRemote<AchievementSummary>,
+    title_id: PlainRemote<Option<u32>>,
+}
+
+impl AchievementWindow {
+    pub const WINDOW_CLASS: &'static str = "achievement";
+}
+
+impl PrototypeWindow<InterfaceSettings> for AchievementWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let achievement_view = AchievementView::new(self.achievements.clone(), self.summary.clone(), self.title_id.clone());
+        let elements = vec![ScrollView::new(vec![achievement_view.wrap()], size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Achievements".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 350 < 500, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,59 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::AttendanceDay;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::ATTENDANCE_DAYS;
+
+#[derive(new)]
+pub struct AttendanceWindow {
+    claimed_days: PlainRemote<u32>,
+    claimed_today: PlainRemote<bool>,
+}
+
+impl AttendanceWindow {
+    pub const WINDOW_CLASS: &'static str = "attendance";
+}
+
+impl PrototypeWindow<InterfaceSettings> for AttendanceWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let mut elements: Vec<_> = (1..=ATTENDANCE_DAYS)
+            .map(|day| AttendanceDay::new(day, self.claimed_days.clone(), self.claimed_today.clone()).wrap())
+            .collect();
+
+        let claimed_today = self.claimed_today.clone();
+
+        elements.push(Text::default().with_text("Rewards are delivered by mail").wrap());
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Claim reward")
+                .with_disabled_selector(move || *claimed_today.get())
+                .with_event(UserEvent::ClaimAttendanceReward)
+                .build()
+                .wrap(),
+        );
+
+        WindowBuilder::new()
+            .with_title("Attendance".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
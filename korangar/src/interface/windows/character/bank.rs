@@ -0,0 +1,109 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::BalanceText;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::{ErrorWindow, WindowCache};
+
+#[derive(new)]
+pub struct BankWindow {
+    zeny: PlainRemote<u32>,
+    bank_balance: PlainRemote<i64>,
+}
+
+impl BankWindow {
+    pub const WINDOW_CLASS: &'static str = "bank";
+}
+
+/// Parse the amount entered by the player and check it against the amount of
+/// zeny that is available for the transaction.
+fn validate_amount(input: &str, available: i64) -> Result<u32, String> {
+    let amount = input
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|amount| *amount > 0 && *amount <= i32::MAX as u32)
+        .ok_or_else(|| format!("'{}' is not a valid amount of zeny", input.trim()))?;
+
+    match i64::from(amount) <= available {
+        true => Ok(amount),
+        false => Err(format!("Not enough zeny available ({available} z)")),
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for BankWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let amount = PlainTrackedState::<String>::default();
+
+        let deposit_action = {
+            let amount = amount.clone();
+            let zeny = self.zeny.clone();
+
+            Box::new(move || match validate_amount(&amount.get(), i64::from(*zeny.get())) {
+                Ok(amount) => vec![ClickAction::Custom(UserEvent::DepositZeny(amount))],
+                Err(message) => vec![ClickAction::OpenWindow(Box::new(ErrorWindow::new(message)))],
+            })
+        };
+
+        let withdraw_action = {
+            let amount = amount.clone();
+            let bank_balance = self.bank_balance.clone();
+
+            Box::new(move || match validate_amount(&amount.get(), *bank_balance.get()) {
+                Ok(amount) => vec![ClickAction::Custom(UserEvent::WithdrawZeny(amount))],
+                Err(message) => vec![ClickAction::OpenWindow(Box::new(ErrorWindow::new(message)))],
+            })
+        };
+
+        let elements = vec![
+            BalanceText::new("Carried", self.zeny.clone()).wrap(),
+            BalanceText::new("Bank", self.bank_balance.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(amount)
+                .with_ghost_text("Amount")
+                .with_enter_action(deposit_action.clone())
+                .with_length(10)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Deposit")
+                .with_event(deposit_action)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Withdraw")
+                .with_event(withdraw_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Close")
+                .with_event(UserEvent::CloseBank)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Bank".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,26 +1,70 @@
 use derive_new::new;
-use korangar_interface::elements::{ButtonBuilder, ElementWrap, FocusMode, InputFieldBuilder};
+use korangar_interface::elements::{ButtonBuilder, ElementCell, ElementWrap, FocusMode, InputFieldBuilder};
 use korangar_interface::event::ClickAction;
-use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateClone};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateClone};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::CharacterAppearance;
 
 use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::AppearancePreview;
 use crate::interface::layout::ScreenSize;
 use crate::interface::theme::InterfaceThemeKind;
 use crate::interface::windows::WindowCache;
+use crate::world::PlayerAppearance;
 
-const MINIMUM_NAME_LENGTH: usize = 4;
-const MAXIMUM_NAME_LENGTH: usize = 24;
+pub(super) const MINIMUM_NAME_LENGTH: usize = 4;
+pub(super) const MAXIMUM_NAME_LENGTH: usize = 24;
+
+/// Jobs that a new character can start as (Novice and Summoner), together with
+/// the number of hair styles of their race.
+const STARTING_JOBS: [(u16, u16); 2] = [(0, 23), (4218, 6)];
+/// Number of hair colors that can be picked during character creation.
+const HAIR_COLOR_COUNT: u16 = 9;
 
 #[derive(new)]
 pub struct CharacterCreationWindow {
     slot: usize,
+    appearance: PlainRemote<Option<PlayerAppearance>>,
 }
 
 impl CharacterCreationWindow {
     pub const WINDOW_CLASS: &'static str = "character_creation";
+
+    fn appearance_button(
+        appearance: &PlainRemote<Option<PlayerAppearance>>,
+        text: &'static str,
+        change: impl Fn(&mut CharacterAppearance) + 'static,
+    ) -> ElementCell<InterfaceSettings> {
+        let appearance = appearance.clone();
+
+        let action = move || {
+            let mut appearance = appearance
+                .get()
+                .as_ref()
+                .map(|player_appearance| player_appearance.appearance)
+                .unwrap_or_default();
+
+            change(&mut appearance);
+
+            vec![ClickAction::Custom(UserEvent::ChangeCharacterAppearance(appearance))]
+        };
+
+        ButtonBuilder::new()
+            .with_text(text)
+            .with_event(Box::new(action))
+            .with_width_bound(dimension_bound!(33%))
+            .build()
+            .wrap()
+    }
+}
+
+fn get_starting_job_index(start_job: u16) -> usize {
+    STARTING_JOBS
+        .iter()
+        .position(|(job_id, _)| *job_id == start_job)
+        .unwrap_or_default()
 }
 
 impl PrototypeWindow<InterfaceSettings> for CharacterCreationWindow {
@@ -51,6 +95,21 @@ impl PrototypeWindow<InterfaceSettings> for CharacterCreationWindow {
         let input_action = Box::new(move || vec![ClickAction::FocusNext(FocusMode::FocusNext)]);
 
         let elements = vec![
+            AppearancePreview::new(self.appearance.clone()).wrap(),
+            Self::appearance_button(&self.appearance, "Hair style", |appearance| {
+                let (_, hair_style_count) = STARTING_JOBS[get_starting_job_index(appearance.start_job)];
+                appearance.hair_style = appearance.hair_style % hair_style_count + 1;
+            }),
+            Self::appearance_button(&self.appearance, "Hair color", |appearance| {
+                appearance.hair_color = (appearance.hair_color + 1) % HAIR_COLOR_COUNT;
+            }),
+            Self::appearance_button(&self.appearance, "Job", |appearance| {
+                let (job_id, _) = STARTING_JOBS[(get_starting_job_index(appearance.start_job) + 1) % STARTING_JOBS.len()];
+
+                // Not every race has the same number of hair styles.
+                appearance.start_job = job_id;
+                appearance.hair_style = 1;
+            }),
             InputFieldBuilder::new()
                 .with_state(name)
                 .with_ghost_text("Character name")
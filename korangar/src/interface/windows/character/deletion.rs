@@ -0,0 +1,91 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, FocusMode, InputFieldBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateClone};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::CharacterId;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceThemeKind;
+use crate::interface::windows::WindowCache;
+
+/// Length of the birthdate in the format `YYMMDD`.
+const BIRTHDATE_LENGTH: usize = 6;
+
+#[derive(new)]
+pub struct CharacterDeletionWindow {
+    character_id: CharacterId,
+}
+
+impl CharacterDeletionWindow {
+    pub const WINDOW_CLASS: &'static str = "character_deletion";
+}
+
+impl PrototypeWindow<InterfaceSettings> for CharacterDeletionWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let birthdate = PlainTrackedState::<String>::default();
+
+        let selector = {
+            let birthdate = birthdate.clone();
+            move || {
+                let birthdate = birthdate.get();
+                birthdate.len() == BIRTHDATE_LENGTH && birthdate.chars().all(|character| character.is_ascii_digit())
+            }
+        };
+
+        let action = {
+            let character_id = self.character_id;
+            let birthdate = birthdate.clone();
+
+            move || {
+                vec![ClickAction::Custom(UserEvent::ConfirmCharacterDeletion(
+                    character_id,
+                    birthdate.cloned(),
+                ))]
+            }
+        };
+
+        let input_action = Box::new(move || vec![ClickAction::FocusNext(FocusMode::FocusNext)]);
+
+        let elements = vec![
+            Text::default()
+                .with_text("Enter your birthdate (YYMMDD) to delete this character")
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(birthdate)
+                .with_ghost_text("Birthdate")
+                .with_enter_action(input_action)
+                .with_length(BIRTHDATE_LENGTH)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Delete")
+                .with_disabled_selector(selector)
+                .with_event(Box::new(action))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Delete Character".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .with_theme_kind(InterfaceThemeKind::Menu)
+            .build(window_cache, application, available_space)
+    }
+}
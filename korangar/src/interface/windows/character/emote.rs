@@ -0,0 +1,44 @@
+use derive_new::new;
+use korangar_interface::elements::ElementWrap;
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::EmotionBox;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::world::{Emotions, EMOTIONS};
+
+#[derive(new)]
+pub struct EmoteWindow {
+    emotions: Emotions,
+}
+
+impl EmoteWindow {
+    pub const WINDOW_CLASS: &'static str = "emote";
+}
+
+impl PrototypeWindow<InterfaceSettings> for EmoteWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = EMOTIONS
+            .iter()
+            .map(|(emotion, _)| EmotionBox::new(self.emotions.clone(), *emotion).wrap())
+            .collect();
+
+        WindowBuilder::new()
+            .with_title("Emotes".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 350 < 500, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
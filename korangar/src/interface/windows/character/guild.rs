@@ -0,0 +1,47 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, Text};
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::GuildNotice;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct GuildWindow {
+    notice: Option<GuildNotice>,
+}
+
+impl GuildWindow {
+    pub const WINDOW_CLASS: &'static str = "guild";
+}
+
+impl PrototypeWindow<InterfaceSettings> for GuildWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = match &self.notice {
+            Some(notice) => vec![
+                Text::default().with_text(format!("^ffaa00{}^000000", notice.subject)).wrap(),
+                Text::default().with_text(notice.notice.clone()).wrap(),
+            ],
+            None => vec![Text::default().with_text("No guild notice".to_owned()).wrap()],
+        };
+
+        WindowBuilder::new()
+            .with_title("Guild".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
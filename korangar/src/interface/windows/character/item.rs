@@ -0,0 +1,50 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, Text};
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use ragnarok_packets::ItemId;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Information about an item that is looked up by id, for example when
+/// clicking an item that is linked in the chat.
+#[derive(new)]
+pub struct ItemInformationWindow {
+    item_id: ItemId,
+    name: String,
+    description: Vec<String>,
+}
+
+impl ItemInformationWindow {
+    pub const WINDOW_CLASS: &'static str = "item_information";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ItemInformationWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let mut elements = vec![
+            Text::default().with_text(self.name.clone()).wrap(),
+            Text::default().with_text(format!("Item id: {}", self.item_id.0)).wrap(),
+        ];
+
+        elements.extend(self.description.iter().map(|line| Text::default().with_text(line.clone()).wrap()));
+
+        WindowBuilder::new()
+            .with_title("Item Information".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,15 +1,41 @@
+mod achievement;
+mod attendance;
+mod bank;
 mod creation;
+mod deletion;
+mod emote;
 mod equipment;
+mod guild;
 mod hotbar;
 mod inventory;
+mod item;
+mod monster;
 mod overview;
+mod pet;
+mod quest;
+mod refine;
+mod rename;
 mod selection;
 mod skill_tree;
+mod status_effects;
 
+pub use self::achievement::AchievementWindow;
+pub use self::attendance::AttendanceWindow;
+pub use self::bank::BankWindow;
 pub use self::creation::CharacterCreationWindow;
+pub use self::deletion::CharacterDeletionWindow;
+pub use self::emote::EmoteWindow;
 pub use self::equipment::EquipmentWindow;
+pub use self::guild::GuildWindow;
 pub use self::hotbar::HotbarWindow;
 pub use self::inventory::InventoryWindow;
+pub use self::item::ItemInformationWindow;
+pub use self::monster::MonsterInformationWindow;
 pub use self::overview::CharacterOverviewWindow;
+pub use self::pet::PetWindow;
+pub use self::quest::QuestWindow;
+pub use self::refine::RefineWindow;
+pub use self::rename::CharacterRenameWindow;
 pub use self::selection::CharacterSelectionWindow;
 pub use self::skill_tree::SkillTreeWindow;
+pub use self::status_effects::StatusEffectsWindow;
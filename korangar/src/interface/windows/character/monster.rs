@@ -0,0 +1,120 @@
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::chat::monster_link_markup;
+use crate::interface::elements::ProgressBar;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::{MonsterData, MonsterDatabase, ScriptLoader};
+
+/// Information about a monster, either looked up by id or taken from a monster
+/// on the map. Health is only known for the latter, and stats and drops only
+/// if the monster is in the mob database.
+pub struct MonsterInformationWindow {
+    monster_id: usize,
+    name: String,
+    health: Option<(usize, usize)>,
+    data: Option<MonsterData>,
+    /// Names of the items that the monster drops, in the order of the drops.
+    drop_names: Vec<String>,
+}
+
+impl MonsterInformationWindow {
+    pub const WINDOW_CLASS: &'static str = "monster_information";
+
+    pub fn new(
+        monster_id: usize,
+        health: Option<(usize, usize)>,
+        monster_database: &MonsterDatabase,
+        script_loader: &ScriptLoader,
+    ) -> Self {
+        let data = monster_database.get(monster_id).cloned();
+        let drop_names = data
+            .iter()
+            .flat_map(|data| &data.drops)
+            .map(|drop| script_loader.get_item_name_from_id(drop.item_id, true))
+            .collect();
+
+        Self {
+            monster_id,
+            name: monster_database.get_name(monster_id, script_loader),
+            health,
+            data,
+            drop_names,
+        }
+    }
+
+    /// Show a different name, like the one the map server sent for a monster.
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for MonsterInformationWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let mut elements = vec![
+            Text::default().with_text(self.name.clone()).wrap(),
+            Text::default().with_text(format!("Monster id: {}", self.monster_id)).wrap(),
+        ];
+
+        if let Some((health_points, maximum_health_points)) = self.health {
+            elements.push(ProgressBar::new("Health".to_owned(), health_points as u32, maximum_health_points as u32).wrap());
+        }
+
+        match &self.data {
+            Some(data) => {
+                let stats = [
+                    format!("Level: {}", data.level),
+                    format!("Health points: {}", data.health_points),
+                    format!("Attack: {} - {}", data.attack.start(), data.attack.end()),
+                    format!("Defense: {}", data.defense),
+                    format!("Magic defense: {}", data.magic_defense),
+                    format!("Race: {}", data.race),
+                    format!("Element: {} {}", data.element, data.element_level),
+                    format!("Experience: {} / {}", data.base_experience, data.job_experience),
+                ];
+
+                elements.extend(stats.into_iter().map(|line| Text::default().with_text(line).wrap()));
+
+                if !data.drops.is_empty() {
+                    elements.push(Text::default().with_text("Drops").wrap());
+                }
+
+                for (drop, name) in data.drops.iter().zip(&self.drop_names) {
+                    let chance = drop.chance as f32 / 100.0;
+                    elements.push(Text::default().with_text(format!("{name} ({chance}%)")).wrap());
+                }
+            }
+            None => elements.push(Text::default().with_text("Not in the mob database").wrap()),
+        }
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Link to chat")
+                .with_event(UserEvent::InsertChatText(monster_link_markup(self.monster_id)))
+                .build()
+                .wrap(),
+        );
+
+        WindowBuilder::new()
+            .with_title("Monster Information".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 250 < 300, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -48,11 +48,46 @@ impl PrototypeWindow<InterfaceSettings> for CharacterOverviewWindow {
                 .with_event(UserEvent::OpenSkillTreeWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Quests")
+                .with_event(UserEvent::OpenQuestWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Achievements")
+                .with_event(UserEvent::OpenAchievementWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Attendance")
+                .with_event(UserEvent::OpenAttendanceWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Guild")
+                .with_event(UserEvent::OpenGuildWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Status effects")
+                .with_event(UserEvent::OpenStatusEffectsWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Pet")
+                .with_event(UserEvent::OpenPetWindow)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Friends")
                 .with_event(UserEvent::OpenFriendsWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Emotes")
+                .with_event(UserEvent::OpenEmoteWindow)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Menu")
                 .with_event(UserEvent::OpenMenuWindow)
@@ -0,0 +1,43 @@
+use derive_new::new;
+use korangar_interface::elements::ElementWrap;
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::PetInformation;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::PetView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct PetWindow {
+    information: PlainRemote<Option<PetInformation>>,
+}
+
+impl PetWindow {
+    pub const WINDOW_CLASS: &'static str = "pet";
+}
+
+impl PrototypeWindow<InterfaceSettings> for PetWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![PetView::new(self.information.clone()).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Pet".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,61 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView, StateButtonBuilder};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateBinary};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::QuestView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::Quest;
+
+#[derive(new)]
+pub struct QuestWindow {
+    quests: PlainRemote<Vec<Quest>>,
+    show_quest_markers: PlainTrackedState<bool>,
+    show_quest_tracker: PlainTrackedState<bool>,
+}
+
+impl QuestWindow {
+    pub const WINDOW_CLASS: &'static str = "quest";
+}
+
+impl PrototypeWindow<InterfaceSettings> for QuestWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            StateButtonBuilder::new()
+                .with_text("Show markers")
+                .with_remote(self.show_quest_markers.new_remote())
+                .with_event(self.show_quest_markers.toggle_action())
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Show tracker")
+                .with_remote(self.show_quest_tracker.new_remote())
+                .with_event(self.show_quest_tracker.toggle_action())
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ScrollView::new(vec![QuestView::new(self.quests.clone()).wrap()], size_bound!(100%, ? < super)).wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Quests".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
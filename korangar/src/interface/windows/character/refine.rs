@@ -0,0 +1,61 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, PlainTrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::InventoryItem;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::RefineView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::{RefineItem, RefineOutcome};
+use crate::loaders::ResourceMetadata;
+
+#[derive(new)]
+pub struct RefineWindow {
+    items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+    refine_item: PlainRemote<Option<RefineItem>>,
+    outcome: PlainRemote<Option<RefineOutcome>>,
+}
+
+impl RefineWindow {
+    pub const WINDOW_CLASS: &'static str = "refine";
+}
+
+impl PrototypeWindow<InterfaceSettings> for RefineWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let refine_view = RefineView::new(
+            self.items.clone(),
+            self.refine_item.clone(),
+            self.outcome.clone(),
+            PlainTrackedState::default(),
+        );
+
+        let elements = vec![
+            ScrollView::new(vec![refine_view.wrap()], size_bound!(100%, ? < super)).wrap(),
+            ButtonBuilder::new()
+                .with_text("Close")
+                .with_event(UserEvent::CloseRefineWindow)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Refine".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 350 < 450, ? < 80%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
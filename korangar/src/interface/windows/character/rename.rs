@@ -0,0 +1,78 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, FocusMode, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateClone};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::CharacterId;
+
+use super::creation::{MAXIMUM_NAME_LENGTH, MINIMUM_NAME_LENGTH};
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceThemeKind;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct CharacterRenameWindow {
+    character_id: CharacterId,
+}
+
+impl CharacterRenameWindow {
+    pub const WINDOW_CLASS: &'static str = "character_rename";
+}
+
+impl PrototypeWindow<InterfaceSettings> for CharacterRenameWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let name = PlainTrackedState::<String>::default();
+
+        let selector = {
+            let name = name.clone();
+            move || name.get().len() >= MINIMUM_NAME_LENGTH
+        };
+
+        let action = {
+            let character_id = self.character_id;
+            let name = name.clone();
+
+            move || vec![ClickAction::Custom(UserEvent::RenameCharacter(character_id, name.cloned()))]
+        };
+
+        let input_action = Box::new(move || vec![ClickAction::FocusNext(FocusMode::FocusNext)]);
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(name)
+                .with_ghost_text("New character name")
+                .with_enter_action(input_action)
+                .with_length(MAXIMUM_NAME_LENGTH)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Rename")
+                .with_disabled_selector(selector)
+                .with_event(Box::new(action))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Rename Character".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .with_theme_kind(InterfaceThemeKind::Menu)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,13 +1,18 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
 use derive_new::new;
-use korangar_interface::elements::ElementWrap;
-use korangar_interface::size_bound;
-use korangar_interface::state::PlainRemote;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
-use ragnarok_packets::CharacterInformation;
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::{CharacterId, CharacterInformation};
 
+use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
-use crate::interface::elements::CharacterPreview;
+use crate::interface::elements::{CharacterPreview, CharacterSlots, SLOTS_PER_PAGE};
 use crate::interface::layout::ScreenSize;
+use crate::interface::privacy::PrivacySettings;
 use crate::interface::theme::InterfaceThemeKind;
 use crate::interface::windows::WindowCache;
 
@@ -15,7 +20,9 @@ use crate::interface::windows::WindowCache;
 pub struct CharacterSelectionWindow {
     characters: PlainRemote<Vec<CharacterInformation>>,
     move_request: PlainRemote<Option<usize>>,
-    slot_count: usize,
+    deletion_dates: PlainRemote<HashMap<CharacterId, Instant>>,
+    privacy_settings: PlainRemote<PrivacySettings>,
+    slots: CharacterSlots,
 }
 
 impl CharacterSelectionWindow {
@@ -33,10 +40,81 @@ impl PrototypeWindow<InterfaceSettings> for CharacterSelectionWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = (0..self.slot_count)
-            .map(|slot| CharacterPreview::new(self.characters.clone(), self.move_request.clone(), slot).wrap())
+        let page = PlainTrackedState::new(0);
+        let page_count = self.slots.page_count();
+
+        let mut elements: Vec<_> = (0..SLOTS_PER_PAGE)
+            .map(|index| {
+                CharacterPreview::new(
+                    self.characters.clone(),
+                    self.move_request.clone(),
+                    self.deletion_dates.clone(),
+                    self.privacy_settings.clone(),
+                    page.new_remote(),
+                    self.slots,
+                    index,
+                )
+                .wrap()
+            })
             .collect();
 
+        if page_count > 1 {
+            let previous_selector = {
+                let page = page.clone();
+                move || *page.get() > 0
+            };
+
+            let previous_action = {
+                let mut page = page.clone();
+
+                move || {
+                    page.mutate(|page| *page = page.saturating_sub(1));
+                    Vec::new()
+                }
+            };
+
+            let next_selector = {
+                let page = page.clone();
+                move || *page.get() + 1 < page_count
+            };
+
+            let next_action = {
+                let mut page = page.clone();
+
+                move || {
+                    page.mutate(|page| *page = (*page + 1).min(page_count - 1));
+                    Vec::new()
+                }
+            };
+
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Previous page")
+                    .with_disabled_selector(previous_selector)
+                    .with_event(Box::new(previous_action))
+                    .with_width_bound(dimension_bound!(50%))
+                    .build()
+                    .wrap(),
+            );
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Next page")
+                    .with_disabled_selector(next_selector)
+                    .with_event(Box::new(next_action))
+                    .with_width_bound(dimension_bound!(!))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Switch account")
+                .with_event(UserEvent::SwitchAccount)
+                .build()
+                .wrap(),
+        );
+
         WindowBuilder::new()
             .with_title("Character Selection".to_string())
             .with_class(Self::WINDOW_CLASS.to_string())
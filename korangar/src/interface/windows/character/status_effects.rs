@@ -0,0 +1,43 @@
+use derive_new::new;
+use korangar_interface::elements::ElementWrap;
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::StatusEffectView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::StatusEffectEntry;
+
+#[derive(new)]
+pub struct StatusEffectsWindow {
+    entries: PlainRemote<Vec<StatusEffectEntry>>,
+}
+
+impl StatusEffectsWindow {
+    pub const WINDOW_CLASS: &'static str = "status_effects";
+}
+
+impl PrototypeWindow<InterfaceSettings> for StatusEffectsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![StatusEffectView::new(self.entries.clone()).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Status effects".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,4 +1,4 @@
-use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView, StateButtonBuilder};
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView, StateButtonBuilder};
 use korangar_interface::event::ClickAction;
 use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateBinary};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
@@ -14,18 +14,22 @@ pub struct PacketWindow {
     packets: PacketHistoryRemote,
     show_pings: PlainTrackedState<bool>,
     update: PlainTrackedState<bool>,
+    filter: PlainTrackedState<String>,
 }
 
 impl PacketWindow {
     pub const WINDOW_CLASS: &'static str = "network";
 
-    pub fn new(packets: PacketHistoryRemote, update: PlainTrackedState<bool>) -> Self {
+    pub fn new(packets: PacketHistoryRemote) -> Self {
         let show_pings = PlainTrackedState::default();
+        let update = PlainTrackedState::new(!packets.is_paused());
+        let filter = PlainTrackedState::default();
 
         Self {
             packets,
             show_pings,
             update,
+            filter,
         }
     }
 }
@@ -41,7 +45,7 @@ impl PrototypeWindow<InterfaceSettings> for PacketWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![PacketView::new(self.packets.clone(), self.show_pings.new_remote()).wrap()];
+        let elements = vec![PacketView::new(self.packets.clone(), self.show_pings.new_remote(), self.filter.new_remote()).wrap()];
 
         let clear_selector = {
             let packets = self.packets.clone();
@@ -50,28 +54,67 @@ impl PrototypeWindow<InterfaceSettings> for PacketWindow {
 
         let clear_action = { move || vec![ClickAction::Custom(UserEvent::ClearPacketHistory)] };
 
+        let update_action = {
+            let mut update = self.update.clone();
+            let packets = self.packets.clone();
+
+            move || {
+                update.toggle();
+                packets.set_paused(!update.cloned());
+                Vec::new()
+            }
+        };
+
+        let step_selector = {
+            let packets = self.packets.clone();
+            move || packets.is_paused() && packets.has_pending()
+        };
+
+        let step_action = {
+            let packets = self.packets.clone();
+
+            move || {
+                packets.step();
+                Vec::new()
+            }
+        };
+
         let elements = vec![
             ButtonBuilder::new()
                 .with_text("Clear")
                 .with_disabled_selector(clear_selector)
                 .with_event(Box::new(clear_action))
-                .with_width_bound(dimension_bound!(33.33%))
+                .with_width_bound(dimension_bound!(25%))
                 .build()
                 .wrap(),
             StateButtonBuilder::new()
                 .with_text("Show pings")
                 .with_remote(self.show_pings.new_remote())
                 .with_event(self.show_pings.toggle_action())
-                .with_width_bound(dimension_bound!(33.33%))
+                .with_width_bound(dimension_bound!(25%))
                 .build()
                 .wrap(),
             StateButtonBuilder::new()
                 .with_text("Update")
                 .with_remote(self.update.new_remote())
-                .with_event(self.update.toggle_action())
+                .with_event(update_action)
+                .with_width_bound(dimension_bound!(25%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Step")
+                .with_disabled_selector(step_selector)
+                .with_event(Box::new(step_action))
                 .with_width_bound(dimension_bound!(!))
                 .build()
                 .wrap(),
+            InputFieldBuilder::new()
+                .with_state(self.filter.clone())
+                .with_ghost_text("Filter by name or header")
+                .with_enter_action(Vec::new)
+                .with_length(40)
+                .build()
+                .wrap(),
             ScrollView::new(elements, size_bound!(100%, ? < super)).wrap(),
         ];
 
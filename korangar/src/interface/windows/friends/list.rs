@@ -11,11 +11,13 @@ use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::FriendView;
 use crate::interface::layout::ScreenSize;
 use crate::interface::linked::LinkedElement;
+use crate::interface::privacy::PrivacySettings;
 use crate::interface::windows::WindowCache;
 
 #[derive(new)]
 pub struct FriendsWindow {
     friend_list: PlainRemote<Vec<(Friend, LinkedElement)>>,
+    privacy_settings: PlainRemote<PrivacySettings>,
 }
 
 impl FriendsWindow {
@@ -62,7 +64,7 @@ impl PrototypeWindow<InterfaceSettings> for FriendsWindow {
                 .with_width_bound(dimension_bound!(!))
                 .build()
                 .wrap(),
-            FriendView::new(self.friend_list.clone()).wrap(),
+            FriendView::new(self.friend_list.clone(), self.privacy_settings.clone()).wrap(),
         ];
 
         WindowBuilder::new()
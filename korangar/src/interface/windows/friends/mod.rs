@@ -1,5 +1,3 @@
 mod list;
-mod request;
 
 pub use self::list::FriendsWindow;
-pub use self::request::FriendRequestWindow;
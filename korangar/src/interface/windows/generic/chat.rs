@@ -1,17 +1,19 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use chrono::{DateTime, Local};
 use derive_new::new;
 use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView};
 use korangar_interface::event::ClickAction;
-use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateTake};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt, TrackedStateTake};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_interface::{dimension_bound, size_bound};
 use korangar_networking::MessageColor;
 
-use crate::input::UserEvent;
+use crate::input::{complete_chat_command, parse_chat_input};
 use crate::interface::application::InterfaceSettings;
-use crate::interface::elements::ChatBuilder;
+use crate::interface::chat::{ChatSettings, ChatTab, INPUT_HISTORY_LENGTH};
+use crate::interface::elements::{ChatBuilder, ChatTabBar};
 use crate::interface::layout::ScreenSize;
 use crate::interface::theme::InterfaceTheme;
 use crate::interface::windows::WindowCache;
@@ -21,11 +23,27 @@ use crate::loaders::FontLoader;
 pub struct ChatMessage {
     pub text: String,
     pub color: MessageColor,
+    /// Time at which the message was received.
+    pub timestamp: DateTime<Local>,
+}
+
+impl ChatMessage {
+    pub fn new(text: String, color: MessageColor) -> Self {
+        Self {
+            text,
+            color,
+            timestamp: Local::now(),
+        }
+    }
 }
 
 #[derive(new)]
 pub struct ChatWindow {
     messages: PlainRemote<Vec<ChatMessage>>,
+    input_history: PlainTrackedState<Vec<String>>,
+    chat_tabs: PlainRemote<Vec<ChatTab>>,
+    selected_chat_tab: PlainTrackedState<usize>,
+    chat_settings: PlainRemote<ChatSettings>,
     font_loader: Rc<RefCell<FontLoader>>,
 }
 
@@ -46,6 +64,23 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
     ) -> Window<InterfaceSettings> {
         let input_text = PlainTrackedState::<String>::default();
 
+        let mut send_message = {
+            let mut input_history = self.input_history.clone();
+
+            move |message: String| {
+                input_history.mutate(|input_history| {
+                    if input_history.last() != Some(&message) {
+                        input_history.push(message.clone());
+                    }
+
+                    let excess = input_history.len().saturating_sub(INPUT_HISTORY_LENGTH);
+                    input_history.drain(..excess);
+                });
+
+                vec![ClickAction::Custom(parse_chat_input(message))]
+            }
+        };
+
         let button_selector = {
             let input_text = input_text.clone();
 
@@ -54,11 +89,9 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
 
         let button_action = {
             let mut input_text = input_text.clone();
+            let mut send_message = send_message.clone();
 
-            move || {
-                let message = input_text.take();
-                vec![ClickAction::Custom(UserEvent::SendMessage(message))]
-            }
+            move || send_message(input_text.take())
         };
 
         let input_action = {
@@ -66,17 +99,18 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
             Box::new(move || {
                 let message = input_text.take();
 
-                (!message.is_empty())
-                    .then_some(vec![ClickAction::Custom(UserEvent::SendMessage(message))])
-                    .unwrap_or_default()
+                (!message.is_empty()).then(|| send_message(message)).unwrap_or_default()
             })
         };
 
         let elements = vec![
+            ChatTabBar::new(self.chat_tabs.clone(), self.selected_chat_tab.clone(), self.messages.clone()).wrap(),
             InputFieldBuilder::new()
                 .with_state(input_text)
                 .with_ghost_text("Write message or command")
                 .with_enter_action(input_action)
+                .with_completion_action(complete_chat_command)
+                .with_history(self.input_history.clone())
                 .with_length(80)
                 .with_width_bound(dimension_bound!(75%))
                 .build()
@@ -89,15 +123,16 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
                 .build()
                 .wrap(),
             ScrollView::new(
-                vec![
-                    ChatBuilder::new()
-                        .with_messages(self.messages.clone())
-                        .with_font_loader(self.font_loader.clone())
-                        .build()
-                        .wrap(),
-                ],
+                vec![ChatBuilder::new()
+                    .with_messages(self.messages.clone())
+                    .with_font_loader(self.font_loader.clone())
+                    .with_tab_filter(self.chat_tabs.clone(), self.selected_chat_tab.new_remote())
+                    .with_settings(self.chat_settings.clone())
+                    .build()
+                    .wrap()],
                 size_bound!(100%, !),
             )
+            .with_follow_bottom()
             .wrap(),
         ];
 
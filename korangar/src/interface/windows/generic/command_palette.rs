@@ -0,0 +1,93 @@
+use std::rc::Rc;
+
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, InputFieldBuilder, ScrollView};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::{ServerCommandList, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::CommandPaletteView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Searchable list of server commands. Selecting a command inserts it into
+/// the chat window.
+#[derive(new)]
+pub struct CommandPaletteWindow {
+    commands: Rc<ServerCommandList>,
+}
+
+impl CommandPaletteWindow {
+    pub const WINDOW_CLASS: &'static str = "command_palette";
+}
+
+impl PrototypeWindow<InterfaceSettings> for CommandPaletteWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let input_text = PlainTrackedState::<String>::default();
+
+        let enter_action = {
+            let input_text = input_text.clone();
+            let commands = self.commands.clone();
+
+            move || {
+                let input = input_text.get().clone();
+
+                if input.trim().is_empty() {
+                    return Vec::new();
+                }
+
+                // Insert the best match unless the user already typed arguments.
+                let (prefix, matches) = commands.search(&input);
+                let text = match (input.contains(' '), matches.first()) {
+                    (false, Some(command)) => format!("{}{} ", prefix, command.name),
+                    _ => input,
+                };
+
+                vec![ClickAction::Custom(UserEvent::InsertChatText(text)), ClickAction::CloseWindow]
+            }
+        };
+
+        let completion_action = {
+            let commands = self.commands.clone();
+
+            move |input: &str| commands.complete(input)
+        };
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(input_text.clone())
+                .with_ghost_text("Search command")
+                .with_enter_action(enter_action)
+                .with_completion_action(completion_action)
+                .with_length(80)
+                .with_width_bound(dimension_bound!(100%))
+                .build()
+                .wrap(),
+            ScrollView::new(
+                vec![CommandPaletteView::new(self.commands.clone(), input_text.new_remote()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Commands".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 600, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,58 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
+use korangar_interface::event::ClickAction;
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::windows::WindowCache;
+
+/// Small window with a list of options that is opened at the mouse position
+/// when right-clicking on something. Only one context menu can be open at a
+/// time and it is closed when clicking anywhere else.
+#[derive(new)]
+pub struct ContextMenuWindow {
+    title: String,
+    options: Vec<(String, UserEvent)>,
+    position: ScreenPosition,
+}
+
+impl ContextMenuWindow {
+    pub const WINDOW_CLASS: &'static str = "context_menu";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ContextMenuWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = self
+            .options
+            .iter()
+            .cloned()
+            .map(|(text, event)| {
+                ButtonBuilder::new()
+                    .with_text(text)
+                    .with_event(move || vec![ClickAction::Custom(event.clone()), ClickAction::CloseWindow])
+                    .build()
+                    .wrap()
+            })
+            .collect();
+
+        WindowBuilder::new()
+            .with_title(self.title.clone())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(150 > 150 < 200, ?))
+            .with_elements(elements)
+            .with_position(self.position)
+            .build(window_cache, application, available_space)
+    }
+}
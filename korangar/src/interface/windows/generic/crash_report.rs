@@ -0,0 +1,56 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceThemeKind;
+use crate::interface::windows::WindowCache;
+
+/// Shown on the first launch after a crash.
+#[derive(new)]
+pub struct CrashReportWindow {
+    report_path: String,
+}
+
+impl PrototypeWindow<InterfaceSettings> for CrashReportWindow {
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let report_path = self.report_path.clone();
+
+        let elements = vec![
+            Text::default().with_text("Korangar crashed the last time it was running.").wrap(),
+            Text::default()
+                .with_text(format!(
+                    "A crash report was saved to {}. Please attach it when reporting the bug.",
+                    self.report_path
+                ))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Open report")
+                .with_event(Box::new(move || {
+                    vec![
+                        ClickAction::Custom(UserEvent::OpenCrashReport(report_path.clone())),
+                        ClickAction::CloseWindow,
+                    ]
+                }))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Crash report".to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ?))
+            .with_elements(elements)
+            .closable()
+            .with_theme_kind(InterfaceThemeKind::Menu)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,9 +1,10 @@
-use korangar_interface::elements::ElementWrap;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
 use korangar_interface::size_bound;
 use korangar_interface::state::{PlainTrackedState, TrackedState};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use ragnarok_packets::EntityId;
 
+use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::{DialogContainer, DialogElement};
 use crate::interface::layout::ScreenSize;
@@ -12,17 +13,19 @@ use crate::interface::windows::WindowCache;
 pub struct DialogWindow {
     elements: PlainTrackedState<Vec<DialogElement>>,
     npc_id: EntityId,
+    show_bank_button: bool,
 }
 
 impl DialogWindow {
     pub const WINDOW_CLASS: &'static str = "dialog";
 
-    pub fn new(text: String, npc_id: EntityId) -> (Self, PlainTrackedState<Vec<DialogElement>>) {
+    pub fn new(text: String, npc_id: EntityId, show_bank_button: bool) -> (Self, PlainTrackedState<Vec<DialogElement>>) {
         let elements = PlainTrackedState::new(vec![DialogElement::Text(text)]);
 
         let dialog_window = Self {
             elements: elements.clone(),
             npc_id,
+            show_bank_button,
         };
 
         (dialog_window, elements)
@@ -40,7 +43,17 @@ impl PrototypeWindow<InterfaceSettings> for DialogWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![DialogContainer::new(self.elements.new_remote(), self.npc_id).wrap()];
+        let mut elements = vec![DialogContainer::new(self.elements.new_remote(), self.npc_id).wrap()];
+
+        if self.show_bank_button {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Bank")
+                    .with_event(UserEvent::OpenBank)
+                    .build()
+                    .wrap(),
+            );
+        }
 
         WindowBuilder::new()
             .with_title("Dialog".to_string())
@@ -37,6 +37,46 @@ impl PrototypeWindow<InterfaceSettings> for MenuWindow {
                 .with_event(UserEvent::OpenAudioSettingsWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Request settings")
+                .with_event(UserEvent::OpenRequestSettingsWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Chat settings")
+                .with_event(UserEvent::OpenChatSettingsWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Privacy settings")
+                .with_event(UserEvent::OpenPrivacySettingsWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Key bindings")
+                .with_event(UserEvent::OpenKeyBindingsWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Theme editor")
+                .with_event(UserEvent::OpenThemeEditorWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Minimap")
+                .with_event(UserEvent::OpenMinimapWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("World map")
+                .with_event(UserEvent::OpenWorldMapWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Network quality")
+                .with_event(UserEvent::OpenNetworkQualityWindow)
+                .build()
+                .wrap(),
             #[cfg(feature = "debug")]
             ButtonBuilder::new()
                 .with_text("Render settings")
@@ -73,13 +113,6 @@ impl PrototypeWindow<InterfaceSettings> for MenuWindow {
                 .build()
                 .wrap(),
             #[cfg(feature = "debug")]
-            ButtonBuilder::new()
-                .with_text("Theme viewer")
-                .with_event(UserEvent::OpenThemeViewerWindow)
-                .with_foreground_color(|theme: &InterfaceTheme| theme.button.debug_foreground_color.get())
-                .build()
-                .wrap(),
-            #[cfg(feature = "debug")]
             ButtonBuilder::new()
                 .with_text("Profiler")
                 .with_event(UserEvent::OpenProfilerWindow)
@@ -98,6 +131,11 @@ impl PrototypeWindow<InterfaceSettings> for MenuWindow {
                 .with_event(UserEvent::LogOut)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Switch account")
+                .with_event(UserEvent::SwitchAccount)
+                .build()
+                .wrap(),
             ButtonBuilder::new().with_text("Exit").with_event(UserEvent::Exit).build().wrap(),
         ];
 
@@ -0,0 +1,79 @@
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+use korangar_interface::windows::{AnchorPoint, PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::Minimap;
+use crate::interface::layout::ScreenSize;
+use crate::interface::minimap::{MinimapData, ZOOM_LEVELS};
+use crate::interface::windows::WindowCache;
+
+pub struct MinimapWindow {
+    data: PlainRemote<MinimapData>,
+    zoom_level: PlainTrackedState<usize>,
+}
+
+impl MinimapWindow {
+    pub const WINDOW_CLASS: &'static str = "minimap";
+
+    pub fn new(data: PlainRemote<MinimapData>, zoom_level: PlainTrackedState<usize>) -> Self {
+        Self { data, zoom_level }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for MinimapWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let zoom_out = {
+            let mut zoom_level = self.zoom_level.clone();
+
+            move || {
+                zoom_level.mutate(|level| *level = level.saturating_sub(1));
+                Vec::new()
+            }
+        };
+
+        let zoom_in = {
+            let mut zoom_level = self.zoom_level.clone();
+
+            move || {
+                zoom_level.mutate(|level| *level = (*level + 1).min(ZOOM_LEVELS.len() - 1));
+                Vec::new()
+            }
+        };
+
+        let elements = vec![
+            Minimap::new(self.data.clone(), self.zoom_level.new_remote()).wrap(),
+            ButtonBuilder::new()
+                .with_text("-")
+                .with_event(Box::new(zoom_out))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("+")
+                .with_event(Box::new(zoom_in))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Minimap".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(150 > 220 < 400, ?))
+            .with_elements(elements)
+            .with_default_anchor(AnchorPoint::TopRight)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
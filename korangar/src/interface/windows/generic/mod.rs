@@ -1,9 +1,27 @@
 mod chat;
+mod command_palette;
+mod context_menu;
+mod crash_report;
 mod dialog;
 mod error;
 mod menu;
+mod minimap;
+mod network;
+mod plugin;
+mod request;
+mod update;
+mod world_map;
 
 pub use self::chat::{ChatMessage, ChatWindow};
+pub use self::command_palette::CommandPaletteWindow;
+pub use self::context_menu::ContextMenuWindow;
+pub use self::crash_report::CrashReportWindow;
 pub use self::dialog::DialogWindow;
 pub use self::error::ErrorWindow;
 pub use self::menu::MenuWindow;
+pub use self::minimap::MinimapWindow;
+pub use self::network::NetworkQualityWindow;
+pub use self::plugin::PluginWindow;
+pub use self::request::IncomingRequestWindow;
+pub use self::update::UpdateWindow;
+pub use self::world_map::WorldMapWindow;
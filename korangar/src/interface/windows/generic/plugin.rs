@@ -0,0 +1,39 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, Text};
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceThemeKind;
+use crate::interface::windows::WindowCache;
+
+/// A window that was opened by a plugin.
+#[derive(new)]
+pub struct PluginWindow {
+    title: String,
+    lines: Vec<String>,
+}
+
+impl PrototypeWindow<InterfaceSettings> for PluginWindow {
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = self
+            .lines
+            .iter()
+            .map(|line| Text::default().with_text(line.clone()).wrap())
+            .collect();
+
+        WindowBuilder::new()
+            .with_title(self.title.clone())
+            .with_size_bound(size_bound!(200 > 300 < 500, ?))
+            .with_elements(elements)
+            .closable()
+            .with_theme_kind(InterfaceThemeKind::Menu)
+            .build(window_cache, application, available_space)
+    }
+}
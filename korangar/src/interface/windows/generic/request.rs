@@ -0,0 +1,110 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::IncomingRequest;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::privacy::{display_name, mask_label};
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct IncomingRequestWindow {
+    request: IncomingRequest,
+    streamer_mode: bool,
+}
+
+impl IncomingRequestWindow {
+    pub const WINDOW_CLASS: &'static str = "incoming_request";
+}
+
+impl PrototypeWindow<InterfaceSettings> for IncomingRequestWindow {
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let group_name = |kind: &str, name: &str| match self.streamer_mode {
+            true => mask_label(kind, name),
+            false => name.to_owned(),
+        };
+
+        let (title, text) = match &self.request {
+            IncomingRequest::Party { party_name, .. } => (
+                "Party invite",
+                format!(
+                    "You have been invited to join the party ^ffaa00{}^000000",
+                    group_name("Party", party_name)
+                ),
+            ),
+            IncomingRequest::Guild { guild_name, .. } => (
+                "Guild invite",
+                format!(
+                    "You have been invited to join the guild ^ffaa00{}^000000",
+                    group_name("Guild", guild_name)
+                ),
+            ),
+            IncomingRequest::Trade { name, base_level, .. } => (
+                "Trade request",
+                format!(
+                    "^ffaa00{}^000000 (level {}) wants to trade with you",
+                    display_name(name, self.streamer_mode),
+                    base_level
+                ),
+            ),
+            IncomingRequest::Friend { requestee } => (
+                "Friend request",
+                format!(
+                    "^ffaa00{}^000000 wants to be friends with you",
+                    display_name(&requestee.name, self.streamer_mode)
+                ),
+            ),
+        };
+
+        // Multiple requests can be open at the same time, so every window closes itself
+        // once it's answered.
+        let respond = |accept: bool| {
+            let request = self.request.clone();
+
+            move || -> Vec<ClickAction<InterfaceSettings>> {
+                vec![
+                    ClickAction::Custom(UserEvent::RespondToRequest {
+                        request: request.clone(),
+                        accept,
+                    }),
+                    ClickAction::CloseWindow,
+                ]
+            }
+        };
+
+        let elements = vec![
+            Text::default().with_text(text).wrap(),
+            ButtonBuilder::new()
+                .with_text("reject")
+                .with_event(respond(false))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("accept")
+                .with_event(respond(true))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title(title.to_string())
+            // We give the builder a class but we don't implement the `window_class` method
+            // of the trait. This way we can open multiple windos of this type but we can still
+            // close them with the class name.
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 250 < 250, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,58 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceThemeKind;
+use crate::interface::windows::WindowCache;
+
+/// Shown when a newer version of the client is available.
+#[derive(new)]
+pub struct UpdateWindow {
+    version: String,
+    changelog: String,
+}
+
+impl UpdateWindow {
+    pub const WINDOW_CLASS: &'static str = "update";
+}
+
+impl PrototypeWindow<InterfaceSettings> for UpdateWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let mut elements = vec![Text::default().with_text(format!("Version {} is available.", self.version)).wrap()];
+
+        elements.extend(self.changelog.lines().map(|line| Text::default().with_text(line.to_owned()).wrap()));
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Download")
+                .with_event(Box::new(|| {
+                    vec![ClickAction::Custom(UserEvent::DownloadUpdate), ClickAction::CloseWindow]
+                }))
+                .build()
+                .wrap(),
+        );
+
+        WindowBuilder::new()
+            .with_title("Update".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ?))
+            .with_elements(elements)
+            .closable()
+            .with_theme_kind(InterfaceThemeKind::Menu)
+            .build(window_cache, application, available_space)
+    }
+}
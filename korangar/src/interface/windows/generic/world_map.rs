@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use korangar_interface::elements::{ElementWrap, InputFieldBuilder};
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use vulkano::image::view::ImageView;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::WorldMapView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::interface::world_map::WorldMap;
+
+pub struct WorldMapWindow {
+    world_map: WorldMap,
+    texture: Option<Arc<ImageView>>,
+    current_map_name: String,
+    search: PlainTrackedState<String>,
+}
+
+impl WorldMapWindow {
+    pub const WINDOW_CLASS: &'static str = "world_map";
+
+    pub fn new(world_map: WorldMap, texture: Option<Arc<ImageView>>, current_map_name: String) -> Self {
+        Self {
+            world_map,
+            texture,
+            current_map_name,
+            search: PlainTrackedState::default(),
+        }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for WorldMapWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(self.search.clone())
+                .with_ghost_text("Search map")
+                .with_enter_action(Vec::new)
+                .with_length(30)
+                .build()
+                .wrap(),
+            WorldMapView::new(
+                self.world_map.clone(),
+                self.texture.clone(),
+                self.current_map_name.clone(),
+                self.search.new_remote(),
+            )
+            .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("World map".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 500 < 800, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,12 +1,21 @@
-use korangar_interface::size_bound;
+use derive_new::new;
+use korangar_audio::get_output_device_names;
+use korangar_interface::elements::{ElementWrap, PickList, StateButtonBuilder, Text};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateBinary};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
 
+use crate::audio::AudioSettings;
 use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{NowPlayingText, VolumeSlider};
 use crate::interface::layout::ScreenSize;
 use crate::interface::windows::WindowCache;
 
-#[derive(Default)]
-pub struct AudioSettingsWindow;
+#[derive(new)]
+pub struct AudioSettingsWindow {
+    background_music_track: PlainRemote<Option<String>>,
+    audio_settings: PlainTrackedState<AudioSettings>,
+}
 
 impl AudioSettingsWindow {
     pub const WINDOW_CLASS: &'static str = "audio_settings";
@@ -23,7 +32,65 @@ impl PrototypeWindow<InterfaceSettings> for AudioSettingsWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![];
+        let output_devices = std::iter::once(("Default".to_owned(), None))
+            .chain(get_output_device_names().into_iter().map(|name| (name.clone(), Some(name))))
+            .collect();
+
+        let reduce_volume_unfocused = self.audio_settings.mapped(|settings| &settings.reduce_volume_unfocused);
+        let doppler_effect = self.audio_settings.mapped(|settings| &settings.doppler_effect);
+
+        let elements = vec![
+            Text::default().with_text("Output device").with_width(dimension_bound!(50%)).wrap(),
+            PickList::default()
+                .with_options(output_devices)
+                .with_selected(self.audio_settings.mapped(|settings| &settings.output_device))
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .wrap(),
+            VolumeSlider::new(
+                "Master".to_owned(),
+                self.audio_settings.mapped(|settings| &settings.master_volume),
+            )
+            .wrap(),
+            VolumeSlider::new(
+                "Music".to_owned(),
+                self.audio_settings.mapped(|settings| &settings.background_music_volume),
+            )
+            .wrap(),
+            VolumeSlider::new(
+                "Effects".to_owned(),
+                self.audio_settings.mapped(|settings| &settings.effects_volume),
+            )
+            .wrap(),
+            VolumeSlider::new(
+                "Ambient".to_owned(),
+                self.audio_settings.mapped(|settings| &settings.ambient_volume),
+            )
+            .wrap(),
+            VolumeSlider::new(
+                "Interface".to_owned(),
+                self.audio_settings.mapped(|settings| &settings.interface_volume),
+            )
+            .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Lower volume when unfocused")
+                .with_event(reduce_volume_unfocused.toggle_action())
+                .with_remote(reduce_volume_unfocused.new_remote())
+                .build()
+                .wrap(),
+            VolumeSlider::new(
+                "Unfocused".to_owned(),
+                self.audio_settings.mapped(|settings| &settings.unfocused_volume),
+            )
+            .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Doppler effect")
+                .with_event(doppler_effect.toggle_action())
+                .with_remote(doppler_effect.new_remote())
+                .build()
+                .wrap(),
+            NowPlayingText::new(self.background_music_track.clone()).wrap(),
+        ];
 
         WindowBuilder::new()
             .with_title("Audio Settings".to_string())
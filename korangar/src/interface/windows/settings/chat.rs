@@ -0,0 +1,123 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, PickList, StateButtonBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateBinary, TrackedStateTake};
+use korangar_interface::tooltip::WithTooltip;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::chat::{ChatCategory, ChatColors, ChatLineSpacing, ChatSettings, ChatTab};
+use crate::interface::elements::ChatTabsView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct ChatSettingsWindow {
+    chat_settings: PlainTrackedState<ChatSettings>,
+    chat_tabs: PlainRemote<Vec<ChatTab>>,
+}
+
+impl ChatSettingsWindow {
+    pub const WINDOW_CLASS: &'static str = "chat_settings";
+}
+
+impl PrototypeWindow<InterfaceSettings> for ChatSettingsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let save_chat_log = self.chat_settings.mapped(|settings| &settings.save_chat_log);
+        let show_timestamps = self.chat_settings.mapped(|settings| &settings.show_timestamps);
+        let tab_name = PlainTrackedState::<String>::default();
+
+        let add_tab = {
+            let mut tab_name = tab_name.clone();
+
+            move || {
+                let name = tab_name.take();
+
+                (!name.is_empty())
+                    .then(|| vec![ClickAction::Custom(UserEvent::AddChatTab(name))])
+                    .unwrap_or_default()
+            }
+        };
+
+        let mut elements = vec![
+            StateButtonBuilder::new()
+                .with_text("Save chat log")
+                .with_remote(save_chat_log.new_remote())
+                .with_event(save_chat_log.toggle_action())
+                .build()
+                .with_tooltip("Write all chat messages to a file in client/chat_logs")
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Show timestamps")
+                .with_remote(show_timestamps.new_remote())
+                .with_event(show_timestamps.toggle_action())
+                .build()
+                .wrap(),
+            Text::default().with_text("Line spacing").with_width(dimension_bound!(50%)).wrap(),
+            PickList::default()
+                .with_options(vec![
+                    ("Compact", ChatLineSpacing::Compact),
+                    ("Expanded", ChatLineSpacing::Expanded),
+                ])
+                .with_selected(self.chat_settings.mapped(|settings| &settings.line_spacing))
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .wrap(),
+        ];
+
+        for category in ChatCategory::all() {
+            elements.push(
+                Text::default()
+                    .with_text(format!("{category} color"))
+                    .with_width(dimension_bound!(50%))
+                    .wrap(),
+            );
+            elements.push(
+                PickList::default()
+                    .with_options(ChatColors::options())
+                    .with_selected(self.chat_settings.mapped(move |settings| settings.colors.get(category)))
+                    .with_event(Box::new(Vec::new))
+                    .with_width(dimension_bound!(!))
+                    .wrap(),
+            );
+        }
+
+        elements.extend([
+            Text::default().with_text("Chat tabs").wrap(),
+            ChatTabsView::new(self.chat_tabs.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(tab_name)
+                .with_ghost_text("Tab name")
+                .with_enter_action(Box::new(add_tab.clone()))
+                .with_length(16)
+                .with_width_bound(dimension_bound!(70%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Add tab")
+                .with_event(Box::new(add_tab))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]);
+
+        WindowBuilder::new()
+            .with_title("Chat Settings".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 350 < 500, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,5 +1,6 @@
 use korangar_interface::elements::{ElementWrap, PickList, PrototypeElement, StateButtonBuilder, Text};
 use korangar_interface::state::{TrackedState, TrackedStateBinary};
+use korangar_interface::tooltip::WithTooltip;
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_interface::{dimension_bound, size_bound};
 
@@ -7,37 +8,179 @@ use crate::graphics::{PresentModeInfo, ShadowDetail};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::ScreenSize;
 use crate::interface::windows::WindowCache;
+use crate::world::HealthBarVisibility;
 
-pub struct GraphicsSettingsWindow<Shadow, Framerate>
-where
+pub struct GraphicsSettingsWindow<
+    Shadow,
+    Framerate,
+    Overlay,
+    Metrics,
+    HealthBar,
+    Smoothing,
+    Interpolation,
+    Corpse,
+    Bloom,
+    BloomThreshold,
+    RenderScale,
+    DynamicScale,
+    Sharpening,
+> where
     Shadow: TrackedState<ShadowDetail> + 'static,
     Framerate: TrackedStateBinary<bool>,
+    Overlay: TrackedStateBinary<bool>,
+    Metrics: TrackedStateBinary<bool>,
+    HealthBar: TrackedState<HealthBarVisibility> + 'static,
+    Smoothing: TrackedStateBinary<bool>,
+    Interpolation: TrackedState<u32> + 'static,
+    Corpse: TrackedState<u32> + 'static,
+    Bloom: TrackedState<f32> + 'static,
+    BloomThreshold: TrackedState<f32> + 'static,
+    RenderScale: TrackedState<f32> + 'static,
+    DynamicScale: TrackedStateBinary<bool>,
+    Sharpening: TrackedStateBinary<bool>,
 {
     present_mode_info: PresentModeInfo,
     shadow_detail: Shadow,
     framerate_limit: Framerate,
+    show_frame_overlay: Overlay,
+    export_metrics: Metrics,
+    health_bar_visibility: HealthBar,
+    smooth_movement: Smoothing,
+    interpolation_delay: Interpolation,
+    corpse_duration: Corpse,
+    bloom_intensity: Bloom,
+    bloom_threshold: BloomThreshold,
+    render_scale: RenderScale,
+    dynamic_render_scale: DynamicScale,
+    sharpen_upscaling: Sharpening,
 }
 
-impl<Shadow, Framerate> GraphicsSettingsWindow<Shadow, Framerate>
+impl<
+        Shadow,
+        Framerate,
+        Overlay,
+        Metrics,
+        HealthBar,
+        Smoothing,
+        Interpolation,
+        Corpse,
+        Bloom,
+        BloomThreshold,
+        RenderScale,
+        DynamicScale,
+        Sharpening,
+    >
+    GraphicsSettingsWindow<
+        Shadow,
+        Framerate,
+        Overlay,
+        Metrics,
+        HealthBar,
+        Smoothing,
+        Interpolation,
+        Corpse,
+        Bloom,
+        BloomThreshold,
+        RenderScale,
+        DynamicScale,
+        Sharpening,
+    >
 where
     Shadow: TrackedState<ShadowDetail> + 'static,
     Framerate: TrackedStateBinary<bool>,
+    Overlay: TrackedStateBinary<bool>,
+    Metrics: TrackedStateBinary<bool>,
+    HealthBar: TrackedState<HealthBarVisibility> + 'static,
+    Smoothing: TrackedStateBinary<bool>,
+    Interpolation: TrackedState<u32> + 'static,
+    Corpse: TrackedState<u32> + 'static,
+    Bloom: TrackedState<f32> + 'static,
+    BloomThreshold: TrackedState<f32> + 'static,
+    RenderScale: TrackedState<f32> + 'static,
+    DynamicScale: TrackedStateBinary<bool>,
+    Sharpening: TrackedStateBinary<bool>,
 {
     pub const WINDOW_CLASS: &'static str = "graphics_settings";
 
-    pub fn new(present_mode_info: PresentModeInfo, shadow_detail: Shadow, framerate_limit: Framerate) -> Self {
+    pub fn new(
+        present_mode_info: PresentModeInfo,
+        shadow_detail: Shadow,
+        framerate_limit: Framerate,
+        show_frame_overlay: Overlay,
+        export_metrics: Metrics,
+        health_bar_visibility: HealthBar,
+        smooth_movement: Smoothing,
+        interpolation_delay: Interpolation,
+        corpse_duration: Corpse,
+        bloom_intensity: Bloom,
+        bloom_threshold: BloomThreshold,
+        render_scale: RenderScale,
+        dynamic_render_scale: DynamicScale,
+        sharpen_upscaling: Sharpening,
+    ) -> Self {
         Self {
             present_mode_info,
             shadow_detail,
             framerate_limit,
+            show_frame_overlay,
+            export_metrics,
+            health_bar_visibility,
+            smooth_movement,
+            interpolation_delay,
+            corpse_duration,
+            bloom_intensity,
+            bloom_threshold,
+            render_scale,
+            dynamic_render_scale,
+            sharpen_upscaling,
         }
     }
 }
 
-impl<Shadow, Framerate> PrototypeWindow<InterfaceSettings> for GraphicsSettingsWindow<Shadow, Framerate>
+impl<
+        Shadow,
+        Framerate,
+        Overlay,
+        Metrics,
+        HealthBar,
+        Smoothing,
+        Interpolation,
+        Corpse,
+        Bloom,
+        BloomThreshold,
+        RenderScale,
+        DynamicScale,
+        Sharpening,
+    > PrototypeWindow<InterfaceSettings>
+    for GraphicsSettingsWindow<
+        Shadow,
+        Framerate,
+        Overlay,
+        Metrics,
+        HealthBar,
+        Smoothing,
+        Interpolation,
+        Corpse,
+        Bloom,
+        BloomThreshold,
+        RenderScale,
+        DynamicScale,
+        Sharpening,
+    >
 where
     Shadow: TrackedState<ShadowDetail> + 'static,
     Framerate: TrackedStateBinary<bool>,
+    Overlay: TrackedStateBinary<bool>,
+    Metrics: TrackedStateBinary<bool>,
+    HealthBar: TrackedState<HealthBarVisibility> + 'static,
+    Smoothing: TrackedStateBinary<bool>,
+    Interpolation: TrackedState<u32> + 'static,
+    Corpse: TrackedState<u32> + 'static,
+    Bloom: TrackedState<f32> + 'static,
+    BloomThreshold: TrackedState<f32> + 'static,
+    RenderScale: TrackedState<f32> + 'static,
+    DynamicScale: TrackedStateBinary<bool>,
+    Sharpening: TrackedStateBinary<bool>,
 {
     fn window_class(&self) -> Option<&str> {
         Self::WINDOW_CLASS.into()
@@ -62,6 +205,108 @@ where
                 .with_event(Box::new(Vec::new))
                 .with_width(dimension_bound!(!))
                 .wrap(),
+            Text::default()
+                .with_text("Monster health bars")
+                .with_width(dimension_bound!(50%))
+                .wrap(),
+            PickList::default()
+                .with_options(vec![
+                    ("Always", HealthBarVisibility::Always),
+                    ("After damage", HealthBarVisibility::AfterDamage),
+                    ("Never", HealthBarVisibility::Never),
+                ])
+                .with_selected(self.health_bar_visibility.clone())
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Smooth movement")
+                .with_event(self.smooth_movement.toggle_action())
+                .with_remote(self.smooth_movement.new_remote())
+                .build()
+                .with_tooltip("Round off the corners of walking paths")
+                .wrap(),
+            Text::default()
+                .with_text("Interpolation delay")
+                .with_width(dimension_bound!(50%))
+                .wrap(),
+            PickList::default()
+                .with_options(vec![("Off", 0), ("100 ms", 100), ("150 ms", 150), ("200 ms", 200)])
+                .with_selected(self.interpolation_delay.clone())
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .with_tooltip("Delay the walks of other players and monsters to smooth out network jitter")
+                .wrap(),
+            Text::default().with_text("Corpses").with_width(dimension_bound!(50%)).wrap(),
+            PickList::default()
+                .with_options(vec![("Off", 0), ("5 s", 5000), ("10 s", 10000), ("30 s", 30000)])
+                .with_selected(self.corpse_duration.clone())
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .with_tooltip("Keep dead monsters on the ground for a while")
+                .wrap(),
+            Text::default().with_text("Bloom").with_width(dimension_bound!(50%)).wrap(),
+            PickList::default()
+                .with_options(vec![("Off", 0.0), ("Low", 0.25), ("Medium", 0.5), ("High", 1.0)])
+                .with_selected(self.bloom_intensity.clone())
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .with_tooltip("Make bright lights, effects and reflections on the water glow")
+                .wrap(),
+            Text::default()
+                .with_text("Bloom threshold")
+                .with_width(dimension_bound!(50%))
+                .wrap(),
+            PickList::default()
+                .with_options(vec![("Low", 0.6), ("Medium", 1.0), ("High", 1.4)])
+                .with_selected(self.bloom_threshold.clone())
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .with_tooltip("How bright light has to be before it starts to glow")
+                .wrap(),
+            Text::default().with_text("Render scale").with_width(dimension_bound!(50%)).wrap(),
+            PickList::default()
+                .with_options(vec![("50%", 0.5), ("75%", 0.75), ("100%", 1.0), ("150%", 1.5), ("200%", 2.0)])
+                .with_selected(self.render_scale.clone())
+                .with_event(Box::new(Vec::new))
+                .with_width(dimension_bound!(!))
+                .with_tooltip("Resolution of the game world relative to the window")
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Dynamic render scale")
+                .with_event(self.dynamic_render_scale.toggle_action())
+                .with_remote(self.dynamic_render_scale.new_remote())
+                .build()
+                .with_tooltip("Lower the render scale while the frame rate drops below the refresh rate of the monitor")
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Sharpen upscaling")
+                .with_event(self.sharpen_upscaling.toggle_action())
+                .with_remote(self.sharpen_upscaling.new_remote())
+                .build()
+                .with_tooltip("Sharpen the game world when it is rendered below the resolution of the window")
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Show frame overlay")
+                .with_event(self.show_frame_overlay.toggle_action())
+                .with_remote(self.show_frame_overlay.new_remote())
+                .build()
+                .with_tooltip("Show the frame rate and a graph of the recent frame times")
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Export performance metrics")
+                .with_event(self.export_metrics.toggle_action())
+                .with_remote(self.export_metrics.new_remote())
+                .build()
+                .with_tooltip("Write the timings of every frame to a file in the metrics directory")
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Use monitor scaling")
+                .with_event(application.get_automatic_scaling().toggle_action())
+                .with_remote(application.get_automatic_scaling().new_remote())
+                .build()
+                .with_tooltip("Multiply the interface scaling with the scale factor of the monitor")
+                .wrap(),
             application.to_element("Interface settings".to_string()),
         ];
 
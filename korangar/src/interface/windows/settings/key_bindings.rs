@@ -0,0 +1,153 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView, StateButtonBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateBinary, TrackedStateClone};
+use korangar_interface::tooltip::WithTooltip;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::{InputAction, KeyBindings, MouseAction, MouseSettings, UserEvent, MACRO_COUNT};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{KeyBindingView, VolumeSlider};
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct KeyBindingsWindow {
+    key_bindings: PlainRemote<KeyBindings>,
+    key_capture: PlainRemote<Option<InputAction>>,
+    mouse_capture: PlainRemote<Option<MouseAction>>,
+    macro_scripts: Vec<String>,
+    mouse_settings: PlainTrackedState<MouseSettings>,
+}
+
+impl KeyBindingsWindow {
+    pub const WINDOW_CLASS: &'static str = "key_bindings";
+}
+
+impl PrototypeWindow<InterfaceSettings> for KeyBindingsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let profile_name = PlainTrackedState::<String>::default();
+
+        let profile_action = |event: fn(String) -> UserEvent| {
+            let profile_name = profile_name.clone();
+
+            Box::new(move || {
+                let name = profile_name.cloned();
+
+                (!name.is_empty())
+                    .then(|| vec![ClickAction::Custom(event(name))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let raw_input = self.mouse_settings.mapped(|settings| &settings.raw_input);
+        let invert_rotation = self.mouse_settings.mapped(|settings| &settings.invert_rotation);
+        let walk_prediction = self.mouse_settings.mapped(|settings| &settings.walk_prediction);
+
+        let mut elements = vec![
+            Text::default()
+                .with_text("Click a binding and press a key or mouse button. Escape cancels, backspace unbinds.")
+                .wrap(),
+            ScrollView::new(
+                vec![KeyBindingView::new(self.key_bindings.clone(), self.key_capture.clone(), self.mouse_capture.clone()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+            InputFieldBuilder::new()
+                .with_state(profile_name.clone())
+                .with_ghost_text("Profile name")
+                .with_enter_action(profile_action(UserEvent::ExportKeyBindings))
+                .with_length(24)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Export")
+                .with_event(profile_action(UserEvent::ExportKeyBindings))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Import")
+                .with_event(profile_action(UserEvent::ImportKeyBindings))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Reset to defaults")
+                .with_event(UserEvent::ResetKeyBindings)
+                .build()
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Raw mouse input")
+                .with_event(raw_input.toggle_action())
+                .with_remote(raw_input.new_remote())
+                .build()
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Invert camera rotation")
+                .with_event(invert_rotation.toggle_action())
+                .with_remote(invert_rotation.new_remote())
+                .build()
+                .wrap(),
+            StateButtonBuilder::new()
+                .with_text("Predict walking")
+                .with_event(walk_prediction.toggle_action())
+                .with_remote(walk_prediction.new_remote())
+                .build()
+                .with_tooltip("Start walking before the server confirms the walk")
+                .wrap(),
+            VolumeSlider::new(
+                "Mouse sensitivity".to_owned(),
+                self.mouse_settings.mapped(|settings| &settings.sensitivity),
+            )
+            .wrap(),
+            Text::default()
+                .with_text("Macros run steps separated by ;, for example: hotbar 1; wait 300; /sit")
+                .wrap(),
+        ];
+
+        for index in 0..MACRO_COUNT {
+            let script = PlainTrackedState::new(self.macro_scripts.get(index).cloned().unwrap_or_default());
+
+            let save_action =
+                |script: PlainTrackedState<String>| move || vec![ClickAction::Custom(UserEvent::SetMacro(index, script.cloned()))];
+
+            elements.push(
+                InputFieldBuilder::new()
+                    .with_state(script.clone())
+                    .with_ghost_text(format!("Macro {}", index + 1))
+                    .with_enter_action(save_action(script.clone()))
+                    .with_length(200)
+                    .with_width_bound(dimension_bound!(75%))
+                    .build()
+                    .wrap(),
+            );
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Save")
+                    .with_event(save_action(script))
+                    .with_width_bound(dimension_bound!(!))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        WindowBuilder::new()
+            .with_title("Key Bindings".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 350 < 500, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,9 +1,17 @@
 mod audio;
+mod chat;
 mod graphics;
+mod key_bindings;
+mod privacy;
 #[cfg(feature = "debug")]
 mod render;
+mod request;
 
 pub use self::audio::AudioSettingsWindow;
+pub use self::chat::ChatSettingsWindow;
 pub use self::graphics::GraphicsSettingsWindow;
+pub use self::key_bindings::KeyBindingsWindow;
+pub use self::privacy::PrivacySettingsWindow;
 #[cfg(feature = "debug")]
 pub use self::render::RenderSettingsWindow;
+pub use self::request::RequestSettingsWindow;
@@ -0,0 +1,51 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, StateButtonBuilder};
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateBinary};
+use korangar_interface::tooltip::WithTooltip;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::privacy::PrivacySettings;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct PrivacySettingsWindow {
+    privacy_settings: PlainTrackedState<PrivacySettings>,
+}
+
+impl PrivacySettingsWindow {
+    pub const WINDOW_CLASS: &'static str = "privacy_settings";
+}
+
+impl PrototypeWindow<InterfaceSettings> for PrivacySettingsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let streamer_mode = self.privacy_settings.mapped(|settings| &settings.streamer_mode);
+
+        let elements = vec![StateButtonBuilder::new()
+            .with_text("Streamer mode")
+            .with_remote(streamer_mode.new_remote())
+            .with_event(streamer_mode.toggle_action())
+            .build()
+            .with_tooltip("Hide the names of other players, whisper contents and the account name. Toggled with /privacy")
+            .wrap()];
+
+        WindowBuilder::new()
+            .with_title("Privacy Settings".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -23,6 +23,7 @@ fn general_expandable(settings: &PlainTrackedState<RenderSettings>) -> ElementCe
         render_state_button("show fps", settings.mapped(|settings| &settings.show_frames_per_second)),
         render_state_button("show wireframe", settings.mapped(|settings| &settings.show_wireframe)),
         render_state_button("frustum culling", settings.mapped(|settings| &settings.frustum_culling)),
+        render_state_button("occlusion culling", settings.mapped(|settings| &settings.occlusion_culling)),
         render_state_button("show bounding boxes", settings.mapped(|settings| &settings.show_bounding_boxes)),
     ];
 
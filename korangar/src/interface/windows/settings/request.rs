@@ -0,0 +1,61 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, StateButtonBuilder};
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateBinary};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::requests::RequestSettings;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct RequestSettingsWindow {
+    request_settings: PlainTrackedState<RequestSettings>,
+}
+
+impl RequestSettingsWindow {
+    pub const WINDOW_CLASS: &'static str = "request_settings";
+}
+
+impl PrototypeWindow<InterfaceSettings> for RequestSettingsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let options: [(&str, fn(&RequestSettings) -> &bool); 4] = [
+            ("Reject party invites", |settings| &settings.reject_party_invites),
+            ("Reject guild invites", |settings| &settings.reject_guild_invites),
+            ("Reject trade requests", |settings| &settings.reject_trade_requests),
+            ("Reject friend requests", |settings| &settings.reject_friend_requests),
+        ];
+
+        let elements = options
+            .into_iter()
+            .map(|(text, mapping)| {
+                let state = self.request_settings.mapped(mapping);
+
+                StateButtonBuilder::new()
+                    .with_text(text)
+                    .with_remote(state.new_remote())
+                    .with_event(state.toggle_action())
+                    .build()
+                    .wrap()
+            })
+            .collect();
+
+        WindowBuilder::new()
+            .with_title("Request Settings".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
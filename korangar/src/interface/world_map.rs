@@ -0,0 +1,173 @@
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use serde::{Deserialize, Serialize};
+
+/// Towns of Rune-Midgard with their approximate position on the world map
+/// image and the towns that can be reached from them by walking, used if
+/// there is no world map in the client folder.
+const DEFAULT_AREAS: &[(&str, &str, f32, f32, &[&str])] = &[
+    ("prontera", "Prontera", 0.47, 0.44, &[
+        "izlude",
+        "geffen",
+        "payon",
+        "morocc",
+        "aldebaran",
+    ]),
+    ("izlude", "Izlude", 0.56, 0.46, &["prontera"]),
+    ("geffen", "Geffen", 0.3, 0.43, &["prontera", "aldebaran", "morocc"]),
+    ("payon", "Payon", 0.7, 0.58, &["prontera", "alberta", "morocc"]),
+    ("alberta", "Alberta", 0.8, 0.72, &["payon"]),
+    ("morocc", "Morroc", 0.37, 0.7, &["prontera", "geffen", "payon", "comodo"]),
+    ("comodo", "Comodo", 0.28, 0.9, &["morocc", "umbala"]),
+    ("umbala", "Umbala", 0.14, 0.7, &["comodo"]),
+    ("aldebaran", "Al De Baran", 0.45, 0.2, &["prontera", "geffen", "yuno"]),
+    ("yuno", "Juno", 0.55, 0.08, &["aldebaran"]),
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorldMapArea {
+    pub map_name: String,
+    pub display_name: String,
+    /// Position on the world map image, from 0 to 1.
+    pub left: f32,
+    pub top: f32,
+    /// Maps that are directly connected to this one.
+    pub connections: Vec<String>,
+}
+
+/// Position of every map on the world map and the connections between them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorldMap {
+    /// Path of the world map image in the texture folder.
+    pub image: String,
+    pub areas: Vec<WorldMapArea>,
+}
+
+impl Default for WorldMap {
+    fn default() -> Self {
+        let areas = DEFAULT_AREAS
+            .iter()
+            .map(|(map_name, display_name, left, top, connections)| WorldMapArea {
+                map_name: map_name.to_string(),
+                display_name: display_name.to_string(),
+                left: *left,
+                top: *top,
+                connections: connections.iter().map(|map_name| map_name.to_string()).collect(),
+            })
+            .collect();
+
+        Self {
+            image: "À¯ÀúÀÎÅÍÆäÀÌ½º\\worldmap.bmp".to_owned(),
+            areas,
+        }
+    }
+}
+
+impl WorldMap {
+    const FILE_NAME: &'static str = "client/world_map.ron";
+
+    pub fn load() -> Self {
+        #[cfg(feature = "debug")]
+        print_debug!("loading world map from {}", Self::FILE_NAME.magenta());
+
+        std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())
+            .unwrap_or_else(|| {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] failed to load world map from {}; using default",
+                    "warning".yellow(),
+                    Self::FILE_NAME.magenta(),
+                );
+
+                WorldMap::default()
+            })
+    }
+
+    /// All areas whose map name or display name contains the query, ignoring
+    /// case. An empty query matches nothing.
+    pub fn search(&self, query: &str) -> Vec<&WorldMapArea> {
+        let query = query.trim().to_lowercase();
+
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        self.areas
+            .iter()
+            .filter(|area| area.map_name.to_lowercase().contains(&query) || area.display_name.to_lowercase().contains(&query))
+            .collect()
+    }
+
+    /// Every connection between two known maps, listed once.
+    pub fn connections(&self) -> Vec<(&WorldMapArea, &WorldMapArea)> {
+        let mut connections = Vec::new();
+
+        for (index, area) in self.areas.iter().enumerate() {
+            for other in &self.areas[index + 1..] {
+                let connected = area.connections.contains(&other.map_name) || other.connections.contains(&area.map_name);
+
+                if connected {
+                    connections.push((area, other));
+                }
+            }
+        }
+
+        connections
+    }
+
+    /// Closest area to a position on the world map, if it is within the given
+    /// distance.
+    pub fn area_at(&self, left: f32, top: f32, maximum_distance: f32) -> Option<&WorldMapArea> {
+        let distance = |area: &WorldMapArea| f32::hypot(area.left - left, area.top - top);
+
+        self.areas
+            .iter()
+            .filter(|area| distance(area) <= maximum_distance)
+            .min_by(|first, second| distance(first).total_cmp(&distance(second)))
+    }
+}
+
+#[cfg(test)]
+mod search {
+    use super::WorldMap;
+
+    fn names<'a>(areas: impl IntoIterator<Item = &'a super::WorldMapArea>) -> Vec<&'a str> {
+        areas.into_iter().map(|area| area.map_name.as_str()).collect()
+    }
+
+    #[test]
+    fn search_names() {
+        let world_map = WorldMap::default();
+
+        assert_eq!(names(world_map.search("PRONT")), ["prontera"]);
+        assert_eq!(names(world_map.search("juno")), ["yuno"]);
+        assert!(world_map.search("  ").is_empty());
+    }
+
+    #[test]
+    fn connections_listed_once() {
+        let world_map = WorldMap::default();
+        let connections = world_map.connections();
+
+        let prontera_izlude = connections
+            .iter()
+            .filter(|(first, second)| names([*first, *second]) == ["prontera", "izlude"])
+            .count();
+
+        assert_eq!(prontera_izlude, 1);
+        assert!(connections.iter().all(|(first, second)| first.map_name != second.map_name));
+    }
+
+    #[test]
+    fn area_at_position() {
+        let world_map = WorldMap::default();
+
+        assert_eq!(
+            world_map.area_at(0.48, 0.45, 0.05).map(|area| area.map_name.as_str()),
+            Some("prontera")
+        );
+        assert!(world_map.area_at(0.95, 0.05, 0.05).is_none());
+    }
+}
@@ -0,0 +1,88 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_networking::{AchievementProgress, AchievementSummary};
+
+use crate::loaders::{AchievementMetadata, ScriptLoader};
+
+#[derive(Clone, Debug)]
+pub struct Achievement {
+    pub metadata: AchievementMetadata,
+    pub progress: AchievementProgress,
+}
+
+#[derive(Default)]
+pub struct Achievements {
+    achievements: PlainTrackedState<Vec<Achievement>>,
+    summary: PlainTrackedState<AchievementSummary>,
+    title_id: PlainTrackedState<Option<u32>>,
+}
+
+impl Achievements {
+    pub fn fill(&mut self, script_loader: &ScriptLoader, summary: AchievementSummary, achievements: Vec<AchievementProgress>) {
+        let achievements = achievements
+            .into_iter()
+            .map(|progress| Achievement {
+                metadata: script_loader.get_achievement_metadata(progress.achievement_id),
+                progress,
+            })
+            .collect();
+
+        self.summary.set(summary);
+        self.achievements.set(achievements);
+    }
+
+    /// Update the progress of a single achievement. Returns the name of the
+    /// achievement if it was just completed.
+    pub fn update(&mut self, script_loader: &ScriptLoader, summary: AchievementSummary, progress: AchievementProgress) -> Option<String> {
+        self.summary.set(summary);
+
+        self.achievements.mutate(|achievements| {
+            match achievements
+                .iter_mut()
+                .find(|achievement| achievement.progress.achievement_id == progress.achievement_id)
+            {
+                Some(achievement) => {
+                    let newly_completed = progress.completed && !achievement.progress.completed;
+                    achievement.progress = progress;
+                    newly_completed.then(|| achievement.metadata.name.clone())
+                }
+                None => {
+                    let achievement = Achievement {
+                        metadata: script_loader.get_achievement_metadata(progress.achievement_id),
+                        progress,
+                    };
+                    let completed = achievement.progress.completed.then(|| achievement.metadata.name.clone());
+
+                    achievements.push(achievement);
+                    completed
+                }
+            }
+        })
+    }
+
+    pub fn set_rewarded(&mut self, achievement_id: u32) {
+        self.achievements.mutate(|achievements| {
+            if let Some(achievement) = achievements
+                .iter_mut()
+                .find(|achievement| achievement.progress.achievement_id == achievement_id)
+            {
+                achievement.progress.rewarded = true;
+            }
+        });
+    }
+
+    pub fn set_title(&mut self, title_id: u32) {
+        self.title_id.set(Some(title_id));
+    }
+
+    pub fn get_achievements(&self) -> PlainRemote<Vec<Achievement>> {
+        self.achievements.new_remote()
+    }
+
+    pub fn get_summary(&self) -> PlainRemote<AchievementSummary> {
+        self.summary.new_remote()
+    }
+
+    pub fn get_title(&self) -> PlainRemote<Option<u32>> {
+        self.title_id.new_remote()
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{Local, NaiveDate};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+
+/// Number of days in one attendance period.
+pub const ATTENDANCE_DAYS: u32 = 20;
+
+#[derive(Default)]
+pub struct Attendance {
+    claimed_days: PlainTrackedState<u32>,
+    claimed_today: PlainTrackedState<bool>,
+    /// Local date of the last claimed reward.
+    claimed_on: Option<NaiveDate>,
+}
+
+impl Attendance {
+    /// Called with the number of claimed days whenever the server sends them,
+    /// which is also when a reward that was claimed on an earlier day becomes
+    /// claimable again.
+    pub fn set_claimed_days(&mut self, claimed_days: u32) {
+        self.claimed_days.set(claimed_days);
+        self.update();
+    }
+
+    pub fn set_claimed(&mut self, claimed_days: u32) {
+        self.claimed_days.set(claimed_days);
+        self.claimed_today.set(true);
+        self.claimed_on = Some(Local::now().date_naive());
+    }
+
+    /// Make the reward claimable again once the day changed, so the window
+    /// doesn't have to be reopened after midnight.
+    pub fn update(&mut self) {
+        self.update_for_date(Local::now().date_naive());
+    }
+
+    fn update_for_date(&mut self, today: NaiveDate) {
+        if *self.claimed_today.get() && self.claimed_on != Some(today) {
+            self.claimed_today.set(false);
+        }
+    }
+
+    pub fn get_claimed_days(&self) -> PlainRemote<u32> {
+        self.claimed_days.new_remote()
+    }
+
+    pub fn get_claimed_today(&self) -> PlainRemote<bool> {
+        self.claimed_today.new_remote()
+    }
+}
+
+#[cfg(test)]
+mod rollover {
+    use chrono::Days;
+    use korangar_interface::state::TrackedState;
+
+    use super::Attendance;
+
+    #[test]
+    fn claimable_on_the_next_day() {
+        let mut attendance = Attendance::default();
+        attendance.set_claimed(3);
+
+        let today = attendance.claimed_on.unwrap();
+        attendance.update_for_date(today);
+        assert!(*attendance.claimed_today.get());
+
+        attendance.update_for_date(today.checked_add_days(Days::new(1)).unwrap());
+        assert!(!*attendance.claimed_today.get());
+        assert_eq!(*attendance.claimed_days.get(), 3);
+    }
+}
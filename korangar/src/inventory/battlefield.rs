@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use korangar_interface::application::{Application, FontSizeTrait, FontSizeTraitExt};
+use ragnarok_packets::{EntityId, MapTypeFlags};
+
+use crate::graphics::{Camera, DeferredRenderer, Renderer};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::privacy::display_name;
+use crate::interface::theme::GameTheme;
+use crate::world::Entity;
+
+/// Job id of the emperium that has to be destroyed to conquer a castle.
+const EMPERIUM_JOB_ID: usize = 1288;
+/// Battlefield scripts revive dead players in waves, so this is the longest
+/// time a player has to wait.
+const RESPAWN_TIME: Duration = Duration::from_secs(25);
+
+/// State of the War of Emperium or battleground map the player is on.
+#[derive(Default)]
+pub struct Battlefield {
+    flags: MapTypeFlags,
+    scores: Option<(u16, u16)>,
+    teams: HashMap<EntityId, (String, u16)>,
+    death: Option<Instant>,
+}
+
+impl Battlefield {
+    pub fn set_map_flags(&mut self, flags: MapTypeFlags) {
+        self.flags = flags;
+
+        if !self.is_active() {
+            self.clear();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.scores = None;
+        self.teams.clear();
+        self.death = None;
+    }
+
+    /// Whether the player is on a siege or battleground map.
+    pub fn is_active(&self) -> bool {
+        self.flags.intersects(MapTypeFlags::SIEGE | MapTypeFlags::BATTLEFIELD)
+    }
+
+    pub fn set_scores(&mut self, lion_score: u16, eagle_score: u16) {
+        self.scores = Some((lion_score, eagle_score));
+    }
+
+    pub fn set_team(&mut self, entity_id: EntityId, name: String, team_id: u16) {
+        self.teams.insert(entity_id, (name, team_id));
+    }
+
+    /// Start or stop the respawn timer depending on the health of the player.
+    pub fn set_player_health(&mut self, health_points: usize) {
+        match health_points {
+            0 if self.is_active() => {
+                self.death.get_or_insert_with(Instant::now);
+            }
+            _ => self.death = None,
+        }
+    }
+
+    fn get_lines(&self, entities: &[Entity]) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        if let Some((lion_score, eagle_score)) = self.scores {
+            lines.push(format!("Lion {lion_score} : {eagle_score} Eagle"));
+        }
+
+        if self.flags.contains(MapTypeFlags::SIEGE)
+            && let Some(emperium) = entities.iter().find(|entity| entity.get_job() == EMPERIUM_JOB_ID)
+        {
+            let (health_points, maximum_health_points) = emperium.get_health();
+            let percentage = health_points * 100 / maximum_health_points.max(1);
+
+            lines.push(format!("Emperium {percentage}%"));
+        }
+
+        if let Some(death) = self.death {
+            let remaining_time = RESPAWN_TIME.saturating_sub(death.elapsed());
+
+            lines.push(format!("Respawn in {}s", remaining_time.as_secs()));
+        }
+
+        lines
+    }
+
+    /// Render the scores, the emperium status and the respawn timer at the top
+    /// of the screen.
+    pub fn render_hud(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        application: &InterfaceSettings,
+        window_size: ScreenSize,
+        entities: &[Entity],
+    ) {
+        if !self.is_active() {
+            return;
+        }
+
+        let lines = self.get_lines(entities);
+
+        if lines.is_empty() {
+            return;
+        }
+
+        let scaling = application.get_scaling_factor();
+        let font_size = theme.notification.font_size.get().scaled(application.get_scaling());
+        let padding = theme.notification.padding.get() * scaling;
+        let line_height = font_size.get_value() + padding;
+
+        let text_width = lines
+            .iter()
+            .map(|line| renderer.get_text_dimensions(line, font_size).width)
+            .fold(0.0, f32::max);
+        let size = ScreenSize {
+            width: text_width + padding * 2.0,
+            height: line_height * lines.len() as f32 + padding,
+        };
+        let position = ScreenPosition {
+            left: (window_size.width - size.width) / 2.0,
+            top: padding,
+        };
+
+        renderer.render_rectangle(render_target, position, size, theme.notification.background_color.get());
+
+        for (index, line) in lines.iter().enumerate() {
+            renderer.render_text(
+                render_target,
+                line,
+                position
+                    + ScreenSize {
+                        width: padding,
+                        height: padding + line_height * index as f32,
+                    },
+                theme.notification.foreground_color.get(),
+                font_size,
+            );
+        }
+    }
+
+    /// Render the names of all players with a known team, colored by whether
+    /// they are in the same team as the player.
+    pub fn render_name_plates(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+        entities: &[Entity],
+        streamer_mode: bool,
+    ) {
+        if !self.is_active() || entities.is_empty() {
+            return;
+        }
+
+        let player_team_id = self.teams.get(&entities[0].get_entity_id()).map(|(_, team_id)| *team_id);
+
+        for entity in entities {
+            let Some((name, team_id)) = self.teams.get(&entity.get_entity_id()) else {
+                continue;
+            };
+
+            let color = match player_team_id == Some(*team_id) {
+                true => theme.battlefield.ally_color.get(),
+                false => theme.battlefield.enemy_color.get(),
+            };
+
+            // The player can always see their own name.
+            let name = match entity.get_entity_id() == entities[0].get_entity_id() {
+                true => name.into(),
+                false => display_name(name, streamer_mode),
+            };
+
+            entity.render_name_plate(render_target, renderer, camera, &name, color, theme, window_size);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use korangar_networking::GuildNotice;
+
+use crate::input::UserEvent;
+use crate::interface::notifications::{NotificationKind, Notifications};
+
+#[derive(Default)]
+pub struct Guild {
+    notice: Option<GuildNotice>,
+}
+
+impl Guild {
+    pub fn set_notice(&mut self, notice: GuildNotice, notifications: &mut Notifications) {
+        self.notice = Some(notice);
+        self.show_notice(notifications);
+    }
+
+    /// Show the current notice again, for example after changing the map.
+    pub fn show_notice(&self, notifications: &mut Notifications) {
+        if let Some(notice) = &self.notice {
+            notifications.push_with_action(
+                NotificationKind::Social,
+                format!("{}: {}", notice.subject, notice.notice),
+                UserEvent::OpenGuildWindow,
+            );
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.notice = None;
+    }
+
+    pub fn get_notice(&self) -> Option<&GuildNotice> {
+        self.notice.as_ref()
+    }
+}
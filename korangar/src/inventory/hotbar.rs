@@ -4,15 +4,61 @@ use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, Tr
 use korangar_networking::NetworkingSystem;
 use ragnarok_packets::handler::PacketCallback;
 use ragnarok_packets::{HotbarSlot, HotbarTab, HotkeyData};
+use serde::{Deserialize, Serialize};
 
 use super::Skill;
+use crate::system::SettingsFile;
+
+/// Number of slots in a single row of the hotbar. The map server stores the
+/// hotkeys in tabs of nine, which are bound to F1 to F9.
+pub const HOTBAR_ROW_SIZE: usize = 9;
+/// Number of rows in the hotbar. In battle mode, each row is bound to one row
+/// of the keyboard.
+pub const HOTBAR_ROW_COUNT: usize = 3;
+pub const HOTBAR_SLOT_COUNT: usize = HOTBAR_ROW_SIZE * HOTBAR_ROW_COUNT;
+
+/// Slot in a row and column of the hotbar. Rows line up with the tabs of the
+/// map server, so this is also the index of the hotkey on the server.
+pub fn hotbar_slot(row: usize, column: usize) -> HotbarSlot {
+    HotbarSlot((row * HOTBAR_ROW_SIZE + column) as u16)
+}
+
+/// Row and column of a slot in the hotbar.
+pub fn hotbar_slot_position(slot: HotbarSlot) -> (usize, usize) {
+    (slot.0 as usize / HOTBAR_ROW_SIZE, slot.0 as usize % HOTBAR_ROW_SIZE)
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct HotbarSettings {
+    /// Slots that cast their skill at the cursor as soon as the hotkey is
+    /// pressed, instead of waiting for a click on the target.
+    smart_cast_slots: Vec<u16>,
+}
 
-#[derive(Default)]
 pub struct Hotbar {
-    skills: PlainTrackedState<[Option<Skill>; 10]>,
+    skills: PlainTrackedState<[Option<Skill>; HOTBAR_SLOT_COUNT]>,
+    smart_cast: PlainTrackedState<[bool; HOTBAR_SLOT_COUNT]>,
 }
 
 impl Hotbar {
+    const SETTINGS_SECTION: &'static str = "hotbar";
+
+    pub fn new() -> Self {
+        let settings: HotbarSettings = SettingsFile::load_section(Self::SETTINGS_SECTION).unwrap_or_default();
+        let mut smart_cast = [false; HOTBAR_SLOT_COUNT];
+
+        for slot in settings.smart_cast_slots {
+            if let Some(enabled) = smart_cast.get_mut(slot as usize) {
+                *enabled = true;
+            }
+        }
+
+        Self {
+            skills: PlainTrackedState::default(),
+            smart_cast: PlainTrackedState::new(smart_cast),
+        }
+    }
+
     /// Set the slot without notifying the map server.
     pub fn set_slot(&mut self, slot: HotbarSlot, skill: Skill) {
         self.skills.mutate(|skills| {
@@ -100,7 +146,62 @@ impl Hotbar {
         Ref::map(self.skills.get(), |skills| &skills[slot.0 as usize])
     }
 
-    pub fn get_skills(&self) -> PlainRemote<[Option<Skill>; 10]> {
+    pub fn get_skills(&self) -> PlainRemote<[Option<Skill>; HOTBAR_SLOT_COUNT]> {
         self.skills.new_remote()
     }
+
+    pub fn is_smart_cast(&self, slot: HotbarSlot) -> bool {
+        self.smart_cast.get()[slot.0 as usize]
+    }
+
+    /// Toggle smart cast for the slot and save the setting.
+    pub fn toggle_smart_cast(&mut self, slot: HotbarSlot) {
+        self.smart_cast.mutate(|smart_cast| {
+            smart_cast[slot.0 as usize] ^= true;
+        });
+
+        let settings = HotbarSettings {
+            smart_cast_slots: (0..HOTBAR_SLOT_COUNT as u16)
+                .filter(|slot| self.smart_cast.get()[*slot as usize])
+                .collect(),
+        };
+
+        SettingsFile::save_section(Self::SETTINGS_SECTION, &settings);
+    }
+
+    pub fn get_smart_cast(&self) -> PlainRemote<[bool; HOTBAR_SLOT_COUNT]> {
+        self.smart_cast.new_remote()
+    }
+}
+
+#[cfg(test)]
+mod server_index {
+    use ragnarok_packets::HotbarSlot;
+
+    use super::{hotbar_slot, hotbar_slot_position, HOTBAR_ROW_COUNT, HOTBAR_ROW_SIZE, HOTBAR_SLOT_COUNT};
+
+    /// Number of hotkeys that the map server sends.
+    const SERVER_HOTKEY_COUNT: usize = 38;
+
+    #[test]
+    fn rows_match_server_tabs() {
+        assert_eq!(hotbar_slot(0, 0), HotbarSlot(0));
+        assert_eq!(hotbar_slot(0, 8), HotbarSlot(8));
+        assert_eq!(hotbar_slot(1, 0), HotbarSlot(9));
+        assert_eq!(hotbar_slot(2, 8), HotbarSlot(26));
+    }
+
+    #[test]
+    fn round_trip() {
+        for row in 0..HOTBAR_ROW_COUNT {
+            for column in 0..HOTBAR_ROW_SIZE {
+                assert_eq!(hotbar_slot_position(hotbar_slot(row, column)), (row, column));
+            }
+        }
+    }
+
+    #[test]
+    fn fits_server_hotkeys() {
+        assert!(HOTBAR_SLOT_COUNT <= SERVER_HOTKEY_COUNT);
+    }
 }
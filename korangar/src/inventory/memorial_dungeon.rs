@@ -0,0 +1,139 @@
+use std::time::{Duration, Instant};
+
+use korangar_interface::application::{Application, FontSizeTrait, FontSizeTraitExt};
+use korangar_networking::InstanceInformation;
+
+use crate::graphics::{DeferredRenderer, Renderer};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::theme::GameTheme;
+use crate::system::format_remaining_time;
+
+enum MemorialDungeonState {
+    Queued {
+        name: String,
+        queue_position: u16,
+    },
+    Active {
+        name: String,
+        destruction: Instant,
+        entry_deadline: Option<Instant>,
+    },
+}
+
+/// Instance (memorial dungeon) of the party.
+#[derive(Default)]
+pub struct MemorialDungeon {
+    state: Option<MemorialDungeonState>,
+}
+
+impl MemorialDungeon {
+    pub fn set_queued(&mut self, name: String, queue_position: u16) {
+        self.state = Some(MemorialDungeonState::Queued { name, queue_position });
+    }
+
+    pub fn set_queue_position(&mut self, new_queue_position: u16) {
+        if let Some(MemorialDungeonState::Queued { queue_position, .. }) = &mut self.state {
+            *queue_position = new_queue_position;
+        }
+    }
+
+    pub fn set_information(&mut self, information: InstanceInformation) {
+        let now = Instant::now();
+
+        self.state = Some(MemorialDungeonState::Active {
+            name: information.name,
+            destruction: now + information.remaining_time,
+            entry_deadline: information.entry_time_limit.map(|entry_time_limit| now + entry_time_limit),
+        });
+    }
+
+    pub fn set_time_limit(&mut self, remaining_time: Duration) {
+        if let Some(MemorialDungeonState::Active { destruction, .. }) = &mut self.state {
+            *destruction = Instant::now() + remaining_time;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.state = None;
+    }
+
+    fn get_lines(&self) -> Option<Vec<String>> {
+        let lines = match self.state.as_ref()? {
+            MemorialDungeonState::Queued { name, queue_position } => vec![name.clone(), format!("Queue position {queue_position}")],
+            MemorialDungeonState::Active {
+                name,
+                destruction,
+                entry_deadline,
+            } => {
+                let now = Instant::now();
+                let mut lines = vec![
+                    name.clone(),
+                    format!(
+                        "Time left {}",
+                        format_remaining_time(destruction.saturating_duration_since(now))
+                    ),
+                ];
+
+                // Once the deadline has passed, nobody can enter the instance anymore.
+                if let Some(entry_deadline) = entry_deadline.filter(|entry_deadline| *entry_deadline > now) {
+                    lines.push(format!("Entry closes in {}", format_remaining_time(entry_deadline - now)));
+                }
+
+                lines
+            }
+        };
+
+        Some(lines)
+    }
+
+    /// Render the status of the instance in the upper right corner of the
+    /// screen.
+    pub fn render_banner(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        application: &InterfaceSettings,
+        window_size: ScreenSize,
+    ) {
+        let Some(lines) = self.get_lines() else {
+            return;
+        };
+
+        let scaling = application.get_scaling_factor();
+        let font_size = theme.notification.font_size.get().scaled(application.get_scaling());
+        let padding = theme.notification.padding.get() * scaling;
+        let offset = theme.notification.offset.get() * scaling;
+        let line_height = font_size.get_value() + padding;
+
+        let text_width = lines
+            .iter()
+            .map(|line| renderer.get_text_dimensions(line, font_size).width)
+            .fold(0.0, f32::max);
+        let size = ScreenSize {
+            width: text_width + padding * 2.0,
+            height: line_height * lines.len() as f32 + padding,
+        };
+        let position = ScreenPosition {
+            left: window_size.width - size.width - offset,
+            top: offset,
+        };
+
+        renderer.render_rectangle(render_target, position, size, theme.notification.background_color.get());
+
+        for (index, line) in lines.iter().enumerate() {
+            renderer.render_text(
+                render_target,
+                line,
+                position
+                    + ScreenSize {
+                        width: padding,
+                        height: padding + line_height * index as f32,
+                    },
+                theme.notification.foreground_color.get(),
+                font_size,
+            );
+        }
+    }
+}
@@ -1,19 +1,46 @@
+mod achievements;
+mod attendance;
+mod battlefield;
+mod guild;
 mod hotbar;
+mod memorial_dungeon;
+mod pet;
+mod quests;
+mod refine;
 mod skills;
+mod status_effects;
 
 use std::cell::Ref;
+use std::collections::HashSet;
+use std::time::Duration;
 
 use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt, ValueState};
 use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata};
-use ragnarok_packets::{EquipPosition, InventoryIndex};
-
-pub use self::hotbar::Hotbar;
+use ragnarok_packets::{EquipPosition, InventoryIndex, ItemId};
+
+pub use self::achievements::{Achievement, Achievements};
+pub use self::attendance::{Attendance, ATTENDANCE_DAYS};
+pub use self::battlefield::Battlefield;
+pub use self::guild::Guild;
+pub use self::hotbar::{hotbar_slot, hotbar_slot_position, Hotbar, HOTBAR_ROW_COUNT, HOTBAR_ROW_SIZE, HOTBAR_SLOT_COUNT};
+pub use self::memorial_dungeon::MemorialDungeon;
+pub use self::pet::{hunger_description, intimacy_description, Pet, PET_AWKWARD_THRESHOLD, PET_HUNGRY_EMOTION, PET_HUNGRY_THRESHOLD};
+pub use self::quests::{Quest, QuestLog};
+pub use self::refine::{RefineItem, RefineOutcome, Refinement};
 pub use self::skills::{Skill, SkillTree};
+pub use self::status_effects::{PlayerStatusEffects, StatusEffectEntry};
 use crate::loaders::{GameFileLoader, ResourceMetadata, ScriptLoader, TextureLoader};
+use crate::system::format_remaining_time;
+
+/// Rental items that expire in less than this time show a notification.
+const RENTAL_WARNING_TIME: Duration = Duration::from_secs(5 * 60);
 
 #[derive(Default)]
 pub struct Inventory {
     items: PlainTrackedState<Vec<InventoryItem<ResourceMetadata>>>,
+    zeny: PlainTrackedState<u32>,
+    /// Rental items that a notification was already shown for.
+    expiring_rentals: HashSet<InventoryIndex>,
 }
 
 impl Inventory {
@@ -41,15 +68,14 @@ impl Inventory {
     ) {
         self.items.with_mut(|items| {
             if let Some(found_item) = items.iter_mut().find(|inventory_item| inventory_item.index == item.index) {
-                let InventoryItemDetails::Regular { amount, .. } = &mut found_item.details else {
-                    panic!();
-                };
-
-                let InventoryItemDetails::Regular { amount: added_amount, .. } = item.details else {
-                    panic!();
-                };
-
-                *amount += added_amount;
+                match (&mut found_item.details, &item.details) {
+                    (InventoryItemDetails::Regular { amount, .. }, InventoryItemDetails::Regular { amount: added_amount, .. }) => {
+                        *amount += added_amount;
+                    }
+                    // Enchanting or otherwise modifying an equipment sends the entire item again
+                    // with the same index, so we replace it.
+                    _ => *found_item = script_loader.load_inventory_item_metadata(game_file_loader, texture_loader, item),
+                }
             } else {
                 let item = script_loader.load_inventory_item_metadata(game_file_loader, texture_loader, item);
 
@@ -88,6 +114,42 @@ impl Inventory {
         });
     }
 
+    /// Update the remaining time of all rental items with the given item id.
+    pub fn set_rental_time(&mut self, item_id: ItemId, remaining_time: Duration) {
+        self.items.mutate(|items| {
+            items
+                .iter_mut()
+                .filter(|item| item.item_id == item_id)
+                .for_each(|item| item.set_remaining_rental_time(remaining_time));
+        });
+    }
+
+    /// Unequip an expired rental item. The item itself is removed by the
+    /// server afterwards. Returns the name of the item if it is in the
+    /// inventory.
+    pub fn expire_rental_item(&mut self, index: InventoryIndex) -> Option<String> {
+        self.items.mutate(|items| {
+            let item = items.iter_mut().find(|item| item.index == index)?;
+
+            if let InventoryItemDetails::Equippable { equipped_position, .. } = &mut item.details {
+                *equipped_position = EquipPosition::NONE;
+            }
+
+            Some(item.metadata.name.clone())
+        })
+    }
+
+    pub fn update_refinement_level(&mut self, index: InventoryIndex, new_refinement_level: u8) {
+        self.items.mutate(|items| {
+            let item = items.iter_mut().find(|item| item.index == index).unwrap();
+            let InventoryItemDetails::Equippable { refinement_level, .. } = &mut item.details else {
+                panic!();
+            };
+
+            *refinement_level = new_refinement_level;
+        });
+    }
+
     pub fn get_items(&self) -> Ref<'_, Vec<InventoryItem<ResourceMetadata>>> {
         self.items.get()
     }
@@ -95,4 +157,39 @@ impl Inventory {
     pub fn item_remote(&self) -> PlainRemote<Vec<InventoryItem<ResourceMetadata>>> {
         self.items.new_remote()
     }
+
+    pub fn set_zeny(&mut self, zeny: u32) {
+        self.zeny.set(zeny);
+    }
+
+    pub fn zeny_remote(&self) -> PlainRemote<u32> {
+        self.zeny.new_remote()
+    }
+
+    /// Warnings for rental items that are about to expire. Every item is only
+    /// reported once.
+    pub fn take_rental_warnings(&mut self) -> Vec<String> {
+        let expiring: Vec<(InventoryIndex, String)> = self
+            .items
+            .get()
+            .iter()
+            .filter_map(|item| {
+                let remaining_time = item.remaining_rental_time()?;
+
+                (remaining_time < RENTAL_WARNING_TIME).then(|| {
+                    let text = format!("{} expires in {}", item.metadata.name, format_remaining_time(remaining_time));
+                    (item.index, text)
+                })
+            })
+            .collect();
+
+        // Forget items that are gone, since their index might be reused.
+        self.expiring_rentals
+            .retain(|index| expiring.iter().any(|(expiring_index, _)| expiring_index == index));
+
+        expiring
+            .into_iter()
+            .filter_map(|(index, text)| self.expiring_rentals.insert(index).then_some(text))
+            .collect()
+    }
 }
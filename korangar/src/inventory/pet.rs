@@ -0,0 +1,84 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_networking::{PetInformation, PetStatus};
+use ragnarok_packets::EntityId;
+
+/// Hunger at or below which the pet starts losing intimacy.
+pub const PET_HUNGRY_THRESHOLD: u16 = 25;
+/// Hunger at or below which the player is warned about their pet.
+const PET_VERY_HUNGRY_THRESHOLD: u16 = 10;
+/// Intimacy at or below which the pet is at risk of running away.
+pub const PET_AWKWARD_THRESHOLD: u16 = 100;
+/// Emotion displayed above the pet when it becomes very hungry.
+pub const PET_HUNGRY_EMOTION: u8 = 37;
+
+pub fn hunger_description(hunger: u16) -> &'static str {
+    match hunger {
+        0..=10 => "Very hungry",
+        11..=25 => "Hungry",
+        26..=75 => "Neutral",
+        76..=90 => "Satisfied",
+        _ => "Stuffed",
+    }
+}
+
+pub fn intimacy_description(intimacy: u16) -> &'static str {
+    match intimacy {
+        0..=100 => "Awkward",
+        101..=250 => "Shy",
+        251..=750 => "Neutral",
+        751..=910 => "Cordial",
+        _ => "Loyal",
+    }
+}
+
+#[derive(Default)]
+pub struct Pet {
+    pet_id: Option<EntityId>,
+    information: PlainTrackedState<Option<PetInformation>>,
+}
+
+impl Pet {
+    pub fn set_information(&mut self, information: PetInformation) {
+        self.information.set(Some(information));
+    }
+
+    pub fn set_pet_id(&mut self, pet_id: EntityId) {
+        self.pet_id = Some(pet_id);
+    }
+
+    pub fn get_pet_id(&self) -> Option<EntityId> {
+        self.pet_id
+    }
+
+    pub fn clear(&mut self) {
+        self.pet_id = None;
+        self.information.set(None);
+    }
+
+    /// Update a single value of the pet. Returns `true` if the pet just
+    /// became very hungry.
+    pub fn update_status(&mut self, status: PetStatus) -> bool {
+        self.information.mutate(|information| {
+            let Some(information) = information else {
+                return false;
+            };
+
+            match status {
+                PetStatus::Intimacy(intimacy) => information.intimacy = intimacy,
+                PetStatus::Hunger(hunger) => {
+                    let was_very_hungry = information.hunger <= PET_VERY_HUNGRY_THRESHOLD;
+                    information.hunger = hunger;
+
+                    return !was_very_hungry && hunger <= PET_VERY_HUNGRY_THRESHOLD;
+                }
+                PetStatus::Accessory(accessory) => information.accessory = accessory,
+            }
+
+            false
+        })
+    }
+
+    pub fn get_information(&self) -> PlainRemote<Option<PetInformation>> {
+        self.information.new_remote()
+    }
+}
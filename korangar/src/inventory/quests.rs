@@ -0,0 +1,78 @@
+use std::cell::Ref;
+
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_networking::QuestEntry;
+use ragnarok_packets::HuntingObjective;
+
+use crate::loaders::ScriptLoader;
+
+#[derive(Clone, Debug)]
+pub struct Quest {
+    pub name: String,
+    pub entry: QuestEntry,
+}
+
+impl Quest {
+    fn new(script_loader: &ScriptLoader, entry: QuestEntry) -> Self {
+        Self {
+            name: script_loader.get_quest_name(entry.quest_id),
+            entry,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct QuestLog {
+    quests: PlainTrackedState<Vec<Quest>>,
+}
+
+impl QuestLog {
+    pub fn fill(&mut self, script_loader: &ScriptLoader, quests: Vec<QuestEntry>) {
+        let quests = quests.into_iter().map(|entry| Quest::new(script_loader, entry)).collect();
+
+        self.quests.set(quests);
+    }
+
+    /// Add a quest to the log. If the quest is already in the log, it is
+    /// replaced.
+    pub fn add_quest(&mut self, script_loader: &ScriptLoader, entry: QuestEntry) {
+        let quest = Quest::new(script_loader, entry);
+
+        self.quests.mutate(
+            |quests| match quests.iter_mut().find(|existing| existing.entry.quest_id == quest.entry.quest_id) {
+                Some(existing) => *existing = quest,
+                None => quests.push(quest),
+            },
+        );
+    }
+
+    pub fn remove_quest(&mut self, quest_id: u32) {
+        self.quests.mutate(|quests| {
+            quests.retain(|quest| quest.entry.quest_id != quest_id);
+        });
+    }
+
+    pub fn update_objectives(&mut self, objectives: Vec<HuntingObjective>) {
+        self.quests.mutate(|quests| {
+            for hunting_objective in objectives {
+                let objective = quests
+                    .iter_mut()
+                    .flat_map(|quest| quest.entry.objectives.iter_mut())
+                    .find(|objective| objective.hunt_identification == hunting_objective.hunt_identification);
+
+                if let Some(objective) = objective {
+                    objective.current_count = hunting_objective.current_count;
+                    objective.total_count = hunting_objective.total_count;
+                }
+            }
+        });
+    }
+
+    pub fn get_quests(&self) -> PlainRemote<Vec<Quest>> {
+        self.quests.new_remote()
+    }
+
+    pub fn get_quest_list(&self) -> Ref<'_, Vec<Quest>> {
+        self.quests.get()
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::Instant;
+
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+use ragnarok_packets::{InventoryIndex, ItemId, RefineMaterialInformation, RefineResult};
+
+use crate::loaders::ScriptLoader;
+
+#[derive(Clone, Debug)]
+pub struct RefineMaterial {
+    pub item_id: ItemId,
+    pub name: String,
+    pub chance: u8,
+    pub zeny: u32,
+}
+
+/// Item that is currently selected in the refine window.
+#[derive(Clone, Debug)]
+pub struct RefineItem {
+    pub index: InventoryIndex,
+    pub blacksmith_blessing: u8,
+    pub materials: Vec<RefineMaterial>,
+}
+
+/// Result of the last refinement.
+#[derive(Clone, Debug)]
+pub struct RefineOutcome {
+    pub result: RefineResult,
+    pub message: String,
+    pub time: Instant,
+}
+
+/// State of the refine window.
+///
+/// Enchant NPCs are regular NPC dialogs. The server sends the enchanted item
+/// again with the same index, which replaces it in the inventory, so there is
+/// no state for them here. The enchant UI of newer clients is not supported.
+#[derive(Default)]
+pub struct Refinement {
+    item: PlainTrackedState<Option<RefineItem>>,
+    outcome: PlainTrackedState<Option<RefineOutcome>>,
+}
+
+impl Refinement {
+    pub fn set_item(
+        &mut self,
+        script_loader: &ScriptLoader,
+        index: InventoryIndex,
+        blacksmith_blessing: u8,
+        materials: Vec<RefineMaterialInformation>,
+    ) {
+        let materials = materials
+            .into_iter()
+            .map(|material| RefineMaterial {
+                item_id: material.item_id,
+                name: script_loader.get_item_name_from_id(material.item_id, true),
+                chance: material.chance,
+                zeny: material.zeny,
+            })
+            .collect();
+
+        self.item.set(Some(RefineItem {
+            index,
+            blacksmith_blessing,
+            materials,
+        }));
+    }
+
+    pub fn clear(&mut self) {
+        self.item.set(None);
+        self.outcome.set(None);
+    }
+
+    /// Display the result of a refinement and return a message that describes
+    /// it. If the item broke it is deselected.
+    pub fn set_result(&mut self, result: RefineResult, refinement_level: u8) -> String {
+        let message = match result {
+            RefineResult::Success => format!("Refinement succeeded (+{refinement_level})"),
+            RefineResult::Failure => "Refinement failed, the item was destroyed".to_owned(),
+            RefineResult::Downgrade => format!("Refinement failed, the item was downgraded to +{refinement_level}"),
+        };
+
+        if result == RefineResult::Failure {
+            self.item.set(None);
+        }
+
+        self.outcome.set(Some(RefineOutcome {
+            result,
+            message: message.clone(),
+            time: Instant::now(),
+        }));
+        message
+    }
+
+    pub fn get_item(&self) -> PlainRemote<Option<RefineItem>> {
+        self.item.new_remote()
+    }
+
+    pub fn get_outcome(&self) -> PlainRemote<Option<RefineOutcome>> {
+        self.outcome.new_remote()
+    }
+}
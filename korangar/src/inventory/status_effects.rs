@@ -0,0 +1,43 @@
+use std::time::{Duration, Instant};
+
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+
+#[derive(Clone)]
+pub struct StatusEffectEntry {
+    pub id: u16,
+    pub name: String,
+    /// Point in time at which the effect runs out. Effects without an end
+    /// last until the server removes them.
+    pub end: Option<Instant>,
+}
+
+/// Status effects of the player, with the details that are shown in the
+/// status effect window.
+#[derive(Default)]
+pub struct PlayerStatusEffects {
+    entries: PlainTrackedState<Vec<StatusEffectEntry>>,
+}
+
+impl PlayerStatusEffects {
+    pub fn add(&mut self, id: u16, name: String, duration: Option<Duration>) {
+        let end = duration.map(|duration| Instant::now() + duration);
+
+        self.entries
+            .mutate(|entries| match entries.iter_mut().find(|entry| entry.id == id) {
+                Some(entry) => entry.end = end,
+                None => entries.push(StatusEffectEntry { id, name, end }),
+            });
+    }
+
+    pub fn remove(&mut self, id: u16) {
+        self.entries.mutate(|entries| entries.retain(|entry| entry.id != id));
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.set(Vec::new());
+    }
+
+    pub fn get_entries(&self) -> PlainRemote<Vec<StatusEffectEntry>> {
+        self.entries.new_remote()
+    }
+}
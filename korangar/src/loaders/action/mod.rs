@@ -8,17 +8,20 @@ use derive_new::new;
 use korangar_debug::logging::{print_debug, Colorize, Timer};
 use korangar_interface::elements::PrototypeElement;
 use ragnarok_bytes::{ByteStream, FromBytes};
-use ragnarok_formats::action::{Action, ActionsData};
+use ragnarok_formats::action::{Action, ActionsData, Motion};
 use ragnarok_formats::version::InternalVersion;
 use ragnarok_packets::ClientTick;
 use vulkano::image::view::ImageView;
 
+use super::cache::evict_unused;
 use super::error::LoadError;
 use super::Sprite;
 use crate::graphics::{Color, Renderer, SpriteRenderer};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::loaders::{GameFileLoader, FALLBACK_ACTIONS_FILE};
+use crate::system::{measure_load, LoaderKind};
+use crate::world::{attachment_offset, AttachmentPoint};
 
 #[derive(Clone, Debug, new)]
 pub struct AnimationState {
@@ -31,6 +34,9 @@ pub struct AnimationState {
     pub duration: Option<u32>,
     #[new(default)]
     pub factor: Option<f32>,
+    /// Stop on the last frame instead of starting the action over.
+    #[new(default)]
+    pub hold_last_frame: bool,
 }
 
 impl AnimationState {
@@ -39,6 +45,27 @@ impl AnimationState {
         self.start_time = client_tick;
         self.duration = None;
         self.factor = None;
+        self.hold_last_frame = false;
+    }
+
+    /// Only players can sit, other entities use the same action index for
+    /// something else.
+    pub fn sit(&mut self, client_tick: ClientTick) {
+        self.action = 2;
+        self.start_time = client_tick;
+        self.duration = None;
+        self.factor = None;
+        self.hold_last_frame = false;
+    }
+
+    /// Only monsters have their dying action at this index. The action stops on
+    /// its last frame, so the monster stays on the ground.
+    pub fn die(&mut self, client_tick: ClientTick) {
+        self.action = 4;
+        self.start_time = client_tick;
+        self.duration = None;
+        self.factor = None;
+        self.hold_last_frame = true;
     }
 
     pub fn walk(&mut self, movement_speed: usize, client_tick: ClientTick) {
@@ -46,6 +73,7 @@ impl AnimationState {
         self.start_time = client_tick;
         self.duration = None;
         self.factor = Some(movement_speed as f32 * 100.0 / 150.0);
+        self.hold_last_frame = false;
     }
 
     pub fn update(&mut self, client_tick: ClientTick) {
@@ -78,14 +106,9 @@ pub struct Actions {
 }
 
 impl Actions {
-    pub fn render(
-        &self,
-        sprite: &Sprite,
-        animation_state: &AnimationState,
-        camera_direction: usize,
-        head_direction: usize,
-    ) -> (Arc<ImageView>, Vector2<f32>, bool) {
-        let direction = (camera_direction + head_direction) % 8;
+    /// Action for the direction and the time in milliseconds that each of its
+    /// frames is shown.
+    fn get_action(&self, animation_state: &AnimationState, direction: usize) -> (&Action, f32) {
         let aa = animation_state.action * 8 + direction;
         let a = &self.actions[aa % self.actions.len()];
         let delay = self.delays[aa % self.delays.len()];
@@ -95,6 +118,18 @@ impl Actions {
             .map(|factor| delay * (factor / 5.0))
             .unwrap_or_else(|| delay * 50.0);
 
+        (a, factor)
+    }
+
+    /// Time in milliseconds that it takes to play the action once.
+    pub fn get_duration(&self, animation_state: &AnimationState, direction: usize) -> u32 {
+        let (action, factor) = self.get_action(animation_state, direction % 8);
+        (action.motions.len() as f32 * factor) as u32
+    }
+
+    fn get_motion(&self, animation_state: &AnimationState, direction: usize) -> &Motion {
+        let (a, factor) = self.get_action(animation_state, direction);
+
         let frame = animation_state
             .duration
             .map(|duration| animation_state.time * a.motions.len() as u32 / duration)
@@ -102,7 +137,33 @@ impl Actions {
         // TODO: work out how to avoid losing digits when casting timg to an f32. When
         // fixed remove set_start_time in MouseCursor.
 
-        let fs = &a.motions[frame as usize % a.motions.len()];
+        let frame = match animation_state.hold_last_frame {
+            true => usize::min(frame as usize, a.motions.len() - 1),
+            false => frame as usize % a.motions.len(),
+        };
+
+        &a.motions[frame]
+    }
+
+    /// Get the position of the first attach point of the current frame.
+    /// Attach points are used to align sprites that are rendered on top of
+    /// each other, like the head and body of a player.
+    pub fn get_attach_point(&self, animation_state: &AnimationState, camera_direction: usize) -> Option<Vector2<f32>> {
+        self.get_motion(animation_state, camera_direction % 8)
+            .attach_points
+            .first()
+            .map(|attach_point| attach_point.position.map(|component| component as f32))
+    }
+
+    pub fn render(
+        &self,
+        sprite: &Sprite,
+        animation_state: &AnimationState,
+        camera_direction: usize,
+        head_direction: usize,
+    ) -> (Arc<ImageView>, Vector2<f32>, bool) {
+        let direction = (camera_direction + head_direction) % 8;
+        let fs = self.get_motion(animation_state, direction);
 
         let texture = sprite.textures[fs.sprite_clips[0].sprite_number as usize].clone();
         let texture_size = texture.image().extent().map(|component| component as f32);
@@ -115,6 +176,34 @@ impl Actions {
         )
     }
 
+    /// Offset of an attachment point in the current frame, in the same space
+    /// as the offset returned by [`Actions::render`]. `scale` is the scale that
+    /// the sprite is rendered with.
+    pub fn get_attachment_offset(
+        &self,
+        sprite: &Sprite,
+        animation_state: &AnimationState,
+        camera_direction: usize,
+        head_direction: usize,
+        scale: f32,
+        attachment_point: AttachmentPoint,
+    ) -> Vector2<f32> {
+        let direction = (camera_direction + head_direction) % 8;
+        let fs = self.get_motion(animation_state, direction);
+
+        // `get` instead of a direct index in case a fallback was loaded
+        let Some(texture) = sprite.textures.get(fs.sprite_clips[0].sprite_number as usize) else {
+            return Vector2::new(0.0, 0.0);
+        };
+
+        let texture_height = texture.image().extent()[1] as f32;
+        let offset = fs.sprite_clips[0].position.map(|component| component as f32);
+        let origin = Vector2::new(-offset.x, offset.y + texture_height / 2.0) / 10.0;
+
+        // The sprite is rendered two units high before scaling.
+        attachment_offset(attachment_point, origin, texture_height * scale / 5.0)
+    }
+
     pub fn render2<T>(
         &self,
         render_target: &mut T::Target,
@@ -128,24 +217,7 @@ impl Actions {
     ) where
         T: Renderer + SpriteRenderer,
     {
-        let direction = camera_direction % 8;
-        let aa = animation_state.action * 8 + direction;
-        let a = &self.actions[aa % self.actions.len()];
-        let delay = self.delays[aa % self.delays.len()];
-
-        let factor = animation_state
-            .factor
-            .map(|factor| delay * (factor / 5.0))
-            .unwrap_or_else(|| delay * 50.0);
-
-        let frame = animation_state
-            .duration
-            .map(|duration| animation_state.time * a.motions.len() as u32 / duration)
-            .unwrap_or_else(|| (animation_state.time as f32 / factor) as u32);
-        // TODO: work out how to avoid losing digits when casting timg to an f32. When
-        // fixed remove set_start_time in MouseCursor.
-
-        let fs = &a.motions[frame as usize % a.motions.len()];
+        let fs = self.get_motion(animation_state, camera_direction % 8);
 
         for sprite_clip in &fs.sprite_clips {
             // `get` instead of a direct index in case a fallback was loaded
@@ -203,6 +275,12 @@ pub struct ActionLoader {
 }
 
 impl ActionLoader {
+    /// Remove up to `limit` cached actions that aren't used anymore. Returns
+    /// `true` if there might be more to remove.
+    pub fn evict_unused(&mut self, limit: usize) -> bool {
+        evict_unused(&mut self.cache, limit)
+    }
+
     fn load(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<Actions>, LoadError> {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load actions from {}", path.magenta()));
@@ -248,7 +326,7 @@ impl ActionLoader {
     pub fn get(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<Actions>, LoadError> {
         match self.cache.get(path) {
             Some(sprite) => Ok(sprite.clone()),
-            None => self.load(path, game_file_loader),
+            None => measure_load(LoaderKind::Action, || self.load(path, game_file_loader)),
         }
     }
 }
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// Remove up to `limit` values from a cache that aren't used anywhere else.
+/// Returns `true` if there might be more unused values left, so large caches
+/// can be trimmed over multiple frames.
+pub fn evict_unused<Key, Value>(cache: &mut HashMap<Key, Arc<Value>>, limit: usize) -> bool
+where
+    Key: Clone + Eq + Hash,
+{
+    let unused: Vec<Key> = cache
+        .iter()
+        .filter(|(_, value)| Arc::strong_count(value) == 1)
+        .map(|(key, _)| key.clone())
+        .take(limit + 1)
+        .collect();
+
+    let more_left = unused.len() > limit;
+
+    for key in unused.into_iter().take(limit) {
+        cache.remove(&key);
+    }
+
+    more_left
+}
+
+#[cfg(test)]
+mod eviction {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use super::evict_unused;
+
+    #[test]
+    fn keep_used_values() {
+        let used = Arc::new(0);
+        let mut cache = HashMap::from([("used", used.clone()), ("unused", Arc::new(1))]);
+
+        assert!(!evict_unused(&mut cache, 10));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains_key("used"));
+    }
+
+    #[test]
+    fn limit() {
+        let mut cache: HashMap<_, _> = (0..5).map(|key| (key, Arc::new(key))).collect();
+
+        assert!(evict_unused(&mut cache, 2));
+        assert_eq!(cache.len(), 3);
+        assert!(evict_unused(&mut cache, 2));
+        assert!(!evict_unused(&mut cache, 2));
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+//! Remembered passwords are never written to the login settings. They are
+//! stored in the keyring of the operating system instead, or in an encrypted
+//! file if no keyring is available.
+
+use std::collections::HashMap;
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use keyring::Entry;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::loaders::ServiceId;
+
+const KEYRING_SERVICE: &str = "korangar";
+const NONCE_SIZE: usize = 12;
+const KEY_SIZE: usize = 32;
+
+/// The account that a password belongs to. Services remember a single
+/// account, while every connection profile can remember its own.
+#[derive(Clone, Copy)]
+pub enum CredentialKey<'a> {
+    Service(ServiceId),
+    Profile(&'a str),
+}
+
+fn keyring_entry(key: CredentialKey) -> keyring::Result<Entry> {
+    match key {
+        CredentialKey::Service(service_id) => Entry::new(KEYRING_SERVICE, &format!("service-{}", service_id.0)),
+        CredentialKey::Profile(name) => Entry::new(KEYRING_SERVICE, &format!("profile-{name}")),
+    }
+}
+
+pub fn load_password(key: CredentialKey) -> Option<String> {
+    match keyring_entry(key).and_then(|entry| entry.get_password()) {
+        Ok(password) => Some(password),
+        Err(_) => EncryptedPasswords::load().get(key),
+    }
+}
+
+pub fn store_password(key: CredentialKey, password: &str) {
+    match keyring_entry(key).and_then(|entry| entry.set_password(password)) {
+        Ok(()) => EncryptedPasswords::load().remove(key),
+        Err(_error) => {
+            #[cfg(feature = "debug")]
+            print_debug!(
+                "failed to store password in the keyring ({}), falling back to {}",
+                _error,
+                EncryptedPasswords::FILE_NAME.magenta()
+            );
+
+            EncryptedPasswords::load().insert(key, password);
+        }
+    }
+}
+
+pub fn forget_password(key: CredentialKey) {
+    let _ = keyring_entry(key).and_then(|entry| entry.delete_password());
+    EncryptedPasswords::load().remove(key);
+}
+
+/// Fallback for systems without a keyring. The key is generated once and
+/// stored next to the passwords, so this only protects against the file
+/// being read on its own, not against access to the whole client directory.
+#[derive(Default, Serialize, Deserialize)]
+struct EncryptedPasswords {
+    passwords: HashMap<ServiceId, Vec<u8>>,
+    #[serde(default)]
+    profile_passwords: HashMap<String, Vec<u8>>,
+}
+
+impl EncryptedPasswords {
+    const FILE_NAME: &'static str = "client/credentials.ron";
+    const KEY_FILE_NAME: &'static str = "client/credentials.key";
+
+    fn load() -> Self {
+        std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    fn load_key() -> Key {
+        if let Some(key) = std::fs::read(Self::KEY_FILE_NAME).ok().filter(|bytes| bytes.len() == KEY_SIZE) {
+            return *Key::from_slice(&key);
+        }
+
+        #[cfg(feature = "debug")]
+        print_debug!("generating new credential key in {}", Self::KEY_FILE_NAME.magenta());
+
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        std::fs::write(Self::KEY_FILE_NAME, key).expect("unable to write file");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let _ = std::fs::set_permissions(Self::KEY_FILE_NAME, std::fs::Permissions::from_mode(0o600));
+        }
+
+        key
+    }
+
+    fn get(&self, key: CredentialKey) -> Option<String> {
+        let data = match key {
+            CredentialKey::Service(service_id) => self.passwords.get(&service_id),
+            CredentialKey::Profile(name) => self.profile_passwords.get(name),
+        };
+
+        data.and_then(|data| decrypt(&Self::load_key(), data))
+    }
+
+    fn insert(&mut self, key: CredentialKey, password: &str) {
+        let data = encrypt(&Self::load_key(), password);
+
+        match key {
+            CredentialKey::Service(service_id) => self.passwords.insert(service_id, data),
+            CredentialKey::Profile(name) => self.profile_passwords.insert(name.to_owned(), data),
+        };
+
+        self.save();
+    }
+
+    fn remove(&mut self, key: CredentialKey) {
+        let removed = match key {
+            CredentialKey::Service(service_id) => self.passwords.remove(&service_id),
+            CredentialKey::Profile(name) => self.profile_passwords.remove(name),
+        };
+
+        if removed.is_some() {
+            self.save();
+        }
+    }
+
+    fn save(&self) {
+        #[cfg(feature = "debug")]
+        print_debug!("saving encrypted passwords to {}", Self::FILE_NAME.magenta());
+
+        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
+    }
+}
+
+/// Encrypt a password with a random nonce. The nonce is prepended to the
+/// ciphertext.
+fn encrypt(key: &Key, password: &str) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(key)
+        .encrypt(&nonce, password.as_bytes())
+        .expect("failed to encrypt password");
+
+    nonce.into_iter().chain(ciphertext).collect()
+}
+
+/// Returns `None` if the data was not encrypted with the given key or has
+/// been modified.
+fn decrypt(key: &Key, data: &[u8]) -> Option<String> {
+    if data.len() < NONCE_SIZE {
+        return None;
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+    let password = ChaCha20Poly1305::new(key).decrypt(Nonce::from_slice(nonce), ciphertext).ok()?;
+
+    String::from_utf8(password).ok()
+}
+
+#[cfg(test)]
+mod encryption {
+    use chacha20poly1305::aead::{KeyInit, OsRng};
+    use chacha20poly1305::ChaCha20Poly1305;
+
+    use super::{decrypt, encrypt};
+
+    #[test]
+    fn round_trip() {
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        let data = encrypt(&key, "hunter2");
+
+        assert_eq!(decrypt(&key, &data).as_deref(), Some("hunter2"));
+    }
+
+    #[test]
+    fn no_plaintext() {
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        let data = encrypt(&key, "hunter2");
+
+        assert!(!data.windows(7).any(|window| window == b"hunter2"));
+    }
+
+    #[test]
+    fn wrong_key() {
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        let other_key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        let data = encrypt(&key, "hunter2");
+
+        assert_eq!(decrypt(&other_key, &data), None);
+    }
+
+    #[test]
+    fn modified_data() {
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        let mut data = encrypt(&key, "hunter2");
+        *data.last_mut().unwrap() ^= 1;
+
+        assert_eq!(decrypt(&key, &data), None);
+        assert_eq!(decrypt(&key, &data[..4]), None);
+    }
+}
@@ -1,3 +1,5 @@
+mod credentials;
+
 use std::collections::HashMap;
 
 #[cfg(feature = "debug")]
@@ -6,6 +8,7 @@ use ron::ser::PrettyConfig;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serialize, Serializer};
 
+use self::credentials::CredentialKey;
 use crate::loaders::ServiceId;
 
 #[derive(Clone, Default, Serialize, Deserialize)]
@@ -13,11 +16,37 @@ pub struct LoginSettings {
     pub service: String,
     pub service_settings: HashMap<ServiceId, ServiceSettings>,
     pub recent_service_id: Option<ServiceId>,
+    #[serde(default)]
+    pub profiles: Vec<ConnectionProfile>,
+}
+
+/// A named set of connection details, so switching between accounts or
+/// servers doesn't require typing them again.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub name: String,
+    pub service_id: ServiceId,
+    /// Overrides the packet version of the service.
+    #[serde(default)]
+    pub packet_version: Option<u32>,
+    pub username: String,
+    /// Loaded from and saved to the credential store under the name of the
+    /// profile.
+    #[serde(skip)]
+    pub password: String,
+    pub remember_password: bool,
+    /// Name of the window layout to use after logging in. Profiles without a
+    /// layout share the default one.
+    #[serde(default)]
+    pub window_layout: Option<String>,
 }
 
 #[derive(Clone, Default, Deserialize)]
 pub struct ServiceSettings {
     pub username: String,
+    /// Only read from the file to move passwords saved by older versions into
+    /// the credential store. It is never written back.
+    #[serde(default)]
     pub password: String,
     pub remember_username: bool,
     pub remember_password: bool,
@@ -28,17 +57,12 @@ impl Serialize for ServiceSettings {
     where
         S: serde::Serializer,
     {
-        let mut serde_state = Serializer::serialize_struct(serializer, "ServiceSettings", 4)?;
+        let mut serde_state = Serializer::serialize_struct(serializer, "ServiceSettings", 3)?;
         SerializeStruct::serialize_field(
             &mut serde_state,
             "username",
             self.remember_username.then_some(self.username.as_str()).unwrap_or_default(),
         )?;
-        SerializeStruct::serialize_field(
-            &mut serde_state,
-            "password",
-            self.remember_password.then_some(self.password.as_str()).unwrap_or_default(),
-        )?;
         SerializeStruct::serialize_field(&mut serde_state, "remember_username", &self.remember_username)?;
         SerializeStruct::serialize_field(&mut serde_state, "remember_password", &self.remember_password)?;
         SerializeStruct::end(serde_state)
@@ -61,18 +85,78 @@ impl LoginSettings {
         #[cfg(feature = "debug")]
         print_debug!("loading login settings from {}", Self::FILE_NAME.magenta());
 
-        std::fs::read_to_string(Self::FILE_NAME)
+        let mut login_settings: Self = std::fs::read_to_string(Self::FILE_NAME)
             .ok()
-            .and_then(|data| ron::from_str(&data).ok())
+            .and_then(|data| ron::from_str(&data).ok())?;
+
+        for (&service_id, service_settings) in &mut login_settings.service_settings {
+            if !service_settings.remember_password {
+                continue;
+            }
+
+            let key = CredentialKey::Service(service_id);
+
+            match service_settings.password.is_empty() {
+                true => service_settings.password = credentials::load_password(key).unwrap_or_default(),
+                false => credentials::store_password(key, &service_settings.password),
+            }
+        }
+
+        for profile in login_settings.profiles.iter_mut().filter(|profile| profile.remember_password) {
+            profile.password = credentials::load_password(CredentialKey::Profile(&profile.name)).unwrap_or_default();
+        }
+
+        Some(login_settings)
     }
 
     pub fn save(&self) {
         #[cfg(feature = "debug")]
         print_debug!("saving login settings to {}", Self::FILE_NAME.magenta());
 
+        for (&service_id, service_settings) in &self.service_settings {
+            let key = CredentialKey::Service(service_id);
+
+            match service_settings.remember_password {
+                true if !service_settings.password.is_empty() => credentials::store_password(key, &service_settings.password),
+                true => {}
+                false => credentials::forget_password(key),
+            }
+        }
+
+        for profile in &self.profiles {
+            let key = CredentialKey::Profile(&profile.name);
+
+            match profile.remember_password {
+                true if !profile.password.is_empty() => credentials::store_password(key, &profile.password),
+                true => {}
+                false => credentials::forget_password(key),
+            }
+        }
+
         let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
         std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
     }
+
+    /// Remove the saved username and password of a service, including the
+    /// password in the credential store.
+    pub fn forget_credentials(&mut self, service_id: ServiceId) {
+        self.service_settings.insert(service_id, ServiceSettings::default());
+        credentials::forget_password(CredentialKey::Service(service_id));
+    }
+
+    /// Add a profile, replacing the profile with the same name if there is
+    /// one.
+    pub fn save_profile(&mut self, profile: ConnectionProfile) {
+        match self.profiles.iter_mut().find(|saved_profile| saved_profile.name == profile.name) {
+            Some(saved_profile) => *saved_profile = profile,
+            None => self.profiles.push(profile),
+        }
+    }
+
+    pub fn remove_profile(&mut self, name: &str) {
+        self.profiles.retain(|profile| profile.name != name);
+        credentials::forget_password(CredentialKey::Profile(name));
+    }
 }
 
 impl Drop for LoginSettings {
@@ -15,6 +15,7 @@ use super::error::LoadError;
 use super::TextureLoader;
 use crate::graphics::{Camera, Color, DeferredRenderer, Renderer};
 use crate::loaders::GameFileLoader;
+use crate::world::{AttachmentPoint, EntityHandle, EntityStore};
 
 fn ease_interpolate(start_value: f32, end_value: f32, time: f32, bias: f32, sub_multiplier: f32) -> f32 {
     if bias > 0.0 {
@@ -291,20 +292,22 @@ impl EffectLoader {
 }
 
 pub enum EffectCenter {
-    Entity(EntityId, Vector3<f32>),
+    /// Follows an attachment point of an entity, starting at the given
+    /// position.
+    Entity(EntityHandle, AttachmentPoint, Vector3<f32>),
     Position(Vector3<f32>),
 }
 
 impl EffectCenter {
     fn to_position(&self) -> Vector3<f32> {
         match self {
-            EffectCenter::Entity(_, position) | EffectCenter::Position(position) => *position,
+            EffectCenter::Entity(_, _, position) | EffectCenter::Position(position) => *position,
         }
     }
 }
 
 pub trait EffectBase {
-    fn update(&mut self, entities: &[crate::world::Entity], delta_time: f32) -> bool;
+    fn update(&mut self, entities: &EntityStore, camera: &dyn Camera, delta_time: f32) -> bool;
 
     fn mark_for_deletion(&mut self);
 
@@ -328,14 +331,13 @@ pub struct EffectWithLight {
 }
 
 impl EffectBase for EffectWithLight {
-    fn update(&mut self, entities: &[crate::world::Entity], delta_time: f32) -> bool {
+    fn update(&mut self, entities: &EntityStore, camera: &dyn Camera, delta_time: f32) -> bool {
         const FADE_SPEED: f32 = 5.0;
 
-        if let EffectCenter::Entity(entity_id, position) = &mut self.center
-            && let Some(entity) = entities.iter().find(|entity| entity.get_entity_id() == *entity_id)
+        if let EffectCenter::Entity(entity_handle, attachment_point, position) = &mut self.center
+            && let Some(entity) = entities.get_by_handle(*entity_handle)
         {
-            let new_position = entity.get_position();
-            *position = new_position;
+            *position = entity.get_attachment_position(camera, *attachment_point);
         }
 
         if !self.gets_deleted && !self.frame_timer.update(delta_time) && !self.repeating {
@@ -403,8 +405,8 @@ impl EffectHolder {
         self.effects.clear();
     }
 
-    pub fn update(&mut self, entities: &[crate::world::Entity], delta_time: f32) {
-        self.effects.retain_mut(|(effect, _)| effect.update(entities, delta_time));
+    pub fn update(&mut self, entities: &EntityStore, camera: &dyn Camera, delta_time: f32) {
+        self.effects.retain_mut(|(effect, _)| effect.update(entities, camera, delta_time));
     }
 
     pub fn render(&self, render_target: &mut <DeferredRenderer as Renderer>::Target, renderer: &DeferredRenderer, camera: &dyn Camera) {
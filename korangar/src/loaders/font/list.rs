@@ -0,0 +1,48 @@
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use serde::{Deserialize, Serialize};
+
+/// Fonts used to render the interface. If the first font is missing a glyph,
+/// the next font in the list is used instead.
+#[derive(Serialize, Deserialize)]
+pub(super) struct FontList {
+    pub fonts: Vec<String>,
+}
+
+impl Default for FontList {
+    fn default() -> Self {
+        Self {
+            fonts: Self::DEFAULT_FONTS.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl FontList {
+    const DEFAULT_FONTS: &'static [&'static str] = &[
+        "data\\WenQuanYiMicroHei.ttf",
+        "malgun.ttf",
+        "msgothic.ttc",
+        "NotoSansCJK-Regular.ttc",
+    ];
+    const FILE_NAME: &'static str = "client/fonts.ron";
+
+    pub(super) fn load() -> Self {
+        #[cfg(feature = "debug")]
+        print_debug!("loading font list from {}", Self::FILE_NAME.magenta());
+
+        std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())
+            .map(|fonts| Self { fonts })
+            .unwrap_or_else(|| {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] failed to load font list from {}; trying with default",
+                    "warning".yellow(),
+                    Self::FILE_NAME.magenta(),
+                );
+
+                FontList::default()
+            })
+    }
+}
@@ -1,9 +1,14 @@
+mod list;
+
+use std::path::PathBuf;
 use std::sync::Arc;
 
-use cgmath::{Array, Vector2};
+use cgmath::Vector2;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
 use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::ElementDisplay;
-use rusttype::gpu_cache::Cache;
+use rusttype::gpu_cache::{Cache, CacheWriteErr};
 use rusttype::*;
 use serde::{Deserialize, Serialize};
 use vulkano::buffer::{Buffer, BufferCreateInfo, BufferUsage};
@@ -17,7 +22,9 @@ use vulkano::image::{Image, ImageCreateInfo, ImageUsage};
 use vulkano::memory::allocator::{AllocationCreateInfo, MemoryTypeFilter};
 use vulkano::sync::future::FenceSignalFuture;
 use vulkano::sync::GpuFuture;
+use walkdir::WalkDir;
 
+use self::list::FontList;
 use super::GameFileLoader;
 use crate::graphics::{Color, CommandBuilder, MemoryAllocator};
 use crate::interface::application::InterfaceSettings;
@@ -93,23 +100,36 @@ impl korangar_interface::application::ScalingTrait for Scaling {
     }
 }
 
+/// Size of the font atlas when starting the client. CJK text uses a lot of
+/// different glyphs, so the atlas grows if they don't fit anymore.
+const INITIAL_ATLAS_SIZE: u32 = 1024;
+const MAXIMUM_ATLAS_SIZE: u32 = 4096;
+
 pub struct FontLoader {
     memory_allocator: Arc<MemoryAllocator>,
     queue: Arc<Queue>,
     font_atlas: Arc<ImageView>,
     cache: Box<Cache<'static>>,
     load_buffer: Option<CommandBuilder>,
-    font: Box<Font<'static>>,
+    /// Fallback chain of fonts. The first font is used for the line metrics.
+    fonts: Vec<Font<'static>>,
 }
 
 struct GlyphData {
     glyph: PositionedGlyph<'static>,
+    font_id: usize,
     color: Color,
 }
 
-fn layout_paragraph(font: &Font<'static>, scale: Scale, width: f32, text: &str, default_color: Color) -> (Vec<GlyphData>, Vector2<f32>) {
+/// Get the first font that has a glyph for the character. If none of the fonts
+/// has one, the first font is used to render the missing glyph.
+fn select_font(fonts: &[Font<'static>], character: char) -> usize {
+    fonts.iter().position(|font| font.glyph(character).id().0 != 0).unwrap_or(0)
+}
+
+fn layout_paragraph(fonts: &[Font<'static>], scale: Scale, width: f32, text: &str, default_color: Color) -> (Vec<GlyphData>, Vector2<f32>) {
     let mut result = Vec::new();
-    let v_metrics = font.v_metrics(scale);
+    let v_metrics = fonts[0].v_metrics(scale);
     let advance_height = v_metrics.ascent - v_metrics.descent + v_metrics.line_gap;
     let mut caret = point(0.0, v_metrics.ascent);
     let mut last_glyph_id = None;
@@ -153,12 +173,18 @@ fn layout_paragraph(font: &Font<'static>, scale: Scale, width: f32, text: &str,
             }
         }
 
+        let font_id = select_font(fonts, character);
+        let font = &fonts[font_id];
         let base_glyph = font.glyph(character);
-        if let Some(id) = last_glyph_id.take() {
+
+        // Kerning is only defined between glyphs of the same font.
+        if let Some((last_font_id, id)) = last_glyph_id.take()
+            && last_font_id == font_id
+        {
             caret.x += font.pair_kerning(scale, id, base_glyph.id());
         }
 
-        last_glyph_id = Some(base_glyph.id());
+        last_glyph_id = Some((font_id, base_glyph.id()));
         let mut glyph = base_glyph.scaled(scale).positioned(caret);
 
         if let Some(bb) = glyph.pixel_bounding_box() {
@@ -170,7 +196,7 @@ fn layout_paragraph(font: &Font<'static>, scale: Scale, width: f32, text: &str,
         }
 
         caret.x += glyph.unpositioned().h_metrics().advance_width;
-        result.push(GlyphData { glyph, color });
+        result.push(GlyphData { glyph, font_id, color });
     }
 
     (result, Vector2::new(caret.x, caret.y))
@@ -178,55 +204,153 @@ fn layout_paragraph(font: &Font<'static>, scale: Scale, width: f32, text: &str,
 
 impl FontLoader {
     pub fn new(memory_allocator: Arc<MemoryAllocator>, queue: Arc<Queue>, game_file_loader: &mut GameFileLoader) -> Self {
-        let cache_size = Vector2::from_value(512);
-        let cache = Cache::builder().dimensions(cache_size.x, cache_size.y).build();
+        let cache = Cache::builder().dimensions(INITIAL_ATLAS_SIZE, INITIAL_ATLAS_SIZE).build();
+
+        let fonts: Vec<Font<'static>> = FontList::load()
+            .fonts
+            .iter()
+            .filter_map(|font_path| Self::load_font(font_path, game_file_loader))
+            .collect();
+
+        if fonts.is_empty() {
+            panic!("failed to load any of the fonts in the font list");
+        }
+
+        let mut font_loader = Self {
+            font_atlas: Self::create_font_atlas(&memory_allocator, INITIAL_ATLAS_SIZE),
+            memory_allocator,
+            queue,
+            cache: Box::new(cache),
+            load_buffer: None,
+            fonts,
+        };
+
+        font_loader.clear_font_atlas();
+        font_loader
+    }
+
+    /// Load a font from the game archives, a path on disk, or the font
+    /// directories of the operating system, in that order.
+    fn load_font(font_path: &str, game_file_loader: &mut GameFileLoader) -> Option<Font<'static>> {
+        let data = game_file_loader
+            .get(font_path)
+            .ok()
+            .or_else(|| std::fs::read(font_path).ok())
+            .or_else(|| find_system_font(font_path).and_then(|path| std::fs::read(path).ok()));
+
+        let Some(data) = data else {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to find font {}", "warning".yellow(), font_path.magenta());
+
+            return None;
+        };
 
+        let font = Font::try_from_vec(data);
+
+        #[cfg(feature = "debug")]
+        if font.is_none() {
+            print_debug!("[{}] failed to construct font from {}", "error".red(), font_path.magenta());
+        }
+
+        font
+    }
+
+    fn create_font_atlas(memory_allocator: &MemoryAllocator, size: u32) -> Arc<ImageView> {
         let font_atlas_image = Image::new(
-            &*memory_allocator,
+            memory_allocator,
             ImageCreateInfo {
                 format: Format::R8_UNORM,
-                extent: [cache_size.x, cache_size.y, 1],
+                extent: [size, size, 1],
                 usage: ImageUsage::TRANSFER_DST | ImageUsage::SAMPLED,
                 ..Default::default()
             },
             AllocationCreateInfo::default(),
         )
         .unwrap();
-        let font_atlas = ImageView::new_default(font_atlas_image.clone()).unwrap();
-
-        let font_path = "data\\WenQuanYiMicroHei.ttf";
-        let data = game_file_loader.get(font_path).unwrap();
-        let font = Font::try_from_vec(data).unwrap_or_else(|| {
-            panic!("error constructing a font from data at {font_path:?}");
-        });
-
-        let mut builder = AutoCommandBufferBuilder::primary(
-            &*memory_allocator,
-            queue.queue_family_index(),
-            CommandBufferUsage::OneTimeSubmit,
-        )
-        .unwrap();
 
+        ImageView::new_default(font_atlas_image).unwrap()
+    }
+
+    fn get_load_buffer(&mut self) -> &mut CommandBuilder {
+        self.load_buffer.get_or_insert_with(|| {
+            AutoCommandBufferBuilder::primary(
+                &*self.memory_allocator,
+                self.queue.queue_family_index(),
+                CommandBufferUsage::OneTimeSubmit,
+            )
+            .unwrap()
+        })
+    }
+
+    fn clear_font_atlas(&mut self) {
         let clear_color_image_info = ClearColorImageInfo {
             clear_value: [0f32].into(),
-            ..ClearColorImageInfo::image(font_atlas_image)
+            ..ClearColorImageInfo::image(self.font_atlas.image().clone())
         };
 
-        builder.clear_color_image(clear_color_image_info).unwrap();
+        self.get_load_buffer().clear_color_image(clear_color_image_info).unwrap();
+    }
 
-        Self {
-            memory_allocator,
-            queue,
-            font_atlas,
-            cache: Box::new(cache),
-            load_buffer: builder.into(),
-            font: Box::new(font),
-        }
+    /// Double the size of the font atlas. Text that was already rendered this
+    /// frame keeps using the old atlas, so it is not corrupted.
+    fn grow_font_atlas(&mut self) {
+        let size = (self.cache.dimensions().0 * 2).min(MAXIMUM_ATLAS_SIZE);
+
+        #[cfg(feature = "debug")]
+        print_debug!("growing font atlas to {}", size.magenta());
+
+        self.cache.to_builder().dimensions(size, size).rebuild(&mut self.cache);
+        self.font_atlas = Self::create_font_atlas(&self.memory_allocator, size);
+        self.clear_font_atlas();
+    }
+
+    fn cache_queued(&mut self) -> Result<(), CacheWriteErr> {
+        self.cache.cache_queued(|rect, data| {
+            let builder = self.load_buffer.get_or_insert_with(|| {
+                AutoCommandBufferBuilder::primary(
+                    &*self.memory_allocator,
+                    self.queue.queue_family_index(),
+                    CommandBufferUsage::OneTimeSubmit,
+                )
+                .unwrap()
+            });
+
+            let pixels = data.iter().map(|&value| value as i8);
+            let buffer = Buffer::from_iter(
+                &*self.memory_allocator,
+                BufferCreateInfo {
+                    usage: BufferUsage::TRANSFER_SRC,
+                    ..Default::default()
+                },
+                AllocationCreateInfo {
+                    memory_type_filter: MemoryTypeFilter::PREFER_HOST | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                    ..Default::default()
+                },
+                pixels,
+            )
+            .unwrap();
+
+            let image = self.font_atlas.image().clone();
+
+            let region = BufferImageCopy {
+                image_subresource: image.subresource_layers(),
+                image_extent: [rect.width(), rect.height(), 1],
+                image_offset: [rect.min.x, rect.min.y, 0],
+                ..Default::default()
+            };
+
+            builder
+                .copy_buffer_to_image(CopyBufferToImageInfo {
+                    regions: [region].into(),
+                    ..CopyBufferToImageInfo::buffer_image(buffer, image)
+                })
+                .unwrap();
+        })
     }
 
     pub fn get_text_dimensions(&self, text: &str, font_size: FontSize, available_width: f32) -> ScreenSize {
         let (_, size) = layout_paragraph(
-            &self.font,
+            &self.fonts,
             Scale::uniform(font_size.get_value()),
             available_width,
             text,
@@ -247,7 +371,7 @@ impl FontLoader {
         available_width: f32,
     ) -> (Vec<(Rect<f32>, Rect<i32>, Color)>, f32) {
         let (glyphs, size) = layout_paragraph(
-            &self.font,
+            &self.fonts,
             Scale::uniform(font_size.get_value()),
             available_width,
             text,
@@ -255,59 +379,22 @@ impl FontLoader {
         );
 
         for glyph in &glyphs {
-            self.cache.queue_glyph(0, glyph.glyph.clone());
+            self.cache.queue_glyph(glyph.font_id, glyph.glyph.clone());
         }
 
-        self.cache
-            .cache_queued(|rect, data| {
-                let builder = self.load_buffer.get_or_insert_with(|| {
-                    AutoCommandBufferBuilder::primary(
-                        &*self.memory_allocator,
-                        self.queue.queue_family_index(),
-                        CommandBufferUsage::OneTimeSubmit,
-                    )
-                    .unwrap()
-                });
-
-                let pixels = data.iter().map(|&value| value as i8);
-                let buffer = Buffer::from_iter(
-                    &*self.memory_allocator,
-                    BufferCreateInfo {
-                        usage: BufferUsage::TRANSFER_SRC,
-                        ..Default::default()
-                    },
-                    AllocationCreateInfo {
-                        memory_type_filter: MemoryTypeFilter::PREFER_HOST | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
-                        ..Default::default()
-                    },
-                    pixels,
-                )
-                .unwrap();
-
-                let image = self.font_atlas.image().clone();
-
-                let region = BufferImageCopy {
-                    image_subresource: image.subresource_layers(),
-                    image_extent: [rect.width(), rect.height(), 1],
-                    image_offset: [rect.min.x, rect.min.y, 0],
-                    ..Default::default()
-                };
-
-                builder
-                    .copy_buffer_to_image(CopyBufferToImageInfo {
-                        regions: [region].into(),
-                        ..CopyBufferToImageInfo::buffer_image(buffer, image)
-                    })
-                    .unwrap();
-            })
-            .unwrap();
+        loop {
+            match self.cache_queued() {
+                Err(CacheWriteErr::NoRoomForWholeQueue) if self.cache.dimensions().0 < MAXIMUM_ATLAS_SIZE => self.grow_font_atlas(),
+                result => break result.unwrap(),
+            }
+        }
 
         (
             glyphs
                 .into_iter()
                 .filter_map(|glyph| {
                     self.cache
-                        .rect_for(0, &glyph.glyph)
+                        .rect_for(glyph.font_id, &glyph.glyph)
                         .unwrap()
                         .map(|tuple| (tuple.0, tuple.1, glyph.color))
                 })
@@ -334,6 +421,33 @@ impl FontLoader {
     }
 }
 
+/// Search the font directories of the operating system for a font file with
+/// the given name.
+fn find_system_font(file_name: &str) -> Option<PathBuf> {
+    let mut directories = vec![
+        PathBuf::from("/usr/share/fonts"),
+        PathBuf::from("/usr/local/share/fonts"),
+        PathBuf::from("/Library/Fonts"),
+        PathBuf::from("/System/Library/Fonts"),
+    ];
+
+    if let Some(windows_directory) = std::env::var_os("WINDIR") {
+        directories.push(PathBuf::from(windows_directory).join("Fonts"));
+    }
+
+    if let Some(home_directory) = std::env::var_os("HOME") {
+        directories.push(PathBuf::from(&home_directory).join(".local/share/fonts"));
+        directories.push(PathBuf::from(&home_directory).join("Library/Fonts"));
+    }
+
+    directories
+        .iter()
+        .filter(|directory| directory.exists())
+        .flat_map(|directory| WalkDir::new(directory).into_iter().filter_map(Result::ok))
+        .find(|entry| entry.file_type().is_file() && entry.file_name().eq_ignore_ascii_case(file_name))
+        .map(|entry| entry.into_path())
+}
+
 impl korangar_interface::application::FontLoaderTrait<InterfaceSettings> for std::rc::Rc<std::cell::RefCell<FontLoader>> {
     fn get_text_dimensions(&self, text: &str, font_size: FontSize, available_width: f32) -> ScreenSize {
         self.borrow().get_text_dimensions(text, font_size, available_width)
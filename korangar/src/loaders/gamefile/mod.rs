@@ -4,7 +4,7 @@
 mod list;
 
 use core::panic;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::u8;
 
 #[cfg(feature = "debug")]
@@ -20,6 +20,10 @@ const LUA_GRF_FILE_NAME: &str = "lua_files/";
 #[cfg(not(feature = "patched_as_folder"))]
 const LUA_GRF_FILE_NAME: &str = "lua_files.grf";
 
+/// Folder next to the archives that contains Lua files which are not part of
+/// any archive, like the achievement list.
+const SYSTEM_FOLDER_NAME: &str = "system";
+
 pub const FALLBACK_PNG_FILE: &str = "data\\texture\\missing.png";
 pub const FALLBACK_BMP_FILE: &str = "data\\texture\\missing.bmp";
 pub const FALLBACK_TGA_FILE: &str = "data\\texture\\missing.tga";
@@ -39,6 +43,8 @@ pub struct FileNotFoundError(String);
 #[derive(Default)]
 pub struct GameFileLoader {
     archives: Vec<Box<dyn Archive>>,
+    /// Path that relative archive paths and the system folder are resolved in.
+    data_path: PathBuf,
 }
 
 impl GameFileLoader {
@@ -58,23 +64,29 @@ impl GameFileLoader {
         }
     }
 
-    fn load_archive_from_path(path: &str) -> Box<dyn Archive> {
-        let path = Path::new(path);
-
+    fn load_archive_from_path(path: &Path) -> Box<dyn Archive> {
         match GameFileLoader::get_archive_type_by_path(path) {
             ArchiveType::Folder => Box::new(FolderArchive::from_path(path)),
             ArchiveType::Native => Box::new(NativeArchive::from_path(path)),
         }
     }
 
-    pub fn load_archives_from_settings(&mut self) {
+    /// Load the archives of the archive list. Relative paths are resolved in
+    /// the data path if there is one.
+    pub fn load_archives_from_settings(&mut self, data_path: Option<&Path>) {
         #[cfg(feature = "debug")]
         let timer = Timer::new("load game archives");
 
         let game_archive_list = GameArchiveList::load();
+        self.data_path = data_path.map(Path::to_path_buf).unwrap_or_default();
 
         game_archive_list.archives.iter().for_each(|path| {
-            let game_archive = Self::load_archive_from_path(path);
+            let path = match data_path {
+                Some(data_path) => data_path.join(path),
+                None => PathBuf::from(path),
+            };
+
+            let game_archive = Self::load_archive_from_path(&path);
             self.add_archive(game_archive);
         });
 
@@ -87,7 +99,7 @@ impl GameFileLoader {
             self.patch_lua_files();
         }
 
-        let lua_archive = Self::load_archive_from_path(LUA_GRF_FILE_NAME);
+        let lua_archive = Self::load_archive_from_path(Path::new(LUA_GRF_FILE_NAME));
         self.add_archive(lua_archive);
     }
 
@@ -157,6 +169,35 @@ impl GameFileLoader {
         lua_archive.save();
     }
 
+    /// Load a file from the system folder. Pre-compiled Lua files are
+    /// converted the same way as the ones in the archives.
+    pub fn get_system_file(&self, file_name: &str) -> Result<Vec<u8>, FileNotFoundError> {
+        use lunify::{unify, Format, Settings};
+
+        // Clients name the folder `System`, so names are compared case insensitively
+        // to also find it on case sensitive file systems.
+        let find_entry = |directory: &Path, name: &str| {
+            std::fs::read_dir(directory)
+                .ok()?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .find(|path| path.file_name().is_some_and(|entry_name| entry_name.eq_ignore_ascii_case(name)))
+        };
+
+        let directory = match self.data_path.as_os_str().is_empty() {
+            true => Path::new("."),
+            false => self.data_path.as_path(),
+        };
+
+        let bytes = find_entry(directory, SYSTEM_FOLDER_NAME)
+            .and_then(|system_folder| find_entry(&system_folder, file_name))
+            .and_then(|path| std::fs::read(path).ok())
+            .ok_or_else(|| FileNotFoundError(format!("{SYSTEM_FOLDER_NAME}/{file_name}")))?;
+
+        // Source files fail the signature check and are used as they are.
+        Ok(unify(&bytes, &Format::default(), &Settings::default()).unwrap_or(bytes))
+    }
+
     pub fn get(&mut self, path: &str) -> Result<Vec<u8>, FileNotFoundError> {
         let lowercase_path = path.to_lowercase();
         let result = self
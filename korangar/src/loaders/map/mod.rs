@@ -8,13 +8,15 @@ use derive_new::new;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::Timer;
 use ragnarok_bytes::{ByteStream, FromBytes};
-use ragnarok_formats::map::{GatData, GroundData, GroundTile, MapData, MapResources};
+use ragnarok_formats::map::{GatData, GroundData, GroundTile, MapData, MapResources, TileFlags};
 use ragnarok_formats::version::InternalVersion;
 
 use self::vertices::{generate_tile_vertices, ground_water_vertices, load_textures};
+use super::cache::evict_unused;
 use super::error::LoadError;
 use crate::graphics::{BufferAllocator, NativeModelVertex};
 use crate::loaders::{GameFileLoader, ModelLoader, TextureLoader};
+use crate::system::{measure_load, LoaderKind};
 use crate::world::*;
 
 const MAP_OFFSET: f32 = 5.0;
@@ -39,6 +41,12 @@ pub struct MapLoader {
 }
 
 impl MapLoader {
+    /// Remove up to `limit` cached maps that aren't used anymore. Returns
+    /// `true` if there might be more to remove.
+    pub fn evict_unused(&mut self, limit: usize) -> bool {
+        evict_unused(&mut self.cache, limit)
+    }
+
     pub fn get(
         &mut self,
         resource_file: String,
@@ -49,7 +57,9 @@ impl MapLoader {
     ) -> Result<Arc<Map>, LoadError> {
         match self.cache.get(&resource_file) {
             Some(map) => Ok(map.clone()),
-            None => self.load(resource_file, game_file_loader, buffer_allocator, model_loader, texture_loader),
+            None => measure_load(LoaderKind::Map, || {
+                self.load(resource_file, game_file_loader, buffer_allocator, model_loader, texture_loader)
+            }),
         }
     }
 
@@ -118,6 +128,31 @@ impl MapLoader {
                 )
             })
             .collect();
+        let object_bounding_boxes: Vec<BoundingBox> = objects.iter().map(Object::world_bounding_box).collect();
+        let bounding_box_corners: Vec<[f32; 4]> = object_bounding_boxes
+            .iter()
+            .flat_map(|bounding_box| [bounding_box.smallest.extend(0.0).into(), bounding_box.biggest.extend(0.0).into()])
+            .collect();
+        let object_bounding_box_buffer =
+            (!bounding_box_corners.is_empty()).then(|| buffer_allocator.allocate_storage_buffer(bounding_box_corners));
+
+        // Every node of every object is drawn with its own draw command, which the
+        // culling pass writes based on the bounding box of the object.
+        let mut object_draw_offsets = vec![0];
+        let mut object_draws: Vec<[u32; 2]> = Vec::new();
+
+        for (object_index, object) in objects.iter().enumerate() {
+            let vertex_counts = object.model.node_vertex_counts();
+            object_draws.extend(vertex_counts.into_iter().map(|vertex_count| [object_index as u32, vertex_count]));
+            object_draw_offsets.push(object_draws.len() as u64);
+        }
+
+        let object_draw_buffer = (!object_draws.is_empty()).then(|| buffer_allocator.allocate_storage_buffer(object_draws));
+
+        let map_width = gat_data.map_width as usize;
+        let path_graph = PathGraph::new(map_width, gat_data.map_height as usize, |x, y| {
+            gat_data.tiles[x + y * map_width].flags.contains(TileFlags::WALKABLE)
+        });
 
         let map = Arc::new(Map::new(
             gat_data.map_width as usize,
@@ -129,11 +164,17 @@ impl MapLoader {
             water_vertex_buffer,
             textures,
             objects,
+            #[cfg(feature = "debug")]
+            object_bounding_boxes,
+            object_bounding_box_buffer,
+            object_draw_offsets,
+            object_draw_buffer,
             map_data.resources.light_sources,
             map_data.resources.sound_sources,
             map_data.resources.effect_sources,
             tile_picker_vertex_buffer.unwrap(),
             tile_vertex_buffer.unwrap(),
+            path_graph,
             #[cfg(feature = "debug")]
             map_data_clone,
         ));
@@ -1,5 +1,6 @@
 mod action;
 mod archive;
+mod cache;
 pub mod client;
 mod effect;
 pub mod error;
@@ -7,8 +8,10 @@ mod font;
 mod gamefile;
 mod map;
 mod model;
+mod monster;
 mod script;
 mod server;
+mod sound;
 mod sprite;
 mod texture;
 
@@ -18,7 +21,9 @@ pub use self::font::{FontLoader, FontSize, Scaling};
 pub use self::gamefile::*;
 pub use self::map::MapLoader;
 pub use self::model::*;
-pub use self::script::{ResourceMetadata, ScriptLoader};
+pub use self::monster::{MonsterData, MonsterDatabase, MONSTER_JOB_IDS};
+pub use self::script::{AchievementMetadata, ResourceMetadata, ScriptLoader, ACHIEVEMENT_CATEGORIES};
 pub use self::server::{load_client_info, ClientInfo, ServiceId};
+pub use self::sound::SoundLoader;
 pub use self::sprite::*;
-pub use self::texture::TextureLoader;
+pub use self::texture::{make_magenta_transparent, TextureLoader};
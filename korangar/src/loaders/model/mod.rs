@@ -10,11 +10,12 @@ use ragnarok_formats::model::{ModelData, ModelString, NodeData};
 use ragnarok_formats::version::InternalVersion;
 use vulkano::image::view::ImageView;
 
+use super::cache::evict_unused;
 use super::error::LoadError;
 use super::FALLBACK_MODEL_FILE;
 use crate::graphics::{BufferAllocator, NativeModelVertex};
 use crate::loaders::{GameFileLoader, TextureLoader};
-use crate::system::multiply_matrix4_and_vector3;
+use crate::system::{measure_load, multiply_matrix4_and_vector3, LoaderKind};
 use crate::world::{BoundingBox, Model, Node};
 
 #[derive(new)]
@@ -24,6 +25,12 @@ pub struct ModelLoader {
 }
 
 impl ModelLoader {
+    /// Remove up to `limit` cached models that aren't used anymore. Returns
+    /// `true` if there might be more to remove.
+    pub fn evict_unused(&mut self, limit: usize) -> bool {
+        evict_unused(&mut self.cache, limit)
+    }
+
     fn add_vertices(
         native_vertices: &mut Vec<NativeModelVertex>,
         vertex_positions: &[Vector3<f32>],
@@ -283,7 +290,9 @@ impl ModelLoader {
         match self.cache.get(&(model_file.to_string(), reverse_order)) {
             // kinda dirty
             Some(model) => Ok(model.clone()),
-            None => self.load(buffer_allocator, game_file_loader, texture_loader, model_file, reverse_order),
+            None => measure_load(LoaderKind::Model, || {
+                self.load(buffer_allocator, game_file_loader, texture_loader, model_file, reverse_order)
+            }),
         }
     }
 }
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize, Timer};
+use ragnarok_packets::ItemId;
+
+use super::{GameFileLoader, ScriptLoader};
+
+/// Job ids that belong to monsters.
+pub const MONSTER_JOB_IDS: RangeInclusive<usize> = 1000..=3999;
+
+/// The client doesn't ship any monster stats, so they are read from a mob
+/// database in the format of the server emulators, if one is placed in the
+/// data folder.
+const MONSTER_DATABASE_PATH: &str = "data\\mob_db.txt";
+/// Number of comma separated fields in every line of the mob database.
+const FIELD_COUNT: usize = 57;
+/// Index of the first drop. Every drop takes two fields, the item id and the
+/// chance.
+const FIRST_DROP_FIELD: usize = 37;
+/// Nine regular drops and the card.
+const DROP_COUNT: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonsterRace {
+    Formless,
+    Undead,
+    Brute,
+    Plant,
+    Insect,
+    Fish,
+    Demon,
+    DemiHuman,
+    Angel,
+    Dragon,
+}
+
+impl MonsterRace {
+    fn from_id(id: u32) -> Option<Self> {
+        let race = match id {
+            0 => Self::Formless,
+            1 => Self::Undead,
+            2 => Self::Brute,
+            3 => Self::Plant,
+            4 => Self::Insect,
+            5 => Self::Fish,
+            6 => Self::Demon,
+            7 => Self::DemiHuman,
+            8 => Self::Angel,
+            9 => Self::Dragon,
+            _ => return None,
+        };
+
+        Some(race)
+    }
+}
+
+impl Display for MonsterRace {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Formless => write!(formatter, "Formless"),
+            Self::Undead => write!(formatter, "Undead"),
+            Self::Brute => write!(formatter, "Brute"),
+            Self::Plant => write!(formatter, "Plant"),
+            Self::Insect => write!(formatter, "Insect"),
+            Self::Fish => write!(formatter, "Fish"),
+            Self::Demon => write!(formatter, "Demon"),
+            Self::DemiHuman => write!(formatter, "Demi-Human"),
+            Self::Angel => write!(formatter, "Angel"),
+            Self::Dragon => write!(formatter, "Dragon"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonsterElement {
+    Neutral,
+    Water,
+    Earth,
+    Fire,
+    Wind,
+    Poison,
+    Holy,
+    Dark,
+    Ghost,
+    Undead,
+}
+
+impl MonsterElement {
+    fn from_id(id: u32) -> Option<Self> {
+        let element = match id {
+            0 => Self::Neutral,
+            1 => Self::Water,
+            2 => Self::Earth,
+            3 => Self::Fire,
+            4 => Self::Wind,
+            5 => Self::Poison,
+            6 => Self::Holy,
+            7 => Self::Dark,
+            8 => Self::Ghost,
+            9 => Self::Undead,
+            _ => return None,
+        };
+
+        Some(element)
+    }
+}
+
+impl Display for MonsterElement {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Neutral => write!(formatter, "Neutral"),
+            Self::Water => write!(formatter, "Water"),
+            Self::Earth => write!(formatter, "Earth"),
+            Self::Fire => write!(formatter, "Fire"),
+            Self::Wind => write!(formatter, "Wind"),
+            Self::Poison => write!(formatter, "Poison"),
+            Self::Holy => write!(formatter, "Holy"),
+            Self::Dark => write!(formatter, "Dark"),
+            Self::Ghost => write!(formatter, "Ghost"),
+            Self::Undead => write!(formatter, "Undead"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonsterDrop {
+    pub item_id: ItemId,
+    /// Chance of the drop in hundredths of a percent.
+    pub chance: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonsterData {
+    pub name: String,
+    pub level: u32,
+    pub health_points: u32,
+    pub base_experience: u32,
+    pub job_experience: u32,
+    pub attack: RangeInclusive<u32>,
+    pub defense: u32,
+    pub magic_defense: u32,
+    pub race: MonsterRace,
+    pub element: MonsterElement,
+    pub element_level: u32,
+    pub drops: Vec<MonsterDrop>,
+}
+
+fn parse_monster(line: &str) -> Option<(usize, MonsterData)> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+
+    if fields.len() != FIELD_COUNT {
+        return None;
+    }
+
+    let number = |index: usize| fields[index].parse::<u32>().ok();
+
+    // The element and its level are stored in one number.
+    let element = number(24)?;
+
+    let drops = (0..DROP_COUNT)
+        .map(|drop| FIRST_DROP_FIELD + drop * 2)
+        .filter_map(|field| {
+            let item_id = number(field)?;
+            let chance = number(field + 1)?;
+            (item_id != 0 && chance != 0).then_some(MonsterDrop {
+                item_id: ItemId(item_id),
+                chance,
+            })
+        })
+        .collect();
+
+    let monster = MonsterData {
+        name: fields[3].to_owned(),
+        level: number(4)?,
+        health_points: number(5)?,
+        base_experience: number(7)?,
+        job_experience: number(8)?,
+        attack: number(10)?..=number(11)?,
+        defense: number(12)?,
+        magic_defense: number(13)?,
+        race: MonsterRace::from_id(number(23)?)?,
+        element: MonsterElement::from_id(element % 20)?,
+        element_level: element / 20,
+        drops,
+    };
+
+    Some((fields[0].parse().ok()?, monster))
+}
+
+/// Stats and drops of every monster in the mob database.
+#[derive(Default)]
+pub struct MonsterDatabase {
+    monsters: HashMap<usize, MonsterData>,
+}
+
+impl MonsterDatabase {
+    pub fn load(game_file_loader: &mut GameFileLoader) -> Self {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new("load mob database");
+
+        let Ok(bytes) = game_file_loader.get(MONSTER_DATABASE_PATH) else {
+            #[cfg(feature = "debug")]
+            {
+                print_debug!("no mob database at {}", MONSTER_DATABASE_PATH.magenta());
+                timer.stop();
+            }
+
+            return Self::default();
+        };
+
+        let monsters = String::from_utf8_lossy(&bytes)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with("//"))
+            .filter_map(parse_monster)
+            .collect();
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Self { monsters }
+    }
+
+    pub fn get(&self, monster_id: usize) -> Option<&MonsterData> {
+        self.monsters.get(&monster_id)
+    }
+
+    /// Name of a monster, falling back to the name of its sprite if it's not
+    /// in the database.
+    pub fn get_name(&self, monster_id: usize, script_loader: &ScriptLoader) -> String {
+        self.get(monster_id)
+            .map(|monster| monster.name.clone())
+            .unwrap_or_else(|| script_loader.get_job_name_from_id(monster_id))
+    }
+}
+
+#[cfg(test)]
+mod mob_database {
+    use ragnarok_packets::ItemId;
+
+    use super::{parse_monster, MonsterDrop, MonsterElement, MonsterRace};
+
+    const PORING: &str = "1002,PORING,Poring,Poring,1,50,0,2,1,1,7,10,0,5,1,1,1,0,6,30,10,12,1,3,21,0x83,400,1872,672,480,0,0,0,0,0,0,0,\
+                          909,7000,1202,100,938,400,512,1000,713,1500,512,150,619,20,0,0,0,0,4001,1";
+
+    #[test]
+    fn parse_stats() {
+        let (monster_id, monster) = parse_monster(PORING).unwrap();
+
+        assert_eq!(monster_id, 1002);
+        assert_eq!(monster.name, "Poring");
+        assert_eq!(monster.level, 1);
+        assert_eq!(monster.health_points, 50);
+        assert_eq!(monster.attack, 7..=10);
+        assert_eq!(monster.race, MonsterRace::Plant);
+        assert_eq!(monster.element, MonsterElement::Water);
+        assert_eq!(monster.element_level, 1);
+    }
+
+    #[test]
+    fn parse_drops() {
+        let (_, monster) = parse_monster(PORING).unwrap();
+
+        assert_eq!(monster.drops.len(), 8);
+        assert_eq!(monster.drops[0], MonsterDrop {
+            item_id: ItemId(909),
+            chance: 7000,
+        });
+        assert_eq!(monster.drops[7], MonsterDrop {
+            item_id: ItemId(4001),
+            chance: 1,
+        });
+    }
+
+    #[test]
+    fn reject_malformed_lines() {
+        assert_eq!(parse_monster("1002,PORING,Poring"), None);
+        assert_eq!(parse_monster(&PORING.replace(",3,21,", ",12,21,")), None);
+    }
+}
@@ -14,6 +14,21 @@ pub struct ResourceMetadata {
     pub name: String,
 }
 
+/// Names of the achievement categories, indexed by the `major` field of the
+/// achievement list.
+pub const ACHIEVEMENT_CATEGORIES: [&str; 6] = ["General", "Adventure", "Battle", "Quest", "Memorial", "Feat"];
+
+#[derive(Debug, Clone)]
+pub struct AchievementMetadata {
+    pub name: String,
+    pub category: &'static str,
+    pub summary: String,
+    /// Description and required count of every objective.
+    pub objectives: Vec<(String, u32)>,
+    pub title_id: Option<u32>,
+    pub score: u32,
+}
+
 pub struct ScriptLoader {
     state: Lua,
 }
@@ -60,6 +75,49 @@ end
 
         state.load(job_id_function).exec().unwrap();
 
+        // Monsters whose sprites hover above the ground, by job id. The elevation is in
+        // world units.
+        let job_elevation_table = r#"
+JobElevationTable = {
+  [1005] = 3.0, -- FAMILIAR
+  [1011] = 3.0, -- CHONCHON
+  [1035] = 4.0, -- HUNTER_FLY
+  [1042] = 3.0, -- STEEL_CHONCHON
+  [1091] = 4.0, -- DRAGON_FLY
+  [1111] = 3.0, -- DRAINLIAR
+  [1179] = 4.0, -- WHISPER
+}
+"#;
+
+        state.load(job_elevation_table).exec().unwrap();
+
+        // Not every client ships the achievement list, so achievements fall back to
+        // generic names if it is missing.
+        if let Ok(data) = game_file_loader.get_system_file("achievement_list.lub") {
+            let _ = state.load(&data).exec();
+        }
+
+        // Quests are shown with their id instead of their name if the quest list is
+        // missing.
+        if let Ok(data) = game_file_loader.get_system_file("OngoingQuestInfoList_True.lub") {
+            let _ = state.load(&data).exec();
+        }
+
+        // Headgears and weapons are simply not shown if their tables are missing.
+        for file in ["accessoryid.lub", "accname.lub", "weapontable.lub"] {
+            if let Ok(data) = game_file_loader.get(&format!("data\\luafiles514\\lua files\\datainfo\\{file}")) {
+                let _ = state.load(&data).exec();
+            }
+        }
+
+        // Status effects are shown without icons and with generic names if the state
+        // icon tables are missing.
+        for file in ["efstids.lub", "stateiconimginfo.lub", "stateiconinfo.lub"] {
+            if let Ok(data) = game_file_loader.get(&format!("data\\luafiles514\\lua files\\stateicon\\{file}")) {
+                let _ = state.load(&data).exec();
+            }
+        }
+
         Self { state }
     }
 
@@ -76,11 +134,88 @@ end
             .unwrap()
             .to_str()
             .unwrap()
-            .replace("CHONCHON", "chocho") // TODO: find a way to do this properly
+            .replace("CHONCHON", "chocho") // TODO: find a way to do this
+                                           // properly
+    }
+
+    /// Distance that the sprite of a job is rendered above the ground.
+    pub fn get_job_elevation(&self, job_id: usize) -> f32 {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+
+        globals
+            .get::<_, LuaTable>("JobElevationTable")
+            .and_then(|table| table.get::<_, Option<f32>>(job_id))
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Name of the sprite of a headgear, starting with an underscore.
+    pub fn get_headgear_sprite_name(&self, view_id: u16) -> Option<String> {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+
+        globals
+            .get::<_, LuaTable>("AccNameTable")
+            .and_then(|table| table.get::<_, LuaString>(view_id))
+            .ok()
+            .and_then(|name| name.to_str().ok().map(str::to_owned))
+    }
+
+    /// Name of the sprite of a weapon, starting with an underscore. Weapons
+    /// that don't have a sprite of their own use the sprite of their type.
+    pub fn get_weapon_sprite_name(&self, view_id: u32) -> Option<String> {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+        let names = globals.get::<_, LuaTable>("WeaponNameTable").ok()?;
+
+        let name = names.get::<_, LuaString>(view_id).or_else(|_| {
+            let weapon_type = globals.get::<_, LuaTable>("Expansion_Weapon_IDs")?.get::<_, u32>(view_id)?;
+            names.get::<_, LuaString>(weapon_type)
+        });
+
+        name.ok().and_then(|name| name.to_str().ok().map(str::to_owned))
+    }
+
+    /// File name of the icon of a status effect, relative to the effect
+    /// texture directory.
+    pub fn get_status_effect_icon_name(&self, status_effect: u16) -> Option<String> {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+
+        // Icons are grouped by the color of their border.
+        globals
+            .get::<_, LuaTable>("StateIconImgList")
+            .ok()?
+            .pairs::<LuaValue, LuaTable>()
+            .filter_map(Result::ok)
+            .find_map(|(_, icons)| icons.get::<_, LuaString>(status_effect).ok())
+            .and_then(|name| name.to_str().ok().map(str::to_owned))
+    }
+
+    /// Name of a status effect, which is the first line of its description.
+    pub fn get_status_effect_name(&self, status_effect: u16) -> Option<String> {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+
+        globals
+            .get::<_, LuaTable>("StateIconList")
+            .and_then(|table| table.get::<_, LuaTable>(status_effect))
+            .and_then(|information| information.get::<_, LuaTable>("descript"))
+            .and_then(|description| description.get::<_, LuaTable>(1))
+            .and_then(|line| line.get::<_, LuaString>(1))
+            .ok()
+            .and_then(|name| name.to_str().ok().map(str::to_owned))
     }
 
     // TODO: move this to a different class that utilizes the script loader
-    fn get_item_name_from_id(&self, item_id: ItemId, is_identified: bool) -> String {
+    pub fn get_item_name_from_id(&self, item_id: ItemId, is_identified: bool) -> String {
         use mlua::prelude::*;
 
         let globals = self.state.globals();
@@ -97,6 +232,26 @@ end
             .unwrap_or_else(|_| "NOTFOUND".to_owned())
     }
 
+    // TODO: move this to a different class that utilizes the script loader
+    pub fn get_item_description_from_id(&self, item_id: ItemId) -> Vec<String> {
+        use mlua::prelude::*;
+
+        let globals = self.state.globals();
+
+        globals
+            .get::<_, LuaTable>("tbl")
+            .unwrap()
+            .get::<_, LuaTable>(item_id.0)
+            .and_then(|table| table.get::<_, LuaTable>("identifiedDescriptionName"))
+            .map(|lines| {
+                lines
+                    .sequence_values::<LuaString>()
+                    .filter_map(|line| Some(line.ok()?.to_str().ok()?.to_owned()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     // TODO: move this to a different class that utilizes the script loader
     fn get_item_resource_from_id(&self, item_id: ItemId, is_identified: bool) -> String {
         use mlua::prelude::*;
@@ -115,6 +270,83 @@ end
             .unwrap_or_else(|_| "»ç°ú".to_owned())
     }
 
+    pub fn get_achievement_metadata(&self, achievement_id: u32) -> AchievementMetadata {
+        use mlua::prelude::*;
+
+        let table = self
+            .state
+            .globals()
+            .get::<_, LuaTable>("achievement_tbl")
+            .and_then(|table| table.get::<_, LuaTable>(achievement_id));
+
+        let Ok(table) = table else {
+            return AchievementMetadata {
+                name: format!("Achievement {achievement_id}"),
+                category: ACHIEVEMENT_CATEGORIES[0],
+                summary: String::new(),
+                objectives: Vec::new(),
+                title_id: None,
+                score: 0,
+            };
+        };
+
+        let get_string = |table: &LuaTable, key: &str| {
+            table
+                .get::<_, LuaString>(key)
+                .map(|string| string.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+
+        let category = table
+            .get::<_, usize>("major")
+            .ok()
+            .and_then(|major| ACHIEVEMENT_CATEGORIES.get(major))
+            .copied()
+            .unwrap_or(ACHIEVEMENT_CATEGORIES[0]);
+
+        let summary = table
+            .get::<_, LuaTable>("content")
+            .map(|content| get_string(&content, "summary"))
+            .unwrap_or_default();
+
+        let objectives = table
+            .get::<_, LuaTable>("resource")
+            .map(|resource| {
+                resource
+                    .sequence_values::<LuaTable>()
+                    .filter_map(Result::ok)
+                    .map(|objective| (get_string(&objective, "text"), objective.get::<_, u32>("count").unwrap_or(1)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let title_id = table
+            .get::<_, LuaTable>("reward")
+            .ok()
+            .and_then(|reward| reward.get::<_, u32>("title").ok());
+
+        AchievementMetadata {
+            name: get_string(&table, "title"),
+            category,
+            summary,
+            objectives,
+            title_id,
+            score: table.get::<_, u32>("score").unwrap_or_default(),
+        }
+    }
+
+    pub fn get_quest_name(&self, quest_id: u32) -> String {
+        use mlua::prelude::*;
+
+        self.state
+            .globals()
+            .get::<_, LuaTable>("QuestInfoList")
+            .and_then(|table| table.get::<_, LuaTable>(quest_id))
+            .and_then(|quest| quest.get::<_, LuaString>("Title"))
+            .map(|title| title.to_string_lossy().into_owned())
+            .unwrap_or_else(|_| format!("Quest {quest_id}"))
+    }
+
     pub fn load_inventory_item_metadata(
         &self,
         game_file_loader: &mut GameFileLoader,
@@ -93,6 +93,11 @@ pub struct Service {
     /// login_athena.conf.
     pub version: i8,
 
+    /// Packet version of the server. Used to send packets in the format that
+    /// the server expects.
+    #[serde(default, alias = "packetver")]
+    pub packet_version: Option<u32>,
+
     /// Uses the same value from `ServiceType` enum.
     #[serde(default, alias = "langtype", deserialize_with = "language_type_from_index")]
     pub language_type: Option<ServiceType>,
@@ -110,6 +115,11 @@ pub struct Service {
     #[serde(default, alias = "aid")]
     pub game_master_accounts: Vec<GameMasterAccount>,
 
+    /// When present, the client refuses to play back key macros while
+    /// connected to this server.
+    #[serde(default, alias = "disablemacros", deserialize_with = "bool_deserializer")]
+    pub disable_macros: bool,
+
     /// Define each loading screen in the path `/data/texture/À¯ÀúÀÎÅÍÆäÀÌ½º/`
     #[serde(default, alias = "loading")]
     pub loading_images: Option<Vec<LoadingImage>>,
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use korangar_audio::SoundEffect;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize, Timer};
+
+use super::error::LoadError;
+use crate::loaders::GameFileLoader;
+
+#[derive(Default)]
+pub struct SoundLoader {
+    cache: HashMap<String, SoundEffect>,
+}
+
+impl SoundLoader {
+    fn load(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<SoundEffect, LoadError> {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("load sound effect from {}", path.magenta()));
+
+        let bytes = game_file_loader.get(&format!("data\\wav\\{path}")).map_err(LoadError::File)?;
+        let sound_effect = SoundEffect::from_bytes(bytes).map_err(|error| {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to decode sound effect: {:?}", "error".red(), error);
+
+            LoadError::UnsupportedFormat(error.to_string())
+        })?;
+
+        self.cache.insert(path.to_string(), sound_effect.clone());
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(sound_effect)
+    }
+
+    pub fn get(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<SoundEffect, LoadError> {
+        match self.cache.get(path) {
+            Some(sound_effect) => Ok(sound_effect.clone()),
+            None => self.load(path, game_file_loader),
+        }
+    }
+}
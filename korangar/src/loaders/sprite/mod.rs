@@ -6,7 +6,7 @@ use derive_new::new;
 use korangar_debug::logging::{print_debug, Colorize, Timer};
 use korangar_interface::elements::PrototypeElement;
 use ragnarok_bytes::{ByteStream, FromBytes};
-use ragnarok_formats::sprite::{PaletteColor, RgbaImageData, SpriteData};
+use ragnarok_formats::sprite::{Palette, PaletteColor, RgbaImageData, SpriteData};
 use ragnarok_formats::version::InternalVersion;
 use vulkano::buffer::{Buffer, BufferCreateInfo, BufferUsage};
 use vulkano::command_buffer::{
@@ -20,10 +20,12 @@ use vulkano::memory::allocator::{AllocationCreateInfo, MemoryTypeFilter};
 use vulkano::sync::future::FenceSignalFuture;
 use vulkano::sync::GpuFuture;
 
+use super::cache::evict_unused;
 use super::FALLBACK_SPRITE_FILE;
 use crate::graphics::MemoryAllocator;
 use crate::loaders::error::LoadError;
 use crate::loaders::GameFileLoader;
+use crate::system::{measure_load, LoaderKind};
 
 #[derive(Clone, Debug, PrototypeElement)]
 pub struct Sprite {
@@ -44,7 +46,13 @@ pub struct SpriteLoader {
 }
 
 impl SpriteLoader {
-    fn load(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<Sprite>, LoadError> {
+    /// Remove up to `limit` cached sprites that aren't used anymore. Returns
+    /// `true` if there might be more to remove.
+    pub fn evict_unused(&mut self, limit: usize) -> bool {
+        evict_unused(&mut self.cache, limit)
+    }
+
+    fn load(&mut self, path: &str, palette_path: Option<&str>, game_file_loader: &mut GameFileLoader) -> Result<Arc<Sprite>, LoadError> {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load sprite from {}", path.magenta()));
 
@@ -67,8 +75,17 @@ impl SpriteLoader {
         #[cfg(feature = "debug")]
         let cloned_sprite_data = sprite_data.clone();
 
-        let palette = sprite_data.palette.unwrap(); // unwrap_or_default() as soon as i know what
-        // the default palette is
+        // unwrap_or_default() as soon as i know what the default palette is
+        let palette = match palette_path.map(|palette_path| Self::load_palette(palette_path, game_file_loader)) {
+            Some(Ok(palette)) => palette,
+            Some(Err(_error)) => {
+                #[cfg(feature = "debug")]
+                print_debug!("Failed to load palette: {:?}", _error);
+
+                sprite_data.palette.unwrap()
+            }
+            None => sprite_data.palette.unwrap(),
+        };
 
         let rgba_images/*: Vec<Arc<ImmutableImage>>*/ = sprite_data
             .rgba_image_data
@@ -152,7 +169,7 @@ impl SpriteLoader {
             sprite_data: cloned_sprite_data,
         });
 
-        self.cache.insert(path.to_string(), sprite.clone());
+        self.cache.insert(Self::cache_key(path, palette_path), sprite.clone());
 
         #[cfg(feature = "debug")]
         timer.stop();
@@ -160,10 +177,38 @@ impl SpriteLoader {
         Ok(sprite)
     }
 
+    fn load_palette(path: &str, game_file_loader: &mut GameFileLoader) -> Result<Palette, LoadError> {
+        let bytes = game_file_loader.get(&format!("data\\palette\\{path}")).map_err(LoadError::File)?;
+        let mut byte_stream: ByteStream<Option<InternalVersion>> = ByteStream::without_metadata(&bytes);
+
+        Palette::from_bytes(&mut byte_stream).map_err(LoadError::Conversion)
+    }
+
+    fn cache_key(path: &str, palette_path: Option<&str>) -> String {
+        match palette_path {
+            Some(palette_path) => format!("{path}:{palette_path}"),
+            None => path.to_string(),
+        }
+    }
+
     pub fn get(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<Sprite>, LoadError> {
         match self.cache.get(path) {
             Some(sprite) => Ok(sprite.clone()),
-            None => self.load(path, game_file_loader),
+            None => measure_load(LoaderKind::Sprite, || self.load(path, None, game_file_loader)),
+        }
+    }
+
+    /// Get a sprite with its colors replaced by an external palette, for
+    /// example to dye the hair of a character.
+    pub fn get_with_palette(
+        &mut self,
+        path: &str,
+        palette_path: &str,
+        game_file_loader: &mut GameFileLoader,
+    ) -> Result<Arc<Sprite>, LoadError> {
+        match self.cache.get(&Self::cache_key(path, Some(palette_path))) {
+            Some(sprite) => Ok(sprite.clone()),
+            None => self.load(path, Some(palette_path), game_file_loader),
         }
     }
 
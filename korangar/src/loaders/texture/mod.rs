@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use derive_new::new;
 use image::io::Reader as ImageReader;
-use image::{EncodableLayout, ImageFormat, Rgba};
+use image::{EncodableLayout, ImageFormat, Rgba, RgbaImage};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{print_debug, Colorize, Timer};
 use vulkano::buffer::{Buffer, BufferCreateInfo, BufferUsage};
@@ -19,10 +19,21 @@ use vulkano::memory::allocator::{AllocationCreateInfo, MemoryTypeFilter};
 use vulkano::sync::future::FenceSignalFuture;
 use vulkano::sync::GpuFuture;
 
+use super::cache::evict_unused;
 use super::error::LoadError;
 use super::{FALLBACK_BMP_FILE, FALLBACK_PNG_FILE, FALLBACK_TGA_FILE};
 use crate::graphics::MemoryAllocator;
 use crate::loaders::GameFileLoader;
+use crate::system::{measure_load, LoaderKind};
+
+/// Make the magenta background of a bitmap transparent.
+pub fn make_magenta_transparent(image_buffer: &mut RgbaImage) {
+    // These numbers are taken from https://github.com/Duckwhale/RagnarokFileFormats
+    image_buffer
+        .pixels_mut()
+        .filter(|pixel| pixel.0[0] > 0xF0 && pixel.0[1] < 0x10 && pixel.0[2] > 0x0F)
+        .for_each(|pixel| *pixel = Rgba([0; 4]));
+}
 
 #[derive(new)]
 pub struct TextureLoader {
@@ -35,6 +46,12 @@ pub struct TextureLoader {
 }
 
 impl TextureLoader {
+    /// Remove up to `limit` cached textures that aren't used anymore. Returns
+    /// `true` if there might be more to remove.
+    pub fn evict_unused(&mut self, limit: usize) -> bool {
+        evict_unused(&mut self.cache, limit)
+    }
+
     fn load(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<ImageView>, LoadError> {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load texture from {}", path.magenta()));
@@ -70,13 +87,19 @@ impl TextureLoader {
         };
 
         if image_format == ImageFormat::Bmp {
-            // These numbers are taken from https://github.com/Duckwhale/RagnarokFileFormats
-            image_buffer
-                .pixels_mut()
-                .filter(|pixel| pixel.0[0] > 0xF0 && pixel.0[1] < 0x10 && pixel.0[2] > 0x0F)
-                .for_each(|pixel| *pixel = Rgba([0; 4]));
+            make_magenta_transparent(&mut image_buffer);
         }
 
+        let texture = self.upload(&image_buffer);
+        self.cache.insert(path.to_string(), texture.clone());
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(texture)
+    }
+
+    fn upload(&mut self, image_buffer: &RgbaImage) -> Arc<ImageView> {
         let load_buffer = self.load_buffer.get_or_insert_with(|| {
             AutoCommandBufferBuilder::primary(
                 &*self.memory_allocator,
@@ -116,19 +139,25 @@ impl TextureLoader {
             .copy_buffer_to_image(CopyBufferToImageInfo::buffer_image(buffer, image.clone()))
             .unwrap();
 
-        let texture = ImageView::new_default(image).unwrap();
-        self.cache.insert(path.to_string(), texture.clone());
+        ImageView::new_default(image).unwrap()
+    }
 
-        #[cfg(feature = "debug")]
-        timer.stop();
+    /// Create a texture from an image that is generated at runtime. The
+    /// texture is cached under the given name, so it is only uploaded once.
+    pub fn get_or_create(&mut self, name: &str, create_image: impl FnOnce() -> RgbaImage) -> Arc<ImageView> {
+        if let Some(texture) = self.cache.get(name) {
+            return texture.clone();
+        }
 
-        Ok(texture)
+        let texture = self.upload(&create_image());
+        self.cache.insert(name.to_string(), texture.clone());
+        texture
     }
 
     pub fn get(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<ImageView>, LoadError> {
         match self.cache.get(path) {
             Some(texture) => Ok(texture.clone()),
-            None => self.load(path, game_file_loader),
+            None => measure_load(LoaderKind::Texture, || self.load(path, game_file_loader)),
         }
     }
 
@@ -20,36 +20,46 @@
 mod input;
 #[macro_use]
 mod system;
+mod audio;
+mod benchmark;
 mod graphics;
+mod headless;
 mod interface;
 mod inventory;
 mod loaders;
+mod plugins;
+mod updater;
 mod world;
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::net::ToSocketAddrs;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use cgmath::{Vector2, Vector3};
+use cgmath::{EuclideanSpace, Vector2, Vector3, Zero};
 use image::io::Reader as ImageReader;
 use image::{EncodableLayout, ImageFormat};
+use korangar_audio::{AttenuationCurve, AudioChannel, AudioEngine, BackgroundMusicTable, SoundEmitter, SpatialSettings};
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{print_debug, Colorize, Timer};
+use korangar_debug::logging::{print_debug, set_logging_enabled, Colorize, Timer};
 #[cfg(feature = "debug")]
 use korangar_debug::profile_block;
 #[cfg(feature = "debug")]
 use korangar_debug::profiling::Profiler;
 use korangar_interface::application::{Application, FocusState, FontSizeTrait, FontSizeTraitExt, PositionTraitExt};
 use korangar_interface::state::{PlainTrackedState, Remote, RemoteClone, TrackedState, TrackedStateExt, TrackedStateTake, TrackedStateVec};
+use korangar_interface::tooltip::{place_tooltip, TooltipTracker};
 use korangar_interface::Interface;
 use korangar_networking::{
-    DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkingSystem, SellItem, ShopItem,
+    CharacterAppearance, DisconnectReason, HotkeyState, InventoryItemDetails, LoginServerLoginData, MessageColor, Mount, NetworkEvent,
+    NetworkingSystem, Replay, ReplayHeader, ReplayRecorder, SellItem, ShopItem,
 };
 use ragnarok_packets::{
-    BuyShopItemsResult, CharacterId, CharacterInformation, CharacterServerInformation, Friend, HotbarSlot, SellItemsResult, SkillId,
-    SkillType, TilePosition, UnitId, WorldPosition,
+    AccountId, BuyShopItemsResult, CharacterId, CharacterInformation, CharacterServerInformation, EntityId, Friend, HotbarSlot,
+    OnlineState, PetCommand, RefineResult, SellItemsResult, SkillId, SkillType, StatusType, TilePosition, UnitId, WorldPosition,
 };
 use vulkano::device::{Device, DeviceCreateInfo, QueueCreateInfo};
 #[cfg(feature = "debug")]
@@ -59,28 +69,67 @@ use vulkano::instance::debug::{
 use vulkano::instance::{Instance, InstanceCreateFlags, InstanceCreateInfo, InstanceExtensions};
 use vulkano::swapchain::Surface;
 use vulkano::sync::{now, GpuFuture};
-use vulkano::VulkanLibrary;
-use winit::event::{Event, WindowEvent};
+use vulkano::{Version, VulkanLibrary};
+use winit::event::{DeviceEvent, Event, WindowEvent};
 use winit::event_loop::{ControlFlow, EventLoop};
-use winit::window::{Icon, WindowBuilder};
+use winit::window::{Fullscreen, Icon, WindowBuilder};
 
+use crate::audio::{AudioSettings, SoundEvent, SoundState};
+use crate::benchmark::{camera_path, synthetic_entities, Benchmark, GpuTimer, MeasuredPass, DEFAULT_DURATION};
 use crate::graphics::*;
-use crate::input::{InputSystem, UserEvent};
+use crate::input::{parse_macro, InputSystem, KeyBindings, MouseInputMode, MouseSettings, ServerCommandList, UserEvent};
 use crate::interface::application::InterfaceSettings;
+use crate::interface::chat::{
+    item_link_markup, mark_chat_tab_read, move_chat_tab, name_item_links, name_monster_links, ChatCategory, ChatLog, ChatSettings, ChatTab,
+};
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
-use crate::interface::dialog::DialogSystem;
+use crate::interface::dialog::{is_banker, DialogSystem};
+use crate::interface::elements::CharacterSlots;
+use crate::interface::frame_overlay::FrameStatistics;
 use crate::interface::layout::{ScreenPosition, ScreenSize};
 use crate::interface::linked::LinkedElement;
+use crate::interface::minimap::{load_minimap_texture, update_minimap, MinimapData};
+use crate::interface::notifications::{NotificationKind, Notifications};
+use crate::interface::privacy::{display_name, mask_chat_message, mask_whisper, whisper_notification, PrivacySettings};
+use crate::interface::quest_tracker::render_quest_tracker;
+use crate::interface::radial_menu::render_radial_menu;
+use crate::interface::requests::RequestSettings;
 use crate::interface::resource::{ItemSource, Move, SkillSource};
 use crate::interface::windows::*;
-use crate::inventory::{Hotbar, Inventory, SkillTree};
+use crate::interface::world_map::WorldMap;
+use crate::inventory::{
+    Achievements, Attendance, Battlefield, Guild, Hotbar, Inventory, MemorialDungeon, Pet, PlayerStatusEffects, QuestLog, Refinement,
+    SkillTree, HOTBAR_ROW_SIZE, HOTBAR_SLOT_COUNT, PET_HUNGRY_EMOTION,
+};
+use crate::loaders::client::LoginSettings;
 use crate::loaders::*;
+use crate::plugins::{PluginAction, PluginSystem};
 #[cfg(feature = "debug")]
 use crate::system::vulkan_message_callback;
-use crate::system::{choose_physical_device, get_device_extensions, get_layers, GameTimer};
+use crate::system::{
+    choose_physical_device, get_device_extensions, get_layers, install_crash_reporter, open_crash_report, set_crash_adapter_info,
+    set_crash_map_name, take_pending_crash_report, Arguments, CrashPacketCallback, FrameMetrics, FramePacer, GameTimer, LatencyTracker,
+    MetricsExporter, RenderBackend, SettingsFile, TaskScheduler, WindowMode, USAGE,
+};
+use crate::updater::{apply_pending_update, Updater, UpdaterEvent};
 use crate::world::*;
 
 const ROLLING_CUTTER_ID: SkillId = SkillId(2036);
+/// Directory that recorded sessions are saved to.
+const REPLAY_DIRECTORY: &str = "replays";
+const REPLAY_EXTENSION: &str = "krpl";
+/// Distance at which the looping sound of a skill unit becomes inaudible.
+const UNIT_SOUND_RANGE: f32 = 100.0;
+/// Distance in tiles that the player walks ahead when moving with a gamepad.
+const GAMEPAD_WALK_DISTANCE: f32 = 3.0;
+/// Distance in tiles in which monsters and NPCs can be targeted with a gamepad.
+const GAMEPAD_TARGET_RANGE: usize = 9;
+/// Range in tiles that the player can attack from until the server sends the
+/// actual range.
+const DEFAULT_ATTACK_RANGE: usize = 1;
+/// Distance in tiles from which the server accepts talking to an NPC. Value
+/// taken from rAthena.
+const NPC_TALK_RANGE: usize = 14;
 
 // Create the `threads` module.
 #[cfg(feature = "debug")]
@@ -91,9 +140,159 @@ korangar_debug::create_profiler_threads!(threads, {
     Deferred,
 });
 
+/// Number of cache entries that are checked by a single background task.
+const EVICTION_SLICE: usize = 16;
+
+/// Work that runs in the time that is left at the end of a frame.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BackgroundTask {
+    EvictMaps,
+    EvictModels,
+    EvictTextures,
+    EvictSprites,
+    EvictActions,
+}
+
+impl BackgroundTask {
+    /// Tasks that free the resources of the previous map. Maps are evicted
+    /// first, since they hold on to the models, which in turn hold on to the
+    /// textures.
+    const CACHE_MAINTENANCE: [Self; 5] = [
+        Self::EvictMaps,
+        Self::EvictModels,
+        Self::EvictTextures,
+        Self::EvictSprites,
+        Self::EvictActions,
+    ];
+}
+
+/// Looping sound of a skill unit, relative to `data\wav`.
+fn get_unit_sound_file(unit_id: &UnitId) -> Option<&'static str> {
+    match unit_id {
+        UnitId::Firewall => Some("effect\\ef_firewall.wav"),
+        UnitId::Magnus => Some("effect\\ef_magnus.wav"),
+        _ => None,
+    }
+}
+
+/// Warps and hidden entities don't have a name that could be shown.
+fn has_name_label(entity: &Entity) -> bool {
+    matches!(
+        entity.get_entity_type(),
+        EntityType::Player | EntityType::Npc | EntityType::Monster
+    )
+}
+
+/// Closest entity of the given type within the gamepad target range of the
+/// player.
+fn closest_entity(entities: &[Entity], entity_type: EntityType) -> Option<&Entity> {
+    let player_position = entities.first()?.get_grid_position();
+    let distance = |entity: &&Entity| tile_distance(entity.get_grid_position(), player_position);
+
+    entities
+        .iter()
+        .skip(1)
+        .filter(|entity| entity.get_entity_type() == entity_type && !entity.is_dead())
+        .filter(|entity| distance(entity) <= GAMEPAD_TARGET_RANGE)
+        .min_by_key(distance)
+}
+
+/// Skills cast with a gamepad can't be aimed with the mouse, so they target
+/// the closest monster or the tile the player is standing on.
+fn gamepad_target(entities: &[Entity]) -> Option<PickerTarget> {
+    let player_position = entities.first()?.get_grid_position();
+
+    let target = match closest_entity(entities, EntityType::Monster) {
+        Some(monster) => PickerTarget::Entity(monster.get_entity_id()),
+        None => PickerTarget::Tile {
+            x: player_position.x as u16,
+            y: player_position.y as u16,
+        },
+    };
+
+    Some(target)
+}
+
+/// Log in with a saved connection profile, as if it was selected in the login
+/// window.
+fn profile_login_event(client_info: &ClientInfo, login_settings: &LoginSettings, profile_name: &str) -> Result<UserEvent, String> {
+    let profile = login_settings
+        .profiles
+        .iter()
+        .find(|profile| profile.name == profile_name)
+        .ok_or_else(|| format!("There is no connection profile called {profile_name}"))?;
+
+    if !client_info
+        .services
+        .iter()
+        .any(|service| service.service_id() == profile.service_id)
+    {
+        return Err(format!(
+            "The server of the connection profile {profile_name} doesn't exist anymore"
+        ));
+    }
+
+    if profile.password.is_empty() {
+        return Err(format!("The connection profile {profile_name} has no saved password"));
+    }
+
+    Ok(UserEvent::LogIn {
+        service_id: profile.service_id,
+        username: profile.username.clone(),
+        password: profile.password.clone(),
+        packet_version: profile.packet_version,
+        window_layout: profile.window_layout.clone(),
+    })
+}
+
 fn main() {
     const DEFAULT_MAP: &str = "geffen";
 
+    install_crash_reporter();
+
+    let arguments = match Arguments::from_env() {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    if arguments.help {
+        println!("{USAGE}");
+        return;
+    }
+
+    if apply_pending_update() {
+        return;
+    }
+
+    #[cfg(feature = "debug")]
+    if let Some(log_level) = arguments.log_level {
+        set_logging_enabled(log_level == crate::system::LogLevel::Debug);
+    }
+
+    if let Some(config_path) = &arguments.config_path {
+        if let Some(directory) = config_path.parent() {
+            let _ = std::fs::create_dir_all(directory);
+        }
+
+        SettingsFile::set_path(config_path.clone());
+    }
+
+    // Vulkan is the only backend so far, so there is nothing to choose yet.
+    let RenderBackend::Vulkan = arguments.render_backend.unwrap_or(RenderBackend::Vulkan);
+
+    if arguments.headless {
+        headless::run();
+        return;
+    }
+
+    let mut benchmark = arguments.benchmark.clone().map(|map_name| {
+        let duration = arguments.benchmark_duration.map(Duration::from_secs).unwrap_or(DEFAULT_DURATION);
+        Benchmark::new(map_name, duration)
+    });
+
     // We start a frame so that functions trying to start a measurement don't panic.
     #[cfg(feature = "debug")]
     let _measurement = threads::Main::start_frame();
@@ -149,6 +348,10 @@ fn main() {
     let window = WindowBuilder::new()
         .with_title("Korangar".to_string())
         .with_window_icon(Some(icon))
+        .with_fullscreen(match arguments.window_mode {
+            Some(WindowMode::Fullscreen) => Some(Fullscreen::Borderless(None)),
+            Some(WindowMode::Windowed) | None => None,
+        })
         .build(&event_loop)
         .unwrap();
     window.set_cursor_visible(false);
@@ -170,6 +373,18 @@ fn main() {
 
     let present_mode_info = PresentModeInfo::from_device(&physical_device, &surface);
 
+    let device_properties = physical_device.properties();
+    let adapter_name = device_properties.device_name.clone();
+    set_crash_adapter_info(format!(
+        "{} ({:?}, vendor 0x{:04x}, driver {} {}, Vulkan {})",
+        device_properties.device_name,
+        device_properties.device_type,
+        device_properties.vendor_id,
+        device_properties.driver_name.as_deref().unwrap_or("unknown"),
+        device_properties.driver_info.as_deref().unwrap_or_default(),
+        device_properties.api_version,
+    ));
+
     #[cfg(feature = "debug")]
     timer.stop();
 
@@ -182,6 +397,10 @@ fn main() {
             sampler_anisotropy: true,
             #[cfg(feature = "debug")]
             fill_mode_non_solid: true,
+            // The GPU timings of the benchmark reset their queries from the CPU.
+            host_query_reset: benchmark.is_some()
+                && physical_device.api_version() >= Version::V1_2
+                && physical_device.supported_features().host_query_reset,
             ..Default::default()
         },
         queue_create_infos: vec![QueueCreateInfo {
@@ -210,9 +429,14 @@ fn main() {
 
     let mut game_file_loader = GameFileLoader::default();
 
-    game_file_loader.load_archives_from_settings();
+    game_file_loader.load_archives_from_settings(arguments.data_path.as_deref());
     game_file_loader.load_patched_lua_files();
 
+    let background_music_table = game_file_loader
+        .get("data\\mp3nametable.txt")
+        .map(|bytes| BackgroundMusicTable::parse(&bytes))
+        .unwrap_or_default();
+
     let memory_allocator = Arc::new(MemoryAllocator::new(device.clone()));
 
     let font_loader = Rc::new(RefCell::new(FontLoader::new(
@@ -228,7 +452,9 @@ fn main() {
     let mut sprite_loader = SpriteLoader::new(memory_allocator.clone(), queue.clone());
     let mut action_loader = ActionLoader::default();
     let mut effect_loader = EffectLoader::default();
+    let mut sound_loader = SoundLoader::default();
     let script_loader = ScriptLoader::new(&mut game_file_loader);
+    let monster_database = MonsterDatabase::load(&mut game_file_loader);
 
     #[cfg(feature = "debug")]
     timer.stop();
@@ -236,9 +462,11 @@ fn main() {
     #[cfg(feature = "debug")]
     let timer = Timer::new("load resources");
 
+    let initial_map = benchmark.as_ref().map(|benchmark| benchmark.map_name().to_owned());
+
     let mut map = map_loader
         .get(
-            DEFAULT_MAP.to_string(),
+            initial_map.unwrap_or_else(|| DEFAULT_MAP.to_string()),
             &mut game_file_loader,
             &mut buffer_allocator,
             &mut model_loader,
@@ -255,12 +483,22 @@ fn main() {
     let mut swapchain_holder = SwapchainHolder::new(&physical_device, device.clone(), queue.clone(), surface.clone());
     let viewport = swapchain_holder.viewport();
 
+    // The benchmark should measure how fast a frame can be rendered, not the
+    // refresh rate of the monitor.
+    if benchmark.is_some() {
+        swapchain_holder.set_frame_limit(present_mode_info, false);
+    }
+
     #[cfg(feature = "debug")]
     timer.stop();
 
     #[cfg(feature = "debug")]
     let timer = Timer::new("create renderers");
 
+    // The render scale is needed to create the deferred renderer.
+    let graphics_settings = PlainTrackedState::new(GraphicsSettings::new());
+    let mut current_render_scale = graphics_settings.get().render_scale;
+
     let mut deferred_renderer = DeferredRenderer::new(
         memory_allocator.clone(),
         &mut buffer_allocator,
@@ -270,6 +508,7 @@ fn main() {
         swapchain_holder.swapchain_format(),
         viewport.clone(),
         swapchain_holder.window_size_u32(),
+        current_render_scale,
     );
 
     let mut interface_renderer = InterfaceRenderer::new(
@@ -297,11 +536,33 @@ fn main() {
     #[cfg(feature = "debug")]
     let timer = Timer::new("load settings");
 
-    let mut input_system = InputSystem::new();
-    let graphics_settings = PlainTrackedState::new(GraphicsSettings::new());
+    let mut key_bindings = PlainTrackedState::new(KeyBindings::new());
+    let mouse_settings = PlainTrackedState::new(MouseSettings::new());
+    let mut input_system = InputSystem::new(key_bindings.clone(), mouse_settings.clone());
 
     let mut shadow_detail = graphics_settings.mapped(|settings| &settings.shadow_detail).new_remote();
     let mut framerate_limit = graphics_settings.mapped(|settings| &settings.frame_limit).new_remote();
+    let show_frame_overlay = graphics_settings.mapped(|settings| &settings.show_frame_overlay).new_remote();
+    let export_metrics = graphics_settings.mapped(|settings| &settings.export_metrics).new_remote();
+    let health_bar_visibility = graphics_settings.mapped(|settings| &settings.health_bar_visibility).new_remote();
+    let smooth_movement = graphics_settings.mapped(|settings| &settings.smooth_movement).new_remote();
+    let interpolation_delay = graphics_settings.mapped(|settings| &settings.interpolation_delay).new_remote();
+    let corpse_duration = graphics_settings.mapped(|settings| &settings.corpse_duration).new_remote();
+    let bloom_intensity = graphics_settings.mapped(|settings| &settings.bloom_intensity).new_remote();
+    let bloom_threshold = graphics_settings.mapped(|settings| &settings.bloom_threshold).new_remote();
+    let mut render_scale = graphics_settings.mapped(|settings| &settings.render_scale).new_remote();
+    let mut dynamic_render_scale = graphics_settings.mapped(|settings| &settings.dynamic_render_scale).new_remote();
+    let sharpen_upscaling = graphics_settings.mapped(|settings| &settings.sharpen_upscaling).new_remote();
+    let mut dynamic_resolution = DynamicRenderScale::new(current_render_scale);
+    let mut render_scale_changed = false;
+
+    let audio_settings = PlainTrackedState::new(AudioSettings::new());
+    let mut audio_settings_remote = audio_settings.new_remote();
+
+    let chat_settings = PlainTrackedState::new(ChatSettings::new());
+    let mut privacy_settings = PlainTrackedState::new(PrivacySettings::new());
+    let login_settings = PlainTrackedState::new(LoginSettings::new());
+    let request_settings = PlainTrackedState::new(RequestSettings::new());
 
     #[cfg(feature = "debug")]
     let render_settings = PlainTrackedState::new(RenderSettings::new());
@@ -332,6 +593,9 @@ fn main() {
         .map(|_| shadow_renderer.create_render_target(shadow_detail.get().into_resolution()))
         .collect::<Vec<<ShadowRenderer as Renderer>::Target>>();
 
+    // The GPU time is needed by the benchmark and the dynamic render scale.
+    let gpu_timer = GpuTimer::new(device.clone(), queue_family_index, screen_targets.len());
+
     #[cfg(feature = "debug")]
     timer.stop();
 
@@ -339,9 +603,21 @@ fn main() {
     let timer = Timer::new("initialize interface");
 
     let mut application = InterfaceSettings::load_or_default();
+    application.set_monitor_scaling(
+        surface
+            .object()
+            .unwrap()
+            .downcast_ref::<winit::window::Window>()
+            .unwrap()
+            .scale_factor() as f32,
+    );
+    let mut automatic_scaling = application.get_automatic_scaling().new_remote();
     let mut interface = Interface::new(swapchain_holder.window_screen_size());
     let mut focus_state = FocusState::default();
+    let mut tooltip_tracker = TooltipTracker::default();
+    let mut ime_allowed = false;
     let mut mouse_cursor = MouseCursor::new(&mut game_file_loader, &mut sprite_loader, &mut action_loader);
+    let emotions = Emotions::new(&mut game_file_loader, &mut sprite_loader, &mut action_loader);
     let mut dialog_system = DialogSystem::default();
     let mut show_interface = true;
 
@@ -352,6 +628,16 @@ fn main() {
     let timer = Timer::new("initialize timer");
 
     let mut game_timer = GameTimer::new();
+    let mut frame_pacer = FramePacer::new(
+        surface
+            .object()
+            .unwrap()
+            .downcast_ref::<winit::window::Window>()
+            .unwrap()
+            .current_monitor()
+            .and_then(|monitor| monitor.refresh_rate_millihertz()),
+    );
+    let mut background_tasks = TaskScheduler::default();
 
     #[cfg(feature = "debug")]
     timer.stop();
@@ -377,7 +663,7 @@ fn main() {
     let client_info = load_client_info(&mut game_file_loader);
 
     #[cfg(not(feature = "debug"))]
-    let mut networking_system = NetworkingSystem::spawn();
+    let mut networking_system = NetworkingSystem::spawn_with_callback(CrashPacketCallback);
     #[cfg(feature = "debug")]
     let packet_callback = {
         // SAFETY: This function leaks memory, but it's fine since we only call
@@ -394,33 +680,116 @@ fn main() {
     let mut shop_items: PlainTrackedState<Vec<ShopItem<ResourceMetadata>>> = PlainTrackedState::default();
     let mut sell_items: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>> = PlainTrackedState::default();
     let mut currently_deleting: Option<CharacterId> = None;
+    let mut character_deletion_dates: PlainTrackedState<HashMap<CharacterId, Instant>> = PlainTrackedState::default();
     let mut saved_player_name = String::new();
+    let mut saved_character_id: Option<CharacterId> = None;
+    let mut current_map_name = String::new();
     let mut move_request: PlainTrackedState<Option<usize>> = PlainTrackedState::default();
+    let mut character_appearance: PlainTrackedState<Option<PlayerAppearance>> = PlainTrackedState::default();
     let mut saved_login_server_address = None;
     let mut saved_password = String::new();
     let mut saved_username = String::new();
-    let mut saved_slot_count = 0;
+    let mut saved_character_slots = CharacterSlots::default();
+    let mut replaying = false;
+    let mut switching_account = false;
+    let mut window_layout: Option<String> = None;
+    let record_replays = PlainTrackedState::new(false);
+
+    if benchmark.is_none() {
+        interface.open_window(
+            &application,
+            &mut focus_state,
+            &LoginWindow::new(&client_info, login_settings.clone(), privacy_settings.clone()),
+        );
+    }
+
+    let mut updater = Updater::new();
+    updater.check_for_update();
+
+    let mut startup_events = Vec::new();
+
+    if let Some(profile_name) = &arguments.profile {
+        match profile_login_event(&client_info, &login_settings.get(), profile_name) {
+            Ok(event) => startup_events.push(event),
+            Err(message) => interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message)),
+        }
+    }
 
-    interface.open_window(&application, &mut focus_state, &LoginWindow::new(&client_info));
+    if let Some(report_path) = take_pending_crash_report() {
+        interface.open_window(&application, &mut focus_state, &CrashReportWindow::new(report_path));
+    }
 
     #[cfg(feature = "debug")]
     timer.stop();
 
     let mut particle_holder = ParticleHolder::default();
+    let mut combat_texts = CombatTexts::default();
     let mut effect_holder = EffectHolder::default();
-    let mut entities = Vec::<Entity>::new();
+    let mut entities = EntityStore::<Entity>::default();
+    let mut guild_emblem_cache = GuildEmblemCache::default();
     let mut player_inventory = Inventory::default();
     let mut player_skill_tree = SkillTree::default();
-    let mut hotbar = Hotbar::default();
+    let mut hotbar = Hotbar::new();
+    let mut quest_log = QuestLog::default();
+    let mut achievements = Achievements::default();
+    let mut notifications = Notifications::default();
+    let mut frame_statistics = FrameStatistics::default();
+    let mut metrics_exporter = MetricsExporter::default();
+    let mut attendance = Attendance::default();
+    let mut refinement = Refinement::default();
+    let mut pet = Pet::default();
+    let mut player_status_effects = PlayerStatusEffects::default();
+    let mut guild = Guild::default();
+    let server_commands = Rc::new(ServerCommandList::load());
+    let mut memorial_dungeon = MemorialDungeon::default();
+    let mut battlefield = Battlefield::default();
+    let mut audio_engine = AudioEngine::new();
+    audio_settings.get().apply(&mut audio_engine);
+    let mut sound_state = SoundState::new(&mut sound_loader, &mut game_file_loader);
+    let mut background_music_track: PlainTrackedState<Option<String>> = PlainTrackedState::default();
+    let mut ambient_emitters: Vec<SoundEmitter> = Vec::new();
+    let mut skill_unit_emitters: HashMap<EntityId, SoundEmitter> = HashMap::new();
+    let mut previous_listener_position: Option<Vector3<f32>> = None;
+    let mut latency_tracker = LatencyTracker::default();
+    let mut plugin_system = PluginSystem::load();
+
+    if let Some(benchmark) = &mut benchmark {
+        benchmark.measure_path_finding(&map);
+
+        for entity_data in synthetic_entities(map.get_size()) {
+            let npc = Npc::new(
+                &mut game_file_loader,
+                &mut sprite_loader,
+                &mut action_loader,
+                &script_loader,
+                &map,
+                entity_data,
+                game_timer.get_client_tick(),
+            );
+
+            entities.insert(Entity::Npc(npc));
+        }
+    }
+
+    let mut minimap_data = PlainTrackedState::new(MinimapData::default());
+    let mut party_member_positions: HashMap<AccountId, Vector2<usize>> = HashMap::new();
+    let minimap_zoom_level = PlainTrackedState::new(1);
+    let world_map = WorldMap::load();
+    let mut gamepad_walk_target = None;
+    let mut player_attack_range = DEFAULT_ATTACK_RANGE;
+    let mut bank_balance = PlainTrackedState::<i64>::default();
+    let show_quest_markers = PlainTrackedState::new(true);
+    let show_quest_tracker = PlainTrackedState::new(true);
+    let chat_input_history = PlainTrackedState::<Vec<String>>::default();
+    let mut chat_tabs = application.get_chat_tabs();
+    let mut selected_chat_tab = PlainTrackedState::new(0);
+    let mut chat_log = ChatLog::default();
 
     let welcome_string = format!(
         "Welcome to ^ffff00★^000000 ^ff8800Korangar^000000 ^ffff00★^000000 version ^ff8800{}^000000!",
         env!("CARGO_PKG_VERSION")
     );
-    let mut chat_messages = PlainTrackedState::new(vec![ChatMessage {
-        text: welcome_string,
-        color: MessageColor::Server,
-    }]);
+    let mut chat_messages = PlainTrackedState::new(vec![ChatMessage::new(welcome_string, MessageColor::Server)]);
 
     let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(3).build().unwrap();
 
@@ -430,9 +799,10 @@ fn main() {
                 event: WindowEvent::CloseRequested,
                 ..
             } => {
-                // FIX: For some reason GraphicsSettings is not dropped unless we use it in this
-                // scope. This fixes it.
+                // FIX: For some reason GraphicsSettings and AudioSettings are not dropped unless
+                // we use them in this scope. This fixes it.
                 let _ = &graphics_settings;
+                let _ = &audio_settings;
                 control_flow.set_exit()
             }
             Event::WindowEvent {
@@ -451,10 +821,20 @@ fn main() {
                 });
                 swapchain_holder.update_window_size(window_size.into());
             }
+            Event::WindowEvent {
+                event: WindowEvent::ScaleFactorChanged { scale_factor, .. },
+                ..
+            } => {
+                if application.set_monitor_scaling(scale_factor as f32) {
+                    interface.schedule_resolve();
+                }
+            }
             Event::WindowEvent {
                 event: WindowEvent::Focused(focused),
                 ..
             } => {
+                audio_engine.set_focused(focused);
+
                 if !focused {
                     input_system.reset();
                     focus_state.remove_focus();
@@ -480,6 +860,14 @@ fn main() {
                 event: WindowEvent::MouseWheel { delta, .. },
                 ..
             } => input_system.update_mouse_wheel(delta),
+            Event::WindowEvent {
+                event: WindowEvent::Touch(touch),
+                ..
+            } => input_system.update_touch(touch),
+            Event::DeviceEvent {
+                event: DeviceEvent::MouseMotion { delta },
+                ..
+            } => input_system.update_raw_mouse_motion(delta),
             Event::WindowEvent {
                 event: WindowEvent::KeyboardInput { input, .. },
                 ..
@@ -492,6 +880,10 @@ fn main() {
                 event: WindowEvent::ReceivedCharacter(character),
                 ..
             } => input_system.buffer_character(character),
+            Event::WindowEvent {
+                event: WindowEvent::Ime(ime),
+                ..
+            } => input_system.update_ime(ime),
             Event::MainEventsCleared => {
                 #[cfg(feature = "debug")]
                 let _measurement = threads::Main::start_frame();
@@ -499,9 +891,18 @@ fn main() {
                 #[cfg(feature = "debug")]
                 let timer_measurement = Profiler::start_measurement("update timers");
 
+                let frame_start = Instant::now();
+                frame_pacer.begin_frame(frame_start);
                 input_system.update_delta();
 
+                // Make the interface easier to use with a finger once a touch screen is used.
+                if application.set_touch_input(input_system.is_touch_active()) {
+                    interface.schedule_resolve();
+                }
+
                 let delta_time = game_timer.update();
+                frame_statistics.add_frame(delta_time);
+                plugin_system.update(delta_time);
                 let day_timer = game_timer.get_day_timer();
                 let animation_timer = game_timer.get_animation_timer();
                 let client_tick = game_timer.get_client_tick();
@@ -511,25 +912,72 @@ fn main() {
 
                 let network_events = networking_system.get_events();
 
-                let (user_events, hovered_element, focused_element, mouse_target) = input_system.user_events(
+                let (mut user_events, hovered_element, focused_element, mouse_target) = input_system.user_events(
                     &mut interface,
                     &application,
                     &mut focus_state,
                     &mut picker_targets[swapchain_holder.get_image_number()],
                     &mut mouse_cursor,
+                    &mut notifications,
                     #[cfg(feature = "debug")]
                     &render_settings,
                     swapchain_holder.window_size(),
                     client_tick,
                 );
 
+                user_events.extend(startup_events.drain(..));
+
+                let mouse_target = match mouse_target.is_none() && input_system.is_gamepad_active() {
+                    true => gamepad_target(&entities),
+                    false => mouse_target,
+                };
+
+                // Input methods are only allowed while an element is focused, so that they
+                // don't swallow hotkeys.
+                if focused_element.is_some() != ime_allowed {
+                    ime_allowed = focused_element.is_some();
+
+                    surface
+                        .object()
+                        .unwrap()
+                        .downcast_ref::<winit::window::Window>()
+                        .unwrap()
+                        .set_ime_allowed(ime_allowed);
+                }
+
+                // Tooltips are only shown while nothing is being dragged.
+                let tooltip_element = match input_system.get_mouse_mode() {
+                    MouseInputMode::None => hovered_element.as_ref(),
+                    _ => None,
+                };
+
+                if let Some(tooltip_window) = tooltip_tracker.update(tooltip_element, application.get_tooltip_delay()) {
+                    interface.open_window(&application, &mut focus_state, tooltip_window.as_ref());
+                }
+
                 #[cfg(feature = "debug")]
                 let picker_measurement = Profiler::start_measurement("update picker target");
 
+                // Names are only shown once they are known, so all of them are requested while
+                // they are shown.
+                if input_system.show_all_names() {
+                    for entity in entities.iter_mut() {
+                        if entity.are_details_unavailable() && has_name_label(entity) {
+                            match networking_system.entity_details(entity.get_entity_id()) {
+                                Some(details) => entity.set_details(details),
+                                None => entity.set_details_requested(),
+                            }
+                        }
+                    }
+                }
+
                 if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
-                    if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
-                        if entity.are_details_unavailable() && networking_system.entity_details(entity_id).is_ok() {
-                            entity.set_details_requested();
+                    if let Some(entity) = entities.get_mut(entity_id) {
+                        if entity.are_details_unavailable() {
+                            match networking_system.entity_details(entity_id) {
+                                Some(details) => entity.set_details(details),
+                                None => entity.set_details_requested(),
+                            }
                         }
 
                         match entity.get_entity_type() {
@@ -561,28 +1009,39 @@ fn main() {
                         }
                         NetworkEvent::LoginServerDisconnected { reason } => {
                             if reason != DisconnectReason::ClosedByClient {
-                                // TODO: Make this an on-screen popup.
                                 #[cfg(feature = "debug")]
                                 print_debug!("Disconnection from the character server with error");
 
+                                notifications.push(NotificationKind::Warning, "Lost connection to the login server, reconnecting");
+
                                 let socket_address = saved_login_server_address.unwrap();
                                 networking_system.connect_to_login_server(socket_address, &saved_username, &saved_password);
                             }
                         },
-                        NetworkEvent::CharacterServerConnected { normal_slot_count } => {
-                            saved_slot_count = normal_slot_count;
+                        NetworkEvent::CharacterServerConnected {
+                            normal_slot_count,
+                            premium_slot_count,
+                        } => {
+                            saved_character_slots = CharacterSlots {
+                                normal: normal_slot_count,
+                                premium: premium_slot_count,
+                                pages: 0,
+                            };
+                            saved_characters.set(Vec::new());
                             let _ = networking_system.request_character_list();
                         },
+                        NetworkEvent::CharacterListPageCount { page_count } => saved_character_slots.pages = page_count,
                         NetworkEvent::CharacterServerConnectionFailed { message, .. } => {
                             networking_system.disconnect_from_character_server();
                             interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()));
                         },
                         NetworkEvent::CharacterServerDisconnected { reason } => {
                             if reason != DisconnectReason::ClosedByClient {
-                                // TODO: Make this an on-screen popup.
                                 #[cfg(feature = "debug")]
                                 print_debug!("Disconnection from the character server with error");
 
+                                notifications.push(NotificationKind::Warning, "Lost connection to the character server, reconnecting");
+
                                 let login_data = saved_login_data.as_ref().unwrap();
                                 let server = saved_character_server.clone().unwrap();
                                 networking_system.connect_to_character_server(login_data, server);
@@ -590,18 +1049,32 @@ fn main() {
                         },
                         NetworkEvent::MapServerDisconnected {  reason } => {
                             if reason != DisconnectReason::ClosedByClient {
-                                // TODO: Make this an on-screen popup.
                                 #[cfg(feature = "debug")]
                                 print_debug!("Disconnection from the map server with error");
+
+                                notifications.push(NotificationKind::Warning, "Lost connection to the map server");
                             }
 
-                            let login_data = saved_login_data.as_ref().unwrap();
-                            let server = saved_character_server.clone().unwrap();
-                            networking_system.connect_to_character_server(login_data, server);
+                            // There is no character server to return to after a replay.
+                            let replay_finished = std::mem::take(&mut replaying);
+                            let return_to_login = replay_finished || std::mem::take(&mut switching_account);
+
+                            if return_to_login {
+                                saved_login_data = None;
+                                saved_character_server = None;
+                                saved_characters.set(Vec::new());
+                            } else {
+                                let login_data = saved_login_data.as_ref().unwrap();
+                                let server = saved_character_server.clone().unwrap();
+                                networking_system.connect_to_character_server(login_data, server);
+                            }
 
                             entities.clear();
                             particle_holder.clear();
+                            combat_texts.clear();
                             effect_holder.clear();
+                            ambient_emitters.clear();
+                            skill_unit_emitters.clear();
 
                             map = map_loader
                                 .get(
@@ -615,8 +1088,17 @@ fn main() {
 
                             interface.close_all_windows_except(&mut focus_state);
 
-                            let character_selection_window = CharacterSelectionWindow::new(saved_characters.new_remote(), move_request.new_remote(), saved_slot_count);
-                            interface.open_window(&application, &mut focus_state, &character_selection_window);
+                            match return_to_login {
+                                true => interface.open_window(
+                                    &application,
+                                    &mut focus_state,
+                                    &LoginWindow::new(&client_info, login_settings.clone(), privacy_settings.clone()),
+                                ),
+                                false => {
+                                    let character_selection_window = CharacterSelectionWindow::new(saved_characters.new_remote(), move_request.new_remote(), character_deletion_dates.new_remote(), privacy_settings.new_remote(), saved_character_slots);
+                                    interface.open_window(&application, &mut focus_state, &character_selection_window);
+                                }
+                            }
 
                             start_camera.set_focus_point(cgmath::Point3::new(600.0, 0.0, 240.0));
                             directional_shadow_camera.set_focus_point(cgmath::Point3::new(600.0, 0.0, 240.0));
@@ -624,8 +1106,28 @@ fn main() {
                         },
                         NetworkEvent::AccountId(..) => {},
                         NetworkEvent::CharacterList { characters } => {
-                            saved_characters.set(characters);
-                            let character_selection_window = CharacterSelectionWindow::new(saved_characters.new_remote(), move_request.new_remote(), saved_slot_count);
+                            // The list is received one page at a time, so only the characters in
+                            // the same slots are replaced.
+                            character_deletion_dates.mutate(|deletion_dates| {
+                                for character in &characters {
+                                    match character.deletion_reverse_date > 0 {
+                                        true => {
+                                            let waiting_time = Duration::from_secs(character.deletion_reverse_date as u64);
+                                            deletion_dates.insert(character.character_id, Instant::now() + waiting_time);
+                                        }
+                                        false => {
+                                            deletion_dates.remove(&character.character_id);
+                                        }
+                                    }
+                                }
+                            });
+                            saved_characters.mutate(|saved_characters| {
+                                for character in characters {
+                                    saved_characters.retain(|saved_character| saved_character.character_number != character.character_number);
+                                    saved_characters.push(character);
+                                }
+                            });
+                            let character_selection_window = CharacterSelectionWindow::new(saved_characters.new_remote(), move_request.new_remote(), character_deletion_dates.new_remote(), privacy_settings.new_remote(), saved_character_slots);
 
                             // TODO: this will do one unnecessary restore_focus. check if
                             // that will be problematic
@@ -639,16 +1141,34 @@ fn main() {
                             let character_id = currently_deleting.take().unwrap();
 
                             saved_characters.retain(|character| character.character_id != character_id);
+                            character_deletion_dates.mutate(|deletion_dates| {
+                                deletion_dates.remove(&character_id);
+                            });
+
+                            interface.close_window_with_class(&mut focus_state, CharacterDeletionWindow::WINDOW_CLASS);
                         },
-                        NetworkEvent::CharacterDeletionFailed { message, .. } => {
+                        NetworkEvent::CharacterDeletionReserved { character_id, waiting_time } => {
+                            currently_deleting = None;
+                            character_deletion_dates.mutate(|deletion_dates| {
+                                deletion_dates.insert(character_id, Instant::now() + waiting_time);
+                            });
+                        }
+                        NetworkEvent::CharacterDeletionCanceled { character_id } => {
+                            character_deletion_dates.mutate(|deletion_dates| {
+                                deletion_dates.remove(&character_id);
+                            });
+                        }
+                        NetworkEvent::CharacterDeletionFailed { message, .. }
+                        | NetworkEvent::CharacterDeletionReservationFailed { message, .. }
+                        | NetworkEvent::CharacterDeletionConfirmationFailed { message, .. } => {
                             currently_deleting = None;
                             interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()))
                         }
+                        NetworkEvent::CharacterDeletionCancellationFailed { message, .. } => {
+                            interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()))
+                        }
                         NetworkEvent::CharacterSelected { login_data, map_name } => {
                             let saved_login_data = saved_login_data.as_ref().unwrap();
-                            networking_system.disconnect_from_character_server();
-                            networking_system.connect_to_map_server(saved_login_data, login_data);
-
                             let character_information = saved_characters
                                 .get()
                                 .iter()
@@ -656,9 +1176,38 @@ fn main() {
                                 .cloned()
                                 .unwrap();
 
+                            // A replay is already connected to the map server, so it is never recorded
+                            // again.
+                            let replay_recorder = match *record_replays.get() && !replaying {
+                                true => {
+                                    let header = ReplayHeader {
+                                        account_id: saved_login_data.account_id,
+                                        map_name: map_name.clone(),
+                                        character_information: character_information.clone(),
+                                    };
+                                    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                                    let path = format!("{REPLAY_DIRECTORY}/{timestamp}.{REPLAY_EXTENSION}");
+
+                                    match std::fs::create_dir_all(REPLAY_DIRECTORY).and_then(|_| ReplayRecorder::create(path, &header)) {
+                                        Ok(replay_recorder) => Some(replay_recorder),
+                                        Err(..) => {
+                                            chat_messages.push(ChatMessage::new(
+                                                "Failed to start recording the session".to_owned(),
+                                                MessageColor::Error,
+                                            ));
+                                            None
+                                        }
+                                    }
+                                }
+                                false => None,
+                            };
+
+                            networking_system.disconnect_from_character_server();
+                            networking_system.connect_to_map_server(saved_login_data, login_data, replay_recorder);
+
                             map = map_loader
                                 .get(
-                                    map_name,
+                                    map_name.clone(),
                                     &mut game_file_loader,
                                     &mut buffer_allocator,
                                     &mut model_loader,
@@ -666,7 +1215,30 @@ fn main() {
                                 )
                                 .unwrap();
 
+                            current_map_name = map_name;
+                            set_crash_map_name(&current_map_name);
+                            BackgroundTask::CACHE_MAINTENANCE.into_iter().for_each(|task| background_tasks.push(task));
+                            minimap_data.mutate(|minimap_data| {
+                                minimap_data.map_size = map.get_size();
+                                minimap_data.texture = Some(load_minimap_texture(
+                                    &current_map_name,
+                                    &map,
+                                    &mut texture_loader,
+                                    &mut game_file_loader,
+                                ));
+                            });
+                            audio_engine.play_background_music_track(background_music_table.get(&current_map_name));
+                            background_music_track.set(audio_engine.get_background_music_track().map(str::to_owned));
+                            ambient_emitters = map.start_sound_emitters(&mut audio_engine, &mut sound_loader, &mut game_file_loader);
+                            skill_unit_emitters.clear();
                             saved_player_name = character_information.name.clone();
+                            saved_character_id = Some(character_information.character_id);
+                            player_inventory.set_zeny(character_information.money as u32);
+                            guild.clear();
+                            memorial_dungeon.clear();
+                            battlefield.clear();
+                            latency_tracker.clear();
+                            player_status_effects.clear();
 
                             let player = Player::new(
                                 &mut game_file_loader,
@@ -682,7 +1254,7 @@ fn main() {
                             let player = Entity::Player(player);
 
                             player_camera.set_focus_point(player.get_position());
-                            entities.push(player);
+                            entities.insert(player);
 
                             // TODO: this will do one unnecessary restore_focus. check if
                             // that will be problematic
@@ -691,14 +1263,26 @@ fn main() {
                             interface.open_window(
                                 &application,
                                 &mut focus_state,
-                                &ChatWindow::new(chat_messages.new_remote(), font_loader.clone()),
+                                &ChatWindow::new(
+                                    chat_messages.new_remote(),
+                                    chat_input_history.clone(),
+                                    chat_tabs.new_remote(),
+                                    selected_chat_tab.clone(),
+                                    chat_settings.new_remote(),
+                                    font_loader.clone(),
+                                ),
+                            );
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &HotbarWindow::new(hotbar.get_skills(), hotbar.get_smart_cast()),
                             );
-                            interface.open_window(&application, &mut focus_state, &HotbarWindow::new(hotbar.get_skills()));
 
                             // Put the dialog system in a well-defined state.
                             dialog_system.close_dialog();
 
                             particle_holder.clear();
+                            combat_texts.clear();
                             let _ = networking_system.map_loaded();
                             // TODO: This is just a workaround until I find a better solution to make the
                             // cursor always look correct.
@@ -707,21 +1291,31 @@ fn main() {
                         },
                         NetworkEvent::CharacterCreated { character_information } => {
                             saved_characters.push(character_information);
+                            character_appearance.set(None);
 
                             interface.close_window_with_class(&mut focus_state, CharacterCreationWindow::WINDOW_CLASS);
                         },
                         NetworkEvent::CharacterCreationFailed { message, .. } => {
                             interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()));
                         },
-                        NetworkEvent::CharacterSlotSwitched => {},
+                        NetworkEvent::CharacterSlotSwitched => {
+                            saved_characters.set(Vec::new());
+                            let _ = networking_system.request_character_list();
+                        },
                         NetworkEvent::CharacterSlotSwitchFailed => {
                             interface.open_window(&application, &mut focus_state, &ErrorWindow::new("Failed to switch character slots".to_owned()));
                         },
+                        NetworkEvent::CharacterRenamed => {
+                            interface.close_window_with_class(&mut focus_state, CharacterRenameWindow::WINDOW_CLASS);
+
+                            saved_characters.set(Vec::new());
+                            let _ = networking_system.request_character_list();
+                        },
+                        NetworkEvent::CharacterRenameFailed { message, .. } => {
+                            interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()));
+                        },
                         NetworkEvent::AddEntity(entity_appeared_data) => {
-                            // Sometimes (like after a job change) the server will tell the client
-                            // that a new entity appeared, even though it was already on screen. So
-                            // to prevent the entity existing twice, we remove the old one.
-                            entities.retain(|entity| entity.get_entity_id() != entity_appeared_data.entity_id);
+                            plugin_system.entity_spawned(entity_appeared_data.entity_id, entity_appeared_data.job);
 
                             let npc = Npc::new(
                                 &mut game_file_loader,
@@ -733,14 +1327,33 @@ fn main() {
                                 client_tick,
                             );
 
+                            // Sometimes (like after a job change) the server will tell the client
+                            // that a new entity appeared, even though it was already on screen. So
+                            // to prevent the entity existing twice, the old one is replaced.
                             let npc = Entity::Npc(npc);
-                            entities.push(npc);
+                            entities.insert(npc);
                         }
                         NetworkEvent::RemoveEntity(entity_id) => {
-                            entities.retain(|entity| entity.get_entity_id() != entity_id);
+                            plugin_system.entity_despawned(entity_id);
+                            entities.remove(entity_id);
+                        }
+                        NetworkEvent::EntityDied(entity_id) => {
+                            // Dead monsters can stay on the map as a corpse, everything else
+                            // disappears right away.
+                            let corpse_duration = *corpse_duration.get();
+
+                            match entities.get_mut(entity_id) {
+                                Some(entity) if corpse_duration > 0 && entity.get_entity_type() == EntityType::Monster => {
+                                    entity.die(client_tick);
+                                }
+                                _ => {
+                                    plugin_system.entity_despawned(entity_id);
+                                    entities.remove(entity_id);
+                                }
+                            }
                         }
                         NetworkEvent::EntityMove(entity_id, position_from, position_to, starting_timestamp) => {
-                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+                            let entity = entities.get_mut(entity_id);
 
                             if let Some(entity) = entity {
                                 let position_from = Vector2::new(position_from.x, position_from.y);
@@ -751,20 +1364,30 @@ fn main() {
                                 entity.generate_steps_vertex_buffer(device.clone(), &map);*/
                             }
                         }
+                        NetworkEvent::EntityForcedMove(entity_id, position) => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                let position = Vector2::new(position.x as usize, position.y as usize);
+                                entity.force_move_to(position, client_tick);
+                            }
+                        }
                         NetworkEvent::PlayerMove(position_from, position_to, starting_timestamp) => {
                             let position_from = Vector2::new(position_from.x, position_from.y);
                             let position_to = Vector2::new(position_to.x, position_to.y);
-                            entities[0].move_from_to(&map, position_from, position_to, starting_timestamp);
+
+                            if let Some(player) = entities.first_mut() {
+                                player.confirm_move(&map, position_from, position_to, starting_timestamp, client_tick);
+                            }
 
                             /*#[cfg(feature = "debug")]
                             entities[0].generate_steps_vertex_buffer(device.clone(), &map);*/
                         }
                         NetworkEvent::ChangeMap(map_name, player_position) => {
-                            entities.truncate(1);
+                            entities.retain_player();
+                            party_member_positions.clear();
 
                             map = map_loader
                                 .get(
-                                    map_name,
+                                    map_name.clone(),
                                     &mut game_file_loader,
                                     &mut buffer_allocator,
                                     &mut model_loader,
@@ -772,11 +1395,33 @@ fn main() {
                                 )
                                 .unwrap();
 
+                            current_map_name = map_name;
+                            set_crash_map_name(&current_map_name);
+                            BackgroundTask::CACHE_MAINTENANCE.into_iter().for_each(|task| background_tasks.push(task));
+                            minimap_data.mutate(|minimap_data| {
+                                minimap_data.map_size = map.get_size();
+                                minimap_data.texture = Some(load_minimap_texture(
+                                    &current_map_name,
+                                    &map,
+                                    &mut texture_loader,
+                                    &mut game_file_loader,
+                                ));
+                            });
+                            audio_engine.play_background_music_track(background_music_table.get(&current_map_name));
+                            background_music_track.set(audio_engine.get_background_music_track().map(str::to_owned));
+                            ambient_emitters = map.start_sound_emitters(&mut audio_engine, &mut sound_loader, &mut game_file_loader);
+                            skill_unit_emitters.clear();
+                            guild.show_notice(&mut notifications);
+
                             let player_position = Vector2::new(player_position.x as usize, player_position.y as usize);
-                            entities[0].set_position(&map, player_position, client_tick);
-                            player_camera.set_focus_point(entities[0].get_position());
+
+                            if let Some(player) = entities.first_mut() {
+                                player.set_position(&map, player_position, client_tick);
+                                player_camera.set_focus_point(player.get_position());
+                            }
 
                             particle_holder.clear();
+                            combat_texts.clear();
                             effect_holder.clear();
                             let _ = networking_system.map_loaded();
 
@@ -786,54 +1431,114 @@ fn main() {
                         }
                         NetworkEvent::SetPlayerPosition(player_position) => {
                             let player_position = Vector2::new(player_position.x, player_position.y);
-                            entities[0].set_position(&map, player_position, client_tick);
-                            player_camera.set_focus_point(entities[0].get_position());
+
+                            if let Some(player) = entities.first_mut() {
+                                player.set_position(&map, player_position, client_tick);
+                                player_camera.set_focus_point(player.get_position());
+                            }
                         }
                         NetworkEvent::UpdateClientTick(client_tick) => {
                             game_timer.set_client_tick(client_tick);
                         }
+                        NetworkEvent::Latency { round_trip_time } => latency_tracker.add_sample(round_trip_time),
+                        NetworkEvent::DisplayEmotion { entity_id, emotion } => {
+                            let entity = entities.get_mut(entity_id);
+
+                            if let Some(entity) = entity {
+                                entity.show_emotion(&emotions, emotion, client_tick);
+                            }
+                        }
                         NetworkEvent::ChatMessage { text, color } => {
-                            chat_messages.push(ChatMessage { text, color });
+                            let text = name_item_links(&text, |item_id| script_loader.get_item_name_from_id(item_id, true));
+                            let text = name_monster_links(&text, |monster_id| monster_database.get_name(monster_id, &script_loader));
+                            let streamer_mode = privacy_settings.get().streamer_mode;
+
+                            // The server sends our own chat messages back without an entity id.
+                            if matches!(color, MessageColor::Server)
+                                && text.starts_with(&format!("{saved_player_name} : "))
+                                && let Some(player) = entities.first_mut()
+                            {
+                                player.show_overhead_text(&text, client_tick);
+                            }
+
+                            if matches!(color, MessageColor::Whisper)
+                                && let Some(notification) = whisper_notification(&text, streamer_mode)
+                            {
+                                notifications.push(NotificationKind::Social, notification);
+                            }
+
+                            if plugin_system.chat_message(&text) {
+                                let text = match color {
+                                    MessageColor::Rgb { .. } => mask_chat_message(text, &saved_player_name, streamer_mode),
+                                    MessageColor::Whisper => mask_whisper(text, streamer_mode),
+                                    _ => text,
+                                };
+
+                                chat_messages.push(ChatMessage::new(text, color));
+                            }
+                        }
+                        NetworkEvent::OverheadMessage { entity_id, text } => {
+                            let text = name_item_links(&text, |item_id| script_loader.get_item_name_from_id(item_id, true));
+                            let text = name_monster_links(&text, |monster_id| monster_database.get_name(monster_id, &script_loader));
+                            let text = mask_chat_message(text, &saved_player_name, privacy_settings.get().streamer_mode);
+
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.show_overhead_text(&text, client_tick);
+                            }
                         }
-                        NetworkEvent::UpdateEntityDetails(entity_id, name) => {
-                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+                        NetworkEvent::UpdateEntityDetails(entity_id, details) => {
+                            let entity = entities.get_mut(entity_id);
 
                             if let Some(entity) = entity {
-                                entity.set_details(name);
+                                entity.set_details(details);
                             }
                         }
-                        NetworkEvent::DamageEffect(entity_id, damage_amount) => {
-                            let entity = entities
-                                .iter()
-                                .find(|entity| entity.get_entity_id() == entity_id)
-                                .unwrap_or(&entities[0]);
+                        NetworkEvent::DamageEffect(entity_id, damage_amount, damage_kind) => {
+                            let entity = entities.get(entity_id).unwrap_or(&entities[0]);
+
+                            combat_texts.spawn(entity.get_position(), CombatTextKind::Damage(damage_kind), damage_amount);
+
+                            let material = map.get_sound_material(entity.get_grid_position());
+                            sound_state.play(&mut audio_engine, SoundEvent::Hit, material, entity.get_position());
 
-                            particle_holder.spawn_particle(Box::new(DamageNumber::new(entity.get_position(), damage_amount.to_string())));
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.register_damage(client_tick);
+                            }
                         }
                         NetworkEvent::HealEffect(entity_id, damage_amount) => {
-                            let entity = entities
-                                .iter()
-                                .find(|entity| entity.get_entity_id() == entity_id)
-                                .unwrap_or(&entities[0]);
+                            let entity = entities.get(entity_id).unwrap_or(&entities[0]);
 
-                            particle_holder.spawn_particle(Box::new(HealNumber::new(entity.get_position(), damage_amount.to_string())));
+                            combat_texts.spawn(entity.get_position(), CombatTextKind::Heal, damage_amount);
                         }
                         NetworkEvent::UpdateEntityHealth(entity_id, health_points, maximum_health_points) => {
-                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+                            let entity = entities.get_mut(entity_id);
 
                             if let Some(entity) = entity {
                                 entity.update_health(health_points, maximum_health_points);
                             }
                         }
+                        NetworkEvent::UpdateAttackRange(attack_range) => player_attack_range = attack_range,
                         NetworkEvent::UpdateStatus(status_type) => {
-                            let Entity::Player(player) = &mut entities[0] else {
+                            let Some(Entity::Player(player)) = entities.first_mut() else {
                                 panic!();
                             };
 
+                            if let StatusType::Zeny(zeny) = &status_type {
+                                player_inventory.set_zeny(*zeny);
+                            }
+
+                            if let StatusType::HealthPoints(health_points) = &status_type {
+                                battlefield.set_player_health(*health_points as usize);
+                            }
+
                             player.update_status(status_type);
                         }
                         NetworkEvent::OpenDialog(text, npc_id) => {
-                            if let Some(dialog_window) = dialog_system.open_dialog_window(text, npc_id) {
+                            let show_bank_button = entities
+                                .get(npc_id)
+                                .is_some_and(|npc| is_banker(&script_loader.get_job_name_from_id(npc.get_common().job_id)));
+
+                            if let Some(dialog_window) = dialog_system.open_dialog_window(text, npc_id, show_bank_button) {
                                 interface.open_window(&application, &mut focus_state, &dialog_window);
                             }
                         }
@@ -844,12 +1549,108 @@ fn main() {
                             particle_holder.add_quest_icon(&mut game_file_loader, &mut texture_loader, &map, quest_effect)
                         }
                         NetworkEvent::RemoveQuestEffect(entity_id) => particle_holder.remove_quest_icon(entity_id),
+                        NetworkEvent::SetQuestList { quests } => quest_log.fill(&script_loader, quests),
+                        NetworkEvent::QuestAdded { quest } => quest_log.add_quest(&script_loader, quest),
+                        NetworkEvent::QuestRemoved { quest_id } => quest_log.remove_quest(quest_id),
+                        NetworkEvent::UpdateQuestObjectives { objectives } => quest_log.update_objectives(objectives),
+                        NetworkEvent::SetAchievementList {
+                            summary,
+                            achievements: achievement_list,
+                        } => achievements.fill(&script_loader, summary, achievement_list),
+                        NetworkEvent::AchievementUpdated { summary, achievement } => {
+                            if let Some(name) = achievements.update(&script_loader, summary, achievement) {
+                                notifications.push_with_action(
+                                    NotificationKind::Achievement,
+                                    format!("Achievement completed: {name}"),
+                                    UserEvent::OpenAchievementWindow,
+                                );
+                            }
+                        }
+                        NetworkEvent::AchievementRewarded { achievement_id } => achievements.set_rewarded(achievement_id),
+                        NetworkEvent::TitleChanged { title_id } => achievements.set_title(title_id),
+                        NetworkEvent::BankOpened => {
+                            let Some(account_id) = saved_login_data.as_ref().map(|login_data| login_data.account_id) else {
+                                continue;
+                            };
+                            let _ = networking_system.request_bank_balance(account_id);
+
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &BankWindow::new(player_inventory.zeny_remote(), bank_balance.new_remote()),
+                            );
+                        }
+                        NetworkEvent::BankBalance { bank_balance: balance } => bank_balance.set(balance),
+                        NetworkEvent::OpenAttendanceWindow { claimed_days } => {
+                            attendance.set_claimed_days(claimed_days);
+
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &AttendanceWindow::new(attendance.get_claimed_days(), attendance.get_claimed_today()),
+                            );
+                        }
+                        NetworkEvent::AttendanceRewardClaimed { claimed_days } => attendance.set_claimed(claimed_days),
+                        NetworkEvent::OpenRefineWindow => {
+                            refinement.clear();
+
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &RefineWindow::new(player_inventory.item_remote(), refinement.get_item(), refinement.get_outcome()),
+                            );
+                        }
+                        NetworkEvent::SetRefineItem {
+                            index,
+                            blacksmith_blessing,
+                            materials,
+                        } => refinement.set_item(&script_loader, index, blacksmith_blessing, materials),
+                        NetworkEvent::RefineResult {
+                            result,
+                            index,
+                            refinement_level,
+                        } => {
+                            if result != RefineResult::Failure {
+                                player_inventory.update_refinement_level(index, refinement_level);
+                            }
+
+                            let text = refinement.set_result(result, refinement_level);
+                            let color = match result {
+                                RefineResult::Success => MessageColor::Information,
+                                RefineResult::Failure | RefineResult::Downgrade => MessageColor::Error,
+                            };
+
+                            chat_messages.push(ChatMessage::new(text, color));
+                        }
+                        NetworkEvent::SetPetInformation { pet: information } => pet.set_information(information),
+                        NetworkEvent::PetSummoned { pet_id } => pet.set_pet_id(pet_id),
+                        NetworkEvent::UpdatePetStatus { status } => {
+                            if pet.update_status(status) {
+                                chat_messages.push(ChatMessage::new(
+                                    "Your pet is very hungry and needs to be fed.".to_owned(),
+                                    MessageColor::Error,
+                                ));
+
+                                if let Some(pet_id) = pet.get_pet_id()
+                                    && let Some(entity) = entities.get_mut(pet_id)
+                                {
+                                    entity.show_emotion(&emotions, PET_HUNGRY_EMOTION, client_tick);
+                                }
+                            }
+                        }
                         NetworkEvent::SetInventory { items } => {
                             player_inventory.fill(&mut game_file_loader, &mut texture_loader, &script_loader, items);
                         }
                         NetworkEvent::IventoryItemAdded {
                             item
                         }=> {
+                            let name = script_loader.get_item_name_from_id(item.item_id, item.is_identifed());
+                            let text = match &item.details {
+                                InventoryItemDetails::Regular { amount, .. } if *amount > 1 => format!("Obtained {amount}x {name}"),
+                                _ => format!("Obtained {name}"),
+                            };
+                            notifications.push_with_action(NotificationKind::Item, text, UserEvent::OpenInventoryWindow);
+
                             player_inventory.add_item(
                                 &mut game_file_loader,
                                 &mut texture_loader,
@@ -857,6 +1658,9 @@ fn main() {
                                 item,
                             );
 
+                            let material = map.get_sound_material(entities[0].get_grid_position());
+                            sound_state.play(&mut audio_engine, SoundEvent::ItemDrop, material, entities[0].get_position());
+
                             // TODO: Update the selling items. If you pick up an item that you
                             // already have the sell window should allow you to sell the new amount
                             // of items.
@@ -866,6 +1670,14 @@ fn main() {
                                 index, amount,
                             );
                         }
+                        NetworkEvent::RentalItemTime { item_id, remaining_time } => {
+                            player_inventory.set_rental_time(item_id, remaining_time);
+                        }
+                        NetworkEvent::RentalItemExpired { index, item_id: _item_id } => {
+                            if let Some(name) = player_inventory.expire_rental_item(index) {
+                                chat_messages.push(ChatMessage::new(format!("{name} has expired"), MessageColor::Information));
+                            }
+                        }
                         NetworkEvent::SkillTree(skill_information) => {
                             player_skill_tree.fill(&mut game_file_loader, &mut sprite_loader, &mut action_loader, skill_information);
                         }
@@ -873,7 +1685,7 @@ fn main() {
                             player_inventory.update_equipped_position(index, equipped_position);
                         }
                         NetworkEvent::ChangeJob(account_id, job_id) => {
-                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id().0 == account_id.0).unwrap();
+                            let entity = entities.get_mut(EntityId(account_id.0)).unwrap();
 
                             // FIX: A job change does not automatically send packets for the
                             // inventory and for unequipping items. We should probably manually
@@ -882,87 +1694,243 @@ fn main() {
                             entity.set_job(job_id as usize);
                             entity.reload_sprite(&mut game_file_loader, &mut sprite_loader, &mut action_loader, &script_loader);
                         }
-                        NetworkEvent::LoggedOut => {
-                            networking_system.disconnect_from_map_server();
+                        NetworkEvent::UpdateSitting { entity_id, sitting } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.set_sitting(sitting, client_tick);
+                            }
+                        }
+                        NetworkEvent::UpdateEntityCondition { entity_id, condition } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.set_condition(condition);
+                            }
                         }
-                        NetworkEvent::FriendRequest { requestee } => {
-                            interface.open_window(&application, &mut focus_state, &FriendRequestWindow::new(requestee))
+                        NetworkEvent::UpdateEntityScale { entity_id, scale } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.set_scale(scale);
+                            }
                         }
-                        NetworkEvent::FriendRemoved { account_id, character_id } => {
-                            friend_list.retain(|(friend, _)| !(friend.account_id == account_id && friend.character_id == character_id));
+                        NetworkEvent::UpdateEntityLook { entity_id, change } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.update_look(&mut game_file_loader, &mut sprite_loader, &mut action_loader, &script_loader, change);
+                            }
                         }
-                        NetworkEvent::FriendAdded { friend } => {
-                            friend_list.push((friend, LinkedElement::new()));
+                        NetworkEvent::UpdateMount { entity_id, mount } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                match mount {
+                                    Some(mount) => entity.set_mount(&mut game_file_loader, &mut sprite_loader, &mut action_loader, mount),
+                                    // Costume mounts are only removed by their own status change.
+                                    None if entity.get_mount() != Some(Mount::Costume) => entity.remove_mount(),
+                                    None => {}
+                                }
+                            }
                         }
-                        NetworkEvent::VisualEffect(path, entity_id) => {
-                            let effect = effect_loader.get(path, &mut game_file_loader, &mut texture_loader).unwrap();
-                            let frame_timer = effect.new_frame_timer();
-
-                            effect_holder.add_effect(Box::new(EffectWithLight::new(
-                                effect,
-                                frame_timer,
-                                EffectCenter::Entity(entity_id, cgmath::Vector3::new(0.0, 0.0, 0.0)),
-                                Vector3::new(0.0, 9.0, 0.0),
-                                Vector3::new(0.0, 12.0, 0.0),
-                                Color::monochrome_u8(255),
-                                50.0,
-                                false,
-                            )));
+                        NetworkEvent::UpdateFollowers { entity_id, followers } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.set_followers(&mut game_file_loader, &mut sprite_loader, &mut action_loader, followers, client_tick);
+                            }
                         }
-                        NetworkEvent::AddSkillUnit(entity_id, unit_id, position) => match unit_id {
-                            UnitId::Firewall => {
-                                let position = Vector2::new(position.x as usize, position.y as usize);
-                                let position = map.get_world_position(position);
-                                let effect = effect_loader
-                                    .get("firewall.str", &mut game_file_loader, &mut texture_loader)
-                                    .unwrap();
-                                let frame_timer = effect.new_frame_timer();
+                        NetworkEvent::UpdateCostumeMount { entity_id, mounted } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                match mounted {
+                                    true => entity.set_mount(&mut game_file_loader, &mut sprite_loader, &mut action_loader, Mount::Costume),
+                                    false if entity.get_mount() == Some(Mount::Costume) => entity.remove_mount(),
+                                    false => {}
+                                }
+                            }
+                        }
+                        NetworkEvent::AddStatusEffect {
+                            entity_id,
+                            status_effect,
+                            duration,
+                        } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                let icon = script_loader
+                                    .get_status_effect_icon_name(status_effect)
+                                    .and_then(|name| texture_loader.get(&format!("effect\\{name}"), &mut game_file_loader).ok());
 
-                                effect_holder.add_unit(
-                                    Box::new(EffectWithLight::new(
-                                        effect,
-                                        frame_timer,
-                                        EffectCenter::Position(position),
-                                        Vector3::new(0.0, 0.0, 0.0),
-                                        Vector3::new(0.0, 3.0, 0.0),
-                                        Color::rgb_u8(255, 30, 0),
-                                        20.0,
-                                        true,
-                                    )),
-                                    entity_id,
-                                );
+                                entity.add_status_effect(status_effect, duration, icon, client_tick);
                             }
-                            UnitId::Pneuma => {
-                                let position = Vector2::new(position.x as usize, position.y as usize);
-                                let position = map.get_world_position(position);
-                                let effect = effect_loader
-                                    .get("pneuma1.str", &mut game_file_loader, &mut texture_loader)
-                                    .unwrap();
-                                let frame_timer = effect.new_frame_timer();
 
-                                effect_holder.add_unit(
-                                    Box::new(EffectWithLight::new(
-                                        effect,
-                                        frame_timer,
-                                        EffectCenter::Position(position),
-                                        Vector3::new(0.0, 0.0, 0.0),
-                                        Vector3::new(0.0, 3.0, 0.0),
-                                        Color::rgb_u8(83, 220, 108),
-                                        40.0,
-                                        false,
-                                    )),
-                                    entity_id,
-                                );
+                            if entities.first().is_some_and(|player| player.get_entity_id() == entity_id) {
+                                let name = script_loader
+                                    .get_status_effect_name(status_effect)
+                                    .unwrap_or_else(|| format!("Status {status_effect}"));
+
+                                player_status_effects.add(status_effect, name, duration);
                             }
-                            _ => {}
-                        },
-                        NetworkEvent::RemoveSkillUnit(entity_id) => {
-                            effect_holder.remove_unit(entity_id);
-                        }
-                        NetworkEvent::SetFriendList { friends } => {
-                            friend_list.mutate(|friend_list| {
-                                *friend_list = friends.into_iter().map(|friend| (friend, LinkedElement::new())).collect();
-                            });
+                        }
+                        NetworkEvent::RemoveStatusEffect { entity_id, status_effect } => {
+                            if let Some(entity) = entities.get_mut(entity_id) {
+                                entity.remove_status_effect(status_effect);
+                            }
+
+                            if entities.first().is_some_and(|player| player.get_entity_id() == entity_id) {
+                                player_status_effects.remove(status_effect);
+                            }
+                        }
+                        NetworkEvent::LoggedOut => {
+                            networking_system.disconnect_from_map_server();
+                        }
+                        NetworkEvent::GuildNotice { notice } => guild.set_notice(notice, &mut notifications),
+                        NetworkEvent::GuildEmblem { emblem_id, data } => {
+                            let Some(image) = decode_guild_emblem(&data) else {
+                                #[cfg(feature = "debug")]
+                                print_debug!("[{}] failed to decode emblem of guild {}", "error".red(), emblem_id.guild_id);
+                                continue;
+                            };
+
+                            let name = format!("guild emblem {} {}", emblem_id.guild_id, emblem_id.version);
+                            let texture = texture_loader.get_or_create(&name, || image);
+
+                            entities
+                                .iter_mut()
+                                .filter(|entity| entity.get_guild_emblem_id() == Some(emblem_id))
+                                .for_each(|entity| entity.set_guild_emblem(texture.clone()));
+
+                            guild_emblem_cache.insert(emblem_id, texture);
+                        }
+                        NetworkEvent::InstanceQueued { name, queue_position } => memorial_dungeon.set_queued(name, queue_position),
+                        NetworkEvent::InstanceQueuePosition { queue_position } => memorial_dungeon.set_queue_position(queue_position),
+                        NetworkEvent::InstanceInformation { information } => memorial_dungeon.set_information(information),
+                        NetworkEvent::InstanceTimeLimitChanged { remaining_time } => memorial_dungeon.set_time_limit(remaining_time),
+                        NetworkEvent::InstanceClosed { reason: _reason, message } => {
+                            memorial_dungeon.clear();
+
+                            chat_messages.push(ChatMessage::new(message.to_owned(), MessageColor::Information));
+                        }
+                        NetworkEvent::MapType { flags } => battlefield.set_map_flags(flags),
+                        NetworkEvent::BattlefieldTeam { entity_id, name, team_id } => battlefield.set_team(entity_id, name, team_id),
+                        NetworkEvent::BattlefieldScore { lion_score, eagle_score } => battlefield.set_scores(lion_score, eagle_score),
+                        NetworkEvent::PartyMemberPosition { account_id, position } => match position {
+                            Some(position) => {
+                                party_member_positions.insert(account_id, Vector2::new(position.x as usize, position.y as usize));
+                            }
+                            None => {
+                                party_member_positions.remove(&account_id);
+                            }
+                        },
+                        NetworkEvent::IncomingRequest { request } => {
+                            match request_settings.get().rejects(&request) {
+                                true => {
+                                    let _ = networking_system.respond_to_request(&request, false);
+                                }
+                                false => interface.open_window(&application, &mut focus_state, &IncomingRequestWindow::new(request, privacy_settings.get().streamer_mode)),
+                            }
+                        }
+                        NetworkEvent::FriendRemoved { account_id, character_id } => {
+                            friend_list.retain(|(friend, _)| !(friend.account_id == account_id && friend.character_id == character_id));
+                        }
+                        NetworkEvent::FriendAdded { friend } => {
+                            notifications.push_with_action(
+                                NotificationKind::Social,
+                                format!("{} is now your friend", display_name(&friend.name, privacy_settings.get().streamer_mode)),
+                                UserEvent::OpenFriendsWindow,
+                            );
+                            friend_list.push((friend, LinkedElement::new()));
+                        }
+                        NetworkEvent::FriendOnlineStatus { name, state } => {
+                            let name = display_name(&name, privacy_settings.get().streamer_mode);
+                            let text = match state {
+                                OnlineState::Online => format!("{name} is now online"),
+                                OnlineState::Offline => format!("{name} is now offline"),
+                            };
+
+                            notifications.push_with_action(NotificationKind::Social, text, UserEvent::OpenFriendsWindow);
+                        }
+                        NetworkEvent::VisualEffect(path, entity_id) => {
+                            // Effects of entities that are not on screen are not shown.
+                            if let Some(entity_handle) = entities.handle(entity_id) {
+                                let effect = effect_loader.get(path, &mut game_file_loader, &mut texture_loader).unwrap();
+                                let frame_timer = effect.new_frame_timer();
+
+                                effect_holder.add_effect(Box::new(EffectWithLight::new(
+                                    effect,
+                                    frame_timer,
+                                    EffectCenter::Entity(entity_handle, AttachmentPoint::Body, cgmath::Vector3::new(0.0, 0.0, 0.0)),
+                                    Vector3::new(0.0, 0.0, 0.0),
+                                    Vector3::new(0.0, 3.0, 0.0),
+                                    Color::monochrome_u8(255),
+                                    50.0,
+                                    false,
+                                )));
+                            }
+                        }
+                        NetworkEvent::AddSkillUnit(entity_id, unit_id, position) => {
+                            if let Some(sound_file) = get_unit_sound_file(&unit_id)
+                                && let Ok(sound_effect) = sound_loader.get(sound_file, &mut game_file_loader)
+                            {
+                                let world_position = map.get_world_position(Vector2::new(position.x as usize, position.y as usize));
+
+                                let spatial_settings = SpatialSettings {
+                                    position: world_position,
+                                    velocity: Vector3::zero(),
+                                    volume: 1.0,
+                                    pitch: 1.0,
+                                    range: UNIT_SOUND_RANGE,
+                                    attenuation: AttenuationCurve::Focused,
+                                };
+
+                                if let Some(emitter) = audio_engine.start_emitter(AudioChannel::Effects, &sound_effect, spatial_settings) {
+                                    skill_unit_emitters.insert(entity_id, emitter);
+                                }
+                            }
+
+                            match unit_id {
+                                UnitId::Firewall => {
+                                    let position = Vector2::new(position.x as usize, position.y as usize);
+                                    let position = map.get_world_position(position);
+                                    let effect = effect_loader
+                                        .get("firewall.str", &mut game_file_loader, &mut texture_loader)
+                                        .unwrap();
+                                    let frame_timer = effect.new_frame_timer();
+
+                                    effect_holder.add_unit(
+                                        Box::new(EffectWithLight::new(
+                                            effect,
+                                            frame_timer,
+                                            EffectCenter::Position(position),
+                                            Vector3::new(0.0, 0.0, 0.0),
+                                            Vector3::new(0.0, 3.0, 0.0),
+                                            Color::rgb_u8(255, 30, 0),
+                                            20.0,
+                                            true,
+                                        )),
+                                        entity_id,
+                                    );
+                                }
+                                UnitId::Pneuma => {
+                                    let position = Vector2::new(position.x as usize, position.y as usize);
+                                    let position = map.get_world_position(position);
+                                    let effect = effect_loader
+                                        .get("pneuma1.str", &mut game_file_loader, &mut texture_loader)
+                                        .unwrap();
+                                    let frame_timer = effect.new_frame_timer();
+
+                                    effect_holder.add_unit(
+                                        Box::new(EffectWithLight::new(
+                                            effect,
+                                            frame_timer,
+                                            EffectCenter::Position(position),
+                                            Vector3::new(0.0, 0.0, 0.0),
+                                            Vector3::new(0.0, 3.0, 0.0),
+                                            Color::rgb_u8(83, 220, 108),
+                                            40.0,
+                                            false,
+                                        )),
+                                        entity_id,
+                                    );
+                                }
+                                _ => {}
+                            }
+                        }
+                        NetworkEvent::RemoveSkillUnit(entity_id) => {
+                            effect_holder.remove_unit(entity_id);
+                            skill_unit_emitters.remove(&entity_id);
+                        }
+                        NetworkEvent::SetFriendList { friends } => {
+                            friend_list.mutate(|friend_list| {
+                                *friend_list = friends.into_iter().map(|friend| (friend, LinkedElement::new())).collect();
+                            });
                         }
                         NetworkEvent::SetHotkeyData { tab, hotkeys } => {
                             // FIX: Since we only have one hotbar at the moment, we ignore
@@ -971,7 +1939,7 @@ fn main() {
                                 continue;
                             }
 
-                            for (index, hotkey) in hotkeys.into_iter().take(10).enumerate() {
+                            for (index, hotkey) in hotkeys.into_iter().take(HOTBAR_SLOT_COUNT).enumerate() {
                                 match hotkey {
                                     HotkeyState::Bound(hotkey) => {
                                         let Some(mut skill) = player_skill_tree.find_skill(SkillId(hotkey.skill_id as u16)) else {
@@ -1008,10 +1976,7 @@ fn main() {
                                     interface.close_window_with_class(&mut focus_state, BuyCartWindow::WINDOW_CLASS);
                                 }
                                 BuyShopItemsResult::Error => {
-                                    chat_messages.push(ChatMessage {
-                                        text: "Failed to buy items".to_owned(),
-                                        color: MessageColor::Error,
-                                    });
+                                    chat_messages.push(ChatMessage::new("Failed to buy items".to_owned(), MessageColor::Error));
                                 },
                             }
                         },
@@ -1048,10 +2013,7 @@ fn main() {
                                     interface.close_window_with_class(&mut focus_state, SellCartWindow::WINDOW_CLASS);
                                 }
                                 SellItemsResult::Error => {
-                                    chat_messages.push(ChatMessage {
-                                        text: "Failed to sell items".to_owned(),
-                                        color: MessageColor::Error,
-                                    });
+                                    chat_messages.push(ChatMessage::new("Failed to sell items".to_owned(), MessageColor::Error));
                                 },
                             }
                         },
@@ -1065,11 +2027,15 @@ fn main() {
                 let user_event_measurement = Profiler::start_measurement("process user events");
 
                 for event in user_events {
+                    plugin_system.user_event(&event);
+
                     match event {
                         UserEvent::LogIn {
                             service_id,
                             username,
                             password,
+                            packet_version,
+                            window_layout: profile_window_layout,
                         } => {
                             let service = client_info
                                 .services
@@ -1081,9 +2047,18 @@ fn main() {
 
                             saved_login_server_address = Some(socket_address);
                             saved_username = username.clone();
+
+                            networking_system.set_packet_version(packet_version.or(service.packet_version));
+                            input_system.set_macros_allowed(!service.disable_macros);
+                            plugin_system.set_macros_allowed(!service.disable_macros);
                             saved_password = password.clone();
 
                             networking_system.connect_to_login_server(socket_address, username, password);
+
+                            if profile_window_layout != window_layout {
+                                interface.set_window_cache(WindowCache::with_layout(profile_window_layout.as_deref()));
+                                window_layout = profile_window_layout;
+                            }
                         }
                         UserEvent::SelectServer(server) => {
                             saved_character_server = Some(server.clone());
@@ -1096,8 +2071,81 @@ fn main() {
                             let login_data = saved_login_data.as_ref().unwrap();
                             networking_system.connect_to_character_server(login_data, server);
                         }
-                        UserEvent::LogOut => {
-                            let _ = networking_system.log_out();
+                        UserEvent::OpenLoginWindow => {
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &LoginWindow::new(&client_info, login_settings.clone(), privacy_settings.clone()),
+                            )
+                        }
+                        UserEvent::OpenCrashReport(path) => open_crash_report(&path),
+                        UserEvent::DownloadUpdate => updater.download_update(),
+                        UserEvent::OpenReplayWindow => {
+                            let mut replays: Vec<String> = std::fs::read_dir(REPLAY_DIRECTORY)
+                                .map(|entries| {
+                                    entries
+                                        .filter_map(Result::ok)
+                                        .map(|entry| entry.path())
+                                        .filter(|path| path.extension().is_some_and(|extension| extension == REPLAY_EXTENSION))
+                                        .map(|path| path.to_string_lossy().into_owned())
+                                        .collect()
+                                })
+                                .unwrap_or_default();
+                            replays.sort();
+
+                            interface.open_window(&application, &mut focus_state, &ReplayWindow::new(record_replays.clone(), replays));
+                        }
+                        UserEvent::PlayReplay(path) => match Replay::load(&path) {
+                            Ok(replay) => {
+                                let character_information = replay.header.character_information.clone();
+
+                                // The login ids are only needed to connect to the map server, which
+                                // never happens during a replay.
+                                saved_login_data = Some(LoginServerLoginData {
+                                    account_id: replay.header.account_id,
+                                    login_id1: 0,
+                                    login_id2: 0,
+                                    sex: character_information.sex,
+                                });
+                                saved_characters.set(vec![character_information]);
+                                replaying = true;
+
+                                interface.close_all_windows_except(&mut focus_state);
+                                networking_system.play_replay(replay);
+                            }
+                            Err(..) => {
+                                interface.open_window(&application, &mut focus_state, &ErrorWindow::new(format!("Failed to load replay {path}")))
+                            }
+                        },
+                        UserEvent::LogOut => match replaying {
+                            // The recorded session can't be logged out of, so the replay is just stopped.
+                            true => networking_system.disconnect_from_map_server(),
+                            false => {
+                                let _ = networking_system.log_out();
+                            }
+                        },
+                        UserEvent::SwitchAccount => match entities.is_empty() {
+                            // The login window is opened once the map server disconnected.
+                            false if replaying => networking_system.disconnect_from_map_server(),
+                            false => {
+                                switching_account = true;
+                                let _ = networking_system.log_out();
+                            }
+                            true => {
+                                networking_system.disconnect_from_character_server();
+                                networking_system.disconnect_from_login_server();
+
+                                saved_login_data = None;
+                                saved_character_server = None;
+                                saved_characters.set(Vec::new());
+
+                                interface.close_all_windows_except(&mut focus_state);
+                                interface.open_window(
+                                    &application,
+                                    &mut focus_state,
+                                    &LoginWindow::new(&client_info, login_settings.clone(), privacy_settings.clone()),
+                                );
+                            }
                         },
                         UserEvent::Exit => *control_flow = ControlFlow::Exit,
                         UserEvent::CameraZoom(factor) => player_camera.soft_zoom(factor),
@@ -1137,24 +2185,383 @@ fn main() {
                         UserEvent::OpenGraphicsSettingsWindow => interface.open_window(
                             &application,
                             &mut focus_state,
-                            &GraphicsSettingsWindow::new(present_mode_info, shadow_detail.clone_state(), framerate_limit.clone_state()),
+                            &GraphicsSettingsWindow::new(
+                                present_mode_info,
+                                shadow_detail.clone_state(),
+                                framerate_limit.clone_state(),
+                                show_frame_overlay.clone_state(),
+                                export_metrics.clone_state(),
+                                health_bar_visibility.clone_state(),
+                                smooth_movement.clone_state(),
+                                interpolation_delay.clone_state(),
+                                corpse_duration.clone_state(),
+                                bloom_intensity.clone_state(),
+                                bloom_threshold.clone_state(),
+                                render_scale.clone_state(),
+                                dynamic_render_scale.clone_state(),
+                                sharpen_upscaling.clone_state(),
+                            ),
+                        ),
+                        UserEvent::OpenAudioSettingsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &AudioSettingsWindow::new(background_music_track.new_remote(), audio_settings.clone()),
+                        ),
+                        UserEvent::OpenMinimapWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &MinimapWindow::new(minimap_data.new_remote(), minimap_zoom_level.clone()),
+                        ),
+                        UserEvent::OpenWorldMapWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &WorldMapWindow::new(
+                                world_map.clone(),
+                                texture_loader.get(&world_map.image, &mut game_file_loader).ok(),
+                                current_map_name.clone(),
+                            ),
+                        ),
+                        UserEvent::OpenNetworkQualityWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &NetworkQualityWindow::new(latency_tracker.get_samples()),
+                        ),
+                        UserEvent::OpenRequestSettingsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &RequestSettingsWindow::new(request_settings.clone()),
+                        ),
+                        UserEvent::OpenChatSettingsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &ChatSettingsWindow::new(chat_settings.clone(), chat_tabs.new_remote()),
+                        ),
+                        UserEvent::OpenPrivacySettingsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &PrivacySettingsWindow::new(privacy_settings.clone()),
+                        ),
+                        UserEvent::AddChatTab(name) => {
+                            let mut tab = ChatTab::new(name, ChatCategory::all());
+                            tab.read = chat_messages.get().len();
+                            chat_tabs.push(tab);
+                        }
+                        UserEvent::RemoveChatTab(index) => {
+                            let remaining = chat_tabs.mutate(|tabs| {
+                                if index < tabs.len() {
+                                    tabs.remove(index);
+                                }
+
+                                tabs.len()
+                            });
+
+                            let selected = *selected_chat_tab.get();
+                            let selected = match selected > index {
+                                true => selected - 1,
+                                false => selected,
+                            };
+                            selected_chat_tab.set(selected.min(remaining.saturating_sub(1)));
+                        }
+                        UserEvent::ToggleChatTabCategory { tab, category } => chat_tabs.mutate(|tabs| {
+                            if let Some(tab) = tabs.get_mut(tab) {
+                                tab.toggle_category(category);
+                            }
+                        }),
+                        UserEvent::OpenKeyBindingsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &KeyBindingsWindow::new(
+                                key_bindings.new_remote(),
+                                input_system.get_key_capture(),
+                                input_system.get_mouse_capture(),
+                                input_system.get_macro_scripts(),
+                                mouse_settings.clone(),
+                            ),
                         ),
-                        UserEvent::OpenAudioSettingsWindow => interface.open_window(&application, &mut focus_state, &AudioSettingsWindow),
+                        UserEvent::CaptureKeyBinding(action) => input_system.start_key_capture(action),
+                        UserEvent::SetKeyBinding(action, binding) => {
+                            key_bindings.mutate(|key_bindings| key_bindings.set(action, binding));
+                            key_bindings.get().save();
+                        }
+                        UserEvent::CaptureMouseBinding(action) => input_system.start_mouse_capture(action),
+                        UserEvent::SetMouseBinding(action, binding) => {
+                            key_bindings.mutate(|key_bindings| key_bindings.set_mouse(action, binding));
+                            key_bindings.get().save();
+                        }
+                        UserEvent::ResetKeyBindings => {
+                            key_bindings.set(KeyBindings::default());
+                            key_bindings.get().save();
+                        }
+                        UserEvent::ExportKeyBindings(name) => {
+                            let message = match key_bindings.get().export_profile(&name) {
+                                Ok(()) => ChatMessage::new(
+                                    format!("Exported key bindings to profile \"{name}\""),
+                                    MessageColor::Information,
+                                ),
+                                Err(text) => ChatMessage::new(text, MessageColor::Error),
+                            };
+
+                            chat_messages.push(message);
+                        }
+                        UserEvent::ImportKeyBindings(name) => {
+                            let imported = key_bindings.get().import_profile(&name);
+
+                            match imported {
+                                Ok(imported) => {
+                                    key_bindings.set(imported);
+                                    key_bindings.get().save();
+
+                                    chat_messages.push(ChatMessage::new(
+                                        format!("Imported key bindings from profile \"{name}\""),
+                                        MessageColor::Information,
+                                    ));
+                                }
+                                Err(text) => chat_messages.push(ChatMessage::new(text, MessageColor::Error)),
+                            }
+                        }
+                        UserEvent::SetMacro(index, script) => match parse_macro(&script) {
+                            Ok(..) => {
+                                input_system.set_macro(index, script);
+
+                                chat_messages.push(ChatMessage::new(
+                                    format!("Saved macro {}", index + 1),
+                                    MessageColor::Information,
+                                ));
+                            }
+                            Err(text) => chat_messages.push(ChatMessage::new(format!("Macro {}: {text}", index + 1), MessageColor::Error)),
+                        },
                         UserEvent::OpenFriendsWindow => {
-                            interface.open_window(&application, &mut focus_state, &FriendsWindow::new(friend_list.new_remote()));
+                            interface.open_window(&application, &mut focus_state, &FriendsWindow::new(friend_list.new_remote(), privacy_settings.new_remote()));
+                        }
+                        UserEvent::OpenEmoteWindow => {
+                            interface.open_window(&application, &mut focus_state, &EmoteWindow::new(emotions.clone()));
+                        }
+                        UserEvent::OpenAchievementWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &AchievementWindow::new(achievements.get_achievements(), achievements.get_summary(), achievements.get_title()),
+                        ),
+                        UserEvent::OpenAttendanceWindow => {
+                            let _ = networking_system.open_attendance();
+                        }
+                        UserEvent::OpenItemInformation(item_id) => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &ItemInformationWindow::new(
+                                item_id,
+                                script_loader.get_item_name_from_id(item_id, true),
+                                script_loader.get_item_description_from_id(item_id),
+                            ),
+                        ),
+                        UserEvent::OpenMonsterInformation(monster_id) => match MONSTER_JOB_IDS.contains(&monster_id) {
+                            true => interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &MonsterInformationWindow::new(monster_id, None, &monster_database, &script_loader),
+                            ),
+                            false => chat_messages.push(ChatMessage::new(
+                                format!("{} is not a valid monster id", monster_id),
+                                MessageColor::Error,
+                            )),
+                        },
+                        UserEvent::InspectEntity(entity_id) => {
+                            let entity = entities.get(entity_id);
+
+                            if let Some(entity) = entity
+                                && entity.get_entity_type() == EntityType::Monster
+                            {
+                                let common = entity.get_common();
+                                let mut window = MonsterInformationWindow::new(
+                                    common.job_id,
+                                    Some((common.health_points, common.maximum_health_points)),
+                                    &monster_database,
+                                    &script_loader,
+                                );
+
+                                if let Some(name) = entity.get_details() {
+                                    window = window.with_name(name.clone());
+                                }
+
+                                interface.open_window(&application, &mut focus_state, &window);
+                            }
+                        }
+                        UserEvent::OpenEntityContextMenu(entity_id) => {
+                            // The first entity is the player, which doesn't have a context menu.
+                            let entity = entities.get(entity_id).filter(|_| entities[0].get_entity_id() != entity_id);
+
+                            if let Some(entity) = entity
+                                && entity.get_entity_type() == EntityType::Player
+                            {
+                                let account_id = AccountId(entity_id.0);
+                                let mut options = Vec::new();
+
+                                // The name is only known once the details of the entity have been received.
+                                if let Some(name) = entity.get_details() {
+                                    options.push(("Whisper".to_owned(), UserEvent::InsertChatText(format!("/w \"{}\" ", name))));
+                                    options.push(("Invite to party".to_owned(), UserEvent::InviteToParty(name.clone())));
+                                }
+
+                                options.push(("Trade".to_owned(), UserEvent::RequestTrade(account_id)));
+                                options.push(("Invite to guild".to_owned(), UserEvent::InviteToGuild(account_id)));
+                                options.push(("View equipment".to_owned(), UserEvent::ViewEquipment(account_id)));
+
+                                let title = entity
+                                    .get_details()
+                                    .map(|name| display_name(name, privacy_settings.get().streamer_mode).into_owned())
+                                    .unwrap_or_else(|| "Player".to_owned());
+
+                                interface.open_window(
+                                    &application,
+                                    &mut focus_state,
+                                    &ContextMenuWindow::new(title, options, input_system.get_mouse_position()),
+                                );
+                            }
                         }
+                        UserEvent::OpenItemContextMenu { source, item } => {
+                            let mut options = Vec::new();
+
+                            match (source, &item.details) {
+                                (ItemSource::Inventory, InventoryItemDetails::Regular { amount, .. }) => {
+                                    options.push(("Use".to_owned(), UserEvent::UseItem(item.index)));
+                                    options.push(("Drop".to_owned(), UserEvent::DropItem {
+                                        index: item.index,
+                                        amount: *amount,
+                                    }));
+                                }
+                                (ItemSource::Inventory, InventoryItemDetails::Equippable { equip_position, .. }) => {
+                                    options.push(("Equip".to_owned(), UserEvent::MoveResource(Move::Item {
+                                        source,
+                                        destination: ItemSource::Equipment { position: *equip_position },
+                                        item: item.clone(),
+                                    })));
+                                    options.push(("Drop".to_owned(), UserEvent::DropItem {
+                                        index: item.index,
+                                        amount: 1,
+                                    }));
+                                }
+                                (ItemSource::Equipment { .. }, _) => {
+                                    options.push(("Unequip".to_owned(), UserEvent::MoveResource(Move::Item {
+                                        source,
+                                        destination: ItemSource::Inventory,
+                                        item: item.clone(),
+                                    })));
+                                }
+                            }
+
+                            options.push((
+                                "Link to chat".to_owned(),
+                                UserEvent::InsertChatText(item_link_markup(item.item_id)),
+                            ));
+
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &ContextMenuWindow::new(item.metadata.name.clone(), options, input_system.get_mouse_position()),
+                            );
+                        }
+                        UserEvent::RequestTrade(account_id) => {
+                            let _ = networking_system.request_trade(account_id);
+                        }
+                        UserEvent::InviteToParty(name) => {
+                            let _ = networking_system.invite_to_party(name);
+                        }
+                        UserEvent::InviteToGuild(account_id) => {
+                            if let Some(login_data) = &saved_login_data
+                                && let Some(character_id) = saved_character_id
+                            {
+                                let _ = networking_system.invite_to_guild(account_id, login_data.account_id, character_id);
+                            }
+                        }
+                        UserEvent::ViewEquipment(account_id) => {
+                            let _ = networking_system.request_equipment_window(account_id);
+                        }
+                        UserEvent::UseItem(index) => {
+                            let account_id = saved_login_data.as_ref().unwrap().account_id;
+                            let _ = networking_system.use_item(index, account_id);
+                        }
+                        UserEvent::DropItem { index, amount } => {
+                            let _ = networking_system.drop_item(index, amount);
+                        }
+                        UserEvent::OpenGuildWindow => {
+                            interface.open_window(&application, &mut focus_state, &GuildWindow::new(guild.get_notice().cloned()))
+                        }
+                        UserEvent::OpenPetWindow => {
+                            let _ = networking_system.send_pet_command(PetCommand::RequestInformation);
+
+                            interface.open_window(&application, &mut focus_state, &PetWindow::new(pet.get_information()));
+                        }
+                        UserEvent::OpenStatusEffectsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &StatusEffectsWindow::new(player_status_effects.get_entries()),
+                        ),
+                        UserEvent::OpenCommandPaletteWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &CommandPaletteWindow::new(server_commands.clone()),
+                        ),
+                        UserEvent::OpenQuestWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &QuestWindow::new(quest_log.get_quests(), show_quest_markers.clone(), show_quest_tracker.clone()),
+                        ),
                         UserEvent::ToggleShowInterface => show_interface = !show_interface,
                         UserEvent::SetThemeFile { theme_file, theme_kind } => application.set_theme_file(theme_file, theme_kind),
                         UserEvent::SaveTheme { theme_kind } => application.save_theme(theme_kind),
-                        UserEvent::ReloadTheme { theme_kind } => application.reload_theme(theme_kind),
+                        UserEvent::ReloadTheme { theme_kind } => {
+                            application.reload_theme(theme_kind);
+                            interface.schedule_resolve();
+                        }
+                        UserEvent::SaveThemeAs { theme_kind, name } => {
+                            if let Err(text) = application.save_theme_as(theme_kind, &name) {
+                                chat_messages.push(ChatMessage::new(text, MessageColor::Error));
+                            }
+                        }
+                        UserEvent::LoadTheme { theme_kind, name } => match application.load_theme(theme_kind, &name) {
+                            Ok(()) => interface.schedule_resolve(),
+                            Err(text) => chat_messages.push(ChatMessage::new(text, MessageColor::Error)),
+                        },
                         UserEvent::SelectCharacter(character_slot) => {
                             let _ = networking_system.select_character(character_slot);
                         },
                         UserEvent::OpenCharacterCreationWindow(character_slot) => {
-                            interface.open_window(&application, &mut focus_state, &CharacterCreationWindow::new(character_slot))
+                            character_appearance.set(Some(PlayerAppearance::new(
+                                &mut game_file_loader,
+                                &mut sprite_loader,
+                                &mut action_loader,
+                                &script_loader,
+                                CharacterAppearance::default(),
+                            )));
+
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &CharacterCreationWindow::new(character_slot, character_appearance.new_remote()),
+                            )
+                        }
+                        UserEvent::ChangeCharacterAppearance(appearance) => {
+                            character_appearance.set(Some(PlayerAppearance::new(
+                                &mut game_file_loader,
+                                &mut sprite_loader,
+                                &mut action_loader,
+                                &script_loader,
+                                appearance,
+                            )));
                         }
                         UserEvent::CreateCharacter(character_slot, name) => {
-                            let _ = networking_system.create_character(character_slot, name);
+                            let appearance = character_appearance
+                                .get()
+                                .as_ref()
+                                .map(|player_appearance| player_appearance.appearance)
+                                .unwrap_or_default();
+
+                            let _ = networking_system.create_character(character_slot, name, appearance);
+                        },
+                        UserEvent::OpenCharacterRenameWindow(character_id) => {
+                            interface.open_window(&application, &mut focus_state, &CharacterRenameWindow::new(character_id))
+                        }
+                        UserEvent::RenameCharacter(character_id, name) => {
+                            let _ = networking_system.rename_character(character_id, name);
                         },
                         UserEvent::DeleteCharacter(character_id) => {
                             if currently_deleting.is_none() {
@@ -1162,31 +2569,102 @@ fn main() {
                                 currently_deleting = Some(character_id);
                             }
                         },
+                        UserEvent::OpenCharacterDeletionWindow(character_id) => {
+                            interface.open_window(&application, &mut focus_state, &CharacterDeletionWindow::new(character_id))
+                        }
+                        UserEvent::ConfirmCharacterDeletion(character_id, birthdate) => {
+                            if currently_deleting.is_none() {
+                                let _ = networking_system.confirm_character_deletion(character_id, birthdate);
+                                currently_deleting = Some(character_id);
+                            }
+                        },
+                        UserEvent::CancelCharacterDeletion(character_id) => {
+                            let _ = networking_system.cancel_character_deletion(character_id);
+                        },
                         UserEvent::RequestSwitchCharacterSlot(origin_slot) => move_request.set(Some(origin_slot)),
                         UserEvent::CancelSwitchCharacterSlot => move_request.set(None),
                         UserEvent::SwitchCharacterSlot(destination_slot) => {
                             let _ = networking_system.switch_character_slot(move_request.take().unwrap(), destination_slot);
                         },
                         UserEvent::RequestPlayerMove(destination) => {
-                            if !entities.is_empty() {
+                            if let Some(player) = entities.first_mut() {
                                 let _ = networking_system.player_move(WorldPosition { x: destination.x, y: destination.y });
+
+                                if mouse_settings.get().walk_prediction {
+                                    player.predict_move(&map, destination, 0, client_tick);
+                                }
+                            }
+                        }
+                        UserEvent::GamepadMove(input) => {
+                            if let Some(player) = entities.first() {
+                                let direction = player_camera.ground_direction(input) * GAMEPAD_WALK_DISTANCE;
+                                let position = player.get_grid_position();
+                                let target = Vector2::new(
+                                    (position.x as f32 + direction.x).round().max(0.0) as usize,
+                                    (position.y as f32 + direction.y).round().max(0.0) as usize,
+                                );
+
+                                // Only send a new request once the target tile changes.
+                                if gamepad_walk_target != Some(target) {
+                                    gamepad_walk_target = Some(target);
+                                    let _ = networking_system.player_move(WorldPosition { x: target.x, y: target.y });
+                                }
+                            }
+                        }
+                        UserEvent::InteractWithNearestNpc => {
+                            if let Some(npc) = closest_entity(&entities, EntityType::Npc) {
+                                let _ = networking_system.start_dialog(npc.get_entity_id());
                             }
                         }
                         UserEvent::RequestPlayerInteract(entity_id) => {
-                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+                            let target = entities
+                                .get(entity_id)
+                                .map(|entity| (entity.get_entity_type(), entity.get_grid_position(), entity.is_dead()));
+
+                            if let Some((entity_type, position, dead)) = target {
+                                let walk_prediction = mouse_settings.get().walk_prediction;
+
+                                let _ = match entity_type {
+                                    EntityType::Npc => {
+                                        // Walk up to NPCs that are too far away instead of asking the
+                                        // server for a dialog that it would reject.
+                                        let player_position = entities[0].get_grid_position();
+                                        let stop = find_path_within_range(&map, player_position, position, NPC_TALK_RANGE)
+                                            .and_then(|path| path.last().copied())
+                                            .filter(|&tile| tile != player_position);
+
+                                        match stop {
+                                            Some(destination) => {
+                                                if walk_prediction && let Some(player) = entities.first_mut() {
+                                                    player.predict_move(&map, destination, 0, client_tick);
+                                                }
+
+                                                networking_system.player_move(WorldPosition { x: destination.x, y: destination.y })
+                                            }
+                                            None => networking_system.start_dialog(entity_id),
+                                        }
+                                    }
+                                    // Walk onto corpses, so the items that they dropped can be picked up.
+                                    EntityType::Monster if dead => {
+                                        networking_system.player_move(WorldPosition { x: position.x, y: position.y })
+                                    }
+                                    EntityType::Monster => {
+                                        // The server walks up to the monster by itself, so the walk is
+                                        // only predicted.
+                                        if walk_prediction && let Some(player) = entities.first_mut() {
+                                            player.predict_move(&map, position, player_attack_range, client_tick);
+                                        }
 
-                            if let Some(entity) = entity {
-                                let _ = match entity.get_entity_type() {
-                                    EntityType::Npc => networking_system.start_dialog(entity_id),
-                                    EntityType::Monster => networking_system.player_attack(entity_id),
-                                    EntityType::Warp => networking_system.player_move({
-                                        let position = entity.get_grid_position();
-                                        WorldPosition { x: position.x, y: position.y }
-                                    }),
+                                        networking_system.player_attack(entity_id)
+                                    }
+                                    EntityType::Warp => networking_system.player_move(WorldPosition { x: position.x, y: position.y }),
                                     _ => Ok(())
                                 };
                             }
                         }
+                        UserEvent::RequestPlayerAttack(entity_id) => {
+                            let _ = networking_system.player_attack(entity_id);
+                        }
                         UserEvent::RequestWarpToMap(map_name, position) => {
                             let _ = networking_system.warp_to_map(map_name, position);
                         },
@@ -1196,6 +2674,139 @@ fn main() {
                             // this becomes problematic
                             focus_state.remove_focus();
                         }
+                        UserEvent::SendEmotion(emotion) => {
+                            let _ = networking_system.send_emotion(emotion);
+                        }
+                        UserEvent::SendWhisper { recipient_name, message } => {
+                            let text = format!("(To {}) : {}", recipient_name, message);
+                            let text = mask_whisper(text, privacy_settings.get().streamer_mode);
+
+                            chat_messages.push(ChatMessage::new(text, MessageColor::Whisper));
+
+                            let _ = networking_system.send_whisper(recipient_name, message);
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::InvalidChatCommand(message) => {
+                            chat_messages.push(ChatMessage::new(message, MessageColor::Error));
+                        }
+                        UserEvent::SitDown => {
+                            let _ = networking_system.sit_down();
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::ToggleSitting => {
+                            let _ = match entities.first().is_some_and(|player| player.is_sitting()) {
+                                true => networking_system.stand_up(),
+                                false => networking_system.sit_down(),
+                            };
+                        }
+                        UserEvent::StandUp => {
+                            let _ = networking_system.stand_up();
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::ShowPlayerPosition => {
+                            let position = entities[0].get_grid_position();
+
+                            chat_messages.push(ChatMessage::new(
+                                format!("{} ({}, {})", current_map_name, position.x, position.y),
+                                MessageColor::Information,
+                            ));
+                        }
+                        UserEvent::RememberWarpPoint => {
+                            let _ = networking_system.remember_warp_point();
+                        }
+                        UserEvent::ToggleBattleMode => {
+                            let text = match input_system.toggle_battle_mode() {
+                                true => "Battle mode enabled.",
+                                false => "Battle mode disabled.",
+                            };
+
+                            chat_messages.push(ChatMessage::new(text.to_owned(), MessageColor::Information));
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::ToggleStreamerMode => {
+                            let streamer_mode = privacy_settings.mutate(|settings| {
+                                settings.streamer_mode = !settings.streamer_mode;
+                                settings.streamer_mode
+                            });
+
+                            let text = match streamer_mode {
+                                true => "Streamer mode enabled.",
+                                false => "Streamer mode disabled.",
+                            };
+
+                            chat_messages.push(ChatMessage::new(text.to_owned(), MessageColor::Information));
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::GreetFriends(message) => {
+                            for (friend, _) in friend_list.get().iter() {
+                                let _ = networking_system.send_whisper(friend.name.clone(), message.clone());
+                            }
+
+                            focus_state.remove_focus();
+                        }
+                        UserEvent::CreateGuild(guild_name) => {
+                            if let Some(character_id) = saved_character_id {
+                                let _ = networking_system.create_guild(character_id, guild_name);
+                            }
+                        }
+                        UserEvent::RequestAchievementReward(achievement_id) => {
+                            let _ = networking_system.request_achievement_reward(achievement_id);
+                        }
+                        UserEvent::ChangeTitle(title_id) => {
+                            let _ = networking_system.change_title(title_id);
+                        }
+                        UserEvent::OpenBank => {
+                            let Some(account_id) = saved_login_data.as_ref().map(|login_data| login_data.account_id) else {
+                                continue;
+                            };
+                            let _ = networking_system.open_bank(account_id);
+                        }
+                        UserEvent::CloseBank => {
+                            interface.close_window_with_class(&mut focus_state, BankWindow::WINDOW_CLASS);
+
+                            let Some(account_id) = saved_login_data.as_ref().map(|login_data| login_data.account_id) else {
+                                continue;
+                            };
+                            let _ = networking_system.close_bank(account_id);
+                        }
+                        UserEvent::DepositZeny(amount) => {
+                            let Some(account_id) = saved_login_data.as_ref().map(|login_data| login_data.account_id) else {
+                                continue;
+                            };
+                            let _ = networking_system.deposit_zeny(account_id, amount);
+                        }
+                        UserEvent::WithdrawZeny(amount) => {
+                            let Some(account_id) = saved_login_data.as_ref().map(|login_data| login_data.account_id) else {
+                                continue;
+                            };
+                            let _ = networking_system.withdraw_zeny(account_id, amount);
+                        }
+                        UserEvent::ClaimAttendanceReward => {
+                            let _ = networking_system.claim_attendance_reward();
+                        }
+                        UserEvent::SelectRefineItem(index) => {
+                            let _ = networking_system.select_refine_item(index);
+                        }
+                        UserEvent::RefineItem {
+                            index,
+                            material_id,
+                            use_blacksmith_blessing,
+                        } => {
+                            let _ = networking_system.refine_item(index, material_id, use_blacksmith_blessing);
+                        }
+                        UserEvent::SendPetCommand(command) => {
+                            let _ = networking_system.send_pet_command(command);
+
+                            if command == PetCommand::ReturnToEgg {
+                                pet.clear();
+                            }
+                        }
+                        UserEvent::CloseRefineWindow => {
+                            let _ = networking_system.close_refine_window();
+
+                            refinement.clear();
+                            interface.close_window_with_class(&mut focus_state, RefineWindow::WINDOW_CLASS);
+                        }
                         UserEvent::NextDialog(npc_id) => {
                             let _ = networking_system.next_dialog(npc_id);
                         },
@@ -1236,11 +2847,27 @@ fn main() {
                                     }
                                     _ => {}
                                 },
+                                Move::ChatTab { source, destination } => {
+                                    let selected = *selected_chat_tab.get();
+                                    let selected = chat_tabs.mutate(|tabs| move_chat_tab(tabs, selected, source, destination));
+                                    selected_chat_tab.set(selected);
+                                }
                             }
                         },
-                        UserEvent::CastSkill(slot) => {
+                        UserEvent::CastSkill(slot) | UserEvent::ConfirmSkill(slot) => {
                             if let Some(skill) = hotbar.get_skill_in_slot(slot).as_ref() {
+                                // Without smart cast, targeted skills wait for a click on their target.
+                                // Gamepad casts can't be aimed, so they are always cast right away.
+                                let wait_for_target = !matches!(event, UserEvent::ConfirmSkill(_))
+                                    && matches!(
+                                        skill.skill_type,
+                                        SkillType::Attack | SkillType::Ground | SkillType::Trap | SkillType::Support
+                                    )
+                                    && !hotbar.is_smart_cast(slot)
+                                    && !input_system.is_gamepad_active();
+
                                 match skill.skill_type {
+                                    _ if wait_for_target => input_system.start_skill_targeting(slot),
                                     SkillType::Passive => {}
                                     SkillType::Attack => {
                                         if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
@@ -1274,6 +2901,7 @@ fn main() {
                                 }
                             }
                         }
+                        UserEvent::ToggleSmartCast(slot) => hotbar.toggle_smart_cast(slot),
                         UserEvent::StopSkill(slot) => {
                             if let Some(skill) = hotbar.get_skill_in_slot(slot).as_ref() {
                                 if skill.skill_id == ROLLING_CUTTER_ID {
@@ -1292,13 +2920,8 @@ fn main() {
                         UserEvent::RemoveFriend { account_id, character_id } => {
                             let _ = networking_system.remove_friend(account_id, character_id);
                         }
-                        UserEvent::RejectFriendRequest { account_id, character_id } => {
-                            let _ = networking_system.reject_friend_request(account_id, character_id);
-                            interface.close_window_with_class(&mut focus_state, FriendRequestWindow::WINDOW_CLASS);
-                        }
-                        UserEvent::AcceptFriendRequest { account_id, character_id } => {
-                            let _ = networking_system.accept_friend_request(account_id, character_id);
-                            interface.close_window_with_class(&mut focus_state, FriendRequestWindow::WINDOW_CLASS);
+                        UserEvent::RespondToRequest { request, accept } => {
+                            let _ = networking_system.respond_to_request(&request, accept);
                         }
                         UserEvent::BuyItems { items } => {
                             let _ = networking_system.purchase_items(items);
@@ -1321,6 +2944,10 @@ fn main() {
                         UserEvent::FocusChatWindow => {
                             interface.focus_window_with_class(&mut focus_state, ChatWindow::WINDOW_CLASS);
                         },
+                        UserEvent::InsertChatText(text) => {
+                            input_system.insert_chat_text(&text);
+                            interface.focus_window_with_class(&mut focus_state, ChatWindow::WINDOW_CLASS);
+                        }
                         #[cfg(feature = "debug")]
                         UserEvent::OpenMarkerDetails(marker_identifier) => {
                             interface.open_window(&application, &mut focus_state, map.resolve_marker(&entities, marker_identifier))
@@ -1347,15 +2974,14 @@ fn main() {
                         UserEvent::SetDusk => game_timer.set_day_timer(std::f32::consts::PI),
                         #[cfg(feature = "debug")]
                         UserEvent::SetMidnight => game_timer.set_day_timer(-std::f32::consts::FRAC_PI_2),
-                        #[cfg(feature = "debug")]
-                        UserEvent::OpenThemeViewerWindow => {
+                        UserEvent::OpenThemeEditorWindow => {
                             interface.open_window(&application, &mut focus_state, application.theme_window())
                         }
                         #[cfg(feature = "debug")]
                         UserEvent::OpenProfilerWindow => interface.open_window(&application, &mut focus_state, &ProfilerWindow::new()),
                         #[cfg(feature = "debug")]
                         UserEvent::OpenPacketWindow => {
-                            interface.open_window(&application, &mut focus_state, &PacketWindow::new(packet_callback.remote(), PlainTrackedState::default()))
+                            interface.open_window(&application, &mut focus_state, &PacketWindow::new(packet_callback.remote()))
                         }
                         #[cfg(feature = "debug")]
                         UserEvent::ClearPacketHistory => packet_callback.clear_all(),
@@ -1378,6 +3004,37 @@ fn main() {
                     }
                 }
 
+                for event in updater.poll() {
+                    match event {
+                        UpdaterEvent::UpdateAvailable(release) => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &UpdateWindow::new(release.version, release.changelog),
+                        ),
+                        UpdaterEvent::Downloaded { version } => notifications.push(
+                            NotificationKind::Warning,
+                            format!("Version {version} will be installed the next time Korangar starts"),
+                        ),
+                        UpdaterEvent::Failed(message) => notifications.push(NotificationKind::Warning, message),
+                    }
+                }
+
+                for action in plugin_system.take_actions() {
+                    match action {
+                        PluginAction::SendMessage(message) => {
+                            let _ = networking_system.send_chat_message(&saved_player_name, &message);
+                        }
+                        PluginAction::Print(message) => chat_messages.push(ChatMessage::new(message, MessageColor::Information)),
+                        PluginAction::OpenWindow { title, lines } => {
+                            interface.open_window(&application, &mut focus_state, &PluginWindow::new(title, lines));
+                        }
+                        PluginAction::Error { plugin, message } => {
+                            let message = format!("Plugin {plugin} was disabled: {message}");
+                            chat_messages.push(ChatMessage::new(message, MessageColor::Error));
+                        }
+                    }
+                }
+
                 #[cfg(feature = "debug")]
                 user_event_measurement.stop();
 
@@ -1388,9 +3045,55 @@ fn main() {
                 #[cfg(feature = "debug")]
                 let update_entities_measurement = Profiler::start_measurement("update entities");
 
-                entities
-                    .iter_mut()
-                    .for_each(|entity| entity.update(&map, delta_time as f32, client_tick));
+                let smooth_movement = *smooth_movement.get();
+                let interpolation_delay = *interpolation_delay.get();
+                entities.iter_mut().enumerate().for_each(|(index, entity)| {
+                    // The player is the first entity and walks without a delay.
+                    let interpolation_delay = match index {
+                        0 => 0,
+                        _ => interpolation_delay,
+                    };
+
+                    entity.update(&map, delta_time as f32, client_tick, smooth_movement, interpolation_delay);
+                });
+
+                let corpse_duration = *corpse_duration.get();
+                let removed_corpses: Vec<EntityId> = entities
+                    .iter()
+                    .filter(|entity| entity.should_be_removed(corpse_duration, client_tick))
+                    .map(|entity| entity.get_entity_id())
+                    .collect();
+
+                for entity_id in removed_corpses {
+                    plugin_system.entity_despawned(entity_id);
+                    entities.remove(entity_id);
+                }
+
+                update_minimap(&mut minimap_data, &entities, party_member_positions.values().copied());
+
+                for entity in entities.iter_mut() {
+                    if let Some(tile_position) = entity.take_footstep() {
+                        let material = map.get_sound_material(tile_position);
+                        sound_state.play(&mut audio_engine, SoundEvent::Footstep, material, entity.get_position());
+                    }
+                }
+
+                for entity in entities.iter_mut() {
+                    let Some(emblem_id) = entity.get_unavailable_guild_emblem() else {
+                        continue;
+                    };
+
+                    match guild_emblem_cache.get(emblem_id) {
+                        Some(texture) => entity.set_guild_emblem(texture),
+                        None => {
+                            if guild_emblem_cache.request(emblem_id) {
+                                let _ = networking_system.request_guild_emblem(emblem_id.guild_id);
+                            }
+
+                            entity.set_guild_emblem_requested();
+                        }
+                    }
+                }
 
                 #[cfg(feature = "debug")]
                 update_entities_measurement.stop();
@@ -1406,17 +3109,56 @@ fn main() {
 
                 start_camera.update(delta_time);
                 player_camera.update(delta_time);
+
+                if let Some(benchmark) = &benchmark {
+                    let (focus_point, view_angle) = camera_path(benchmark.elapsed().as_secs_f32(), map.get_size());
+                    let height = map.get_world_position(focus_point.map(|coordinate| coordinate as usize)).y;
+                    let focus_point = cgmath::Point3::new(focus_point.x * 5.0, height, focus_point.y * 5.0);
+
+                    start_camera.set_focus_point(focus_point);
+                    start_camera.set_view_angle(view_angle);
+                    directional_shadow_camera.set_focus_point(focus_point);
+                }
+
                 directional_shadow_camera.update(day_timer);
 
+                let listener_position = player_camera.get_focus_point().to_vec();
+                let listener_velocity = match previous_listener_position {
+                    Some(previous_position) if delta_time > 0.0 => (listener_position - previous_position) / delta_time as f32,
+                    _ => Vector3::zero(),
+                };
+                previous_listener_position = Some(listener_position);
+                audio_engine.set_listener(listener_position, player_camera.get_orientation(), listener_velocity);
+
                 #[cfg(feature = "debug")]
                 update_cameras_measurement.stop();
 
-                particle_holder.update(delta_time as f32);
-                effect_holder.update(&entities, delta_time as f32);
+                combat_texts.update(delta_time as f32);
+                effect_holder.update(&entities, &player_camera, delta_time as f32);
+                attendance.update();
+
+                for text in player_inventory.take_rental_warnings() {
+                    notifications.push_with_action(NotificationKind::Warning, text, UserEvent::OpenInventoryWindow);
+                }
+
+                notifications.update(
+                    &deferred_renderer,
+                    application.get_game_theme(),
+                    &application,
+                    swapchain_holder.window_screen_size(),
+                );
 
                 let (clear_interface, render_interface) = interface.update(&application, font_loader.clone(), &mut focus_state);
                 mouse_cursor.update(client_tick);
 
+                if render_scale.consume_changed() | dynamic_render_scale.consume_changed() {
+                    current_render_scale = *render_scale.get();
+                    dynamic_resolution = DynamicRenderScale::new(current_render_scale);
+                    render_scale_changed = true;
+                }
+
+                let render_scale_outdated = std::mem::take(&mut render_scale_changed);
+
                 if swapchain_holder.is_swapchain_invalid() {
                     #[cfg(feature = "debug")]
                     profile_block!("re-create buffers");
@@ -1426,6 +3168,7 @@ fn main() {
                     deferred_renderer.recreate_pipeline(
                         viewport.clone(),
                         swapchain_holder.window_size_u32(),
+                        current_render_scale,
                         #[cfg(feature = "debug")]
                         render_settings.get().show_wireframe,
                     );
@@ -1445,13 +3188,43 @@ fn main() {
                         .into_iter()
                         .map(|_| picker_renderer.create_render_target())
                         .collect();
+                } else if render_scale_outdated {
+                    #[cfg(feature = "debug")]
+                    profile_block!("re-create scene buffers");
+
+                    deferred_renderer.recreate_pipeline(
+                        swapchain_holder.viewport(),
+                        swapchain_holder.window_size_u32(),
+                        current_render_scale,
+                        #[cfg(feature = "debug")]
+                        render_settings.get().show_wireframe,
+                    );
+
+                    screen_targets = swapchain_holder
+                        .get_swapchain_images()
+                        .into_iter()
+                        .map(|swapchain_image| deferred_renderer.create_render_target(swapchain_image))
+                        .collect();
                 }
 
+                let swapchain_wait_start = Instant::now();
+
                 if swapchain_holder.acquire_next_image().is_err() {
                     // temporary check?
                     return;
                 }
 
+                let swapchain_wait = swapchain_wait_start.elapsed();
+
+                if audio_settings_remote.consume_changed() {
+                    audio_settings.get().apply(&mut audio_engine);
+                }
+
+                chat_log.update(&chat_messages.get(), chat_settings.get().save_chat_log);
+                mark_chat_tab_read(&mut chat_tabs, *selected_chat_tab.get(), chat_messages.get().len());
+
+                audio_engine.update();
+
                 if shadow_detail.consume_changed() {
                     #[cfg(feature = "debug")]
                     print_debug!("re-creating {}", "directional shadow targets".magenta());
@@ -1468,8 +3241,13 @@ fn main() {
                         .collect::<Vec<<ShadowRenderer as Renderer>::Target>>();
                 }
 
+                if automatic_scaling.consume_changed() {
+                    // The scaling affects the layout of every window.
+                    interface.schedule_resolve();
+                }
+
                 if framerate_limit.consume_changed() {
-                    swapchain_holder.set_frame_limit(present_mode_info, framerate_limit.cloned());
+                    swapchain_holder.set_frame_limit(present_mode_info, framerate_limit.cloned() && benchmark.is_none());
 
                     // For some reason the interface buffer becomes messed up when
                     // recreating the swapchain, so we need to render it again.
@@ -1479,7 +3257,7 @@ fn main() {
                 #[cfg(feature = "debug")]
                 let matrices_measurement = Profiler::start_measurement("generate view and projection matrices");
 
-                if entities.is_empty() {
+                if entities.is_empty() || benchmark.is_some() {
                     start_camera.generate_view_projection(swapchain_holder.window_size());
                 }
 
@@ -1493,13 +3271,15 @@ fn main() {
                 #[cfg(feature = "debug")]
                 matrices_measurement.stop();
 
-                let current_camera: &(dyn Camera + Send + Sync) = match entities.is_empty() {
+                let current_camera: &(dyn Camera + Send + Sync) = match entities.is_empty() || benchmark.is_some() {
                     #[cfg(feature = "debug")]
                     _ if render_settings.get().use_debug_camera => &debug_camera,
                     true => &start_camera,
                     false => &player_camera,
                 };
 
+                let gpu_wait_start = Instant::now();
+
                 if let Some(mut fence) = screen_targets[swapchain_holder.get_image_number()].state.try_take_fence() {
                     #[cfg(feature = "debug")]
                     profile_block!("wait for frame in current slot");
@@ -1508,6 +3288,18 @@ fn main() {
                     fence.cleanup_finished();
                 }
 
+                let gpu_wait = gpu_wait_start.elapsed();
+
+                let pass_times = gpu_timer
+                    .as_ref()
+                    .and_then(|gpu_timer| gpu_timer.read(swapchain_holder.get_image_number()));
+
+                if let Some(benchmark) = &mut benchmark
+                    && let Some(pass_times) = pass_times
+                {
+                    benchmark.record_passes(pass_times);
+                }
+
                 if let Some(mut fence) = buffer_fence {
                     #[cfg(feature = "debug")]
                     profile_block!("wait for buffers");
@@ -1544,6 +3336,15 @@ fn main() {
                 let window_size = swapchain_holder.window_screen_size();
                 let window_size_u32 = swapchain_holder.window_size_u32();
                 let entities = &entities[..];
+                let render_quest_markers = *show_quest_markers.get();
+                let object_bounding_boxes = map.get_object_bounding_box_buffer();
+                #[cfg(feature = "debug")]
+                let object_bounding_boxes = object_bounding_boxes.filter(|_| render_settings.occlusion_culling);
+                // The fence of the render target was waited for above, so the result of the
+                // occlusion test from the last time it was rendered to can be read.
+                let visible_objects = object_bounding_boxes
+                    .as_ref()
+                    .and_then(|bounding_boxes| screen_target.visible_objects(bounding_boxes));
                 #[cfg(feature = "debug")]
                 let hovered_marker_identifier = match mouse_target {
                     Some(PickerTarget::Marker(marker_identifier)) => Some(marker_identifier),
@@ -1562,6 +3363,10 @@ fn main() {
 
                         picker_target.start();
 
+                        if let Some(gpu_timer) = &gpu_timer {
+                            gpu_timer.begin(picker_target.state.get_builder(), image_number, MeasuredPass::Picker);
+                        }
+
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_map))]
                         map.render_tiles(picker_target, &picker_renderer, current_camera);
 
@@ -1578,6 +3383,10 @@ fn main() {
                             hovered_marker_identifier,
                         );
 
+                        if let Some(gpu_timer) = &gpu_timer {
+                            gpu_timer.end(picker_target.state.get_builder(), image_number, MeasuredPass::Picker);
+                        }
+
                         picker_target.finish();
                     });
 
@@ -1589,6 +3398,20 @@ fn main() {
 
                         directional_shadow_target.start();
 
+                        if let Some(gpu_timer) = &gpu_timer {
+                            gpu_timer.begin(directional_shadow_target.state.get_builder(), image_number, MeasuredPass::Shadow);
+                        }
+
+                        let culled_draws = map.cull_objects(
+                            directional_shadow_target,
+                            &shadow_renderer,
+                            &directional_shadow_camera,
+                            #[cfg(feature = "debug")]
+                            render_settings.frustum_culling,
+                        );
+
+                        directional_shadow_target.begin_render_pass();
+
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_map))]
                         map.render_ground(
                             directional_shadow_target,
@@ -1604,8 +3427,8 @@ fn main() {
                             &directional_shadow_camera,
                             client_tick,
                             animation_timer,
-                            #[cfg(feature = "debug")]
-                            render_settings.frustum_culling,
+                            None,
+                            culled_draws,
                         );
 
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
@@ -1630,6 +3453,10 @@ fn main() {
                             );
                         }
 
+                        if let Some(gpu_timer) = &gpu_timer {
+                            gpu_timer.end(directional_shadow_target.state.get_builder(), image_number, MeasuredPass::Shadow);
+                        }
+
                         directional_shadow_target.finish();
                     });
 
@@ -1639,6 +3466,20 @@ fn main() {
 
                         screen_target.start();
 
+                        if let Some(gpu_timer) = &gpu_timer {
+                            gpu_timer.begin(screen_target.state.get_builder(), image_number, MeasuredPass::Deferred);
+                        }
+
+                        let culled_draws = map.cull_objects(
+                            screen_target,
+                            &deferred_renderer,
+                            current_camera,
+                            #[cfg(feature = "debug")]
+                            render_settings.frustum_culling,
+                        );
+
+                        screen_target.geometry_pass();
+
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_map))]
                         map.render_ground(screen_target, &deferred_renderer, current_camera, animation_timer);
 
@@ -1654,8 +3495,8 @@ fn main() {
                             current_camera,
                             client_tick,
                             animation_timer,
-                            #[cfg(feature = "debug")]
-                            render_settings.frustum_culling,
+                            visible_objects.as_deref(),
+                            culled_draws,
                         );
 
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
@@ -1701,33 +3542,6 @@ fn main() {
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_water && !render_settings.show_buffers()))]
                         map.water_light(screen_target, &deferred_renderer, current_camera);
 
-                        #[cfg(feature = "debug")]
-                        map.render_markers(
-                            screen_target,
-                            &deferred_renderer,
-                            current_camera,
-                            render_settings,
-                            entities,
-                            hovered_marker_identifier,
-                        );
-
-                        #[cfg(feature = "debug")]
-                        if render_settings.show_bounding_boxes {
-                            map.render_bounding(
-                                screen_target,
-                                &deferred_renderer,
-                                current_camera,
-                                &player_camera,
-                                render_settings.frustum_culling,
-                            );
-                        }
-
-                        #[cfg(feature = "debug")]
-                        if let Some(marker_identifier) = hovered_marker_identifier {
-                            map.render_marker_box(screen_target, &deferred_renderer, current_camera, marker_identifier);
-                        }
-
-                        particle_holder.render(screen_target, &deferred_renderer, current_camera, window_size, entities);
                         effect_holder.render(screen_target, &deferred_renderer, current_camera);
                     });
 
@@ -1768,45 +3582,192 @@ fn main() {
                     );
                 }
 
-                if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
+                let bloom_intensity = *bloom_intensity.get();
+                // Bloom would blur the buffers, making them harder to read.
+                #[cfg(feature = "debug")]
+                let bloom_intensity = match render_settings.show_buffers() {
+                    true => 0.0,
+                    false => bloom_intensity,
+                };
+
+                deferred_renderer.post_process(
+                    screen_target,
+                    current_camera,
+                    object_bounding_boxes,
+                    bloom_intensity,
+                    *bloom_threshold.get(),
+                    *sharpen_upscaling.get(),
+                );
+
+                #[cfg(feature = "debug")]
+                map.render_markers(
+                    screen_target,
+                    &deferred_renderer,
+                    current_camera,
+                    render_settings,
+                    entities,
+                    hovered_marker_identifier,
+                );
+
+                #[cfg(feature = "debug")]
+                if render_settings.show_bounding_boxes {
+                    map.render_bounding(
+                        screen_target,
+                        &deferred_renderer,
+                        current_camera,
+                        &player_camera,
+                        render_settings.frustum_culling,
+                        visible_objects.as_deref(),
+                    );
+                }
+
+                #[cfg(feature = "debug")]
+                if let Some(marker_identifier) = hovered_marker_identifier {
+                    map.render_marker_box(screen_target, &deferred_renderer, current_camera, marker_identifier);
+                }
+
+                particle_holder.render(
+                    screen_target,
+                    &deferred_renderer,
+                    current_camera,
+                    window_size,
+                    entities,
+                    render_quest_markers,
+                );
+                combat_texts.render(screen_target, &deferred_renderer, current_camera, window_size);
+
+                if entities.len() > 1 {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render entity status");
+
+                    let hovered_entity_id = match mouse_target {
+                        Some(PickerTarget::Entity(entity_id)) => Some(entity_id),
+                        _ => None,
+                    };
+                    let health_bar_visibility = *health_bar_visibility.get();
+
+                    for entity in entities.iter().skip(1) {
+                        let hovered = hovered_entity_id == Some(entity.get_entity_id());
+
+                        if entity.is_status_visible(health_bar_visibility, hovered, client_tick) {
+                            entity.render_status(
+                                screen_target,
+                                &deferred_renderer,
+                                current_camera,
+                                application.get_game_theme(),
+                                window_size,
+                            );
+                        }
+                    }
+                }
+
+                if !entities.is_empty() {
                     #[cfg(feature = "debug")]
-                    profile_block!("render hovered entity status");
+                    profile_block!("render name labels");
+
+                    let hovered_entity_id = match mouse_target {
+                        Some(PickerTarget::Entity(entity_id)) => Some(entity_id),
+                        _ => None,
+                    };
+                    let show_all_names = input_system.show_all_names();
+                    let player_id = entities[0].get_entity_id();
+                    let streamer_mode = privacy_settings.get().streamer_mode;
+
+                    for entity in entities.iter() {
+                        let entity_id = entity.get_entity_id();
 
-                    let entity = entities.iter().find(|entity| entity.get_entity_id() == entity_id);
+                        let is_shown = hovered_entity_id == Some(entity_id) || (show_all_names && has_name_label(entity));
 
-                    if let Some(entity) = entity {
-                        entity.render_status(
+                        if !is_shown {
+                            continue;
+                        }
+
+                        let Some(name) = entity.get_details() else {
+                            continue;
+                        };
+
+                        let name = name.split('#').next().unwrap();
+                        // The player can always see their own name and affiliation.
+                        let is_other_player = entity.get_entity_type() == EntityType::Player && entity_id != player_id;
+                        let name = match is_other_player {
+                            true => display_name(name, streamer_mode),
+                            false => name.into(),
+                        };
+
+                        entity.render_name_label(
                             screen_target,
                             &deferred_renderer,
                             current_camera,
+                            &name,
+                            !(is_other_player && streamer_mode),
                             application.get_game_theme(),
                             window_size,
                         );
+                    }
+                }
 
-                        if let Some(name) = &entity.get_details() {
-                            let name = name.split('#').next().unwrap();
+                battlefield.render_name_plates(
+                    screen_target,
+                    &deferred_renderer,
+                    current_camera,
+                    application.get_game_theme(),
+                    window_size,
+                    &entities,
+                    privacy_settings.get().streamer_mode,
+                );
 
-                            let offset = ScreenPosition {
-                                left: name.len() as f32 * -3.0,
-                                top: 20.0,
-                            };
+                if !entities.is_empty() {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render emotions");
 
-                            deferred_renderer.render_text(
-                                screen_target,
-                                name,
-                                input_system.get_mouse_position() + offset + ScreenPosition::uniform(1.0),
-                                Color::monochrome_u8(0),
-                                FontSize::new(12.0),
-                            ); // TODO: move variables into theme
+                    for entity in entities.iter() {
+                        entity.render_emotion(
+                            screen_target,
+                            &deferred_renderer,
+                            current_camera,
+                            &emotions,
+                            window_size,
+                            &application,
+                        );
+                    }
+                }
 
-                            deferred_renderer.render_text(
-                                screen_target,
-                                name,
-                                input_system.get_mouse_position() + offset,
-                                Color::monochrome_u8(255),
-                                FontSize::new(12.0),
-                            );
-                        }
+                if !entities.is_empty() {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render status effects");
+
+                    for entity in entities.iter() {
+                        entity.render_status_effects(
+                            screen_target,
+                            &deferred_renderer,
+                            current_camera,
+                            application.get_game_theme(),
+                            window_size,
+                        );
+                    }
+                }
+
+                if !entities.is_empty() {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render overhead texts");
+
+                    // Only the newest bubbles are shown so crowded places stay readable.
+                    let mut speaking_entities: Vec<_> = entities
+                        .iter()
+                        .filter_map(|entity| Some((entity, entity.get_overhead_text()?.start_time())))
+                        .collect();
+                    speaking_entities.sort_by(|(_, left), (_, right)| right.cmp(left));
+                    speaking_entities.truncate(MAXIMUM_OVERHEAD_TEXTS);
+
+                    for (entity, _) in speaking_entities {
+                        entity.render_overhead_text(
+                            screen_target,
+                            &deferred_renderer,
+                            current_camera,
+                            application.get_game_theme(),
+                            window_size,
+                            client_tick,
+                        );
                     }
                 }
 
@@ -1836,9 +3797,114 @@ fn main() {
                     );
                 }
 
+                if *show_frame_overlay.get() {
+                    frame_statistics.render(screen_target, &deferred_renderer, application.get_game_theme(), &application);
+                }
+
+                if let Some(selected_slot) = input_system.get_radial_menu() {
+                    let skill_names: Vec<Option<String>> = (0..HOTBAR_ROW_SIZE as u16)
+                        .map(|slot| hotbar.get_skill_in_slot(HotbarSlot(slot)).as_ref().map(|skill| skill.skill_name.clone()))
+                        .collect();
+
+                    render_radial_menu(
+                        screen_target,
+                        &deferred_renderer,
+                        application.get_game_theme(),
+                        &application,
+                        window_size,
+                        &skill_names,
+                        selected_slot,
+                    );
+                }
+
+                if *show_quest_tracker.get() {
+                    render_quest_tracker(
+                        screen_target,
+                        &deferred_renderer,
+                        application.get_game_theme(),
+                        &application,
+                        window_size,
+                        &quest_log.get_quest_list(),
+                    );
+                }
+
+                notifications.render(screen_target, &deferred_renderer, application.get_game_theme(), &application);
+
+                memorial_dungeon.render_banner(
+                    screen_target,
+                    &deferred_renderer,
+                    application.get_game_theme(),
+                    &application,
+                    window_size,
+                );
+
+                battlefield.render_hud(
+                    screen_target,
+                    &deferred_renderer,
+                    application.get_game_theme(),
+                    &application,
+                    window_size,
+                    &entities,
+                );
+
+                if !entities.is_empty()
+                    && let Some(ping) = latency_tracker.get_ping()
+                {
+                    let game_theme = application.get_game_theme();
+                    let text = format!("{} ms", ping.as_millis());
+                    let font_size = game_theme.indicator.ping_font_size.get().scaled(application.get_scaling());
+                    let offset = game_theme.indicator.ping_offset.get() * application.get_scaling_factor();
+
+                    let text_size = deferred_renderer.get_text_dimensions(&text, font_size);
+                    let position = ScreenPosition {
+                        left: window_size.width - offset - text_size.width,
+                        top: window_size.height - offset - text_size.height,
+                    };
+
+                    deferred_renderer.render_text(screen_target, &text, position, game_theme.indicator.ping.get(), font_size);
+                }
+
+                if input_system.is_battle_mode() {
+                    let game_theme = application.get_game_theme();
+                    let text = "Battle mode";
+                    let font_size = game_theme.indicator.battle_mode_font_size.get().scaled(application.get_scaling());
+
+                    let text_width = deferred_renderer.get_text_dimensions(text, font_size).width;
+                    let position = ScreenPosition {
+                        left: (window_size.width - text_width) / 2.0,
+                        top: game_theme.indicator.battle_mode_offset.get() * application.get_scaling_factor(),
+                    };
+
+                    deferred_renderer.render_text(screen_target, text, position, game_theme.indicator.battle_mode.get(), font_size);
+                }
+
                 if show_interface {
                     deferred_renderer.overlay_interface(screen_target, interface_target.image.clone());
 
+                    if let Some(text) = tooltip_tracker.get_text() {
+                        let game_theme = application.get_game_theme();
+                        let scaling = application.get_scaling_factor();
+                        let font_size = game_theme.tooltip.font_size.get().scaled(application.get_scaling());
+                        let padding = game_theme.tooltip.padding.get() * scaling;
+                        let offset = game_theme.tooltip.offset.get() * scaling;
+                        let mouse_position = input_system.get_mouse_position();
+
+                        let size = deferred_renderer.get_text_dimensions(text, font_size) + ScreenSize::uniform(padding * 2.0);
+                        let position = ScreenPosition {
+                            left: place_tooltip(mouse_position.left, size.width, window_size.width, offset),
+                            top: place_tooltip(mouse_position.top, size.height, window_size.height, offset),
+                        };
+
+                        deferred_renderer.render_rectangle(screen_target, position, size, game_theme.tooltip.background_color.get());
+                        deferred_renderer.render_text(
+                            screen_target,
+                            text,
+                            position + ScreenSize::uniform(padding),
+                            game_theme.tooltip.foreground_color.get(),
+                            font_size,
+                        );
+                    }
+
                     mouse_cursor.render(
                         screen_target,
                         &deferred_renderer,
@@ -1864,10 +3930,65 @@ fn main() {
                     .join(swapchain_acquire_future)
                     .boxed();
 
+                if let Some(gpu_timer) = &gpu_timer {
+                    gpu_timer.end(screen_target.state.get_builder(), image_number, MeasuredPass::Deferred);
+                }
+
                 screen_target.finish(swapchain_holder.get_swapchain(), combined_future, image_number);
 
+                let cpu_time = frame_start.elapsed().saturating_sub(gpu_wait + swapchain_wait);
+
+                metrics_exporter.set_enabled(*export_metrics.get());
+                metrics_exporter.record_frame(FrameMetrics {
+                    frame_time: Duration::from_secs_f64(delta_time),
+                    cpu_time,
+                    gpu_wait,
+                    gpu_passes: pass_times,
+                });
+
+                // The pass times belong to the last frame that was rendered to this
+                // swapchain image, which is close enough to average over.
+                let gpu_time: Duration = pass_times.map(|pass_times| pass_times.iter().sum()).unwrap_or_default();
+
+                if *dynamic_render_scale.get()
+                    && benchmark.is_none()
+                    && let Some(scale) = dynamic_resolution.record_frame(cpu_time + gpu_time, frame_pacer.frame_time(), *render_scale.get())
+                {
+                    current_render_scale = scale;
+                    render_scale_changed = true;
+                }
+
+                if let Some(benchmark) = &mut benchmark
+                    && benchmark.record_frame(Duration::from_secs_f64(delta_time))
+                {
+                    match benchmark.write_report(&adapter_name) {
+                        Ok(path) => println!("benchmark report written to {}", path.display()),
+                        Err(error) => eprintln!("failed to write benchmark report: {error}"),
+                    }
+
+                    *control_flow = ControlFlow::Exit;
+                }
+
                 #[cfg(feature = "debug")]
                 finalize_frame_measurement.stop();
+
+                #[cfg(feature = "debug")]
+                let background_measurement = Profiler::start_measurement("background tasks");
+
+                background_tasks.run(&frame_pacer, |task| {
+                    let more_left = match task {
+                        BackgroundTask::EvictMaps => map_loader.evict_unused(EVICTION_SLICE),
+                        BackgroundTask::EvictModels => model_loader.evict_unused(EVICTION_SLICE),
+                        BackgroundTask::EvictTextures => texture_loader.evict_unused(EVICTION_SLICE),
+                        BackgroundTask::EvictSprites => sprite_loader.evict_unused(EVICTION_SLICE),
+                        BackgroundTask::EvictActions => action_loader.evict_unused(EVICTION_SLICE),
+                    };
+
+                    more_left.then_some(task)
+                });
+
+                #[cfg(feature = "debug")]
+                background_measurement.stop();
             }
             _ignored => {},
         }
@@ -0,0 +1,468 @@
+//! Lua plugins that extend the client without changing its code, for example
+//! loot filters or timers. Every plugin runs in its own Lua state that only
+//! has access to the `table`, `string` and `math` libraries and to the
+//! `korangar` table, so plugins can't touch files or other processes.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use mlua::{Function, HookTriggers, Lua, LuaOptions, MultiValue, StdLib, Table, ToLuaMulti, Value};
+use ragnarok_packets::EntityId;
+
+use crate::input::UserEvent;
+
+const PLUGIN_DIRECTORY: &str = "plugins";
+const HOOKS_KEY: &str = "korangar_hooks";
+/// Hooks that plugins can register a function for with `korangar.on`.
+const HOOK_NAMES: [&str; 5] = ["chat_message", "user_event", "entity_spawn", "entity_despawn", "update"];
+/// Longest time a plugin may run for a single hook or while loading, so an
+/// endless loop doesn't freeze the client.
+const TIME_BUDGET: Duration = Duration::from_millis(10);
+/// Number of instructions between checks of the time budget.
+const INSTRUCTION_INTERVAL: u32 = 1000;
+const MEMORY_LIMIT: usize = 16 * 1024 * 1024;
+/// Longest string that `string.rep` may create.
+const MAX_STRING_LENGTH: usize = 64 * 1024;
+/// Longest string that the pattern functions may search. Patterns are matched
+/// in C, where the time budget can't interrupt them, and some patterns take
+/// exponential time.
+const MAX_PATTERN_SUBJECT_LENGTH: usize = 16 * 1024;
+/// Functions of the string library that match patterns.
+const PATTERN_FUNCTIONS: [&str; 4] = ["find", "match", "gmatch", "gsub"];
+/// Globals of the base library that could load code from files or escape
+/// the sandbox.
+const REMOVED_GLOBALS: [&str; 7] = ["dofile", "loadfile", "load", "loadstring", "getfenv", "setfenv", "collectgarbage"];
+
+/// Something a plugin asked the client to do.
+#[derive(Debug, PartialEq)]
+pub enum PluginAction {
+    SendMessage(String),
+    /// Show a message in the chat without sending it.
+    Print(String),
+    OpenWindow {
+        title: String,
+        lines: Vec<String>,
+    },
+    /// A plugin failed and was disabled.
+    Error {
+        plugin: String,
+        message: String,
+    },
+}
+
+struct Plugin {
+    name: String,
+    state: Lua,
+    deadline: Rc<Cell<Option<Instant>>>,
+    enabled: bool,
+}
+
+impl Plugin {
+    fn new(name: String, source: &str, actions: Rc<RefCell<Vec<PluginAction>>>, macros_allowed: Rc<Cell<bool>>) -> mlua::Result<Self> {
+        let state = Lua::new_with(StdLib::TABLE | StdLib::STRING | StdLib::MATH, LuaOptions::new())?;
+        let deadline = Rc::new(Cell::new(None::<Instant>));
+
+        state.set_memory_limit(MEMORY_LIMIT)?;
+
+        let hook_deadline = deadline.clone();
+        state.set_hook(
+            HookTriggers {
+                every_nth_instruction: Some(INSTRUCTION_INTERVAL),
+                ..Default::default()
+            },
+            move |_, _| match hook_deadline.get().is_some_and(|deadline| Instant::now() > deadline) {
+                true => Err(mlua::Error::RuntimeError("plugin exceeded its time budget".to_owned())),
+                false => Ok(()),
+            },
+        )?;
+
+        Self::limit_string_library(&state)?;
+        Self::register_api(&state, actions, macros_allowed)?;
+
+        let plugin = Self {
+            name,
+            state,
+            deadline,
+            enabled: true,
+        };
+
+        plugin.with_budget(|state| state.load(source).exec())?;
+
+        Ok(plugin)
+    }
+
+    /// Replace the functions of the string library that can take a long time
+    /// or create huge strings in a single call with ones that check their
+    /// arguments first.
+    fn limit_string_library(state: &Lua) -> mlua::Result<()> {
+        let string: Table = state.globals().get("string")?;
+
+        let rep = state.create_registry_value(string.get::<_, Function>("rep")?)?;
+        string.set(
+            "rep",
+            state.create_function(move |state, (text, count): (mlua::String, i64)| {
+                if text.as_bytes().len().saturating_mul(count.max(0) as usize) > MAX_STRING_LENGTH {
+                    return Err(mlua::Error::RuntimeError(format!(
+                        "string.rep may not create strings longer than {MAX_STRING_LENGTH} bytes"
+                    )));
+                }
+
+                state.registry_value::<Function>(&rep)?.call::<_, mlua::String>((text, count))
+            })?,
+        )?;
+
+        for name in PATTERN_FUNCTIONS {
+            let function = state.create_registry_value(string.get::<_, Function>(name)?)?;
+
+            string.set(
+                name,
+                state.create_function(move |state, arguments: MultiValue| {
+                    let too_long = arguments
+                        .iter()
+                        .take(2)
+                        .any(|argument| matches!(argument, Value::String(text) if text.as_bytes().len() > MAX_PATTERN_SUBJECT_LENGTH));
+
+                    if too_long {
+                        return Err(mlua::Error::RuntimeError(format!(
+                            "string.{name} may not search strings longer than {MAX_PATTERN_SUBJECT_LENGTH} bytes"
+                        )));
+                    }
+
+                    state.registry_value::<Function>(&function)?.call::<_, MultiValue>(arguments)
+                })?,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn register_api(state: &Lua, actions: Rc<RefCell<Vec<PluginAction>>>, macros_allowed: Rc<Cell<bool>>) -> mlua::Result<()> {
+        let globals = state.globals();
+
+        for name in REMOVED_GLOBALS {
+            globals.set(name, Value::Nil)?;
+        }
+
+        let hooks = state.create_table()?;
+        for name in HOOK_NAMES {
+            hooks.set(name, state.create_table()?)?;
+        }
+        state.set_named_registry_value(HOOKS_KEY, hooks)?;
+
+        let api = state.create_table()?;
+
+        api.set(
+            "on",
+            state.create_function(|state, (name, function): (String, Function)| {
+                let hooks: Table = state.named_registry_value(HOOKS_KEY)?;
+                let Some(functions) = hooks.get::<_, Option<Table>>(name.as_str())? else {
+                    return Err(mlua::Error::RuntimeError(format!("unknown hook {name}")));
+                };
+
+                functions.raw_set(functions.raw_len() + 1, function)
+            })?,
+        )?;
+
+        let send_actions = actions.clone();
+        api.set(
+            "send_chat",
+            state.create_function(move |_, text: String| {
+                // Sending messages automatically is what the macro opt-out of a
+                // server is meant to prevent.
+                if !macros_allowed.get() {
+                    return Err(mlua::Error::RuntimeError(
+                        "sending chat messages from plugins is disabled on this server".to_owned(),
+                    ));
+                }
+
+                send_actions.borrow_mut().push(PluginAction::SendMessage(text));
+                Ok(())
+            })?,
+        )?;
+
+        let print_actions = actions.clone();
+        let print = state.create_function(move |_, text: String| {
+            print_actions.borrow_mut().push(PluginAction::Print(text));
+            Ok(())
+        })?;
+        api.set("print", print.clone())?;
+        globals.set("print", print)?;
+
+        api.set(
+            "open_window",
+            state.create_function(move |_, (title, lines): (String, Vec<String>)| {
+                actions.borrow_mut().push(PluginAction::OpenWindow { title, lines });
+                Ok(())
+            })?,
+        )?;
+
+        globals.set("korangar", api)
+    }
+
+    fn with_budget<T>(&self, run: impl FnOnce(&Lua) -> mlua::Result<T>) -> mlua::Result<T> {
+        self.deadline.set(Some(Instant::now() + TIME_BUDGET));
+        let result = run(&self.state);
+        self.deadline.set(None);
+        result
+    }
+
+    /// Call every function registered for a hook. Returns `true` if any of
+    /// them returned `false`.
+    fn call_hook<A>(&self, hook: &str, arguments: A) -> mlua::Result<bool>
+    where
+        A: for<'lua> ToLuaMulti<'lua> + Clone,
+    {
+        self.with_budget(|state| {
+            let hooks: Table = state.named_registry_value(HOOKS_KEY)?;
+            let functions: Table = hooks.get(hook)?;
+            let mut rejected = false;
+
+            for function in functions.sequence_values::<Function>() {
+                let result: Value = function?.call(arguments.clone())?;
+                rejected |= matches!(result, Value::Boolean(false));
+            }
+
+            Ok(rejected)
+        })
+    }
+
+    fn has_hook(&self, hook: &str) -> bool {
+        let hooks: mlua::Result<Table> = self.state.named_registry_value(HOOKS_KEY);
+
+        hooks
+            .and_then(|hooks| hooks.get::<_, Table>(hook))
+            .is_ok_and(|functions| functions.raw_len() > 0)
+    }
+}
+
+/// Loads all plugins and calls their hooks.
+pub struct PluginSystem {
+    plugins: Vec<Plugin>,
+    actions: Rc<RefCell<Vec<PluginAction>>>,
+    macros_allowed: Rc<Cell<bool>>,
+}
+
+impl PluginSystem {
+    /// Load every `.lua` file in the plugin directory.
+    pub fn load() -> Self {
+        let mut paths: Vec<_> = std::fs::read_dir(PLUGIN_DIRECTORY)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "lua"))
+            .collect();
+        paths.sort();
+
+        let sources = paths.into_iter().filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().into_owned();
+            let source = std::fs::read_to_string(&path).ok()?;
+            Some((name, source))
+        });
+
+        Self::from_sources(sources)
+    }
+
+    fn from_sources(sources: impl IntoIterator<Item = (String, String)>) -> Self {
+        let actions = Rc::new(RefCell::new(Vec::new()));
+        let macros_allowed = Rc::new(Cell::new(true));
+        let mut plugins = Vec::new();
+
+        for (name, source) in sources {
+            match Plugin::new(name.clone(), &source, actions.clone(), macros_allowed.clone()) {
+                Ok(plugin) => {
+                    #[cfg(feature = "debug")]
+                    print_debug!("loaded plugin {}", plugin.name);
+
+                    plugins.push(plugin);
+                }
+                Err(error) => actions.borrow_mut().push(PluginAction::Error {
+                    plugin: name,
+                    message: error.to_string(),
+                }),
+            }
+        }
+
+        Self {
+            plugins,
+            actions,
+            macros_allowed,
+        }
+    }
+
+    /// Plugins sending chat messages count as macros, so they can't do that
+    /// on servers that opt out of macros.
+    pub fn set_macros_allowed(&mut self, macros_allowed: bool) {
+        self.macros_allowed.set(macros_allowed);
+    }
+
+    /// Call a hook of every plugin. Returns `true` if any plugin rejected the
+    /// hook by returning `false`. A plugin that fails is disabled, so it
+    /// doesn't report the same error every frame.
+    fn call_hook<A>(&mut self, hook: &str, arguments: A) -> bool
+    where
+        A: for<'lua> ToLuaMulti<'lua> + Clone,
+    {
+        let mut rejected = false;
+
+        for plugin in self.plugins.iter_mut().filter(|plugin| plugin.enabled) {
+            match plugin.call_hook(hook, arguments.clone()) {
+                Ok(plugin_rejected) => rejected |= plugin_rejected,
+                Err(error) => {
+                    plugin.enabled = false;
+                    self.actions.borrow_mut().push(PluginAction::Error {
+                        plugin: plugin.name.clone(),
+                        message: error.to_string(),
+                    });
+                }
+            }
+        }
+
+        rejected
+    }
+
+    /// Returns `false` if a plugin wants to hide the message, which it does by
+    /// returning `false` from its hook.
+    pub fn chat_message(&mut self, text: &str) -> bool {
+        !self.call_hook("chat_message", text.to_owned())
+    }
+
+    pub fn user_event(&mut self, event: &UserEvent) {
+        self.call_hook("user_event", event_name(event));
+    }
+
+    pub fn entity_spawned(&mut self, entity_id: EntityId, job: u16) {
+        self.call_hook("entity_spawn", (entity_id.0, job));
+    }
+
+    pub fn entity_despawned(&mut self, entity_id: EntityId) {
+        self.call_hook("entity_despawn", entity_id.0);
+    }
+
+    /// Called once per frame with the time since the last frame in seconds.
+    pub fn update(&mut self, delta_time: f64) {
+        // Most plugins don't need to run every frame, so the hook is only
+        // called for plugins that registered it.
+        if !self.plugins.iter().any(|plugin| plugin.enabled && plugin.has_hook("update")) {
+            return;
+        }
+
+        self.call_hook("update", delta_time);
+    }
+
+    pub fn take_actions(&mut self) -> Vec<PluginAction> {
+        std::mem::take(&mut self.actions.borrow_mut())
+    }
+}
+
+/// Name of the variant of an event, which is what plugins receive.
+fn event_name(event: &UserEvent) -> String {
+    let debug = format!("{event:?}");
+    debug
+        .split(|character: char| !character.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod hooks {
+    use super::{event_name, PluginAction, PluginSystem};
+    use crate::input::UserEvent;
+
+    fn plugins(source: &str) -> PluginSystem {
+        PluginSystem::from_sources([("test".to_owned(), source.to_owned())])
+    }
+
+    #[test]
+    fn hide_chat_messages() {
+        let mut plugin_system = plugins(
+            r#"
+            korangar.on("chat_message", function(text)
+                return not string.find(text, "Jellopy")
+            end)
+            "#,
+        );
+
+        assert!(plugin_system.chat_message("You got Apple (1)"));
+        assert!(!plugin_system.chat_message("You got Jellopy (1)"));
+    }
+
+    #[test]
+    fn collect_actions() {
+        let mut plugin_system = plugins(
+            r#"
+            korangar.on("entity_despawn", function(entity_id)
+                print("despawned " .. entity_id)
+                korangar.open_window("Timer", { "one", "two" })
+            end)
+            "#,
+        );
+
+        plugin_system.entity_despawned(ragnarok_packets::EntityId(42));
+
+        assert_eq!(plugin_system.take_actions(), vec![
+            PluginAction::Print("despawned 42".to_owned()),
+            PluginAction::OpenWindow {
+                title: "Timer".to_owned(),
+                lines: vec!["one".to_owned(), "two".to_owned()],
+            },
+        ]);
+        assert!(plugin_system.take_actions().is_empty());
+    }
+
+    #[test]
+    fn sandbox() {
+        let mut plugin_system = plugins(r#"io.open("settings.toml")"#);
+        assert!(matches!(plugin_system.take_actions().as_slice(), [PluginAction::Error { .. }]));
+
+        let mut plugin_system = plugins(r#"dofile("settings.toml")"#);
+        assert!(matches!(plugin_system.take_actions().as_slice(), [PluginAction::Error { .. }]));
+    }
+
+    #[test]
+    fn limit_strings() {
+        let mut plugin_system = plugins(r#"local text = string.rep("x", 1000000)"#);
+        assert!(matches!(plugin_system.take_actions().as_slice(), [PluginAction::Error { .. }]));
+
+        let mut plugin_system = plugins(r#"local start = ("x"):rep(20000):find("y")"#);
+        assert!(matches!(plugin_system.take_actions().as_slice(), [PluginAction::Error { .. }]));
+
+        let mut plugin_system = plugins(r#"print(string.rep("ab", 2) .. " " .. string.gsub("hello", "l", "L"))"#);
+        assert_eq!(plugin_system.take_actions(), vec![PluginAction::Print("abab heLLo".to_owned())]);
+    }
+
+    #[test]
+    fn send_chat_respects_macro_opt_out() {
+        let mut plugin_system = plugins(r#"korangar.on("update", function() korangar.send_chat("hello") end)"#);
+
+        plugin_system.update(0.016);
+        assert_eq!(plugin_system.take_actions(), vec![PluginAction::SendMessage(
+            "hello".to_owned()
+        )]);
+
+        plugin_system.set_macros_allowed(false);
+        plugin_system.update(0.016);
+        assert!(matches!(plugin_system.take_actions().as_slice(), [PluginAction::Error { .. }]));
+    }
+
+    #[test]
+    fn disable_endless_loop() {
+        let mut plugin_system = plugins(r#"korangar.on("update", function() while true do end end)"#);
+
+        plugin_system.update(0.016);
+        assert!(matches!(plugin_system.take_actions().as_slice(), [PluginAction::Error { .. }]));
+
+        // The plugin is disabled after the first error.
+        plugin_system.update(0.016);
+        assert!(plugin_system.take_actions().is_empty());
+    }
+
+    #[test]
+    fn user_event_name() {
+        assert_eq!(event_name(&UserEvent::SitDown), "SitDown");
+        assert_eq!(event_name(&UserEvent::SendMessage("hello".to_owned())), "SendMessage");
+    }
+}
@@ -0,0 +1,198 @@
+//! Command line arguments and environment variables. Both override the
+//! settings file, so launchers can start the client without changing the
+//! settings of the user. Arguments take precedence over environment
+//! variables.
+
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: korangar [options]
+
+Options:
+  --data-path <path>         Directory that relative game archive paths are resolved in [KORANGAR_DATA_PATH]
+  --config <path>            Settings file to use instead of client/settings.toml [KORANGAR_CONFIG]
+  --profile <name>           Log in with a saved connection profile right away [KORANGAR_PROFILE]
+  --windowed                 Start in a window [KORANGAR_WINDOW_MODE=windowed]
+  --fullscreen               Start in borderless fullscreen [KORANGAR_WINDOW_MODE=fullscreen]
+  --render-backend <name>    Graphics backend, currently only vulkan [KORANGAR_RENDER_BACKEND]
+  --log-level <level>        Debug output, either off or debug [KORANGAR_LOG_LEVEL]
+  --headless                 Run without a window, controlled through the standard input
+  --benchmark <map>          Render a fixed camera path on a map and write a report to benchmarks/
+  --benchmark-duration <s>   Length of the benchmark in seconds, 30 by default
+  --help                     Print this message";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowMode {
+    Windowed,
+    Fullscreen,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderBackend {
+    Vulkan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Off,
+    Debug,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Arguments {
+    pub data_path: Option<PathBuf>,
+    pub config_path: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub window_mode: Option<WindowMode>,
+    pub render_backend: Option<RenderBackend>,
+    /// Only used by the debug output, which release builds don't have.
+    #[cfg_attr(not(feature = "debug"), allow(dead_code))]
+    pub log_level: Option<LogLevel>,
+    pub headless: bool,
+    /// Map to run the benchmark on.
+    pub benchmark: Option<String>,
+    pub benchmark_duration: Option<u64>,
+    pub help: bool,
+}
+
+fn parse_window_mode(value: &str) -> Result<WindowMode, String> {
+    match value {
+        "windowed" => Ok(WindowMode::Windowed),
+        "fullscreen" => Ok(WindowMode::Fullscreen),
+        _ => Err(format!("unknown window mode {value}, expected windowed or fullscreen")),
+    }
+}
+
+fn parse_render_backend(value: &str) -> Result<RenderBackend, String> {
+    match value {
+        "vulkan" => Ok(RenderBackend::Vulkan),
+        _ => Err(format!("unsupported render backend {value}, only vulkan is available")),
+    }
+}
+
+fn parse_log_level(value: &str) -> Result<LogLevel, String> {
+    match value {
+        "off" => Ok(LogLevel::Off),
+        "debug" => Ok(LogLevel::Debug),
+        _ => Err(format!("unknown log level {value}, expected off or debug")),
+    }
+}
+
+impl Arguments {
+    /// Parse the arguments and environment of the current process.
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(std::env::args().skip(1), |name| std::env::var(name).ok())
+    }
+
+    fn parse(arguments: impl IntoIterator<Item = String>, variable: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            data_path: variable("KORANGAR_DATA_PATH").map(PathBuf::from),
+            config_path: variable("KORANGAR_CONFIG").map(PathBuf::from),
+            profile: variable("KORANGAR_PROFILE"),
+            window_mode: variable("KORANGAR_WINDOW_MODE").as_deref().map(parse_window_mode).transpose()?,
+            render_backend: variable("KORANGAR_RENDER_BACKEND")
+                .as_deref()
+                .map(parse_render_backend)
+                .transpose()?,
+            log_level: variable("KORANGAR_LOG_LEVEL").as_deref().map(parse_log_level).transpose()?,
+            headless: false,
+            benchmark: None,
+            benchmark_duration: None,
+            help: false,
+        };
+
+        let mut arguments = arguments.into_iter();
+
+        while let Some(argument) = arguments.next() {
+            // Both `--option value` and `--option=value` are accepted.
+            let (name, inline_value) = match argument.split_once('=') {
+                Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+                None => (argument, None),
+            };
+
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| arguments.next())
+                    .ok_or_else(|| format!("{name} requires a value"))
+            };
+
+            match name.as_str() {
+                "--data-path" => parsed.data_path = Some(PathBuf::from(value()?)),
+                "--config" => parsed.config_path = Some(PathBuf::from(value()?)),
+                "--profile" => parsed.profile = Some(value()?),
+                "--windowed" => parsed.window_mode = Some(WindowMode::Windowed),
+                "--fullscreen" => parsed.window_mode = Some(WindowMode::Fullscreen),
+                "--render-backend" => parsed.render_backend = Some(parse_render_backend(&value()?)?),
+                "--log-level" => parsed.log_level = Some(parse_log_level(&value()?)?),
+                "--headless" => parsed.headless = true,
+                "--benchmark" => parsed.benchmark = Some(value()?),
+                "--benchmark-duration" => {
+                    let duration = value()?;
+                    parsed.benchmark_duration = Some(duration.parse().map_err(|_| format!("invalid benchmark duration {duration}"))?);
+                }
+                "--help" | "-h" => parsed.help = true,
+                _ => return Err(format!("unknown argument {name}")),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod parsing {
+    use std::path::PathBuf;
+
+    use super::{Arguments, LogLevel, WindowMode};
+
+    fn parse(arguments: &[&str], variables: &[(&str, &str)]) -> Result<Arguments, String> {
+        Arguments::parse(arguments.iter().map(|argument| argument.to_string()), |name| {
+            variables
+                .iter()
+                .find(|(variable, _)| *variable == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn arguments() {
+        let arguments = parse(
+            &["--data-path", "/games/ro", "--profile=main", "--fullscreen", "--log-level", "off"],
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(arguments.data_path, Some(PathBuf::from("/games/ro")));
+        assert_eq!(arguments.profile.as_deref(), Some("main"));
+        assert_eq!(arguments.window_mode, Some(WindowMode::Fullscreen));
+        assert_eq!(arguments.log_level, Some(LogLevel::Off));
+        assert_eq!(arguments.config_path, None);
+    }
+
+    #[test]
+    fn arguments_override_environment() {
+        let variables = [("KORANGAR_PROFILE", "alt"), ("KORANGAR_WINDOW_MODE", "windowed")];
+
+        let arguments = parse(&["--fullscreen"], &variables).unwrap();
+        assert_eq!(arguments.profile.as_deref(), Some("alt"));
+        assert_eq!(arguments.window_mode, Some(WindowMode::Fullscreen));
+    }
+
+    #[test]
+    fn benchmark() {
+        let arguments = parse(&["--benchmark", "prontera", "--benchmark-duration=60"], &[]).unwrap();
+
+        assert_eq!(arguments.benchmark.as_deref(), Some("prontera"));
+        assert_eq!(arguments.benchmark_duration, Some(60));
+        assert!(parse(&["--benchmark-duration", "long"], &[]).is_err());
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert!(parse(&["--profile"], &[]).is_err());
+        assert!(parse(&["--render-backend", "wgpu"], &[]).is_err());
+        assert!(parse(&["--unknown"], &[]).is_err());
+        assert!(parse(&[], &[("KORANGAR_LOG_LEVEL", "loud")]).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+//! Crash reports that are written when the client panics, so that bug reports
+//! contain more than a description of what the user saw.
+
+use std::backtrace::Backtrace;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::panic::PanicInfo;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use ragnarok_packets::handler::PacketCallback;
+use ragnarok_packets::PacketHeader;
+
+use super::SettingsFile;
+
+const REPORT_DIRECTORY: &str = "crashes";
+/// Contains the path of the latest report until the crash dialog was shown.
+const PENDING_FILE_NAME: &str = "crashes/pending";
+/// Number of packets that are listed in a report.
+const RECENT_PACKET_COUNT: usize = 32;
+
+/// State of the session at the time of a crash. It is updated while the client
+/// runs, since a panic can happen anywhere.
+struct CrashContext {
+    adapter_info: String,
+    map_name: String,
+    recent_packets: VecDeque<(PacketDirection, PacketHeader)>,
+}
+
+#[derive(Clone, Copy)]
+pub enum PacketDirection {
+    Incoming,
+    Outgoing,
+}
+
+static CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext {
+    adapter_info: String::new(),
+    map_name: String::new(),
+    recent_packets: VecDeque::new(),
+});
+
+fn update_context(update: impl FnOnce(&mut CrashContext)) {
+    // A poisoned lock still contains usable data, and crash reporting should
+    // never cause a second panic.
+    let mut context = CONTEXT.lock().unwrap_or_else(|error| error.into_inner());
+    update(&mut context);
+}
+
+pub fn set_crash_adapter_info(adapter_info: String) {
+    update_context(|context| context.adapter_info = adapter_info);
+}
+
+pub fn set_crash_map_name(map_name: &str) {
+    update_context(|context| map_name.clone_into(&mut context.map_name));
+}
+
+pub fn record_packet(direction: PacketDirection, header: PacketHeader) {
+    update_context(|context| {
+        if context.recent_packets.len() == RECENT_PACKET_COUNT {
+            context.recent_packets.pop_front();
+        }
+
+        context.recent_packets.push_back((direction, header));
+    });
+}
+
+/// Remembers the headers of the latest packets for crash reports.
+#[derive(Clone, Default)]
+pub struct CrashPacketCallback;
+
+impl PacketCallback for CrashPacketCallback {
+    fn incoming_packet<Packet>(&self, _packet: &Packet)
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        record_packet(PacketDirection::Incoming, Packet::HEADER);
+    }
+
+    fn outgoing_packet<Packet>(&self, _packet: &Packet)
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        record_packet(PacketDirection::Outgoing, Packet::HEADER);
+    }
+}
+
+/// Write a crash report whenever the client panics. The previous panic hook
+/// still runs afterwards, so the panic is printed as usual.
+pub fn install_crash_reporter() {
+    let previous_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        write_report(info);
+        previous_hook(info);
+    }));
+}
+
+fn write_report(info: &PanicInfo) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let path = format!("{REPORT_DIRECTORY}/crash-{timestamp}.txt");
+
+    let mut report = String::new();
+    let _ = writeln!(report, "Korangar {} crashed at {timestamp}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(report, "\n{info}");
+
+    {
+        let context = CONTEXT.lock().unwrap_or_else(|error| error.into_inner());
+
+        let _ = writeln!(report, "\nAdapter: {}", context.adapter_info);
+        let _ = writeln!(report, "Map: {}", context.map_name);
+        let _ = writeln!(report, "\nRecent packets (oldest first):");
+
+        for (direction, header) in &context.recent_packets {
+            let direction = match direction {
+                PacketDirection::Incoming => "in ",
+                PacketDirection::Outgoing => "out",
+            };
+
+            let _ = writeln!(report, "  {direction} 0x{:04x}", header.0);
+        }
+    }
+
+    let settings = std::fs::read_to_string(SettingsFile::path()).unwrap_or_else(|_| "(no settings file)".to_owned());
+    let _ = writeln!(report, "\nSettings:\n{settings}");
+    let _ = writeln!(report, "\nBacktrace:\n{}", Backtrace::force_capture());
+
+    let written = std::fs::create_dir_all(REPORT_DIRECTORY)
+        .and_then(|_| std::fs::write(&path, report))
+        .and_then(|_| std::fs::write(PENDING_FILE_NAME, &path));
+
+    if written.is_ok() {
+        eprintln!("a crash report was written to {path}");
+    }
+}
+
+/// Path of the report of the last crash, if it wasn't shown to the user yet.
+/// The report is only returned once.
+pub fn take_pending_crash_report() -> Option<String> {
+    let path = std::fs::read_to_string(PENDING_FILE_NAME).ok()?;
+    let _ = std::fs::remove_file(PENDING_FILE_NAME);
+
+    Some(path.trim().to_owned()).filter(|path| std::path::Path::new(path).exists())
+}
+
+/// Open a crash report with the default application of the operating system.
+pub fn open_crash_report(path: &str) {
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("cmd").args(["/C", "start", "", path]).spawn();
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").arg(path).spawn();
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let result = std::process::Command::new("xdg-open").arg(path).spawn();
+
+    if let Err(_error) = result {
+        #[cfg(feature = "debug")]
+        print_debug!("failed to open crash report: {}", _error);
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::{Duration, Instant};
+
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+
+/// Time span of latency samples that are kept around.
+const SAMPLE_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug)]
+pub struct LatencySample {
+    pub received_at: Instant,
+    /// [`None`] if the keep-alive packet was not answered in time.
+    pub round_trip_time: Option<Duration>,
+}
+
+/// Keeps track of the round trip times to the map server over the last minute.
+#[derive(Default)]
+pub struct LatencyTracker {
+    samples: PlainTrackedState<Vec<LatencySample>>,
+}
+
+impl LatencyTracker {
+    pub fn add_sample(&mut self, round_trip_time: Option<Duration>) {
+        let received_at = Instant::now();
+
+        self.samples.mutate(|samples| {
+            samples.retain(|sample| received_at.duration_since(sample.received_at) < SAMPLE_WINDOW);
+            samples.push(LatencySample {
+                received_at,
+                round_trip_time,
+            });
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.set(Vec::new());
+    }
+
+    /// Round trip time of the last answered keep-alive packet.
+    pub fn get_ping(&self) -> Option<Duration> {
+        self.samples.get().iter().rev().find_map(|sample| sample.round_trip_time)
+    }
+
+    pub fn get_samples(&self) -> PlainRemote<Vec<LatencySample>> {
+        self.samples.new_remote()
+    }
+}
+
+/// Fraction of keep-alive packets that were not answered.
+pub fn packet_loss(samples: &[LatencySample]) -> f32 {
+    match samples.len() {
+        0 => 0.0,
+        length => samples.iter().filter(|sample| sample.round_trip_time.is_none()).count() as f32 / length as f32,
+    }
+}
+
+#[cfg(test)]
+mod tracker {
+    use korangar_interface::state::Remote;
+
+    use super::*;
+
+    #[test]
+    fn ping_ignores_lost_packets() {
+        let mut tracker = LatencyTracker::default();
+        tracker.add_sample(Some(Duration::from_millis(40)));
+        tracker.add_sample(None);
+
+        assert_eq!(tracker.get_ping(), Some(Duration::from_millis(40)));
+    }
+
+    #[test]
+    fn packet_loss_is_ratio_of_lost_packets() {
+        let mut tracker = LatencyTracker::default();
+        tracker.add_sample(Some(Duration::from_millis(40)));
+        tracker.add_sample(None);
+        tracker.add_sample(Some(Duration::from_millis(60)));
+        tracker.add_sample(None);
+
+        assert_eq!(packet_loss(&tracker.get_samples().get()), 0.5);
+    }
+}
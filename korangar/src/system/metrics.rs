@@ -0,0 +1,319 @@
+//! Opt-in export of performance metrics, so the performance of different
+//! client versions can be compared on real hardware. Every frame is written
+//! as a line of a CSV file in the metrics directory.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+#[cfg(feature = "debug")]
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+
+use crate::benchmark::{MeasuredPass, PASS_COUNT};
+
+const METRICS_DIRECTORY: &str = "metrics";
+const HEADER: &str = "frame,frame_time_ms,cpu_time_ms,gpu_wait_ms,picker_gpu_ms,shadow_gpu_ms,deferred_gpu_ms,map_load_ms,model_load_ms,\
+                      texture_load_ms,sprite_load_ms,action_load_ms,resident_memory_kib";
+/// Address of the Prometheus endpoint in debug builds.
+#[cfg(feature = "debug")]
+const PROMETHEUS_ADDRESS: &str = "127.0.0.1:9464";
+
+#[derive(Clone, Copy)]
+pub enum LoaderKind {
+    Map,
+    Model,
+    Texture,
+    Sprite,
+    Action,
+}
+
+const LOADER_COUNT: usize = 5;
+#[cfg(feature = "debug")]
+const LOADER_NAMES: [&str; LOADER_COUNT] = ["map", "model", "texture", "sprite", "action"];
+
+/// Time spent loading resources since the last frame was recorded. Loaders are
+/// also used from worker threads, so they can't report to the exporter
+/// directly.
+static LOAD_TIMES: Mutex<[Duration; LOADER_COUNT]> = Mutex::new([Duration::ZERO; LOADER_COUNT]);
+
+/// Run a load and add its duration to the metrics of the current frame. The
+/// time of a map includes the time of its models and textures.
+pub fn measure_load<T>(loader: LoaderKind, load: impl FnOnce() -> T) -> T {
+    let start = std::time::Instant::now();
+    let result = load();
+    let duration = start.elapsed();
+
+    if let Ok(mut load_times) = LOAD_TIMES.lock() {
+        load_times[loader as usize] += duration;
+    }
+
+    result
+}
+
+fn take_load_times() -> [Duration; LOADER_COUNT] {
+    LOAD_TIMES
+        .lock()
+        .map(|mut load_times| std::mem::take(&mut *load_times))
+        .unwrap_or_default()
+}
+
+/// Resident memory of the process in kibibytes. Only available on Linux.
+#[cfg(target_os = "linux")]
+fn resident_memory() -> Option<u64> {
+    // SAFETY: `sysconf` only reads a configuration value.
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+
+    std::fs::read_to_string("/proc/self/statm")
+        .ok()
+        .and_then(|statm| parse_statm(&statm, u64::try_from(page_size).ok()?))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn resident_memory() -> Option<u64> {
+    None
+}
+
+/// `/proc/self/statm` counts memory in pages.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_statm(statm: &str, page_size: u64) -> Option<u64> {
+    let resident_pages: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+    Some(resident_pages * page_size / 1024)
+}
+
+fn milliseconds(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+/// Timings of a single frame.
+pub struct FrameMetrics {
+    pub frame_time: Duration,
+    /// Time from the start of the frame until it was submitted, without the
+    /// time spent waiting for the GPU and the swapchain.
+    pub cpu_time: Duration,
+    /// Time the CPU spent waiting for the GPU to finish an earlier frame. This
+    /// is not the time the GPU spent on the frame, see `gpu_passes` for that.
+    pub gpu_wait: Duration,
+    /// Time the GPU spent on every render pass, if the device supports
+    /// timestamps. The times belong to the last frame that was rendered to the
+    /// same swapchain image.
+    pub gpu_passes: Option<[Duration; PASS_COUNT]>,
+}
+
+fn format_row(frame: u64, metrics: &FrameMetrics, load_times: &[Duration; LOADER_COUNT], resident_memory: Option<u64>) -> String {
+    let mut row = format!(
+        "{frame},{},{},{}",
+        milliseconds(metrics.frame_time),
+        milliseconds(metrics.cpu_time),
+        milliseconds(metrics.gpu_wait)
+    );
+
+    for pass in MeasuredPass::all() {
+        let pass_time = metrics.gpu_passes.map(|gpu_passes| milliseconds(gpu_passes[pass as usize]));
+        let _ = write!(row, ",{}", pass_time.unwrap_or_default());
+    }
+
+    for load_time in load_times {
+        let _ = write!(row, ",{}", milliseconds(*load_time));
+    }
+
+    let _ = write!(row, ",{}", resident_memory.map(|memory| memory.to_string()).unwrap_or_default());
+    row
+}
+
+/// Writes the metrics of every frame while the export is enabled.
+#[derive(Default)]
+pub struct MetricsExporter {
+    writer: Option<BufWriter<File>>,
+    frame: u64,
+    #[cfg(feature = "debug")]
+    prometheus: Option<Arc<Mutex<String>>>,
+}
+
+impl MetricsExporter {
+    /// Start or stop the export. Every time the export is started, the
+    /// metrics are written to a new file.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if enabled == self.writer.is_some() {
+            return;
+        }
+
+        if !enabled {
+            if let Some(mut writer) = self.writer.take() {
+                let _ = writer.flush();
+            }
+
+            return;
+        }
+
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let path = format!("{METRICS_DIRECTORY}/metrics-{timestamp}.csv");
+
+        let writer = std::fs::create_dir_all(METRICS_DIRECTORY)
+            .and_then(|_| File::create(&path))
+            .map(BufWriter::new)
+            .and_then(|mut writer| {
+                writeln!(writer, "# korangar {}", env!("CARGO_PKG_VERSION"))?;
+                writeln!(writer, "{HEADER}")?;
+                Ok(writer)
+            });
+
+        match writer {
+            Ok(writer) => {
+                // Loads that happened before the export was enabled would otherwise
+                // all show up in the first frame.
+                take_load_times();
+
+                self.writer = Some(writer);
+                self.frame = 0;
+            }
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("failed to create metrics file {}: {}", path, _error);
+            }
+        }
+
+        #[cfg(feature = "debug")]
+        if self.writer.is_some() && self.prometheus.is_none() {
+            self.prometheus = spawn_prometheus_endpoint();
+        }
+    }
+
+    pub fn record_frame(&mut self, metrics: FrameMetrics) {
+        let Some(writer) = &mut self.writer else {
+            return;
+        };
+
+        let load_times = take_load_times();
+        let resident_memory = resident_memory();
+
+        if writeln!(writer, "{}", format_row(self.frame, &metrics, &load_times, resident_memory)).is_err() {
+            self.writer = None;
+            return;
+        }
+
+        #[cfg(feature = "debug")]
+        if let Some(prometheus) = &self.prometheus
+            && let Ok(mut exposition) = prometheus.lock()
+        {
+            *exposition = format_prometheus(&metrics, &load_times, resident_memory);
+        }
+
+        self.frame += 1;
+    }
+}
+
+#[cfg(feature = "debug")]
+fn format_prometheus(metrics: &FrameMetrics, load_times: &[Duration; LOADER_COUNT], resident_memory: Option<u64>) -> String {
+    let mut exposition = String::new();
+
+    let _ = writeln!(exposition, "korangar_frame_time_seconds {}", metrics.frame_time.as_secs_f64());
+    let _ = writeln!(exposition, "korangar_cpu_time_seconds {}", metrics.cpu_time.as_secs_f64());
+    let _ = writeln!(exposition, "korangar_gpu_wait_seconds {}", metrics.gpu_wait.as_secs_f64());
+
+    if let Some(gpu_passes) = metrics.gpu_passes {
+        for pass in MeasuredPass::all() {
+            let _ = writeln!(
+                exposition,
+                "korangar_gpu_pass_seconds{{pass=\"{}\"}} {}",
+                pass.name(),
+                gpu_passes[pass as usize].as_secs_f64()
+            );
+        }
+    }
+
+    for (name, load_time) in LOADER_NAMES.iter().zip(load_times) {
+        let _ = writeln!(
+            exposition,
+            "korangar_load_seconds{{loader=\"{name}\"}} {}",
+            load_time.as_secs_f64()
+        );
+    }
+
+    if let Some(resident_memory) = resident_memory {
+        let _ = writeln!(exposition, "korangar_resident_memory_bytes {}", resident_memory * 1024);
+    }
+
+    exposition
+}
+
+/// Serve the metrics of the latest frame in the Prometheus text format.
+#[cfg(feature = "debug")]
+fn spawn_prometheus_endpoint() -> Option<Arc<Mutex<String>>> {
+    use std::io::Read;
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind(PROMETHEUS_ADDRESS)
+        .map_err(|_error| {
+            print_debug!("failed to start the prometheus endpoint: {}", _error);
+        })
+        .ok()?;
+    let exposition = Arc::new(Mutex::new(String::new()));
+    let thread_exposition = exposition.clone();
+
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            // The request itself doesn't matter, every path returns the metrics.
+            let _ = stream.read(&mut [0; 1024]);
+
+            let body = thread_exposition.lock().map(|exposition| exposition.clone()).unwrap_or_default();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+
+    print_debug!("serving metrics on http://{}/metrics", PROMETHEUS_ADDRESS);
+
+    Some(exposition)
+}
+
+#[cfg(test)]
+mod export {
+    use std::time::Duration;
+
+    use super::{format_row, parse_statm, FrameMetrics, HEADER, LOADER_COUNT};
+
+    #[test]
+    fn statm() {
+        assert_eq!(parse_statm("12345 2048 512 1 0 4096 0\n", 4096), Some(8192));
+        assert_eq!(parse_statm("12345 2048 512 1 0 4096 0\n", 16384), Some(32768));
+        assert_eq!(parse_statm("", 4096), None);
+    }
+
+    #[test]
+    fn row_matches_header() {
+        let metrics = FrameMetrics {
+            frame_time: Duration::from_micros(16_667),
+            cpu_time: Duration::from_millis(5),
+            gpu_wait: Duration::ZERO,
+            gpu_passes: None,
+        };
+        let mut load_times = [Duration::ZERO; LOADER_COUNT];
+        load_times[0] = Duration::from_millis(120);
+
+        let row = format_row(7, &metrics, &load_times, None);
+
+        assert_eq!(row, "7,16.667,5.000,0.000,,,,120.000,0.000,0.000,0.000,0.000,");
+        assert_eq!(row.split(',').count(), HEADER.split(',').count());
+    }
+
+    #[test]
+    fn gpu_pass_times() {
+        let metrics = FrameMetrics {
+            frame_time: Duration::from_millis(16),
+            cpu_time: Duration::from_millis(5),
+            gpu_wait: Duration::from_millis(2),
+            gpu_passes: Some([Duration::from_micros(500), Duration::from_millis(1), Duration::from_millis(4)]),
+        };
+
+        let row = format_row(0, &metrics, &[Duration::ZERO; LOADER_COUNT], Some(1024));
+
+        assert_eq!(row, "0,16.000,5.000,2.000,0.500,1.000,4.000,0.000,0.000,0.000,0.000,0.000,1024");
+    }
+}
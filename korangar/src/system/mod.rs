@@ -1,6 +1,21 @@
+mod arguments;
+mod crash;
+mod latency;
+mod metrics;
+mod scheduler;
+mod settings;
 mod timer;
 #[macro_use]
 mod vulkan;
 
-pub use self::timer::GameTimer;
+pub use self::arguments::{Arguments, LogLevel, RenderBackend, WindowMode, USAGE};
+pub use self::crash::{
+    install_crash_reporter, open_crash_report, record_packet, set_crash_adapter_info, set_crash_map_name, take_pending_crash_report,
+    CrashPacketCallback, PacketDirection,
+};
+pub use self::latency::{packet_loss, LatencySample, LatencyTracker};
+pub use self::metrics::{measure_load, FrameMetrics, LoaderKind, MetricsExporter};
+pub use self::scheduler::{FramePacer, TaskScheduler};
+pub use self::settings::SettingsFile;
+pub use self::timer::{format_remaining_time, format_remaining_time_short, GameTimer};
 pub use self::vulkan::*;
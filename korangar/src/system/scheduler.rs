@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Refresh rate that is assumed if the monitor doesn't report one.
+const DEFAULT_REFRESH_RATE: f64 = 60.0;
+
+/// Time of the frame that is kept free of background work, since the frame
+/// still has to be submitted and presented.
+const SAFETY_MARGIN: Duration = Duration::from_millis(2);
+
+/// Background work still runs after this many frames without any budget left,
+/// so it can't be delayed forever on a slow machine.
+const MAXIMUM_SKIPPED_FRAMES: usize = 30;
+
+/// Weight of the latest duration in the estimate of how long a task takes.
+const ESTIMATE_WEIGHT: f64 = 0.2;
+
+/// Keeps track of how much time is left until the deadline of the current
+/// frame.
+pub struct FramePacer {
+    frame_time: Duration,
+    frame_start: Instant,
+}
+
+impl FramePacer {
+    /// Create a pacer for a display with the given refresh rate in millihertz.
+    pub fn new(refresh_rate_millihertz: Option<u32>) -> Self {
+        let refresh_rate = refresh_rate_millihertz
+            .filter(|&millihertz| millihertz > 0)
+            .map(|millihertz| millihertz as f64 / 1000.0)
+            .unwrap_or(DEFAULT_REFRESH_RATE);
+
+        Self {
+            frame_time: Duration::from_secs_f64(1.0 / refresh_rate),
+            frame_start: Instant::now(),
+        }
+    }
+
+    pub fn begin_frame(&mut self, now: Instant) {
+        self.frame_start = now;
+    }
+
+    /// Time that a frame can take while still holding the refresh rate of the
+    /// display.
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// Time that can still be spent on the current frame without missing its
+    /// deadline.
+    pub fn remaining_budget(&self, now: Instant) -> Duration {
+        let deadline = self.frame_start + self.frame_time;
+        deadline.saturating_duration_since(now).saturating_sub(SAFETY_MARGIN)
+    }
+}
+
+/// Runs queued tasks in the time that is left at the end of a frame. Tasks
+/// should be small slices of work, so a single task never takes much longer
+/// than the estimate.
+pub struct TaskScheduler<T> {
+    tasks: VecDeque<T>,
+    /// Moving average of the time a task takes.
+    estimate: Duration,
+    skipped_frames: usize,
+}
+
+impl<T> Default for TaskScheduler<T> {
+    fn default() -> Self {
+        Self {
+            tasks: VecDeque::new(),
+            estimate: Duration::from_millis(1),
+            skipped_frames: 0,
+        }
+    }
+}
+
+impl<T: PartialEq> TaskScheduler<T> {
+    /// Queue a task, unless the same task is already waiting.
+    pub fn push(&mut self, task: T) {
+        if !self.tasks.contains(&task) {
+            self.tasks.push_back(task);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Run as many tasks as fit into the remaining budget of the frame. A task
+    /// returns a follow-up task if its work isn't done yet.
+    pub fn run(&mut self, frame_pacer: &FramePacer, execute: impl FnMut(T) -> Option<T>) {
+        self.run_with_budget(|| frame_pacer.remaining_budget(Instant::now()), execute);
+    }
+
+    fn run_with_budget(&mut self, mut remaining_budget: impl FnMut() -> Duration, mut execute: impl FnMut(T) -> Option<T>) {
+        let mut ran_task = false;
+
+        while !self.tasks.is_empty() {
+            let starved = !ran_task && self.skipped_frames >= MAXIMUM_SKIPPED_FRAMES;
+
+            if !starved && remaining_budget() < self.estimate {
+                break;
+            }
+
+            let task = self.tasks.pop_front().unwrap();
+            let start = Instant::now();
+
+            if let Some(follow_up) = execute(task) {
+                self.tasks.push_back(follow_up);
+            }
+
+            self.update_estimate(start.elapsed());
+            ran_task = true;
+        }
+
+        self.skipped_frames = match ran_task || self.tasks.is_empty() {
+            true => 0,
+            false => self.skipped_frames + 1,
+        };
+    }
+
+    fn update_estimate(&mut self, duration: Duration) {
+        self.estimate = self.estimate.mul_f64(1.0 - ESTIMATE_WEIGHT) + duration.mul_f64(ESTIMATE_WEIGHT);
+    }
+}
+
+#[cfg(test)]
+mod budget {
+    use std::time::{Duration, Instant};
+
+    use super::{FramePacer, TaskScheduler, MAXIMUM_SKIPPED_FRAMES, SAFETY_MARGIN};
+
+    #[test]
+    fn remaining_budget() {
+        let mut frame_pacer = FramePacer::new(Some(50_000));
+        let now = Instant::now();
+        frame_pacer.begin_frame(now);
+
+        assert_eq!(frame_pacer.remaining_budget(now), Duration::from_millis(20) - SAFETY_MARGIN);
+        assert_eq!(frame_pacer.remaining_budget(now + Duration::from_millis(30)), Duration::ZERO);
+    }
+
+    #[test]
+    fn run_within_budget() {
+        let mut scheduler = TaskScheduler::default();
+        (0..5).for_each(|task| scheduler.push(task));
+
+        // The budget is used up after three tasks.
+        let mut budget = [Duration::from_millis(10); 3].into_iter();
+        let mut executed = Vec::new();
+        scheduler.run_with_budget(
+            || budget.next().unwrap_or_default(),
+            |task| {
+                executed.push(task);
+                None
+            },
+        );
+
+        assert_eq!(executed, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn follow_up_tasks() {
+        let mut scheduler = TaskScheduler::default();
+        scheduler.push(3);
+
+        let mut executed = Vec::new();
+        scheduler.run_with_budget(
+            || Duration::from_secs(1),
+            |task| {
+                executed.push(task);
+                (task > 0).then_some(task - 1)
+            },
+        );
+
+        assert_eq!(executed, vec![3, 2, 1, 0]);
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn no_starvation() {
+        let mut scheduler = TaskScheduler::default();
+        scheduler.push(0);
+
+        for _ in 0..MAXIMUM_SKIPPED_FRAMES {
+            scheduler.run_with_budget(|| Duration::ZERO, |_| panic!("no budget left"));
+        }
+
+        let mut executed = false;
+        scheduler.run_with_budget(
+            || Duration::ZERO,
+            |_| {
+                executed = true;
+                None
+            },
+        );
+
+        assert!(executed);
+    }
+}
@@ -0,0 +1,254 @@
+use std::ffi::OsStr;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use toml::{Table, Value};
+
+/// Version of the layout of the settings file. Increase this and add a
+/// migration to [`MIGRATIONS`] whenever a setting is renamed, moved or changes
+/// its meaning.
+const CURRENT_VERSION: i64 = 1;
+
+/// Path of the settings file, if it was changed from the default.
+static PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Function that upgrades the settings from one version to the next.
+type Migration = fn(&mut Table);
+
+/// Migrations indexed by the version they upgrade from, starting at version 1.
+const MIGRATIONS: &[Migration] = &[];
+
+/// Settings of all systems, stored in a single TOML file with one table per
+/// system. Older versions of the file are migrated when loading, so settings
+/// saved by a previous version of the client are never lost.
+pub struct SettingsFile;
+
+impl SettingsFile {
+    const DEFAULT_FILE_NAME: &'static str = "client/settings.toml";
+    const VERSION_KEY: &'static str = "version";
+
+    /// Use a different settings file. This has to happen before any settings
+    /// are loaded.
+    pub fn set_path(path: PathBuf) {
+        let _ = PATH.set(path);
+    }
+
+    pub fn path() -> &'static Path {
+        PATH.get_or_init(|| PathBuf::from(Self::DEFAULT_FILE_NAME))
+    }
+
+    /// Read and migrate the whole settings file. Fails if the file exists but
+    /// can't be read, or if it can't be parsed and backing it up failed.
+    fn read() -> std::io::Result<Table> {
+        let mut table = read_table(Self::path())?;
+        let version = version_of(&table);
+
+        #[cfg(feature = "debug")]
+        if version > CURRENT_VERSION {
+            print_debug!(
+                "settings in {} were saved by a newer client (version {})",
+                Self::path().display().magenta(),
+                version
+            );
+        }
+
+        migrate(&mut table, version, MIGRATIONS);
+        Ok(table)
+    }
+
+    /// Load the settings of a single system. Returns `None` if the system has
+    /// no settings yet or they can't be parsed.
+    pub fn load_section<T: DeserializeOwned>(section: &str) -> Option<T> {
+        #[cfg(feature = "debug")]
+        print_debug!("loading {} settings from {}", section, Self::path().display().magenta());
+
+        Self::read().ok()?.remove(section).and_then(|value| value.try_into().ok())
+    }
+
+    /// Load settings from the file a system used before the settings were
+    /// moved to the settings file. The section is written to the settings
+    /// file the next time it is saved.
+    pub fn load_legacy<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+        #[cfg(feature = "debug")]
+        print_debug!("importing legacy settings from {}", file_name.magenta());
+
+        std::fs::read_to_string(file_name).ok().and_then(|data| ron::from_str(&data).ok())
+    }
+
+    /// Replace the settings of a single system, keeping all other sections.
+    pub fn save_section<T: Serialize>(section: &str, settings: &T) {
+        #[cfg(feature = "debug")]
+        print_debug!("saving {} settings to {}", section, Self::path().display().magenta());
+
+        // Writing the file without the sections that couldn't be read would lose them.
+        let Ok(mut table) = Self::read() else {
+            #[cfg(feature = "debug")]
+            print_debug!(
+                "not saving {} settings, since {} can't be read",
+                section,
+                Self::path().display().magenta()
+            );
+            return;
+        };
+
+        let value = Value::try_from(settings).expect("failed to serialize settings");
+
+        table.insert(section.to_owned(), value);
+        table.insert(
+            Self::VERSION_KEY.to_owned(),
+            Value::Integer(CURRENT_VERSION.max(version_of(&table))),
+        );
+
+        // Write to a temporary file first, so that a crash while saving doesn't
+        // leave a truncated settings file behind.
+        let data = toml::to_string_pretty(&table).expect("failed to serialize settings");
+        let temporary_path = with_suffix(Self::path(), ".tmp");
+
+        std::fs::write(&temporary_path, data)
+            .and_then(|_| std::fs::rename(&temporary_path, Self::path()))
+            .expect("unable to write file");
+    }
+}
+
+/// Path of a file next to the given path, with a suffix added to its name.
+fn with_suffix(path: &Path, suffix: impl AsRef<OsStr>) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    path.into()
+}
+
+/// Read the settings table from a file. A missing file is treated as empty.
+/// A file that can't be parsed is copied to a backup first and also treated
+/// as empty, since saving a section afterwards replaces it.
+fn read_table(path: &Path) -> std::io::Result<Table> {
+    let data = match std::fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Table::new()),
+        Err(error) => return Err(error),
+    };
+
+    match data.parse::<Table>() {
+        Ok(table) => Ok(table),
+        Err(_error) => {
+            std::fs::write(with_suffix(path, ".bak"), data)?;
+
+            #[cfg(feature = "debug")]
+            print_debug!(
+                "failed to parse {}, a backup was saved next to it: {}",
+                path.display().magenta(),
+                _error
+            );
+
+            Ok(Table::new())
+        }
+    }
+}
+
+/// Settings files without a version were saved before versioning was added.
+fn version_of(table: &Table) -> i64 {
+    table.get(SettingsFile::VERSION_KEY).and_then(Value::as_integer).unwrap_or(1)
+}
+
+/// Apply all migrations that are newer than the version of the settings and
+/// update the version.
+fn migrate(table: &mut Table, version: i64, migrations: &[Migration]) {
+    let first = usize::try_from(version - 1).unwrap_or(0);
+
+    if first >= migrations.len() {
+        return;
+    }
+
+    migrations[first..].iter().for_each(|migration| migration(table));
+
+    let new_version = migrations.len() as i64 + 1;
+    table.insert(SettingsFile::VERSION_KEY.to_owned(), Value::Integer(new_version));
+}
+
+#[cfg(test)]
+mod migration {
+    use toml::{Table, Value};
+
+    use super::{migrate, version_of, Migration};
+
+    fn rename_volume(table: &mut Table) {
+        if let Some(Value::Table(audio)) = table.get_mut("audio") {
+            if let Some(volume) = audio.remove("volume") {
+                audio.insert("master_volume".to_owned(), volume);
+            }
+        }
+    }
+
+    fn add_graphics(table: &mut Table) {
+        table.insert("graphics".to_owned(), Value::Table(Table::new()));
+    }
+
+    const MIGRATIONS: &[Migration] = &[rename_volume, add_graphics];
+
+    fn settings(version: i64) -> Table {
+        format!("version = {version}\n[audio]\nvolume = 0.5\n").parse().unwrap()
+    }
+
+    #[test]
+    fn migrate_oldest() {
+        let mut table = settings(1);
+        migrate(&mut table, 1, MIGRATIONS);
+
+        assert_eq!(version_of(&table), 3);
+        assert_eq!(table["audio"]["master_volume"].as_float(), Some(0.5));
+        assert!(table.contains_key("graphics"));
+    }
+
+    #[test]
+    fn skip_applied_migrations() {
+        let mut table = settings(2);
+        migrate(&mut table, 2, MIGRATIONS);
+
+        assert_eq!(version_of(&table), 3);
+        assert_eq!(table["audio"].get("master_volume"), None);
+        assert!(table.contains_key("graphics"));
+    }
+
+    #[test]
+    fn keep_current_and_newer() {
+        for version in [3, 4] {
+            let mut table = settings(version);
+            migrate(&mut table, version, MIGRATIONS);
+
+            assert_eq!(version_of(&table), version);
+            assert_eq!(table["audio"]["volume"].as_float(), Some(0.5));
+        }
+    }
+}
+
+#[cfg(test)]
+mod broken_file {
+    use super::{read_table, with_suffix};
+
+    #[test]
+    fn backed_up() {
+        let path = std::env::temp_dir().join(format!("korangar-settings-{}.toml", std::process::id()));
+        let backup_path = with_suffix(&path, ".bak");
+        let data = "[audio]\nmaster_volume = ";
+
+        std::fs::write(&path, data).unwrap();
+        let table = read_table(&path);
+        let backup = std::fs::read_to_string(&backup_path);
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup_path);
+
+        assert!(table.unwrap().is_empty());
+        assert_eq!(backup.unwrap(), data);
+    }
+
+    #[test]
+    fn missing() {
+        let path = std::env::temp_dir().join("korangar-settings-missing.toml");
+        assert!(read_table(&path).unwrap().is_empty());
+    }
+}
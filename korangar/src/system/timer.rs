@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use chrono::prelude::*;
 use ragnarok_packets::ClientTick;
@@ -83,6 +83,33 @@ impl GameTimer {
     }
 }
 
+/// Format a remaining time as `hh:mm:ss`, prefixed with the number of days if
+/// there is at least one day left.
+pub fn format_remaining_time(remaining_time: Duration) -> String {
+    let seconds = remaining_time.as_secs();
+
+    match seconds / 86400 {
+        0 => format!("{:02}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60),
+        days => format!(
+            "{}d {:02}:{:02}:{:02}",
+            days,
+            seconds / 3600 % 24,
+            seconds / 60 % 60,
+            seconds % 60
+        ),
+    }
+}
+
+/// Format a remaining time using only its largest unit, e.g. `3h` or `12m`.
+pub fn format_remaining_time_short(remaining_time: Duration) -> String {
+    match remaining_time.as_secs() {
+        seconds @ 86400.. => format!("{}d", seconds / 86400),
+        seconds @ 3600.. => format!("{}h", seconds / 3600),
+        seconds @ 60.. => format!("{}m", seconds / 60),
+        seconds => format!("{seconds}s"),
+    }
+}
+
 #[cfg(test)]
 mod increment {
     use super::*;
@@ -111,3 +138,28 @@ mod increment {
         assert!(updated_animation_timer > animation_timer);
     }
 }
+
+#[cfg(test)]
+mod format {
+    use std::time::Duration;
+
+    use super::{format_remaining_time, format_remaining_time_short};
+
+    #[test]
+    fn hours_minutes_seconds() {
+        assert_eq!(format_remaining_time(Duration::from_secs(3 * 3600 + 25 * 60 + 7)), "03:25:07");
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(format_remaining_time(Duration::from_secs(86400 + 3600 + 1)), "1d 01:00:01");
+    }
+
+    #[test]
+    fn largest_unit() {
+        assert_eq!(format_remaining_time_short(Duration::from_secs(2 * 86400 + 5)), "2d");
+        assert_eq!(format_remaining_time_short(Duration::from_secs(3 * 3600 + 59 * 60)), "3h");
+        assert_eq!(format_remaining_time_short(Duration::from_secs(12 * 60 + 30)), "12m");
+        assert_eq!(format_remaining_time_short(Duration::from_secs(45)), "45s");
+    }
+}
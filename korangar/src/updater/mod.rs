@@ -0,0 +1,428 @@
+//! Updates the client from a release manifest, for servers that distribute
+//! their own build of the client. The manifest is a TOML file that looks
+//! like this:
+//!
+//! ```toml
+//! version = "0.2.0"
+//! changelog = "Fixed the thing"
+//!
+//! [downloads.linux-x86_64]
+//! url = "https://example.com/korangar-0.2.0"
+//! sha256 = "<hex encoded hash of the binary>"
+//! signature = "<hex encoded ed25519 signature of the version, platform and hash>"
+//! ```
+//!
+//! Updates are only ever downloaded if the client was built with the public
+//! key of the server in `KORANGAR_UPDATE_PUBLIC_KEY`, and every download has
+//! to be signed with the matching private key. The signed message is
+//! `korangar-update:<version>:<platform>:` followed by the raw hash, so an old
+//! release can't be offered again as a downgrade or for another platform.
+//!
+//! A downloaded binary is only placed next to the current one and replaces it
+//! the next time the client is started.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, Sender};
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::system::SettingsFile;
+
+/// Hex encoded ed25519 key that every release has to be signed with. The
+/// updater is disabled for builds without one.
+const PUBLIC_KEY: Option<&str> = option_env!("KORANGAR_UPDATE_PUBLIC_KEY");
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct UpdaterSettings {
+    /// URL of the release manifest. Nothing is checked if there is none.
+    pub manifest_url: Option<String>,
+}
+
+impl UpdaterSettings {
+    const SECTION: &'static str = "updater";
+
+    pub fn new() -> Self {
+        SettingsFile::load_section(Self::SECTION).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct Download {
+    url: String,
+    sha256: String,
+    signature: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct Manifest {
+    version: String,
+    #[serde(default)]
+    changelog: String,
+    /// Downloads by operating system and architecture, for example
+    /// `windows-x86_64`.
+    downloads: HashMap<String, Download>,
+}
+
+/// A release that is newer than the running client.
+#[derive(Clone, Debug)]
+pub struct Release {
+    pub version: String,
+    pub changelog: String,
+    download: Download,
+}
+
+pub enum UpdaterEvent {
+    UpdateAvailable(Release),
+    /// The update was downloaded and is applied on the next start.
+    Downloaded {
+        version: String,
+    },
+    Failed(String),
+}
+
+fn platform() -> String {
+    format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH)
+}
+
+/// Compare dotted version numbers, ignoring anything that isn't a number.
+fn is_newer(version: &str, current_version: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> { version.split('.').map(|part| part.parse().unwrap_or(0)).collect() };
+    parse(version) > parse(current_version)
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex = hex.trim();
+
+    if hex.len() % 2 != 0 {
+        return Err("hex string has an odd length".to_owned());
+    }
+
+    let nibble = |byte: u8| (byte as char).to_digit(16).ok_or_else(|| format!("invalid hex string {hex}"));
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| Ok((nibble(pair[0])? << 4 | nibble(pair[1])?) as u8))
+        .collect()
+}
+
+/// Only allow downloads over https, so nobody on the way can swap out the
+/// manifest or the binary.
+fn require_https(url: &str) -> Result<(), String> {
+    match url.starts_with("https://") {
+        true => Ok(()),
+        false => Err(format!("refusing to download {url} without https")),
+    }
+}
+
+/// Message that the private key signs for every download.
+fn signed_message(version: &str, platform: &str, hash: &[u8]) -> Vec<u8> {
+    let mut message = format!("korangar-update:{version}:{platform}:").into_bytes();
+    message.extend_from_slice(hash);
+    message
+}
+
+/// Check that the binary matches the hash of the manifest, and that the
+/// version, platform and hash were signed with the private key belonging to
+/// `public_key`.
+fn verify(binary: &[u8], version: &str, platform: &str, download: &Download, public_key: &str) -> Result<(), String> {
+    let hash = Sha256::digest(binary);
+
+    if hash.as_slice() != decode_hex(&download.sha256)?.as_slice() {
+        return Err("the hash of the download doesn't match the manifest".to_owned());
+    }
+
+    let public_key: [u8; 32] = decode_hex(public_key)?
+        .try_into()
+        .map_err(|_| "the public key has the wrong length")?;
+    let signature: [u8; 64] = decode_hex(&download.signature)?
+        .try_into()
+        .map_err(|_| "the signature has the wrong length")?;
+
+    VerifyingKey::from_bytes(&public_key)
+        .map_err(|_| "the public key is invalid".to_owned())?
+        .verify(
+            &signed_message(version, platform, hash.as_slice()),
+            &Signature::from_bytes(&signature),
+        )
+        .map_err(|_| "the signature of the download is invalid".to_owned())
+}
+
+/// Path that a downloaded binary waits at until the next start.
+fn pending_path() -> Result<PathBuf, String> {
+    let current_path = std::env::current_exe().map_err(|error| error.to_string())?;
+    Ok(current_path.with_extension("update"))
+}
+
+fn check(manifest_url: &str) -> Result<Option<Release>, String> {
+    require_https(manifest_url)?;
+
+    let manifest = reqwest::blocking::get(manifest_url)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .map_err(|error| format!("failed to download the release manifest: {error}"))?;
+    let manifest: Manifest = toml::from_str(&manifest).map_err(|error| format!("invalid release manifest: {error}"))?;
+
+    if !is_newer(&manifest.version, env!("CARGO_PKG_VERSION")) {
+        return Ok(None);
+    }
+
+    let download = manifest
+        .downloads
+        .get(&platform())
+        .cloned()
+        .ok_or_else(|| format!("version {} is not available for {}", manifest.version, platform()))?;
+
+    Ok(Some(Release {
+        version: manifest.version,
+        changelog: manifest.changelog,
+        download,
+    }))
+}
+
+fn download(release: &Release, public_key: &str) -> Result<(), String> {
+    require_https(&release.download.url)?;
+
+    let binary = reqwest::blocking::get(&release.download.url)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.bytes())
+        .map_err(|error| format!("failed to download version {}: {error}", release.version))?;
+
+    verify(&binary, &release.version, &platform(), &release.download, public_key)?;
+
+    std::fs::write(pending_path()?, &binary).map_err(|error| format!("failed to save the update: {error}"))
+}
+
+/// Checks for and downloads updates on a separate thread, so a slow server
+/// never blocks the client.
+pub struct Updater {
+    settings: UpdaterSettings,
+    release: Option<Release>,
+    sender: Sender<UpdaterEvent>,
+    receiver: Receiver<UpdaterEvent>,
+}
+
+impl Updater {
+    pub fn new() -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        Self {
+            settings: UpdaterSettings::new(),
+            release: None,
+            sender,
+            receiver,
+        }
+    }
+
+    pub fn check_for_update(&self) {
+        let Some(manifest_url) = self.settings.manifest_url.clone() else {
+            return;
+        };
+
+        if PUBLIC_KEY.is_none() {
+            #[cfg(feature = "debug")]
+            print_debug!("not checking for updates, because the client was built without a public key");
+
+            return;
+        }
+
+        let sender = self.sender.clone();
+
+        std::thread::spawn(move || {
+            let event = match check(&manifest_url) {
+                Ok(Some(release)) => UpdaterEvent::UpdateAvailable(release),
+                Ok(None) => return,
+                Err(message) => UpdaterEvent::Failed(message),
+            };
+
+            let _ = sender.send(event);
+        });
+    }
+
+    /// Download the release that was found by the last check.
+    pub fn download_update(&self) {
+        let (Some(release), Some(public_key)) = (self.release.clone(), PUBLIC_KEY) else {
+            return;
+        };
+
+        let sender = self.sender.clone();
+
+        std::thread::spawn(move || {
+            let event = match download(&release, public_key) {
+                Ok(()) => UpdaterEvent::Downloaded { version: release.version },
+                Err(message) => UpdaterEvent::Failed(message),
+            };
+
+            let _ = sender.send(event);
+        });
+    }
+
+    pub fn poll(&mut self) -> Vec<UpdaterEvent> {
+        let events: Vec<UpdaterEvent> = self.receiver.try_iter().collect();
+
+        for event in &events {
+            if let UpdaterEvent::UpdateAvailable(release) = event {
+                self.release = Some(release.clone());
+            }
+        }
+
+        events
+    }
+}
+
+/// Replace the running binary with a downloaded update and start the new
+/// client. Returns `true` if the new client was started, in which case this
+/// one should exit right away.
+pub fn apply_pending_update() -> bool {
+    let Ok(current_path) = std::env::current_exe() else {
+        return false;
+    };
+
+    let pending_path = current_path.with_extension("update");
+    let old_path = current_path.with_extension("old");
+
+    // The binary that was replaced by the last update can only be removed once
+    // it isn't running anymore.
+    let _ = std::fs::remove_file(&old_path);
+
+    if !pending_path.exists() {
+        return false;
+    }
+
+    // Running binaries can be renamed on every platform, but not always
+    // overwritten.
+    let swapped = std::fs::rename(&current_path, &old_path).and_then(|_| {
+        std::fs::rename(&pending_path, &current_path).inspect_err(|_| {
+            let _ = std::fs::rename(&old_path, &current_path);
+        })
+    });
+
+    if let Err(_error) = swapped {
+        #[cfg(feature = "debug")]
+        print_debug!("failed to apply update: {}", _error);
+
+        return false;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let _ = std::fs::set_permissions(&current_path, std::fs::Permissions::from_mode(0o755));
+    }
+
+    std::process::Command::new(&current_path)
+        .args(std::env::args_os().skip(1))
+        .spawn()
+        .is_ok()
+}
+
+#[cfg(test)]
+mod verification {
+    use ed25519_dalek::{Signer, SigningKey};
+    use sha2::{Digest, Sha256};
+
+    use super::{decode_hex, is_newer, require_https, signed_message, verify, Download};
+
+    const BINARY: &[u8] = b"korangar";
+    const VERSION: &str = "0.2.0";
+    const PLATFORM: &str = "linux-x86_64";
+
+    fn encode_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn public_key() -> String {
+        encode_hex(signing_key().verifying_key().as_bytes())
+    }
+
+    fn signed_download(version: &str, platform: &str) -> Download {
+        let hash = Sha256::digest(BINARY);
+        let signature = signing_key().sign(&signed_message(version, platform, hash.as_slice()));
+
+        Download {
+            url: String::new(),
+            sha256: encode_hex(hash.as_slice()),
+            signature: encode_hex(&signature.to_bytes()),
+        }
+    }
+
+    #[test]
+    fn versions() {
+        assert!(is_newer("0.2.0", "0.1.0"));
+        assert!(is_newer("0.10.0", "0.9.3"));
+        assert!(is_newer("1.0.0.1", "1.0.0"));
+        assert!(!is_newer("0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1.0", "0.2.0"));
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(decode_hex("00ff10"), Ok(vec![0x00, 0xFF, 0x10]));
+        assert_eq!(decode_hex("00FF"), Ok(vec![0x00, 0xFF]));
+        assert!(decode_hex("0").is_err());
+        assert!(decode_hex("zz").is_err());
+        assert!(decode_hex("0\u{e9}0").is_err());
+    }
+
+    #[test]
+    fn https_only() {
+        assert!(require_https("https://example.com/manifest.toml").is_ok());
+        assert!(require_https("http://example.com/manifest.toml").is_err());
+        assert!(require_https("file:///tmp/korangar").is_err());
+    }
+
+    #[test]
+    fn valid_signature() {
+        let download = signed_download(VERSION, PLATFORM);
+
+        assert!(verify(BINARY, VERSION, PLATFORM, &download, &public_key()).is_ok());
+    }
+
+    #[test]
+    fn hash_mismatch() {
+        let download = signed_download(VERSION, PLATFORM);
+
+        assert!(verify(b"korangar!", VERSION, PLATFORM, &download, &public_key()).is_err());
+    }
+
+    #[test]
+    fn replayed_release() {
+        let download = signed_download("0.1.0", PLATFORM);
+
+        assert!(verify(BINARY, VERSION, PLATFORM, &download, &public_key()).is_err());
+    }
+
+    #[test]
+    fn other_platform() {
+        let download = signed_download(VERSION, "windows-x86_64");
+
+        assert!(verify(BINARY, VERSION, PLATFORM, &download, &public_key()).is_err());
+    }
+
+    #[test]
+    fn other_key() {
+        let download = signed_download(VERSION, PLATFORM);
+        let other_key = encode_hex(SigningKey::from_bytes(&[8; 32]).verifying_key().as_bytes());
+
+        assert!(verify(BINARY, VERSION, PLATFORM, &download, &other_key).is_err());
+    }
+
+    #[test]
+    fn unsigned() {
+        let download = Download {
+            signature: String::new(),
+            ..signed_download(VERSION, PLATFORM)
+        };
+
+        assert!(verify(BINARY, VERSION, PLATFORM, &download, &public_key()).is_err());
+    }
+}
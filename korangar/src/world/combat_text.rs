@@ -0,0 +1,218 @@
+use cgmath::{Vector2, Vector3};
+use korangar_networking::DamageKind;
+use rand::{thread_rng, Rng};
+
+use crate::graphics::{Camera, Color, DeferredRenderer, Renderer};
+use crate::interface::layout::{ScreenPosition, ScreenSize};
+
+/// Part of the lifetime of a text at the end of which it fades out.
+const FADE_OUT_PART: f32 = 0.4;
+/// Distance in pixels between the feet of an entity and the text when it
+/// appears.
+const STARTING_OFFSET: f32 = 40.0;
+/// Maximum horizontal distance in pixels that a text drifts away from its
+/// entity, so texts of hits in quick succession don't overlap.
+const MAXIMUM_DRIFT: f32 = 20.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CombatTextKind {
+    Damage(DamageKind),
+    Heal,
+}
+
+struct CombatTextStyle {
+    color: Color,
+    font_size: f32,
+    /// Time in seconds that the text is shown.
+    duration: f32,
+    /// Distance in pixels that the text rises per second.
+    rise_speed: f32,
+}
+
+fn get_style(kind: CombatTextKind) -> CombatTextStyle {
+    match kind {
+        CombatTextKind::Damage(DamageKind::Normal) => CombatTextStyle {
+            color: Color::monochrome_u8(255),
+            font_size: 16.0,
+            duration: 0.8,
+            rise_speed: 60.0,
+        },
+        CombatTextKind::Damage(DamageKind::Critical) => CombatTextStyle {
+            color: Color::rgb_u8(255, 200, 40),
+            font_size: 22.0,
+            duration: 1.0,
+            rise_speed: 50.0,
+        },
+        CombatTextKind::Damage(DamageKind::Miss | DamageKind::LuckyDodge) => CombatTextStyle {
+            color: Color::monochrome_u8(180),
+            font_size: 14.0,
+            duration: 0.8,
+            rise_speed: 40.0,
+        },
+        CombatTextKind::Heal => CombatTextStyle {
+            color: Color::rgb_u8(30, 255, 30),
+            font_size: 16.0,
+            duration: 1.0,
+            rise_speed: 35.0,
+        },
+    }
+}
+
+fn get_text(kind: CombatTextKind, amount: usize) -> String {
+    match kind {
+        CombatTextKind::Damage(DamageKind::Miss) => "Miss".to_owned(),
+        CombatTextKind::Damage(DamageKind::LuckyDodge) => "Lucky".to_owned(),
+        _ => amount.to_string(),
+    }
+}
+
+/// Number that rises above an entity and fades out after it took damage or
+/// was healed.
+struct CombatText {
+    position: Vector3<f32>,
+    text: String,
+    style: CombatTextStyle,
+    drift: f32,
+    elapsed: f32,
+}
+
+impl CombatText {
+    fn new(position: Vector3<f32>, kind: CombatTextKind, amount: usize) -> Self {
+        let drift = match kind {
+            CombatTextKind::Damage(_) => thread_rng().gen_range(-MAXIMUM_DRIFT..MAXIMUM_DRIFT),
+            CombatTextKind::Heal => 0.0,
+        };
+
+        Self {
+            position,
+            text: get_text(kind, amount),
+            style: get_style(kind),
+            drift,
+            elapsed: 0.0,
+        }
+    }
+
+    fn progress(&self) -> f32 {
+        (self.elapsed / self.style.duration).min(1.0)
+    }
+
+    /// Opacity of the text, between 1 until it starts fading out and 0 once it
+    /// expired.
+    fn opacity(&self) -> f32 {
+        ((1.0 - self.progress()) / FADE_OUT_PART).min(1.0)
+    }
+
+    /// Returns `false` once the text expired.
+    fn update(&mut self, delta_time: f32) -> bool {
+        self.elapsed += delta_time;
+        self.elapsed < self.style.duration
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+    ) {
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.position.extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+
+        let CombatTextStyle {
+            color,
+            font_size,
+            rise_speed,
+            ..
+        } = self.style;
+
+        // Characters are rendered with a width of half the font size.
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width - self.text.len() as f32 * font_size / 4.0 + self.drift * self.progress(),
+            top: screen_position.y * window_size.height - STARTING_OFFSET - rise_speed * self.elapsed,
+        };
+        let color = Color {
+            alpha: color.alpha * self.opacity(),
+            ..color
+        };
+
+        renderer.render_damage_text(render_target, &self.text, final_position, color, font_size);
+    }
+}
+
+/// Floating combat text of all entities.
+#[derive(Default)]
+pub struct CombatTexts {
+    texts: Vec<CombatText>,
+}
+
+impl CombatTexts {
+    pub fn spawn(&mut self, position: Vector3<f32>, kind: CombatTextKind, amount: usize) {
+        self.texts.push(CombatText::new(position, kind, amount));
+    }
+
+    pub fn clear(&mut self) {
+        self.texts.clear();
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.texts.retain_mut(|text| text.update(delta_time));
+    }
+
+    pub fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+    ) {
+        self.texts
+            .iter()
+            .for_each(|text| text.render(render_target, renderer, camera, window_size));
+    }
+}
+
+#[cfg(test)]
+mod combat_text {
+    use cgmath::Vector3;
+    use korangar_networking::DamageKind;
+
+    use super::{get_text, CombatText, CombatTextKind, CombatTexts};
+
+    #[test]
+    fn texts() {
+        assert_eq!(get_text(CombatTextKind::Damage(DamageKind::Critical), 120), "120");
+        assert_eq!(get_text(CombatTextKind::Damage(DamageKind::Miss), 0), "Miss");
+        assert_eq!(get_text(CombatTextKind::Damage(DamageKind::LuckyDodge), 0), "Lucky");
+        assert_eq!(get_text(CombatTextKind::Heal, 35), "35");
+    }
+
+    #[test]
+    fn fade_out() {
+        let mut text = CombatText::new(Vector3::new(0.0, 0.0, 0.0), CombatTextKind::Heal, 35);
+
+        assert_eq!(text.opacity(), 1.0);
+        assert!(text.update(text.style.duration * 0.5));
+        assert_eq!(text.opacity(), 1.0);
+        assert!(text.update(text.style.duration * 0.3));
+        assert!(text.opacity() < 1.0 && text.opacity() > 0.0);
+        assert!(!text.update(text.style.duration));
+    }
+
+    #[test]
+    fn expiry() {
+        let mut texts = CombatTexts::default();
+        texts.spawn(Vector3::new(0.0, 0.0, 0.0), CombatTextKind::Damage(DamageKind::Normal), 25);
+        texts.spawn(Vector3::new(0.0, 0.0, 0.0), CombatTextKind::Heal, 35);
+
+        texts.update(0.9);
+        assert_eq!(texts.texts.len(), 1);
+
+        texts.update(0.2);
+        assert!(texts.texts.is_empty());
+    }
+}
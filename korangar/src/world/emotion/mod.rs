@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use ragnarok_packets::ClientTick;
+
+use crate::graphics::{Color, Renderer, SpriteRenderer};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenPosition;
+use crate::loaders::{ActionLoader, Actions, AnimationState, GameFileLoader, Sprite, SpriteLoader};
+
+/// Time in milliseconds that an emotion stays above an entity if its
+/// animation has no length, like the fallback animation.
+pub const EMOTION_DURATION: u32 = 3000;
+
+/// Emotions that the player can select from the emote window, together with
+/// their names. The index into the emotion sprite is stored alongside since
+/// some emotions can not be triggered by the player.
+pub const EMOTIONS: &[(u8, &str)] = &[
+    (0, "Surprise"),
+    (1, "Question"),
+    (2, "Delight"),
+    (3, "Throb"),
+    (4, "Sweat"),
+    (5, "Aha"),
+    (6, "Fret"),
+    (7, "Anger"),
+    (8, "Money"),
+    (9, "Think"),
+    (10, "Scissors"),
+    (11, "Rock"),
+    (12, "Paper"),
+    (13, "Flag"),
+    (14, "Big throb"),
+    (15, "Thanks"),
+    (16, "Kek"),
+    (17, "Sorry"),
+    (18, "Smile"),
+    (19, "Profuse sweat"),
+    (20, "Scratch"),
+    (21, "Best"),
+    (22, "Stare about"),
+    (23, "Huk"),
+    (24, "O"),
+    (25, "X"),
+    (26, "Help"),
+    (27, "Go"),
+    (28, "Cry"),
+    (29, "Kik"),
+    (30, "Chup"),
+    (31, "Chup chup"),
+    (32, "Hng"),
+    (33, "Ok"),
+    (36, "Stare"),
+    (37, "Hungry"),
+    (38, "Cool"),
+    (39, "Merong"),
+    (40, "Shy"),
+    (41, "Good boy"),
+    (42, "Sp time"),
+    (43, "Sexy"),
+    (44, "Come on"),
+    (45, "Sleepy"),
+    (46, "Congratulations"),
+    (47, "Hp time"),
+];
+
+/// Emotion that is displayed above an entity until its animation finished
+/// playing once.
+#[derive(Clone)]
+pub struct EmotionState {
+    pub animation_state: AnimationState,
+    /// Time in milliseconds after which the emotion is removed.
+    pub duration: u32,
+}
+
+impl EmotionState {
+    /// Advance the animation. Returns `false` once the animation finished.
+    pub fn update(&mut self, client_tick: ClientTick) -> bool {
+        self.animation_state.update(client_tick);
+        self.animation_state.time < self.duration
+    }
+}
+
+/// Sprite and actions of the emotion bubbles that are displayed above
+/// entities and in the emote window.
+#[derive(Clone)]
+pub struct Emotions {
+    sprite: Arc<Sprite>,
+    actions: Arc<Actions>,
+}
+
+impl Emotions {
+    pub fn new(game_file_loader: &mut GameFileLoader, sprite_loader: &mut SpriteLoader, action_loader: &mut ActionLoader) -> Self {
+        let sprite = sprite_loader.get("ÀÌÆÑÆ®\\emotion.spr", game_file_loader).unwrap();
+        let actions = action_loader.get("ÀÌÆÑÆ®\\emotion.act", game_file_loader).unwrap();
+
+        Self { sprite, actions }
+    }
+
+    /// Create the animation state for displaying an emotion, starting at the
+    /// given client tick.
+    pub fn animation_state(emotion: u8, client_tick: ClientTick) -> AnimationState {
+        let mut animation_state = AnimationState::new(client_tick);
+        animation_state.action = emotion as usize;
+        animation_state
+    }
+
+    /// Start displaying an emotion above an entity.
+    pub fn emotion_state(&self, emotion: u8, client_tick: ClientTick) -> EmotionState {
+        let animation_state = Self::animation_state(emotion, client_tick);
+        let split_state = AnimationState {
+            action: animation_state.action / 8,
+            ..animation_state.clone()
+        };
+
+        let duration = match self.actions.get_duration(&split_state, animation_state.action % 8) {
+            0 => EMOTION_DURATION,
+            duration => duration,
+        };
+
+        EmotionState { animation_state, duration }
+    }
+
+    pub fn render<T>(
+        &self,
+        render_target: &mut T::Target,
+        renderer: &T,
+        animation_state: &AnimationState,
+        position: ScreenPosition,
+        application: &InterfaceSettings,
+    ) where
+        T: Renderer + SpriteRenderer,
+    {
+        // Emotions don't have directions, so the emotion is split into action and
+        // direction to end up at the correct index.
+        let direction = animation_state.action % 8;
+        let animation_state = AnimationState {
+            action: animation_state.action / 8,
+            ..animation_state.clone()
+        };
+
+        self.actions.render2(
+            render_target,
+            renderer,
+            &self.sprite,
+            &animation_state,
+            position,
+            direction,
+            Color::monochrome_u8(255),
+            application,
+        );
+    }
+}
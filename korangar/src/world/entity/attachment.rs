@@ -0,0 +1,52 @@
+use cgmath::Vector2;
+
+/// Named points on an entity that effects can be attached to, so they follow
+/// the entity while it moves and animates instead of staying at a fixed
+/// offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentPoint {
+    /// Top of the sprite.
+    Head,
+    /// Middle of the sprite.
+    Body,
+    /// Point that the sprite stands on.
+    Feet,
+    /// Middle of the weapon sprite, or of the body for entities that don't
+    /// hold a weapon.
+    Weapon,
+}
+
+/// Offset of an attachment point in billboard space, given the origin that a
+/// sprite is rendered at and its rendered height. The y axis of billboard
+/// space points down, so points higher up on the sprite have a smaller y.
+pub fn attachment_offset(attachment_point: AttachmentPoint, origin: Vector2<f32>, height: f32) -> Vector2<f32> {
+    match attachment_point {
+        AttachmentPoint::Head => origin - Vector2::new(0.0, height),
+        AttachmentPoint::Body | AttachmentPoint::Weapon => origin - Vector2::new(0.0, height / 2.0),
+        AttachmentPoint::Feet => Vector2::new(0.0, 0.0),
+    }
+}
+
+#[cfg(test)]
+mod anchors {
+    use cgmath::Vector2;
+
+    use super::{attachment_offset, AttachmentPoint};
+
+    #[test]
+    fn points_on_sprite() {
+        let origin = Vector2::new(1.0, 4.0);
+
+        assert_eq!(attachment_offset(AttachmentPoint::Head, origin, 6.0), Vector2::new(1.0, -2.0));
+        assert_eq!(attachment_offset(AttachmentPoint::Body, origin, 6.0), Vector2::new(1.0, 1.0));
+        assert_eq!(attachment_offset(AttachmentPoint::Weapon, origin, 6.0), Vector2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn feet_ignore_sprite() {
+        assert_eq!(
+            attachment_offset(AttachmentPoint::Feet, Vector2::new(1.0, 4.0), 6.0),
+            Vector2::new(0.0, 0.0)
+        );
+    }
+}
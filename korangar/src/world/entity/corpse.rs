@@ -0,0 +1,34 @@
+use ragnarok_packets::ClientTick;
+
+/// Whether a dead entity has been shown as a corpse for long enough. Entities
+/// that are still alive are never removed. `corpse_duration` is the time in
+/// milliseconds that corpses stay on the map.
+pub fn should_be_removed(death_timestamp: Option<u32>, corpse_duration: u32, client_tick: ClientTick) -> bool {
+    death_timestamp.is_some_and(|timestamp| client_tick.0.saturating_sub(timestamp) >= corpse_duration)
+}
+
+#[cfg(test)]
+mod persistence {
+    use ragnarok_packets::ClientTick;
+
+    use super::should_be_removed;
+
+    #[test]
+    fn alive() {
+        assert!(!should_be_removed(None, 0, ClientTick(1000)));
+        assert!(!should_be_removed(None, 5000, ClientTick(100000)));
+    }
+
+    #[test]
+    fn corpse_duration() {
+        assert!(!should_be_removed(Some(1000), 5000, ClientTick(1000)));
+        assert!(!should_be_removed(Some(1000), 5000, ClientTick(5999)));
+        assert!(should_be_removed(Some(1000), 5000, ClientTick(6000)));
+    }
+
+    #[test]
+    fn died_after_client_tick() {
+        // The client tick can lag behind when it was adjusted by the server.
+        assert!(!should_be_removed(Some(2000), 5000, ClientTick(1000)));
+    }
+}
@@ -0,0 +1,108 @@
+/// Time in seconds that an entity takes to turn towards a new direction.
+const TURN_DURATION: f32 = 0.1;
+const DIRECTION_COUNT: f32 = 8.0;
+
+/// Signed number of directions between `from` and `to`, taking the shorter
+/// way around.
+fn shortest_turn(from: f32, to: f32) -> f32 {
+    let difference = (to - from).rem_euclid(DIRECTION_COUNT);
+
+    match difference > DIRECTION_COUNT / 2.0 {
+        true => difference - DIRECTION_COUNT,
+        false => difference,
+    }
+}
+
+/// Direction an entity is rendered in. The direction that is sent by the
+/// server snaps between the 8 directions, so the rendered direction turns
+/// towards it over a short time instead, passing the directions in between.
+pub struct Facing {
+    /// Direction that is currently rendered, in the range `0.0..8.0`.
+    direction: f32,
+    /// Direction at the start of the current turn.
+    start_direction: f32,
+    target_direction: usize,
+    elapsed: f32,
+}
+
+impl Facing {
+    pub fn new(direction: usize) -> Self {
+        let direction = direction % DIRECTION_COUNT as usize;
+
+        Self {
+            direction: direction as f32,
+            start_direction: direction as f32,
+            target_direction: direction,
+            elapsed: TURN_DURATION,
+        }
+    }
+
+    pub fn update(&mut self, target_direction: usize, delta_time: f32) {
+        if target_direction != self.target_direction {
+            self.start_direction = self.direction;
+            self.target_direction = target_direction;
+            self.elapsed = 0.0;
+        }
+
+        self.elapsed = (self.elapsed + delta_time).min(TURN_DURATION);
+
+        let turn = shortest_turn(self.start_direction, self.target_direction as f32);
+        let direction = self.start_direction + turn * (self.elapsed / TURN_DURATION);
+
+        self.direction = direction.rem_euclid(DIRECTION_COUNT);
+    }
+
+    /// The nearest of the 8 directions to the rendered direction.
+    pub fn get_direction(&self) -> usize {
+        self.direction.round() as usize % DIRECTION_COUNT as usize
+    }
+}
+
+#[cfg(test)]
+mod turning {
+    use super::{shortest_turn, Facing, TURN_DURATION};
+
+    #[test]
+    fn shorter_way_around() {
+        assert_eq!(shortest_turn(1.0, 3.0), 2.0);
+        assert_eq!(shortest_turn(1.0, 7.0), -2.0);
+        assert_eq!(shortest_turn(7.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn pass_directions_in_between() {
+        let mut facing = Facing::new(0);
+
+        facing.update(4, TURN_DURATION / 4.0);
+        assert_eq!(facing.get_direction(), 1);
+
+        facing.update(4, TURN_DURATION / 4.0);
+        assert_eq!(facing.get_direction(), 2);
+
+        facing.update(4, TURN_DURATION);
+        assert_eq!(facing.get_direction(), 4);
+    }
+
+    #[test]
+    fn wrap_around() {
+        let mut facing = Facing::new(1);
+
+        facing.update(6, TURN_DURATION / 2.0);
+        assert_eq!(facing.get_direction(), 0);
+
+        facing.update(6, TURN_DURATION);
+        assert_eq!(facing.get_direction(), 6);
+    }
+
+    #[test]
+    fn change_target_while_turning() {
+        let mut facing = Facing::new(0);
+
+        facing.update(2, TURN_DURATION / 2.0);
+        facing.update(0, TURN_DURATION / 2.0);
+        assert_eq!(facing.get_direction(), 1);
+
+        facing.update(0, TURN_DURATION);
+        assert_eq!(facing.get_direction(), 0);
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use cgmath::{Array, InnerSpace, Vector2, Vector3, VectorSpace};
+use korangar_networking::FollowerKind;
+use ragnarok_packets::{ClientTick, EntityId};
+
+use crate::graphics::{Camera, EntityRenderer, EntityTint, Renderer};
+use crate::loaders::{ActionLoader, Actions, AnimationState, GameFileLoader, Sprite, SpriteLoader};
+
+/// How quickly followers catch up with their owner. Higher values make them
+/// trail less.
+const FOLLOW_SPEED: f32 = 8.0;
+/// Distance at which a follower counts as caught up, so it stops moving
+/// instead of creeping closer forever.
+const TRAIL_TOLERANCE: f32 = 0.05;
+/// Movement speed that the walking animation of followers is played at.
+const FOLLOWER_MOVEMENT_SPEED: usize = 150;
+
+fn get_follower_sprite_path(kind: FollowerKind) -> String {
+    match kind {
+        FollowerKind::Falcon => "ÀÌÆÑÆ®\\¸Å".to_owned(),
+        FollowerKind::Cart(1) => "ÀÌÆÑÆ®\\¼Õ¼ö·¹".to_owned(),
+        FollowerKind::Cart(cart_type) => format!("ÀÌÆÑÆ®\\¼Õ¼ö·¹{cart_type}"),
+    }
+}
+
+/// Distance that a follower keeps to its owner. The falcon sits on the
+/// shoulder, so its sprite is already offset from the owner.
+fn get_follower_distance(kind: FollowerKind) -> f32 {
+    match kind {
+        FollowerKind::Falcon => 0.0,
+        FollowerKind::Cart(_) => 4.0,
+    }
+}
+
+/// Move a follower towards its owner until it is `distance` away from it. A
+/// follower that is close enough doesn't move, so it ends up behind its owner.
+fn trail_position(position: Vector3<f32>, owner_position: Vector3<f32>, distance: f32, delta_time: f32) -> Vector3<f32> {
+    let offset = position - owner_position;
+    let length = offset.magnitude();
+
+    if length <= distance + TRAIL_TOLERANCE {
+        return position;
+    }
+
+    let target = owner_position + offset * (distance / length);
+    position.lerp(target, (FOLLOW_SPEED * delta_time).min(1.0))
+}
+
+/// Falcon or pushcart that trails an entity. Followers have their own
+/// animation but are not entities of their own on the server.
+pub struct Follower {
+    kind: FollowerKind,
+    sprite: Arc<Sprite>,
+    actions: Arc<Actions>,
+    animation_state: AnimationState,
+    position: Vector3<f32>,
+    moving: bool,
+}
+
+impl Follower {
+    pub fn new(
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        kind: FollowerKind,
+        owner_position: Vector3<f32>,
+        client_tick: ClientTick,
+    ) -> Self {
+        let file_path = get_follower_sprite_path(kind);
+
+        Self {
+            kind,
+            sprite: sprite_loader.get(&format!("{file_path}.spr"), game_file_loader).unwrap(),
+            actions: action_loader.get(&format!("{file_path}.act"), game_file_loader).unwrap(),
+            animation_state: AnimationState::new(client_tick),
+            position: owner_position,
+            moving: false,
+        }
+    }
+
+    pub fn get_kind(&self) -> FollowerKind {
+        self.kind
+    }
+
+    pub fn update(&mut self, owner_position: Vector3<f32>, delta_time: f32, client_tick: ClientTick) {
+        let position = trail_position(self.position, owner_position, get_follower_distance(self.kind), delta_time);
+        let moving = position != self.position;
+
+        if moving != self.moving {
+            match moving {
+                true => self.animation_state.walk(FOLLOWER_MOVEMENT_SPEED, client_tick),
+                false => self.animation_state.idle(client_tick),
+            }
+        }
+
+        self.position = position;
+        self.moving = moving;
+        self.animation_state.update(client_tick);
+    }
+
+    /// Followers look in the same direction as their owner and can be picked
+    /// as their owner.
+    pub fn render<T>(
+        &self,
+        render_target: &mut T::Target,
+        renderer: &T,
+        camera: &dyn Camera,
+        head_direction: usize,
+        depth_offset: f32,
+        entity_id: EntityId,
+    ) where
+        T: Renderer + EntityRenderer,
+    {
+        let (texture, position, mirror) = self.actions.render(
+            &self.sprite,
+            &self.animation_state,
+            camera.get_camera_direction(),
+            head_direction,
+        );
+
+        renderer.render_entity(
+            render_target,
+            camera,
+            texture,
+            self.position,
+            Vector3::new(position.x, position.y, depth_offset),
+            Vector2::from_value(0.7),
+            Vector2::new(1, 1),
+            Vector2::new(0, 0),
+            mirror,
+            EntityTint::default(),
+            entity_id,
+        );
+    }
+}
+
+#[cfg(test)]
+mod trailing {
+    use cgmath::{InnerSpace, Vector3};
+
+    use super::{trail_position, TRAIL_TOLERANCE};
+
+    #[test]
+    fn stay_close_enough() {
+        let position = Vector3::new(3.0, 0.0, 0.0);
+
+        assert_eq!(trail_position(position, Vector3::new(0.0, 0.0, 0.0), 4.0, 0.1), position);
+    }
+
+    #[test]
+    fn catch_up() {
+        let owner_position = Vector3::new(0.0, 0.0, 0.0);
+        let mut position = Vector3::new(20.0, 0.0, 0.0);
+
+        for _ in 0..100 {
+            position = trail_position(position, owner_position, 4.0, 0.05);
+        }
+
+        let distance = (position - owner_position).magnitude();
+        assert!(distance > 3.99 && distance <= 4.0 + TRAIL_TOLERANCE);
+    }
+
+    #[test]
+    fn no_overshoot() {
+        let position = trail_position(Vector3::new(10.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.0), 4.0, 10.0);
+
+        assert_eq!(position, Vector3::new(4.0, 0.0, 0.0));
+    }
+}
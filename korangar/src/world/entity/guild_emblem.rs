@@ -0,0 +1,74 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use image::{ImageFormat, RgbaImage};
+use korangar_networking::GuildEmblemId;
+use vulkano::image::view::ImageView;
+use yazi::{decompress, Format};
+
+use crate::loaders::make_magenta_transparent;
+
+/// Decode an emblem as sent by the server, which is a zlib compressed bitmap
+/// with a magenta background.
+pub fn decode_guild_emblem(data: &[u8]) -> Option<RgbaImage> {
+    let (bitmap, _checksum) = decompress(data, Format::Zlib).ok()?;
+    let mut image_buffer = image::load_from_memory_with_format(&bitmap, ImageFormat::Bmp).ok()?.to_rgba8();
+
+    make_magenta_transparent(&mut image_buffer);
+
+    Some(image_buffer)
+}
+
+/// Emblems of all guilds that were seen so far. Every emblem is only
+/// requested once, even if the server never responds.
+#[derive(Default)]
+pub struct GuildEmblemCache {
+    emblems: HashMap<GuildEmblemId, Arc<ImageView>>,
+    requested: HashSet<GuildEmblemId>,
+}
+
+impl GuildEmblemCache {
+    pub fn get(&self, emblem_id: GuildEmblemId) -> Option<Arc<ImageView>> {
+        self.emblems.get(&emblem_id).cloned()
+    }
+
+    /// Returns `true` if the emblem wasn't requested before and should be
+    /// requested from the server.
+    pub fn request(&mut self, emblem_id: GuildEmblemId) -> bool {
+        self.requested.insert(emblem_id)
+    }
+
+    pub fn insert(&mut self, emblem_id: GuildEmblemId, texture: Arc<ImageView>) {
+        self.emblems.insert(emblem_id, texture);
+    }
+}
+
+#[cfg(test)]
+mod decoding {
+    use std::io::Cursor;
+
+    use image::{ImageOutputFormat, Rgb, RgbImage};
+    use yazi::{compress, CompressionLevel, Format};
+
+    use super::decode_guild_emblem;
+
+    #[test]
+    fn magenta_background() {
+        let mut bitmap = RgbImage::from_pixel(2, 1, Rgb([255, 0, 255]));
+        bitmap.put_pixel(1, 0, Rgb([10, 20, 30]));
+
+        let mut encoded = Cursor::new(Vec::new());
+        bitmap.write_to(&mut encoded, ImageOutputFormat::Bmp).unwrap();
+        let data = compress(encoded.get_ref(), Format::Zlib, CompressionLevel::Default).unwrap();
+
+        let emblem = decode_guild_emblem(&data).unwrap();
+
+        assert_eq!(emblem.get_pixel(0, 0).0, [0; 4]);
+        assert_eq!(emblem.get_pixel(1, 0).0, [10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn invalid_data() {
+        assert!(decode_guild_emblem(&[1, 2, 3]).is_none());
+    }
+}
@@ -0,0 +1,56 @@
+use ragnarok_packets::ClientTick;
+use serde::{Deserialize, Serialize};
+
+/// Time in milliseconds that the health bar of a monster stays visible after
+/// it took damage.
+const VISIBLE_AFTER_DAMAGE: u32 = 5000;
+
+/// When the health bars of monsters are shown.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HealthBarVisibility {
+    Always,
+    /// Only for a few seconds after the monster took damage and while it is
+    /// hovered.
+    #[default]
+    AfterDamage,
+    Never,
+}
+
+impl HealthBarVisibility {
+    pub fn is_visible(self, last_damage_timestamp: Option<u32>, hovered: bool, client_tick: ClientTick) -> bool {
+        match self {
+            HealthBarVisibility::Always => true,
+            HealthBarVisibility::AfterDamage => {
+                hovered || last_damage_timestamp.is_some_and(|timestamp| client_tick.0.saturating_sub(timestamp) < VISIBLE_AFTER_DAMAGE)
+            }
+            HealthBarVisibility::Never => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod visibility {
+    use ragnarok_packets::ClientTick;
+
+    use super::{HealthBarVisibility, VISIBLE_AFTER_DAMAGE};
+
+    #[test]
+    fn always_and_never() {
+        assert!(HealthBarVisibility::Always.is_visible(None, false, ClientTick(1000)));
+        assert!(!HealthBarVisibility::Never.is_visible(Some(1000), true, ClientTick(1000)));
+    }
+
+    #[test]
+    fn after_damage() {
+        let visibility = HealthBarVisibility::AfterDamage;
+
+        assert!(!visibility.is_visible(None, false, ClientTick(1000)));
+        assert!(visibility.is_visible(Some(1000), false, ClientTick(1000 + VISIBLE_AFTER_DAMAGE - 1)));
+        assert!(!visibility.is_visible(Some(1000), false, ClientTick(1000 + VISIBLE_AFTER_DAMAGE)));
+    }
+
+    #[test]
+    fn while_hovered() {
+        assert!(HealthBarVisibility::AfterDamage.is_visible(None, true, ClientTick(1000)));
+    }
+}
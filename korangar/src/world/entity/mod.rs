@@ -1,25 +1,60 @@
+mod attachment;
+mod corpse;
+mod facing;
+mod follower;
+mod guild_emblem;
+mod health_bar;
+mod overhead;
+mod path_finder;
+mod path_graph;
+mod prediction;
+mod smoothing;
+mod status_effects;
+mod store;
+mod tint;
+
 use std::sync::Arc;
+use std::time::Duration;
 
-use cgmath::{Array, Vector2, Vector3, VectorSpace};
+use cgmath::{Array, Vector2, Vector3, Vector4, VectorSpace};
 use derive_new::new;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::print_debug;
+use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::PrototypeElement;
 use korangar_interface::windows::{PrototypeWindow, Window};
-use korangar_networking::EntityData;
-use ragnarok_formats::map::TileFlags;
+use korangar_networking::{
+    CharacterAppearance, EntityCondition, EntityData, EntityDetails, EntityLook, FollowerKind, GuildEmblemId, LookChange, Mount,
+};
 use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, EntityId, Sex, StatusType, WorldPosition};
 use vulkano::buffer::Subbuffer;
-
+use vulkano::image::view::ImageView;
+
+pub use self::attachment::{attachment_offset, AttachmentPoint};
+use self::corpse::should_be_removed;
+pub use self::facing::Facing;
+pub use self::follower::Follower;
+pub use self::guild_emblem::{decode_guild_emblem, GuildEmblemCache};
+pub use self::health_bar::HealthBarVisibility;
+pub use self::overhead::{OverheadText, MAXIMUM_OVERHEAD_TEXTS};
+pub use self::path_finder::{find_path_within_range, find_tile_path, tile_distance};
+pub use self::path_graph::PathGraph;
+use self::prediction::{has_diverged, tile_at};
+use self::smoothing::smoothed_step_position;
+pub use self::status_effects::StatusEffects;
+pub use self::store::{EntityHandle, EntityStore, StoredEntity};
+use self::tint::TintState;
 #[cfg(feature = "debug")]
 use crate::graphics::MarkerRenderer;
-use crate::graphics::{Camera, DeferredRenderer, EntityRenderer, ModelVertex, Renderer};
+use crate::graphics::{Camera, Color, DeferredRenderer, EntityRenderer, EntityTint, ModelVertex, Renderer};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{ScreenPosition, ScreenSize};
 use crate::interface::theme::GameTheme;
 use crate::interface::windows::WindowCache;
 use crate::loaders::{ActionLoader, Actions, AnimationState, GameFileLoader, ScriptLoader, Sprite, SpriteLoader};
-use crate::world::Map;
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
+use crate::world::{EmotionState, Emotions, Map};
 
 pub enum ResourceState<T> {
     Available(T),
@@ -41,12 +76,55 @@ pub struct Movement {
     #[hidden_element]
     steps: Vec<(Vector2<usize>, u32)>,
     starting_timestamp: u32,
+    /// Index of the last step that was reached.
+    #[new(default)]
+    #[hidden_element]
+    reached_step_index: usize,
     #[cfg(feature = "debug")]
     #[new(default)]
     #[hidden_element]
     pub steps_vertex_buffer: Option<Subbuffer<[ModelVertex]>>,
 }
 
+/// Movement that is forced on an entity, for example by a knockback. The
+/// entity slides to its destination in a straight line over a fixed duration,
+/// regardless of the tiles in between.
+#[derive(Clone, new, PrototypeElement)]
+pub struct ForcedMovement {
+    origin: Vector3<f32>,
+    destination: Vector2<usize>,
+    starting_timestamp: u32,
+}
+
+/// Time in milliseconds that a forced movement takes.
+const FORCED_MOVEMENT_DURATION: u32 = 150;
+
+/// Distance in pixels between the position of an entity and its emotion.
+const EMOTION_OFFSET: f32 = 90.0;
+
+/// Job id of the Summoner, the starting job of the Doram race.
+const SUMMONER_JOB_ID: usize = 4218;
+
+/// Distance between the rider and the mount along the view direction, so the
+/// mount is always behind the rider.
+const MOUNT_DEPTH_OFFSET: f32 = 0.01;
+/// Distance between two parts of a character along the view direction, so
+/// parts that are added later are always in front.
+const PART_DEPTH_OFFSET: f32 = 0.001;
+/// Scale that the sprites of entities are rendered with.
+const SPRITE_SCALE: f32 = 0.7;
+
+/// Sprite that is rendered on top of the body of a player, like the head, a
+/// headgear or a weapon.
+struct EntityPart {
+    sprite: Arc<Sprite>,
+    actions: Arc<Actions>,
+    /// Whether the part is moved so its attach point is on the attach point of
+    /// the body. Weapons and shields share the origin of the body instead.
+    attached: bool,
+    weapon: bool,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum EntityType {
     Warp,
@@ -69,14 +147,69 @@ pub struct Common {
     #[hidden_element]
     pub entity_type: EntityType,
     pub active_movement: Option<Movement>,
+    pub forced_movement: Option<ForcedMovement>,
     pub sprite: Arc<Sprite>,
     pub actions: Arc<Actions>,
+    #[hidden_element]
+    mount: Option<Mount>,
+    /// Sprite and actions of the mount, rendered as a separate part below the
+    /// rider.
+    #[hidden_element]
+    mount_part: Option<(Arc<Sprite>, Arc<Actions>)>,
+    #[hidden_element]
+    look: EntityLook,
+    #[hidden_element]
+    parts: Vec<EntityPart>,
+    #[hidden_element]
+    followers: Vec<Follower>,
+    /// Direction that the entity is rendered in, which turns smoothly towards
+    /// the head direction.
+    #[hidden_element]
+    facing: Facing,
+    #[hidden_element]
+    sitting: bool,
     pub grid_position: Vector2<usize>,
     pub position: Vector3<f32>,
+    /// Distance that the sprite is rendered above the ground, for entities
+    /// that hover.
+    pub job_elevation: f32,
+    /// Factor that the sprites are scaled by, for entities that were made
+    /// smaller or larger.
+    pub scale: f32,
+    #[hidden_element]
+    details: ResourceState<EntityDetails>,
+    #[hidden_element]
+    guild_emblem_id: Option<GuildEmblemId>,
     #[hidden_element]
-    details: ResourceState<String>,
+    guild_emblem: ResourceState<Arc<ImageView>>,
     #[hidden_element]
     animation_state: AnimationState,
+    #[hidden_element]
+    emotion_state: Option<EmotionState>,
+    #[hidden_element]
+    overhead_text: Option<OverheadText>,
+    #[hidden_element]
+    status_effects: StatusEffects,
+    /// Client tick at which the entity last took damage.
+    #[hidden_element]
+    last_damage_timestamp: Option<u32>,
+    /// Client tick at which the entity died, for entities that stay on the map
+    /// as a corpse.
+    #[hidden_element]
+    death_timestamp: Option<u32>,
+    /// Hit flash and conditions that change the color of the entity.
+    #[hidden_element]
+    tint_state: TintState,
+    /// Tint that the sprites are rendered with in the current frame.
+    #[hidden_element]
+    tint: EntityTint,
+    /// Destination of a walk that was started before the server confirmed
+    /// it.
+    #[hidden_element]
+    predicted_destination: Option<Vector2<usize>>,
+    /// Tile that the entity stepped on since the footstep was last taken.
+    #[hidden_element]
+    footstep: Option<Vector2<usize>>,
 }
 
 #[cfg_attr(feature = "debug", korangar_debug::profile)]
@@ -226,10 +359,73 @@ fn get_sprite_path_for_player_job(job_id: usize) -> &'static str {
         4305 => "SHIRANUI",        // SHIRANUI
         4306 => "NIGHT_WATCH",     // NIGHT WATCH
         4307 => "HYPER_NOVICE",    // HYPER NOVICE
+        4218 => "¼ÒÈ¯»ç",          // SUMMONER
         _ => "ÃÊº¸ÀÚ",             // NOVICE
     }
 }
 
+/// Job ids of mounted characters only swap the body sprite, so they are split
+/// into the job without the mount and the mount itself.
+fn get_mounted_job(job_id: usize) -> (usize, Option<Mount>) {
+    match job_id {
+        13 => (7, Some(Mount::Peco)),          // KNIGHT2
+        21 => (14, Some(Mount::Peco)),         // CRUSADER2
+        4014 => (4008, Some(Mount::Peco)),     // LORD_KNIGHT2
+        4022 => (4015, Some(Mount::Peco)),     // PALADIN2
+        4036 => (4030, Some(Mount::Peco)),     // BABY_KNIGHT2
+        4044 => (4037, Some(Mount::Peco)),     // BABY_CRUSADER2
+        4080 => (4054, Some(Mount::Dragon)),   // RUNE_KNIGHT2
+        4081 => (4060, Some(Mount::Dragon)),   // RUNE_KNIGHT_T2
+        4082 => (4066, Some(Mount::Peco)),     // ROYAL_GUARD2
+        4083 => (4073, Some(Mount::Peco)),     // ROYAL_GUARD_T2
+        4084 => (4056, Some(Mount::Warg)),     // RANGER2
+        4085 => (4062, Some(Mount::Warg)),     // RANGER_T2
+        4086 => (4058, Some(Mount::MadoGear)), // MECHANIC2
+        4087 => (4064, Some(Mount::MadoGear)), // MECHANIC_T2
+        4109 => (4096, Some(Mount::Dragon)),   // BABY_RUNE2
+        4110 => (4102, Some(Mount::Peco)),     // BABY_GUARD2
+        4111 => (4098, Some(Mount::Warg)),     // BABY_RANGER2
+        4112 => (4100, Some(Mount::MadoGear)), // BABY_MECHANIC2
+        _ => (job_id, None),
+    }
+}
+
+#[allow(clippy::invisible_characters)]
+fn get_mount_sprite_name(mount: Mount, job_id: usize) -> &'static str {
+    match mount {
+        // Royal guards ride a gryphon instead of a peco peco.
+        Mount::Peco if matches!(job_id, 4066 | 4073 | 4102) => "±×¸®Æù", // GRYPHON
+        Mount::Peco => "ÆäÄÚÆäÄÚ",                                       // PECO_PECO
+        Mount::Dragon => "µå·¡°ï",                                       // DRAGON
+        Mount::Warg => "´Á´ë",                                           // WARG
+        Mount::MadoGear => "¸¶µµ±â¾î",                                   // MADO_GEAR
+        Mount::Costume => "ÄÚ½ºÆ¬",                                      // COSTUME
+    }
+}
+
+/// Distance that a mount and its rider are rendered above the ground.
+fn get_mount_elevation(mount: Mount, job_id: usize) -> f32 {
+    match mount {
+        Mount::Peco if matches!(job_id, 4066 | 4073 | 4102) => 3.0, // GRYPHON
+        Mount::Dragon => 2.0,
+        _ => 0.0,
+    }
+}
+
+fn get_sex_sprite_path(sex: Sex) -> &'static str {
+    match sex == Sex::Female {
+        true => "¿©",
+        false => "³²",
+    }
+}
+
+fn get_race_sprite_path(job_id: usize) -> &'static str {
+    match job_id {
+        SUMMONER_JOB_ID => "µµ¶÷Á·", // DORAM
+        _ => "ÀÎ°£Á·",               // HUMAN
+    }
+}
+
 fn get_sprite_and_actions(
     game_file_loader: &mut GameFileLoader,
     sprite_loader: &mut SpriteLoader,
@@ -239,14 +435,12 @@ fn get_sprite_and_actions(
     job_id: usize,
     sex: Sex,
 ) -> (Arc<Sprite>, Arc<Actions>) {
-    let sex_sprite_path = match sex == Sex::Female {
-        true => "¿©",
-        false => "³²",
-    };
+    let sex_sprite_path = get_sex_sprite_path(sex);
 
     let file_path = match entity_type {
         EntityType::Player => format!(
-            "ÀÎ°£Á·\\¸öÅë\\{}\\{}_{}",
+            "{}\\¸öÅë\\{}\\{}_{}",
+            get_race_sprite_path(job_id),
             sex_sprite_path,
             get_sprite_path_for_player_job(job_id),
             sex_sprite_path
@@ -262,6 +456,232 @@ fn get_sprite_and_actions(
     )
 }
 
+fn get_head_sprite_and_actions(
+    game_file_loader: &mut GameFileLoader,
+    sprite_loader: &mut SpriteLoader,
+    action_loader: &mut ActionLoader,
+    job_id: usize,
+    sex: Sex,
+    hair_style: u16,
+    hair_color: u16,
+) -> (Arc<Sprite>, Arc<Actions>) {
+    let sex_sprite_path = get_sex_sprite_path(sex);
+    let race_sprite_path = get_race_sprite_path(job_id);
+    let head_path = format!(
+        "{}\\¸Ó¸®Åë\\{}\\{}_{}",
+        race_sprite_path, sex_sprite_path, hair_style, sex_sprite_path
+    );
+
+    // Hair color 0 is the palette that is stored in the sprite itself.
+    let head_sprite = match hair_color {
+        0 => sprite_loader.get(&format!("{head_path}.spr"), game_file_loader),
+        hair_color => {
+            let palette_file = format!("¸Ó¸®{}_{}_{}.pal", hair_style, sex_sprite_path, hair_color);
+            let palette_path = match job_id {
+                SUMMONER_JOB_ID => format!("{race_sprite_path}\\¸Ó¸®\\{palette_file}"),
+                _ => format!("¸Ó¸®\\{palette_file}"),
+            };
+
+            sprite_loader.get_with_palette(&format!("{head_path}.spr"), &palette_path, game_file_loader)
+        }
+    };
+
+    (
+        head_sprite.unwrap(),
+        action_loader.get(&format!("{head_path}.act"), game_file_loader).unwrap(),
+    )
+}
+
+/// Sprite and actions of an optional layer, like equipment or a mount. Layers
+/// that fail to load are left out instead of hiding the whole entity.
+fn get_layer_sprite_and_actions(
+    game_file_loader: &mut GameFileLoader,
+    sprite_loader: &mut SpriteLoader,
+    action_loader: &mut ActionLoader,
+    file_path: &str,
+) -> Option<(Arc<Sprite>, Arc<Actions>)> {
+    let sprite_and_actions = sprite_loader
+        .get(&format!("{file_path}.spr"), game_file_loader)
+        .and_then(|sprite| Ok((sprite, action_loader.get(&format!("{file_path}.act"), game_file_loader)?)));
+
+    match sprite_and_actions {
+        Ok(sprite_and_actions) => Some(sprite_and_actions),
+        Err(_error) => {
+            #[cfg(feature = "debug")]
+            print_debug!("skipping layer {}: {:?}", file_path, _error);
+
+            None
+        }
+    }
+}
+
+/// Shields that aren't known yet have no sprite, so they are not shown.
+#[allow(clippy::invisible_characters)]
+fn get_shield_sprite_name(view_id: u32) -> Option<&'static str> {
+    match view_id {
+        0 => None,
+        1 => Some("_°¡µå"),     // GUARD
+        2 => Some("_¹öÅ¬·¯"),   // BUCKLER
+        3 => Some("_½¯µå"),     // SHIELD
+        4 => Some("_¹Ì·¯½¯µå"), // MIRROR_SHIELD
+        _unknown => {
+            #[cfg(feature = "debug")]
+            print_debug!("skipping unknown shield {}", _unknown);
+
+            None
+        }
+    }
+}
+
+/// Head and visible equipment of a player, from back to front.
+fn get_player_parts(
+    game_file_loader: &mut GameFileLoader,
+    sprite_loader: &mut SpriteLoader,
+    action_loader: &mut ActionLoader,
+    script_loader: &ScriptLoader,
+    job_id: usize,
+    sex: Sex,
+    look: EntityLook,
+) -> Vec<EntityPart> {
+    let sex_sprite_path = get_sex_sprite_path(sex);
+    let job_sprite_path = get_sprite_path_for_player_job(job_id);
+
+    let (head_sprite, head_actions) = get_head_sprite_and_actions(
+        game_file_loader,
+        sprite_loader,
+        action_loader,
+        job_id,
+        sex,
+        look.hair_style,
+        look.hair_color,
+    );
+
+    let mut parts = vec![EntityPart {
+        sprite: head_sprite,
+        actions: head_actions,
+        attached: true,
+        weapon: false,
+    }];
+
+    let mut add_part = |file_path: String, attached: bool, weapon: bool| {
+        if let Some((sprite, actions)) = get_layer_sprite_and_actions(game_file_loader, sprite_loader, action_loader, &file_path) {
+            parts.push(EntityPart {
+                sprite,
+                actions,
+                attached,
+                weapon,
+            });
+        }
+    };
+
+    // A headgear that covers multiple positions is sent for each of them, but only
+    // shown once.
+    let headgears = [
+        (look.head_bottom != look.head_middle && look.head_bottom != look.head_top).then_some(look.head_bottom),
+        (look.head_middle != look.head_top).then_some(look.head_middle),
+        Some(look.head_top),
+    ];
+
+    for view_id in headgears.into_iter().flatten().filter(|view_id| *view_id != 0) {
+        if let Some(name) = script_loader.get_headgear_sprite_name(view_id) {
+            add_part(format!("¾Ç¼¼»ç¸®\\{sex_sprite_path}\\{sex_sprite_path}{name}"), true, false);
+        }
+    }
+
+    if look.weapon != 0
+        && let Some(name) = script_loader.get_weapon_sprite_name(look.weapon)
+    {
+        add_part(
+            format!(
+                "{}\\{job_sprite_path}\\{job_sprite_path}_{sex_sprite_path}{name}",
+                get_race_sprite_path(job_id)
+            ),
+            false,
+            true,
+        );
+    }
+
+    if let Some(name) = get_shield_sprite_name(look.shield) {
+        add_part(
+            format!("¹æÆÐ\\{job_sprite_path}\\{job_sprite_path}_{sex_sprite_path}{name}"),
+            false,
+            false,
+        );
+    }
+
+    parts
+}
+
+fn get_mount_sprite_and_actions(
+    game_file_loader: &mut GameFileLoader,
+    sprite_loader: &mut SpriteLoader,
+    action_loader: &mut ActionLoader,
+    mount: Mount,
+    job_id: usize,
+    sex: Sex,
+) -> Option<(Arc<Sprite>, Arc<Actions>)> {
+    let sex_sprite_path = get_sex_sprite_path(sex);
+    let file_path = format!(
+        "{}\\Å»°Í\\{}\\{}_{}",
+        get_race_sprite_path(job_id),
+        sex_sprite_path,
+        get_mount_sprite_name(mount, job_id),
+        sex_sprite_path
+    );
+
+    get_layer_sprite_and_actions(game_file_loader, sprite_loader, action_loader, &file_path)
+}
+
+/// Sprites of a player character that is not part of the world, like the
+/// preview during character creation.
+#[derive(Clone)]
+pub struct PlayerAppearance {
+    pub appearance: CharacterAppearance,
+    pub body_sprite: Arc<Sprite>,
+    pub body_actions: Arc<Actions>,
+    pub head_sprite: Arc<Sprite>,
+    pub head_actions: Arc<Actions>,
+}
+
+impl PlayerAppearance {
+    pub fn new(
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        script_loader: &ScriptLoader,
+        appearance: CharacterAppearance,
+    ) -> Self {
+        let job_id = appearance.start_job as usize;
+        let (body_sprite, body_actions) = get_sprite_and_actions(
+            game_file_loader,
+            sprite_loader,
+            action_loader,
+            script_loader,
+            EntityType::Player,
+            job_id,
+            appearance.sex,
+        );
+
+        let (head_sprite, head_actions) = get_head_sprite_and_actions(
+            game_file_loader,
+            sprite_loader,
+            action_loader,
+            job_id,
+            appearance.sex,
+            appearance.hair_style,
+            appearance.hair_color,
+        );
+
+        Self {
+            appearance,
+            body_sprite,
+            body_actions,
+            head_sprite,
+            head_actions,
+        }
+    }
+}
+
 impl Common {
     pub fn new(
         game_file_loader: &mut GameFileLoader,
@@ -283,8 +703,11 @@ impl Common {
         let health_points = entity_data.health_points as usize;
         let maximum_health_points = entity_data.maximum_health_points as usize;
         let sex = entity_data.sex;
+        let look = entity_data.look;
+        let sitting = entity_data.sitting;
 
         let active_movement = None;
+        let forced_movement = None;
 
         let entity_type = match job_id {
             45 => EntityType::Warp,
@@ -296,6 +719,11 @@ impl Common {
             _ => EntityType::Npc,
         };
 
+        let (job_id, mount) = match entity_type {
+            EntityType::Player => get_mounted_job(job_id),
+            _ => (job_id, None),
+        };
+
         let (sprite, actions) = get_sprite_and_actions(
             game_file_loader,
             sprite_loader,
@@ -305,31 +733,75 @@ impl Common {
             job_id,
             sex,
         );
+        let mount_part =
+            mount.and_then(|mount| get_mount_sprite_and_actions(game_file_loader, sprite_loader, action_loader, mount, job_id, sex));
+        let parts = match entity_type {
+            EntityType::Player => get_player_parts(game_file_loader, sprite_loader, action_loader, script_loader, job_id, sex, look),
+            _ => Vec::new(),
+        };
+        let followers = Vec::new();
+        let job_elevation = script_loader.get_job_elevation(job_id);
+        let scale = 1.0;
+        let facing = Facing::new(head_direction);
         let details = ResourceState::Unavailable;
+        let guild_emblem_id = entity_data.guild_emblem;
+        let guild_emblem = ResourceState::Unavailable;
         let animation_state = AnimationState::new(client_tick);
+        let emotion_state = None;
+        let overhead_text = None;
+        let status_effects = StatusEffects::default();
+        let last_damage_timestamp = None;
+        let death_timestamp = None;
+        let tint_state = TintState::default();
+        let tint = EntityTint::default();
+        let predicted_destination = None;
+        let footstep = None;
 
         let mut common = Self {
             grid_position,
             position,
+            job_elevation,
+            scale,
             entity_id,
             job_id,
             head_direction,
             sex,
             active_movement,
+            forced_movement,
             entity_type,
             movement_speed,
             health_points,
             maximum_health_points,
             sprite,
             actions,
+            mount,
+            mount_part,
+            look,
+            parts,
+            followers,
+            facing,
+            sitting,
             details,
+            guild_emblem_id,
+            guild_emblem,
             animation_state,
+            emotion_state,
+            overhead_text,
+            status_effects,
+            last_damage_timestamp,
+            death_timestamp,
+            tint_state,
+            tint,
+            predicted_destination,
+            footstep,
         };
 
         if let Some(destination) = entity_data.destination {
             let position_from = Vector2::new(entity_data.position.x, entity_data.position.y);
             let position_to = Vector2::new(destination.x, destination.y);
             common.move_from_to(map, position_from, position_to, client_tick);
+        } else if sitting {
+            common.set_sitting(true, client_tick);
         }
 
         common
@@ -351,31 +823,199 @@ impl Common {
             self.job_id,
             self.sex,
         );
+        self.mount_part = self
+            .mount
+            .and_then(|mount| get_mount_sprite_and_actions(game_file_loader, sprite_loader, action_loader, mount, self.job_id, self.sex));
+        self.reload_parts(game_file_loader, sprite_loader, action_loader, script_loader);
+    }
+
+    fn reload_parts(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        script_loader: &ScriptLoader,
+    ) {
+        if self.entity_type != EntityType::Player {
+            return;
+        }
+
+        self.parts = get_player_parts(
+            game_file_loader,
+            sprite_loader,
+            action_loader,
+            script_loader,
+            self.job_id,
+            self.sex,
+            self.look,
+        );
+    }
+
+    pub fn update_look(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        script_loader: &ScriptLoader,
+        change: LookChange,
+    ) {
+        self.look.apply(change);
+        self.reload_parts(game_file_loader, sprite_loader, action_loader, script_loader);
+    }
+
+    pub fn set_job(&mut self, job_id: usize) {
+        let (job_id, mount) = match self.entity_type {
+            EntityType::Player => get_mounted_job(job_id),
+            _ => (job_id, None),
+        };
+
+        // The server sends the option flags again after a job change, but a
+        // costume mount is independent of the job.
+        self.job_id = job_id;
+        self.mount = mount.or(self.mount.filter(|mount| *mount == Mount::Costume));
+    }
+
+    pub fn set_mount(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        mount: Mount,
+    ) {
+        if self.mount == Some(mount) {
+            return;
+        }
+
+        self.mount = Some(mount);
+        self.mount_part = get_mount_sprite_and_actions(game_file_loader, sprite_loader, action_loader, mount, self.job_id, self.sex);
+    }
+
+    pub fn remove_mount(&mut self) {
+        self.mount = None;
+        self.mount_part = None;
+    }
+
+    /// Add and remove followers, so the entity has exactly the given ones.
+    /// Followers that the entity already has keep their position.
+    pub fn set_followers(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        kinds: Vec<FollowerKind>,
+        client_tick: ClientTick,
+    ) {
+        self.followers.retain(|follower| kinds.contains(&follower.get_kind()));
+
+        for kind in kinds {
+            if !self.followers.iter().any(|follower| follower.get_kind() == kind) {
+                let follower = Follower::new(game_file_loader, sprite_loader, action_loader, kind, self.position, client_tick);
+                self.followers.push(follower);
+            }
+        }
     }
 
     pub fn set_position(&mut self, map: &Map, position: Vector2<usize>, client_tick: ClientTick) {
         self.grid_position = position;
         self.position = map.get_world_position(position);
         self.active_movement = None;
-        self.animation_state.idle(client_tick);
+        self.forced_movement = None;
+
+        match self.sitting {
+            true => self.animation_state.sit(client_tick),
+            false => self.animation_state.idle(client_tick),
+        }
+    }
+
+    /// Stop the entity and leave it on the ground on the last frame of its
+    /// dying action.
+    pub fn die(&mut self, client_tick: ClientTick) {
+        self.active_movement = None;
+        self.forced_movement = None;
+        self.predicted_destination = None;
+        self.death_timestamp = Some(client_tick.0);
+        self.animation_state.die(client_tick);
+    }
+
+    pub fn set_sitting(&mut self, sitting: bool, client_tick: ClientTick) {
+        // Only players have a sitting action.
+        if self.entity_type != EntityType::Player || self.active_movement.is_some() {
+            return;
+        }
+
+        self.sitting = sitting;
+
+        match sitting {
+            true => self.animation_state.sit(client_tick),
+            false => self.animation_state.idle(client_tick),
+        }
+    }
+
+    /// Slide to a new position without walking. The server sends the same
+    /// position more than once for a single knockback, so a slide to the same
+    /// destination doesn't start over.
+    pub fn force_move_to(&mut self, destination: Vector2<usize>, client_tick: ClientTick) {
+        if self
+            .forced_movement
+            .as_ref()
+            .is_some_and(|forced_movement| forced_movement.destination == destination)
+        {
+            return;
+        }
+
+        self.grid_position = destination;
+        self.active_movement = None;
+        self.forced_movement = Some(ForcedMovement::new(self.position, destination, client_tick.0));
+
+        match self.sitting {
+            true => self.animation_state.sit(client_tick),
+            false => self.animation_state.idle(client_tick),
+        }
     }
 
-    pub fn update(&mut self, map: &Map, _delta_time: f32, client_tick: ClientTick) {
-        if let Some(active_movement) = self.active_movement.take() {
+    /// Walks are played back `interpolation_delay` milliseconds in the past,
+    /// so walks that arrive a little late still start at the beginning of
+    /// their path instead of jumping ahead.
+    pub fn update(&mut self, map: &Map, delta_time: f32, client_tick: ClientTick, smooth_movement: bool, interpolation_delay: u32) {
+        if let Some(forced_movement) = self.forced_movement.take() {
+            let destination = map.get_world_position(forced_movement.destination);
+            let elapsed = client_tick.0.saturating_sub(forced_movement.starting_timestamp);
+
+            if elapsed >= FORCED_MOVEMENT_DURATION {
+                self.position = destination;
+            } else {
+                let progress = elapsed as f32 / FORCED_MOVEMENT_DURATION as f32;
+                self.position = forced_movement.origin.lerp(destination, progress);
+                self.forced_movement = Some(forced_movement);
+            }
+        }
+
+        let movement_tick = client_tick.0.saturating_sub(interpolation_delay);
+
+        if let Some(mut active_movement) = self.active_movement.take() {
             let last_step = active_movement.steps.last().unwrap();
 
-            if client_tick.0 > last_step.1 {
+            if movement_tick > last_step.1 {
                 let position = Vector2::new(last_step.0.x, last_step.0.y);
                 self.set_position(map, position, client_tick);
+
+                if active_movement.steps.len() > 1 {
+                    self.footstep = Some(position);
+                }
             } else {
                 let mut last_step_index = 0;
-                while active_movement.steps[last_step_index + 1].1 < client_tick.0 {
+                while active_movement.steps[last_step_index + 1].1 < movement_tick {
                     last_step_index += 1;
                 }
 
                 let last_step = active_movement.steps[last_step_index];
                 let next_step = active_movement.steps[last_step_index + 1];
 
+                if last_step_index > active_movement.reached_step_index {
+                    active_movement.reached_step_index = last_step_index;
+                    self.footstep = Some(last_step.0);
+                }
+
                 let array = (last_step.0 - next_step.0).map(|c| c as isize);
                 let array: &[isize; 2] = array.as_ref();
                 self.head_direction = match array {
@@ -393,152 +1033,177 @@ impl Common {
                 let last_step_position = map.get_world_position(last_step.0);
                 let next_step_position = map.get_world_position(next_step.0);
 
-                let clamped_tick = u32::max(last_step.1, client_tick.0);
+                let clamped_tick = u32::max(last_step.1, movement_tick);
                 let total = next_step.1 - last_step.1;
                 let offset = clamped_tick - last_step.1;
 
                 let movement_elapsed = (1.0 / total as f32) * offset as f32;
-                let position = last_step_position.lerp(next_step_position, movement_elapsed);
+                let position = match smooth_movement {
+                    true => {
+                        let final_step_index = active_movement.steps.len() - 1;
+                        let previous_step = active_movement.steps[last_step_index.saturating_sub(1)];
+                        let step_after = active_movement.steps[usize::min(last_step_index + 2, final_step_index)];
+
+                        let steps = [
+                            map.get_world_position(previous_step.0),
+                            last_step_position,
+                            next_step_position,
+                            map.get_world_position(step_after.0),
+                        ];
+
+                        smoothed_step_position(steps, movement_elapsed)
+                    }
+                    false => last_step_position.lerp(next_step_position, movement_elapsed),
+                };
 
                 self.position = position;
                 self.active_movement = active_movement.into();
             }
         }
 
+        self.facing.update(self.head_direction, delta_time);
         self.animation_state.update(client_tick);
-    }
-
-    pub fn move_from_to(&mut self, map: &Map, from: Vector2<usize>, to: Vector2<usize>, starting_timestamp: ClientTick) {
-        use pathfinding::prelude::astar;
-
-        #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-        struct Pos(usize, usize);
+        self.tint = self.tint_state.tint(client_tick);
 
-        impl Pos {
-            fn successors(&self, map: &Map) -> Vec<Pos> {
-                let &Pos(x, y) = self;
-                let mut successors = Vec::new();
-
-                if map.x_in_bounds(x + 1) {
-                    successors.push(Pos(x + 1, y));
-                }
+        if let Some(emotion_state) = &mut self.emotion_state
+            && !emotion_state.update(client_tick)
+        {
+            self.emotion_state = None;
+        }
 
-                if x > 0 {
-                    successors.push(Pos(x - 1, y));
-                }
+        if self
+            .overhead_text
+            .as_ref()
+            .is_some_and(|overhead_text| overhead_text.is_expired(client_tick))
+        {
+            self.overhead_text = None;
+        }
 
-                if map.y_in_bounds(y + 1) {
-                    successors.push(Pos(x, y + 1));
-                }
+        self.status_effects.update(client_tick);
 
-                if y > 0 {
-                    successors.push(Pos(x, y - 1));
-                }
+        for follower in &mut self.followers {
+            follower.update(self.position, delta_time, client_tick);
+        }
+    }
 
-                if map.x_in_bounds(x + 1)
-                    && map.y_in_bounds(y + 1)
-                    && map.get_tile(Vector2::new(x + 1, y)).flags.contains(TileFlags::WALKABLE)
-                    && map.get_tile(Vector2::new(x, y + 1)).flags.contains(TileFlags::WALKABLE)
-                {
-                    successors.push(Pos(x + 1, y + 1));
+    /// Path from one tile to within `range` tiles of another, with the client
+    /// tick at which every step is reached.
+    fn find_steps(
+        &self,
+        map: &Map,
+        from: Vector2<usize>,
+        to: Vector2<usize>,
+        range: usize,
+        starting_timestamp: u32,
+    ) -> Option<Vec<(Vector2<usize>, u32)>> {
+        const DIAGONAL_MULTIPLIER: f32 = 1.4;
+
+        let path = find_path_within_range(map, from, to, range)?;
+        let mut last_step = (from, starting_timestamp);
+
+        let steps = path
+            .into_iter()
+            .enumerate()
+            .map(|(index, position)| {
+                if index == 0 {
+                    return last_step;
                 }
 
-                if x > 0
-                    && map.y_in_bounds(y + 1)
-                    && map.get_tile(Vector2::new(x - 1, y)).flags.contains(TileFlags::WALKABLE)
-                    && map.get_tile(Vector2::new(x, y + 1)).flags.contains(TileFlags::WALKABLE)
-                {
-                    successors.push(Pos(x - 1, y + 1));
-                }
+                let (last_position, last_timestamp) = last_step;
+                let speed = match last_position.x == position.x || last_position.y == position.y {
+                    // true means we are moving orthogonally
+                    true => self.movement_speed as u32,
+                    // false means we are moving diagonally
+                    false => (self.movement_speed as f32 * DIAGONAL_MULTIPLIER) as u32,
+                };
 
-                if map.x_in_bounds(x + 1)
-                    && y > 0
-                    && map.get_tile(Vector2::new(x + 1, y)).flags.contains(TileFlags::WALKABLE)
-                    && map.get_tile(Vector2::new(x, y - 1)).flags.contains(TileFlags::WALKABLE)
-                {
-                    successors.push(Pos(x + 1, y - 1));
-                }
+                last_step = (position, last_timestamp + speed);
+                last_step
+            })
+            .collect();
 
-                if x > 0
-                    && y > 0
-                    && map.get_tile(Vector2::new(x - 1, y)).flags.contains(TileFlags::WALKABLE)
-                    && map.get_tile(Vector2::new(x, y - 1)).flags.contains(TileFlags::WALKABLE)
-                {
-                    successors.push(Pos(x - 1, y - 1));
-                }
+        Some(steps)
+    }
 
-                let successors = successors
-                    .drain(..)
-                    .filter(|Pos(x, y)| map.get_tile(Vector2::new(*x, *y)).flags.contains(TileFlags::WALKABLE))
-                    .collect::<Vec<Pos>>();
+    fn start_movement(&mut self, steps: Vec<(Vector2<usize>, u32)>, starting_timestamp: ClientTick) {
+        // If there is only a single step the player is already on the correct tile.
+        if steps.len() > 1 {
+            self.active_movement = Movement::new(steps, starting_timestamp.0).into();
+            self.forced_movement = None;
+            self.sitting = false;
 
-                successors
+            if self.animation_state.action != 1 {
+                self.animation_state.walk(self.movement_speed, starting_timestamp);
             }
+        }
+    }
 
-            fn convert_to_vector(self) -> Vector2<usize> {
-                Vector2::new(self.0, self.1)
-            }
+    pub fn move_from_to(&mut self, map: &Map, from: Vector2<usize>, to: Vector2<usize>, starting_timestamp: ClientTick) {
+        if let Some(steps) = self.find_steps(map, from, to, 0, starting_timestamp.0) {
+            self.start_movement(steps, starting_timestamp);
         }
+    }
 
-        let result = astar(
-            &Pos(from.x, from.y),
-            |position| position.successors(map).into_iter().map(|position| (position, 0)),
-            |position| -> usize {
-                // Values taken from rAthena.
-                const MOVE_COST: usize = 10;
-                const DIAGONAL_MOVE_COST: usize = 14;
+    /// Start walking before the server confirmed the walk. The current step is
+    /// finished first, so the entity doesn't jump back to the tile it last
+    /// reached. The walk stops within `range` tiles of the destination.
+    pub fn predict_move(&mut self, map: &Map, destination: Vector2<usize>, range: usize, client_tick: ClientTick) {
+        let current_step = self.active_movement.as_ref().and_then(|movement| {
+            let next_step_index = movement.steps.iter().position(|step| step.1 > client_tick.0)?;
+            (next_step_index > 0).then(|| (movement.steps[next_step_index - 1], movement.steps[next_step_index]))
+        });
+
+        let (from, starting_timestamp) = match current_step {
+            Some((_, next_step)) => next_step,
+            None => (self.grid_position, client_tick.0),
+        };
 
-                let distance_x = usize::abs_diff(position.0, to.x);
-                let distance_y = usize::abs_diff(position.1, to.y);
+        let Some(mut steps) = self.find_steps(map, from, destination, range, starting_timestamp) else {
+            return;
+        };
 
-                let straight_moves = usize::abs_diff(distance_x, distance_y);
-                let diagonal_moves = usize::min(distance_x, distance_y);
+        if let Some((last_step, _)) = current_step {
+            steps.insert(0, last_step);
+        }
 
-                DIAGONAL_MOVE_COST * diagonal_moves + MOVE_COST * straight_moves
-            },
-            |position| *position == Pos(to.x, to.y),
-        )
-        .map(|x| x.0);
-
-        if let Some(path) = result {
-            let mut last_timestamp = starting_timestamp.0;
-            let mut last_position: Option<Vector2<usize>> = None;
-
-            let steps: Vec<(Vector2<usize>, u32)> = path
-                .into_iter()
-                .map(|pos| {
-                    if let Some(position) = last_position {
-                        const DIAGONAL_MULTIPLIER: f32 = 1.4;
-
-                        let speed = match position.x == pos.0 || position.y == pos.1 {
-                            // true means we are moving orthogonally
-                            true => self.movement_speed as u32,
-                            // false means we are moving diagonally
-                            false => (self.movement_speed as f32 * DIAGONAL_MULTIPLIER) as u32,
-                        };
-
-                        let arrival_position = pos.convert_to_vector();
-                        let arrival_timestamp = last_timestamp + speed;
-
-                        last_timestamp = arrival_timestamp;
-                        last_position = Some(arrival_position);
-
-                        (arrival_position, arrival_timestamp)
-                    } else {
-                        last_position = Some(from);
-                        (from, last_timestamp)
-                    }
-                })
-                .collect();
+        let starting_timestamp = ClientTick(steps[0].1);
+        self.predicted_destination = steps.last().map(|step| step.0);
+        self.start_movement(steps, starting_timestamp);
+    }
 
-            // If there is only a single step the player is already on the correct tile.
-            if steps.len() > 1 {
-                self.active_movement = Movement::new(steps, starting_timestamp.0).into();
+    /// Reconcile a predicted walk with the walk that the server confirmed.
+    /// The prediction is kept as long as it is close enough to the confirmed
+    /// walk, otherwise the entity snaps to the confirmed walk.
+    pub fn confirm_move(
+        &mut self,
+        map: &Map,
+        from: Vector2<usize>,
+        to: Vector2<usize>,
+        starting_timestamp: ClientTick,
+        client_tick: ClientTick,
+    ) {
+        let Some(predicted_destination) = self.predicted_destination.take() else {
+            self.move_from_to(map, from, to, starting_timestamp);
+            return;
+        };
 
-                if self.animation_state.action != 1 {
-                    self.animation_state.walk(self.movement_speed, starting_timestamp);
-                }
-            }
+        let Some(confirmed_steps) = self.find_steps(map, from, to, 0, starting_timestamp.0) else {
+            return;
+        };
+
+        let predicted_tile = match &self.active_movement {
+            Some(movement) => tile_at(&movement.steps, client_tick.0).unwrap_or(self.grid_position),
+            None => self.grid_position,
+        };
+        let confirmed_tile = tile_at(&confirmed_steps, client_tick.0).unwrap_or(from);
+
+        if !has_diverged(predicted_destination, predicted_tile, to, confirmed_tile) {
+            return;
+        }
+
+        match confirmed_steps.len() > 1 {
+            true => self.start_movement(confirmed_steps, starting_timestamp),
+            false => self.set_position(map, to, client_tick),
         }
     }
 
@@ -722,27 +1387,156 @@ impl Common {
         active_movement.steps_vertex_buffer = Some(vertex_buffer);
     }*/
 
+    /// Position that the sprites are rendered at, which is above the ground
+    /// for hovering entities and mounts.
+    fn get_elevated_position(&self) -> Vector3<f32> {
+        let mount_elevation = self.mount.map(|mount| get_mount_elevation(mount, self.job_id)).unwrap_or_default();
+
+        self.position + Vector3::new(0.0, self.job_elevation + mount_elevation, 0.0)
+    }
+
+    /// Offset that moves the rider so its attach point is on the attach point
+    /// of the mount, which is where the saddle is.
+    fn get_rider_offset(&self, direction: usize) -> Vector2<f32> {
+        let Some((_, mount_actions)) = &self.mount_part else {
+            return Vector2::from_value(0.0);
+        };
+
+        let mount_attach_point = mount_actions.get_attach_point(&self.animation_state, direction);
+        let rider_attach_point = self.actions.get_attach_point(&self.animation_state, direction);
+
+        match (mount_attach_point, rider_attach_point) {
+            (Some(mount_attach_point), Some(rider_attach_point)) => {
+                let difference = mount_attach_point - rider_attach_point;
+                Vector2::new(-difference.x, difference.y) / 10.0
+            }
+            _ => Vector2::from_value(0.0),
+        }
+    }
+
+    /// World position of an attachment point in the current animation frame.
+    pub fn get_attachment_position(&self, camera: &dyn Camera, attachment_point: AttachmentPoint) -> Vector3<f32> {
+        let camera_direction = camera.get_camera_direction();
+        let head_direction = self.facing.get_direction();
+
+        let (sprite, actions) = match attachment_point {
+            AttachmentPoint::Weapon => self
+                .parts
+                .iter()
+                .find(|part| part.weapon)
+                .map(|part| (&part.sprite, &part.actions))
+                .unwrap_or((&self.sprite, &self.actions)),
+            _ => (&self.sprite, &self.actions),
+        };
+
+        let mut offset = actions.get_attachment_offset(
+            sprite,
+            &self.animation_state,
+            camera_direction,
+            head_direction,
+            SPRITE_SCALE,
+            attachment_point,
+        );
+
+        if attachment_point != AttachmentPoint::Feet {
+            offset += self.get_rider_offset(camera_direction + head_direction);
+        }
+
+        let offset = offset * self.scale;
+        let world_matrix = camera.billboard_matrix(
+            self.get_elevated_position(),
+            Vector3::new(offset.x, offset.y, 0.0),
+            Vector2::from_value(1.0),
+        );
+
+        (world_matrix * Vector4::new(0.0, 0.0, 0.0, 1.0)).truncate()
+    }
+
     pub fn render<T>(&self, render_target: &mut T::Target, renderer: &T, camera: &dyn Camera)
     where
         T: Renderer + EntityRenderer,
     {
         let camera_direction = camera.get_camera_direction();
+        let head_direction = self.facing.get_direction();
+        let elevated_position = self.get_elevated_position();
+        let direction = camera_direction + head_direction;
+        let rider_offset = self.get_rider_offset(direction);
+
+        if let Some((mount_sprite, mount_actions)) = &self.mount_part {
+            let (texture, position, mirror) = mount_actions.render(mount_sprite, &self.animation_state, camera_direction, head_direction);
+            let position = position * self.scale;
+
+            renderer.render_entity(
+                render_target,
+                camera,
+                texture,
+                elevated_position,
+                Vector3::new(position.x, position.y, MOUNT_DEPTH_OFFSET),
+                Vector2::from_value(SPRITE_SCALE * self.scale),
+                Vector2::new(1, 1),
+                Vector2::new(0, 0),
+                mirror,
+                self.tint,
+                self.entity_id,
+            );
+        }
+
         let (texture, position, mirror) = self
             .actions
-            .render(&self.sprite, &self.animation_state, camera_direction, self.head_direction);
+            .render(&self.sprite, &self.animation_state, camera_direction, head_direction);
+        let position = (position + rider_offset) * self.scale;
 
         renderer.render_entity(
             render_target,
             camera,
             texture,
-            self.position,
+            elevated_position,
             Vector3::new(position.x, position.y, 0.0),
-            Vector2::from_value(0.7),
+            Vector2::from_value(SPRITE_SCALE * self.scale),
             Vector2::new(1, 1),
             Vector2::new(0, 0),
             mirror,
+            self.tint,
             self.entity_id,
         );
+
+        let body_attach_point = self.actions.get_attach_point(&self.animation_state, direction);
+
+        for (index, part) in self.parts.iter().enumerate() {
+            let (texture, position, mirror) = part
+                .actions
+                .render(&part.sprite, &self.animation_state, camera_direction, head_direction);
+            let mut position = position + rider_offset;
+
+            if part.attached
+                && let Some(body_attach_point) = body_attach_point
+                && let Some(part_attach_point) = part.actions.get_attach_point(&self.animation_state, direction)
+            {
+                let difference = body_attach_point - part_attach_point;
+                position += Vector2::new(-difference.x, difference.y) / 10.0;
+            }
+
+            let position = position * self.scale;
+
+            renderer.render_entity(
+                render_target,
+                camera,
+                texture,
+                elevated_position,
+                Vector3::new(position.x, position.y, -PART_DEPTH_OFFSET * (index + 1) as f32),
+                Vector2::from_value(SPRITE_SCALE * self.scale),
+                Vector2::new(1, 1),
+                Vector2::new(0, 0),
+                mirror,
+                self.tint,
+                self.entity_id,
+            );
+        }
+
+        for (index, follower) in self.followers.iter().enumerate() {
+            let depth_offset = -PART_DEPTH_OFFSET * (self.parts.len() + index + 1) as f32;
+            follower.render(render_target, renderer, camera, head_direction, depth_offset, self.entity_id);
+        }
     }
 
     #[cfg(feature = "debug")]
@@ -758,6 +1552,254 @@ impl Common {
     {
         renderer.render_marker(render_target, camera, marker_identifier, self.position, hovered);
     }
+
+    pub fn render_emotion(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        emotions: &Emotions,
+        window_size: ScreenSize,
+        application: &InterfaceSettings,
+    ) {
+        let Some(emotion_state) = &self.emotion_state else {
+            return;
+        };
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.get_elevated_position().extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height - EMOTION_OFFSET * application.get_scaling_factor() * self.scale,
+        };
+
+        emotions.render(
+            render_target,
+            renderer,
+            &emotion_state.animation_state,
+            final_position,
+            application,
+        );
+    }
+
+    pub fn render_status_effects(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        let icons: Vec<_> = self.status_effects.icons().collect();
+
+        if icons.is_empty() {
+            return;
+        }
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.get_elevated_position().extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+
+        let icon_size = theme.status_effects.icon_size.get();
+        let spacing = theme.status_effects.spacing.get();
+        let row_width = icons.len() as f32 * (icon_size + spacing) - spacing;
+        let mut position = ScreenPosition {
+            left: screen_position.x * window_size.width - row_width / 2.0,
+            top: screen_position.y * window_size.height - theme.status_effects.offset.get() * self.scale - icon_size,
+        };
+
+        for icon in icons {
+            renderer.render_texture(
+                render_target,
+                icon.clone(),
+                position,
+                ScreenSize::uniform(icon_size),
+                Color::monochrome_u8(255),
+            );
+
+            position.left += icon_size + spacing;
+        }
+    }
+
+    pub fn render_overhead_text(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+        client_tick: ClientTick,
+    ) {
+        let Some(overhead_text) = &self.overhead_text else {
+            return;
+        };
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.get_elevated_position().extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+
+        let lines = overhead_text.lines();
+        let font_size = theme.chat_bubble.font_size.get();
+        let padding = theme.chat_bubble.padding.get();
+        let text_width = lines
+            .iter()
+            .map(|line| renderer.get_text_dimensions(line, font_size).width)
+            .fold(0.0, f32::max);
+        let size = ScreenSize {
+            width: text_width + padding * 2.0,
+            height: lines.len() as f32 * font_size.get_value() + padding * 2.0,
+        };
+        let position = ScreenPosition {
+            left: screen_position.x * window_size.width - size.width / 2.0,
+            top: screen_position.y * window_size.height - theme.chat_bubble.offset.get() * self.scale - size.height,
+        };
+
+        let opacity = overhead_text.opacity(client_tick);
+        let fade = |color: Color| Color {
+            alpha: color.alpha * opacity,
+            ..color
+        };
+
+        renderer.render_rectangle(render_target, position, size, fade(theme.chat_bubble.background_color.get()));
+
+        for (index, line) in lines.iter().enumerate() {
+            let line_position = ScreenPosition {
+                left: position.left + padding,
+                top: position.top + padding + index as f32 * font_size.get_value(),
+            };
+
+            renderer.render_text(
+                render_target,
+                line,
+                line_position,
+                fade(theme.chat_bubble.foreground_color.get()),
+                font_size,
+            );
+        }
+    }
+
+    /// Name of the entity above its head. Players also show the name of their
+    /// party next to their name and the name of their guild below it.
+    pub fn render_name_label(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        name: &str,
+        show_affiliation: bool,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        let (party_name, guild_name) = match self.details.as_option() {
+            Some(details) if show_affiliation => (details.party_name.as_deref(), details.guild_name.as_deref()),
+            _ => (None, None),
+        };
+
+        let name_line = match party_name {
+            Some(party_name) => format!("{name} ({party_name})"),
+            None => name.to_owned(),
+        };
+        let lines: Vec<(&str, Color)> = std::iter::once((name_line.as_str(), theme.name_label.name_color.get()))
+            .chain(guild_name.map(|guild_name| (guild_name, theme.name_label.affiliation_color.get())))
+            .collect();
+
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.get_elevated_position().extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+        let font_size = theme.name_label.font_size.get();
+        let top = screen_position.y * window_size.height
+            - theme.name_label.offset.get() * self.scale
+            - lines.len() as f32 * font_size.get_value();
+
+        for (index, (text, color)) in lines.iter().enumerate() {
+            let position = ScreenPosition {
+                left: screen_position.x * window_size.width - renderer.get_text_dimensions(text, font_size).width / 2.0,
+                top: top + index as f32 * font_size.get_value(),
+            };
+
+            // The emblem is shown next to the name of the guild.
+            if index + 1 == lines.len()
+                && let Some(guild_emblem) = self.guild_emblem.as_option()
+            {
+                renderer.render_texture(
+                    render_target,
+                    guild_emblem.clone(),
+                    position - ScreenSize::only_width(font_size.get_value() + 2.0),
+                    ScreenSize::uniform(font_size.get_value()),
+                    Color::monochrome_u8(255),
+                );
+            }
+
+            renderer.render_text(
+                render_target,
+                text,
+                position + ScreenPosition::uniform(1.0),
+                Color::monochrome_u8(0),
+                font_size,
+            );
+            renderer.render_text(render_target, text, position, *color, font_size);
+        }
+    }
+
+    fn render_name_plate(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        name: &str,
+        color: Color,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.position.extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+        let font_size = theme.battlefield.font_size.get();
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width - renderer.get_text_dimensions(name, font_size).width / 2.0,
+            top: screen_position.y * window_size.height + theme.battlefield.name_offset.get(),
+        };
+
+        if let Some(guild_emblem) = self.guild_emblem.as_option() {
+            renderer.render_texture(
+                render_target,
+                guild_emblem.clone(),
+                final_position - ScreenSize::only_width(font_size.get_value() + 2.0),
+                ScreenSize::uniform(font_size.get_value()),
+                Color::monochrome_u8(255),
+            );
+        }
+
+        renderer.render_text(
+            render_target,
+            name,
+            final_position + ScreenPosition::uniform(1.0),
+            Color::monochrome_u8(0),
+            font_size,
+        );
+        renderer.render_text(render_target, name, final_position, color, font_size);
+    }
 }
 
 #[derive(PrototypeWindow)]
@@ -841,7 +1883,7 @@ impl Player {
         let screen_position = screen_position / 2.0;
         let final_position = ScreenPosition {
             left: screen_position.x * window_size.width,
-            top: screen_position.y * window_size.height + 5.0,
+            top: screen_position.y * window_size.height + 5.0 * self.common.scale,
         };
 
         let bar_width = theme.status_bar.player_bar_width.get();
@@ -966,7 +2008,7 @@ impl Npc {
         let screen_position = screen_position / 2.0;
         let final_position = ScreenPosition {
             left: screen_position.left * window_size.width,
-            top: screen_position.top * window_size.height + 5.0,
+            top: screen_position.top * window_size.height + 5.0 * self.common.scale,
         };
 
         let bar_width = theme.status_bar.enemy_bar_width.get();
@@ -1025,6 +2067,20 @@ impl Entity {
         self.get_common().entity_type
     }
 
+    pub fn get_head_direction(&self) -> usize {
+        self.get_common().head_direction
+    }
+
+    pub fn get_job(&self) -> usize {
+        self.get_common().job_id
+    }
+
+    /// Current and maximum health points.
+    pub fn get_health(&self) -> (usize, usize) {
+        let common = self.get_common();
+        (common.health_points, common.maximum_health_points)
+    }
+
     pub fn are_details_unavailable(&self) -> bool {
         match &self.get_common().details {
             ResourceState::Unavailable => true,
@@ -1033,7 +2089,62 @@ impl Entity {
     }
 
     pub fn set_job(&mut self, job_id: usize) {
-        self.get_common_mut().job_id = job_id;
+        self.get_common_mut().set_job(job_id);
+    }
+
+    pub fn update_look(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        script_loader: &ScriptLoader,
+        change: LookChange,
+    ) {
+        self.get_common_mut()
+            .update_look(game_file_loader, sprite_loader, action_loader, script_loader, change);
+    }
+
+    pub fn is_sitting(&self) -> bool {
+        self.get_common().sitting
+    }
+
+    pub fn set_sitting(&mut self, sitting: bool, client_tick: ClientTick) {
+        self.get_common_mut().set_sitting(sitting, client_tick);
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.get_common_mut().scale = scale;
+    }
+
+    pub fn get_mount(&self) -> Option<Mount> {
+        self.get_common().mount
+    }
+
+    pub fn set_mount(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        mount: Mount,
+    ) {
+        self.get_common_mut()
+            .set_mount(game_file_loader, sprite_loader, action_loader, mount);
+    }
+
+    pub fn remove_mount(&mut self) {
+        self.get_common_mut().remove_mount();
+    }
+
+    pub fn set_followers(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        kinds: Vec<FollowerKind>,
+        client_tick: ClientTick,
+    ) {
+        self.get_common_mut()
+            .set_followers(game_file_loader, sprite_loader, action_loader, kinds, client_tick);
     }
 
     pub fn reload_sprite(
@@ -1051,12 +2162,39 @@ impl Entity {
         self.get_common_mut().details = ResourceState::Requested;
     }
 
-    pub fn set_details(&mut self, details: String) {
+    pub fn set_details(&mut self, details: EntityDetails) {
         self.get_common_mut().details = ResourceState::Available(details);
     }
 
+    /// Emblem of the guild that the entity is in, if it wasn't requested yet.
+    pub fn get_unavailable_guild_emblem(&self) -> Option<GuildEmblemId> {
+        let common = self.get_common();
+
+        match common.guild_emblem {
+            ResourceState::Unavailable => common.guild_emblem_id,
+            _ => None,
+        }
+    }
+
+    pub fn get_guild_emblem_id(&self) -> Option<GuildEmblemId> {
+        self.get_common().guild_emblem_id
+    }
+
+    pub fn set_guild_emblem_requested(&mut self) {
+        self.get_common_mut().guild_emblem = ResourceState::Requested;
+    }
+
+    pub fn set_guild_emblem(&mut self, texture: Arc<ImageView>) {
+        self.get_common_mut().guild_emblem = ResourceState::Available(texture);
+    }
+
+    pub fn get_guild_emblem(&self) -> Option<&Arc<ImageView>> {
+        self.get_common().guild_emblem.as_option()
+    }
+
+    /// Name of the entity, if it is known already.
     pub fn get_details(&self) -> Option<&String> {
-        self.get_common().details.as_option()
+        self.get_common().details.as_option().map(|details| &details.name)
     }
 
     pub fn get_grid_position(&self) -> Vector2<usize> {
@@ -1067,24 +2205,135 @@ impl Entity {
         self.get_common().position
     }
 
+    pub fn get_attachment_position(&self, camera: &dyn Camera, attachment_point: AttachmentPoint) -> Vector3<f32> {
+        self.get_common().get_attachment_position(camera, attachment_point)
+    }
+
     pub fn set_position(&mut self, map: &Map, position: Vector2<usize>, client_tick: ClientTick) {
         self.get_common_mut().set_position(map, position, client_tick);
     }
 
+    pub fn add_status_effect(&mut self, id: u16, duration: Option<Duration>, icon: Option<Arc<ImageView>>, client_tick: ClientTick) {
+        self.get_common_mut().status_effects.insert(id, duration, icon, client_tick);
+    }
+
+    pub fn remove_status_effect(&mut self, id: u16) {
+        self.get_common_mut().status_effects.remove(id);
+    }
+
+    pub fn render_status_effects(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        self.get_common()
+            .render_status_effects(render_target, renderer, camera, theme, window_size);
+    }
+
+    pub fn show_overhead_text(&mut self, text: &str, client_tick: ClientTick) {
+        self.get_common_mut().overhead_text = Some(OverheadText::new(text, client_tick));
+    }
+
+    pub fn get_overhead_text(&self) -> Option<&OverheadText> {
+        self.get_common().overhead_text.as_ref()
+    }
+
+    pub fn render_overhead_text(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+        client_tick: ClientTick,
+    ) {
+        self.get_common()
+            .render_overhead_text(render_target, renderer, camera, theme, window_size, client_tick);
+    }
+
+    pub fn show_emotion(&mut self, emotions: &Emotions, emotion: u8, client_tick: ClientTick) {
+        self.get_common_mut().emotion_state = Some(emotions.emotion_state(emotion, client_tick));
+    }
+
     pub fn update_health(&mut self, health_points: usize, maximum_health_points: usize) {
         let common = self.get_common_mut();
         common.health_points = health_points;
         common.maximum_health_points = maximum_health_points;
     }
 
-    pub fn update(&mut self, map: &Map, delta_time: f32, client_tick: ClientTick) {
-        self.get_common_mut().update(map, delta_time, client_tick);
+    pub fn register_damage(&mut self, client_tick: ClientTick) {
+        let common = self.get_common_mut();
+        common.last_damage_timestamp = Some(client_tick.0);
+        common.tint_state.hit(client_tick);
+    }
+
+    pub fn set_condition(&mut self, condition: EntityCondition) {
+        let tint_state = &mut self.get_common_mut().tint_state;
+        tint_state.petrified = condition.petrified;
+        tint_state.hidden = condition.hidden;
+    }
+
+    pub fn die(&mut self, client_tick: ClientTick) {
+        self.get_common_mut().die(client_tick);
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.get_common().death_timestamp.is_some()
+    }
+
+    /// Whether the entity died and was shown as a corpse for long enough.
+    pub fn should_be_removed(&self, corpse_duration: u32, client_tick: ClientTick) -> bool {
+        should_be_removed(self.get_common().death_timestamp, corpse_duration, client_tick)
+    }
+
+    /// Whether the health bar of a monster is shown. Other entities only show
+    /// their status while they are hovered.
+    pub fn is_status_visible(&self, health_bar_visibility: HealthBarVisibility, hovered: bool, client_tick: ClientTick) -> bool {
+        let common = self.get_common();
+
+        match common.entity_type {
+            _ if common.death_timestamp.is_some() => false,
+            EntityType::Monster => health_bar_visibility.is_visible(common.last_damage_timestamp, hovered, client_tick),
+            _ => hovered,
+        }
+    }
+
+    pub fn update(&mut self, map: &Map, delta_time: f32, client_tick: ClientTick, smooth_movement: bool, interpolation_delay: u32) {
+        self.get_common_mut()
+            .update(map, delta_time, client_tick, smooth_movement, interpolation_delay);
+    }
+
+    /// Tile that the entity stepped on since the last call, if any.
+    pub fn take_footstep(&mut self) -> Option<Vector2<usize>> {
+        self.get_common_mut().footstep.take()
     }
 
     pub fn move_from_to(&mut self, map: &Map, from: Vector2<usize>, to: Vector2<usize>, starting_timestamp: ClientTick) {
         self.get_common_mut().move_from_to(map, from, to, starting_timestamp);
     }
 
+    pub fn predict_move(&mut self, map: &Map, destination: Vector2<usize>, range: usize, client_tick: ClientTick) {
+        self.get_common_mut().predict_move(map, destination, range, client_tick);
+    }
+
+    pub fn confirm_move(
+        &mut self,
+        map: &Map,
+        from: Vector2<usize>,
+        to: Vector2<usize>,
+        starting_timestamp: ClientTick,
+        client_tick: ClientTick,
+    ) {
+        self.get_common_mut().confirm_move(map, from, to, starting_timestamp, client_tick);
+    }
+
+    pub fn force_move_to(&mut self, destination: Vector2<usize>, client_tick: ClientTick) {
+        self.get_common_mut().force_move_to(destination, client_tick);
+    }
+
     /*#[cfg(feature = "debug")]
     pub fn generate_steps_vertex_buffer(&mut self, device: Arc<Device>, map: &Map) {
         self.get_common_mut().generate_steps_vertex_buffer(device, map);
@@ -1112,6 +2361,47 @@ impl Entity {
             .render_marker(render_target, renderer, camera, marker_identifier, hovered);
     }
 
+    pub fn render_emotion(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        emotions: &Emotions,
+        window_size: ScreenSize,
+        application: &InterfaceSettings,
+    ) {
+        self.get_common()
+            .render_emotion(render_target, renderer, camera, emotions, window_size, application);
+    }
+
+    pub fn render_name_plate(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        name: &str,
+        color: Color,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        self.get_common()
+            .render_name_plate(render_target, renderer, camera, name, color, theme, window_size);
+    }
+
+    pub fn render_name_label(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        name: &str,
+        show_affiliation: bool,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        self.get_common()
+            .render_name_label(render_target, renderer, camera, name, show_affiliation, theme, window_size);
+    }
+
     pub fn render_status(
         &self,
         render_target: &mut <DeferredRenderer as Renderer>::Target,
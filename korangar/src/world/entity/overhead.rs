@@ -0,0 +1,112 @@
+use ragnarok_packets::ClientTick;
+
+/// Time in milliseconds that a chat bubble is shown, including the fade out.
+const OVERHEAD_TEXT_DURATION: u32 = 5000;
+/// Time in milliseconds at the end of the duration in which the chat bubble
+/// fades out.
+const FADE_OUT_DURATION: u32 = 1000;
+/// Maximum number of characters in a line of a chat bubble.
+const LINE_LENGTH: usize = 32;
+/// Maximum number of chat bubbles that are shown at the same time. If more
+/// entities are speaking, only the newest bubbles are shown.
+pub const MAXIMUM_OVERHEAD_TEXTS: usize = 12;
+
+/// Split a text into lines at spaces. Words that are longer than a line are
+/// split as well.
+fn wrap_text(text: &str, line_length: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+
+        while word.len() > line_length {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            lines.push(word.drain(..line_length).collect());
+        }
+
+        let line_count = line.chars().count();
+
+        if line_count > 0 && line_count + 1 + word.len() > line_length {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        if !line.is_empty() {
+            line.push(' ');
+        }
+
+        line.extend(word);
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Speech bubble that is shown above an entity when it says something.
+pub struct OverheadText {
+    lines: Vec<String>,
+    start_time: u32,
+}
+
+impl OverheadText {
+    pub fn new(text: &str, client_tick: ClientTick) -> Self {
+        Self {
+            lines: wrap_text(text, LINE_LENGTH),
+            start_time: client_tick.0,
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn start_time(&self) -> u32 {
+        self.start_time
+    }
+
+    pub fn is_expired(&self, client_tick: ClientTick) -> bool {
+        client_tick.0.saturating_sub(self.start_time) >= OVERHEAD_TEXT_DURATION
+    }
+
+    /// Opacity of the bubble, between 1 while it is shown and 0 once the fade
+    /// out finished.
+    pub fn opacity(&self, client_tick: ClientTick) -> f32 {
+        let remaining = OVERHEAD_TEXT_DURATION.saturating_sub(client_tick.0.saturating_sub(self.start_time));
+        (remaining as f32 / FADE_OUT_DURATION as f32).min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod overhead_text {
+    use ragnarok_packets::ClientTick;
+
+    use super::{wrap_text, OverheadText, OVERHEAD_TEXT_DURATION};
+
+    #[test]
+    fn wrap_words() {
+        assert_eq!(wrap_text("Poring : hello there", 10), vec!["Poring :", "hello", "there"]);
+        assert_eq!(wrap_text("  spaced   out  ", 20), vec!["spaced out"]);
+        assert!(wrap_text("", 10).is_empty());
+    }
+
+    #[test]
+    fn wrap_long_words() {
+        assert_eq!(wrap_text("a abcdefghijkl", 5), vec!["a", "abcde", "fghij", "kl"]);
+    }
+
+    #[test]
+    fn fade_out() {
+        let text = OverheadText::new("hello", ClientTick(1000));
+
+        assert_eq!(text.opacity(ClientTick(1000)), 1.0);
+        assert_eq!(text.opacity(ClientTick(1000 + OVERHEAD_TEXT_DURATION - 500)), 0.5);
+        assert!(!text.is_expired(ClientTick(1000 + OVERHEAD_TEXT_DURATION - 1)));
+        assert!(text.is_expired(ClientTick(1000 + OVERHEAD_TEXT_DURATION)));
+    }
+}
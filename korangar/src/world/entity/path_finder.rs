@@ -0,0 +1,157 @@
+use cgmath::Vector2;
+use pathfinding::prelude::astar;
+use ragnarok_formats::map::TileFlags;
+
+use super::path_graph::CLUSTER_SIZE;
+use crate::world::Map;
+
+// Values taken from rAthena.
+const MOVE_COST: usize = 10;
+const DIAGONAL_MOVE_COST: usize = 14;
+/// Distance in tiles above which paths are found on the path graph of the map
+/// instead of tile by tile.
+const HIERARCHICAL_DISTANCE: usize = 2 * CLUSTER_SIZE;
+
+/// Distance in tiles, counting diagonal steps the same as straight ones, the
+/// same way the server checks ranges.
+pub fn tile_distance(from: Vector2<usize>, to: Vector2<usize>) -> usize {
+    usize::max(from.x.abs_diff(to.x), from.y.abs_diff(to.y))
+}
+
+/// Lower bound for the cost of walking from one tile to within `range` tiles of
+/// another.
+pub(super) fn estimate_cost(from: (usize, usize), to: Vector2<usize>, range: usize) -> usize {
+    let distance_x = usize::abs_diff(from.0, to.x).saturating_sub(range);
+    let distance_y = usize::abs_diff(from.1, to.y).saturating_sub(range);
+
+    let straight_moves = usize::abs_diff(distance_x, distance_y);
+    let diagonal_moves = usize::min(distance_x, distance_y);
+
+    DIAGONAL_MOVE_COST * diagonal_moves + MOVE_COST * straight_moves
+}
+
+pub(super) fn step_cost(from: (usize, usize), to: (usize, usize)) -> usize {
+    match from.0 == to.0 || from.1 == to.1 {
+        true => MOVE_COST,
+        false => DIAGONAL_MOVE_COST,
+    }
+}
+
+pub(super) fn successors(position: (usize, usize), is_walkable: &impl Fn(usize, usize) -> bool) -> Vec<(usize, usize)> {
+    let (x, y) = position;
+    let mut successors = vec![(x + 1, y), (x, y + 1)];
+
+    if x > 0 {
+        successors.push((x - 1, y));
+    }
+
+    if y > 0 {
+        successors.push((x, y - 1));
+    }
+
+    // Diagonal steps can't cut the corners of tiles that are not walkable.
+    let diagonals = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+    for (offset_x, offset_y) in diagonals {
+        let (Some(next_x), Some(next_y)) = (x.checked_add_signed(offset_x), y.checked_add_signed(offset_y)) else {
+            continue;
+        };
+
+        if is_walkable(next_x, y) && is_walkable(x, next_y) {
+            successors.push((next_x, next_y));
+        }
+    }
+
+    successors.retain(|&(x, y)| is_walkable(x, y));
+    successors
+}
+
+fn find_path_on_grid(
+    is_walkable: impl Fn(usize, usize) -> bool,
+    from: Vector2<usize>,
+    to: Vector2<usize>,
+    range: usize,
+) -> Option<Vec<Vector2<usize>>> {
+    let in_range = |position: &(usize, usize)| tile_distance(Vector2::new(position.0, position.1), to) <= range;
+
+    let result = astar(
+        &(from.x, from.y),
+        |&position| successors(position, &is_walkable).into_iter().map(|position| (position, 0)),
+        |&position| estimate_cost(position, to, range),
+        in_range,
+    );
+
+    result.map(|(path, _)| path.into_iter().map(|(x, y)| Vector2::new(x, y)).collect())
+}
+
+fn is_walkable(map: &Map, x: usize, y: usize) -> bool {
+    map.x_in_bounds(x) && map.y_in_bounds(y) && map.get_tile(Vector2::new(x, y)).flags.contains(TileFlags::WALKABLE)
+}
+
+/// Path from one tile to another that is searched tile by tile.
+pub fn find_tile_path(map: &Map, from: Vector2<usize>, to: Vector2<usize>, range: usize) -> Option<Vec<Vector2<usize>>> {
+    find_path_on_grid(|x, y| is_walkable(map, x, y), from, to, range)
+}
+
+/// Path from one tile to another, including both of them. The path stops as
+/// soon as it is within `range` tiles of the goal, like the server does when
+/// walking up to a target to attack or talk to it.
+///
+/// Long paths are found on the path graph of the map, since searching them
+/// tile by tile can take a long time on large maps.
+pub fn find_path_within_range(map: &Map, from: Vector2<usize>, to: Vector2<usize>, range: usize) -> Option<Vec<Vector2<usize>>> {
+    match tile_distance(from, to) > HIERARCHICAL_DISTANCE {
+        true => map.get_path_graph().find_path(from, to, range),
+        false => find_tile_path(map, from, to, range),
+    }
+}
+
+#[cfg(test)]
+mod path_finding {
+    use cgmath::Vector2;
+
+    use super::{find_path_on_grid, tile_distance};
+
+    /// Open grid of 10 by 10 tiles with a wall at `x = 5`, that has a gap at
+    /// `y = 9`.
+    fn is_walkable(x: usize, y: usize) -> bool {
+        x < 10 && y < 10 && (x != 5 || y == 9)
+    }
+
+    #[test]
+    fn exact_goal() {
+        let path = find_path_on_grid(is_walkable, Vector2::new(0, 0), Vector2::new(3, 3), 0).unwrap();
+
+        assert_eq!(path.first(), Some(&Vector2::new(0, 0)));
+        assert_eq!(path.last(), Some(&Vector2::new(3, 3)));
+    }
+
+    #[test]
+    fn stop_within_range() {
+        let goal = Vector2::new(3, 3);
+        let path = find_path_on_grid(is_walkable, Vector2::new(0, 0), goal, 2).unwrap();
+
+        assert_eq!(path, vec![Vector2::new(0, 0), Vector2::new(1, 1)]);
+        assert_eq!(tile_distance(*path.last().unwrap(), goal), 2);
+    }
+
+    #[test]
+    fn already_within_range() {
+        let path = find_path_on_grid(is_walkable, Vector2::new(2, 2), Vector2::new(3, 3), 1).unwrap();
+
+        assert_eq!(path, vec![Vector2::new(2, 2)]);
+    }
+
+    #[test]
+    fn around_wall() {
+        let path = find_path_on_grid(is_walkable, Vector2::new(4, 0), Vector2::new(6, 0), 0).unwrap();
+
+        assert!(path.contains(&Vector2::new(5, 9)));
+        assert!(path.iter().all(|tile| is_walkable(tile.x, tile.y)));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        assert_eq!(find_path_on_grid(is_walkable, Vector2::new(0, 0), Vector2::new(20, 0), 0), None);
+    }
+}
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+use cgmath::Vector2;
+use pathfinding::prelude::astar;
+
+use super::path_finder::{estimate_cost, step_cost, successors, tile_distance};
+
+/// Width and height of the clusters that the tiles of a map are split into.
+pub const CLUSTER_SIZE: usize = 16;
+
+/// Connections between the clusters of a map, so long paths can be found
+/// cluster by cluster and only the parts inside of clusters need to be
+/// searched tile by tile. The graph is built once when the map is loaded.
+pub struct PathGraph {
+    width: usize,
+    height: usize,
+    walkable: Vec<bool>,
+    clusters_per_row: usize,
+    /// Tiles on the borders of clusters that paths between clusters pass
+    /// through.
+    nodes: Vec<Vector2<usize>>,
+    /// Nodes of every cluster.
+    cluster_nodes: Vec<Vec<usize>>,
+    /// Nodes that can be reached from every node, with the cost of getting
+    /// there.
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl PathGraph {
+    pub fn new(width: usize, height: usize, is_walkable: impl Fn(usize, usize) -> bool) -> Self {
+        let walkable = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| is_walkable(x, y))
+            .collect();
+        let clusters_per_row = width.div_ceil(CLUSTER_SIZE);
+        let cluster_count = clusters_per_row * height.div_ceil(CLUSTER_SIZE);
+
+        let mut graph = Self {
+            width,
+            height,
+            walkable,
+            clusters_per_row,
+            nodes: Vec::new(),
+            cluster_nodes: vec![Vec::new(); cluster_count],
+            edges: Vec::new(),
+        };
+        let mut node_indices = HashMap::new();
+
+        // Borders between clusters next to each other.
+        for border_x in (CLUSTER_SIZE..width).step_by(CLUSTER_SIZE) {
+            for start_y in (0..height).step_by(CLUSTER_SIZE) {
+                let pairs = (start_y..usize::min(start_y + CLUSTER_SIZE, height)).map(|y| ((border_x - 1, y), (border_x, y)));
+                graph.add_entrances(pairs, &mut node_indices);
+            }
+        }
+
+        // Borders between clusters above each other.
+        for border_y in (CLUSTER_SIZE..height).step_by(CLUSTER_SIZE) {
+            for start_x in (0..width).step_by(CLUSTER_SIZE) {
+                let pairs = (start_x..usize::min(start_x + CLUSTER_SIZE, width)).map(|x| ((x, border_y - 1), (x, border_y)));
+                graph.add_entrances(pairs, &mut node_indices);
+            }
+        }
+
+        for cluster_nodes in graph.cluster_nodes.clone() {
+            for (index, &first) in cluster_nodes.iter().enumerate() {
+                for &second in &cluster_nodes[index + 1..] {
+                    let cost = graph
+                        .search_in_cluster(graph.nodes[first], graph.nodes[second], 0)
+                        .map(|(_, cost)| cost);
+
+                    if let Some(cost) = cost {
+                        graph.edges[first].push((second, cost));
+                        graph.edges[second].push((first, cost));
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn is_walkable(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height && self.walkable[x + y * self.width]
+    }
+
+    fn cluster_of(&self, (x, y): (usize, usize)) -> usize {
+        (y / CLUSTER_SIZE) * self.clusters_per_row + x / CLUSTER_SIZE
+    }
+
+    fn add_node(&mut self, tile: (usize, usize), node_indices: &mut HashMap<(usize, usize), usize>) -> usize {
+        *node_indices.entry(tile).or_insert_with(|| {
+            let index = self.nodes.len();
+            let cluster = self.cluster_of(tile);
+
+            self.nodes.push(Vector2::new(tile.0, tile.1));
+            self.edges.push(Vec::new());
+            self.cluster_nodes[cluster].push(index);
+
+            index
+        })
+    }
+
+    /// Connect two clusters in the middle of every run of walkable tiles
+    /// along the border between them.
+    fn add_entrances(
+        &mut self,
+        pairs: impl Iterator<Item = ((usize, usize), (usize, usize))>,
+        node_indices: &mut HashMap<(usize, usize), usize>,
+    ) {
+        let pairs: Vec<_> = pairs.collect();
+        let entrances: Vec<_> = pairs
+            .split(|&(first, second)| !self.is_walkable(first) || !self.is_walkable(second))
+            .filter_map(|run| run.get(run.len() / 2).copied())
+            .collect();
+
+        for (first, second) in entrances {
+            let cost = step_cost(first, second);
+            let first = self.add_node(first, node_indices);
+            let second = self.add_node(second, node_indices);
+
+            self.edges[first].push((second, cost));
+            self.edges[second].push((first, cost));
+        }
+    }
+
+    /// Path that doesn't leave the cluster of `from`, with its cost.
+    fn search_in_cluster(&self, from: Vector2<usize>, to: Vector2<usize>, range: usize) -> Option<(Vec<Vector2<usize>>, usize)> {
+        let cluster = self.cluster_of((from.x, from.y));
+        let is_walkable = |x: usize, y: usize| self.is_walkable((x, y)) && self.cluster_of((x, y)) == cluster;
+
+        astar(
+            &(from.x, from.y),
+            |&position| {
+                successors(position, &is_walkable)
+                    .into_iter()
+                    .map(move |next| (next, step_cost(position, next)))
+            },
+            |&position| estimate_cost(position, to, range),
+            |&(x, y)| tile_distance(Vector2::new(x, y), to) <= range,
+        )
+        .map(|(path, cost)| (path.into_iter().map(|(x, y)| Vector2::new(x, y)).collect(), cost))
+    }
+
+    /// Path from one tile to within `range` tiles of another, found on the
+    /// graph first and then refined tile by tile inside of every cluster on
+    /// the way.
+    pub fn find_path(&self, from: Vector2<usize>, to: Vector2<usize>, range: usize) -> Option<Vec<Vector2<usize>>> {
+        let in_bounds = |tile: Vector2<usize>| tile.x < self.width && tile.y < self.height;
+
+        if !in_bounds(from) || !in_bounds(to) {
+            return None;
+        }
+
+        let start = self.nodes.len();
+        let goal = start + 1;
+
+        let start_cluster = self.cluster_of((from.x, from.y));
+        let goal_cluster = self.cluster_of((to.x, to.y));
+
+        let start_edges: Vec<(usize, usize)> = self.cluster_nodes[start_cluster]
+            .iter()
+            .filter_map(|&node| self.search_in_cluster(from, self.nodes[node], 0).map(|(_, cost)| (node, cost)))
+            .collect();
+        let goal_edges: HashMap<usize, usize> = self.cluster_nodes[goal_cluster]
+            .iter()
+            .filter_map(|&node| self.search_in_cluster(self.nodes[node], to, range).map(|(_, cost)| (node, cost)))
+            .collect();
+        let direct_path = match start_cluster == goal_cluster {
+            true => self.search_in_cluster(from, to, range),
+            false => None,
+        };
+
+        let position = |node: usize| match node == start {
+            true => from,
+            false => self.nodes[node],
+        };
+
+        let (nodes, _) = astar(
+            &start,
+            |&node| {
+                let mut neighbours = match node {
+                    node if node == start => start_edges.clone(),
+                    node if node == goal => Vec::new(),
+                    node => self.edges[node].clone(),
+                };
+
+                if node == start
+                    && let Some((_, cost)) = &direct_path
+                {
+                    neighbours.push((goal, *cost));
+                }
+
+                if let Some(&cost) = goal_edges.get(&node) {
+                    neighbours.push((goal, cost));
+                }
+
+                neighbours
+            },
+            |&node| match node == goal {
+                true => 0,
+                false => {
+                    let position = position(node);
+                    estimate_cost((position.x, position.y), to, range)
+                }
+            },
+            |&node| node == goal,
+        )?;
+
+        let mut path = vec![from];
+
+        for window in nodes.windows(2) {
+            let (first, second) = (window[0], window[1]);
+            let first_tile = position(first);
+
+            let segment = match second == goal {
+                true if first == start => direct_path.clone()?.0,
+                true => self.search_in_cluster(first_tile, to, range)?.0,
+                false => {
+                    let second_tile = self.nodes[second];
+
+                    match self.cluster_of((first_tile.x, first_tile.y)) == self.cluster_of((second_tile.x, second_tile.y)) {
+                        true => self.search_in_cluster(first_tile, second_tile, 0)?.0,
+                        // Neighbouring tiles in different clusters are connected directly.
+                        false => vec![first_tile, second_tile],
+                    }
+                }
+            };
+
+            path.extend(segment.into_iter().skip(1));
+        }
+
+        // The path can come within range before it reaches the last cluster.
+        if let Some(index) = path.iter().position(|&tile| tile_distance(tile, to) <= range) {
+            path.truncate(index + 1);
+        }
+
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod hierarchical {
+    use cgmath::Vector2;
+
+    use super::{tile_distance, PathGraph, CLUSTER_SIZE};
+
+    const WIDTH: usize = 64;
+    const HEIGHT: usize = 40;
+
+    fn assert_valid(graph: &PathGraph, path: &[Vector2<usize>], from: Vector2<usize>) {
+        assert_eq!(path.first(), Some(&from));
+        assert!(path.windows(2).all(|step| tile_distance(step[0], step[1]) == 1));
+        assert!(path.iter().all(|tile| graph.is_walkable((tile.x, tile.y))));
+    }
+
+    #[test]
+    fn open_map() {
+        let graph = PathGraph::new(WIDTH, HEIGHT, |_, _| true);
+        let (from, to) = (Vector2::new(1, 1), Vector2::new(60, 38));
+        let path = graph.find_path(from, to, 0).unwrap();
+
+        assert_valid(&graph, &path, from);
+        assert_eq!(path.last(), Some(&to));
+    }
+
+    #[test]
+    fn wall_with_gap() {
+        let graph = PathGraph::new(WIDTH, HEIGHT, |x, y| x != 30 || y == 35);
+        let (from, to) = (Vector2::new(2, 2), Vector2::new(60, 2));
+        let path = graph.find_path(from, to, 0).unwrap();
+
+        assert_valid(&graph, &path, from);
+        assert_eq!(path.last(), Some(&to));
+        assert!(path.contains(&Vector2::new(30, 35)));
+    }
+
+    #[test]
+    fn leave_cluster_to_get_around_wall() {
+        let graph = PathGraph::new(WIDTH, HEIGHT, |x, y| x != 8 || y >= CLUSTER_SIZE);
+        let (from, to) = (Vector2::new(4, 4), Vector2::new(12, 4));
+        let path = graph.find_path(from, to, 0).unwrap();
+
+        assert_valid(&graph, &path, from);
+        assert_eq!(path.last(), Some(&to));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let graph = PathGraph::new(WIDTH, HEIGHT, |x, _| x != 30);
+
+        assert_eq!(graph.find_path(Vector2::new(2, 2), Vector2::new(60, 2), 0), None);
+    }
+
+    #[test]
+    fn stop_within_range() {
+        let graph = PathGraph::new(WIDTH, HEIGHT, |_, _| true);
+        let (from, to) = (Vector2::new(1, 1), Vector2::new(60, 38));
+        let path = graph.find_path(from, to, 3).unwrap();
+
+        assert_valid(&graph, &path, from);
+        assert_eq!(tile_distance(*path.last().unwrap(), to), 3);
+    }
+}
@@ -0,0 +1,77 @@
+use cgmath::Vector2;
+
+/// Distance in tiles between the predicted position of the player and the
+/// position on the path that the server confirmed, above which the
+/// prediction is discarded.
+const RECONCILIATION_THRESHOLD: usize = 2;
+
+/// Tile that was last reached on a path at the given client tick.
+pub fn tile_at(steps: &[(Vector2<usize>, u32)], client_tick: u32) -> Option<Vector2<usize>> {
+    steps
+        .iter()
+        .take_while(|step| step.1 <= client_tick)
+        .last()
+        .or(steps.first())
+        .map(|step| step.0)
+}
+
+/// Whether a predicted walk ended up too far away from the walk that the
+/// server confirmed. A walk to a different destination always diverges, while
+/// a small difference in position is expected, since the confirmation only
+/// arrives after a round trip to the server.
+pub fn has_diverged(
+    predicted_destination: Vector2<usize>,
+    predicted_tile: Vector2<usize>,
+    confirmed_destination: Vector2<usize>,
+    confirmed_tile: Vector2<usize>,
+) -> bool {
+    let distance = usize::max(
+        predicted_tile.x.abs_diff(confirmed_tile.x),
+        predicted_tile.y.abs_diff(confirmed_tile.y),
+    );
+
+    predicted_destination != confirmed_destination || distance > RECONCILIATION_THRESHOLD
+}
+
+#[cfg(test)]
+mod reconciliation {
+    use cgmath::Vector2;
+
+    use super::{has_diverged, tile_at, RECONCILIATION_THRESHOLD};
+
+    #[test]
+    fn tile_on_path() {
+        let steps = [(Vector2::new(1, 1), 1000), (Vector2::new(2, 1), 1150), (Vector2::new(3, 1), 1300)];
+
+        assert_eq!(tile_at(&steps, 900), Some(Vector2::new(1, 1)));
+        assert_eq!(tile_at(&steps, 1149), Some(Vector2::new(1, 1)));
+        assert_eq!(tile_at(&steps, 1150), Some(Vector2::new(2, 1)));
+        assert_eq!(tile_at(&steps, 5000), Some(Vector2::new(3, 1)));
+        assert_eq!(tile_at(&[], 1000), None);
+    }
+
+    #[test]
+    fn small_difference() {
+        let destination = Vector2::new(20, 20);
+        let confirmed_tile = Vector2::new(10, 10);
+        let predicted_tile = confirmed_tile + Vector2::new(RECONCILIATION_THRESHOLD, 1);
+
+        assert!(!has_diverged(destination, predicted_tile, destination, confirmed_tile));
+    }
+
+    #[test]
+    fn large_difference() {
+        let destination = Vector2::new(20, 20);
+        let confirmed_tile = Vector2::new(10, 10);
+        let predicted_tile = confirmed_tile + Vector2::new(RECONCILIATION_THRESHOLD + 1, 0);
+
+        assert!(has_diverged(destination, predicted_tile, destination, confirmed_tile));
+    }
+
+    #[test]
+    fn different_destination() {
+        let tile = Vector2::new(10, 10);
+
+        assert!(has_diverged(Vector2::new(20, 20), tile, Vector2::new(20, 21), tile));
+    }
+}
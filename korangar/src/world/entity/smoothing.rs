@@ -0,0 +1,85 @@
+use cgmath::{Vector3, VectorSpace};
+
+fn quadratic_bezier(start: Vector3<f32>, control: Vector3<f32>, end: Vector3<f32>, progress: f32) -> Vector3<f32> {
+    let first = start.lerp(control, progress);
+    let second = control.lerp(end, progress);
+    first.lerp(second, progress)
+}
+
+/// Position between two steps of a path, rounding off the corners at both
+/// steps instead of moving in a straight line. `steps` are the world
+/// positions of the step before, the two steps that are moved between and the
+/// step after. At the start and end of a path, the outer steps are the same as
+/// the inner ones.
+///
+/// Every corner is replaced by a curve from the middle of the incoming segment
+/// to the middle of the outgoing one, so straight parts of the path are not
+/// affected and the entity still passes the middle of every segment at the
+/// same time as before.
+pub fn smoothed_step_position(steps: [Vector3<f32>; 4], progress: f32) -> Vector3<f32> {
+    let [previous, last, next, after] = steps;
+
+    let incoming_middle = previous.lerp(last, 0.5);
+    let middle = last.lerp(next, 0.5);
+    let outgoing_middle = next.lerp(after, 0.5);
+
+    match progress < 0.5 {
+        true => quadratic_bezier(incoming_middle, last, middle, progress + 0.5),
+        false => quadratic_bezier(middle, next, outgoing_middle, progress - 0.5),
+    }
+}
+
+#[cfg(test)]
+mod corner_cutting {
+    use cgmath::{InnerSpace, Vector3, VectorSpace};
+
+    use super::smoothed_step_position;
+
+    fn assert_close(left: Vector3<f32>, right: Vector3<f32>) {
+        assert!((left - right).magnitude() < 0.001, "{left:?} != {right:?}");
+    }
+
+    #[test]
+    fn straight_path() {
+        let steps = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(5.0, 0.0, 0.0),
+            Vector3::new(10.0, 0.0, 0.0),
+            Vector3::new(15.0, 0.0, 0.0),
+        ];
+
+        for progress in [0.0, 0.25, 0.5, 0.75] {
+            assert_close(smoothed_step_position(steps, progress), steps[1].lerp(steps[2], progress));
+        }
+    }
+
+    #[test]
+    fn start_and_end_of_path() {
+        let start = Vector3::new(0.0, 0.0, 0.0);
+        let end = Vector3::new(5.0, 0.0, 5.0);
+        let steps = [start, start, end, end];
+
+        assert_close(smoothed_step_position(steps, 0.0), start);
+        assert_close(smoothed_step_position(steps, 0.5), start.lerp(end, 0.5));
+        assert_close(smoothed_step_position(steps, 1.0), end);
+    }
+
+    #[test]
+    fn cut_corner() {
+        let steps = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(5.0, 0.0, 0.0),
+            Vector3::new(5.0, 0.0, 5.0),
+            Vector3::new(5.0, 0.0, 10.0),
+        ];
+
+        // The entity no longer passes the corner, but still passes the middle
+        // of the segment.
+        assert_close(smoothed_step_position(steps, 0.0), Vector3::new(4.375, 0.0, 0.625));
+        assert_close(smoothed_step_position(steps, 0.5), Vector3::new(5.0, 0.0, 2.5));
+
+        // The next segment continues where this one ends.
+        let next_steps = [steps[1], steps[2], steps[3], steps[3]];
+        assert_close(smoothed_step_position(steps, 1.0), smoothed_step_position(next_steps, 0.0));
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use ragnarok_packets::ClientTick;
+use vulkano::image::view::ImageView;
+
+struct StatusEffect {
+    id: u16,
+    /// Client tick at which the effect runs out. Effects without an expiry
+    /// last until the server removes them.
+    expiry: Option<u32>,
+    icon: Option<Arc<ImageView>>,
+}
+
+/// Status effects that are currently applied to an entity, in the order they
+/// were applied.
+#[derive(Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Add a status effect. Applying an effect that is already active only
+    /// refreshes its duration.
+    pub fn insert(&mut self, id: u16, duration: Option<Duration>, icon: Option<Arc<ImageView>>, client_tick: ClientTick) {
+        let expiry = duration.map(|duration| client_tick.0.saturating_add(duration.as_millis() as u32));
+
+        match self.effects.iter_mut().find(|effect| effect.id == id) {
+            Some(effect) => {
+                effect.expiry = expiry;
+                effect.icon = icon;
+            }
+            None => self.effects.push(StatusEffect { id, expiry, icon }),
+        }
+    }
+
+    pub fn remove(&mut self, id: u16) {
+        self.effects.retain(|effect| effect.id != id);
+    }
+
+    /// Remove effects that ran out, in case the server doesn't remove them
+    /// in time.
+    pub fn update(&mut self, client_tick: ClientTick) {
+        self.effects
+            .retain(|effect| !effect.expiry.is_some_and(|expiry| client_tick.0 >= expiry));
+    }
+
+    /// Icons of all effects that have one.
+    pub fn icons(&self) -> impl Iterator<Item = &Arc<ImageView>> {
+        self.effects.iter().filter_map(|effect| effect.icon.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tracking {
+    use std::time::Duration;
+
+    use ragnarok_packets::ClientTick;
+
+    use super::StatusEffects;
+
+    const BLESSING: u16 = 10;
+    const INCREASE_AGILITY: u16 = 12;
+
+    impl StatusEffects {
+        fn contains(&self, id: u16) -> bool {
+            self.effects.iter().any(|effect| effect.id == id)
+        }
+    }
+
+    #[test]
+    fn expiry() {
+        let mut status_effects = StatusEffects::default();
+        status_effects.insert(BLESSING, Some(Duration::from_secs(2)), None, ClientTick(1000));
+        status_effects.insert(INCREASE_AGILITY, None, None, ClientTick(1000));
+
+        status_effects.update(ClientTick(2999));
+        assert!(status_effects.contains(BLESSING));
+
+        status_effects.update(ClientTick(3000));
+        assert!(!status_effects.contains(BLESSING));
+        assert!(status_effects.contains(INCREASE_AGILITY));
+    }
+
+    #[test]
+    fn refresh_duration() {
+        let mut status_effects = StatusEffects::default();
+        status_effects.insert(BLESSING, Some(Duration::from_secs(2)), None, ClientTick(1000));
+        status_effects.insert(BLESSING, Some(Duration::from_secs(2)), None, ClientTick(2000));
+
+        status_effects.update(ClientTick(3500));
+        assert!(status_effects.contains(BLESSING));
+        assert_eq!(status_effects.effects.len(), 1);
+    }
+
+    #[test]
+    fn remove() {
+        let mut status_effects = StatusEffects::default();
+        status_effects.insert(BLESSING, None, None, ClientTick(1000));
+        status_effects.remove(BLESSING);
+
+        assert!(!status_effects.contains(BLESSING));
+    }
+}
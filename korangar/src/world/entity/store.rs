@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use ragnarok_packets::EntityId;
+
+use super::Entity;
+
+/// Anything that can be stored in an [`EntityStore`].
+pub trait StoredEntity {
+    fn stored_entity_id(&self) -> EntityId;
+}
+
+impl StoredEntity for Entity {
+    fn stored_entity_id(&self) -> EntityId {
+        self.get_entity_id()
+    }
+}
+
+/// Reference to an entity that outlives a single frame. Entity ids are
+/// reused, for example when a player leaves the screen and comes back, so
+/// every inserted entity gets a new generation and handles to an older
+/// generation no longer resolve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EntityHandle {
+    entity_id: EntityId,
+    generation: u32,
+}
+
+#[derive(Clone, Copy)]
+struct Slot {
+    index: usize,
+    generation: u32,
+}
+
+/// Entities of the current map, with the player always being the first one.
+/// The entities are stored in a contiguous list for iterating and rendering,
+/// while lookups by id go through an index map, since crowded maps receive
+/// many packets per tick that each look up an entity.
+///
+/// Entities can be changed in place, but the list itself is only changed
+/// through the store, so the indices stay valid.
+pub struct EntityStore<T = Entity> {
+    entities: Vec<T>,
+    slots: HashMap<EntityId, Slot>,
+    next_generation: u32,
+}
+
+impl<T> Default for EntityStore<T> {
+    fn default() -> Self {
+        Self {
+            entities: Vec::new(),
+            slots: HashMap::new(),
+            next_generation: 0,
+        }
+    }
+}
+
+impl<T: StoredEntity> EntityStore<T> {
+    pub fn clear(&mut self) {
+        self.entities.clear();
+        self.slots.clear();
+    }
+
+    /// Add an entity, replacing the entity with the same id if there is one.
+    /// Handles to the replaced entity no longer resolve.
+    pub fn insert(&mut self, entity: T) {
+        let entity_id = entity.stored_entity_id();
+        let generation = self.next_generation;
+        self.next_generation = self.next_generation.wrapping_add(1);
+
+        match self.slots.get_mut(&entity_id) {
+            Some(slot) => {
+                slot.generation = generation;
+                self.entities[slot.index] = entity;
+            }
+            None => {
+                let index = self.entities.len();
+                self.slots.insert(entity_id, Slot { index, generation });
+                self.entities.push(entity);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, entity_id: EntityId) -> Option<T> {
+        let index = self.slots.remove(&entity_id)?.index;
+
+        // Removing the player has to keep the order, so that the next entity
+        // doesn't take its place. Any other entity is replaced by the last one.
+        if index == 0 {
+            let entity = self.entities.remove(0);
+            self.rebuild_indices();
+            return Some(entity);
+        }
+
+        let entity = self.entities.swap_remove(index);
+
+        if let Some(moved_entity) = self.entities.get(index)
+            && let Some(slot) = self.slots.get_mut(&moved_entity.stored_entity_id())
+        {
+            slot.index = index;
+        }
+
+        Some(entity)
+    }
+
+    /// Remove every entity except the player.
+    pub fn retain_player(&mut self) {
+        self.entities.truncate(1);
+        self.rebuild_indices();
+    }
+
+    pub fn get(&self, entity_id: EntityId) -> Option<&T> {
+        self.slots.get(&entity_id).map(|slot| &self.entities[slot.index])
+    }
+
+    pub fn get_mut(&mut self, entity_id: EntityId) -> Option<&mut T> {
+        self.slots.get(&entity_id).map(|slot| &mut self.entities[slot.index])
+    }
+
+    /// Handle to the entity that currently has the given id.
+    pub fn handle(&self, entity_id: EntityId) -> Option<EntityHandle> {
+        self.slots.get(&entity_id).map(|slot| EntityHandle {
+            entity_id,
+            generation: slot.generation,
+        })
+    }
+
+    pub fn get_by_handle(&self, handle: EntityHandle) -> Option<&T> {
+        self.slots
+            .get(&handle.entity_id)
+            .filter(|slot| slot.generation == handle.generation)
+            .map(|slot| &self.entities[slot.index])
+    }
+
+    /// The player is always the first entity.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.entities.first_mut()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.entities.iter_mut()
+    }
+
+    fn rebuild_indices(&mut self) {
+        // Entities keep their generation, so their handles stay valid.
+        self.slots = self
+            .entities
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entity)| {
+                let entity_id = entity.stored_entity_id();
+                let generation = self.slots.get(&entity_id)?.generation;
+                Some((entity_id, Slot { index, generation }))
+            })
+            .collect();
+    }
+}
+
+impl<T> Deref for EntityStore<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.entities
+    }
+}
+
+#[cfg(test)]
+mod indices {
+    use ragnarok_packets::EntityId;
+
+    use super::{EntityStore, StoredEntity};
+
+    #[derive(Debug, PartialEq)]
+    struct TestEntity(u32, &'static str);
+
+    impl StoredEntity for TestEntity {
+        fn stored_entity_id(&self) -> EntityId {
+            EntityId(self.0)
+        }
+    }
+
+    fn store(ids: &[u32]) -> EntityStore<TestEntity> {
+        let mut store = EntityStore::default();
+        ids.iter().for_each(|&id| store.insert(TestEntity(id, "")));
+        store
+    }
+
+    fn ids(store: &EntityStore<TestEntity>) -> Vec<u32> {
+        store.iter().map(|entity| entity.0).collect()
+    }
+
+    #[test]
+    fn insert_replaces() {
+        let mut store = store(&[1, 2, 3]);
+        store.insert(TestEntity(2, "replaced"));
+
+        assert_eq!(ids(&store), vec![1, 2, 3]);
+        assert_eq!(store.get(EntityId(2)), Some(&TestEntity(2, "replaced")));
+    }
+
+    #[test]
+    fn remove_keeps_lookups() {
+        let mut store = store(&[1, 2, 3, 4]);
+
+        assert_eq!(store.remove(EntityId(2)), Some(TestEntity(2, "")));
+        assert_eq!(store.remove(EntityId(2)), None);
+        assert_eq!(ids(&store), vec![1, 4, 3]);
+
+        for id in [1, 3, 4] {
+            assert_eq!(store.get(EntityId(id)).map(|entity| entity.0), Some(id));
+        }
+    }
+
+    #[test]
+    fn remove_player() {
+        let mut store = store(&[1, 2, 3]);
+        store.remove(EntityId(1));
+
+        assert_eq!(ids(&store), vec![2, 3]);
+        assert_eq!(store.get(EntityId(3)).map(|entity| entity.0), Some(3));
+    }
+
+    #[test]
+    fn retain_player() {
+        let mut store = store(&[1, 2, 3]);
+        store.retain_player();
+
+        assert_eq!(ids(&store), vec![1]);
+        assert_eq!(store.get(EntityId(2)), None);
+    }
+
+    #[test]
+    fn handles_follow_moved_entities() {
+        let mut store = store(&[1, 2, 3, 4]);
+        let handle = store.handle(EntityId(4)).unwrap();
+
+        store.remove(EntityId(2));
+        store.remove(EntityId(1));
+
+        assert_eq!(store.get_by_handle(handle).map(|entity| entity.0), Some(4));
+    }
+
+    #[test]
+    fn stale_handles() {
+        let mut store = store(&[1, 2]);
+        let handle = store.handle(EntityId(2)).unwrap();
+
+        store.remove(EntityId(2));
+        assert_eq!(store.get_by_handle(handle), None);
+
+        store.insert(TestEntity(2, "respawned"));
+        assert_eq!(store.get_by_handle(handle), None);
+        assert_eq!(
+            store.handle(EntityId(2)).and_then(|handle| store.get_by_handle(handle)),
+            Some(&TestEntity(2, "respawned"))
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use ragnarok_packets::ClientTick;
+
+use crate::graphics::{Color, EntityTint};
+
+/// Time in milliseconds that an entity flashes after it was hit.
+const HIT_FLASH_DURATION: u32 = 300;
+/// Color that an entity is tinted with right after it was hit.
+const HIT_FLASH_COLOR: Color = Color::rgb(1.0, 0.35, 0.35);
+/// Opacity of entities that are hiding or cloaking.
+const HIDDEN_OPACITY: f32 = 0.5;
+
+/// Strength of the hit flash from 1 right after the hit to 0 once it is over.
+/// The flash fades out quickly at first and then slows down.
+fn hit_flash_strength(hit_timestamp: Option<u32>, client_tick: ClientTick) -> f32 {
+    let Some(hit_timestamp) = hit_timestamp else {
+        return 0.0;
+    };
+
+    let elapsed = client_tick.0.saturating_sub(hit_timestamp);
+
+    if elapsed >= HIT_FLASH_DURATION {
+        return 0.0;
+    }
+
+    let remaining = 1.0 - elapsed as f32 / HIT_FLASH_DURATION as f32;
+    remaining * remaining
+}
+
+/// Conditions that change the color of an entity.
+#[derive(Default)]
+pub struct TintState {
+    hit_timestamp: Option<u32>,
+    pub petrified: bool,
+    pub hidden: bool,
+}
+
+impl TintState {
+    pub fn hit(&mut self, client_tick: ClientTick) {
+        self.hit_timestamp = Some(client_tick.0);
+    }
+
+    pub fn tint(&self, client_tick: ClientTick) -> EntityTint {
+        let strength = hit_flash_strength(self.hit_timestamp, client_tick);
+        let flash = |component: f32| 1.0 + (component - 1.0) * strength;
+
+        let opacity = match self.hidden {
+            true => HIDDEN_OPACITY,
+            false => 1.0,
+        };
+
+        EntityTint {
+            color: Color::rgba(
+                flash(HIT_FLASH_COLOR.red),
+                flash(HIT_FLASH_COLOR.green),
+                flash(HIT_FLASH_COLOR.blue),
+                opacity,
+            ),
+            saturation: match self.petrified {
+                true => 0.0,
+                false => 1.0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod hit_flash {
+    use ragnarok_packets::ClientTick;
+
+    use super::{hit_flash_strength, TintState, HIT_FLASH_DURATION};
+
+    #[test]
+    fn decays() {
+        let hit = Some(1000);
+
+        assert_eq!(hit_flash_strength(None, ClientTick(1000)), 0.0);
+        assert_eq!(hit_flash_strength(hit, ClientTick(1000)), 1.0);
+        assert_eq!(hit_flash_strength(hit, ClientTick(1000 + HIT_FLASH_DURATION / 2)), 0.25);
+        assert_eq!(hit_flash_strength(hit, ClientTick(1000 + HIT_FLASH_DURATION)), 0.0);
+    }
+
+    #[test]
+    fn untinted() {
+        let tint = TintState::default().tint(ClientTick(1000));
+
+        assert_eq!(tint.color.red, 1.0);
+        assert_eq!(tint.color.green, 1.0);
+        assert_eq!(tint.color.alpha, 1.0);
+        assert_eq!(tint.saturation, 1.0);
+    }
+
+    #[test]
+    fn petrified_and_hidden() {
+        let state = TintState {
+            petrified: true,
+            hidden: true,
+            ..Default::default()
+        };
+        let tint = state.tint(ClientTick(1000));
+
+        assert_eq!(tint.saturation, 0.0);
+        assert!(tint.color.alpha < 1.0);
+    }
+}
@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
-use cgmath::{Array, EuclideanSpace, Matrix4, Point3, SquareMatrix, Vector2, Vector3};
+use cgmath::{Array, Matrix4, SquareMatrix, Vector2, Vector3, Zero};
+#[cfg(feature = "debug")]
+use cgmath::{EuclideanSpace, Point3};
+#[cfg(feature = "debug")]
 use collision::{Aabb3, Frustum, Relation};
 use derive_new::new;
-#[cfg(feature = "debug")]
-use korangar_debug::profiling::Profiler;
+use image::{Rgba, RgbaImage};
+use korangar_audio::{AttenuationCurve, AudioChannel, AudioEngine, SoundEmitter, SpatialSettings};
 use korangar_interface::windows::PrototypeWindow;
 #[cfg(feature = "debug")]
 use option_ext::OptionExt;
@@ -13,10 +16,13 @@ use ragnarok_formats::map::{EffectSource, LightSettings, LightSource, MapData, S
 use ragnarok_formats::transform::Transform;
 use ragnarok_packets::ClientTick;
 use vulkano::buffer::Subbuffer;
+use vulkano::command_buffer::DrawIndirectCommand;
 use vulkano::image::view::ImageView;
 
+use crate::audio::SoundMaterial;
 use crate::graphics::*;
 use crate::interface::application::InterfaceSettings;
+use crate::loaders::{GameFileLoader, SoundLoader};
 use crate::world::*;
 
 fn average_tile_height(tile: &Tile) -> f32 {
@@ -105,11 +111,26 @@ pub struct Map {
     water_vertex_buffer: Option<Subbuffer<[WaterVertex]>>,
     ground_textures: Vec<Arc<ImageView>>,
     objects: Vec<Object>,
+    /// World space bounding boxes of the objects, in the same order.
+    #[cfg(feature = "debug")]
+    object_bounding_boxes: Vec<BoundingBox>,
+    /// The world space bounding boxes of the objects on the GPU, as pairs of
+    /// the smallest and the biggest corner, used for frustum and occlusion
+    /// culling.
+    object_bounding_box_buffer: Option<Subbuffer<[[f32; 4]]>>,
+    /// Index of the first draw of every object in
+    /// [`object_draw_buffer`](Self::object_draw_buffer), followed by the
+    /// total number of draws.
+    object_draw_offsets: Vec<u64>,
+    /// Object index and vertex count of every node of every object, in the
+    /// order in which they are rendered.
+    object_draw_buffer: Option<Subbuffer<[[u32; 2]]>>,
     light_sources: Vec<LightSource>,
     sound_sources: Vec<SoundSource>,
     effect_sources: Vec<EffectSource>,
     tile_picker_vertex_buffer: Subbuffer<[TileVertex]>,
     tile_vertex_buffer: Subbuffer<[ModelVertex]>,
+    path_graph: PathGraph,
     #[cfg(feature = "debug")]
     map_data: MapData,
 }
@@ -123,16 +144,74 @@ impl Map {
         y <= self.height
     }
 
+    pub fn get_size(&self) -> Vector2<usize> {
+        Vector2::new(self.width, self.height)
+    }
+
+    pub fn get_object_bounding_box_buffer(&self) -> Option<Subbuffer<[[f32; 4]]>> {
+        self.object_bounding_box_buffer.clone()
+    }
+
+    pub fn get_path_graph(&self) -> &PathGraph {
+        &self.path_graph
+    }
+
+    /// Image with one pixel per tile, used as the minimap if the game files
+    /// don't contain an image of the map. North is at the top.
+    pub fn generate_minimap_image(&self) -> RgbaImage {
+        RgbaImage::from_fn(self.width as u32, self.height as u32, |x, y| {
+            let tile = self.get_tile(Vector2::new(x as usize, self.height - 1 - y as usize));
+
+            match tile.flags {
+                flags if flags.contains(TileFlags::WATER) => Rgba([70, 110, 170, 255]),
+                flags if flags.contains(TileFlags::WALKABLE) => Rgba([190, 180, 150, 255]),
+                _ => Rgba([60, 55, 50, 255]),
+            }
+        })
+    }
+
     pub fn get_world_position(&self, position: Vector2<usize>) -> Vector3<f32> {
         let height = average_tile_height(self.get_tile(position));
         Vector3::new(position.x as f32 * 5.0 + 2.5, height, position.y as f32 * 5.0 + 2.5)
     }
 
+    pub fn get_sound_material(&self, position: Vector2<usize>) -> SoundMaterial {
+        match self.get_tile(position).flags.contains(TileFlags::WATER) {
+            true => SoundMaterial::Water,
+            false => SoundMaterial::Ground,
+        }
+    }
+
     // TODO: Make this private once path finding is properly implemented
     pub fn get_tile(&self, position: Vector2<usize>) -> &Tile {
         &self.tiles[position.x + position.y * self.width]
     }
 
+    /// Start looping the sound sources of the map. The sounds fade out once
+    /// the returned emitters are dropped.
+    pub fn start_sound_emitters(
+        &self,
+        audio_engine: &mut AudioEngine,
+        sound_loader: &mut SoundLoader,
+        game_file_loader: &mut GameFileLoader,
+    ) -> Vec<SoundEmitter> {
+        self.sound_sources
+            .iter()
+            .filter_map(|sound_source| {
+                let sound_effect = sound_loader.get(&sound_source.sound_file, game_file_loader).ok()?;
+
+                audio_engine.start_emitter(AudioChannel::Ambient, &sound_effect, SpatialSettings {
+                    position: sound_source.position,
+                    velocity: Vector3::zero(),
+                    volume: sound_source.volume,
+                    pitch: 1.0,
+                    range: sound_source.range,
+                    attenuation: AttenuationCurve::Linear,
+                })
+            })
+            .collect()
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn render_ground<T>(&self, render_target: &mut T::Target, renderer: &T, camera: &dyn Camera, time: f32)
     where
@@ -148,6 +227,34 @@ impl Map {
         );
     }
 
+    /// Test the objects against the view frustum of `camera` on the GPU. Has
+    /// to be called before the render pass of the target begins, and the
+    /// result is passed to [`render_objects`](Self::render_objects).
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn cull_objects<T>(
+        &self,
+        render_target: &mut T::Target,
+        renderer: &T,
+        camera: &dyn Camera,
+        #[cfg(feature = "debug")] frustum_culling: bool,
+    ) -> Option<Subbuffer<[DrawIndirectCommand]>>
+    where
+        T: Renderer + CullingRenderer,
+    {
+        #[cfg(feature = "debug")]
+        if !frustum_culling {
+            return None;
+        }
+
+        let bounding_boxes = self.object_bounding_box_buffer.clone()?;
+        let draws = self.object_draw_buffer.clone()?;
+
+        Some(renderer.cull_objects(render_target, camera, bounding_boxes, draws))
+    }
+
+    /// If `culled_draws` are given, the nodes of every object are drawn with
+    /// the draw commands of the culling pass, so objects outside of the view
+    /// frustum are skipped on the GPU.
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn render_objects<T>(
         &self,
@@ -156,39 +263,24 @@ impl Map {
         camera: &dyn Camera,
         client_tick: ClientTick,
         time: f32,
-        #[cfg(feature = "debug")] frustum_culling: bool,
+        visible_objects: Option<&[bool]>,
+        culled_draws: Option<Subbuffer<[DrawIndirectCommand]>>,
     ) where
         T: Renderer + GeometryRenderer,
     {
-        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
-        let frustum = Frustum::from_matrix4(projection_matrix * view_matrix).unwrap();
-        let standard_box = OrientedBox::default();
-
-        for object in &self.objects {
-            #[cfg(feature = "debug")]
-            if !frustum_culling {
-                object.render_geometry(render_target, renderer, camera, client_tick, time);
+        for (index, object) in self.objects.iter().enumerate() {
+            if visible_objects.is_some_and(|visible_objects| !visible_objects[index]) {
                 continue;
             }
 
-            #[cfg(feature = "debug")]
-            let culling_measurement = Profiler::start_measurement("frustum culling");
+            let draw_offset = self.object_draw_offsets[index];
+            let draw_end = self.object_draw_offsets[index + 1];
+            let object_draws = culled_draws
+                .clone()
+                .filter(|_| draw_end > draw_offset)
+                .map(|culled_draws| culled_draws.slice(draw_offset..draw_end));
 
-            let bounding_box_matrix = object.get_bounding_box_matrix();
-            let oriented_bounding_box = standard_box.transform(bounding_box_matrix);
-            let bounding_box = BoundingBox::new(oriented_bounding_box.corners);
-            let collision_bounding_box = Aabb3 {
-                min: Point3::from_vec(bounding_box.smallest),
-                max: Point3::from_vec(bounding_box.biggest),
-            };
-            let culled = matches!(frustum.contains(&collision_bounding_box), Relation::Out);
-
-            #[cfg(feature = "debug")]
-            culling_measurement.stop();
-
-            if !culled {
-                object.render_geometry(render_target, renderer, camera, client_tick, time);
-            };
+            object.render_geometry(render_target, renderer, camera, client_tick, time, object_draws);
         }
     }
 
@@ -218,31 +310,31 @@ impl Map {
         camera: &dyn Camera,
         player_camera: &dyn Camera,
         frustum_culling: bool,
+        visible_objects: Option<&[bool]>,
     ) {
         let (view_matrix, projection_matrix) = player_camera.view_projection_matrices();
         let frustum = Frustum::from_matrix4(projection_matrix * view_matrix).unwrap();
-        let standard_box = OrientedBox::default();
 
-        for object in &self.objects {
-            let bounding_box_matrix = object.get_bounding_box_matrix();
-            let oriented_bounding_box = standard_box.transform(bounding_box_matrix);
-            let bounding_box = BoundingBox::new(oriented_bounding_box.corners);
+        for (index, bounding_box) in self.object_bounding_boxes.iter().enumerate() {
             let collision_bounding_box = Aabb3 {
                 min: Point3::from_vec(bounding_box.smallest),
                 max: Point3::from_vec(bounding_box.biggest),
             };
             let culled = matches!(frustum.contains(&collision_bounding_box), Relation::Out);
 
-            let color = match !frustum_culling || !culled {
-                true => Color::rgb_u8(255, 255, 0),
-                false => Color::rgb_u8(255, 0, 255),
+            let occluded = visible_objects.is_some_and(|visible_objects| !visible_objects[index]);
+
+            let color = match (frustum_culling && culled, occluded) {
+                (true, _) => Color::rgb_u8(255, 0, 255),
+                (false, true) => Color::rgb_u8(0, 255, 255),
+                (false, false) => Color::rgb_u8(255, 255, 0),
             };
 
             let offset = bounding_box.size().y / 2.0;
             let position = bounding_box.center() - Vector3::new(0.0, offset, 0.0);
             let transform = Transform::position(position);
 
-            renderer.render_bounding_box(render_target, camera, &transform, &bounding_box, color);
+            renderer.render_bounding_box(render_target, camera, &transform, bounding_box, color);
         }
     }
 
@@ -1,4 +1,6 @@
+mod combat_text;
 mod effect;
+mod emotion;
 mod entity;
 mod light;
 mod map;
@@ -6,7 +8,9 @@ mod model;
 mod object;
 mod sound;
 
+pub use self::combat_text::*;
 pub use self::effect::*;
+pub use self::emotion::*;
 pub use self::entity::*;
 pub use self::light::*;
 pub use self::map::*;
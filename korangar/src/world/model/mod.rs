@@ -9,6 +9,8 @@ use korangar_interface::elements::PrototypeElement;
 use ragnarok_formats::model::ModelData;
 use ragnarok_formats::transform::Transform;
 use ragnarok_packets::ClientTick;
+use vulkano::buffer::Subbuffer;
+use vulkano::command_buffer::DrawIndirectCommand;
 
 pub use self::node::{BoundingBox, Node, OrientedBox};
 use crate::graphics::{Camera, GeometryRenderer, Renderer};
@@ -32,11 +34,27 @@ impl Model {
         root_transform: &Transform,
         client_tick: ClientTick,
         time: f32,
+        culled_draws: Option<Subbuffer<[DrawIndirectCommand]>>,
     ) where
         T: Renderer + GeometryRenderer,
     {
-        self.root_node
-            .render_geometry(render_target, renderer, camera, root_transform, client_tick, time);
+        self.root_node.render_geometry(
+            render_target,
+            renderer,
+            camera,
+            root_transform,
+            client_tick,
+            time,
+            culled_draws.as_ref(),
+            &mut 0,
+        );
+    }
+
+    /// Vertex counts of all nodes, in the order in which they are rendered.
+    pub fn node_vertex_counts(&self) -> Vec<u32> {
+        let mut vertex_counts = Vec::new();
+        self.root_node.collect_vertex_counts(&mut vertex_counts);
+        vertex_counts
     }
 
     #[cfg(feature = "debug")]
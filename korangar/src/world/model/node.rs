@@ -7,6 +7,7 @@ use ragnarok_formats::model::RotationKeyframeData;
 use ragnarok_formats::transform::Transform;
 use ragnarok_packets::ClientTick;
 use vulkano::buffer::Subbuffer;
+use vulkano::command_buffer::DrawIndirectCommand;
 use vulkano::image::view::ImageView;
 
 use crate::graphics::{Camera, GeometryRenderer, ModelVertex, Renderer};
@@ -161,6 +162,16 @@ impl Node {
             * animation_rotation_matrix
     }
 
+    /// Vertex counts of this node and all of its children, in the order in
+    /// which they are rendered.
+    pub fn collect_vertex_counts(&self, vertex_counts: &mut Vec<u32>) {
+        vertex_counts.push(self.vertex_buffer.len() as u32);
+
+        self.child_nodes.iter().for_each(|node| node.collect_vertex_counts(vertex_counts));
+    }
+
+    /// If `culled_draws` are given, every node is drawn with the draw command
+    /// at `draw_index`, which is advanced for the following nodes.
     #[cfg_attr(feature = "debug", korangar_debug::profile("render node geometry"))]
     pub fn render_geometry<T>(
         &self,
@@ -170,9 +181,14 @@ impl Node {
         transform: &Transform,
         client_tick: ClientTick,
         time: f32,
+        culled_draws: Option<&Subbuffer<[DrawIndirectCommand]>>,
+        draw_index: &mut u64,
     ) where
         T: Renderer + GeometryRenderer,
     {
+        let indirect_draw = culled_draws.map(|culled_draws| culled_draws.clone().slice(*draw_index..*draw_index + 1));
+        *draw_index += 1;
+
         renderer.render_geometry(
             render_target,
             camera,
@@ -180,10 +196,20 @@ impl Node {
             &self.textures,
             self.world_matrix(transform, client_tick),
             time,
+            indirect_draw,
         );
 
-        self.child_nodes
-            .iter()
-            .for_each(|node| node.render_geometry(render_target, renderer, camera, transform, client_tick, time));
+        self.child_nodes.iter().for_each(|node| {
+            node.render_geometry(
+                render_target,
+                renderer,
+                camera,
+                transform,
+                client_tick,
+                time,
+                culled_draws,
+                draw_index,
+            )
+        });
     }
 }
@@ -6,6 +6,8 @@ use korangar_interface::elements::PrototypeElement;
 use korangar_interface::windows::PrototypeWindow;
 use ragnarok_formats::transform::Transform;
 use ragnarok_packets::ClientTick;
+use vulkano::buffer::Subbuffer;
+use vulkano::command_buffer::DrawIndirectCommand;
 
 use crate::graphics::*;
 use crate::world::*;
@@ -19,12 +21,27 @@ pub struct Object {
 }
 
 impl Object {
-    pub fn render_geometry<T>(&self, render_target: &mut T::Target, renderer: &T, camera: &dyn Camera, client_tick: ClientTick, time: f32)
-    where
+    /// `culled_draws` holds one draw command for every node of the model.
+    pub fn render_geometry<T>(
+        &self,
+        render_target: &mut T::Target,
+        renderer: &T,
+        camera: &dyn Camera,
+        client_tick: ClientTick,
+        time: f32,
+        culled_draws: Option<Subbuffer<[DrawIndirectCommand]>>,
+    ) where
         T: Renderer + GeometryRenderer,
     {
-        self.model
-            .render_geometry(render_target, renderer, camera, &self.transform, client_tick, time);
+        self.model.render_geometry(
+            render_target,
+            renderer,
+            camera,
+            &self.transform,
+            client_tick,
+            time,
+            culled_draws,
+        );
     }
 
     //#[korangar_debug::profile]
@@ -32,6 +49,13 @@ impl Object {
         self.model.get_bounding_box_matrix(&self.transform)
     }
 
+    /// Axis aligned bounding box of the object in world space. Objects never
+    /// move, so this only needs to be calculated once.
+    pub fn world_bounding_box(&self) -> BoundingBox {
+        let oriented_bounding_box = OrientedBox::default().transform(self.get_bounding_box_matrix());
+        BoundingBox::new(oriented_bounding_box.corners)
+    }
+
     #[cfg(feature = "debug")]
     pub fn render_bounding_box(
         &self,
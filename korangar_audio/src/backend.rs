@@ -0,0 +1,218 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, Stream, StreamConfig, StreamError};
+use kira::manager::backend::cpal::Error;
+use kira::manager::backend::{Backend, Renderer};
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+
+/// Interval in which the output device is checked for disconnects and
+/// changes of the default device.
+const CHECK_DEVICE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Sample rate that is used until an output device becomes available.
+const FALLBACK_SAMPLE_RATE: u32 = 48000;
+
+/// Names of all output devices that are currently available.
+pub fn get_output_device_names() -> Vec<String> {
+    cpal::default_host()
+        .output_devices()
+        .map(|devices| devices.filter_map(|device| device.name().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Find the requested output device, falling back to the default device if it
+/// is not available.
+fn find_device(device_name: Option<&str>) -> Result<(Device, StreamConfig), Error> {
+    let host = cpal::default_host();
+
+    let device = device_name
+        .and_then(|device_name| {
+            host.output_devices()
+                .ok()?
+                .find(|device| device.name().is_ok_and(|name| name == device_name))
+        })
+        .or_else(|| host.default_output_device())
+        .ok_or(Error::NoDefaultOutputDevice)?;
+    let config = device.default_output_config()?.config();
+
+    Ok((device, config))
+}
+
+struct OutputStream {
+    device_name: String,
+    /// Set by the stream if the device was disconnected or failed otherwise.
+    failed: Arc<AtomicBool>,
+    // Only kept alive, audio is played for as long as the stream exists.
+    _stream: Stream,
+}
+
+/// Backend that plays audio on a configurable output device. Unlike the
+/// default backend it never gives up on a device: if the stream fails, it is
+/// restarted on the next check, falling back to the default device if the
+/// selected one is gone.
+pub struct DeviceBackend {
+    renderer: Option<Arc<Mutex<Renderer>>>,
+    /// `None` to follow the default output device of the system.
+    device_name: Option<String>,
+    sample_rate: u32,
+    stream: Option<OutputStream>,
+    last_check: Instant,
+}
+
+impl DeviceBackend {
+    pub fn set_device(&mut self, device_name: Option<String>) {
+        if self.device_name != device_name {
+            self.device_name = device_name;
+            self.restart_stream();
+        }
+    }
+
+    /// Restart the stream if the device was disconnected or a different device
+    /// should be used. Cheap to call every frame.
+    pub fn check_device(&mut self) {
+        if self.last_check.elapsed() < CHECK_DEVICE_INTERVAL {
+            return;
+        }
+
+        self.last_check = Instant::now();
+
+        let Some(stream) = self.stream.as_ref() else {
+            self.restart_stream();
+            return;
+        };
+
+        if stream.failed.load(Ordering::Relaxed) {
+            self.restart_stream();
+            return;
+        }
+
+        let wanted_device_name = match self.device_name.as_deref() {
+            Some(device_name) if device_name != stream.device_name => {
+                // The selected device might have been reconnected.
+                get_output_device_names().into_iter().find(|name| name == device_name)
+            }
+            Some(_) => None,
+            // Querying the default device while playing causes artifacts on macOS.
+            #[cfg(not(target_os = "macos"))]
+            None => cpal::default_host()
+                .default_output_device()
+                .and_then(|device| device.name().ok())
+                .filter(|name| *name != stream.device_name),
+            #[cfg(target_os = "macos")]
+            None => None,
+        };
+
+        if wanted_device_name.is_some() {
+            self.restart_stream();
+        }
+    }
+
+    fn restart_stream(&mut self) {
+        self.stream = None;
+
+        if let Err(_error) = self.start_stream() {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to start audio stream: {}", "error".red(), _error);
+        }
+    }
+
+    fn start_stream(&mut self) -> Result<(), Error> {
+        let Some(renderer) = self.renderer.clone() else {
+            return Ok(());
+        };
+
+        let (device, config) = find_device(self.device_name.as_deref())?;
+        let device_name = device.name().unwrap_or_default();
+        let sample_rate = config.sample_rate.0;
+
+        if sample_rate != self.sample_rate {
+            self.sample_rate = sample_rate;
+            renderer.lock().unwrap().on_change_sample_rate(sample_rate);
+        }
+
+        let failed = Arc::new(AtomicBool::new(false));
+        let stream_failed = failed.clone();
+        let channels = config.channels as usize;
+
+        let stream = device.build_output_stream(
+            &config,
+            move |data: &mut [f32], _| {
+                // The renderer is only locked elsewhere while the stream is being replaced.
+                let Ok(mut renderer) = renderer.try_lock() else {
+                    data.fill(0.0);
+                    return;
+                };
+
+                renderer.on_start_processing();
+
+                for frame in data.chunks_exact_mut(channels) {
+                    let output = renderer.process();
+
+                    if channels == 1 {
+                        frame[0] = (output.left + output.right) / 2.0;
+                    } else {
+                        frame[0] = output.left;
+                        frame[1] = output.right;
+                        // Additional channels need to be silenced explicitly.
+                        frame[2..].fill(0.0);
+                    }
+                }
+            },
+            move |error| {
+                if matches!(error, StreamError::DeviceNotAvailable) {
+                    stream_failed.store(true, Ordering::Relaxed);
+                }
+            },
+            None,
+        )?;
+
+        stream.play()?;
+
+        #[cfg(feature = "debug")]
+        print_debug!("playing audio on {}", device_name.magenta());
+
+        self.stream = Some(OutputStream {
+            device_name,
+            failed,
+            _stream: stream,
+        });
+
+        Ok(())
+    }
+}
+
+impl Backend for DeviceBackend {
+    type Error = Error;
+    type Settings = Option<String>;
+
+    fn setup(device_name: Self::Settings) -> Result<(Self, u32), Self::Error> {
+        // Without an output device the backend still starts, so audio can resume as
+        // soon as a device is connected.
+        let sample_rate = find_device(device_name.as_deref())
+            .map(|(_, config)| config.sample_rate.0)
+            .unwrap_or(FALLBACK_SAMPLE_RATE);
+
+        let backend = Self {
+            renderer: None,
+            device_name,
+            sample_rate,
+            stream: None,
+            last_check: Instant::now(),
+        };
+
+        Ok((backend, sample_rate))
+    }
+
+    fn start(&mut self, renderer: Renderer) -> Result<(), Self::Error> {
+        self.renderer = Some(Arc::new(Mutex::new(renderer)));
+        // Failing to start the stream is not fatal, since it will be restarted on the
+        // next check.
+        self.restart_stream();
+
+        Ok(())
+    }
+}
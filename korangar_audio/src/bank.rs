@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use rand::Rng;
+
+use crate::{AudioChannel, AudioEngine, SoundEffect, SpatialSettings};
+
+/// Interchangeable samples for one kind of event.
+struct SampleSet {
+    samples: Vec<SoundEffect>,
+    pitch_variation: f32,
+    last_index: Option<usize>,
+}
+
+/// Maps events to sets of samples. Every time an event is played, a random
+/// sample with a slightly randomized pitch is picked, so that repeated events
+/// don't sound identical.
+pub struct SoundBank<Key> {
+    sets: HashMap<Key, SampleSet>,
+}
+
+impl<Key> Default for SoundBank<Key> {
+    fn default() -> Self {
+        Self { sets: HashMap::new() }
+    }
+}
+
+impl<Key: Hash + Eq> SoundBank<Key> {
+    /// Register the samples of an event. The pitch of every sample is varied
+    /// by up to `pitch_variation` in either direction, so `0.1` plays the
+    /// samples between 90% and 110% speed.
+    pub fn insert(&mut self, key: Key, samples: Vec<SoundEffect>, pitch_variation: f32) {
+        if samples.is_empty() {
+            return;
+        }
+
+        self.sets.insert(key, SampleSet {
+            samples,
+            pitch_variation,
+            last_index: None,
+        });
+    }
+
+    /// Play a random sample of an event at a position in the world. Does
+    /// nothing if there are no samples for the event.
+    pub fn play(&mut self, audio_engine: &mut AudioEngine, channel: AudioChannel, key: &Key, spatial: SpatialSettings) {
+        let Some(set) = self.sets.get_mut(key) else {
+            return;
+        };
+
+        let mut rng = rand::thread_rng();
+        let index = pick_index(&mut rng, set.samples.len(), set.last_index);
+        let pitch = pick_pitch(&mut rng, set.pitch_variation);
+
+        set.last_index = Some(index);
+
+        let spatial = SpatialSettings {
+            pitch: spatial.pitch * pitch,
+            ..spatial
+        };
+
+        audio_engine.play_spatial_sound_effect(channel, &set.samples[index], spatial);
+    }
+}
+
+/// Pick a random sample, avoiding the one that was played last if there is
+/// more than one.
+fn pick_index(rng: &mut impl Rng, count: usize, last_index: Option<usize>) -> usize {
+    match last_index {
+        Some(last_index) if count > 1 => {
+            // Pick from all samples but the last one and skip over it.
+            let index = rng.gen_range(0..count - 1);
+            match index >= last_index {
+                true => index + 1,
+                false => index,
+            }
+        }
+        _ => rng.gen_range(0..count),
+    }
+}
+
+fn pick_pitch(rng: &mut impl Rng, pitch_variation: f32) -> f32 {
+    match pitch_variation > 0.0 {
+        true => 1.0 + rng.gen_range(-pitch_variation..=pitch_variation),
+        false => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod pick {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::{pick_index, pick_pitch};
+
+    #[test]
+    fn single_sample() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(pick_index(&mut rng, 1, None), 0);
+        assert_eq!(pick_index(&mut rng, 1, Some(0)), 0);
+    }
+
+    #[test]
+    fn no_repeats() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut last_index = None;
+
+        for _ in 0..1000 {
+            let index = pick_index(&mut rng, 3, last_index);
+
+            assert!(index < 3);
+            assert_ne!(Some(index), last_index);
+
+            last_index = Some(index);
+        }
+    }
+
+    #[test]
+    fn all_samples_used() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut used = [false; 4];
+        let mut last_index = None;
+
+        for _ in 0..1000 {
+            let index = pick_index(&mut rng, 4, last_index);
+            used[index] = true;
+            last_index = Some(index);
+        }
+
+        assert!(used.iter().all(|used| *used));
+    }
+
+    #[test]
+    fn pitch_in_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..1000 {
+            let pitch = pick_pitch(&mut rng, 0.1);
+            assert!((0.9..=1.1).contains(&pitch));
+        }
+    }
+
+    #[test]
+    fn no_pitch_variation() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(pick_pitch(&mut rng, 0.0), 1.0);
+    }
+}
@@ -0,0 +1,237 @@
+use std::io::Cursor;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use cgmath::{InnerSpace, Quaternion, Vector3};
+use kira::manager::AudioManager;
+use kira::sound::static_sound::{StaticSoundData, StaticSoundHandle, StaticSoundSettings};
+use kira::sound::FromFileError;
+use kira::spatial::emitter::{EmitterHandle, EmitterSettings};
+use kira::spatial::listener::{ListenerHandle, ListenerSettings};
+use kira::spatial::scene::{AddEmitterError, SpatialSceneHandle, SpatialSceneSettings};
+use kira::track::TrackHandle;
+use kira::tween::{Easing, Tween};
+
+use crate::backend::DeviceBackend;
+
+/// Maximum number of emitters per channel. Some maps have well over a hundred
+/// sound sources.
+const EMITTER_CAPACITY: usize = 512;
+/// Time it takes for a stopped emitter to fade out.
+const FADE_OUT_DURATION: Duration = Duration::from_millis(500);
+/// Speed of sound in world units per second. A tile is 5 units wide, which
+/// is roughly a meter.
+const SPEED_OF_SOUND: f32 = 1700.0;
+/// Limits of the doppler effect, so that teleporting does not cause extreme
+/// pitch changes.
+const DOPPLER_RANGE: RangeInclusive<f64> = 0.5..=2.0;
+
+/// Decoded sound effect that can be played any number of times.
+#[derive(Clone)]
+pub struct SoundEffect(pub(crate) StaticSoundData);
+
+impl SoundEffect {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, FromFileError> {
+        StaticSoundData::from_cursor(Cursor::new(bytes), StaticSoundSettings::default()).map(Self)
+    }
+}
+
+/// How the volume of an emitter falls off between the emitter and the edge of
+/// its range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AttenuationCurve {
+    /// Same volume everywhere, only the panning changes.
+    Constant,
+    /// Steady fall-off over the whole range.
+    #[default]
+    Linear,
+    /// Quiet for most of the range and loud only close to the emitter.
+    Focused,
+    /// Loud for most of the range and quickly fading out at the edge.
+    Wide,
+}
+
+impl AttenuationCurve {
+    pub(crate) fn easing(self) -> Option<Easing> {
+        match self {
+            AttenuationCurve::Constant => None,
+            AttenuationCurve::Linear => Some(Easing::Linear),
+            AttenuationCurve::Focused => Some(Easing::InPowi(2)),
+            AttenuationCurve::Wide => Some(Easing::OutPowi(2)),
+        }
+    }
+}
+
+/// Where and how a spatial sound is played.
+#[derive(Clone, Copy, Debug)]
+pub struct SpatialSettings {
+    pub position: Vector3<f32>,
+    /// Velocity in world units per second, used for the doppler effect.
+    pub velocity: Vector3<f32>,
+    pub volume: f32,
+    /// Playback rate of the sound, `1.0` plays it unchanged.
+    pub pitch: f32,
+    /// Distance at which the sound becomes inaudible.
+    pub range: f32,
+    pub attenuation: AttenuationCurve,
+}
+
+/// Looping sound at a position in the world. The sound fades out when the
+/// emitter is dropped.
+pub struct SoundEmitter {
+    sound: StaticSoundHandle,
+    emitter: EmitterHandle,
+    pitch: f32,
+}
+
+impl SoundEmitter {
+    pub(crate) fn new(sound: StaticSoundHandle, emitter: EmitterHandle, pitch: f32) -> Self {
+        Self { sound, emitter, pitch }
+    }
+
+    pub(crate) fn update(&mut self, position: Vector3<f32>, doppler: f64) {
+        let playback_rate = doppler * self.pitch as f64;
+
+        let _ = self.emitter.set_position(to_array(position), Tween::default());
+        let _ = self.sound.set_playback_rate(playback_rate, Tween::default());
+    }
+}
+
+impl Drop for SoundEmitter {
+    fn drop(&mut self) {
+        let tween = Tween {
+            duration: FADE_OUT_DURATION,
+            ..Default::default()
+        };
+
+        // The emitter is only removed once the sound has finished fading out.
+        let _ = self.sound.stop(tween);
+    }
+}
+
+/// Spatial scene with a single listener, mixed into one channel.
+pub(crate) struct SpatialChannel {
+    scene: SpatialSceneHandle,
+    listener: ListenerHandle,
+}
+
+impl SpatialChannel {
+    pub fn new(manager: &mut AudioManager<DeviceBackend>, track: &TrackHandle) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut scene = manager.add_spatial_scene(SpatialSceneSettings::new().emitter_capacity(EMITTER_CAPACITY))?;
+        let listener = scene.add_listener([0.0; 3], [0.0, 0.0, 0.0, 1.0], ListenerSettings::new().track(track))?;
+
+        Ok(Self { scene, listener })
+    }
+
+    pub fn set_listener(&mut self, position: Vector3<f32>, orientation: Quaternion<f32>) {
+        let orientation = [orientation.v.x, orientation.v.y, orientation.v.z, orientation.s];
+
+        let _ = self.listener.set_position(to_array(position), Tween::default());
+        let _ = self.listener.set_orientation(orientation, Tween::default());
+    }
+
+    pub fn add_emitter(
+        &mut self,
+        position: Vector3<f32>,
+        range: f32,
+        attenuation: AttenuationCurve,
+    ) -> Result<EmitterHandle, AddEmitterError> {
+        let settings = EmitterSettings::new()
+            .distances((0.0, range.max(1.0)))
+            .attenuation_function(attenuation.easing())
+            .persist_until_sounds_finish(true);
+
+        self.scene.add_emitter(to_array(position), settings)
+    }
+}
+
+fn to_array(vector: Vector3<f32>) -> [f32; 3] {
+    [vector.x, vector.y, vector.z]
+}
+
+/// Factor by which the pitch of a sound changes because the source and the
+/// listener move towards or away from each other.
+pub(crate) fn doppler_factor(
+    source_position: Vector3<f32>,
+    source_velocity: Vector3<f32>,
+    listener_position: Vector3<f32>,
+    listener_velocity: Vector3<f32>,
+) -> f64 {
+    let offset = listener_position - source_position;
+    let distance = offset.magnitude();
+
+    if distance < f32::EPSILON {
+        return 1.0;
+    }
+
+    let direction = offset / distance;
+    // Positive if the source moves towards the listener.
+    let source_speed = source_velocity.dot(direction);
+    // Positive if the listener moves towards the source.
+    let listener_speed = -listener_velocity.dot(direction);
+
+    let factor = (SPEED_OF_SOUND + listener_speed) / (SPEED_OF_SOUND - source_speed).max(f32::EPSILON);
+    (factor as f64).clamp(*DOPPLER_RANGE.start(), *DOPPLER_RANGE.end())
+}
+
+#[cfg(test)]
+mod doppler {
+    use cgmath::{Vector3, Zero};
+
+    use super::doppler_factor;
+
+    #[test]
+    fn stationary() {
+        let factor = doppler_factor(Vector3::zero(), Vector3::zero(), Vector3::new(50.0, 0.0, 0.0), Vector3::zero());
+
+        assert_eq!(factor, 1.0);
+    }
+
+    #[test]
+    fn source_approaching() {
+        let factor = doppler_factor(
+            Vector3::zero(),
+            Vector3::new(100.0, 0.0, 0.0),
+            Vector3::new(50.0, 0.0, 0.0),
+            Vector3::zero(),
+        );
+
+        assert!(factor > 1.0);
+    }
+
+    #[test]
+    fn source_receding() {
+        let factor = doppler_factor(
+            Vector3::zero(),
+            Vector3::new(-100.0, 0.0, 0.0),
+            Vector3::new(50.0, 0.0, 0.0),
+            Vector3::zero(),
+        );
+
+        assert!(factor < 1.0);
+    }
+
+    #[test]
+    fn listener_approaching() {
+        let factor = doppler_factor(
+            Vector3::zero(),
+            Vector3::zero(),
+            Vector3::new(50.0, 0.0, 0.0),
+            Vector3::new(-100.0, 0.0, 0.0),
+        );
+
+        assert!(factor > 1.0);
+    }
+
+    #[test]
+    fn clamped() {
+        let factor = doppler_factor(
+            Vector3::zero(),
+            Vector3::new(100000.0, 0.0, 0.0),
+            Vector3::new(50.0, 0.0, 0.0),
+            Vector3::zero(),
+        );
+
+        assert_eq!(factor, 2.0);
+    }
+}
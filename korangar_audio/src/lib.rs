@@ -0,0 +1,436 @@
+mod backend;
+mod bank;
+mod emitter;
+mod table;
+
+use std::error::Error;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::Duration;
+
+use cgmath::{Quaternion, Vector3, Zero};
+use kira::manager::{AudioManager, AudioManagerSettings, Capacities};
+use kira::sound::static_sound::{StaticSoundHandle, StaticSoundSettings};
+use kira::sound::streaming::{StreamingSoundData, StreamingSoundHandle, StreamingSoundSettings};
+use kira::sound::FromFileError;
+use kira::spatial::emitter::EmitterHandle;
+use kira::track::{TrackBuilder, TrackHandle};
+use kira::tween::Tween;
+use kira::{CommandError, Volume};
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{print_debug, Colorize};
+
+pub use self::backend::get_output_device_names;
+use self::backend::DeviceBackend;
+pub use self::bank::SoundBank;
+use self::emitter::{doppler_factor, SpatialChannel};
+pub use self::emitter::{AttenuationCurve, SoundEffect, SoundEmitter, SpatialSettings};
+pub use self::table::BackgroundMusicTable;
+
+/// Time it takes to fade from one background music track to the next.
+const CROSSFADE_DURATION: Duration = Duration::from_secs(2);
+/// Time it takes to lower or restore the volume when the window loses or gains
+/// focus.
+const FOCUS_FADE_DURATION: Duration = Duration::from_millis(500);
+/// Maximum number of sounds that can play at the same time.
+const SOUND_CAPACITY: usize = 1024;
+
+/// Volume channels that can be adjusted independently. Every channel except
+/// [`Master`](AudioChannel::Master) is mixed into the master channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioChannel {
+    Master,
+    BackgroundMusic,
+    Effects,
+    Ambient,
+    Interface,
+}
+
+enum BackgroundMusicState {
+    Loading(Receiver<Result<StreamingSoundData<FromFileError>, FromFileError>>),
+    Playing(StreamingSoundHandle<FromFileError>),
+}
+
+struct BackgroundMusic {
+    track: String,
+    state: BackgroundMusicState,
+}
+
+struct Mixer {
+    manager: AudioManager<DeviceBackend>,
+    background_music_track: TrackHandle,
+    effects_track: TrackHandle,
+    ambient_track: TrackHandle,
+    interface_track: TrackHandle,
+    effects_spatial: SpatialChannel,
+    ambient_spatial: SpatialChannel,
+}
+
+impl Mixer {
+    fn new() -> Result<Self, Box<dyn Error>> {
+        let settings = AudioManagerSettings {
+            capacities: Capacities {
+                sound_capacity: SOUND_CAPACITY,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut manager = AudioManager::<DeviceBackend>::new(settings)?;
+
+        let background_music_track = manager.add_sub_track(TrackBuilder::new())?;
+        let effects_track = manager.add_sub_track(TrackBuilder::new())?;
+        let ambient_track = manager.add_sub_track(TrackBuilder::new())?;
+        let interface_track = manager.add_sub_track(TrackBuilder::new())?;
+        let effects_spatial = SpatialChannel::new(&mut manager, &effects_track)?;
+        let ambient_spatial = SpatialChannel::new(&mut manager, &ambient_track)?;
+
+        Ok(Self {
+            manager,
+            background_music_track,
+            effects_track,
+            ambient_track,
+            interface_track,
+            effects_spatial,
+            ambient_spatial,
+        })
+    }
+
+    fn get_spatial_channel(&mut self, channel: AudioChannel) -> Option<&mut SpatialChannel> {
+        match channel {
+            AudioChannel::Effects => Some(&mut self.effects_spatial),
+            AudioChannel::Ambient => Some(&mut self.ambient_spatial),
+            _ => None,
+        }
+    }
+
+    fn set_volume(&self, channel: AudioChannel, volume: Volume, tween: Tween) -> Result<(), CommandError> {
+        match channel {
+            AudioChannel::Master => self.manager.main_track().set_volume(volume, tween),
+            AudioChannel::BackgroundMusic => self.background_music_track.set_volume(volume, tween),
+            AudioChannel::Effects => self.effects_track.set_volume(volume, tween),
+            AudioChannel::Ambient => self.ambient_track.set_volume(volume, tween),
+            AudioChannel::Interface => self.interface_track.set_volume(volume, tween),
+        }
+    }
+}
+
+pub struct AudioEngine {
+    /// `None` if there is no audio device, in which case nothing is played.
+    mixer: Option<Mixer>,
+    background_music: Option<BackgroundMusic>,
+    master_volume: f32,
+    /// Volume relative to the master volume while the window is unfocused.
+    /// `None` if the volume should not change.
+    unfocused_volume: Option<f32>,
+    focused: bool,
+    listener_position: Vector3<f32>,
+    listener_velocity: Vector3<f32>,
+    doppler_effect: bool,
+}
+
+impl Default for AudioEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioEngine {
+    pub fn new() -> Self {
+        let mixer = match Mixer::new() {
+            Ok(mixer) => Some(mixer),
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("[{}] failed to create audio mixer: {:?}", "error".red(), _error);
+
+                None
+            }
+        };
+
+        Self {
+            mixer,
+            background_music: None,
+            master_volume: 1.0,
+            unfocused_volume: None,
+            focused: true,
+            listener_position: Vector3::zero(),
+            listener_velocity: Vector3::zero(),
+            doppler_effect: false,
+        }
+    }
+
+    fn apply_volume(&self, channel: AudioChannel, volume: f32, tween: Tween) {
+        let Some(mixer) = self.mixer.as_ref() else {
+            return;
+        };
+
+        if let Err(_error) = mixer.set_volume(channel, Volume::Amplitude(volume as f64), tween) {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to set volume of {:?}: {:?}", "error".red(), channel, _error);
+        }
+    }
+
+    /// The focus gain is applied as part of the master volume, so that it
+    /// affects every channel.
+    fn apply_master_volume(&self, tween: Tween) {
+        let focus_gain = match self.focused {
+            true => 1.0,
+            false => self.unfocused_volume.unwrap_or(1.0),
+        };
+
+        self.apply_volume(AudioChannel::Master, self.master_volume * focus_gain, tween);
+    }
+
+    /// Set the volume of a channel, where `0.0` is silent and `1.0` is the
+    /// original volume. The volume is applied to all sounds on the
+    /// channel, including the ones that are already playing.
+    pub fn set_volume(&mut self, channel: AudioChannel, volume: f32) {
+        let volume = volume.clamp(0.0, 1.0);
+
+        match channel {
+            AudioChannel::Master => {
+                self.master_volume = volume;
+                self.apply_master_volume(Tween::default());
+            }
+            channel => self.apply_volume(channel, volume, Tween::default()),
+        }
+    }
+
+    /// Set the volume relative to the master volume that is used while the
+    /// window is unfocused, where `0.0` mutes all audio. `None` keeps playing
+    /// at full volume.
+    pub fn set_unfocused_volume(&mut self, volume: Option<f32>) {
+        self.unfocused_volume = volume.map(|volume| volume.clamp(0.0, 1.0));
+        self.apply_master_volume(Tween::default());
+    }
+
+    /// Fade the volume to or from the unfocused volume.
+    pub fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+
+        let tween = Tween {
+            duration: FOCUS_FADE_DURATION,
+            ..Default::default()
+        };
+
+        self.apply_master_volume(tween);
+    }
+
+    /// Select the output device by name. `None` follows the default output
+    /// device of the system.
+    pub fn set_output_device(&mut self, device_name: Option<String>) {
+        if let Some(mixer) = self.mixer.as_mut() {
+            mixer.manager.backend_mut().set_device(device_name);
+        }
+    }
+
+    /// Recover from disconnected output devices and changes of the default
+    /// device and start background music that finished loading. Should be
+    /// called every frame.
+    pub fn update(&mut self) {
+        if let Some(mixer) = self.mixer.as_mut() {
+            mixer.manager.backend_mut().check_device();
+        }
+
+        self.update_background_music();
+    }
+
+    /// Move the listener for all spatial sounds, usually to the player with the
+    /// orientation of the camera. The orientation determines which speaker a
+    /// sound comes from, the velocity is used for the doppler effect.
+    pub fn set_listener(&mut self, position: Vector3<f32>, orientation: Quaternion<f32>, velocity: Vector3<f32>) {
+        self.listener_position = position;
+        self.listener_velocity = velocity;
+
+        if let Some(mixer) = self.mixer.as_mut() {
+            mixer.effects_spatial.set_listener(position, orientation);
+            mixer.ambient_spatial.set_listener(position, orientation);
+        }
+    }
+
+    /// Shift the pitch of moving sounds depending on their speed relative to
+    /// the listener.
+    pub fn set_doppler_effect(&mut self, enabled: bool) {
+        self.doppler_effect = enabled;
+    }
+
+    fn get_doppler_factor(&self, position: Vector3<f32>, velocity: Vector3<f32>) -> f64 {
+        match self.doppler_effect {
+            true => doppler_factor(position, velocity, self.listener_position, self.listener_velocity),
+            false => 1.0,
+        }
+    }
+
+    /// Start looping a sound effect at a position in the world. Only
+    /// [`Effects`](AudioChannel::Effects) and
+    /// [`Ambient`](AudioChannel::Ambient) support spatial sounds.
+    pub fn start_emitter(&mut self, channel: AudioChannel, sound_effect: &SoundEffect, spatial: SpatialSettings) -> Option<SoundEmitter> {
+        let settings = StaticSoundSettings::new().loop_region(..);
+        let (sound, emitter) = self.play_spatial(channel, sound_effect, spatial, settings)?;
+
+        Some(SoundEmitter::new(sound, emitter, spatial.pitch))
+    }
+
+    /// Move an emitter and update its doppler effect. Should be called every
+    /// frame for emitters attached to moving entities.
+    pub fn update_emitter(&self, emitter: &mut SoundEmitter, position: Vector3<f32>, velocity: Vector3<f32>) {
+        let factor = self.get_doppler_factor(position, velocity);
+        emitter.update(position, factor);
+    }
+
+    /// Play a sound effect once at a position in the world.
+    pub fn play_spatial_sound_effect(&mut self, channel: AudioChannel, sound_effect: &SoundEffect, spatial: SpatialSettings) {
+        // Dropping the handles right away is fine, since the emitter persists until
+        // the sound is finished.
+        let _ = self.play_spatial(channel, sound_effect, spatial, StaticSoundSettings::new());
+    }
+
+    fn play_spatial(
+        &mut self,
+        channel: AudioChannel,
+        sound_effect: &SoundEffect,
+        spatial: SpatialSettings,
+        settings: StaticSoundSettings,
+    ) -> Option<(StaticSoundHandle, EmitterHandle)> {
+        let playback_rate = self.get_doppler_factor(spatial.position, spatial.velocity) * spatial.pitch as f64;
+        let mixer = self.mixer.as_mut()?;
+
+        let Some(spatial_channel) = mixer.get_spatial_channel(channel) else {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] {:?} does not support spatial sounds", "error".red(), channel);
+
+            return None;
+        };
+
+        let emitter = match spatial_channel.add_emitter(spatial.position, spatial.range, spatial.attenuation) {
+            Ok(emitter) => emitter,
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("[{}] failed to add emitter: {:?}", "error".red(), _error);
+
+                return None;
+            }
+        };
+
+        let settings = settings
+            .volume(Volume::Amplitude(spatial.volume.clamp(0.0, 1.0) as f64))
+            .playback_rate(playback_rate)
+            .output_destination(&emitter);
+
+        match mixer.manager.play(sound_effect.0.with_settings(settings)) {
+            Ok(sound) => Some((sound, emitter)),
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("[{}] failed to play sound effect: {:?}", "error".red(), _error);
+
+                None
+            }
+        }
+    }
+
+    /// Path of the background music track that is currently playing.
+    pub fn get_background_music_track(&self) -> Option<&str> {
+        self.background_music
+            .as_ref()
+            .map(|background_music| background_music.track.as_str())
+    }
+
+    /// Crossfade from the current background music to a new track, which is
+    /// looped until the track changes again. Passing the track that is already
+    /// playing does nothing, passing `None` fades out the current track.
+    ///
+    /// The file is opened on a background thread and decoded in chunks while
+    /// playing, so long tracks neither block the caller nor need to be held in
+    /// memory. The new track starts in [`update`](Self::update) once it is
+    /// ready.
+    pub fn play_background_music_track(&mut self, track: Option<&str>) {
+        if self.get_background_music_track() == track {
+            return;
+        }
+
+        let tween = Tween {
+            duration: CROSSFADE_DURATION,
+            ..Default::default()
+        };
+
+        if let Some(BackgroundMusic {
+            state: BackgroundMusicState::Playing(mut handle),
+            ..
+        }) = self.background_music.take()
+        {
+            let _ = handle.stop(tween);
+        }
+
+        let (Some(mixer), Some(track)) = (self.mixer.as_mut(), track) else {
+            return;
+        };
+
+        let settings = StreamingSoundSettings::new()
+            .loop_region(..)
+            .fade_in_tween(tween)
+            .output_destination(&mixer.background_music_track);
+
+        let (sender, receiver) = mpsc::channel();
+        let path = track.to_owned();
+
+        // If the track changes before loading finished, the receiver is dropped and
+        // the result is discarded.
+        std::thread::spawn(move || {
+            let _ = sender.send(StreamingSoundData::from_file(path, settings));
+        });
+
+        self.background_music = Some(BackgroundMusic {
+            track: track.to_owned(),
+            state: BackgroundMusicState::Loading(receiver),
+        });
+    }
+
+    fn update_background_music(&mut self) {
+        let (Some(mixer), Some(background_music)) = (self.mixer.as_mut(), self.background_music.as_mut()) else {
+            return;
+        };
+
+        let BackgroundMusicState::Loading(receiver) = &background_music.state else {
+            return;
+        };
+
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                self.background_music = None;
+                return;
+            }
+        };
+
+        match result {
+            Ok(sound_data) => match mixer.manager.play(sound_data) {
+                Ok(handle) => background_music.state = BackgroundMusicState::Playing(handle),
+                Err(_error) => {
+                    #[cfg(feature = "debug")]
+                    print_debug!(
+                        "[{}] failed to play {}: {:?}",
+                        "error".red(),
+                        background_music.track.magenta(),
+                        _error
+                    );
+
+                    self.background_music = None;
+                }
+            },
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] failed to load {}: {:?}",
+                    "error".red(),
+                    background_music.track.magenta(),
+                    _error
+                );
+
+                self.background_music = None;
+            }
+        }
+    }
+}
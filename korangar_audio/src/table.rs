@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+/// Mapping from map names to their background music, read from the
+/// `mp3nametable.txt` of the game files.
+#[derive(Default)]
+pub struct BackgroundMusicTable {
+    tracks: HashMap<String, String>,
+}
+
+impl BackgroundMusicTable {
+    /// Parse the table. Every line has the form `map.rsw#bgm\\08.mp3#`, lines
+    /// starting with `//` are comments.
+    pub fn parse(bytes: &[u8]) -> Self {
+        let tracks = String::from_utf8_lossy(bytes)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with("//"))
+            .filter_map(|line| {
+                let mut fields = line.split('#');
+                let map_name = fields.next()?.trim();
+                let track = fields.next()?.trim();
+
+                if map_name.is_empty() || track.is_empty() {
+                    return None;
+                }
+
+                let map_name = map_name.to_lowercase();
+                let map_name = map_name.strip_suffix(".rsw").unwrap_or(&map_name).to_owned();
+                // Some entries escape the path separator and some don't.
+                let track = track.replace("\\\\", "/").replace('\\', "/");
+
+                Some((map_name, track))
+            })
+            .collect();
+
+        Self { tracks }
+    }
+
+    /// Path of the background music of a map, relative to the client folder.
+    pub fn get(&self, map_name: &str) -> Option<&str> {
+        self.tracks.get(&map_name.to_lowercase()).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use super::BackgroundMusicTable;
+
+    #[test]
+    fn entries() {
+        let table = BackgroundMusicTable::parse(b"prontera.rsw#bgm\\\\08.mp3#\r\ngeffen.rsw#bgm\\13.mp3#\n");
+
+        assert_eq!(table.get("prontera"), Some("bgm/08.mp3"));
+        assert_eq!(table.get("geffen"), Some("bgm/13.mp3"));
+        assert_eq!(table.get("payon"), None);
+    }
+
+    #[test]
+    fn comments_and_invalid_lines() {
+        let table = BackgroundMusicTable::parse(b"// comment\n\nbroken line\nmorocc.rsw##\nizlude.rsw#bgm\\\\26.mp3#\n");
+
+        assert_eq!(table.get("morocc"), None);
+        assert_eq!(table.get("izlude"), Some("bgm/26.mp3"));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let table = BackgroundMusicTable::parse(b"Prontera.RSW#bgm\\\\08.mp3#\n");
+
+        assert_eq!(table.get("prontera"), Some("bgm/08.mp3"));
+    }
+}
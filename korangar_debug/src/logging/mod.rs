@@ -6,5 +6,5 @@ mod print;
 mod timer;
 
 pub use self::colors::{Colorize, Colorized};
-pub use self::print::{print_debug, print_indented};
+pub use self::print::{print_debug, print_indented, set_logging_enabled};
 pub use self::timer::Timer;
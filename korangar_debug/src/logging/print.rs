@@ -1,6 +1,20 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use crate::logging::stack::{get_message_count, increment_message_count, message_offset, stack_size};
 use crate::logging::symbols::{ARROW, NEWLINE};
 
+static LOGGING_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable all debug output, for example from a command line
+/// argument.
+pub fn set_logging_enabled(enabled: bool) {
+    LOGGING_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub(crate) fn logging_enabled() -> bool {
+    LOGGING_ENABLED.load(Ordering::Relaxed)
+}
+
 pub macro print_debug {
     ($format:expr) => (print_indented(String::from($format), true)),
     ($format:expr, $($arguments:tt)*) => (print_indented(format!($format, $($arguments)*), true)),
@@ -11,7 +25,18 @@ pub(crate) macro print_debug_prefix {
     ($format:expr, $($arguments:tt)*) => (print_indented(format!($format, $($arguments)*), false)),
 }
 
+/// Like `println`, but respects [`set_logging_enabled`].
+pub(crate) macro print_line($($arguments:tt)*) {
+    if logging_enabled() {
+        println!($($arguments)*);
+    }
+}
+
 pub fn print_indented(message: String, newline: bool) {
+    if !logging_enabled() {
+        return;
+    }
+
     let offset = message_offset();
 
     if stack_size() > 0 {
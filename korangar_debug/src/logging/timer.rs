@@ -1,7 +1,7 @@
 use std::time::SystemTime;
 
 use super::stack::{get_message_count, increment_stack, stack_size};
-use crate::logging::print::print_debug_prefix;
+use crate::logging::print::{print_debug_prefix, print_line};
 use crate::logging::stack::decrement_stack;
 use crate::logging::symbols::ARROW;
 use crate::logging::{print_debug, Colorize};
@@ -40,7 +40,7 @@ impl Timer {
     pub fn stop(mut self) {
         if stack_size() > 0 && get_message_count() == 0 {
             decrement_stack();
-            println!(" ({})", format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan());
+            print_line!(" ({})", format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan());
         } else {
             decrement_stack();
             print_debug!(
@@ -53,7 +53,7 @@ impl Timer {
         }
 
         if stack_size() == 0 {
-            println!();
+            print_line!();
         }
 
         self.completed = true;
@@ -65,7 +65,7 @@ impl Drop for Timer {
         if !self.completed {
             if stack_size() > 0 && get_message_count() == 0 {
                 decrement_stack();
-                println!(" ({})", format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan());
+                print_line!(" ({})", format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan());
             } else {
                 decrement_stack();
                 print_debug!(
@@ -78,7 +78,7 @@ impl Drop for Timer {
             }
 
             if stack_size() == 0 {
-                println!();
+                print_line!();
             }
         }
     }
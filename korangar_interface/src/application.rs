@@ -2,7 +2,7 @@ use std::rc::{Rc, Weak};
 
 use crate::elements::{Element, ElementCell, WeakElementCell};
 use crate::theme::InterfaceTheme;
-use crate::windows::Anchor;
+use crate::windows::{Anchor, WindowSettings};
 
 pub trait Application: Sized + 'static {
     type ThemeKind: Default;
@@ -25,6 +25,10 @@ pub trait Application: Sized + 'static {
 
     fn get_scaling(&self) -> Self::Scaling;
 
+    /// Size of the grid that windows snap to while they are being moved, before
+    /// scaling. Zero disables grid snapping.
+    fn get_window_grid(&self) -> f32;
+
     fn get_theme(&self, kind: &Self::ThemeKind) -> &Self::Theme;
 }
 
@@ -37,6 +41,9 @@ where
     fn is_self_dragged(&self, element: &dyn Element<App>) -> bool;
 
     fn is_moving_window(&self, window_index: usize) -> bool;
+
+    /// The resource that is currently being dragged by the mouse, if any.
+    fn dragged_resource(&self) -> Option<&App::DropResource>;
 }
 
 pub trait FontSizeTrait: Copy {
@@ -116,10 +123,16 @@ where
         color: App::Color,
         expanded: bool,
     );
+
+    /// Reset an area of the render target to the clear color, so that it can
+    /// be rendered again without re-rendering the entire interface.
+    fn clear_area(&self, render_target: &mut Self::Target, position: App::Position, size: App::Size);
 }
 
 pub trait ColorTrait: Clone {
     fn is_transparent(&self) -> bool;
+
+    fn with_opacity(self, opacity: f32) -> Self;
 }
 
 pub trait CornerRadiusTrait: Clone {
@@ -401,6 +414,10 @@ where
     fn update_size(&mut self, window_class: &str, size: App::Size);
 
     fn get_window_state(&self, window_class: &str) -> Option<(Anchor<App>, App::Size)>;
+
+    fn update_settings(&mut self, window_class: &str, settings: WindowSettings);
+
+    fn get_window_settings(&self, window_class: &str) -> Option<WindowSettings>;
 }
 
 pub struct FocusState<App>
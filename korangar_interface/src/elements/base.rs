@@ -2,11 +2,12 @@ use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 
 use crate::application::{
-    Application, ClipTrait, CornerRadiusTraitExt, FontSizeTraitExt, InterfaceRenderer, PartialSizeTraitExt, PositionTrait,
-    PositionTraitExt, SizeTrait, SizeTraitExt,
+    Application, ClipTrait, CornerRadiusTraitExt, FontSizeTraitExt, InterfaceRenderer, MouseInputModeTrait, PartialSizeTraitExt,
+    PositionTrait, PositionTraitExt, SizeTrait, SizeTraitExt,
 };
-use crate::event::{ChangeEvent, ClickAction, HoverInformation};
+use crate::event::{ChangeEvent, ClickAction, HoverInformation, TextEdit};
 use crate::layout::{PlacementResolver, SizeBound};
+use crate::tooltip::Tooltip;
 
 pub type ElementCell<App> = Rc<RefCell<dyn Element<App>>>;
 pub type WeakElementCell<App> = Weak<RefCell<dyn Element<App>>>;
@@ -146,6 +147,8 @@ where
     pub self_element: Option<WeakElementCell<App>>,
     pub parent_element: Option<WeakElementCell<App>>,
     pub mouse_position: Cell<App::Position>,
+    /// Text that is shown when hovering the element for a while.
+    pub tooltip: Option<String>,
 }
 
 impl<App> Default for ElementState<App>
@@ -159,6 +162,7 @@ where
             self_element: None,
             parent_element: None,
             mouse_position: Cell::new(App::Position::zero()),
+            tooltip: None,
         }
     }
 }
@@ -325,6 +329,51 @@ where
         (false, Vec::new())
     }
 
+    /// Display text that is being composed with an input method. An empty text
+    /// ends the composition.
+    fn input_preedit(&mut self, _text: &str) -> Option<ChangeEvent> {
+        None
+    }
+
+    fn input_text_edit(&mut self, _edit: TextEdit) -> Option<ChangeEvent> {
+        None
+    }
+
+    /// Text that should be put into the clipboard when the user copies from
+    /// this element.
+    fn copy_text(&self) -> Option<String> {
+        None
+    }
+
+    /// Text that should be inserted into the chat when the user shift-clicks
+    /// this element.
+    fn link_text(&self) -> Option<String> {
+        None
+    }
+
+    /// Tooltip that is shown when hovering the element for a while. Text
+    /// tooltips can be added to any element with
+    /// [`with_tooltip`](crate::tooltip::WithTooltip::with_tooltip).
+    fn tooltip(&self) -> Option<Tooltip<App>> {
+        self.get_state().tooltip.clone().map(Tooltip::Text)
+    }
+
+    /// Whether or not a dragged resource can be dropped onto this element.
+    /// Elements that accept a resource are highlighted while it is being
+    /// dragged.
+    fn accepts_drop(&self, drop_resource: &App::DropResource) -> bool {
+        let _ = drop_resource;
+        false
+    }
+
+    /// Whether or not this element should be highlighted as a drop target for
+    /// the resource that is currently being dragged.
+    fn is_drop_target(&self, mouse_mode: &App::MouseInputMode) -> bool {
+        mouse_mode
+            .dragged_resource()
+            .is_some_and(|drop_resource| self.accepts_drop(drop_resource))
+    }
+
     fn drop_resource(&mut self, drop_resource: App::DropResource) -> Option<App::DropResult> {
         let _ = drop_resource;
         None
@@ -1,56 +1,90 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
 use super::DragButton;
-use crate::application::Application;
+use crate::application::{Application, PositionTraitExt, SizeTraitExt};
 use crate::builder::Unset;
 use crate::layout::DimensionBound;
+use crate::windows::WindowSettings;
 
 /// Type state [`DragButton`] builder. This builder utilizes the type system to
 /// prevent calling the same method multiple times and calling
 /// [`build`](Self::build) before the mandatory methods have been called.
 #[must_use = "`build` needs to be called"]
-pub struct DragButtonBuilder<Title, Width> {
+pub struct DragButtonBuilder<Title, Width, Settings> {
     title: Title,
     width_bound: Width,
+    window_settings: Settings,
 }
 
-impl DragButtonBuilder<Unset, Unset> {
+impl DragButtonBuilder<Unset, Unset, Unset> {
     pub fn new() -> Self {
         Self {
             title: Unset,
             width_bound: Unset,
+            window_settings: Unset,
         }
     }
 }
 
-impl<Width> DragButtonBuilder<Unset, Width> {
-    pub fn with_title(self, title: impl Into<String>) -> DragButtonBuilder<String, Width> {
+impl<Width, Settings> DragButtonBuilder<Unset, Width, Settings> {
+    pub fn with_title(self, title: impl Into<String>) -> DragButtonBuilder<String, Width, Settings> {
         DragButtonBuilder {
             title: title.into(),
-            ..self
+            width_bound: self.width_bound,
+            window_settings: self.window_settings,
         }
     }
 }
 
-impl<Title> DragButtonBuilder<Title, Unset> {
-    pub fn with_width_bound(self, width_bound: DimensionBound) -> DragButtonBuilder<Title, DimensionBound> {
-        DragButtonBuilder { width_bound, ..self }
+impl<Title, Settings> DragButtonBuilder<Title, Unset, Settings> {
+    pub fn with_width_bound(self, width_bound: DimensionBound) -> DragButtonBuilder<Title, DimensionBound, Settings> {
+        DragButtonBuilder {
+            title: self.title,
+            width_bound,
+            window_settings: self.window_settings,
+        }
     }
 }
 
-impl DragButtonBuilder<String, DimensionBound> {
+impl<Title, Width> DragButtonBuilder<Title, Width, Unset> {
+    /// Settings of the window, used to display the current state in the menu
+    /// of the title bar.
+    pub fn with_window_settings(
+        self,
+        window_settings: Rc<Cell<WindowSettings>>,
+    ) -> DragButtonBuilder<Title, Width, Rc<Cell<WindowSettings>>> {
+        DragButtonBuilder {
+            title: self.title,
+            width_bound: self.width_bound,
+            window_settings,
+        }
+    }
+}
+
+impl DragButtonBuilder<String, DimensionBound, Rc<Cell<WindowSettings>>> {
     /// Take the builder and turn it into a [`DragButton`].
     ///
-    /// NOTE: This method is only available if [`with_title`](Self::with_title)
-    /// and [`with_width_bound`](Self::with_width_bound) have been called on
-    /// the builder.
+    /// NOTE: This method is only available if [`with_title`](Self::with_title),
+    /// [`with_width_bound`](Self::with_width_bound) and
+    /// [`with_window_settings`](Self::with_window_settings) have been called
+    /// on the builder.
     pub fn build<App>(self) -> DragButton<App>
     where
         App: Application,
     {
-        let Self { title, width_bound } = self;
+        let Self {
+            title,
+            width_bound,
+            window_settings,
+        } = self;
 
         DragButton {
             title,
             width_bound,
+            window_settings,
+            latest_position: Rc::new(RefCell::new(App::Position::zero())),
+            latest_size: Rc::new(RefCell::new(App::Size::zero())),
             state: Default::default(),
         }
     }
@@ -1,11 +1,18 @@
 mod builder;
 
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
 pub use self::builder::DragButtonBuilder;
 use crate::application::{Application, InterfaceRenderer, MouseInputModeTrait};
-use crate::elements::{Element, ElementState};
+use crate::elements::{ButtonBuilder, Element, ElementState, ElementWrap, ScrollView};
 use crate::event::{ClickAction, HoverInformation};
-use crate::layout::{DimensionBound, PlacementResolver};
-use crate::theme::{InterfaceTheme, WindowTheme};
+use crate::layout::{Dimension, DimensionBound, PlacementResolver, SizeBound};
+use crate::theme::{ButtonTheme, InterfaceTheme, WindowTheme};
+use crate::windows::WindowSettings;
+
+/// Background opacities that can be selected from the menu of the title bar.
+const WINDOW_OPACITIES: [f32; 4] = [1.0, 0.75, 0.5, 0.25];
 
 pub struct DragButton<App>
 where
@@ -13,6 +20,9 @@ where
 {
     title: String,
     width_bound: DimensionBound,
+    window_settings: Rc<Cell<WindowSettings>>,
+    latest_position: Rc<RefCell<App::Position>>,
+    latest_size: Rc<RefCell<App::Size>>,
     state: ElementState<App>,
 }
 
@@ -32,6 +42,8 @@ where
         let size_bound = self.width_bound.add_height(theme.window().title_height());
 
         self.state.resolve(placement_resolver, &size_bound);
+
+        *self.latest_size.borrow_mut() = self.state.cached_size;
     }
 
     fn is_focusable(&self) -> bool {
@@ -52,6 +64,53 @@ where
         vec![ClickAction::MoveInterface]
     }
 
+    fn right_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<App>> {
+        let position_tracker = {
+            let latest_position = Rc::downgrade(&self.latest_position);
+            move || latest_position.upgrade().map(|position| *position.borrow())
+        };
+
+        let size_tracker = {
+            let latest_size = Rc::downgrade(&self.latest_size);
+            move || latest_size.upgrade().map(|size| *size.borrow())
+        };
+
+        let lock_text = match self.window_settings.get().locked {
+            true => "Unlock position",
+            false => "Lock position",
+        };
+
+        let mut options = vec![ButtonBuilder::new()
+            .with_text(lock_text)
+            .with_event(|| vec![ClickAction::ClosePopup, ClickAction::ToggleWindowLock])
+            .build()
+            .wrap()];
+
+        options.extend(WINDOW_OPACITIES.into_iter().map(|opacity| {
+            ButtonBuilder::new()
+                .with_text(format!("Opacity {}%", (opacity * 100.0) as usize))
+                .with_event(move || vec![ClickAction::ClosePopup, ClickAction::SetWindowOpacity(opacity)])
+                .build()
+                .wrap()
+        }));
+
+        let size_bound = SizeBound {
+            minimum_height: Some(Dimension::Super),
+            maximum_height: Some(Dimension::Super),
+            ..SizeBound::only_height(Dimension::Flexible)
+        };
+
+        let element = ScrollView::new(options, size_bound)
+            .with_background_color(|theme: &App::Theme| theme.button().background_color())
+            .wrap();
+
+        vec![ClickAction::OpenPopup {
+            element,
+            position_tracker: Box::new(position_tracker),
+            size_tracker: Box::new(size_tracker),
+        }]
+    }
+
     fn render(
         &self,
         render_target: &mut <App::Renderer as InterfaceRenderer<App>>::Target,
@@ -69,6 +128,8 @@ where
             .state
             .element_renderer(render_target, renderer, application, parent_position, screen_clip);
 
+        *self.latest_position.borrow_mut() = renderer.get_position();
+
         if self.is_element_self(hovered_element) {
             renderer.render_background(theme.window().title_corner_radius(), theme.window().title_background_color());
         }
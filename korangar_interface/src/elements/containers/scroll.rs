@@ -1,15 +1,18 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Weak;
 
 use super::ContainerState;
-use crate::application::{Application, InterfaceRenderer, MouseInputModeTrait, PositionTrait, PositionTraitExt, SizeTrait, SizeTraitExt};
+use crate::application::{
+    Application, InterfaceRenderer, MouseInputModeTrait, PositionTrait, PositionTraitExt, ScalingTrait, SizeTrait, SizeTraitExt,
+};
 use crate::elements::{Element, ElementCell, ElementState, Focus};
-use crate::event::{ChangeEvent, HoverInformation};
+use crate::event::{ChangeEvent, ClickAction, HoverInformation};
 use crate::layout::{PlacementResolver, SizeBound};
 use crate::theme::{ButtonTheme, InterfaceTheme};
 use crate::ColorSelector;
 
 const SCROLL_SPEED: f32 = 0.8;
+const JUMP_BAR_HEIGHT: f32 = 16.0;
 
 pub struct ScrollView<App>
 where
@@ -20,6 +23,9 @@ where
     state: ContainerState<App>,
     size_bound: SizeBound,
     background_color: Option<ColorSelector<App>>,
+    follow_bottom: bool,
+    jump_bar_height: f32,
+    jump_bar_hovered: Cell<bool>,
 }
 
 impl<App> ScrollView<App>
@@ -38,6 +44,9 @@ where
             state,
             size_bound,
             background_color,
+            follow_bottom: false,
+            jump_bar_height: 0.0,
+            jump_bar_hovered: Cell::new(false),
         }
     }
 
@@ -46,10 +55,52 @@ where
         self
     }
 
+    /// Keep the view scrolled to the bottom when the content grows, as long as
+    /// it was at the bottom before. While scrolled up, a bar to jump back to
+    /// the bottom is shown.
+    pub fn with_follow_bottom(mut self) -> Self {
+        self.follow_bottom = true;
+        self
+    }
+
+    fn maximum_scroll(&self) -> f32 {
+        (self.children_height - self.state.state.cached_size.height()).max(0.0)
+    }
+
+    fn is_at_bottom(&self) -> bool {
+        self.scroll >= self.maximum_scroll() - 1.0
+    }
+
+    fn shows_jump_bar(&self) -> bool {
+        self.follow_bottom && !self.is_at_bottom()
+    }
+
     fn clamp_scroll(&mut self) {
-        self.scroll = self
-            .scroll
-            .clamp(0.0, (self.children_height - self.state.state.cached_size.height()).max(0.0));
+        self.scroll = self.scroll.clamp(0.0, self.maximum_scroll());
+    }
+
+    /// Scroll just enough for the child element at the given index to be
+    /// fully visible.
+    pub fn scroll_into_view(&mut self, index: usize) {
+        let Some(element) = self.state.elements.get(index) else {
+            return;
+        };
+
+        let (top, height) = {
+            let element = element.borrow();
+            let state = element.get_state();
+            (state.cached_position.top(), state.cached_size.height())
+        };
+
+        let visible_height = self.state.state.cached_size.height();
+
+        if top < self.scroll {
+            self.scroll = top;
+        } else if top + height > self.scroll + visible_height {
+            self.scroll = top + height - visible_height;
+        }
+
+        self.clamp_scroll();
     }
 }
 
@@ -82,10 +133,17 @@ where
     }
 
     fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, application: &App, theme: &App::Theme) {
+        let was_at_bottom = self.is_at_bottom();
+
         self.children_height = self
             .state
             .resolve(placement_resolver, application, theme, &self.size_bound, App::Size::zero());
-        self.clamp_scroll();
+        self.jump_bar_height = JUMP_BAR_HEIGHT * application.get_scaling().get_factor();
+
+        match self.follow_bottom && was_at_bottom {
+            true => self.scroll = self.maximum_scroll(),
+            false => self.clamp_scroll(),
+        }
     }
 
     fn update(&mut self) -> Option<ChangeEvent> {
@@ -100,6 +158,17 @@ where
             && absolute_position.left() <= self.state.state.cached_size.width()
             && absolute_position.top() <= self.state.state.cached_size.height()
         {
+            let jump_bar_hovered =
+                self.shows_jump_bar() && absolute_position.top() >= self.state.state.cached_size.height() - self.jump_bar_height;
+            self.jump_bar_hovered.set(jump_bar_hovered);
+
+            if jump_bar_hovered {
+                return match mouse_mode.is_none() {
+                    true => HoverInformation::Hovered,
+                    false => HoverInformation::Missed,
+                };
+            }
+
             for element in &self.state.elements {
                 match element
                     .borrow()
@@ -125,6 +194,16 @@ where
         Some(ChangeEvent::RENDER_WINDOW)
     }
 
+    fn left_click(&mut self, force_update: &mut bool) -> Vec<ClickAction<App>> {
+        if self.jump_bar_hovered.get() && self.shows_jump_bar() {
+            self.scroll = self.maximum_scroll();
+            self.jump_bar_hovered.set(false);
+            *force_update = true;
+        }
+
+        Vec::new()
+    }
+
     fn render(
         &self,
         render_target: &mut <App::Renderer as InterfaceRenderer<App>>::Target,
@@ -158,5 +237,34 @@ where
             mouse_mode,
             second_theme,
         );
+
+        if self.shows_jump_bar() {
+            let (background_color, foreground_color) = match self.is_element_self(hovered_element) && self.jump_bar_hovered.get() {
+                true => (
+                    theme.button().hovered_background_color(),
+                    theme.button().hovered_foreground_color(),
+                ),
+                false => (theme.button().background_color(), theme.button().foreground_color()),
+            };
+            let size = self.state.state.cached_size;
+
+            // Undo the scroll so the bar stays at the bottom of the view.
+            renderer.set_scroll(-self.scroll);
+            renderer.render_rectangle(
+                App::Position::only_top(size.height() - self.jump_bar_height),
+                App::Size::new(size.width(), self.jump_bar_height),
+                theme.button().corner_radius(),
+                background_color,
+            );
+            renderer.render_text(
+                "Jump to latest",
+                App::Position::new(
+                    4.0,
+                    (size.height() - self.jump_bar_height) / application.get_scaling().get_factor(),
+                ),
+                foreground_color,
+                theme.button().font_size(),
+            );
+        }
     }
 }
@@ -1,31 +1,37 @@
 use std::fmt::Display;
 use std::marker::PhantomData;
 
-use super::{EnterAction, InputField};
+use super::cursor::TextCursor;
+use super::history::HistoryCursor;
+use super::{CompletionAction, EnterAction, InputField};
 use crate::application::Application;
 use crate::builder::{Set, Unset};
 use crate::event::ClickAction;
 use crate::layout::DimensionBound;
-use crate::state::PlainTrackedState;
+use crate::state::{PlainTrackedState, TrackedState};
+use crate::Selector;
 
 /// Type state [`InputField`] builder. This builder utilizes the type system to
 /// prevent calling the same method multiple times and calling
 /// [`build`](Self::build) before the mandatory methods have been called.
 #[must_use = "`build` needs to be called"]
-pub struct InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width>
+pub struct InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width, Completion>
 where
     App: Application,
 {
     input_state: State,
     ghost_text: Text,
     enter_action: Action,
+    completion_action: Option<CompletionAction>,
+    history: Option<PlainTrackedState<Vec<String>>>,
     length: usize,
     hidden: bool,
+    hidden_selector: Option<Selector>,
     width_bound: DimensionBound,
-    marker: PhantomData<(App, Length, Hidden, Width)>,
+    marker: PhantomData<(App, Length, Hidden, Width, Completion)>,
 }
 
-impl<App> Default for InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset>
+impl<App> Default for InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset, Unset>
 where
     App: Application,
 {
@@ -34,15 +40,18 @@ where
             input_state: Unset,
             ghost_text: Unset,
             enter_action: Unset,
+            completion_action: None,
+            history: None,
             length: 0,
             hidden: false,
+            hidden_selector: None,
             width_bound: DimensionBound::RELATIVE_ONE_HUNDRED,
             marker: PhantomData,
         }
     }
 }
 
-impl<App> InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset>
+impl<App> InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset, Unset>
 where
     App: Application,
 {
@@ -51,14 +60,14 @@ where
     }
 }
 
-impl<App, Text, Action, Length, Hidden, Width> InputFieldBuilder<App, Unset, Text, Action, Length, Hidden, Width>
+impl<App, Text, Action, Length, Hidden, Width, Completion> InputFieldBuilder<App, Unset, Text, Action, Length, Hidden, Width, Completion>
 where
     App: Application,
 {
     pub fn with_state(
         self,
         state: PlainTrackedState<String>,
-    ) -> InputFieldBuilder<App, PlainTrackedState<String>, Text, Action, Length, Hidden, Width> {
+    ) -> InputFieldBuilder<App, PlainTrackedState<String>, Text, Action, Length, Hidden, Width, Completion> {
         InputFieldBuilder {
             input_state: state,
             ..self
@@ -66,12 +75,12 @@ where
     }
 }
 
-impl<App, State, Action, Length, Hidden, Width> InputFieldBuilder<App, State, Unset, Action, Length, Hidden, Width>
+impl<App, State, Action, Length, Hidden, Width, Completion> InputFieldBuilder<App, State, Unset, Action, Length, Hidden, Width, Completion>
 where
     App: Application,
 {
     /// Set the text that will be displayed when the [`InputField`] is empty.
-    pub fn with_ghost_text<Text>(self, ghost_text: Text) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width>
+    pub fn with_ghost_text<Text>(self, ghost_text: Text) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width, Completion>
     where
         Text: Display + 'static,
     {
@@ -79,7 +88,7 @@ where
     }
 }
 
-impl<App, State, Text, Length, Hidden, Width> InputFieldBuilder<App, State, Text, Unset, Length, Hidden, Width>
+impl<App, State, Text, Length, Hidden, Width, Completion> InputFieldBuilder<App, State, Text, Unset, Length, Hidden, Width, Completion>
 where
     App: Application,
 {
@@ -87,7 +96,7 @@ where
     pub fn with_enter_action(
         self,
         enter_action: impl FnMut() -> Vec<ClickAction<App>> + 'static,
-    ) -> InputFieldBuilder<App, State, Text, EnterAction<App>, Length, Hidden, Width> {
+    ) -> InputFieldBuilder<App, State, Text, EnterAction<App>, Length, Hidden, Width, Completion> {
         InputFieldBuilder {
             enter_action: Box::new(enter_action),
             ..self
@@ -95,12 +104,12 @@ where
     }
 }
 
-impl<App, State, Text, Action, Hidden, Width> InputFieldBuilder<App, State, Text, Action, Unset, Hidden, Width>
+impl<App, State, Text, Action, Hidden, Width, Completion> InputFieldBuilder<App, State, Text, Action, Unset, Hidden, Width, Completion>
 where
     App: Application,
 {
     /// Set the maximum number of allowed characters.
-    pub fn with_length(self, length: usize) -> InputFieldBuilder<App, State, Text, Action, Set, Hidden, Width> {
+    pub fn with_length(self, length: usize) -> InputFieldBuilder<App, State, Text, Action, Set, Hidden, Width, Completion> {
         InputFieldBuilder {
             length,
             marker: PhantomData,
@@ -109,25 +118,41 @@ where
     }
 }
 
-impl<App, State, Text, Action, Length, Width> InputFieldBuilder<App, State, Text, Action, Length, Unset, Width>
+impl<App, State, Text, Action, Length, Width, Completion> InputFieldBuilder<App, State, Text, Action, Length, Unset, Width, Completion>
 where
     App: Application,
 {
     /// Only show text as `*` characters. Useful for password fields.
-    pub fn hidden(self) -> InputFieldBuilder<App, State, Text, Action, Length, Set, Width> {
+    pub fn hidden(self) -> InputFieldBuilder<App, State, Text, Action, Length, Set, Width, Completion> {
         InputFieldBuilder {
             hidden: true,
             marker: PhantomData,
             ..self
         }
     }
+
+    /// Only show text as `*` characters while the selector returns `true`.
+    pub fn with_hidden_selector(
+        self,
+        selector: impl Fn() -> bool + 'static,
+    ) -> InputFieldBuilder<App, State, Text, Action, Length, Set, Width, Completion> {
+        InputFieldBuilder {
+            hidden: selector(),
+            hidden_selector: Some(Box::new(selector)),
+            marker: PhantomData,
+            ..self
+        }
+    }
 }
 
-impl<App, State, Text, Action, Length, Hidden> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Unset>
+impl<App, State, Text, Action, Length, Hidden, Completion> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Unset, Completion>
 where
     App: Application,
 {
-    pub fn with_width_bound(self, width_bound: DimensionBound) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Set> {
+    pub fn with_width_bound(
+        self,
+        width_bound: DimensionBound,
+    ) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Set, Completion> {
         InputFieldBuilder {
             width_bound,
             marker: PhantomData,
@@ -136,7 +161,42 @@ where
     }
 }
 
-impl<App, Text, Hidden, Width> InputFieldBuilder<App, PlainTrackedState<String>, Text, EnterAction<App>, Set, Hidden, Width>
+impl<App, State, Text, Action, Length, Hidden, Width> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width, Unset>
+where
+    App: Application,
+{
+    /// Set an action that will be executed when the user presses the tab key.
+    /// The action receives the current input and returns the completed input,
+    /// if there is any.
+    pub fn with_completion_action(
+        self,
+        completion_action: impl FnMut(&str) -> Option<String> + 'static,
+    ) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width, Set> {
+        InputFieldBuilder {
+            completion_action: Some(Box::new(completion_action)),
+            marker: PhantomData,
+            ..self
+        }
+    }
+}
+
+impl<App, State, Text, Action, Length, Hidden, Width, Completion>
+    InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width, Completion>
+where
+    App: Application,
+{
+    /// Previous inputs that can be recalled with the up and down keys. The
+    /// input field only reads the history, adding to it is up to the caller.
+    pub fn with_history(self, history: PlainTrackedState<Vec<String>>) -> Self {
+        Self {
+            history: Some(history),
+            ..self
+        }
+    }
+}
+
+impl<App, Text, Hidden, Width, Completion>
+    InputFieldBuilder<App, PlainTrackedState<String>, Text, EnterAction<App>, Set, Hidden, Width, Completion>
 where
     App: Application,
     Text: Display + 'static,
@@ -152,19 +212,30 @@ where
             input_state,
             ghost_text,
             enter_action,
+            completion_action,
+            history,
             length,
             hidden,
+            hidden_selector,
             width_bound,
             ..
         } = self;
 
+        let cursor = TextCursor::at_end(&input_state.get());
+
         InputField {
             input_state,
             ghost_text,
             enter_action,
+            completion_action,
+            history,
+            history_cursor: HistoryCursor::default(),
             length,
             hidden,
+            hidden_selector,
             width_bound,
+            cursor,
+            preedit: String::new(),
             state: Default::default(),
         }
     }
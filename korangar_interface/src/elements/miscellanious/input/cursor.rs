@@ -0,0 +1,317 @@
+use std::ops::Range;
+
+use crate::event::TextEdit;
+
+/// Cursor and selection inside of a single line of text. All positions are
+/// counted in characters, not bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct TextCursor {
+    position: usize,
+    /// Other end of the selection. Nothing is selected if it is the same as
+    /// the position.
+    anchor: usize,
+}
+
+impl TextCursor {
+    pub fn at_end(text: &str) -> Self {
+        let mut cursor = Self::default();
+        cursor.move_to_end(text);
+        cursor
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn selection(&self) -> Range<usize> {
+        self.position.min(self.anchor)..self.position.max(self.anchor)
+    }
+
+    pub fn has_selection(&self) -> bool {
+        self.position != self.anchor
+    }
+
+    pub fn selected_text<'a>(&self, text: &'a str) -> &'a str {
+        let selection = self.selection();
+        &text[byte_index(text, selection.start)..byte_index(text, selection.end)]
+    }
+
+    /// Keep the cursor inside of the text after it was changed from outside.
+    pub fn clamp(&mut self, text: &str) {
+        let length = text.chars().count();
+
+        self.position = self.position.min(length);
+        self.anchor = self.anchor.min(length);
+    }
+
+    /// Move the cursor to the end of the text and clear the selection.
+    pub fn move_to_end(&mut self, text: &str) {
+        self.position = text.chars().count();
+        self.anchor = self.position;
+    }
+
+    /// Returns `true` if the text or the cursor changed.
+    pub fn edit(&mut self, text: &mut String, edit: TextEdit) -> bool {
+        let length = text.chars().count();
+
+        match edit {
+            TextEdit::Left { word, select } => {
+                let position = match (self.has_selection() && !select, word) {
+                    (true, _) => self.selection().start,
+                    (false, true) => previous_word_boundary(text, self.position),
+                    (false, false) => self.position.saturating_sub(1),
+                };
+
+                self.move_to(position, select)
+            }
+            TextEdit::Right { word, select } => {
+                let position = match (self.has_selection() && !select, word) {
+                    (true, _) => self.selection().end,
+                    (false, true) => next_word_boundary(text, self.position),
+                    (false, false) => (self.position + 1).min(length),
+                };
+
+                self.move_to(position, select)
+            }
+            TextEdit::Home { select } => self.move_to(0, select),
+            TextEdit::End { select } => self.move_to(length, select),
+            TextEdit::SelectAll => {
+                let previous = *self;
+
+                self.anchor = 0;
+                self.position = length;
+
+                *self != previous
+            }
+            TextEdit::Delete { word } => {
+                if !self.has_selection() {
+                    self.anchor = match word {
+                        true => next_word_boundary(text, self.position),
+                        false => (self.position + 1).min(length),
+                    };
+                }
+
+                self.delete_selection(text)
+            }
+            // The history is handled by the input field.
+            TextEdit::Up | TextEdit::Down => false,
+        }
+    }
+
+    /// Replace the selection with a character.
+    pub fn insert(&mut self, text: &mut String, character: char) {
+        self.delete_selection(text);
+
+        text.insert(byte_index(text, self.position), character);
+        self.position += 1;
+        self.anchor = self.position;
+    }
+
+    /// Delete the selection or the character before the cursor. Returns `true`
+    /// if the text changed.
+    pub fn delete_backward(&mut self, text: &mut String) -> bool {
+        if !self.has_selection() {
+            self.anchor = self.position.saturating_sub(1);
+        }
+
+        self.delete_selection(text)
+    }
+
+    /// Returns `true` if the text changed.
+    pub fn delete_selection(&mut self, text: &mut String) -> bool {
+        let selection = self.selection();
+
+        self.position = selection.start;
+        self.anchor = selection.start;
+
+        if selection.is_empty() {
+            return false;
+        }
+
+        text.replace_range(byte_index(text, selection.start)..byte_index(text, selection.end), "");
+        true
+    }
+
+    fn move_to(&mut self, position: usize, select: bool) -> bool {
+        let previous = *self;
+
+        self.position = position;
+
+        if !select {
+            self.anchor = position;
+        }
+
+        *self != previous
+    }
+}
+
+fn byte_index(text: &str, position: usize) -> usize {
+    text.char_indices().nth(position).map(|(index, _)| index).unwrap_or(text.len())
+}
+
+/// Start of the word before the position.
+fn previous_word_boundary(text: &str, position: usize) -> usize {
+    let characters: Vec<char> = text.chars().take(position).collect();
+    let mut index = characters.len();
+
+    while index > 0 && characters[index - 1].is_whitespace() {
+        index -= 1;
+    }
+
+    while index > 0 && !characters[index - 1].is_whitespace() {
+        index -= 1;
+    }
+
+    index
+}
+
+/// Start of the word after the position.
+fn next_word_boundary(text: &str, position: usize) -> usize {
+    let characters: Vec<char> = text.chars().collect();
+    let mut index = position.min(characters.len());
+
+    while index < characters.len() && !characters[index].is_whitespace() {
+        index += 1;
+    }
+
+    while index < characters.len() && characters[index].is_whitespace() {
+        index += 1;
+    }
+
+    index
+}
+
+#[cfg(test)]
+mod edit {
+    use super::TextCursor;
+    use crate::event::TextEdit;
+
+    fn cursor_at(text: &str, position: usize) -> TextCursor {
+        let mut cursor = TextCursor::at_end(text);
+
+        let steps = text.chars().count() - position;
+        for _ in 0..steps {
+            cursor.edit(&mut text.to_owned(), TextEdit::Left {
+                word: false,
+                select: false,
+            });
+        }
+
+        cursor
+    }
+
+    #[test]
+    fn insert_in_the_middle() {
+        let mut text = "helo".to_owned();
+        let mut cursor = cursor_at(&text, 3);
+
+        cursor.insert(&mut text, 'l');
+
+        assert_eq!(text, "hello");
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn insert_multibyte() {
+        let mut text = "안녕".to_owned();
+        let mut cursor = cursor_at(&text, 1);
+
+        cursor.insert(&mut text, 'a');
+
+        assert_eq!(text, "안a녕");
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn move_by_word() {
+        let mut text = "one two  three".to_owned();
+        let mut cursor = cursor_at(&text, 0);
+
+        cursor.edit(&mut text, TextEdit::Right { word: true, select: false });
+        assert_eq!(cursor.position(), 4);
+
+        cursor.edit(&mut text, TextEdit::Right { word: true, select: false });
+        assert_eq!(cursor.position(), 9);
+
+        cursor.edit(&mut text, TextEdit::Left { word: true, select: false });
+        assert_eq!(cursor.position(), 4);
+
+        cursor.edit(&mut text, TextEdit::Left { word: true, select: false });
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn select_and_replace() {
+        let mut text = "hello world".to_owned();
+        let mut cursor = cursor_at(&text, 11);
+
+        cursor.edit(&mut text, TextEdit::Left { word: true, select: true });
+        assert_eq!(cursor.selected_text(&text), "world");
+
+        cursor.insert(&mut text, 'x');
+        assert_eq!(text, "hello x");
+        assert!(!cursor.has_selection());
+    }
+
+    #[test]
+    fn collapse_selection() {
+        let mut text = "hello".to_owned();
+        let mut cursor = cursor_at(&text, 1);
+
+        cursor.edit(&mut text, TextEdit::End { select: true });
+        cursor.edit(&mut text, TextEdit::Left {
+            word: false,
+            select: false,
+        });
+
+        assert_eq!(cursor.position(), 1);
+        assert!(!cursor.has_selection());
+    }
+
+    #[test]
+    fn delete_backward_and_forward() {
+        let mut text = "abc".to_owned();
+        let mut cursor = cursor_at(&text, 1);
+
+        assert!(cursor.delete_backward(&mut text));
+        assert_eq!(text, "bc");
+
+        assert!(!cursor.delete_backward(&mut text));
+
+        cursor.edit(&mut text, TextEdit::Delete { word: false });
+        assert_eq!(text, "c");
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn delete_word() {
+        let mut text = "one two".to_owned();
+        let mut cursor = cursor_at(&text, 0);
+
+        cursor.edit(&mut text, TextEdit::Delete { word: true });
+
+        assert_eq!(text, "two");
+    }
+
+    #[test]
+    fn select_all() {
+        let mut text = "hello".to_owned();
+        let mut cursor = cursor_at(&text, 2);
+
+        cursor.edit(&mut text, TextEdit::SelectAll);
+
+        assert_eq!(cursor.selected_text(&text), "hello");
+    }
+
+    #[test]
+    fn clamp_after_external_change() {
+        let mut text = "hello".to_owned();
+        let mut cursor = cursor_at(&text, 5);
+
+        text.clear();
+        cursor.clamp(&text);
+
+        assert_eq!(cursor.position(), 0);
+        assert!(!cursor.delete_backward(&mut text));
+    }
+}
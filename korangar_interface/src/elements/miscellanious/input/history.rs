@@ -0,0 +1,100 @@
+/// Position while browsing through previous inputs with the up and down keys.
+/// The input that was being written before browsing is kept as a draft and
+/// restored when moving past the newest entry.
+#[derive(Debug, Default)]
+pub(super) struct HistoryCursor {
+    index: Option<usize>,
+    draft: String,
+}
+
+impl HistoryCursor {
+    /// Returns the text that should replace the input, if it changed.
+    pub fn previous(&mut self, history: &[String], input: &str) -> Option<String> {
+        let index = match self.index {
+            _ if history.is_empty() => return None,
+            None => {
+                self.draft = input.to_owned();
+                history.len() - 1
+            }
+            Some(0) => return None,
+            Some(index) => index.min(history.len()) - 1,
+        };
+
+        self.index = Some(index);
+        Some(history[index].clone())
+    }
+
+    /// Returns the text that should replace the input, if it changed.
+    pub fn next(&mut self, history: &[String]) -> Option<String> {
+        let index = self.index?;
+
+        match index + 1 < history.len() {
+            true => {
+                self.index = Some(index + 1);
+                Some(history[index + 1].clone())
+            }
+            false => {
+                self.index = None;
+                Some(std::mem::take(&mut self.draft))
+            }
+        }
+    }
+
+    /// Start browsing from the newest entry again.
+    pub fn reset(&mut self) {
+        self.index = None;
+        self.draft.clear();
+    }
+}
+
+#[cfg(test)]
+mod browse {
+    use super::HistoryCursor;
+
+    fn history() -> Vec<String> {
+        vec!["first".to_owned(), "second".to_owned()]
+    }
+
+    #[test]
+    fn empty_history() {
+        let mut cursor = HistoryCursor::default();
+
+        assert_eq!(cursor.previous(&[], "draft"), None);
+        assert_eq!(cursor.next(&[]), None);
+    }
+
+    #[test]
+    fn newest_first() {
+        let history = history();
+        let mut cursor = HistoryCursor::default();
+
+        assert_eq!(cursor.previous(&history, "").as_deref(), Some("second"));
+        assert_eq!(cursor.previous(&history, "second").as_deref(), Some("first"));
+        assert_eq!(cursor.previous(&history, "first"), None);
+    }
+
+    #[test]
+    fn restore_draft() {
+        let history = history();
+        let mut cursor = HistoryCursor::default();
+
+        cursor.previous(&history, "draft");
+        cursor.previous(&history, "second");
+
+        assert_eq!(cursor.next(&history).as_deref(), Some("second"));
+        assert_eq!(cursor.next(&history).as_deref(), Some("draft"));
+        assert_eq!(cursor.next(&history), None);
+    }
+
+    #[test]
+    fn reset() {
+        let history = history();
+        let mut cursor = HistoryCursor::default();
+
+        cursor.previous(&history, "draft");
+        cursor.previous(&history, "second");
+        cursor.reset();
+
+        assert_eq!(cursor.previous(&history, "").as_deref(), Some("second"));
+    }
+}
@@ -1,20 +1,28 @@
 mod builder;
+mod cursor;
+mod history;
 
 use std::fmt::Display;
 
 pub use self::builder::InputFieldBuilder;
+use self::cursor::TextCursor;
+use self::history::HistoryCursor;
 use crate::application::{
     Application, CornerRadiusTraitExt, InterfaceRenderer, MouseInputModeTrait, PositionTrait, PositionTraitExt, ScalingTrait, SizeTrait,
 };
-use crate::elements::{Element, ElementState};
-use crate::event::{ChangeEvent, ClickAction, HoverInformation};
+use crate::elements::{Element, ElementRenderer, ElementState};
+use crate::event::{ChangeEvent, ClickAction, HoverInformation, TextEdit};
 use crate::layout::{DimensionBound, PlacementResolver};
 use crate::state::{PlainTrackedState, TrackedState, ValueState};
 use crate::theme::{InputTheme, InterfaceTheme};
+use crate::Selector;
 
 /// Local type alias to simplify the builder.
 type EnterAction<App> = Box<dyn FnMut() -> Vec<ClickAction<App>>>;
 
+/// Local type alias to simplify the builder.
+type CompletionAction = Box<dyn FnMut(&str) -> Option<String>>;
+
 pub struct InputField<App, Text>
 where
     App: Application,
@@ -23,9 +31,17 @@ where
     input_state: PlainTrackedState<String>,
     ghost_text: Text,
     enter_action: EnterAction<App>,
+    completion_action: Option<CompletionAction>,
+    history: Option<PlainTrackedState<Vec<String>>>,
+    history_cursor: HistoryCursor,
     length: usize,
     hidden: bool,
+    hidden_selector: Option<Selector>,
     width_bound: DimensionBound,
+    cursor: TextCursor,
+    /// Text that is currently being composed with an input method. It is
+    /// displayed after the input but not part of it until it is committed.
+    preedit: String,
     state: ElementState<App>,
 }
 
@@ -36,27 +52,77 @@ where
 {
     fn remove_character(&mut self) -> Vec<ClickAction<App>> {
         self.input_state.with_mut(|input_state| {
-            if input_state.is_empty() {
+            self.cursor.clamp(input_state);
+
+            if !self.cursor.delete_backward(input_state) {
                 return ValueState::Unchanged(Vec::new());
             }
 
-            input_state.pop();
-
             ValueState::Mutated(vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)])
         })
     }
 
     fn add_character(&mut self, character: char) -> Vec<ClickAction<App>> {
         self.input_state.with_mut(|input_state| {
-            if input_state.len() >= self.length {
+            self.cursor.clamp(input_state);
+
+            if input_state.len() - self.cursor.selected_text(input_state).len() >= self.length {
                 return ValueState::Unchanged(Vec::new());
             }
 
-            input_state.push(character);
+            self.cursor.insert(input_state, character);
 
             ValueState::Mutated(vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)])
         })
     }
+
+    fn complete_input(&mut self) -> (bool, Vec<ClickAction<App>>) {
+        let Some(completion_action) = &mut self.completion_action else {
+            // Without a completion action the tab key is not handled, so it can be used to
+            // move the focus to the next element.
+            return (false, Vec::new());
+        };
+
+        let length = self.length;
+        let actions = self.input_state.with_mut(|input_state| {
+            let Some(completed) = completion_action(input_state) else {
+                return ValueState::Unchanged(Vec::new());
+            };
+
+            if completed == *input_state || completed.len() > length {
+                return ValueState::Unchanged(Vec::new());
+            }
+
+            *input_state = completed;
+            self.cursor.move_to_end(input_state);
+
+            ValueState::Mutated(vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)])
+        });
+
+        (true, actions)
+    }
+
+    /// Replace the input with an entry of the history. Returns `true` if the
+    /// input changed.
+    fn recall_history(&mut self, edit: TextEdit) -> bool {
+        let Some(history) = &self.history else {
+            return false;
+        };
+
+        let history = history.get();
+        let recalled = match edit {
+            TextEdit::Up => self.history_cursor.previous(&history, &self.input_state.get()),
+            _ => self.history_cursor.next(&history),
+        };
+
+        let Some(recalled) = recalled else {
+            return false;
+        };
+
+        self.input_state.set(recalled);
+        self.cursor.move_to_end(&self.input_state.get());
+        true
+    }
 }
 
 impl<App, Text> Element<App> for InputField<App, Text>
@@ -84,18 +150,85 @@ where
         }
     }
 
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let selector = self.hidden_selector.as_ref()?;
+        let hidden = selector();
+
+        (hidden != self.hidden).then(|| {
+            self.hidden = hidden;
+            ChangeEvent::RENDER_WINDOW
+        })
+    }
+
     fn left_click(&mut self, _update: &mut bool) -> Vec<ClickAction<App>> {
+        self.preedit.clear();
+        self.cursor.move_to_end(&self.input_state.get());
+
         vec![ClickAction::FocusElement]
     }
 
     fn input_character(&mut self, character: char) -> (bool, Vec<ClickAction<App>>) {
+        if character == '\t' {
+            return self.complete_input();
+        }
+
         (true, match character {
             '\u{8}' | '\u{7f}' => self.remove_character(),
-            '\r' => (self.enter_action)(),
+            '\r' => {
+                self.history_cursor.reset();
+                (self.enter_action)()
+            }
+            // Other control characters are sent for shortcuts like Ctrl+C, which are
+            // handled as text edits instead.
+            character if character.is_control() => return (false, Vec::new()),
             character => self.add_character(character),
         })
     }
 
+    fn input_text_edit(&mut self, edit: TextEdit) -> Option<ChangeEvent> {
+        if matches!(edit, TextEdit::Up | TextEdit::Down) {
+            return self.recall_history(edit).then_some(ChangeEvent::RENDER_WINDOW);
+        }
+
+        let changed = self.input_state.with_mut(|input_state| {
+            self.cursor.clamp(input_state);
+            let previous_length = input_state.len();
+
+            if !self.cursor.edit(input_state, edit) {
+                return ValueState::Unchanged(false);
+            }
+
+            // Only changes of the text itself need to be propagated.
+            match input_state.len() != previous_length {
+                true => ValueState::Mutated(true),
+                false => ValueState::Unchanged(true),
+            }
+        });
+
+        changed.then_some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn copy_text(&self) -> Option<String> {
+        let input_state = self.input_state.get();
+        let mut cursor = self.cursor;
+        cursor.clamp(&input_state);
+
+        // Never copy passwords.
+        match !self.hidden && cursor.has_selection() {
+            true => Some(cursor.selected_text(&input_state).to_owned()),
+            false => None,
+        }
+    }
+
+    fn input_preedit(&mut self, text: &str) -> Option<ChangeEvent> {
+        if self.preedit == text {
+            return None;
+        }
+
+        self.preedit = text.to_owned();
+        Some(ChangeEvent::RENDER_WINDOW)
+    }
+
     fn render(
         &self,
         render_target: &mut <App::Renderer as InterfaceRenderer<App>>::Target,
@@ -143,21 +276,69 @@ where
         };
 
         renderer.render_background(theme.input().corner_radius(), background_color);
-        renderer.render_text(&text, text_offset, text_color, theme.input().font_size());
 
-        if is_focused {
-            let cursor_offset = (text_offset.left() + theme.input().cursor_offset()) * application.get_scaling().get_factor()
-                + renderer.get_text_dimensions(&text, theme.input().font_size(), f32::MAX).width();
+        if !is_focused {
+            renderer.render_text(&text, text_offset, text_color, theme.input().font_size());
+            return;
+        }
+
+        let mut cursor = self.cursor;
+        cursor.clamp(&input_state);
+
+        let scaling_factor = application.get_scaling().get_factor();
+        let text_left = text_offset.left() * scaling_factor;
+        let get_width = |renderer: &ElementRenderer<App>, position: usize| {
+            let prefix: String = text.chars().take(position).collect();
+            renderer.get_text_dimensions(&prefix, theme.input().font_size(), f32::MAX).width()
+        };
+
+        if cursor.has_selection() {
+            let selection = cursor.selection();
+            let start = get_width(&renderer, selection.start);
+            let end = get_width(&renderer, selection.end);
+
+            renderer.render_rectangle(
+                App::Position::only_left(text_left + start),
+                App::Size::new(end - start, self.state.cached_size.height()),
+                App::CornerRadius::zero(),
+                theme.input().selection_color(),
+            );
+        }
+
+        // Text that is being composed with an input method is displayed at the cursor.
+        // Hidden inputs are used for passwords, which are never composed.
+        let preedit = match self.hidden {
+            true => "",
+            false => self.preedit.as_str(),
+        };
+        let cursor_left = text_left + get_width(&renderer, cursor.position());
+        let preedit_width = renderer.get_text_dimensions(preedit, theme.input().font_size(), f32::MAX).width();
+
+        let mut displayed_text: String = text.chars().take(cursor.position()).collect();
+        displayed_text.push_str(preedit);
+        displayed_text.extend(text.chars().skip(cursor.position()));
 
-            let cursor_position = App::Position::only_left(cursor_offset);
-            let cursor_size = App::Size::new(theme.input().cursor_width(), self.state.cached_size.height());
+        renderer.render_text(&displayed_text, text_offset, text_color, theme.input().font_size());
 
+        if !preedit.is_empty() {
+            // Underline the composed text to set it apart from the input.
             renderer.render_rectangle(
-                cursor_position,
-                cursor_size,
+                App::Position::new(cursor_left, self.state.cached_size.height() - theme.input().cursor_width()),
+                App::Size::new(preedit_width, theme.input().cursor_width()),
                 App::CornerRadius::zero(),
-                theme.input().text_color(),
+                theme.input().focused_text_color(),
             );
         }
+
+        let cursor_offset = cursor_left + preedit_width + theme.input().cursor_offset() * scaling_factor;
+        let cursor_position = App::Position::only_left(cursor_offset);
+        let cursor_size = App::Size::new(theme.input().cursor_width(), self.state.cached_size.height());
+
+        renderer.render_rectangle(
+            cursor_position,
+            cursor_size,
+            App::CornerRadius::zero(),
+            theme.input().text_color(),
+        );
     }
 }
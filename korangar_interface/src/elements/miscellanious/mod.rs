@@ -1,5 +1,6 @@
 mod headline;
 mod input;
+mod number;
 mod picklist;
 mod slider;
 mod static_label;
@@ -7,6 +8,7 @@ mod text;
 
 pub use self::headline::Headline;
 pub use self::input::InputFieldBuilder;
+pub use self::number::NumberInput;
 pub use self::picklist::PickList;
 pub use self::slider::Slider;
 pub use self::static_label::StaticLabel;
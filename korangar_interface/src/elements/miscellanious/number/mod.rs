@@ -0,0 +1,301 @@
+mod value;
+
+use std::cell::Cell;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use num::traits::NumOps;
+use num::{One, Zero};
+
+use self::value::{accepts_input, parse_clamped, step_down, step_up};
+use crate::application::{Application, InterfaceRenderer, MouseInputModeTrait, PositionTrait, PositionTraitExt, ScalingTrait, SizeTrait};
+use crate::elements::{Element, ElementState};
+use crate::event::{ChangeEvent, ClickAction, HoverInformation};
+use crate::layout::{DimensionBound, PlacementResolver};
+use crate::state::{Remote, TrackedState};
+use crate::theme::{ButtonTheme, InputTheme, InterfaceTheme};
+
+/// Part of the [`NumberInput`] that is under the mouse.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NumberInputPart {
+    Text,
+    Decrease,
+    Increase,
+}
+
+/// Input for a number in a fixed range. The value can be typed, changed with
+/// the step buttons or changed by scrolling over the input.
+pub struct NumberInput<App, Value, State>
+where
+    App: Application,
+    Value: NumOps + Zero + One + Copy + PartialOrd + Display + FromStr + 'static,
+    State: TrackedState<Value> + 'static,
+{
+    value: State,
+    remote: State::RemoteType,
+    minimum: Value,
+    maximum: Value,
+    step: Value,
+    /// Text while typing. It may be out of range, in which case the value is
+    /// only updated once enter is pressed.
+    text: String,
+    width_bound: DimensionBound,
+    hovered_part: Cell<NumberInputPart>,
+    state: ElementState<App>,
+}
+
+impl<App, Value, State> NumberInput<App, Value, State>
+where
+    App: Application,
+    Value: NumOps + Zero + One + Copy + PartialOrd + Display + FromStr + 'static,
+    State: TrackedState<Value> + 'static,
+{
+    pub fn new(value: State, minimum: Value, maximum: Value) -> Self {
+        let remote = value.new_remote();
+        let text = value.get().to_string();
+
+        Self {
+            value,
+            remote,
+            minimum,
+            maximum,
+            step: Value::one(),
+            text,
+            width_bound: DimensionBound::RELATIVE_ONE_HUNDRED,
+            hovered_part: Cell::new(NumberInputPart::Text),
+            state: ElementState::default(),
+        }
+    }
+
+    /// Amount that the step buttons and the mouse wheel change the value by.
+    pub fn with_step(mut self, step: Value) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn with_width(mut self, width_bound: DimensionBound) -> Self {
+        self.width_bound = width_bound;
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        self.text
+            .parse::<Value>()
+            .is_ok_and(|value| value >= self.minimum && value <= self.maximum)
+    }
+
+    fn set_value(&mut self, value: Value) -> Option<ChangeEvent> {
+        self.text = value.to_string();
+
+        if *self.value.get() != value {
+            self.value.set(value);
+        }
+
+        Some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn increase(&mut self) -> Option<ChangeEvent> {
+        let value = step_up(*self.value.get(), self.step, self.maximum);
+        self.set_value(value)
+    }
+
+    fn decrease(&mut self) -> Option<ChangeEvent> {
+        let value = step_down(*self.value.get(), self.step, self.minimum);
+        self.set_value(value)
+    }
+
+    /// Apply the typed text, falling back to the current value if the text
+    /// is not a number.
+    fn submit(&mut self) -> Option<ChangeEvent> {
+        let value = parse_clamped(&self.text, self.minimum, self.maximum).unwrap_or(*self.value.get());
+        self.set_value(value)
+    }
+}
+
+impl<App, Value, State> Element<App> for NumberInput<App, Value, State>
+where
+    App: Application,
+    Value: NumOps + Zero + One + Copy + PartialOrd + Display + FromStr + 'static,
+    State: TrackedState<Value> + 'static,
+{
+    fn get_state(&self) -> &ElementState<App> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<App> {
+        &mut self.state
+    }
+
+    fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, _application: &App, theme: &App::Theme) {
+        let size_bound = self.width_bound.add_height(theme.input().height_bound());
+        self.state.resolve(placement_resolver, &size_bound);
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if !self.remote.consume_changed() {
+            return None;
+        }
+
+        // Only replace the text if the value was changed from somewhere else.
+        let value = *self.remote.get();
+        match self.text.parse::<Value>().is_ok_and(|parsed| parsed == value) {
+            true => None,
+            false => {
+                self.text = value.to_string();
+                Some(ChangeEvent::RENDER_WINDOW)
+            }
+        }
+    }
+
+    fn hovered_element(&self, mouse_position: App::Position, mouse_mode: &App::MouseInputMode) -> HoverInformation<App> {
+        if !mouse_mode.is_none() {
+            return HoverInformation::Missed;
+        }
+
+        let hover_information = self.state.hovered_element(mouse_position);
+
+        if let HoverInformation::Hovered = hover_information {
+            let button_width = self.state.cached_size.height();
+            let distance_to_right = self.state.cached_size.width() - self.state.mouse_position.get().left();
+
+            let hovered_part = match distance_to_right {
+                distance if distance <= button_width => NumberInputPart::Increase,
+                distance if distance <= button_width * 2.0 => NumberInputPart::Decrease,
+                _ => NumberInputPart::Text,
+            };
+
+            self.hovered_part.set(hovered_part);
+        }
+
+        hover_information
+    }
+
+    fn left_click(&mut self, force_update: &mut bool) -> Vec<ClickAction<App>> {
+        let change_event = match self.hovered_part.get() {
+            NumberInputPart::Text => return vec![ClickAction::FocusElement],
+            NumberInputPart::Decrease => self.decrease(),
+            NumberInputPart::Increase => self.increase(),
+        };
+
+        *force_update = true;
+        change_event.map(ClickAction::ChangeEvent).into_iter().collect()
+    }
+
+    fn scroll(&mut self, delta: f32) -> Option<ChangeEvent> {
+        match delta > 0.0 {
+            true => self.increase(),
+            false => self.decrease(),
+        }
+    }
+
+    fn input_character(&mut self, character: char) -> (bool, Vec<ClickAction<App>>) {
+        let change_event = match character {
+            '\u{8}' | '\u{7f}' => {
+                if self.text.pop().is_none() {
+                    return (true, Vec::new());
+                }
+
+                Some(ChangeEvent::RENDER_WINDOW)
+            }
+            '\r' => self.submit(),
+            // Let the tab key move the focus to the next element.
+            character if character.is_control() => return (false, Vec::new()),
+            character => {
+                let mut text = self.text.clone();
+                text.push(character);
+
+                if !accepts_input(&text, self.minimum) {
+                    return (true, Vec::new());
+                }
+
+                self.text = text;
+
+                // Update the value right away while the text is in range, so the
+                // caller doesn't have to wait for the enter key.
+                if self.is_valid() {
+                    let value = parse_clamped(&self.text, self.minimum, self.maximum).unwrap();
+                    self.value.set(value);
+                }
+
+                Some(ChangeEvent::RENDER_WINDOW)
+            }
+        };
+
+        (true, change_event.map(ClickAction::ChangeEvent).into_iter().collect())
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <App::Renderer as InterfaceRenderer<App>>::Target,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        parent_position: App::Position,
+        screen_clip: App::Clip,
+        hovered_element: Option<&dyn Element<App>>,
+        focused_element: Option<&dyn Element<App>>,
+        _mouse_mode: &App::MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let is_hovered = self.is_element_self(hovered_element);
+        let is_focused = self.is_element_self(focused_element);
+
+        let background_color = if is_hovered && self.hovered_part.get() == NumberInputPart::Text {
+            theme.input().hovered_background_color()
+        } else if is_focused {
+            theme.input().focused_background_color()
+        } else {
+            theme.input().background_color()
+        };
+
+        // Text that is out of range is displayed like ghost text until it is
+        // corrected or submitted.
+        let text_color = if !self.is_valid() {
+            theme.input().ghost_text_color()
+        } else if is_focused {
+            theme.input().focused_text_color()
+        } else {
+            theme.input().text_color()
+        };
+
+        renderer.render_background(theme.input().corner_radius(), background_color);
+        renderer.render_text(&self.text, theme.input().text_offset(), text_color, theme.input().font_size());
+
+        let scaling_factor = application.get_scaling().get_factor();
+        let button_width = self.state.cached_size.height();
+        let button_size = App::Size::new(button_width, button_width);
+
+        for (part, label, right_offset) in [
+            (NumberInputPart::Decrease, "-", button_width * 2.0),
+            (NumberInputPart::Increase, "+", button_width),
+        ] {
+            let button_left = self.state.cached_size.width() - right_offset;
+            let is_button_hovered = is_hovered && self.hovered_part.get() == part;
+
+            let (background_color, foreground_color) = match is_button_hovered {
+                true => (
+                    theme.button().hovered_background_color(),
+                    theme.button().hovered_foreground_color(),
+                ),
+                false => (theme.button().background_color(), theme.button().foreground_color()),
+            };
+
+            renderer.render_rectangle(
+                App::Position::only_left(button_left),
+                button_size,
+                theme.button().corner_radius(),
+                background_color,
+            );
+
+            let text_offset = App::Position::new(
+                button_left / scaling_factor + button_width / scaling_factor / 3.0,
+                theme.button().text_offset().top(),
+            );
+            renderer.render_text(label, text_offset, foreground_color, theme.button().font_size());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+use num::traits::NumOps;
+use num::{clamp, Zero};
+
+/// Increase the value by one step without going past the maximum. The
+/// distance to the maximum is checked first so integer values can't overflow.
+pub(super) fn step_up<Value>(value: Value, step: Value, maximum: Value) -> Value
+where
+    Value: NumOps + Copy + PartialOrd,
+{
+    match value >= maximum || maximum - value < step {
+        true => maximum,
+        false => value + step,
+    }
+}
+
+/// Decrease the value by one step without going past the minimum.
+pub(super) fn step_down<Value>(value: Value, step: Value, minimum: Value) -> Value
+where
+    Value: NumOps + Copy + PartialOrd,
+{
+    match value <= minimum || value - minimum < step {
+        true => minimum,
+        false => value - step,
+    }
+}
+
+/// Check if the text could still become a valid number while typing.
+pub(super) fn accepts_input<Value>(text: &str, minimum: Value) -> bool
+where
+    Value: FromStr + Zero + PartialOrd,
+{
+    match text {
+        "" => true,
+        "-" => minimum < Value::zero(),
+        text => text.parse::<Value>().is_ok(),
+    }
+}
+
+/// Parse the text and clamp it to the allowed range. Returns [`None`] if the
+/// text is not a number.
+pub(super) fn parse_clamped<Value>(text: &str, minimum: Value, maximum: Value) -> Option<Value>
+where
+    Value: FromStr + PartialOrd,
+{
+    text.parse::<Value>().ok().map(|value| clamp(value, minimum, maximum))
+}
+
+#[cfg(test)]
+mod validation {
+    use super::{accepts_input, parse_clamped, step_down, step_up};
+
+    #[test]
+    fn step_within_range() {
+        assert_eq!(step_up(5u32, 2, 10), 7);
+        assert_eq!(step_down(5u32, 2, 0), 3);
+    }
+
+    #[test]
+    fn step_clamps_without_overflow() {
+        assert_eq!(step_up(u32::MAX - 1, 5, u32::MAX), u32::MAX);
+        assert_eq!(step_down(1u32, 5, 0), 0);
+        assert_eq!(step_up(12i32, 1, 10), 10);
+        assert_eq!(step_down(-3i32, 1, 0), 0);
+    }
+
+    #[test]
+    fn partial_input() {
+        assert!(accepts_input::<u32>("", 0));
+        assert!(accepts_input::<u32>("42", 0));
+        assert!(!accepts_input::<u32>("-", 0));
+        assert!(accepts_input::<i32>("-", -10));
+        assert!(!accepts_input::<u32>("4a", 0));
+        assert!(!accepts_input::<u8>("300", 0));
+        assert!(accepts_input::<f32>("1.", 0.0));
+    }
+
+    #[test]
+    fn parse_and_clamp() {
+        assert_eq!(parse_clamped("50", 1u32, 30), Some(30));
+        assert_eq!(parse_clamped("0", 1u32, 30), Some(1));
+        assert_eq!(parse_clamped("12", 1u32, 30), Some(12));
+        assert_eq!(parse_clamped::<u32>("", 1, 30), None);
+    }
+}
@@ -1,9 +1,9 @@
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
 
 use crate::application::{Application, InterfaceRenderer, MouseInputModeTrait, PositionTraitExt, SizeTraitExt};
-use crate::elements::{ButtonBuilder, Element, ElementState, ElementWrap, ScrollView};
-use crate::event::{ClickAction, HoverInformation};
+use crate::elements::{Element, ElementCell, ElementState, ElementWrap, ScrollView};
+use crate::event::{ChangeEvent, ClickAction, HoverInformation, TextEdit};
 use crate::layout::{Dimension, DimensionBound, PlacementResolver, SizeBound};
 use crate::state::{TrackedState, TrackedStateClone};
 use crate::theme::{ButtonTheme, InterfaceTheme};
@@ -24,6 +24,9 @@ where
     state: ElementState<App>,
     latest_position: Rc<RefCell<App::Position>>,
     latest_size: Rc<RefCell<App::Size>>,
+    /// Option that is highlighted with the keyboard while the list is open.
+    highlighted: Rc<Cell<Option<usize>>>,
+    popup: Weak<RefCell<ScrollView<App>>>,
 }
 
 // HACK: Workaround for Rust incorrect trait bounds when deriving Option<T>
@@ -45,6 +48,8 @@ where
             state: Default::default(),
             latest_position: Rc::new(RefCell::new(App::Position::zero())),
             latest_size: Rc::new(RefCell::new(App::Size::zero())),
+            highlighted: Rc::new(Cell::new(None)),
+            popup: Weak::new(),
         }
     }
 }
@@ -76,6 +81,42 @@ where
         self.width_bound = Some(width_bound);
         self
     }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?.get();
+        self.options.iter().position(|(_, value)| *value == *selected)
+    }
+
+    fn select_action(&self, option: Value) -> impl FnMut() -> Vec<ClickAction<App>> + 'static {
+        // FIX: What is the behavior here when slected is none?
+        let mut selected = self.selected.clone().unwrap();
+        let mut event = self.event.clone();
+
+        move || {
+            selected.set(option.clone());
+            let mut actions = vec![ClickAction::ClosePopup];
+
+            if let Some(event) = &mut event {
+                actions.extend(event.trigger());
+            };
+
+            actions
+        }
+    }
+}
+
+/// Move the keyboard highlight of an open [`PickList`]. Returns [`None`] if
+/// the edit doesn't navigate the list.
+fn navigate(highlighted: Option<usize>, edit: TextEdit, option_count: usize) -> Option<usize> {
+    let last = option_count.checked_sub(1)?;
+
+    match edit {
+        TextEdit::Up => Some(highlighted.map_or(last, |index| index.saturating_sub(1))),
+        TextEdit::Down => Some(highlighted.map_or(0, |index| (index + 1).min(last))),
+        TextEdit::Home { .. } => Some(0),
+        TextEdit::End { .. } => Some(last),
+        _ => None,
+    }
 }
 
 impl<App, Key, Value, State, Event> Element<App> for PickList<App, Key, Value, State, Event>
@@ -114,6 +155,15 @@ where
     }
 
     fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<App>> {
+        // Clicking while the list is open (or pressing enter) picks the option that
+        // was highlighted with the keyboard and closes the list.
+        if self.popup.upgrade().is_some() {
+            return match self.highlighted.get().and_then(|index| self.options.get(index)) {
+                Some((_, option)) => (self.select_action(option.clone()))(),
+                None => vec![ClickAction::ClosePopup],
+            };
+        }
+
         let position_tracker = {
             let latest_position = Rc::downgrade(&self.latest_position);
             move || latest_position.upgrade().map(|position| *position.borrow())
@@ -127,26 +177,16 @@ where
         let options = self
             .options
             .iter()
-            .cloned()
-            .map(|(text, option)| {
-                // FIX: What is the behavior here when slected is none?
-                let mut selected = self.selected.clone().unwrap();
-                let mut event = self.event.clone();
-
-                ButtonBuilder::new()
-                    .with_text(text)
-                    .with_event(Box::new(move || {
-                        selected.set(option.clone());
-                        let mut actions = vec![ClickAction::ClosePopup];
-
-                        if let Some(event) = &mut event {
-                            actions.extend(event.trigger());
-                        };
-
-                        actions
-                    }))
-                    .build()
-                    .wrap()
+            .enumerate()
+            .map(|(index, (text, option))| {
+                PickListOption {
+                    text: text.as_ref().to_owned(),
+                    index,
+                    highlighted: self.highlighted.clone(),
+                    action: Box::new(self.select_action(option.clone())),
+                    state: ElementState::default(),
+                }
+                .wrap()
             })
             .collect();
 
@@ -156,9 +196,14 @@ where
             ..SizeBound::only_height(Dimension::Flexible)
         };
 
-        let element = ScrollView::new(options, size_bound)
-            .with_background_color(|theme| theme.button().background_color())
-            .wrap();
+        let popup = Rc::new(RefCell::new(
+            ScrollView::new(options, size_bound).with_background_color(|theme| theme.button().background_color()),
+        ));
+
+        self.popup = Rc::downgrade(&popup);
+        self.highlighted.set(None);
+
+        let element: ElementCell<App> = popup;
 
         vec![ClickAction::OpenPopup {
             element,
@@ -167,6 +212,17 @@ where
         }]
     }
 
+    fn input_text_edit(&mut self, edit: TextEdit) -> Option<ChangeEvent> {
+        let popup = self.popup.upgrade()?;
+        let highlighted = self.highlighted.get().or_else(|| self.selected_index());
+        let highlighted = navigate(highlighted, edit, self.options.len())?;
+
+        self.highlighted.set(Some(highlighted));
+        popup.borrow_mut().scroll_into_view(highlighted);
+
+        Some(ChangeEvent::RENDER_WINDOW)
+    }
+
     fn render(
         &self,
         render_target: &mut <App::Renderer as InterfaceRenderer<App>>::Target,
@@ -212,3 +268,111 @@ where
         }
     }
 }
+
+/// Option in the list of a [`PickList`]. Besides being hovered, it is also
+/// highlighted while it is selected with the keyboard.
+struct PickListOption<App>
+where
+    App: Application,
+{
+    text: String,
+    index: usize,
+    highlighted: Rc<Cell<Option<usize>>>,
+    action: Box<dyn FnMut() -> Vec<ClickAction<App>>>,
+    state: ElementState<App>,
+}
+
+impl<App> Element<App> for PickListOption<App>
+where
+    App: Application,
+{
+    fn get_state(&self) -> &ElementState<App> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<App> {
+        &mut self.state
+    }
+
+    fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, _application: &App, theme: &App::Theme) {
+        let size_bound = DimensionBound::RELATIVE_ONE_HUNDRED.add_height(theme.button().height_bound());
+        self.state.resolve(placement_resolver, &size_bound);
+    }
+
+    fn hovered_element(&self, mouse_position: App::Position, mouse_mode: &App::MouseInputMode) -> HoverInformation<App> {
+        match mouse_mode.is_none() {
+            true => self.state.hovered_element(mouse_position),
+            false => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<App>> {
+        (self.action)()
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <App::Renderer as InterfaceRenderer<App>>::Target,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        parent_position: App::Position,
+        screen_clip: App::Clip,
+        hovered_element: Option<&dyn Element<App>>,
+        _focused_element: Option<&dyn Element<App>>,
+        _mouse_mode: &App::MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let highlighted = self.is_element_self(hovered_element) || self.highlighted.get() == Some(self.index);
+        let (background_color, foreground_color) = match highlighted {
+            true => (
+                theme.button().hovered_background_color(),
+                theme.button().hovered_foreground_color(),
+            ),
+            false => (theme.button().background_color(), theme.button().foreground_color()),
+        };
+
+        renderer.render_background(theme.button().corner_radius(), background_color);
+        renderer.render_text(
+            &self.text,
+            theme.button().text_offset(),
+            foreground_color,
+            theme.button().font_size(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod navigation {
+    use super::navigate;
+    use crate::event::TextEdit;
+
+    #[test]
+    fn empty_list() {
+        assert_eq!(navigate(None, TextEdit::Down, 0), None);
+    }
+
+    #[test]
+    fn start_at_either_end() {
+        assert_eq!(navigate(None, TextEdit::Down, 3), Some(0));
+        assert_eq!(navigate(None, TextEdit::Up, 3), Some(2));
+    }
+
+    #[test]
+    fn stop_at_either_end() {
+        assert_eq!(navigate(Some(2), TextEdit::Down, 3), Some(2));
+        assert_eq!(navigate(Some(0), TextEdit::Up, 3), Some(0));
+        assert_eq!(navigate(Some(1), TextEdit::Down, 3), Some(2));
+    }
+
+    #[test]
+    fn jump_to_either_end() {
+        assert_eq!(navigate(Some(1), TextEdit::Home { select: false }, 3), Some(0));
+        assert_eq!(navigate(Some(1), TextEdit::End { select: false }, 3), Some(2));
+        assert_eq!(navigate(Some(1), TextEdit::SelectAll, 3), None);
+    }
+}
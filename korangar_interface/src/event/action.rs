@@ -22,5 +22,9 @@ where
         size_tracker: Tracker<App::Size>,
     },
     ClosePopup,
+    /// Change the background opacity of the window.
+    SetWindowOpacity(f32),
+    /// Lock or unlock the position of the window.
+    ToggleWindowLock,
     Custom(App::CustomEvent),
 }
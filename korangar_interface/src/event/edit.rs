@@ -0,0 +1,31 @@
+/// Keyboard commands for editing text that are not sent as characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextEdit {
+    /// Move the cursor one character or word to the left.
+    Left {
+        word: bool,
+        select: bool,
+    },
+    /// Move the cursor one character or word to the right.
+    Right {
+        word: bool,
+        select: bool,
+    },
+    /// Move the cursor to the start of the text.
+    Home {
+        select: bool,
+    },
+    /// Move the cursor to the end of the text.
+    End {
+        select: bool,
+    },
+    SelectAll,
+    /// Delete the selection or the character or word after the cursor.
+    Delete {
+        word: bool,
+    },
+    /// Recall the previous entry of the input history.
+    Up,
+    /// Recall the next entry of the input history.
+    Down,
+}
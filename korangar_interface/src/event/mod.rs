@@ -1,7 +1,9 @@
 mod action;
 mod change;
+mod edit;
 mod hover;
 
 pub use self::action::ClickAction;
 pub use self::change::*;
+pub use self::edit::TextEdit;
 pub use self::hover::HoverInformation;
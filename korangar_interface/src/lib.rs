@@ -12,6 +12,7 @@ pub mod event;
 pub mod layout;
 pub mod state;
 pub mod theme;
+pub mod tooltip;
 #[macro_use]
 pub mod elements;
 pub mod builder;
@@ -19,15 +20,17 @@ pub mod windows;
 
 use std::marker::PhantomData;
 
-use application::{Application, FocusState, InterfaceRenderer, SizeTrait, SizeTraitExt, WindowCache};
+use application::{
+    Application, ClipTrait, ClipTraitExt, FocusState, InterfaceRenderer, PositionTrait, SizeTrait, SizeTraitExt, WindowCache,
+};
 use elements::ElementCell;
-use event::{ChangeEvent, ClickAction, HoverInformation};
+use event::{ChangeEvent, ClickAction, HoverInformation, TextEdit};
 // Re-export proc macros.
 pub use interface_procedural::{dimension_bound, size_bound};
 #[cfg(feature = "debug")]
 use korangar_debug::profile_block;
 use option_ext::OptionExt;
-use windows::{PrototypeWindow, Window};
+use windows::{PrototypeWindow, Window, WindowSettings};
 
 use crate::application::MouseInputModeTrait;
 
@@ -123,6 +126,10 @@ where
     window_cache: App::Cache,
     available_space: App::Size,
     post_update: PostUpdate<Self>,
+    /// Area that needs to be cleared and re-rendered because a window with
+    /// transparency changed. Every window overlapping it is re-rendered
+    /// clipped to the area, which avoids re-rendering the entire interface.
+    dirty_area: Option<(App::Position, App::Size)>,
 }
 
 impl<App> Interface<App>
@@ -139,13 +146,24 @@ where
             window_cache,
             available_space,
             post_update,
+            dirty_area: None,
         }
     }
 
+    /// Replace the window cache, for example to switch to a different window
+    /// layout. Windows that are already open keep their position.
+    pub fn set_window_cache(&mut self, window_cache: App::Cache) {
+        self.window_cache = window_cache;
+    }
+
     pub fn schedule_render(&mut self) {
         self.post_update.render();
     }
 
+    pub fn schedule_resolve(&mut self) {
+        self.post_update.resolve();
+    }
+
     pub fn schedule_render_window(&mut self, window_index: usize) {
         if window_index < self.windows.len() {
             let (_, post_update) = &mut self.windows[window_index];
@@ -230,8 +248,15 @@ where
             self.flag_render_windows(application, 0, None);
         }
 
+        // Rendering the entire interface also covers the dirty area.
+        if self.post_update.needs_render() {
+            self.dirty_area = None;
+        }
+
         let render_interface = self.post_update.needs_render();
-        let render_window = self.post_update.needs_render() | self.windows.iter().any(|(_window, post_update)| post_update.needs_render());
+        let render_window = self.post_update.needs_render()
+            | self.dirty_area.is_some()
+            | self.windows.iter().any(|(_window, post_update)| post_update.needs_render());
 
         (render_interface, render_window)
     }
@@ -337,14 +362,71 @@ where
     }
 
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
-    pub fn move_window(&mut self, window_index: usize, offset: App::Position) {
-        if let Some((window_class, anchor)) = self.windows[window_index].0.offset(self.available_space, offset) {
+    pub fn input_preedit_element(&mut self, element: &ElementCell<App>, window_index: usize, text: &str) {
+        let (_, post_update) = &mut self.windows[window_index];
+
+        if let Some(change_event) = element.borrow_mut().input_preedit(text) {
+            Self::handle_change_event(&mut self.post_update, post_update, change_event);
+        }
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn input_text_edit_element(&mut self, element: &ElementCell<App>, window_index: usize, edit: TextEdit) {
+        let (_, post_update) = &mut self.windows[window_index];
+
+        if let Some(change_event) = element.borrow_mut().input_text_edit(edit) {
+            Self::handle_change_event(&mut self.post_update, post_update, change_event);
+        }
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn move_window(&mut self, application: &App, window_index: usize, offset: App::Position) {
+        let other_windows: Vec<_> = self
+            .windows
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != window_index)
+            .map(|(_, (window, _))| window.get_area())
+            .collect();
+
+        if let Some((window_class, anchor)) = self.windows[window_index]
+            .0
+            .offset(application, self.available_space, offset, &other_windows)
+        {
             self.window_cache.update_anchor(window_class, anchor);
         }
 
         self.post_update.render();
     }
 
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn set_window_opacity(&mut self, window_index: usize, opacity: f32) {
+        self.update_window_settings(window_index, |settings| settings.opacity = opacity);
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn toggle_window_lock(&mut self, window_index: usize) {
+        self.update_window_settings(window_index, |settings| settings.locked = !settings.locked);
+    }
+
+    fn update_window_settings(&mut self, window_index: usize, update: impl FnOnce(&mut WindowSettings)) {
+        let window = &mut self.windows[window_index].0;
+        let mut settings = window.get_settings();
+        update(&mut settings);
+
+        if let Some(window_class) = window.update_settings(settings) {
+            self.window_cache.update_settings(window_class, settings);
+        }
+
+        self.post_update.render();
+    }
+
+    pub fn stop_moving_window(&mut self, window_index: usize) {
+        if let Some((window, _)) = self.windows.get_mut(window_index) {
+            window.stop_moving();
+        }
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn resize_window(&mut self, application: &App, window_index: usize, growth: App::Size) {
         let (window, post_update) = &mut self.windows[window_index];
@@ -365,10 +447,29 @@ where
         }
     }
 
+    /// Extend the dirty area to include the given area. The area is rounded
+    /// to whole pixels so that clearing it doesn't leave partially covered
+    /// pixels at the border.
+    fn mark_dirty(&mut self, position: App::Position, size: App::Size) {
+        let mut left = position.left().floor();
+        let mut top = position.top().floor();
+        let mut right = (position.left() + size.width()).ceil();
+        let mut bottom = (position.top() + size.height()).ceil();
+
+        if let Some((dirty_position, dirty_size)) = self.dirty_area {
+            left = left.min(dirty_position.left());
+            top = top.min(dirty_position.top());
+            right = right.max(dirty_position.left() + dirty_size.width());
+            bottom = bottom.max(dirty_position.top() + dirty_size.height());
+        }
+
+        self.dirty_area = Some((App::Position::new(left, top), App::Size::new(right - left, bottom - top)));
+    }
+
     /// This function is solely responsible for making sure that trying to
-    /// re-render a window with transparency will result in re-rendering the
-    /// entire interface. This serves as a single point of truth and simplifies
-    /// the rest of the code.
+    /// re-render a window with transparency will result in clearing and
+    /// re-rendering the area it covers. This serves as a single point of truth
+    /// and simplifies the rest of the code.
     fn flag_render_windows(&mut self, application: &App, start_index: usize, area: Option<(App::Position, App::Size)>) {
         for window_index in start_index..self.windows.len() {
             let needs_render = self.windows[window_index].1.needs_render();
@@ -380,14 +481,19 @@ where
 
                     let kind = window.get_theme_kind();
                     let theme = application.get_theme(kind);
-
-                    if window.has_transparency(theme) {
-                        self.post_update.render();
-                        return;
+                    let area = window.get_area();
+
+                    // Windows with transparency can't just be drawn over the previous frame, so
+                    // they are rendered as part of the dirty area instead.
+                    match window.has_transparency(theme) {
+                        true => {
+                            post_update.take_render();
+                            self.mark_dirty(area.0, area.1);
+                        }
+                        false => post_update.render(),
                     }
 
-                    post_update.render();
-                    window.get_area()
+                    area
                 };
 
                 self.flag_render_windows(application, window_index + 1, Some((position, scale)));
@@ -407,12 +513,32 @@ where
     ) {
         let hovered_element = hovered_element.map(|element| unsafe { &*element.as_ptr() });
         let focused_element = focused_element.map(|element| unsafe { &*element.as_ptr() });
+        let dirty_area = self.dirty_area.take();
+
+        if let Some((position, size)) = dirty_area {
+            renderer.clear_area(render_target, position, size);
+        }
 
         for (index, (window, post_update)) in self.windows.iter_mut().enumerate() {
-            if post_update.take_render() || self.post_update.needs_render() {
+            let render_window = post_update.take_render() || self.post_update.needs_render();
+            let in_dirty_area = dirty_area.is_some_and(|(position, size)| window.hovers_area(position, size));
+
+            if render_window || in_dirty_area {
                 #[cfg(feature = "debug")]
                 profile_block!("render window");
 
+                // Windows that don't need to be rendered themselves only need to fill the
+                // cleared area.
+                let area_clip = match (render_window, dirty_area) {
+                    (false, Some((position, size))) => App::Clip::new(
+                        position.left(),
+                        position.top(),
+                        position.left() + size.width(),
+                        position.top() + size.height(),
+                    ),
+                    _ => App::Clip::unbound(),
+                };
+
                 let kind = window.get_theme_kind();
                 let theme = application.get_theme(kind);
 
@@ -424,6 +550,7 @@ where
                     hovered_element,
                     focused_element,
                     mouse_mode,
+                    area_clip,
                 );
 
                 if mouse_mode.is_moving_window(index) {
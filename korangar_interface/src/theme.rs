@@ -124,6 +124,7 @@ where
     fn text_color(&self) -> App::Color;
     fn ghost_text_color(&self) -> App::Color;
     fn focused_text_color(&self) -> App::Color;
+    fn selection_color(&self) -> App::Color;
     fn corner_radius(&self) -> App::CornerRadius;
     fn font_size(&self) -> App::FontSize;
     fn text_offset(&self) -> App::Position;
@@ -142,6 +143,7 @@ where
     fn server_color(&self) -> App::Color;
     fn error_color(&self) -> App::Color;
     fn information_color(&self) -> App::Color;
+    fn whisper_color(&self) -> App::Color;
 }
 
 pub trait CursorTheme<App>
@@ -0,0 +1,137 @@
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::application::Application;
+use crate::elements::{Element, ElementCell, WeakElementCell};
+use crate::windows::PrototypeWindow;
+
+/// Information that is shown after hovering an element for a while.
+pub enum Tooltip<App>
+where
+    App: Application,
+{
+    /// Text that is displayed next to the mouse.
+    Text(String),
+    /// Window for information that doesn't fit into a line of text, like the
+    /// description of an item.
+    Window(Box<dyn PrototypeWindow<App>>),
+}
+
+/// Extension trait to add a text tooltip to any element.
+pub trait WithTooltip<App> {
+    fn with_tooltip(self, text: impl Into<String>) -> Self;
+}
+
+impl<App, T> WithTooltip<App> for T
+where
+    App: Application,
+    T: Element<App>,
+{
+    fn with_tooltip(mut self, text: impl Into<String>) -> Self {
+        self.get_state_mut().tooltip = Some(text.into());
+        self
+    }
+}
+
+/// Keeps track of how long the same element has been hovered, so that its
+/// tooltip is only shown after a delay.
+pub struct TooltipTracker<App>
+where
+    App: Application,
+{
+    hovered_element: Option<WeakElementCell<App>>,
+    hovered_since: Instant,
+    text: Option<String>,
+    shown: bool,
+}
+
+impl<App> Default for TooltipTracker<App>
+where
+    App: Application,
+{
+    fn default() -> Self {
+        Self {
+            hovered_element: None,
+            hovered_since: Instant::now(),
+            text: None,
+            shown: false,
+        }
+    }
+}
+
+impl<App> TooltipTracker<App>
+where
+    App: Application,
+{
+    /// Returns a window once the delay of a window tooltip has passed. Text
+    /// tooltips are kept until the hovered element changes and can be
+    /// retrieved with [`get_text`](Self::get_text).
+    pub fn update(&mut self, hovered_element: Option<&ElementCell<App>>, delay: Duration) -> Option<Box<dyn PrototypeWindow<App>>> {
+        let is_same_element = match (&self.hovered_element, hovered_element) {
+            (Some(previous), Some(current)) => previous.ptr_eq(&Rc::downgrade(current)),
+            (None, None) => true,
+            _ => false,
+        };
+
+        if !is_same_element {
+            self.hovered_element = hovered_element.map(Rc::downgrade);
+            self.hovered_since = Instant::now();
+            self.text = None;
+            self.shown = false;
+        }
+
+        if self.shown || self.hovered_since.elapsed() < delay {
+            return None;
+        }
+
+        let element = self.hovered_element.as_ref()?.upgrade()?;
+        self.shown = true;
+
+        let tooltip = element.borrow().tooltip()?;
+        match tooltip {
+            Tooltip::Text(text) => {
+                self.text = Some(text);
+                None
+            }
+            Tooltip::Window(window) => Some(window),
+        }
+    }
+
+    pub fn get_text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+/// Place a tooltip along one axis. The tooltip is placed after the mouse if
+/// it fits and before the mouse otherwise, but never outside of the
+/// available space.
+pub fn place_tooltip(mouse: f32, size: f32, available: f32, offset: f32) -> f32 {
+    let after = mouse + offset;
+    let position = match after + size <= available {
+        true => after,
+        false => mouse - offset - size,
+    };
+
+    position.clamp(0.0, (available - size).max(0.0))
+}
+
+#[cfg(test)]
+mod placement {
+    use super::place_tooltip;
+
+    #[test]
+    fn after_mouse() {
+        assert_eq!(place_tooltip(100.0, 50.0, 1000.0, 10.0), 110.0);
+    }
+
+    #[test]
+    fn before_mouse_at_the_edge() {
+        assert_eq!(place_tooltip(980.0, 50.0, 1000.0, 10.0), 920.0);
+    }
+
+    #[test]
+    fn stays_on_screen() {
+        assert_eq!(place_tooltip(30.0, 990.0, 1000.0, 10.0), 0.0);
+        assert_eq!(place_tooltip(30.0, 2000.0, 1000.0, 10.0), 0.0);
+    }
+}
@@ -65,6 +65,13 @@ impl<App> Anchor<App>
 where
     App: Application,
 {
+    pub fn new(anchor_point: AnchorPoint) -> Self {
+        Self {
+            anchor_point,
+            offset: App::Position::zero(),
+        }
+    }
+
     pub fn update(&mut self, available_space: App::Size, position: App::Position, size: App::Size) {
         let center = Anchor {
             offset: position
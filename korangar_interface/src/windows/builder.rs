@@ -1,7 +1,8 @@
+use std::cell::Cell;
 use std::marker::PhantomData;
 use std::rc::Rc;
 
-use super::{Anchor, Window};
+use super::{Anchor, AnchorPoint, Window};
 use crate::application::{Application, PartialSizeTraitExt, WindowCache};
 use crate::builder::{Set, Unset};
 use crate::elements::{CloseButtonBuilder, Container, DragButtonBuilder, ElementCell, ElementWrap};
@@ -25,6 +26,8 @@ where
     elements: Elements,
     background_color: Option<ColorSelector<App>>,
     theme_kind: App::ThemeKind,
+    position: Option<App::Position>,
+    default_anchor: Option<AnchorPoint>,
     marker: PhantomData<(Title, Closable, Class, Background, Theme)>,
 }
 
@@ -41,6 +44,8 @@ where
             elements: Unset,
             background_color: None,
             theme_kind: App::ThemeKind::default(),
+            position: None,
+            default_anchor: None,
             marker: PhantomData,
         }
     }
@@ -166,6 +171,32 @@ where
     }
 }
 
+impl<App, Title, Closable, Class, Size, Elements, Background, Theme>
+    WindowBuilder<App, Title, Closable, Class, Size, Elements, Background, Theme>
+where
+    App: Application,
+{
+    /// Open the window at a specific position instead of the cached or default
+    /// one, for example at the mouse position. Calling this multiple times
+    /// will overwrite the previous position.
+    pub fn with_position(self, position: App::Position) -> Self {
+        Self {
+            position: Some(position),
+            ..self
+        }
+    }
+
+    /// Corner or edge of the screen that the window is docked to when it is
+    /// opened for the first time. Windows without a cached position start out
+    /// in the center of the screen otherwise.
+    pub fn with_default_anchor(self, anchor_point: AnchorPoint) -> Self {
+        Self {
+            default_anchor: Some(anchor_point),
+            ..self
+        }
+    }
+}
+
 impl<App, Title, Closable, Class, Background, Theme>
     WindowBuilder<App, Title, Closable, Class, SizeBound, Vec<ElementCell<App>>, Background, Theme>
 where
@@ -185,6 +216,8 @@ where
             mut elements,
             background_color,
             theme_kind,
+            position,
+            default_anchor,
             ..
         } = self;
 
@@ -193,6 +226,12 @@ where
             elements.insert(0, close_button);
         }
 
+        let settings = class
+            .as_ref()
+            .and_then(|window_class| window_cache.get_window_settings(window_class))
+            .unwrap_or_default();
+        let settings = Rc::new(Cell::new(settings));
+
         if let Some(title) = title {
             // FIX: Any bound will never work properly, use a different way of allocating.
             let width_bound = match closable {
@@ -211,6 +250,7 @@ where
             let drag_button = DragButtonBuilder::new()
                 .with_title(title)
                 .with_width_bound(width_bound)
+                .with_window_settings(settings.clone())
                 .build()
                 .wrap();
             elements.insert(0, drag_button);
@@ -241,7 +281,7 @@ where
             .and_then(|window_class| window_cache.get_window_state(window_class))
             .unzip();
 
-        let anchor = cached_anchor.unwrap_or(Anchor::default());
+        let mut anchor = cached_anchor.unwrap_or_else(|| default_anchor.map(Anchor::new).unwrap_or_default());
         let size = cached_size
             .map(|size| size_bound.validated_window_size(size, available_space, application.get_scaling()))
             .unwrap_or_else(|| {
@@ -250,6 +290,10 @@ where
                     .finalize_or(0.0)
             });
 
+        if let Some(position) = position {
+            anchor.update(available_space, position, size);
+        }
+
         let position = anchor.current_position(available_space, size);
 
         Window {
@@ -260,6 +304,8 @@ where
             size,
             elements,
             popup_element: None,
+            drag_position: None,
+            settings,
             closable,
             background_color,
             theme_kind,
@@ -1,19 +1,30 @@
 mod anchor;
 mod builder;
 mod prototype;
+mod settings;
+mod snap;
 
+use std::cell::Cell;
 use std::rc::Rc;
 
 pub use self::anchor::{Anchor, AnchorPoint};
 pub use self::builder::WindowBuilder;
 pub use self::prototype::PrototypeWindow;
-use crate::application::{Application, ClipTrait, ColorTrait, InterfaceRenderer, PositionTrait, PositionTraitExt, SizeTrait, SizeTraitExt};
+pub use self::settings::WindowSettings;
+use self::snap::{snap_area, Area};
+use crate::application::{
+    Application, ClipTrait, ColorTrait, InterfaceRenderer, PositionTrait, PositionTraitExt, ScalingTrait, SizeTrait, SizeTraitExt,
+};
 use crate::elements::{Element, ElementCell, Focus};
 use crate::event::{ChangeEvent, HoverInformation};
 use crate::layout::{Dimension, PlacementResolver, SizeBound};
 use crate::theme::{InterfaceTheme, WindowTheme};
 use crate::{ColorSelector, Tracker};
 
+/// Distance at which moved windows snap to the edges of the screen and other
+/// windows.
+const SNAP_DISTANCE: f32 = 10.0;
+
 pub struct Window<App>
 where
     App: Application,
@@ -25,6 +36,11 @@ where
     size: App::Size,
     elements: Vec<ElementCell<App>>,
     popup_element: Option<(ElementCell<App>, Tracker<App::Position>, Tracker<App::Size>)>,
+    /// Position of the window while it is being moved, before snapping it.
+    drag_position: Option<App::Position>,
+    /// Shared with the title bar, which displays the current settings in its
+    /// menu.
+    settings: Rc<Cell<WindowSettings>>,
     closable: bool,
     background_color: Option<ColorSelector<App>>,
     theme_kind: App::ThemeKind,
@@ -43,6 +59,7 @@ where
             .as_ref()
             .map(|closure| closure(theme))
             .unwrap_or(theme.window().background_color())
+            .with_opacity(self.settings.get().opacity)
     }
 
     pub fn has_transparency(&self, theme: &App::Theme) -> bool {
@@ -192,8 +209,40 @@ where
             && self.position.top() < area_combined.top()
     }
 
-    pub fn offset(&mut self, available_space: App::Size, offset: App::Position) -> Option<(&str, Anchor<App>)> {
-        self.position = self.position.combined(offset);
+    pub fn offset(
+        &mut self,
+        application: &App,
+        available_space: App::Size,
+        offset: App::Position,
+        other_windows: &[(App::Position, App::Size)],
+    ) -> Option<(&str, Anchor<App>)> {
+        if self.settings.get().locked {
+            return None;
+        }
+
+        let drag_position = self.drag_position.unwrap_or(self.position).combined(offset);
+        let drag_position = self.size_bound.validated_position(drag_position, self.size, available_space);
+        self.drag_position = Some(drag_position);
+
+        let to_area = |position: App::Position, size: App::Size| Area {
+            left: position.left(),
+            top: position.top(),
+            width: size.width(),
+            height: size.height(),
+        };
+        let other_windows: Vec<Area> = other_windows.iter().map(|(position, size)| to_area(*position, *size)).collect();
+        let scaling = application.get_scaling().get_factor();
+
+        let snapped = snap_area(
+            to_area(drag_position, self.size),
+            available_space.width(),
+            available_space.height(),
+            &other_windows,
+            SNAP_DISTANCE * scaling,
+            application.get_window_grid() * scaling,
+        );
+
+        self.position = App::Position::new(snapped.left, snapped.top);
         self.anchor.update(available_space, self.position, self.size);
 
         self.validate_position(available_space);
@@ -203,6 +252,21 @@ where
             .map(|window_class| (window_class.as_str(), self.anchor.clone()))
     }
 
+    pub fn get_settings(&self) -> WindowSettings {
+        self.settings.get()
+    }
+
+    pub fn update_settings(&mut self, settings: WindowSettings) -> Option<&str> {
+        self.settings.set(settings);
+        self.window_class.as_deref()
+    }
+
+    /// Stop snapping the window relative to the position it had when it
+    /// started moving.
+    pub fn stop_moving(&mut self) {
+        self.drag_position = None;
+    }
+
     fn validate_position(&mut self, available_space: App::Size) {
         self.position = self.anchor.current_position(available_space, self.size);
         self.position = self.size_bound.validated_position(self.position, self.size, available_space);
@@ -241,12 +305,13 @@ where
         hovered_element: Option<&dyn Element<App>>,
         focused_element: Option<&dyn Element<App>>,
         mouse_mode: &App::MouseInputMode,
+        area_clip: App::Clip,
     ) {
         let screen_clip = App::Clip::new(
-            self.position.left(),
-            self.position.top(),
-            self.position.left() + self.size.width(),
-            self.position.top() + self.size.height(),
+            self.position.left().max(area_clip.left()),
+            self.position.top().max(area_clip.top()),
+            (self.position.left() + self.size.width()).min(area_clip.right()),
+            (self.position.top() + self.size.height()).min(area_clip.bottom()),
         );
 
         renderer.render_rectangle(
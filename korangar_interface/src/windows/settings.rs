@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Settings that can be changed for every window individually from the menu
+/// of its title bar.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowSettings {
+    /// Opacity of the window background.
+    pub opacity: f32,
+    /// Prevent the window from being moved.
+    pub locked: bool,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            locked: false,
+        }
+    }
+}
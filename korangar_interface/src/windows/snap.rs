@@ -0,0 +1,128 @@
+/// Screen space occupied by a window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct Area {
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Area {
+    fn right(&self) -> f32 {
+        self.left + self.width
+    }
+
+    fn bottom(&self) -> f32 {
+        self.top + self.height
+    }
+
+    fn overlaps_horizontally(&self, other: &Area, distance: f32) -> bool {
+        self.left - distance <= other.right() && other.left <= self.right() + distance
+    }
+
+    fn overlaps_vertically(&self, other: &Area, distance: f32) -> bool {
+        self.top - distance <= other.bottom() && other.top <= self.bottom() + distance
+    }
+}
+
+/// Get the offset that moves the closest edge onto the closest target, if it
+/// is within the snap distance.
+fn snap_axis(start: f32, length: f32, targets: &[f32], distance: f32) -> Option<f32> {
+    targets
+        .iter()
+        .flat_map(|target| [target - start, target - (start + length)])
+        .filter(|offset| offset.abs() <= distance)
+        .min_by(|left, right| left.abs().total_cmp(&right.abs()))
+}
+
+fn snap_to_grid(value: f32, grid: f32) -> f32 {
+    match grid > 0.0 {
+        true => (value / grid).round() * grid,
+        false => value,
+    }
+}
+
+/// Snap a window to the edges of the screen and the edges of other windows.
+/// Axes that are not close to any edge snap to the grid instead. A grid size
+/// of zero disables grid snapping.
+pub(super) fn snap_area(area: Area, available_width: f32, available_height: f32, other_windows: &[Area], distance: f32, grid: f32) -> Area {
+    let mut horizontal_targets = vec![0.0, available_width];
+    let mut vertical_targets = vec![0.0, available_height];
+
+    // Only snap to windows that are next to this one, otherwise windows would snap
+    // to edges on the other side of the screen.
+    for other in other_windows {
+        if area.overlaps_vertically(other, distance) {
+            horizontal_targets.extend([other.left, other.right()]);
+        }
+
+        if area.overlaps_horizontally(other, distance) {
+            vertical_targets.extend([other.top, other.bottom()]);
+        }
+    }
+
+    let left = snap_axis(area.left, area.width, &horizontal_targets, distance)
+        .map(|offset| area.left + offset)
+        .unwrap_or_else(|| snap_to_grid(area.left, grid));
+    let top = snap_axis(area.top, area.height, &vertical_targets, distance)
+        .map(|offset| area.top + offset)
+        .unwrap_or_else(|| snap_to_grid(area.top, grid));
+
+    Area { left, top, ..area }
+}
+
+#[cfg(test)]
+mod snapping {
+    use super::{snap_area, Area};
+
+    const AVAILABLE_WIDTH: f32 = 1000.0;
+    const AVAILABLE_HEIGHT: f32 = 800.0;
+    const DISTANCE: f32 = 10.0;
+
+    fn area(left: f32, top: f32) -> Area {
+        Area {
+            left,
+            top,
+            width: 200.0,
+            height: 100.0,
+        }
+    }
+
+    #[test]
+    fn snap_to_screen_edges() {
+        let snapped = snap_area(area(6.0, 695.0), AVAILABLE_WIDTH, AVAILABLE_HEIGHT, &[], DISTANCE, 0.0);
+
+        assert_eq!(snapped, area(0.0, 700.0));
+    }
+
+    #[test]
+    fn snap_to_other_window() {
+        let other = area(300.0, 300.0);
+        let snapped = snap_area(area(508.0, 296.0), AVAILABLE_WIDTH, AVAILABLE_HEIGHT, &[other], DISTANCE, 0.0);
+
+        assert_eq!(snapped, area(500.0, 300.0));
+    }
+
+    #[test]
+    fn ignore_distant_window() {
+        // The edges line up horizontally, but the window is far below.
+        let other = area(300.0, 600.0);
+        let snapped = snap_area(area(508.0, 100.0), AVAILABLE_WIDTH, AVAILABLE_HEIGHT, &[other], DISTANCE, 0.0);
+
+        assert_eq!(snapped, area(508.0, 100.0));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let snapped = snap_area(area(123.0, 356.0), AVAILABLE_WIDTH, AVAILABLE_HEIGHT, &[], DISTANCE, 25.0);
+
+        assert_eq!(snapped, area(125.0, 350.0));
+    }
+
+    #[test]
+    fn edges_take_priority_over_grid() {
+        let snapped = snap_area(area(4.0, 356.0), AVAILABLE_WIDTH, AVAILABLE_HEIGHT, &[], DISTANCE, 25.0);
+
+        assert_eq!(snapped, area(0.0, 350.0));
+    }
+}
@@ -140,7 +140,7 @@ async fn main() {
                     let login_login_data = saved_login_data.as_ref().unwrap();
 
                     networking_system.disconnect_from_character_server();
-                    networking_system.connect_to_map_server(login_login_data, login_data);
+                    networking_system.connect_to_map_server(login_login_data, login_data, None);
 
                     networking_system.map_loaded().expect("Map server disconnected");
                 }
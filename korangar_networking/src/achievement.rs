@@ -0,0 +1,53 @@
+use ragnarok_packets::{AchievementData, AchievementListPacket, AchievementUpdatePacket};
+
+/// Overall achievement progress of the player.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AchievementSummary {
+    pub total_score: u32,
+    pub level: u16,
+    pub experience: u32,
+    pub experience_to_next_level: u32,
+}
+
+impl From<&AchievementListPacket> for AchievementSummary {
+    fn from(packet: &AchievementListPacket) -> Self {
+        Self {
+            total_score: packet.total_score,
+            level: packet.level,
+            experience: packet.acheivement_experience,
+            experience_to_next_level: packet.acheivement_experience_to_next_level,
+        }
+    }
+}
+
+impl From<&AchievementUpdatePacket> for AchievementSummary {
+    fn from(packet: &AchievementUpdatePacket) -> Self {
+        Self {
+            total_score: packet.total_score,
+            level: packet.level,
+            experience: packet.acheivement_experience,
+            experience_to_next_level: packet.acheivement_experience_to_next_level,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AchievementProgress {
+    pub achievement_id: u32,
+    pub completed: bool,
+    pub objectives: [u32; 10],
+    pub completion_timestamp: u32,
+    pub rewarded: bool,
+}
+
+impl From<AchievementData> for AchievementProgress {
+    fn from(data: AchievementData) -> Self {
+        Self {
+            achievement_id: data.acheivement_id,
+            completed: data.is_completed != 0,
+            objectives: data.objectives,
+            completion_timestamp: data.completion_timestamp,
+            rewarded: data.got_rewarded != 0,
+        }
+    }
+}
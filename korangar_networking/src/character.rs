@@ -0,0 +1,31 @@
+use ragnarok_packets::Sex;
+
+/// Packet version starting from which the server expects the starting job and
+/// sex of a new character.
+pub(crate) const CREATE_CHARACTER_JOB_PACKET_VERSION: u32 = 20151001;
+/// Packet version starting from which the server no longer expects the initial
+/// stats of a new character.
+pub(crate) const CREATE_CHARACTER_NO_STATS_PACKET_VERSION: u32 = 20120307;
+/// Packet version starting from which characters are scheduled for deletion
+/// instead of being deleted right away.
+pub(crate) const DELAYED_CHARACTER_DELETION_PACKET_VERSION: u32 = 20100803;
+
+/// Looks of a character chosen during character creation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CharacterAppearance {
+    pub hair_style: u16,
+    pub hair_color: u16,
+    pub start_job: u16,
+    pub sex: Sex,
+}
+
+impl Default for CharacterAppearance {
+    fn default() -> Self {
+        Self {
+            hair_style: 1,
+            hair_color: 0,
+            start_job: 0,
+            sex: Sex::Male,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+/// Body states of the state change packet for a character that was turned to
+/// stone, or that is still turning to stone.
+const BODY_STATES_STONE: [u16; 2] = [1, 6];
+/// Option flags of the state change packet that make a character invisible to
+/// other players.
+const OPTION_HIDE: u32 = 0x02;
+const OPTION_CLOAK: u32 = 0x04;
+const OPTION_CHASE_WALK: u32 = 0x4000;
+
+/// Conditions of an entity that change how it looks without changing its
+/// sprites.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EntityCondition {
+    pub petrified: bool,
+    /// Hiding or cloaking, so only the player itself can see the entity.
+    pub hidden: bool,
+}
+
+impl EntityCondition {
+    /// Condition of an entity from the body state and option flags of a state
+    /// change.
+    pub fn from_state(body_state: u16, options: u32) -> Self {
+        Self {
+            petrified: BODY_STATES_STONE.contains(&body_state),
+            hidden: options & (OPTION_HIDE | OPTION_CLOAK | OPTION_CHASE_WALK) != 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use super::EntityCondition;
+
+    #[test]
+    fn petrified() {
+        assert!(EntityCondition::from_state(1, 0).petrified);
+        assert!(EntityCondition::from_state(6, 0).petrified);
+        assert!(!EntityCondition::from_state(2, 0).petrified);
+    }
+
+    #[test]
+    fn hidden() {
+        assert!(EntityCondition::from_state(0, 0x02).hidden);
+        assert!(EntityCondition::from_state(0, 0x04).hidden);
+        assert!(EntityCondition::from_state(0, 0x4000 | 0x20).hidden);
+        assert!(!EntityCondition::from_state(0, 0x20).hidden);
+    }
+
+    #[test]
+    fn normal() {
+        assert_eq!(EntityCondition::from_state(0, 0), EntityCondition::default());
+    }
+}
@@ -0,0 +1,44 @@
+/// Damage types of the damage packet that are critical hits.
+const CRITICAL_DAMAGE_TYPES: [u8; 2] = [10, 13];
+/// Damage type of an attack that was dodged thanks to perfect dodge.
+const LUCKY_DODGE_DAMAGE_TYPE: u8 = 11;
+
+/// How a hit landed, which decides how its damage is shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageKind {
+    Normal,
+    Critical,
+    Miss,
+    LuckyDodge,
+}
+
+impl DamageKind {
+    pub(crate) fn from_damage_type(damage_type: u8, damage_amount: u32) -> Self {
+        match damage_type {
+            LUCKY_DODGE_DAMAGE_TYPE => Self::LuckyDodge,
+            _ if damage_amount == 0 => Self::Miss,
+            damage_type if CRITICAL_DAMAGE_TYPES.contains(&damage_type) => Self::Critical,
+            _ => Self::Normal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod damage_types {
+    use super::DamageKind;
+
+    #[test]
+    fn hits() {
+        assert_eq!(DamageKind::from_damage_type(0, 25), DamageKind::Normal);
+        assert_eq!(DamageKind::from_damage_type(8, 25), DamageKind::Normal);
+        assert_eq!(DamageKind::from_damage_type(10, 25), DamageKind::Critical);
+        assert_eq!(DamageKind::from_damage_type(13, 25), DamageKind::Critical);
+    }
+
+    #[test]
+    fn no_damage() {
+        assert_eq!(DamageKind::from_damage_type(0, 0), DamageKind::Miss);
+        assert_eq!(DamageKind::from_damage_type(10, 0), DamageKind::Miss);
+        assert_eq!(DamageKind::from_damage_type(11, 0), DamageKind::LuckyDodge);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::EntityId;
+
+/// Maximum number of detail requests that are sent at once.
+const MAXIMUM_BATCH_SIZE: usize = 8;
+/// Minimum time between two batches of detail requests.
+const BATCH_INTERVAL: Duration = Duration::from_millis(100);
+/// Time after which an unanswered request is sent again.
+const RETRY_TIMEOUT: Duration = Duration::from_secs(2);
+/// Number of times a request is sent before giving up on it.
+const MAXIMUM_ATTEMPTS: usize = 3;
+
+/// Name of an entity and, for players, the party and guild that they are in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntityDetails {
+    pub name: String,
+    pub party_name: Option<String>,
+    pub guild_name: Option<String>,
+}
+
+impl EntityDetails {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            party_name: None,
+            guild_name: None,
+        }
+    }
+
+    /// The server sends empty names for players that are not in a party or
+    /// guild.
+    pub fn with_affiliation(name: String, party_name: String, guild_name: String) -> Self {
+        let non_empty = |name: String| (!name.is_empty()).then_some(name);
+
+        Self {
+            name,
+            party_name: non_empty(party_name),
+            guild_name: non_empty(guild_name),
+        }
+    }
+}
+
+struct InFlightRequest {
+    sent_at: Instant,
+    attempts: usize,
+}
+
+/// Collects requests for entity details (names) so they can be sent in
+/// batches. Every entity is only requested once and the results are cached
+/// until the map server connection is reset.
+#[derive(Default)]
+pub(crate) struct DetailsScheduler {
+    queue: VecDeque<EntityId>,
+    in_flight: HashMap<EntityId, InFlightRequest>,
+    cache: HashMap<EntityId, EntityDetails>,
+    last_batch: Option<Instant>,
+}
+
+impl DetailsScheduler {
+    /// Returns the details if they are cached, otherwise the entity is queued
+    /// to be requested.
+    pub fn request(&mut self, entity_id: EntityId) -> Option<EntityDetails> {
+        if let Some(details) = self.cache.get(&entity_id) {
+            return Some(details.clone());
+        }
+
+        if !self.in_flight.contains_key(&entity_id) && !self.queue.contains(&entity_id) {
+            self.queue.push_back(entity_id);
+        }
+
+        None
+    }
+
+    pub fn received(&mut self, entity_id: EntityId, details: &EntityDetails) {
+        self.in_flight.remove(&entity_id);
+        self.queue.retain(|queued_id| *queued_id != entity_id);
+        self.cache.insert(entity_id, details.clone());
+    }
+
+    /// Get the entities that should be requested now. Requests that timed out
+    /// are retried before new ones are sent.
+    pub fn next_batch(&mut self, now: Instant) -> Vec<EntityId> {
+        if self
+            .last_batch
+            .is_some_and(|last_batch| now.duration_since(last_batch) < BATCH_INTERVAL)
+        {
+            return Vec::new();
+        }
+
+        self.in_flight
+            .retain(|_, request| now.duration_since(request.sent_at) < RETRY_TIMEOUT || request.attempts < MAXIMUM_ATTEMPTS);
+
+        let mut batch: Vec<EntityId> = self
+            .in_flight
+            .iter_mut()
+            .filter(|(_, request)| now.duration_since(request.sent_at) >= RETRY_TIMEOUT)
+            .take(MAXIMUM_BATCH_SIZE)
+            .map(|(entity_id, request)| {
+                request.sent_at = now;
+                request.attempts += 1;
+                *entity_id
+            })
+            .collect();
+
+        let new_requests = self.queue.len().min(MAXIMUM_BATCH_SIZE - batch.len());
+
+        for entity_id in self.queue.drain(..new_requests) {
+            self.in_flight.insert(entity_id, InFlightRequest { sent_at: now, attempts: 1 });
+            batch.push(entity_id);
+        }
+
+        if !batch.is_empty() {
+            self.last_batch = Some(now);
+        }
+
+        batch
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod scheduler {
+    use super::*;
+
+    #[test]
+    fn deduplicates_requests() {
+        let mut scheduler = DetailsScheduler::default();
+        let now = Instant::now();
+
+        assert!(scheduler.request(EntityId(1)).is_none());
+        assert!(scheduler.request(EntityId(1)).is_none());
+        assert_eq!(scheduler.next_batch(now), vec![EntityId(1)]);
+
+        assert!(scheduler.request(EntityId(1)).is_none());
+        assert!(scheduler.next_batch(now + BATCH_INTERVAL).is_empty());
+    }
+
+    #[test]
+    fn limits_batch_size_and_rate() {
+        let mut scheduler = DetailsScheduler::default();
+        let now = Instant::now();
+
+        (0..MAXIMUM_BATCH_SIZE as u32 + 1).for_each(|id| {
+            scheduler.request(EntityId(id));
+        });
+
+        assert_eq!(scheduler.next_batch(now).len(), MAXIMUM_BATCH_SIZE);
+        assert!(scheduler.next_batch(now).is_empty());
+        assert_eq!(scheduler.next_batch(now + BATCH_INTERVAL), vec![EntityId(
+            MAXIMUM_BATCH_SIZE as u32
+        )]);
+    }
+
+    #[test]
+    fn retries_until_maximum_attempts() {
+        let mut scheduler = DetailsScheduler::default();
+        let mut now = Instant::now();
+
+        scheduler.request(EntityId(1));
+
+        for _ in 0..MAXIMUM_ATTEMPTS {
+            assert_eq!(scheduler.next_batch(now), vec![EntityId(1)]);
+            now += RETRY_TIMEOUT;
+        }
+
+        assert!(scheduler.next_batch(now).is_empty());
+    }
+
+    #[test]
+    fn caches_received_details() {
+        let mut scheduler = DetailsScheduler::default();
+        let now = Instant::now();
+
+        scheduler.request(EntityId(1));
+        scheduler.next_batch(now);
+        scheduler.received(EntityId(1), &EntityDetails::new("Poring".to_owned()));
+
+        assert_eq!(scheduler.request(EntityId(1)), Some(EntityDetails::new("Poring".to_owned())));
+        assert!(scheduler.next_batch(now + RETRY_TIMEOUT).is_empty());
+    }
+
+    #[test]
+    fn empty_affiliation() {
+        let details = EntityDetails::with_affiliation("Lina".to_owned(), String::new(), "Sunset".to_owned());
+
+        assert_eq!(details.party_name, None);
+        assert_eq!(details.guild_name.as_deref(), Some("Sunset"));
+    }
+}
@@ -1,5 +1,10 @@
 use ragnarok_packets::*;
 
+use crate::guild::GuildEmblemId;
+
+/// State of an entity that appeared while sitting.
+const SITTING_STATE: u8 = 2;
+
 #[derive(Debug)]
 pub struct EntityData {
     pub entity_id: EntityId,
@@ -11,6 +16,76 @@ pub struct EntityData {
     pub maximum_health_points: i32,
     pub head_direction: usize,
     pub sex: Sex,
+    pub look: EntityLook,
+    pub sitting: bool,
+    pub guild_emblem: Option<GuildEmblemId>,
+}
+
+/// View ids of the hair and the visible equipment of a character. A view id
+/// of 0 means that nothing is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EntityLook {
+    pub hair_style: u16,
+    pub hair_color: u16,
+    pub weapon: u32,
+    pub shield: u32,
+    pub head_top: u16,
+    pub head_middle: u16,
+    pub head_bottom: u16,
+}
+
+/// Single part of the look of a character that changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LookChange {
+    HairStyle(u16),
+    HairColor(u16),
+    /// Weapons and shields are changed together.
+    Weapon {
+        weapon: u32,
+        shield: u32,
+    },
+    Shield(u32),
+    HeadTop(u16),
+    HeadMiddle(u16),
+    HeadBottom(u16),
+}
+
+impl LookChange {
+    /// Change from the type and values of a sprite change packet. Returns
+    /// `None` for types that don't change the hair or the equipment, like the
+    /// job.
+    pub fn from_sprite_change(sprite_type: u8, value: u32, value2: u32) -> Option<Self> {
+        match sprite_type {
+            1 => Some(Self::HairStyle(value as u16)),
+            2 => Some(Self::Weapon {
+                weapon: value,
+                shield: value2,
+            }),
+            3 => Some(Self::HeadBottom(value as u16)),
+            4 => Some(Self::HeadTop(value as u16)),
+            5 => Some(Self::HeadMiddle(value as u16)),
+            6 => Some(Self::HairColor(value as u16)),
+            8 => Some(Self::Shield(value)),
+            _ => None,
+        }
+    }
+}
+
+impl EntityLook {
+    pub fn apply(&mut self, change: LookChange) {
+        match change {
+            LookChange::HairStyle(hair_style) => self.hair_style = hair_style,
+            LookChange::HairColor(hair_color) => self.hair_color = hair_color,
+            LookChange::Weapon { weapon, shield } => {
+                self.weapon = weapon;
+                self.shield = shield;
+            }
+            LookChange::Shield(shield) => self.shield = shield,
+            LookChange::HeadTop(head_top) => self.head_top = head_top,
+            LookChange::HeadMiddle(head_middle) => self.head_middle = head_middle,
+            LookChange::HeadBottom(head_bottom) => self.head_bottom = head_bottom,
+        }
+    }
 }
 
 impl EntityData {
@@ -25,6 +100,17 @@ impl EntityData {
             maximum_health_points: character_information.maximum_health_points as i32,
             head_direction: 0, // TODO: get correct rotation
             sex: character_information.sex,
+            look: EntityLook {
+                hair_style: character_information.head as u16,
+                hair_color: character_information.hair_color as u16,
+                weapon: character_information.weapon as u32,
+                shield: character_information.shield as u32,
+                head_top: character_information.accessory2 as u16,
+                head_middle: character_information.accessory3 as u16,
+                head_bottom: character_information.accessory as u16,
+            },
+            sitting: false,
+            guild_emblem: None,
         }
     }
 }
@@ -41,6 +127,17 @@ impl From<EntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            look: EntityLook {
+                hair_style: packet.head,
+                hair_color: packet.head_palette,
+                weapon: packet.weapon,
+                shield: packet.shield,
+                head_top: packet.accessory2,
+                head_middle: packet.accessory3,
+                head_bottom: packet.accessory,
+            },
+            sitting: false,
+            guild_emblem: GuildEmblemId::from_guild(packet.guild_id, packet.emblem_version as u32),
         }
     }
 }
@@ -57,6 +154,17 @@ impl From<EntityAppeared2Packet> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            look: EntityLook {
+                hair_style: packet.head,
+                hair_color: packet.head_palette,
+                weapon: packet.weapon,
+                shield: packet.shield,
+                head_top: packet.accessory2,
+                head_middle: packet.accessory3,
+                head_bottom: packet.accessory,
+            },
+            sitting: packet.state == SITTING_STATE,
+            guild_emblem: GuildEmblemId::from_guild(packet.guild_id, packet.emblem_version as u32),
         }
     }
 }
@@ -75,6 +183,48 @@ impl From<MovingEntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            look: EntityLook {
+                hair_style: packet.head,
+                hair_color: packet.head_palette,
+                weapon: packet.weapon,
+                shield: packet.shield,
+                head_top: packet.accessory2,
+                head_middle: packet.accessory3,
+                head_bottom: packet.accessory,
+            },
+            sitting: false,
+            guild_emblem: GuildEmblemId::from_guild(packet.guild_id, packet.emblem_version as u32),
         }
     }
 }
+
+#[cfg(test)]
+mod look {
+    use super::{EntityLook, LookChange};
+
+    #[test]
+    fn sprite_changes() {
+        assert_eq!(LookChange::from_sprite_change(0, 7, 0), None);
+        assert_eq!(LookChange::from_sprite_change(4, 17, 0), Some(LookChange::HeadTop(17)));
+        assert_eq!(
+            LookChange::from_sprite_change(2, 1201, 2101),
+            Some(LookChange::Weapon {
+                weapon: 1201,
+                shield: 2101
+            })
+        );
+    }
+
+    #[test]
+    fn apply_changes() {
+        let mut look = EntityLook::default();
+
+        look.apply(LookChange::Weapon { weapon: 2, shield: 1 });
+        look.apply(LookChange::HeadMiddle(4));
+        look.apply(LookChange::Shield(0));
+
+        assert_eq!(look.weapon, 2);
+        assert_eq!(look.shield, 0);
+        assert_eq!(look.head_middle, 4);
+    }
+}
@@ -1,7 +1,21 @@
+use std::time::Duration;
+
 use ragnarok_packets::*;
 
+use crate::achievement::{AchievementProgress, AchievementSummary};
+use crate::condition::EntityCondition;
+use crate::damage::DamageKind;
+use crate::details::EntityDetails;
+use crate::entity::LookChange;
+use crate::follower::FollowerKind;
+use crate::guild::{GuildEmblemId, GuildNotice};
 use crate::hotkey::HotkeyState;
+use crate::instance::InstanceInformation;
 use crate::items::ShopItem;
+use crate::mount::Mount;
+use crate::pet::{PetInformation, PetStatus};
+use crate::quest::QuestEntry;
+use crate::request::IncomingRequest;
 use crate::{
     CharacterServerLoginData, EntityData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
     UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
@@ -23,6 +37,7 @@ pub enum NetworkEvent {
     },
     CharacterServerConnected {
         normal_slot_count: usize,
+        premium_slot_count: usize,
     },
     CharacterServerConnectionFailed {
         reason: LoginFailedReason,
@@ -32,6 +47,11 @@ pub enum NetworkEvent {
         reason: DisconnectReason,
     },
     AccountId(AccountId),
+    CharacterListPageCount {
+        page_count: usize,
+    },
+    /// A single page of the character list. Characters of previous pages
+    /// should be kept.
     CharacterList {
         characters: Vec<CharacterInformation>,
     },
@@ -55,6 +75,27 @@ pub enum NetworkEvent {
         reason: CharacterDeletionFailedReason,
         message: &'static str,
     },
+    /// The character was scheduled for deletion and can be deleted after the
+    /// waiting time has passed.
+    CharacterDeletionReserved {
+        character_id: CharacterId,
+        waiting_time: Duration,
+    },
+    CharacterDeletionReservationFailed {
+        reason: CharacterDeletionReservationResult,
+        message: &'static str,
+    },
+    CharacterDeletionConfirmationFailed {
+        reason: CharacterDeletionConfirmationResult,
+        message: &'static str,
+    },
+    CharacterDeletionCanceled {
+        character_id: CharacterId,
+    },
+    CharacterDeletionCancellationFailed {
+        reason: CharacterDeletionCancellationResult,
+        message: &'static str,
+    },
     MapServerDisconnected {
         reason: DisconnectReason,
     },
@@ -63,36 +104,163 @@ pub enum NetworkEvent {
     /// Remove an entity from the list of entities that the client is aware of
     /// by its id.
     RemoveEntity(EntityId),
+    /// An entity died. Unlike other entities that disappear, it can stay on
+    /// the map as a corpse for a while.
+    EntityDied(EntityId),
     /// The player is pathing to a new position.
     PlayerMove(WorldPosition, WorldPosition, ClientTick),
     /// An Entity nearby is pathing to a new position.
     EntityMove(EntityId, WorldPosition, WorldPosition, ClientTick),
+    /// An entity was moved to a new position without walking, for example by
+    /// a knockback.
+    EntityForcedMove(EntityId, TilePosition),
     /// Player was moved to a new position on a different map or the current map
     ChangeMap(String, TilePosition),
     /// Update the client side [`tick
     /// counter`](crate::system::GameTimer::base_client_tick) to keep server and
     /// client synchronized.
     UpdateClientTick(ClientTick),
+    /// Round trip time of the last keep-alive packet sent to the map server.
+    /// [`None`] if the server did not answer before the next one was sent.
+    Latency {
+        round_trip_time: Option<Duration>,
+    },
+    /// An entity nearby is displaying an emotion.
+    DisplayEmotion {
+        entity_id: EntityId,
+        emotion: u8,
+    },
     /// New chat message for the client.
     ChatMessage {
         text: String,
         color: MessageColor,
     },
+    /// An entity nearby said something, which is shown above its head.
+    OverheadMessage {
+        entity_id: EntityId,
+        text: String,
+    },
     CharacterSlotSwitched,
     CharacterSlotSwitchFailed,
+    CharacterRenamed,
+    CharacterRenameFailed {
+        reason: RenameCharacterResult,
+        message: &'static str,
+    },
     /// Update entity details. Mostly received when the client sends
     /// [RequestDetailsPacket] after the player hovered an entity.
-    UpdateEntityDetails(EntityId, String),
+    UpdateEntityDetails(EntityId, EntityDetails),
     UpdateEntityHealth(EntityId, usize, usize),
-    DamageEffect(EntityId, usize),
+    DamageEffect(EntityId, usize, DamageKind),
     HealEffect(EntityId, usize),
+    UpdateSitting {
+        entity_id: EntityId,
+        sitting: bool,
+    },
+    /// The mount of an entity that is granted by its option flags changed.
+    UpdateMount {
+        entity_id: EntityId,
+        mount: Option<Mount>,
+    },
+    /// The falcon or pushcart of an entity changed.
+    UpdateFollowers {
+        entity_id: EntityId,
+        followers: Vec<FollowerKind>,
+    },
+    /// An entity was turned to stone or started hiding, or recovered from
+    /// either.
+    UpdateEntityCondition {
+        entity_id: EntityId,
+        condition: EntityCondition,
+    },
+    /// The size of an entity changed, for example through a GM command or
+    /// because a monster was spawned as a giant.
+    UpdateEntityScale {
+        entity_id: EntityId,
+        scale: f32,
+    },
+    /// An entity got on or off a costume mount.
+    UpdateCostumeMount {
+        entity_id: EntityId,
+        mounted: bool,
+    },
+    /// A status effect was applied to an entity. Effects without a duration
+    /// last until they are removed.
+    AddStatusEffect {
+        entity_id: EntityId,
+        status_effect: u16,
+        duration: Option<Duration>,
+    },
+    RemoveStatusEffect {
+        entity_id: EntityId,
+        status_effect: u16,
+    },
     UpdateStatus(StatusType),
+    /// Range in tiles that the player can attack from.
+    UpdateAttackRange(usize),
     OpenDialog(String, EntityId),
     AddNextButton,
     AddCloseButton,
     AddChoiceButtons(Vec<String>),
     AddQuestEffect(QuestEffectPacket),
     RemoveQuestEffect(EntityId),
+    SetQuestList {
+        quests: Vec<QuestEntry>,
+    },
+    /// Sent when a quest is added or restarted.
+    QuestAdded {
+        quest: QuestEntry,
+    },
+    QuestRemoved {
+        quest_id: u32,
+    },
+    UpdateQuestObjectives {
+        objectives: Vec<HuntingObjective>,
+    },
+    SetAchievementList {
+        summary: AchievementSummary,
+        achievements: Vec<AchievementProgress>,
+    },
+    AchievementUpdated {
+        summary: AchievementSummary,
+        achievement: AchievementProgress,
+    },
+    AchievementRewarded {
+        achievement_id: u32,
+    },
+    TitleChanged {
+        title_id: u32,
+    },
+    BankOpened,
+    BankBalance {
+        bank_balance: i64,
+    },
+    OpenAttendanceWindow {
+        claimed_days: u32,
+    },
+    AttendanceRewardClaimed {
+        claimed_days: u32,
+    },
+    OpenRefineWindow,
+    SetRefineItem {
+        index: InventoryIndex,
+        blacksmith_blessing: u8,
+        materials: Vec<RefineMaterialInformation>,
+    },
+    RefineResult {
+        result: RefineResult,
+        index: InventoryIndex,
+        refinement_level: u8,
+    },
+    SetPetInformation {
+        pet: PetInformation,
+    },
+    PetSummoned {
+        pet_id: EntityId,
+    },
+    UpdatePetStatus {
+        status: PetStatus,
+    },
     SetInventory {
         items: Vec<InventoryItem<NoMetadata>>,
     },
@@ -105,10 +273,61 @@ pub enum NetworkEvent {
         equipped_position: EquipPosition,
     },
     ChangeJob(AccountId, u32),
+    /// The hair or the visible equipment of a character changed.
+    UpdateEntityLook {
+        entity_id: EntityId,
+        change: LookChange,
+    },
     SetPlayerPosition(WorldPosition),
     LoggedOut,
-    FriendRequest {
-        requestee: Friend,
+    IncomingRequest {
+        request: IncomingRequest,
+    },
+    /// A party member on the same map moved, or left the map if there is no
+    /// position.
+    PartyMemberPosition {
+        account_id: AccountId,
+        position: Option<TilePosition>,
+    },
+    GuildNotice {
+        notice: GuildNotice,
+    },
+    /// The compressed emblem of a guild that was requested with
+    /// [`NetworkingSystem::request_guild_emblem`](crate::NetworkingSystem::request_guild_emblem).
+    GuildEmblem {
+        emblem_id: GuildEmblemId,
+        data: Vec<u8>,
+    },
+    /// The creation of an instance was requested and is waiting in the
+    /// queue.
+    InstanceQueued {
+        name: String,
+        queue_position: u16,
+    },
+    InstanceQueuePosition {
+        queue_position: u16,
+    },
+    InstanceInformation {
+        information: InstanceInformation,
+    },
+    InstanceTimeLimitChanged {
+        remaining_time: Duration,
+    },
+    InstanceClosed {
+        reason: InstanceStatus,
+        message: &'static str,
+    },
+    MapType {
+        flags: MapTypeFlags,
+    },
+    BattlefieldTeam {
+        entity_id: EntityId,
+        name: String,
+        team_id: u16,
+    },
+    BattlefieldScore {
+        lion_score: u16,
+        eagle_score: u16,
     },
     VisualEffect(&'static str, EntityId),
     AddSkillUnit(EntityId, UnitId, TilePosition),
@@ -123,6 +342,10 @@ pub enum NetworkEvent {
         account_id: AccountId,
         character_id: CharacterId,
     },
+    FriendOnlineStatus {
+        name: String,
+        state: OnlineState,
+    },
     SetHotkeyData {
         tab: HotbarTab,
         hotkeys: Vec<HotkeyState>,
@@ -147,6 +370,16 @@ pub enum NetworkEvent {
         index: InventoryIndex,
         amount: u16,
     },
+    RentalItemTime {
+        item_id: ItemId,
+        remaining_time: Duration,
+    },
+    /// A rental item expired. It will be removed from the inventory by a
+    /// separate [`NetworkEvent::InventoryItemRemoved`].
+    RentalItemExpired {
+        index: InventoryIndex,
+        item_id: ItemId,
+    },
 }
 
 /// New-type so we can implement some `From` traits. This will help when
@@ -0,0 +1,52 @@
+/// Option flags of the state change packet that give a character a falcon or
+/// a pushcart. Every type of pushcart has its own flag.
+const OPTION_FALCON: u32 = 0x10;
+const OPTION_CARTS: [u32; 5] = [0x08, 0x80, 0x100, 0x200, 0x400];
+
+/// Something that follows a character around without being an entity of its
+/// own on the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FollowerKind {
+    Falcon,
+    /// Pushcart of a merchant, with its type from 1 to 5.
+    Cart(u8),
+}
+
+impl FollowerKind {
+    /// Followers of a character from the option flags of a state change.
+    pub fn from_options(options: u32) -> Vec<Self> {
+        let mut followers = Vec::new();
+
+        if options & OPTION_FALCON != 0 {
+            followers.push(Self::Falcon);
+        }
+
+        if let Some(index) = OPTION_CARTS.iter().position(|flag| options & flag != 0) {
+            followers.push(Self::Cart(index as u8 + 1));
+        }
+
+        followers
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use super::FollowerKind;
+
+    #[test]
+    fn followers() {
+        assert_eq!(FollowerKind::from_options(0), vec![]);
+        assert_eq!(FollowerKind::from_options(0x10), vec![FollowerKind::Falcon]);
+        assert_eq!(FollowerKind::from_options(0x08), vec![FollowerKind::Cart(1)]);
+        assert_eq!(FollowerKind::from_options(0x400 | 0x10), vec![
+            FollowerKind::Falcon,
+            FollowerKind::Cart(5)
+        ]);
+    }
+
+    #[test]
+    fn unrelated_options() {
+        // Riding a peco peco.
+        assert_eq!(FollowerKind::from_options(0x20), vec![]);
+    }
+}
@@ -0,0 +1,40 @@
+use ragnarok_packets::GuildNoticePacket;
+
+/// Notice of the guild the player is in, split into a subject line and the
+/// notice itself.
+#[derive(Clone, Debug, Default)]
+pub struct GuildNotice {
+    pub subject: String,
+    pub notice: String,
+}
+
+impl GuildNotice {
+    pub fn is_empty(&self) -> bool {
+        self.subject.is_empty() && self.notice.is_empty()
+    }
+}
+
+/// Guild of an entity together with the version of the guild emblem. The
+/// version changes whenever a new emblem is uploaded, so emblems can be cached
+/// by this id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuildEmblemId {
+    pub guild_id: u32,
+    pub version: u32,
+}
+
+impl GuildEmblemId {
+    /// Entities that are not in a guild have a guild id of 0.
+    pub fn from_guild(guild_id: u32, version: u32) -> Option<Self> {
+        (guild_id != 0).then_some(Self { guild_id, version })
+    }
+}
+
+impl From<GuildNoticePacket> for GuildNotice {
+    fn from(packet: GuildNoticePacket) -> Self {
+        Self {
+            subject: packet.subject,
+            notice: packet.notice,
+        }
+    }
+}
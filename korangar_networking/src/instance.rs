@@ -0,0 +1,30 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ragnarok_packets::InstanceInformationPacket;
+
+/// Time left until a unix timestamp sent by the server.
+pub(crate) fn time_until(timestamp: u32) -> Duration {
+    let date = UNIX_EPOCH + Duration::from_secs(timestamp as u64);
+
+    date.duration_since(SystemTime::now()).unwrap_or_default()
+}
+
+/// Status of the instance (memorial dungeon) of the party.
+#[derive(Clone, Debug)]
+pub struct InstanceInformation {
+    pub name: String,
+    /// Time until the instance is destroyed.
+    pub remaining_time: Duration,
+    /// Time left to enter the instance. `None` if there is no limit.
+    pub entry_time_limit: Option<Duration>,
+}
+
+impl From<InstanceInformationPacket> for InstanceInformation {
+    fn from(packet: InstanceInformationPacket) -> Self {
+        Self {
+            name: packet.name,
+            remaining_time: time_until(packet.destruction_date),
+            entry_time_limit: (packet.entry_deadline != 0).then(|| time_until(packet.entry_deadline)),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use ragnarok_packets::{EquipPosition, EquippableItemFlags, InventoryIndex, ItemId, ItemOptions, Price, RegularItemFlags};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -41,6 +43,25 @@ impl<Meta> InventoryItem<Meta> {
             InventoryItemDetails::Equippable { flags, .. } => flags.contains(EquippableItemFlags::IDENTIFIED),
         }
     }
+
+    /// Time until a rental item expires. Returns `None` if the item is not a
+    /// rental item.
+    pub fn remaining_rental_time(&self) -> Option<Duration> {
+        if self.hire_expiration_date == 0 {
+            return None;
+        }
+
+        let expiration_date = UNIX_EPOCH + Duration::from_secs(self.hire_expiration_date as u64);
+
+        Some(expiration_date.duration_since(SystemTime::now()).unwrap_or_default())
+    }
+
+    /// Set the remaining time of a rental item.
+    pub fn set_remaining_rental_time(&mut self, remaining_time: Duration) {
+        let expiration_date = SystemTime::now() + remaining_time;
+
+        self.hire_expiration_date = expiration_date.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() as u32;
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1,44 +1,87 @@
+mod achievement;
+mod character;
+mod condition;
+mod damage;
+mod details;
 mod entity;
 mod event;
+mod follower;
+mod guild;
 mod hotkey;
+mod instance;
 mod items;
 mod message;
+mod mount;
+mod pet;
+mod quest;
+mod replay;
+mod request;
 mod server;
+mod status_effect;
 
 use std::cell::RefCell;
-use std::net::{IpAddr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::rc::Rc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use character::{CREATE_CHARACTER_JOB_PACKET_VERSION, CREATE_CHARACTER_NO_STATS_PACKET_VERSION, DELAYED_CHARACTER_DELETION_PACKET_VERSION};
 use event::{
     CharacterServerDisconnectedEvent, DisconnectedEvent, LoginServerDisconnectedEvent, MapServerDisconnectedEvent, NetworkEventList,
     NoNetworkEvents,
 };
+use mount::ALL_RIDING_STATUS;
 use ragnarok_bytes::{ByteStream, FromBytes};
 use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler};
 use ragnarok_packets::*;
 use server::{ServerConnectCommand, ServerConnection};
+use status_effect::{remaining_duration, status_effect_event};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::task::JoinHandle;
 
-pub use self::entity::EntityData;
+pub use self::achievement::{AchievementProgress, AchievementSummary};
+pub use self::character::CharacterAppearance;
+pub use self::condition::EntityCondition;
+pub use self::damage::DamageKind;
+pub use self::details::EntityDetails;
+pub use self::entity::{EntityData, EntityLook, LookChange};
 pub use self::event::{DisconnectReason, NetworkEvent};
+pub use self::follower::FollowerKind;
+pub use self::guild::{GuildEmblemId, GuildNotice};
 pub use self::hotkey::HotkeyState;
+pub use self::instance::InstanceInformation;
 pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem};
 pub use self::message::MessageColor;
+pub use self::mount::Mount;
+pub use self::pet::{PetInformation, PetStatus};
+pub use self::quest::{QuestEntry, QuestObjective};
+pub use self::replay::{Replay, ReplayChunk, ReplayHeader, ReplayRecorder};
+pub use self::request::IncomingRequest;
 pub use self::server::{
     CharacterServerLoginData, LoginServerLoginData, NotConnectedError, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
+use crate::details::DetailsScheduler;
 use crate::server::NetworkTaskError;
 
+/// Type of the attendance interface in an [`OpenUiPacket`].
+const ATTENDANCE_OPEN_UI_TYPE: u8 = 7;
+/// Type of the attendance interface in a [`UiActionPacket`].
+const ATTENDANCE_ACTION_UI_TYPE: u32 = 0;
+/// Types of a [`DamagePacket`] that don't deal damage but tell the client
+/// that an entity picked up an item, sat down or stood up.
+const PICK_UP_ITEM_DAMAGE_TYPE: u8 = 1;
+const SIT_DOWN_DAMAGE_TYPE: u8 = 2;
+const STAND_UP_DAMAGE_TYPE: u8 = 3;
+
 pub struct NetworkingSystem<Callback> {
     command_sender: UnboundedSender<ServerConnectCommand>,
     login_server_connection: ServerConnection,
     character_server_connection: ServerConnection,
     map_server_connection: ServerConnection,
+    details_scheduler: DetailsScheduler,
+    packet_version: Option<u32>,
     packet_callback: Callback,
 }
 
@@ -60,6 +103,8 @@ where
             login_server_connection: ServerConnection::Disconnected,
             character_server_connection: ServerConnection::Disconnected,
             map_server_connection: ServerConnection::Disconnected,
+            details_scheduler: DetailsScheduler::default(),
+            packet_version: None,
             packet_callback,
         }
     }
@@ -105,6 +150,8 @@ where
                                 LoginServerKeepalivePacket::new,
                                 Duration::from_secs(58),
                                 false,
+                                false,
+                                None,
                             ));
 
                             login_server_task_handle = Some(handle);
@@ -128,6 +175,8 @@ where
                                 CharacterServerKeepalivePacket::new,
                                 Duration::from_secs(10),
                                 true,
+                                false,
+                                None,
                             ));
 
                             character_server_task_handle = Some(handle);
@@ -136,6 +185,7 @@ where
                             address,
                             action_receiver,
                             event_sender,
+                            replay_recorder,
                         } => {
                             if let Some(handle) = map_server_task_handle.take() {
                                 // TODO: Maybe add a timeout here? Maybe handle Result?
@@ -153,8 +203,25 @@ where
                                 || RequestServerTickPacket::new(ClientTick(100)),
                                 Duration::from_secs(4),
                                 false,
+                                true,
+                                replay_recorder,
                             ));
 
+                            map_server_task_handle = Some(handle);
+                        }
+                        ServerConnectCommand::Replay {
+                            replay,
+                            action_receiver,
+                            event_sender,
+                        } => {
+                            if let Some(handle) = map_server_task_handle.take() {
+                                // TODO: Maybe add a timeout here? Maybe handle Result?
+                                let _ = handle.await.unwrap();
+                            }
+
+                            let packet_handler = Self::create_map_server_packet_handler(packet_callback.clone()).unwrap();
+                            let handle = local_set.spawn_local(Self::handle_replay(replay, action_receiver, event_sender, packet_handler));
+
                             map_server_task_handle = Some(handle);
                         }
                     }
@@ -207,9 +274,40 @@ where
         Self::handle_connection::<CharacterServerDisconnectedEvent>(&mut self.character_server_connection, &mut events);
         Self::handle_connection::<MapServerDisconnectedEvent>(&mut self.map_server_connection, &mut events);
 
+        for event in &events {
+            if let NetworkEvent::UpdateEntityDetails(entity_id, details) = event {
+                self.details_scheduler.received(*entity_id, details);
+            }
+        }
+
+        self.send_details_requests();
+
         events
     }
 
+    /// Send the next batch of entity detail requests as a single message to the
+    /// map server.
+    fn send_details_requests(&mut self) {
+        let ServerConnection::Connected { action_sender, .. } = &mut self.map_server_connection else {
+            return;
+        };
+
+        let mut bytes = Vec::new();
+
+        for entity_id in self.details_scheduler.next_batch(Instant::now()) {
+            let packet = RequestDetailsPacket::new(entity_id);
+            self.packet_callback.outgoing_packet(&packet);
+
+            // FIX: Don't unwrap.
+            bytes.extend(packet.packet_to_bytes().unwrap());
+        }
+
+        if !bytes.is_empty() {
+            let _ = action_sender.send(bytes);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn handle_server_connection<PingPacket>(
         address: SocketAddr,
         mut action_receiver: UnboundedReceiver<Vec<u8>>,
@@ -221,6 +319,10 @@ where
         // Since our packet handler has no way of working with this, we need to add some special
         // logic.
         mut read_account_id: bool,
+        // Only the map server answers the keep-alive packet, so the latency can't be
+        // measured on the other connections.
+        measure_latency: bool,
+        mut replay_recorder: Option<ReplayRecorder>,
     ) -> Result<(), NetworkTaskError>
     where
         PingPacket: Packet + ClientPacket,
@@ -230,6 +332,7 @@ where
         let mut interval = tokio::time::interval(ping_frequency);
         let mut buffer = [0u8; 8192];
         let mut cut_off_buffer_base = 0;
+        let mut ping_sent_at: Option<Instant> = None;
 
         loop {
             tokio::select! {
@@ -255,54 +358,47 @@ where
                         break Err(NetworkTaskError::ConnectionClosed);
                     }
 
-                    let data = &buffer[..cut_off_buffer_base + received_bytes];
-                    let mut byte_stream = ByteStream::without_metadata(data);
-                    let mut events = Vec::new();
+                    let recorded = replay_recorder
+                        .as_mut()
+                        .map(|recorder| recorder.record(&buffer[cut_off_buffer_base..cut_off_buffer_base + received_bytes]));
 
-                    if read_account_id {
-                        let account_id = AccountId::from_bytes(&mut byte_stream).unwrap();
-                        events.push(NetworkEvent::AccountId(account_id));
-                        read_account_id = false;
+                    if let Some(Err(..)) = recorded {
+                        // Failing to write the replay should not affect the connection, so we
+                        // just stop recording.
+                        replay_recorder = None;
                     }
 
-                    while !byte_stream.is_empty() {
-                        match packet_handler.process_one(&mut byte_stream) {
-                            HandlerResult::Ok(packet_events) => events.extend(packet_events.0.into_iter()),
-                            HandlerResult::PacketCutOff => {
-                                let packet_start = byte_stream.get_offset();
-                                let packet_end = cut_off_buffer_base + received_bytes;
-
-                                if packet_start == 0 {
-                                    // If the packet_start is 0, that means the packet is allegidly bigger than the MTU of a TCP packet.
-                                    // We limit the size of a packet to the MTU, to avoid getting stuck on packets that are parsed incorrectly.
-                                    // TODO: Call the packet callback?
-                                    cut_off_buffer_base = 0;
-                                    break;
-                                }
-
-                                buffer.copy_within(packet_start..packet_end, 0);
-                                cut_off_buffer_base = packet_end - packet_start;
-
-                                break;
-                            },
-                            // The packet callback can take care of handling these properly.
-                            HandlerResult::UnhandledPacket => {
-                                cut_off_buffer_base = 0;
-                                break
-                            },
-                            HandlerResult::InternalError(..) => {
-                                cut_off_buffer_base = 0;
-                                break
-                            },
-                        }
-                    }
+                    let events = Self::process_received_bytes(
+                        &mut packet_handler,
+                        &mut buffer,
+                        &mut cut_off_buffer_base,
+                        received_bytes,
+                        &mut read_account_id,
+                    );
 
                     for event in events {
+                        if matches!(event, NetworkEvent::UpdateClientTick(..)) {
+                            if let Some(sent_at) = ping_sent_at.take() {
+                                let latency = NetworkEvent::Latency { round_trip_time: Some(sent_at.elapsed()) };
+                                event_sender.send(latency).map_err(|_| NetworkTaskError::ConnectionClosed)?;
+                            }
+                        }
+
                         event_sender.send(event).map_err(|_| NetworkTaskError::ConnectionClosed)?;
                     }
                 }
                 // Send a keep-alive packet to the server.
                 _ = interval.tick() => {
+                    if measure_latency {
+                        // The previous keep-alive packet was never answered.
+                        if ping_sent_at.is_some() {
+                            let latency = NetworkEvent::Latency { round_trip_time: None };
+                            event_sender.send(latency).map_err(|_| NetworkTaskError::ConnectionClosed)?;
+                        }
+
+                        ping_sent_at = Some(Instant::now());
+                    }
+
                     let packet_bytes = ping_factory().packet_to_bytes().unwrap();
                     stream.write_all(&packet_bytes).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
                 }
@@ -310,6 +406,108 @@ where
         }
     }
 
+    /// Parse all packets in the buffer, starting with the part of a packet that
+    /// was cut off by the previous read. If the last packet is cut off again,
+    /// it is moved to the start of the buffer.
+    fn process_received_bytes(
+        packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+        buffer: &mut [u8],
+        cut_off_buffer_base: &mut usize,
+        received_bytes: usize,
+        read_account_id: &mut bool,
+    ) -> Vec<NetworkEvent> {
+        let packet_end = *cut_off_buffer_base + received_bytes;
+        let data = &buffer[..packet_end];
+        let mut byte_stream = ByteStream::without_metadata(data);
+        let mut events = Vec::new();
+
+        // Only a packet that is cut off again sets a new base, otherwise the next read
+        // starts at the beginning of the buffer.
+        *cut_off_buffer_base = 0;
+
+        if *read_account_id {
+            let account_id = AccountId::from_bytes(&mut byte_stream).unwrap();
+            events.push(NetworkEvent::AccountId(account_id));
+            *read_account_id = false;
+        }
+
+        while !byte_stream.is_empty() {
+            match packet_handler.process_one(&mut byte_stream) {
+                HandlerResult::Ok(packet_events) => events.extend(packet_events.0.into_iter()),
+                HandlerResult::PacketCutOff => {
+                    let packet_start = byte_stream.get_offset();
+
+                    if packet_start == 0 && packet_end == buffer.len() {
+                        // If the packet fills the entire buffer, that means the packet is allegidly
+                        // bigger than the buffer. We limit the size of a packet to the buffer, to
+                        // avoid getting stuck on packets that are parsed incorrectly.
+                        // TODO: Call the packet callback?
+                        break;
+                    }
+
+                    buffer.copy_within(packet_start..packet_end, 0);
+                    *cut_off_buffer_base = packet_end - packet_start;
+
+                    break;
+                }
+                // The packet callback can take care of handling these properly.
+                HandlerResult::UnhandledPacket | HandlerResult::InternalError(..) => break,
+            }
+        }
+
+        events
+    }
+
+    /// Feed the packets of a recorded session through the map server packet
+    /// handler with the original timing. Any packets sent by the client are
+    /// dropped.
+    async fn handle_replay(
+        replay: Replay,
+        mut action_receiver: UnboundedReceiver<Vec<u8>>,
+        event_sender: UnboundedSender<NetworkEvent>,
+        mut packet_handler: PacketHandler<NetworkEventList, (), Callback>,
+    ) -> Result<(), NetworkTaskError>
+    where
+        Callback: PacketCallback,
+    {
+        let start = tokio::time::Instant::now();
+        let mut buffer = [0u8; 8192];
+        let mut cut_off_buffer_base = 0;
+
+        for chunk in replay.chunks {
+            let deadline = start + chunk.elapsed;
+
+            loop {
+                tokio::select! {
+                    action = action_receiver.recv() => {
+                        if action.is_none() {
+                            // Channel was closed by the main thread.
+                            return Ok(());
+                        }
+                    }
+                    _ = tokio::time::sleep_until(deadline) => break,
+                }
+            }
+
+            let received_bytes = chunk.bytes.len().min(buffer.len() - cut_off_buffer_base);
+            buffer[cut_off_buffer_base..cut_off_buffer_base + received_bytes].copy_from_slice(&chunk.bytes[..received_bytes]);
+
+            let events = Self::process_received_bytes(
+                &mut packet_handler,
+                &mut buffer,
+                &mut cut_off_buffer_base,
+                received_bytes,
+                &mut false,
+            );
+
+            for event in events {
+                event_sender.send(event).map_err(|_| NetworkTaskError::ConnectionClosed)?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn connect_to_login_server(&mut self, address: SocketAddr, username: impl Into<String>, password: impl Into<String>) {
         if !matches!(self.login_server_connection, ServerConnection::Disconnected) {
             return;
@@ -381,11 +579,14 @@ where
         &mut self,
         login_server_login_data: &LoginServerLoginData,
         character_server_login_data: CharacterServerLoginData,
+        replay_recorder: Option<ReplayRecorder>,
     ) {
         if !matches!(self.map_server_connection, ServerConnection::Disconnected) {
             return;
         }
 
+        self.details_scheduler.clear();
+
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
 
@@ -396,6 +597,7 @@ where
                 address,
                 action_receiver,
                 event_sender,
+                replay_recorder,
             })
             .expect("network thread dropped");
 
@@ -421,6 +623,45 @@ where
         };
     }
 
+    /// Play back a recorded map server session. The map connection behaves
+    /// like a regular one, except that nothing is sent to any server.
+    pub fn play_replay(&mut self, replay: Replay) {
+        if !matches!(self.map_server_connection, ServerConnection::Disconnected) {
+            return;
+        }
+
+        self.details_scheduler.clear();
+
+        let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+        // Entering the map works the same way as selecting a character. Since the map
+        // server connection is already established, no connection attempt will be made.
+        event_sender
+            .send(NetworkEvent::CharacterSelected {
+                login_data: CharacterServerLoginData {
+                    server_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    server_port: 0,
+                    character_id: replay.header.character_information.character_id,
+                },
+                map_name: replay.header.map_name.clone(),
+            })
+            .expect("event receiver instantly dropped");
+
+        self.command_sender
+            .send(ServerConnectCommand::Replay {
+                replay,
+                action_receiver,
+                event_sender,
+            })
+            .expect("network thread dropped");
+
+        self.map_server_connection = ServerConnection::Connected {
+            action_sender,
+            event_receiver,
+        };
+    }
+
     pub fn disconnect_from_login_server(&mut self) {
         self.login_server_connection = ServerConnection::ClosingManually;
     }
@@ -535,8 +776,12 @@ where
         packet_handler.register(
             |packet: CharacterServerLoginSuccessPacket| NetworkEvent::CharacterServerConnected {
                 normal_slot_count: packet.normal_slot_count as usize,
+                premium_slot_count: packet.vip_slot_count as usize + packet.billing_slot_count as usize,
             },
         )?;
+        packet_handler.register(|packet: CharacterListNotifyPacket| NetworkEvent::CharacterListPageCount {
+            page_count: packet.page_count as usize,
+        })?;
         packet_handler.register(|packet: RequestCharacterListSuccessPacket| NetworkEvent::CharacterList {
             characters: packet.character_information,
         })?;
@@ -592,10 +837,72 @@ where
             };
             NetworkEvent::CharacterDeletionFailed { reason, message }
         })?;
+        packet_handler.register(|packet: ReserveCharacterDeletionResponsePacket| {
+            let reason = packet.result;
+            let message = match reason {
+                CharacterDeletionReservationResult::Success => {
+                    return NetworkEvent::CharacterDeletionReserved {
+                        character_id: packet.character_id,
+                        waiting_time: Duration::from_secs(packet.deletion_date as u64),
+                    };
+                }
+                CharacterDeletionReservationResult::UnknownError => "Deleting the character failed due to an unknown error",
+                CharacterDeletionReservationResult::NotAllowed => "You are not allowed to delete this character",
+                CharacterDeletionReservationResult::DatabaseError => "A database error occurred",
+                CharacterDeletionReservationResult::InGuild => "You have to leave your guild to delete this character",
+                CharacterDeletionReservationResult::InParty => "You have to leave your party to delete this character",
+            };
+
+            NetworkEvent::CharacterDeletionReservationFailed { reason, message }
+        })?;
+        packet_handler.register(|packet: ConfirmCharacterDeletionResponsePacket| {
+            let reason = packet.result;
+            let message = match reason {
+                CharacterDeletionConfirmationResult::Success => return NetworkEvent::CharacterDeleted,
+                CharacterDeletionConfirmationResult::UnknownError => "Deleting the character failed due to an unknown error",
+                CharacterDeletionConfirmationResult::NotAllowed => "You are not allowed to delete this character",
+                CharacterDeletionConfirmationResult::DatabaseError => "A database error occurred",
+                CharacterDeletionConfirmationResult::TooEarly => "The character can not be deleted yet",
+                CharacterDeletionConfirmationResult::IncorrectBirthdate => "Birthdate does not match",
+            };
+
+            NetworkEvent::CharacterDeletionConfirmationFailed { reason, message }
+        })?;
+        packet_handler.register(|packet: CancelCharacterDeletionResponsePacket| {
+            let reason = packet.result;
+            let message = match reason {
+                CharacterDeletionCancellationResult::Success => {
+                    return NetworkEvent::CharacterDeletionCanceled {
+                        character_id: packet.character_id,
+                    };
+                }
+                CharacterDeletionCancellationResult::DatabaseError => "A database error occurred",
+            };
+
+            NetworkEvent::CharacterDeletionCancellationFailed { reason, message }
+        })?;
         packet_handler.register(|packet: SwitchCharacterSlotResponsePacket| match packet.status {
             SwitchCharacterSlotResponseStatus::Success => NetworkEvent::CharacterSlotSwitched,
             SwitchCharacterSlotResponseStatus::Error => NetworkEvent::CharacterSlotSwitchFailed,
         })?;
+        packet_handler.register(|packet: RenameCharacterResponsePacket| {
+            let reason = packet.result;
+            let message = match reason {
+                RenameCharacterResult::Success => return NetworkEvent::CharacterRenamed,
+                RenameCharacterResult::AlreadyRenamed => "This character has already been renamed",
+                RenameCharacterResult::IncorrectUserInformation => "User information is not correct",
+                RenameCharacterResult::Failed => "Failed to rename the character",
+                RenameCharacterResult::NameAlreadyUsed => "Character name is already used",
+                RenameCharacterResult::InGuild => "You have to leave your guild to rename this character",
+                RenameCharacterResult::InParty => "You have to leave your party to rename this character",
+                RenameCharacterResult::NameTooLong => "Character name is too long",
+                RenameCharacterResult::InvalidCharacters => "Character name contains invalid characters",
+                RenameCharacterResult::Prohibited => "Renaming this character is prohibited",
+                RenameCharacterResult::UnknownError => "Renaming failed due to an unknown error",
+            };
+
+            NetworkEvent::CharacterRenameFailed { reason, message }
+        })?;
 
         Ok(packet_handler)
     }
@@ -615,6 +922,23 @@ where
         let inventory_items: Rc<RefCell<Option<Vec<InventoryItem<NoMetadata>>>>> = Rc::new(RefCell::new(None));
 
         packet_handler.register(|_: MapServerPingPacket| NoNetworkEvents)?;
+        packet_handler.register(|packet: WhisperMessagePacket| NetworkEvent::ChatMessage {
+            text: format!("(From {}) : {}", packet.sender_name, packet.message),
+            color: MessageColor::Whisper,
+        })?;
+        packet_handler.register(|packet: WhisperResultPacket| {
+            let text = match packet.result {
+                WhisperResult::Success => return None,
+                WhisperResult::RecipientOffline => "The character is not online.",
+                WhisperResult::RecipientIgnoresYou => "The character is ignoring you.",
+                WhisperResult::RecipientIgnoresEveryone => "The character is ignoring all whispers.",
+            };
+
+            Some(NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            })
+        })?;
         packet_handler.register(|packet: BroadcastMessagePacket| NetworkEvent::ChatMessage {
             text: packet.message,
             color: MessageColor::Broadcast,
@@ -632,11 +956,16 @@ where
             }
         })?;
         packet_handler.register(|packet: OverheadMessagePacket| {
-            // FIX: This should be a different event.
-            NetworkEvent::ChatMessage {
-                text: packet.message,
-                color: MessageColor::Broadcast,
-            }
+            (
+                NetworkEvent::ChatMessage {
+                    text: packet.message.clone(),
+                    color: MessageColor::Broadcast,
+                },
+                NetworkEvent::OverheadMessage {
+                    entity_id: packet.entity_id,
+                    text: packet.message,
+                },
+            )
         })?;
         packet_handler.register(|packet: ServerMessagePacket| NetworkEvent::ChatMessage {
             text: packet.message,
@@ -649,17 +978,29 @@ where
                 green: packet.color.green,
                 blue: packet.color.blue,
             };
-            NetworkEvent::ChatMessage {
-                text: packet.message,
-                color,
-            }
+            (
+                NetworkEvent::ChatMessage {
+                    text: packet.message.clone(),
+                    color,
+                },
+                NetworkEvent::OverheadMessage {
+                    entity_id: packet.entity_id,
+                    text: packet.message,
+                },
+            )
+        })?;
+        packet_handler.register(|packet: DisplayEmotionPacket| NetworkEvent::DisplayEmotion {
+            entity_id: packet.entity_id,
+            emotion: packet.emotion,
         })?;
-        packet_handler.register_noop::<DisplayEmotionPacket>()?;
         packet_handler.register(|packet: EntityMovePacket| {
             let (origin, destination) = packet.from_to.to_origin_destination();
             NetworkEvent::EntityMove(packet.entity_id, origin, destination, packet.timestamp)
         })?;
-        packet_handler.register_noop::<EntityStopMovePacket>()?;
+        // The server sends both of these packets when an entity is knocked back,
+        // so entities slide to the position of either one.
+        packet_handler.register(|packet: EntityStopMovePacket| NetworkEvent::EntityForcedMove(packet.entity_id, packet.position))?;
+        packet_handler.register(|packet: EntitySlidePacket| NetworkEvent::EntityForcedMove(packet.entity_id, packet.position))?;
         packet_handler.register(|packet: PlayerMovePacket| {
             let (origin, destination) = packet.from_to.to_origin_destination();
             NetworkEvent::PlayerMove(origin, destination, packet.timestamp)
@@ -668,18 +1009,138 @@ where
         packet_handler.register(|packet: EntityAppearedPacket| NetworkEvent::AddEntity(packet.into()))?;
         packet_handler.register(|packet: EntityAppeared2Packet| NetworkEvent::AddEntity(packet.into()))?;
         packet_handler.register(|packet: MovingEntityAppearedPacket| NetworkEvent::AddEntity(packet.into()))?;
-        packet_handler.register(|packet: EntityDisappearedPacket| NetworkEvent::RemoveEntity(packet.entity_id))?;
+        packet_handler.register(|packet: EntityDisappearedPacket| match packet.reason {
+            DissapearanceReason::Died => NetworkEvent::EntityDied(packet.entity_id),
+            _ => NetworkEvent::RemoveEntity(packet.entity_id),
+        })?;
         packet_handler.register(|packet: UpdateStatusPacket| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket1| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket2| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket3| NetworkEvent::UpdateStatus(packet.status_type))?;
-        packet_handler.register_noop::<UpdateAttackRangePacket>()?;
+        packet_handler.register(|packet: UpdateAttackRangePacket| NetworkEvent::UpdateAttackRange(packet.attack_range as usize))?;
         packet_handler.register_noop::<NewMailStatusPacket>()?;
-        packet_handler.register_noop::<AchievementUpdatePacket>()?;
-        packet_handler.register_noop::<AchievementListPacket>()?;
+        packet_handler.register(|packet: AchievementUpdatePacket| NetworkEvent::AchievementUpdated {
+            summary: AchievementSummary::from(&packet),
+            achievement: packet.acheivement_data.into(),
+        })?;
+        packet_handler.register(|packet: AchievementListPacket| NetworkEvent::SetAchievementList {
+            summary: AchievementSummary::from(&packet),
+            achievements: packet.acheivement_data.into_iter().map(AchievementProgress::from).collect(),
+        })?;
+        packet_handler.register(|packet: AchievementRewardResultPacket| match packet.received != 0 {
+            true => NetworkEvent::AchievementRewarded {
+                achievement_id: packet.achievement_id,
+            },
+            false => NetworkEvent::ChatMessage {
+                text: "Failed to receive the achievement reward.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register(|packet: ChangeTitleResultPacket| match packet.failed == 0 {
+            true => NetworkEvent::TitleChanged { title_id: packet.title_id },
+            false => NetworkEvent::ChatMessage {
+                text: "Failed to change the title.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register(|packet: OpenBankResultPacket| match packet.reason {
+            0 => NetworkEvent::BankOpened,
+            _ => NetworkEvent::ChatMessage {
+                text: "The bank is currently not available.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register_noop::<CloseBankResultPacket>()?;
+        packet_handler.register(|packet: BankBalancePacket| NetworkEvent::BankBalance {
+            bank_balance: packet.bank_balance,
+        })?;
+        packet_handler.register(|packet: BankDepositResultPacket| match packet.result {
+            BankDepositResult::Success => NetworkEvent::BankBalance {
+                bank_balance: packet.bank_balance,
+            },
+            BankDepositResult::Error => NetworkEvent::ChatMessage {
+                text: "Failed to deposit zeny.".to_owned(),
+                color: MessageColor::Error,
+            },
+            BankDepositResult::NotEnoughZeny => NetworkEvent::ChatMessage {
+                text: "You do not have enough zeny to deposit.".to_owned(),
+                color: MessageColor::Error,
+            },
+            BankDepositResult::Overflow => NetworkEvent::ChatMessage {
+                text: "The bank can not hold any more zeny.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register(|packet: BankWithdrawResultPacket| match packet.result {
+            BankWithdrawResult::Success => NetworkEvent::BankBalance {
+                bank_balance: packet.bank_balance,
+            },
+            BankWithdrawResult::NotEnoughZeny => NetworkEvent::ChatMessage {
+                text: "There is not enough zeny in the bank.".to_owned(),
+                color: MessageColor::Error,
+            },
+            BankWithdrawResult::Overflow => NetworkEvent::ChatMessage {
+                text: "You can not carry any more zeny.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register(|packet: OpenUiPacket| {
+            (packet.ui_type == ATTENDANCE_OPEN_UI_TYPE).then_some(NetworkEvent::OpenAttendanceWindow { claimed_days: packet.data })
+        })?;
+        packet_handler.register(|packet: UiActionPacket| {
+            (packet.ui_type == ATTENDANCE_ACTION_UI_TYPE).then_some(NetworkEvent::AttendanceRewardClaimed { claimed_days: packet.data })
+        })?;
+        packet_handler.register(|_: OpenRefineWindowPacket| NetworkEvent::OpenRefineWindow)?;
+        packet_handler.register(|packet: RefineItemInformationPacket| NetworkEvent::SetRefineItem {
+            index: packet.index,
+            blacksmith_blessing: packet.blacksmith_blessing,
+            materials: packet.materials,
+        })?;
+        packet_handler.register(|packet: RefineResultPacket| NetworkEvent::RefineResult {
+            result: packet.result,
+            index: packet.index,
+            refinement_level: packet.refinement_level as u8,
+        })?;
+        packet_handler.register(|packet: PetInformationPacket| NetworkEvent::SetPetInformation { pet: packet.into() })?;
+        packet_handler.register(|packet: FeedPetResultPacket| match packet.success != 0 {
+            true => NetworkEvent::ChatMessage {
+                text: "You fed your pet.".to_owned(),
+                color: MessageColor::Information,
+            },
+            false => NetworkEvent::ChatMessage {
+                text: "You do not have any food for your pet.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register(|packet: PetStateChangePacket| match packet.state_type {
+            PetStateType::Initialize => Some(NetworkEvent::PetSummoned { pet_id: packet.pet_id }),
+            PetStateType::Intimacy => Some(NetworkEvent::UpdatePetStatus {
+                status: PetStatus::Intimacy(packet.value as u16),
+            }),
+            PetStateType::Hunger => Some(NetworkEvent::UpdatePetStatus {
+                status: PetStatus::Hunger(packet.value as u16),
+            }),
+            PetStateType::Accessory => Some(NetworkEvent::UpdatePetStatus {
+                status: PetStatus::Accessory((packet.value != 0).then_some(ItemId(packet.value))),
+            }),
+            PetStateType::Performance | PetStateType::Hairstyle => None,
+        })?;
+        packet_handler.register(|packet: PetEmotionPacket| {
+            // Talk lines are specific to the pet and we don't support them yet.
+            (packet.emotion < 100).then_some(NetworkEvent::DisplayEmotion {
+                entity_id: packet.pet_id,
+                emotion: packet.emotion as u8,
+            })
+        })?;
         packet_handler.register_noop::<CriticalWeightUpdatePacket>()?;
-        packet_handler.register(|packet: SpriteChangePacket| {
-            (packet.sprite_type == 0).then_some(NetworkEvent::ChangeJob(packet.account_id, packet.value))
+        packet_handler.register(|packet: SpriteChangePacket| match packet.sprite_type {
+            0 => Some(NetworkEvent::ChangeJob(packet.account_id, packet.value)),
+            sprite_type => {
+                LookChange::from_sprite_change(sprite_type, packet.value, packet.value2).map(|change| NetworkEvent::UpdateEntityLook {
+                    entity_id: EntityId(packet.account_id.0),
+                    change,
+                })
+            }
         })?;
         packet_handler.register({
             let inventory_items = inventory_items.clone();
@@ -780,7 +1241,7 @@ where
             }
         })?;
         packet_handler.register_noop::<EquippableSwitchItemListPacket>()?;
-        packet_handler.register_noop::<MapTypePacket>()?;
+        packet_handler.register(|packet: MapTypePacket| NetworkEvent::MapType { flags: packet.flags })?;
         packet_handler.register(|packet: UpdateSkillTreePacket| NetworkEvent::SkillTree(packet.skill_information))?;
         packet_handler.register(|packet: UpdateHotkeysPacket| NetworkEvent::SetHotkeyData {
             tab: packet.tab,
@@ -811,19 +1272,62 @@ where
 
             NetworkEvent::AddChoiceButtons(choices)
         })?;
-        packet_handler.register_noop::<DisplaySpecialEffectPacket>()?;
+        packet_handler.register(|packet: DisplaySpecialEffectPacket| {
+            // Special effects that make an entity smaller or larger instead of
+            // showing an effect.
+            let scale = match packet.effect_id {
+                421 | 423 => 0.5,
+                422 | 424 => 1.5,
+                _ => return None,
+            };
+
+            Some(NetworkEvent::UpdateEntityScale {
+                entity_id: packet.entity_id,
+                scale,
+            })
+        })?;
         packet_handler.register_noop::<DisplaySkillCooldownPacket>()?;
-        packet_handler.register_noop::<DisplaySkillEffectAndDamagePacket>()?;
+        packet_handler.register(|packet: DisplaySkillEffectAndDamagePacket| {
+            NetworkEvent::DamageEffect(
+                packet.destination_entity_id,
+                packet.damage as usize,
+                DamageKind::from_damage_type(packet.skill_type, packet.damage),
+            )
+        })?;
         packet_handler.register(|packet: DisplaySkillEffectNoDamagePacket| {
             NetworkEvent::HealEffect(packet.destination_entity_id, packet.heal_amount as usize)
         })?;
         packet_handler.register_noop::<DisplayPlayerHealEffect>()?;
-        packet_handler.register_noop::<StatusChangePacket>()?;
-        packet_handler.register_noop::<QuestNotificationPacket1>()?;
-        packet_handler.register_noop::<HuntingQuestNotificationPacket>()?;
-        packet_handler.register_noop::<HuntingQuestUpdateObjectivePacket>()?;
-        packet_handler.register_noop::<QuestRemovedPacket>()?;
-        packet_handler.register_noop::<QuestListPacket>()?;
+        packet_handler.register(|packet: StatusChangePacket| {
+            let mut events = vec![status_effect_event(
+                packet.entity_id,
+                packet.index,
+                packet.state,
+                remaining_duration(packet.remaining_in_milliseconds),
+            )];
+
+            if packet.index == ALL_RIDING_STATUS {
+                events.push(NetworkEvent::UpdateCostumeMount {
+                    entity_id: packet.entity_id,
+                    mounted: packet.state != 0,
+                });
+            }
+
+            events
+        })?;
+        packet_handler.register(|packet: QuestNotificationPacket1| NetworkEvent::QuestAdded { quest: packet.into() })?;
+        packet_handler.register(|packet: HuntingQuestNotificationPacket| NetworkEvent::UpdateQuestObjectives {
+            objectives: packet.objective_details,
+        })?;
+        packet_handler.register(
+            |packet: HuntingQuestUpdateObjectivePacket| NetworkEvent::UpdateQuestObjectives {
+                objectives: packet.objective_details,
+            },
+        )?;
+        packet_handler.register(|packet: QuestRemovedPacket| NetworkEvent::QuestRemoved { quest_id: packet.quest_id })?;
+        packet_handler.register(|packet: QuestListPacket| NetworkEvent::SetQuestList {
+            quests: packet.quests.into_iter().map(QuestEntry::from).collect(),
+        })?;
         packet_handler.register(|packet: VisualEffectPacket| {
             let path = match packet.effect {
                 VisualEffect::BaseLevelUp => "angel.str",
@@ -842,7 +1346,22 @@ where
         })?;
         packet_handler.register_noop::<DisplayGainedExperiencePacket>()?;
         packet_handler.register_noop::<DisplayImagePacket>()?;
-        packet_handler.register_noop::<StateChangePacket>()?;
+        packet_handler.register(|packet: StateChangePacket| {
+            vec![
+                NetworkEvent::UpdateMount {
+                    entity_id: packet.entity_id,
+                    mount: Mount::from_options(packet.effect_state),
+                },
+                NetworkEvent::UpdateFollowers {
+                    entity_id: packet.entity_id,
+                    followers: FollowerKind::from_options(packet.effect_state),
+                },
+                NetworkEvent::UpdateEntityCondition {
+                    entity_id: packet.entity_id,
+                    condition: EntityCondition::from_state(packet.body_state, packet.effect_state),
+                },
+            ]
+        })?;
 
         packet_handler.register(|packet: QuestEffectPacket| match packet.effect {
             QuestEffect::None => NetworkEvent::RemoveQuestEffect(packet.entity_id),
@@ -921,12 +1440,22 @@ where
             index: packet.index,
             amount: packet.amount,
         })?;
+        packet_handler.register(|packet: RentalTimePacket| NetworkEvent::RentalItemTime {
+            item_id: packet.item_id,
+            remaining_time: Duration::from_secs(packet.remaining_time as u64),
+        })?;
+        packet_handler.register(|packet: RentalExpiredPacket| NetworkEvent::RentalItemExpired {
+            index: packet.index,
+            item_id: packet.item_id,
+        })?;
         packet_handler.register(|packet: ServerTickPacket| NetworkEvent::UpdateClientTick(packet.client_tick))?;
         packet_handler.register(|packet: RequestPlayerDetailsSuccessPacket| {
-            NetworkEvent::UpdateEntityDetails(EntityId(packet.character_id.0), packet.name)
+            let details = EntityDetails::with_affiliation(packet.name, packet.party_name, packet.guild_name);
+            NetworkEvent::UpdateEntityDetails(EntityId(packet.character_id.0), details)
+        })?;
+        packet_handler.register(|packet: RequestEntityDetailsSuccessPacket| {
+            NetworkEvent::UpdateEntityDetails(packet.entity_id, EntityDetails::new(packet.name))
         })?;
-        packet_handler
-            .register(|packet: RequestEntityDetailsSuccessPacket| NetworkEvent::UpdateEntityDetails(packet.entity_id, packet.name))?;
         packet_handler.register(|packet: UpdateEntityHealthPointsPacket| {
             NetworkEvent::UpdateEntityHealth(
                 packet.entity_id,
@@ -935,8 +1464,19 @@ where
             )
         })?;
         packet_handler.register_noop::<RequestPlayerAttackFailedPacket>()?;
-        packet_handler
-            .register(|packet: DamagePacket| NetworkEvent::DamageEffect(packet.destination_entity_id, packet.damage_amount as usize))?;
+        packet_handler.register(|packet: DamagePacket| match packet.damage_type {
+            PICK_UP_ITEM_DAMAGE_TYPE => None,
+            // The same packet is used to tell the client that an entity sat down or stood up.
+            SIT_DOWN_DAMAGE_TYPE | STAND_UP_DAMAGE_TYPE => Some(NetworkEvent::UpdateSitting {
+                entity_id: packet.source_entity_id,
+                sitting: packet.damage_type == SIT_DOWN_DAMAGE_TYPE,
+            }),
+            damage_type => Some(NetworkEvent::DamageEffect(
+                packet.destination_entity_id,
+                packet.damage_amount as usize,
+                DamageKind::from_damage_type(damage_type, packet.damage_amount),
+            )),
+        })?;
         packet_handler.register(|packet: NpcDialogPacket| NetworkEvent::OpenDialog(packet.text, packet.npc_id))?;
         packet_handler.register(|packet: RequestEquipItemStatusPacket| match packet.result {
             RequestEquipItemStatus::Success => Some(NetworkEvent::UpdateEquippedPosition {
@@ -976,14 +1516,33 @@ where
         })?;
         packet_handler.register_noop::<UseSkillSuccessPacket>()?;
         packet_handler.register_noop::<ToUseSkillSuccessPacket>()?;
+        packet_handler.register(|packet: RememberWarpPointResultPacket| match packet.result {
+            RememberWarpPointResult::Success => NetworkEvent::ChatMessage {
+                text: "Saved the current position as a warp point.".to_owned(),
+                color: MessageColor::Information,
+            },
+            RememberWarpPointResult::SkillLevelTooLow => NetworkEvent::ChatMessage {
+                text: "Your Warp Portal skill level is too low to save more warp points.".to_owned(),
+                color: MessageColor::Error,
+            },
+            RememberWarpPointResult::SkillNotLearned => NetworkEvent::ChatMessage {
+                text: "You need to learn Warp Portal to save warp points.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
         packet_handler
             .register(|packet: NotifySkillUnitPacket| NetworkEvent::AddSkillUnit(packet.entity_id, packet.unit_id, packet.position))?;
         packet_handler.register(|packet: SkillUnitDisappearPacket| NetworkEvent::RemoveSkillUnit(packet.entity_id))?;
         packet_handler.register_noop::<NotifyGroundSkillPacket>()?;
         packet_handler.register(|packet: FriendListPacket| NetworkEvent::SetFriendList { friends: packet.friends })?;
-        packet_handler.register_noop::<FriendOnlineStatusPacket>()?;
-        packet_handler.register(|packet: FriendRequestPacket| NetworkEvent::FriendRequest {
-            requestee: packet.requestee,
+        packet_handler.register(|packet: FriendOnlineStatusPacket| NetworkEvent::FriendOnlineStatus {
+            name: packet.name,
+            state: packet.state,
+        })?;
+        packet_handler.register(|packet: FriendRequestPacket| NetworkEvent::IncomingRequest {
+            request: IncomingRequest::Friend {
+                requestee: packet.requestee,
+            },
         })?;
         packet_handler.register(|packet: FriendRequestResultPacket| {
             let text = match packet.result {
@@ -1008,8 +1567,95 @@ where
             account_id: packet.account_id,
             character_id: packet.character_id,
         })?;
-        packet_handler.register_noop::<PartyInvitePacket>()?;
-        packet_handler.register_noop::<StatusChangeSequencePacket>()?;
+        packet_handler.register(|packet: PartyMemberPositionPacket| NetworkEvent::PartyMemberPosition {
+            account_id: packet.account_id,
+            position: (packet.position.x != u16::MAX || packet.position.y != u16::MAX).then_some(packet.position),
+        })?;
+        packet_handler.register(|packet: PartyInvitePacket| NetworkEvent::IncomingRequest {
+            request: IncomingRequest::Party {
+                party_id: packet.party_id,
+                party_name: packet.party_name,
+            },
+        })?;
+        packet_handler.register(|packet: GuildInvitePacket| NetworkEvent::IncomingRequest {
+            request: IncomingRequest::Guild {
+                guild_id: packet.guild_id,
+                guild_name: packet.guild_name,
+            },
+        })?;
+        packet_handler.register(|packet: GuildNoticePacket| {
+            let notice = GuildNotice::from(packet);
+            (!notice.is_empty()).then_some(NetworkEvent::GuildNotice { notice })
+        })?;
+        packet_handler.register(|packet: GuildEmblemPacket| NetworkEvent::GuildEmblem {
+            emblem_id: GuildEmblemId {
+                guild_id: packet.guild_id,
+                version: packet.emblem_version,
+            },
+            data: packet.emblem_data,
+        })?;
+        packet_handler.register(|packet: InstanceCreationQueuedPacket| NetworkEvent::InstanceQueued {
+            name: packet.name,
+            queue_position: packet.queue_position,
+        })?;
+        packet_handler.register(|packet: InstanceQueuePositionPacket| NetworkEvent::InstanceQueuePosition {
+            queue_position: packet.queue_position,
+        })?;
+        packet_handler.register(|packet: InstanceInformationPacket| NetworkEvent::InstanceInformation {
+            information: InstanceInformation::from(packet),
+        })?;
+        packet_handler.register(|packet: InstanceStatusPacket| {
+            let reason = packet.status;
+            let message = match reason {
+                InstanceStatus::TimeLimitChanged => {
+                    return NetworkEvent::InstanceTimeLimitChanged {
+                        remaining_time: instance::time_until(packet.date),
+                    };
+                }
+                InstanceStatus::TimedOut => "The time limit of the instance has been reached",
+                InstanceStatus::EntryTimedOut => "The instance was closed because nobody entered it in time",
+                InstanceStatus::Destroyed => "The instance has been destroyed",
+                InstanceStatus::CreationFailed => "Failed to create the instance",
+            };
+
+            NetworkEvent::InstanceClosed { reason, message }
+        })?;
+        packet_handler.register(|packet: BattlefieldTeamPacket| NetworkEvent::BattlefieldTeam {
+            entity_id: packet.entity_id,
+            name: packet.name,
+            team_id: packet.team_id,
+        })?;
+        packet_handler.register(|packet: BattlefieldScorePacket| NetworkEvent::BattlefieldScore {
+            lion_score: packet.lion_score,
+            eagle_score: packet.eagle_score,
+        })?;
+        packet_handler.register(|packet: TradeRequestPacket| NetworkEvent::IncomingRequest {
+            request: IncomingRequest::Trade {
+                name: packet.name,
+                character_id: packet.character_id,
+                base_level: packet.base_level,
+            },
+        })?;
+        packet_handler.register(|packet: CreateGuildResultPacket| match packet.result {
+            CreateGuildResult::Success => NetworkEvent::ChatMessage {
+                text: "Your guild has been founded.".to_owned(),
+                color: MessageColor::Information,
+            },
+            CreateGuildResult::AlreadyInGuild => NetworkEvent::ChatMessage {
+                text: "You are already in a guild.".to_owned(),
+                color: MessageColor::Error,
+            },
+            CreateGuildResult::NameAlreadyTaken => NetworkEvent::ChatMessage {
+                text: "A guild with that name already exists.".to_owned(),
+                color: MessageColor::Error,
+            },
+            CreateGuildResult::MissingEmperium => NetworkEvent::ChatMessage {
+                text: "You need an Emperium to found a guild.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler
+            .register(|packet: StatusChangeSequencePacket| status_effect_event(EntityId(packet.id), packet.index, packet.state, None))?;
         packet_handler.register_noop::<ReputationPacket>()?;
         packet_handler.register_noop::<ClanInfoPacket>()?;
         packet_handler.register_noop::<ClanOnlineCountPacket>()?;
@@ -1065,8 +1711,11 @@ where
         self.send_map_server_packet(&RequestWarpToMapPacket::new(map_name, position))
     }
 
-    pub fn entity_details(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
-        self.send_map_server_packet(&RequestDetailsPacket::new(entity_id))
+    /// Returns the details of the entity if they are already known. Otherwise
+    /// they are requested and an [`NetworkEvent::UpdateEntityDetails`] will be
+    /// emitted once the server responds.
+    pub fn entity_details(&mut self, entity_id: EntityId) -> Option<EntityDetails> {
+        self.details_scheduler.request(entity_id)
     }
 
     pub fn player_attack(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
@@ -1079,6 +1728,87 @@ where
         self.send_map_server_packet(&GlobalMessagePacket::new(complete_message))
     }
 
+    pub fn send_whisper(&mut self, recipient_name: String, message: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&WhisperPacket::new(recipient_name, message))
+    }
+
+    pub fn send_emotion(&mut self, emotion: u8) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestEmotionPacket::new(emotion))
+    }
+
+    pub fn request_achievement_reward(&mut self, achievement_id: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestAchievementRewardPacket::new(achievement_id))
+    }
+
+    pub fn change_title(&mut self, title_id: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestChangeTitlePacket::new(title_id))
+    }
+
+    pub fn open_bank(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestOpenBankPacket::new(account_id))
+    }
+
+    pub fn close_bank(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestCloseBankPacket::new(account_id))
+    }
+
+    pub fn request_bank_balance(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestBankBalancePacket::new(account_id))
+    }
+
+    pub fn deposit_zeny(&mut self, account_id: AccountId, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestBankDepositPacket::new(account_id, amount as i32))
+    }
+
+    pub fn withdraw_zeny(&mut self, account_id: AccountId, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestBankWithdrawPacket::new(account_id, amount as i32))
+    }
+
+    pub fn open_attendance(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestOpenUiPacket::new(UiType::Attendance))
+    }
+
+    pub fn claim_attendance_reward(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestAttendanceRewardPacket::default())
+    }
+
+    pub fn select_refine_item(&mut self, index: InventoryIndex) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RefineAddItemPacket::new(index))
+    }
+
+    pub fn refine_item(
+        &mut self,
+        index: InventoryIndex,
+        material_id: ItemId,
+        use_blacksmith_blessing: bool,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestRefinePacket::new(index, material_id, use_blacksmith_blessing as u8))
+    }
+
+    pub fn close_refine_window(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseRefineWindowPacket::default())
+    }
+
+    pub fn send_pet_command(&mut self, command: PetCommand) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestPetCommandPacket::new(command))
+    }
+
+    pub fn sit_down(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestActionPacket::new(EntityId(0), Action::SitDown))
+    }
+
+    pub fn stand_up(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestActionPacket::new(EntityId(0), Action::StandUp))
+    }
+
+    pub fn remember_warp_point(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RememberWarpPointPacket::default())
+    }
+
+    pub fn create_guild(&mut self, character_id: CharacterId, guild_name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CreateGuildPacket::new(character_id, guild_name))
+    }
+
     pub fn start_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&StartDialogPacket::new(npc_id))
     }
@@ -1103,6 +1833,14 @@ where
         self.send_map_server_packet(&RequestUnequipItemPacket::new(item_index))
     }
 
+    pub fn use_item(&mut self, item_index: InventoryIndex, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestUseItemPacket::new(item_index, account_id))
+    }
+
+    pub fn drop_item(&mut self, item_index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestDropItemPacket::new(item_index, amount))
+    }
+
     pub fn cast_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, entity_id: EntityId) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&UseSkillAtIdPacket::new(skill_level, skill_id, entity_id))
     }
@@ -1153,27 +1891,118 @@ where
         ))
     }
 
-    pub fn create_character(&mut self, slot: usize, name: String) -> Result<(), NotConnectedError> {
-        let hair_color = 0;
-        let hair_style = 0;
-        let start_job = 0;
-        let sex = Sex::Male;
+    pub fn request_trade(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestTradePacket::new(account_id))
+    }
+
+    pub fn invite_to_party(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestPartyInvitePacket::new(name))
+    }
 
-        self.send_character_server_packet(&CreateCharacterPacket::new(
-            name, slot as u8, hair_color, hair_style, start_job, sex,
+    pub fn invite_to_guild(
+        &mut self,
+        account_id: AccountId,
+        inviter_account_id: AccountId,
+        inviter_character_id: CharacterId,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestGuildInvitePacket::new(
+            account_id,
+            inviter_account_id,
+            inviter_character_id,
         ))
     }
 
+    pub fn request_guild_emblem(&mut self, guild_id: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestGuildEmblemPacket::new(guild_id))
+    }
+
+    pub fn request_equipment_window(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestEquipmentWindowPacket::new(account_id))
+    }
+
+    pub fn respond_to_request(&mut self, request: &IncomingRequest, accept: bool) -> Result<(), NotConnectedError> {
+        match request {
+            IncomingRequest::Party { party_id, .. } => {
+                self.send_map_server_packet(&PartyInviteResponsePacket::new(*party_id, accept as u8))
+            }
+            IncomingRequest::Guild { guild_id, .. } => {
+                self.send_map_server_packet(&GuildInviteResponsePacket::new(*guild_id, accept as u32))
+            }
+            IncomingRequest::Trade { .. } => {
+                let response = match accept {
+                    true => TradeRequestResponse::Accept,
+                    false => TradeRequestResponse::Reject,
+                };
+
+                self.send_map_server_packet(&TradeRequestResponsePacket::new(response))
+            }
+            IncomingRequest::Friend { requestee } => match accept {
+                true => self.accept_friend_request(requestee.account_id, requestee.character_id),
+                false => self.reject_friend_request(requestee.account_id, requestee.character_id),
+            },
+        }
+    }
+
+    /// Set the packet version of the server. Used to pick between different
+    /// variants of the same packet. If no version is set, the newest variants
+    /// are used.
+    pub fn set_packet_version(&mut self, packet_version: Option<u32>) {
+        self.packet_version = packet_version;
+    }
+
+    pub fn create_character(&mut self, slot: usize, name: String, appearance: CharacterAppearance) -> Result<(), NotConnectedError> {
+        let CharacterAppearance {
+            hair_style,
+            hair_color,
+            start_job,
+            sex,
+        } = appearance;
+
+        match self.packet_version {
+            Some(version) if version < CREATE_CHARACTER_NO_STATS_PACKET_VERSION => {
+                self.send_character_server_packet(&CreateCharacterPacket3::new(name, slot as u8, hair_color, hair_style))
+            }
+            Some(version) if version < CREATE_CHARACTER_JOB_PACKET_VERSION => {
+                self.send_character_server_packet(&CreateCharacterPacket2::new(name, slot as u8, hair_color, hair_style))
+            }
+            _ => self.send_character_server_packet(&CreateCharacterPacket::new(
+                name, slot as u8, hair_color, hair_style, start_job, sex,
+            )),
+        }
+    }
+
+    /// Delete a character. Servers with delayed character deletion will only
+    /// schedule the character for deletion, and the deletion has to be
+    /// confirmed with
+    /// [`confirm_character_deletion`](Self::confirm_character_deletion)
+    /// once the waiting time has passed.
     pub fn delete_character(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
-        let email = "a@a.com".to_string();
+        match self.packet_version {
+            Some(version) if version < DELAYED_CHARACTER_DELETION_PACKET_VERSION => {
+                let email = "a@a.com".to_string();
+
+                self.send_character_server_packet(&DeleteCharacterPacket::new(character_id, email))
+            }
+            _ => self.send_character_server_packet(&ReserveCharacterDeletionPacket::new(character_id)),
+        }
+    }
 
-        self.send_character_server_packet(&DeleteCharacterPacket::new(character_id, email))
+    pub fn confirm_character_deletion(&mut self, character_id: CharacterId, birthdate: String) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&ConfirmCharacterDeletionPacket::new(character_id, birthdate))
+    }
+
+    pub fn cancel_character_deletion(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&CancelCharacterDeletionPacket::new(character_id))
     }
 
     pub fn switch_character_slot(&mut self, origin_slot: usize, destination_slot: usize) -> Result<(), NotConnectedError> {
         self.send_character_server_packet(&SwitchCharacterSlotPacket::new(origin_slot as u16, destination_slot as u16))
     }
 
+    pub fn rename_character(&mut self, character_id: CharacterId, new_name: String) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&RenameCharacterPacket::new(character_id, new_name))
+    }
+
     pub fn set_hotkey_data(&mut self, tab: HotbarTab, index: HotbarSlot, hotkey_data: HotkeyData) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&SetHotkeyData2Packet::new(tab, index, hotkey_data))
     }
@@ -1227,3 +2056,74 @@ mod packet_handlers {
         assert!(result.is_ok());
     }
 }
+
+#[cfg(test)]
+mod received_bytes {
+    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_packets::{ClientTick, PacketExt, ServerTickPacket};
+
+    use crate::{NetworkEvent, NetworkingSystem};
+
+    fn tick_packet() -> Vec<u8> {
+        ServerTickPacket {
+            client_tick: ClientTick(100),
+        }
+        .packet_to_bytes()
+        .unwrap()
+    }
+
+    #[test]
+    fn packet_split_across_reads() {
+        let mut packet_handler = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback).unwrap();
+        let mut buffer = [0; 64];
+        let mut cut_off_buffer_base = 0;
+        let mut read_account_id = false;
+
+        let packet = tick_packet();
+        let (first_read, second_read) = packet.split_at(4);
+
+        buffer[..first_read.len()].copy_from_slice(first_read);
+        let events = NetworkingSystem::process_received_bytes(
+            &mut packet_handler,
+            &mut buffer,
+            &mut cut_off_buffer_base,
+            first_read.len(),
+            &mut read_account_id,
+        );
+
+        assert!(events.is_empty());
+        assert_eq!(cut_off_buffer_base, first_read.len());
+
+        buffer[cut_off_buffer_base..cut_off_buffer_base + second_read.len()].copy_from_slice(second_read);
+        let events = NetworkingSystem::process_received_bytes(
+            &mut packet_handler,
+            &mut buffer,
+            &mut cut_off_buffer_base,
+            second_read.len(),
+            &mut read_account_id,
+        );
+
+        assert!(matches!(events.as_slice(), [NetworkEvent::UpdateClientTick(ClientTick(100))]));
+        assert_eq!(cut_off_buffer_base, 0);
+    }
+
+    #[test]
+    fn packet_bigger_than_buffer() {
+        let mut packet_handler = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback).unwrap();
+        let mut buffer = [0; 4];
+        let mut cut_off_buffer_base = 0;
+        let mut read_account_id = false;
+
+        buffer.copy_from_slice(&tick_packet()[..4]);
+        let events = NetworkingSystem::process_received_bytes(
+            &mut packet_handler,
+            &mut buffer,
+            &mut cut_off_buffer_base,
+            4,
+            &mut read_account_id,
+        );
+
+        assert!(events.is_empty());
+        assert_eq!(cut_off_buffer_base, 0);
+    }
+}
@@ -0,0 +1,54 @@
+/// Option flags of the state change packet that put a character on a mount.
+const OPTION_RIDING: u32 = 0x20;
+const OPTION_DRAGON: u32 = 0x80000 | 0x800000 | 0x1000000 | 0x2000000 | 0x4000000;
+const OPTION_WARG_RIDER: u32 = 0x200000;
+const OPTION_MADO_GEAR: u32 = 0x400000;
+
+/// Index of the status change that puts a character on a costume mount.
+pub(crate) const ALL_RIDING_STATUS: u16 = 613;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mount {
+    /// Peco peco, or a gryphon for royal guards.
+    Peco,
+    Dragon,
+    Warg,
+    MadoGear,
+    /// Mount that any job can use, granted by a status change rather than an
+    /// option flag.
+    Costume,
+}
+
+impl Mount {
+    /// Mount of a character from the option flags of a state change.
+    pub fn from_options(options: u32) -> Option<Self> {
+        match options {
+            options if options & OPTION_DRAGON != 0 => Some(Self::Dragon),
+            options if options & OPTION_MADO_GEAR != 0 => Some(Self::MadoGear),
+            options if options & OPTION_WARG_RIDER != 0 => Some(Self::Warg),
+            options if options & OPTION_RIDING != 0 => Some(Self::Peco),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use super::Mount;
+
+    #[test]
+    fn mounts() {
+        assert_eq!(Mount::from_options(0), None);
+        assert_eq!(Mount::from_options(0x20), Some(Mount::Peco));
+        assert_eq!(Mount::from_options(0x800000), Some(Mount::Dragon));
+        assert_eq!(Mount::from_options(0x200000), Some(Mount::Warg));
+        assert_eq!(Mount::from_options(0x400000), Some(Mount::MadoGear));
+    }
+
+    #[test]
+    fn unrelated_options() {
+        // Cart and falcon.
+        assert_eq!(Mount::from_options(0x08 | 0x10), None);
+        assert_eq!(Mount::from_options(0x08 | 0x20), Some(Mount::Peco));
+    }
+}
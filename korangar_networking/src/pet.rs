@@ -0,0 +1,30 @@
+use ragnarok_packets::{ItemId, PetInformationPacket};
+
+#[derive(Clone, Debug)]
+pub struct PetInformation {
+    pub name: String,
+    pub level: u16,
+    pub hunger: u16,
+    pub intimacy: u16,
+    pub accessory: Option<ItemId>,
+}
+
+impl From<PetInformationPacket> for PetInformation {
+    fn from(packet: PetInformationPacket) -> Self {
+        Self {
+            name: packet.name,
+            level: packet.level,
+            hunger: packet.hunger,
+            intimacy: packet.intimacy,
+            accessory: (packet.accessory_id != 0).then_some(ItemId(packet.accessory_id as u32)),
+        }
+    }
+}
+
+/// Single value of the pet that changed.
+#[derive(Clone, Copy, Debug)]
+pub enum PetStatus {
+    Intimacy(u16),
+    Hunger(u16),
+    Accessory(Option<ItemId>),
+}
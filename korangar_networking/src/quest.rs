@@ -0,0 +1,73 @@
+use ragnarok_packets::{ObjectiveDetails1, Quest, QuestDetails, QuestNotificationPacket1};
+
+#[derive(Clone, Debug)]
+pub struct QuestObjective {
+    pub hunt_identification: u32,
+    pub mob_id: u32,
+    pub mob_name: String,
+    pub current_count: u16,
+    pub total_count: u16,
+}
+
+impl From<QuestDetails> for QuestObjective {
+    fn from(details: QuestDetails) -> Self {
+        Self {
+            hunt_identification: details.hunt_identification,
+            mob_id: details.mob_id,
+            mob_name: details.mob_name,
+            current_count: details.kill_count,
+            total_count: details.total_count,
+        }
+    }
+}
+
+impl From<ObjectiveDetails1> for QuestObjective {
+    fn from(details: ObjectiveDetails1) -> Self {
+        Self {
+            hunt_identification: details.hunt_identification,
+            mob_id: details.mob_id,
+            mob_name: details.mob_name,
+            current_count: 0,
+            total_count: details.mob_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QuestEntry {
+    pub quest_id: u32,
+    pub active: bool,
+    pub expire_time: u32,
+    pub objectives: Vec<QuestObjective>,
+}
+
+impl From<Quest> for QuestEntry {
+    fn from(quest: Quest) -> Self {
+        Self {
+            quest_id: quest.quest_id,
+            active: quest.active != 0,
+            expire_time: quest.expire_time,
+            objectives: quest.objective_details.into_iter().map(QuestObjective::from).collect(),
+        }
+    }
+}
+
+impl From<QuestNotificationPacket1> for QuestEntry {
+    fn from(packet: QuestNotificationPacket1) -> Self {
+        // The packet always contains three objectives, so only the ones that are
+        // actually in use are taken.
+        let objectives = packet
+            .objective_details
+            .into_iter()
+            .take(packet.objective_count as usize)
+            .map(QuestObjective::from)
+            .collect();
+
+        Self {
+            quest_id: packet.quest_id,
+            active: packet.active != 0,
+            expire_time: packet.expire_time,
+            objectives,
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use ragnarok_bytes::{ByteStream, FromBytes, ToBytes};
+use ragnarok_packets::{AccountId, CharacterInformation};
+
+/// Magic bytes at the start of every replay file.
+const REPLAY_MAGIC: &[u8; 4] = b"KRPL";
+/// Version of the replay file format. Needs to be increased whenever the
+/// layout changes.
+const REPLAY_VERSION: u8 = 1;
+
+/// Everything needed to enter the map before the recorded packets can be
+/// played back.
+#[derive(Clone, Debug)]
+pub struct ReplayHeader {
+    pub account_id: AccountId,
+    pub map_name: String,
+    pub character_information: CharacterInformation,
+}
+
+/// Bytes received from the map server together with the time since the start
+/// of the recording.
+#[derive(Clone, Debug)]
+pub struct ReplayChunk {
+    pub elapsed: Duration,
+    pub bytes: Vec<u8>,
+}
+
+/// A recorded map server session.
+#[derive(Clone, Debug)]
+pub struct Replay {
+    pub header: ReplayHeader,
+    pub chunks: Vec<ReplayChunk>,
+}
+
+impl Replay {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    fn read_from(mut reader: impl Read) -> io::Result<Self> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if &magic != REPLAY_MAGIC {
+            return Err(io::Error::new(ErrorKind::InvalidData, "not a replay file"));
+        }
+
+        if read_u8(&mut reader)? != REPLAY_VERSION {
+            return Err(io::Error::new(ErrorKind::InvalidData, "unsupported replay version"));
+        }
+
+        let account_id = AccountId(read_u32(&mut reader)?);
+
+        let mut map_name = vec![0; read_u8(&mut reader)? as usize];
+        reader.read_exact(&mut map_name)?;
+        let map_name = String::from_utf8(map_name).map_err(|_| io::Error::new(ErrorKind::InvalidData, "invalid map name"))?;
+
+        let mut character_bytes = vec![0; read_u16(&mut reader)? as usize];
+        reader.read_exact(&mut character_bytes)?;
+        let character_information = CharacterInformation::from_bytes(&mut ByteStream::<()>::without_metadata(&character_bytes))
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, format!("{error:?}")))?;
+
+        let mut chunks = Vec::new();
+
+        // The client might have been closed in the middle of writing a chunk, so an
+        // incomplete chunk at the end of the file is not an error.
+        while let Some(chunk) = read_chunk(&mut reader)? {
+            chunks.push(chunk);
+        }
+
+        Ok(Self {
+            header: ReplayHeader {
+                account_id,
+                map_name,
+                character_information,
+            },
+            chunks,
+        })
+    }
+}
+
+/// Writes all bytes received from the map server to a replay file.
+pub struct ReplayRecorder {
+    writer: Box<dyn Write + Send>,
+    start: Instant,
+}
+
+impl ReplayRecorder {
+    pub fn create(path: impl AsRef<Path>, header: &ReplayHeader) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), header)
+    }
+
+    fn new(mut writer: impl Write + Send + 'static, header: &ReplayHeader) -> io::Result<Self> {
+        let map_name_length =
+            u8::try_from(header.map_name.len()).map_err(|_| io::Error::new(ErrorKind::InvalidInput, "map name too long"))?;
+        let character_bytes = header
+            .character_information
+            .to_bytes()
+            .map_err(|error| io::Error::new(ErrorKind::InvalidInput, format!("{error:?}")))?;
+
+        writer.write_all(REPLAY_MAGIC)?;
+        writer.write_all(&[REPLAY_VERSION])?;
+        writer.write_all(&header.account_id.0.to_le_bytes())?;
+        writer.write_all(&[map_name_length])?;
+        writer.write_all(header.map_name.as_bytes())?;
+        writer.write_all(&(character_bytes.len() as u16).to_le_bytes())?;
+        writer.write_all(&character_bytes)?;
+
+        Ok(Self {
+            writer: Box::new(writer),
+            start: Instant::now(),
+        })
+    }
+
+    pub(crate) fn record(&mut self, bytes: &[u8]) -> io::Result<()> {
+        let elapsed = self.start.elapsed().as_millis() as u32;
+
+        self.writer.write_all(&elapsed.to_le_bytes())?;
+        self.writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+        self.writer.write_all(bytes)
+    }
+}
+
+fn read_u8(reader: &mut impl Read) -> io::Result<u8> {
+    let mut bytes = [0; 1];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+fn read_u16(reader: &mut impl Read) -> io::Result<u16> {
+    let mut bytes = [0; 2];
+    reader.read_exact(&mut bytes)?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_chunk(reader: &mut impl Read) -> io::Result<Option<ReplayChunk>> {
+    let chunk = read_u32(reader).and_then(|elapsed| {
+        let mut bytes = vec![0; read_u32(reader)? as usize];
+        reader.read_exact(&mut bytes)?;
+
+        Ok(ReplayChunk {
+            elapsed: Duration::from_millis(elapsed as u64),
+            bytes,
+        })
+    });
+
+    match chunk {
+        Ok(chunk) => Ok(Some(chunk)),
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use ragnarok_bytes::{ByteStream, FromBytes};
+    use ragnarok_packets::{AccountId, CharacterInformation};
+
+    use super::{Replay, ReplayHeader, ReplayRecorder};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn header() -> ReplayHeader {
+        let character_bytes = vec![0; 512];
+        let character_information = CharacterInformation::from_bytes(&mut ByteStream::<()>::without_metadata(&character_bytes)).unwrap();
+
+        ReplayHeader {
+            account_id: AccountId(2000001),
+            map_name: "prontera".to_owned(),
+            character_information,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let buffer = SharedBuffer::default();
+        let mut recorder = ReplayRecorder::new(buffer.clone(), &header()).unwrap();
+
+        recorder.record(&[1, 2, 3]).unwrap();
+        recorder.record(&[4, 5]).unwrap();
+        drop(recorder);
+
+        let bytes = buffer.0.lock().unwrap().clone();
+        let replay = Replay::read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(replay.header.account_id, AccountId(2000001));
+        assert_eq!(replay.header.map_name, "prontera");
+        assert_eq!(replay.chunks.len(), 2);
+        assert_eq!(replay.chunks[0].bytes, vec![1, 2, 3]);
+        assert_eq!(replay.chunks[1].bytes, vec![4, 5]);
+    }
+
+    #[test]
+    fn truncated_chunk_is_ignored() {
+        let buffer = SharedBuffer::default();
+        let mut recorder = ReplayRecorder::new(buffer.clone(), &header()).unwrap();
+
+        recorder.record(&[1, 2, 3]).unwrap();
+        recorder.record(&[4, 5, 6, 7]).unwrap();
+        drop(recorder);
+
+        let mut bytes = buffer.0.lock().unwrap().clone();
+        bytes.truncate(bytes.len() - 2);
+        let replay = Replay::read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(replay.chunks.len(), 1);
+    }
+
+    #[test]
+    fn invalid_magic() {
+        assert!(Replay::read_from([0u8; 16].as_slice()).is_err());
+    }
+}
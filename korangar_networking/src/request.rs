@@ -0,0 +1,23 @@
+use ragnarok_packets::{CharacterId, Friend, PartyId};
+
+/// A request from another player that the player can either accept or
+/// reject.
+#[derive(Clone, Debug)]
+pub enum IncomingRequest {
+    Party {
+        party_id: PartyId,
+        party_name: String,
+    },
+    Guild {
+        guild_id: u32,
+        guild_name: String,
+    },
+    Trade {
+        name: String,
+        character_id: CharacterId,
+        base_level: u16,
+    },
+    Friend {
+        requestee: Friend,
+    },
+}
@@ -4,6 +4,7 @@ use ragnarok_packets::{AccountId, CharacterId, Sex};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
 use crate::event::NetworkEvent;
+use crate::replay::{Replay, ReplayRecorder};
 
 #[derive(Debug, Clone, Copy)]
 pub struct LoginServerLoginData {
@@ -57,6 +58,12 @@ pub(crate) enum ServerConnectCommand {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
+        replay_recorder: Option<ReplayRecorder>,
+    },
+    Replay {
+        replay: Replay,
+        action_receiver: UnboundedReceiver<Vec<u8>>,
+        event_sender: UnboundedSender<NetworkEvent>,
     },
 }
 
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use ragnarok_packets::EntityId;
+
+use crate::event::NetworkEvent;
+
+/// Status effects without a duration are sent with a remaining time of either
+/// 0 or -1.
+pub(crate) fn remaining_duration(remaining_in_milliseconds: u32) -> Option<Duration> {
+    match remaining_in_milliseconds {
+        0 => None,
+        remaining if remaining > i32::MAX as u32 => None,
+        remaining => Some(Duration::from_millis(remaining as u64)),
+    }
+}
+
+pub(crate) fn status_effect_event(entity_id: EntityId, status_effect: u16, state: u8, duration: Option<Duration>) -> NetworkEvent {
+    match state {
+        0 => NetworkEvent::RemoveStatusEffect { entity_id, status_effect },
+        _ => NetworkEvent::AddStatusEffect {
+            entity_id,
+            status_effect,
+            duration,
+        },
+    }
+}
+
+#[cfg(test)]
+mod durations {
+    use std::time::Duration;
+
+    use super::remaining_duration;
+
+    #[test]
+    fn remaining() {
+        assert_eq!(remaining_duration(1500), Some(Duration::from_millis(1500)));
+        assert_eq!(remaining_duration(0), None);
+        assert_eq!(remaining_duration(u32::MAX), None);
+    }
+}
@@ -0,0 +1,299 @@
+//! A scriptable stand-in for the login, character and map servers, so the
+//! networking system can be tested without a real game server.
+
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::{Duration, Instant};
+
+use ragnarok_bytes::ByteStream;
+use ragnarok_packets::handler::{HandlerResult, NoPacketCallback, PacketHandler};
+use ragnarok_packets::*;
+
+/// Account id that the mock servers log in every client with.
+pub const ACCOUNT_ID: AccountId = AccountId(2000000);
+/// Character id of the only character on the mock character server.
+pub const CHARACTER_ID: CharacterId = CharacterId(150000);
+pub const MAP_NAME: &str = "prontera";
+
+/// Time that a mock server waits for the client before giving up.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Frames the byte stream of the client into packets and returns the header of
+/// each packet.
+type ClientPacketHandler = PacketHandler<Option<PacketHeader>, (), NoPacketCallback>;
+
+fn register_packet<P: Packet>(packet_handler: &mut ClientPacketHandler) {
+    // Expecting the same packet twice registers it twice.
+    let _ = packet_handler.register(|_: P| Some(P::HEADER));
+}
+
+enum Step {
+    /// Read packets from the client until one with the given header arrives.
+    /// Any other packets before it are skipped.
+    Expect(PacketHeader),
+    Send(Vec<u8>),
+    Wait(Duration),
+}
+
+/// Steps that a mock server plays back for the first client that connects.
+#[derive(Default)]
+pub struct Timeline {
+    steps: Vec<Step>,
+    /// Packets that the mock can frame. Only packets known to the mock can be
+    /// skipped, so an unknown packet fails the timeline.
+    client_packets: Vec<fn(&mut ClientPacketHandler)>,
+}
+
+impl Timeline {
+    pub fn expect<P: Packet>(mut self) -> Self {
+        self.steps.push(Step::Expect(P::HEADER));
+        self.client_packets.push(register_packet::<P>);
+        self
+    }
+
+    pub fn send(self, packet: impl Packet) -> Self {
+        self.send_bytes(packet.packet_to_bytes().unwrap())
+    }
+
+    pub fn send_bytes(mut self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.steps.push(Step::Send(bytes.into()));
+        self
+    }
+
+    pub fn wait(mut self, duration: Duration) -> Self {
+        self.steps.push(Step::Wait(duration));
+        self
+    }
+
+    pub fn then(mut self, other: Timeline) -> Self {
+        self.steps.extend(other.steps);
+        self.client_packets.extend(other.client_packets);
+        self
+    }
+
+    fn packet_handler(&self) -> ClientPacketHandler {
+        let mut packet_handler = ClientPacketHandler::default();
+
+        // The client sends these on its own at any time.
+        register_packet::<LoginServerKeepalivePacket>(&mut packet_handler);
+        register_packet::<CharacterServerKeepalivePacket>(&mut packet_handler);
+        register_packet::<RequestServerTickPacket>(&mut packet_handler);
+
+        self.client_packets.iter().for_each(|register| register(&mut packet_handler));
+
+        packet_handler
+    }
+}
+
+pub struct MockServer {
+    address: SocketAddr,
+    result_receiver: Receiver<Result<(), String>>,
+}
+
+impl MockServer {
+    /// Listen on a free local port and play back the timeline once a client
+    /// connects. The connection stays open until the client closes it.
+    pub fn start(timeline: Timeline) -> Self {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).expect("failed to bind mock server");
+        let address = listener.local_addr().unwrap();
+        let (result_sender, result_receiver) = channel();
+
+        std::thread::spawn(move || {
+            let Ok((mut stream, _)) = listener.accept() else {
+                let _ = result_sender.send(Err("failed to accept client".to_owned()));
+                return;
+            };
+
+            stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+
+            let mut packet_handler = timeline.packet_handler();
+            let mut received = Vec::new();
+            let mut buffer = [0; 4096];
+            let mut result = Ok(());
+
+            for step in timeline.steps {
+                match step {
+                    Step::Expect(header) => {
+                        let deadline = Instant::now() + TIMEOUT;
+
+                        result = loop {
+                            let mut byte_stream = ByteStream::<()>::without_metadata(&received);
+
+                            match packet_handler.process_one(&mut byte_stream) {
+                                HandlerResult::Ok(packet_header) => {
+                                    let packet_length = byte_stream.get_offset();
+                                    received.drain(..packet_length);
+
+                                    if packet_header == Some(header) {
+                                        break Ok(());
+                                    }
+                                }
+                                HandlerResult::PacketCutOff => match stream.read(&mut buffer) {
+                                    Ok(count) if count > 0 && Instant::now() < deadline => received.extend_from_slice(&buffer[..count]),
+                                    _ => break Err(format!("client never sent packet 0x{:04x}", header.0)),
+                                },
+                                HandlerResult::UnhandledPacket => {
+                                    break Err(format!(
+                                        "client sent unknown packet 0x{:04x}",
+                                        u16::from_le_bytes([received[0], received[1]])
+                                    ));
+                                }
+                                HandlerResult::InternalError(error) => {
+                                    break Err(format!("client sent malformed packet: {error:?}"));
+                                }
+                            }
+                        };
+                    }
+                    Step::Send(bytes) => {
+                        if stream.write_all(&bytes).is_err() {
+                            result = Err("client closed the connection".to_owned());
+                        }
+                    }
+                    Step::Wait(duration) => std::thread::sleep(duration),
+                }
+
+                if result.is_err() {
+                    break;
+                }
+            }
+
+            let _ = result_sender.send(result);
+
+            // Hold the connection, so the client doesn't see an unexpected disconnect.
+            while matches!(stream.read(&mut buffer), Ok(count) if count > 0) {}
+        });
+
+        Self { address, result_receiver }
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Wait for the timeline to finish and return any error that occurred.
+    pub fn finish(&self) -> Result<(), String> {
+        self.result_receiver
+            .recv_timeout(TIMEOUT)
+            .unwrap_or_else(|_| Err("timeline did not finish".to_owned()))
+    }
+}
+
+/// All three servers that a client connects to, with the handshake of each
+/// server followed by a custom timeline on the map server.
+pub struct MockGame {
+    pub login_server: MockServer,
+    pub character_server: MockServer,
+    pub map_server: MockServer,
+}
+
+impl MockGame {
+    pub fn start(map_timeline: Timeline) -> Self {
+        let map_server = MockServer::start(
+            Timeline::default()
+                .expect::<MapServerLoginPacket>()
+                .send(MapServerLoginSuccessPacket {
+                    client_tick: ClientTick(100),
+                    position: WorldPosition::new(150, 180),
+                    ignored: [5, 5],
+                    font: 0,
+                })
+                .expect::<MapLoadedPacket>()
+                .then(map_timeline),
+        );
+
+        let character_server = MockServer::start(
+            Timeline::default()
+                .expect::<CharacterServerLoginPacket>()
+                // The character server sends the account id without a packet header.
+                .send_bytes(ACCOUNT_ID.0.to_le_bytes())
+                .send(CharacterServerLoginSuccessPacket {
+                    unknown: 29,
+                    normal_slot_count: 9,
+                    vip_slot_count: 0,
+                    billing_slot_count: 0,
+                    poducilble_slot_count: 9,
+                    vaild_slot: 9,
+                    unused: [0; 20],
+                })
+                .expect::<SelectCharacterPacket>()
+                .send(CharacterSelectionSuccessPacket {
+                    character_id: CHARACTER_ID,
+                    map_name: format!("{MAP_NAME}.gat"),
+                    map_server_ip: ServerAddress([127, 0, 0, 1]),
+                    map_server_port: map_server.address().port(),
+                    unknown: [0; 128],
+                }),
+        );
+
+        let login_server = MockServer::start(
+            Timeline::default()
+                .expect::<LoginServerLoginPacket>()
+                .send(LoginServerLoginSuccessPacket {
+                    login_id1: 1,
+                    account_id: ACCOUNT_ID,
+                    login_id2: 2,
+                    ip_address: 0,
+                    name: [0; 24],
+                    unknown: 0,
+                    sex: Sex::Female,
+                    auth_token: AuthToken([0; 17]),
+                    character_server_information: vec![CharacterServerInformation {
+                        server_ip: ServerAddress([127, 0, 0, 1]),
+                        server_port: character_server.address().port(),
+                        server_name: "Mock".to_owned(),
+                        user_count: 1,
+                        server_type: 0,
+                        display_new: 0,
+                        unknown: [0; 128],
+                    }],
+                }),
+        );
+
+        Self {
+            login_server,
+            character_server,
+            map_server,
+        }
+    }
+}
+
+/// A monster that appears at the given position.
+pub fn monster_appeared(entity_id: EntityId, x: usize, y: usize) -> EntityAppearedPacket {
+    EntityAppearedPacket {
+        object_type: 5,
+        entity_id,
+        group_id: 0,
+        movement_speed: 200,
+        body_state: 0,
+        health_state: 0,
+        effect_state: 0,
+        job: 1002,
+        head: 0,
+        weapon: 0,
+        shield: 0,
+        accessory: 0,
+        accessory2: 0,
+        accessory3: 0,
+        head_palette: 0,
+        body_palette: 0,
+        head_direction: 0,
+        robe: 0,
+        guild_id: 0,
+        emblem_version: 0,
+        honor: 0,
+        virtue: 0,
+        is_pk_mode_on: 0,
+        sex: Sex::Female,
+        position: WorldPosition::new(x, y),
+        x_size: 0,
+        y_size: 0,
+        c_level: 1,
+        font: 0,
+        maximum_health_points: 50,
+        health_points: 50,
+        is_boss: 0,
+        body: 0,
+        name: "Poring".to_owned(),
+    }
+}
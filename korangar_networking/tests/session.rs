@@ -0,0 +1,188 @@
+mod mock_server;
+
+use std::time::{Duration, Instant};
+
+use korangar_networking::{DamageKind, NetworkEvent, NetworkingSystem};
+use ragnarok_packets::handler::{NoPacketCallback, PacketCallback};
+use ragnarok_packets::*;
+
+use self::mock_server::{monster_appeared, MockGame, Timeline, ACCOUNT_ID, CHARACTER_ID, MAP_NAME};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Collect events until one matches the predicate. Panics if no event matched
+/// before the timeout.
+fn wait_for<Callback>(
+    networking_system: &mut NetworkingSystem<Callback>,
+    mut predicate: impl FnMut(&NetworkEvent) -> bool,
+) -> Vec<NetworkEvent>
+where
+    Callback: PacketCallback + Send,
+{
+    let deadline = Instant::now() + TIMEOUT;
+    let mut events = Vec::new();
+
+    while Instant::now() < deadline {
+        for event in networking_system.get_events() {
+            let matched = predicate(&event);
+            events.push(event);
+
+            if matched {
+                return events;
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    panic!("expected event was not received, got {events:?}");
+}
+
+/// Log in through all three mock servers and return once the client is
+/// connected to the map server.
+fn enter_map(map_timeline: Timeline) -> (NetworkingSystem<NoPacketCallback>, MockGame) {
+    let game = MockGame::start(map_timeline);
+    let mut networking_system = NetworkingSystem::spawn();
+
+    networking_system.connect_to_login_server(game.login_server.address(), "username", "password");
+
+    let events = wait_for(&mut networking_system, |event| {
+        matches!(event, NetworkEvent::LoginServerConnected { .. })
+    });
+    let Some(NetworkEvent::LoginServerConnected {
+        character_servers,
+        login_data,
+    }) = events.into_iter().last()
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(login_data.account_id, ACCOUNT_ID);
+    assert_eq!(character_servers[0].server_port, game.character_server.address().port());
+
+    networking_system.disconnect_from_login_server();
+    networking_system.connect_to_character_server(&login_data, character_servers[0].clone());
+
+    wait_for(&mut networking_system, |event| {
+        matches!(event, NetworkEvent::CharacterServerConnected { normal_slot_count: 9, .. })
+    });
+
+    networking_system.select_character(0).unwrap();
+
+    let events = wait_for(&mut networking_system, |event| {
+        matches!(event, NetworkEvent::CharacterSelected { .. })
+    });
+    let Some(NetworkEvent::CharacterSelected {
+        login_data: character_login_data,
+        map_name,
+    }) = events.into_iter().last()
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(map_name, MAP_NAME);
+    assert_eq!(character_login_data.character_id, CHARACTER_ID);
+
+    networking_system.disconnect_from_character_server();
+    networking_system.connect_to_map_server(&login_data, character_login_data, None);
+
+    wait_for(&mut networking_system, |event| {
+        matches!(event, NetworkEvent::SetPlayerPosition(..))
+    });
+
+    // The map server only starts its timeline once the map is loaded, so no
+    // events are lost while waiting for the player position.
+    networking_system.map_loaded().unwrap();
+
+    game.login_server.finish().unwrap();
+    game.character_server.finish().unwrap();
+
+    (networking_system, game)
+}
+
+#[test]
+fn handshake() {
+    let (_networking_system, game) = enter_map(Timeline::default());
+
+    game.map_server.finish().unwrap();
+}
+
+#[test]
+fn entity_lifecycle() {
+    let monster_id = EntityId(110000);
+
+    let timeline = Timeline::default()
+        .send(monster_appeared(monster_id, 155, 185))
+        .send(EntityMovePacket {
+            entity_id: monster_id,
+            from_to: WorldPosition2::new(155, 185, 160, 185),
+            timestamp: ClientTick(200),
+        })
+        .send(DamagePacket {
+            source_entity_id: EntityId(ACCOUNT_ID.0),
+            destination_entity_id: monster_id,
+            client_tick: ClientTick(300),
+            source_movement_speed: 150,
+            destination_movement_speed: 200,
+            damage_amount: 25,
+            is_special_damage: 0,
+            amount_of_hits: 1,
+            damage_type: 0,
+            damage_amount2: 0,
+        })
+        .send(EntityDisappearedPacket {
+            entity_id: monster_id,
+            reason: DissapearanceReason::Died,
+        });
+
+    let (mut networking_system, game) = enter_map(timeline);
+    let events = wait_for(&mut networking_system, |event| matches!(event, NetworkEvent::EntityDied(..)));
+
+    let mut events = events.into_iter().filter(|event| {
+        matches!(
+            event,
+            NetworkEvent::AddEntity(..) | NetworkEvent::EntityMove(..) | NetworkEvent::DamageEffect(..) | NetworkEvent::EntityDied(..)
+        )
+    });
+
+    let Some(NetworkEvent::AddEntity(entity_data)) = events.next() else {
+        panic!("entity was not added first");
+    };
+    assert_eq!(entity_data.entity_id, monster_id);
+    assert_eq!((entity_data.position.x, entity_data.position.y), (155, 185));
+    assert_eq!(entity_data.health_points, 50);
+
+    let Some(NetworkEvent::EntityMove(entity_id, origin, destination, _)) = events.next() else {
+        panic!("entity did not move");
+    };
+    assert_eq!(entity_id, monster_id);
+    assert_eq!((origin.x, origin.y), (155, 185));
+    assert_eq!((destination.x, destination.y), (160, 185));
+
+    assert!(matches!(events.next(), Some(NetworkEvent::DamageEffect(entity_id, 25, DamageKind::Normal)) if entity_id == monster_id));
+    assert!(matches!(events.next(), Some(NetworkEvent::EntityDied(entity_id)) if entity_id == monster_id));
+    assert!(events.next().is_none());
+
+    game.map_server.finish().unwrap();
+}
+
+#[test]
+fn packets_split_across_reads() {
+    let monster_id = EntityId(110001);
+    let bytes = monster_appeared(monster_id, 10, 20).packet_to_bytes().unwrap();
+    let (first_half, second_half) = bytes.split_at(bytes.len() / 2);
+
+    // The second half arrives later, so the client has to keep the first half
+    // until the rest of the packet was received.
+    let timeline = Timeline::default()
+        .send_bytes(first_half)
+        .wait(Duration::from_millis(100))
+        .send_bytes(second_half);
+
+    let (mut networking_system, game) = enter_map(timeline);
+    let events = wait_for(&mut networking_system, |event| matches!(event, NetworkEvent::AddEntity(..)));
+
+    assert!(matches!(events.last(), Some(NetworkEvent::AddEntity(entity_data)) if entity_data.entity_id == monster_id));
+
+    game.map_server.finish().unwrap();
+}
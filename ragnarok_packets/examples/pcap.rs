@@ -197,6 +197,11 @@ fn main() {
         CharacterDeletionSuccessPacket,
         CharacterDeletionFailedPacket,
         SwitchCharacterSlotResponsePacket,
+        CharacterListNotifyPacket,
+        RenameCharacterResponsePacket,
+        ReserveCharacterDeletionResponsePacket,
+        ConfirmCharacterDeletionResponsePacket,
+        CancelCharacterDeletionResponsePacket,
     ]);
 
     let mut server_character_handler = create_handler!(ServerType::Character, Direction::Outgoing, [
@@ -204,8 +209,14 @@ fn main() {
         RequestCharacterListPacket,
         SelectCharacterPacket,
         CreateCharacterPacket,
+        CreateCharacterPacket2,
+        CreateCharacterPacket3,
         DeleteCharacterPacket,
         SwitchCharacterSlotPacket,
+        RenameCharacterPacket,
+        ReserveCharacterDeletionPacket,
+        ConfirmCharacterDeletionPacket,
+        CancelCharacterDeletionPacket,
     ]);
 
     let mut client_map_handler = create_handler!(ServerType::Map, Direction::Incoming, [
@@ -218,6 +229,7 @@ fn main() {
         DisplayEmotionPacket,
         EntityMovePacket,
         EntityStopMovePacket,
+        EntitySlidePacket,
         PlayerMovePacket,
         ChangeMapPacket,
         EntityAppearedPacket,
@@ -232,6 +244,31 @@ fn main() {
         NewMailStatusPacket,
         AchievementUpdatePacket,
         AchievementListPacket,
+        AchievementRewardResultPacket,
+        ChangeTitleResultPacket,
+        OpenBankResultPacket,
+        CloseBankResultPacket,
+        BankBalancePacket,
+        BankDepositResultPacket,
+        BankWithdrawResultPacket,
+        OpenUiPacket,
+        UiActionPacket,
+        OpenRefineWindowPacket,
+        RefineItemInformationPacket,
+        RefineResultPacket,
+        PetInformationPacket,
+        FeedPetResultPacket,
+        PetStateChangePacket,
+        PetEmotionPacket,
+        GuildInvitePacket,
+        TradeRequestPacket,
+        GuildNoticePacket,
+        InstanceCreationQueuedPacket,
+        InstanceQueuePositionPacket,
+        InstanceInformationPacket,
+        InstanceStatusPacket,
+        BattlefieldTeamPacket,
+        BattlefieldScorePacket,
         CriticalWeightUpdatePacket,
         SpriteChangePacket,
         InventoyStartPacket,
@@ -269,6 +306,8 @@ fn main() {
         QuestEffectPacket,
         ItemPickupPacket,
         RemoveItemFromInventoryPacket,
+        RentalTimePacket,
+        RentalExpiredPacket,
         ServerTickPacket,
         RequestPlayerDetailsSuccessPacket,
         RequestEntityDetailsSuccessPacket,
@@ -307,6 +346,11 @@ fn main() {
         SellListPacket,
         SellItemsPacket,
         SellItemsResultPacket,
+        WhisperMessagePacket,
+        WhisperResultPacket,
+        RememberWarpPointResultPacket,
+        CreateGuildResultPacket,
+        GuildEmblemPacket,
     ]);
 
     let mut server_map_handler = create_handler!(ServerType::Map, Direction::Outgoing, [
@@ -336,6 +380,33 @@ fn main() {
         CloseShopPacket,
         SellItemsPacket,
         RequestServerTickPacket,
+        WhisperPacket,
+        RememberWarpPointPacket,
+        CreateGuildPacket,
+        RequestEmotionPacket,
+        RequestAchievementRewardPacket,
+        RequestChangeTitlePacket,
+        RequestOpenBankPacket,
+        RequestCloseBankPacket,
+        RequestBankBalancePacket,
+        RequestBankDepositPacket,
+        RequestBankWithdrawPacket,
+        RequestOpenUiPacket,
+        RequestAttendanceRewardPacket,
+        RefineAddItemPacket,
+        RequestRefinePacket,
+        CloseRefineWindowPacket,
+        RequestPetCommandPacket,
+        PartyInviteResponsePacket,
+        GuildInviteResponsePacket,
+        TradeRequestResponsePacket,
+        RequestTradePacket,
+        RequestPartyInvitePacket,
+        RequestGuildInvitePacket,
+        RequestGuildEmblemPacket,
+        RequestEquipmentWindowPacket,
+        RequestUseItemPacket,
+        RequestDropItemPacket,
     ]);
 
     println!("{}", "Listening for packets".green());
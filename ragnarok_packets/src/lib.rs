@@ -297,6 +297,16 @@ pub struct Packet006b {
     pub unknown: [u8; 20],
 }
 
+/// Sent by the character server after logging in. Provides the number of
+/// pages that the character list is split into.
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09A0)]
+pub struct CharacterListNotifyPacket {
+    pub page_count: u32,
+    pub slot_count: u32,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B18)]
@@ -501,6 +511,38 @@ pub struct CreateCharacterPacket {
     pub sex: Sex,
 }
 
+/// Sent by the client to the character server when the player tries to create
+/// a new character. Used instead of [CreateCharacterPacket] by servers with a
+/// packet version older than 20151001, which don't support choosing the
+/// starting job or sex.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0970)]
+pub struct CreateCharacterPacket2 {
+    #[length(24)]
+    pub name: String,
+    pub slot: u8,
+    pub hair_color: u16, // TODO: HairColor
+    pub hair_style: u16, // TODO: HairStyle
+}
+
+/// Sent by the client to the character server when the player tries to create
+/// a new character. Used instead of [CreateCharacterPacket] by servers with a
+/// packet version older than 20120307, which still expect the initial stats
+/// of the character.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0067)]
+pub struct CreateCharacterPacket3 {
+    #[length(24)]
+    pub name: String,
+    #[new_value([5; 6])]
+    pub stats: [u8; 6],
+    pub slot: u8,
+    pub hair_color: u16, // TODO: HairColor
+    pub hair_style: u16, // TODO: HairStyle
+}
+
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct CharacterInformation {
@@ -629,6 +671,17 @@ pub struct EntityStopMovePacket {
     pub position: TilePosition,
 }
 
+/// Sent by the map server to the client.
+/// Informs the client that an entity was moved to a new position without
+/// walking, for example when it is knocked back by a skill.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01FF)]
+pub struct EntitySlidePacket {
+    pub entity_id: EntityId,
+    pub position: TilePosition,
+}
+
 /// Sent by the map server to the client.
 /// Informs the client that the player is pathing towards a new position.
 /// Provides the initial position and destination of the movement, as well as a
@@ -683,6 +736,116 @@ pub struct CharacterDeletionFailedPacket {
 #[header(0x006F)]
 pub struct CharacterDeletionSuccessPacket {}
 
+/// Sent by the client to the character server when the user tries to delete a
+/// character on servers with delayed character deletion. Schedules the
+/// character for deletion. The character can only be deleted with
+/// [ConfirmCharacterDeletionPacket] once the waiting time has passed.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0827)]
+pub struct ReserveCharacterDeletionPacket {
+    pub character_id: CharacterId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum CharacterDeletionReservationResult {
+    #[numeric_value(0)]
+    UnknownError,
+    #[numeric_value(1)]
+    Success,
+    #[numeric_value(2)]
+    NotAllowed,
+    #[numeric_value(3)]
+    DatabaseError,
+    #[numeric_value(4)]
+    InGuild,
+    #[numeric_value(5)]
+    InParty,
+}
+
+/// Sent by the character server as a response to
+/// [ReserveCharacterDeletionPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0828)]
+pub struct ReserveCharacterDeletionResponsePacket {
+    pub character_id: CharacterId,
+    pub result: CharacterDeletionReservationResult,
+    /// Seconds until the character can be deleted.
+    pub deletion_date: u32,
+}
+
+/// Sent by the client to the character server to delete a character that was
+/// scheduled for deletion with [ReserveCharacterDeletionPacket].
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0829)]
+pub struct ConfirmCharacterDeletionPacket {
+    pub character_id: CharacterId,
+    /// Birthdate of the account holder in the format `YYMMDD`.
+    #[length(6)]
+    pub birthdate: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum CharacterDeletionConfirmationResult {
+    #[numeric_value(0)]
+    UnknownError,
+    #[numeric_value(1)]
+    Success,
+    #[numeric_value(2)]
+    NotAllowed,
+    #[numeric_value(3)]
+    DatabaseError,
+    #[numeric_value(4)]
+    TooEarly,
+    #[numeric_value(5)]
+    IncorrectBirthdate,
+}
+
+/// Sent by the character server as a response to
+/// [ConfirmCharacterDeletionPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x082A)]
+pub struct ConfirmCharacterDeletionResponsePacket {
+    pub character_id: CharacterId,
+    pub result: CharacterDeletionConfirmationResult,
+}
+
+/// Sent by the client to the character server to cancel the scheduled deletion
+/// of a character.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x082B)]
+pub struct CancelCharacterDeletionPacket {
+    pub character_id: CharacterId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum CharacterDeletionCancellationResult {
+    #[numeric_value(1)]
+    Success,
+    #[numeric_value(2)]
+    DatabaseError,
+}
+
+/// Sent by the character server as a response to
+/// [CancelCharacterDeletionPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x082C)]
+pub struct CancelCharacterDeletionResponsePacket {
+    pub character_id: CharacterId,
+    pub result: CharacterDeletionCancellationResult,
+}
+
 /// Sent by the client to the character server when the user selects a
 /// character. Attempts to select the character in the specified slot.
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
@@ -788,6 +951,338 @@ pub struct AchievementListPacket {
     pub acheivement_data: Vec<AchievementData>,
 }
 
+/// Sent by the client to the map server to claim the reward of a completed
+/// achievement.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A25)]
+pub struct RequestAchievementRewardPacket {
+    pub achievement_id: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A26)]
+pub struct AchievementRewardResultPacket {
+    pub received: u8,
+    pub achievement_id: u32,
+}
+
+/// Sent by the client to the map server to change the title that is displayed
+/// for the player. Titles are unlocked by achievements.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A2E)]
+pub struct RequestChangeTitlePacket {
+    pub title_id: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A2F)]
+pub struct ChangeTitleResultPacket {
+    pub failed: u8,
+    pub title_id: u32,
+}
+
+/// Sent by the client to the map server when the player wants to access their
+/// bank.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09B6)]
+pub struct RequestOpenBankPacket {
+    pub account_id: AccountId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09B7)]
+pub struct OpenBankResultPacket {
+    pub reason: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09B8)]
+pub struct RequestCloseBankPacket {
+    pub account_id: AccountId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09B9)]
+pub struct CloseBankResultPacket {
+    pub reason: u16,
+}
+
+/// Sent by the client to the map server to request the current balance of the
+/// bank.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09AB)]
+pub struct RequestBankBalancePacket {
+    pub account_id: AccountId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09A6)]
+pub struct BankBalancePacket {
+    pub bank_balance: i64,
+    pub reason: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09A7)]
+pub struct RequestBankDepositPacket {
+    pub account_id: AccountId,
+    pub amount: i32,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum BankDepositResult {
+    Success,
+    Error,
+    NotEnoughZeny,
+    Overflow,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09A8)]
+pub struct BankDepositResultPacket {
+    pub result: BankDepositResult,
+    pub bank_balance: i64,
+    pub zeny: i32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09A9)]
+pub struct RequestBankWithdrawPacket {
+    pub account_id: AccountId,
+    pub amount: i32,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum BankWithdrawResult {
+    Success,
+    NotEnoughZeny,
+    Overflow,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09AA)]
+pub struct BankWithdrawResultPacket {
+    pub result: BankWithdrawResult,
+    pub bank_balance: i64,
+    pub zeny: i32,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum UiType {
+    #[numeric_value(5)]
+    Attendance,
+}
+
+/// Sent by the client to the map server to request opening a server controlled
+/// user interface.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A68)]
+pub struct RequestOpenUiPacket {
+    pub ui_type: UiType,
+}
+
+/// Sent by the map server to the client to open a user interface. The meaning
+/// of `data` depends on the `ui_type`. For the attendance interface (type 7)
+/// it holds the number of days that were already claimed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AE2)]
+pub struct OpenUiPacket {
+    pub ui_type: u8,
+    pub data: u32,
+}
+
+/// Sent by the client to the map server to claim the attendance reward of the
+/// current day.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AEF)]
+pub struct RequestAttendanceRewardPacket {}
+
+/// Sent by the map server to the client as a response to an action in a server
+/// controlled user interface. For the attendance interface (type 0) `data`
+/// holds the updated number of claimed days.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AF0)]
+pub struct UiActionPacket {
+    pub ui_type: u32,
+    pub data: u32,
+}
+
+/// Sent by the map server to the client to open the refine window.
+#[derive(Debug, Clone, Default, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AA0)]
+pub struct OpenRefineWindowPacket {}
+
+/// Sent by the client to the map server when the player selects an item in the
+/// refine window.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AA1)]
+pub struct RefineAddItemPacket {
+    pub index: InventoryIndex,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct RefineMaterialInformation {
+    pub item_id: ItemId,
+    pub chance: u8,
+    pub zeny: u32,
+}
+
+/// Sent by the map server to the client as a response to a
+/// [`RefineAddItemPacket`]. Lists the materials that can be used to refine the
+/// item.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AA2)]
+#[variable_length]
+pub struct RefineItemInformationPacket {
+    pub index: InventoryIndex,
+    /// Number of Blacksmith Blessings required to protect the item from
+    /// breaking. 0 if the item can not break.
+    pub blacksmith_blessing: u8,
+    #[repeating_remaining]
+    pub materials: Vec<RefineMaterialInformation>,
+}
+
+/// Sent by the client to the map server to refine an item using the selected
+/// material.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AA3)]
+pub struct RequestRefinePacket {
+    pub index: InventoryIndex,
+    pub material_id: ItemId,
+    pub use_blacksmith_blessing: u8,
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AA4)]
+pub struct CloseRefineWindowPacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum RefineResult {
+    Success,
+    Failure,
+    Downgrade,
+}
+
+/// Sent by the map server to the client after an item was refined.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0188)]
+pub struct RefineResultPacket {
+    pub result: RefineResult,
+    pub index: InventoryIndex,
+    pub refinement_level: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum PetCommand {
+    RequestInformation,
+    Feed,
+    Performance,
+    ReturnToEgg,
+    UnequipAccessory,
+}
+
+/// Sent by the client to the map server to interact with the pet of the
+/// player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A1)]
+pub struct RequestPetCommandPacket {
+    pub command: PetCommand,
+}
+
+/// Sent by the map server to the client when the pet of the player is
+/// summoned or as a response to [`PetCommand::RequestInformation`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A2)]
+pub struct PetInformationPacket {
+    #[length(24)]
+    pub name: String,
+    pub renamed: u8,
+    pub level: u16,
+    pub hunger: u16,
+    pub intimacy: u16,
+    pub accessory_id: u16,
+    pub job: u16,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`PetCommand::Feed`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A3)]
+pub struct FeedPetResultPacket {
+    pub success: u8,
+    pub item_id: ItemId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum PetStateType {
+    Initialize,
+    Intimacy,
+    Hunger,
+    Accessory,
+    Performance,
+    Hairstyle,
+}
+
+/// Sent by the map server to the client when a value of a pet changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A4)]
+pub struct PetStateChangePacket {
+    pub state_type: PetStateType,
+    pub pet_id: EntityId,
+    pub value: u32,
+}
+
+/// Sent by the map server to the client to display an emotion above a pet.
+/// Values of 100 and above are pet specific talk lines rather than emotions.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01AA)]
+pub struct PetEmotionPacket {
+    pub pet_id: EntityId,
+    pub emotion: u32,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0ADE)]
@@ -959,7 +1454,40 @@ pub struct EquippableSwitchItemListPacket {
 #[header(0x099B)]
 pub struct MapTypePacket {
     pub map_type: u16,
-    pub flags: u32,
+    pub flags: MapTypeFlags,
+}
+
+bitflags::bitflags! {
+    /// Properties of the current map. Only the flags that are used by the
+    /// client are named.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+    pub struct MapTypeFlags: u32 {
+        const PVP = 0b0000_0001;
+        const GVG = 0b0000_0010;
+        const SIEGE = 0b0000_0100;
+        const BATTLEFIELD = 0b1000_0000;
+    }
+}
+
+impl FixedByteSize for MapTypeFlags {
+    fn size_in_bytes() -> usize {
+        <<Self as bitflags::Flags>::Bits as FixedByteSize>::size_in_bytes()
+    }
+}
+
+impl FromBytes for MapTypeFlags {
+    fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
+        // The server sends a lot more flags than the ones we care about, so unknown
+        // bits are kept instead of failing.
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_stream).map(Self::from_bits_retain)
+    }
+}
+
+impl ToBytes for MapTypeFlags {
+    fn to_bytes(&self) -> ConversionResult<Vec<u8>> {
+        self.bits().to_bytes()
+    }
 }
 
 /// Sent by the map server to the client when there is a new chat message from
@@ -1025,6 +1553,15 @@ pub struct DisplayEmotionPacket {
     pub emotion: u8,
 }
 
+/// Sent by the client to the map server when the player wants to display an
+/// emotion above their character.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BF)]
+pub struct RequestEmotionPacket {
+    pub emotion: u8,
+}
+
 /// Every value that can be set from the server through [UpdateStatusPacket],
 /// [UpdateStatusPacket1], [UpdateStatusPacket2], and [UpdateStatusPacket3].
 /// All UpdateStatusPackets do the same, they just have different sizes
@@ -1317,6 +1854,52 @@ pub struct GlobalMessagePacket {
     pub message: String,
 }
 
+/// Sent by the client to the map server when the player wants to send a
+/// private message to another character.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0096)]
+#[variable_length]
+pub struct WhisperPacket {
+    #[length(24)]
+    pub recipient_name: String,
+    #[length_remaining_off_by_one]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when another character sent a private
+/// message to the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09DE)]
+#[variable_length]
+pub struct WhisperMessagePacket {
+    pub sender_account_id: AccountId,
+    #[length(24)]
+    pub sender_name: String,
+    pub is_admin: u8,
+    #[length_remaining]
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum WhisperResult {
+    Success,
+    RecipientOffline,
+    RecipientIgnoresYou,
+    RecipientIgnoresEveryone,
+}
+
+/// Sent by the map server to the client as a response to a [`WhisperPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09DF)]
+pub struct WhisperResultPacket {
+    pub result: WhisperResult,
+    pub character_id: CharacterId,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0139)]
@@ -1373,6 +1956,42 @@ pub struct RequestServerTickPacket {
     pub client_tick: ClientTick,
 }
 
+/// Sent by the client to the character server when the player wants to
+/// rename a character.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x08FC)]
+pub struct RenameCharacterPacket {
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub new_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum RenameCharacterResult {
+    Success,
+    AlreadyRenamed,
+    IncorrectUserInformation,
+    Failed,
+    NameAlreadyUsed,
+    InGuild,
+    InParty,
+    NameTooLong,
+    InvalidCharacters,
+    Prohibited,
+    UnknownError,
+}
+
+/// Sent by the character server as a response to [RenameCharacterPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x08FD)]
+pub struct RenameCharacterResponsePacket {
+    pub result: RenameCharacterResult,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
@@ -1810,7 +2429,8 @@ pub struct QuestNotificationPacket1 {
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct HuntingObjective {
-    pub quest_id: u32,
+    /// Matches the `hunt_identification` of the objective that is updated.
+    pub hunt_identification: u32,
     pub mob_id: u32,
     pub total_count: u16,
     pub current_count: u16,
@@ -2021,6 +2641,27 @@ pub struct RemoveItemFromInventoryPacket {
     pub amount: u16,
 }
 
+/// Sent by the map server to tell the client how much time is left on a rental
+/// item.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0298)]
+pub struct RentalTimePacket {
+    pub item_id: ItemId,
+    /// Remaining time in seconds.
+    pub remaining_time: u32,
+}
+
+/// Sent by the map server when a rental item expired. The item is removed
+/// from the inventory with a separate [RemoveItemFromInventoryPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0299)]
+pub struct RentalExpiredPacket {
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+}
+
 // TODO: improve names
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
@@ -2331,6 +2972,30 @@ pub struct ToUseSkillSuccessPacket {
     pub cause: u8,
 }
 
+/// Sent by the client to the map server when the player wants to memorize
+/// the current position as a warp portal destination.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x011D)]
+pub struct RememberWarpPointPacket {}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum RememberWarpPointResult {
+    Success,
+    SkillLevelTooLow,
+    SkillNotLearned,
+}
+
+/// Sent by the map server to the client as a response to a
+/// [`RememberWarpPointPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x011E)]
+pub struct RememberWarpPointResultPacket {
+    pub result: RememberWarpPointResult,
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
@@ -2660,6 +3325,17 @@ pub struct FriendRequestResultPacket {
     pub friend: Friend,
 }
 
+/// Sent by the map server to update the position of a party member on the
+/// same map. A position of `u16::MAX` on both axes means that the member
+/// left the map.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0107)]
+pub struct PartyMemberPositionPacket {
+    pub account_id: AccountId,
+    pub position: TilePosition,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x02C6)]
@@ -2669,6 +3345,269 @@ pub struct PartyInvitePacket {
     pub party_name: String,
 }
 
+/// Sent by the client to the map server when the player wants to found a new
+/// guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0165)]
+pub struct CreateGuildPacket {
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum CreateGuildResult {
+    Success,
+    AlreadyInGuild,
+    NameAlreadyTaken,
+    MissingEmperium,
+}
+
+/// Sent by the map server to the client as a response to a
+/// [`CreateGuildPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0167)]
+pub struct CreateGuildResultPacket {
+    pub result: CreateGuildResult,
+}
+
+/// Sent by the client to the map server as a response to a
+/// [`PartyInvitePacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02C7)]
+pub struct PartyInviteResponsePacket {
+    pub party_id: PartyId,
+    pub accept: u8,
+}
+
+/// Sent by the map server to the client when another player invites the
+/// player to their guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016A)]
+pub struct GuildInvitePacket {
+    pub guild_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+/// Sent by the map server to the client when the player enters a map while
+/// being in a guild. Contains the notice set by the guild master.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016F)]
+pub struct GuildNoticePacket {
+    #[length(60)]
+    pub subject: String,
+    #[length(120)]
+    pub notice: String,
+}
+
+/// Sent by the map server when the party requested the creation of an
+/// instance (memorial dungeon). Contains the position in the creation queue.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02CB)]
+pub struct InstanceCreationQueuedPacket {
+    #[length(61)]
+    pub name: String,
+    pub queue_position: u16,
+}
+
+/// Sent by the map server when the position in the instance creation queue
+/// changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02CC)]
+pub struct InstanceQueuePositionPacket {
+    pub queue_position: u16,
+}
+
+/// Sent by the map server once the instance was created or when entering it.
+/// Both dates are unix timestamps.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02CD)]
+pub struct InstanceInformationPacket {
+    #[length(61)]
+    pub name: String,
+    pub destruction_date: u32,
+    pub entry_deadline: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum InstanceStatus {
+    #[numeric_value(0)]
+    TimeLimitChanged,
+    #[numeric_value(1)]
+    TimedOut,
+    #[numeric_value(2)]
+    EntryTimedOut,
+    #[numeric_value(3)]
+    Destroyed,
+    #[numeric_value(4)]
+    CreationFailed,
+}
+
+/// Sent by the map server when the status of the instance changed. For
+/// [`InstanceStatus::TimeLimitChanged`] `date` is the new destruction date as
+/// a unix timestamp.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02CE)]
+pub struct InstanceStatusPacket {
+    pub status: InstanceStatus,
+    pub date: u32,
+}
+
+/// Sent by the map server to tell the client which battlefield team a player
+/// belongs to.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02DD)]
+pub struct BattlefieldTeamPacket {
+    pub entity_id: EntityId,
+    #[length(24)]
+    pub name: String,
+    pub team_id: u16,
+}
+
+/// Sent by the map server when the score of a battlefield changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02DE)]
+pub struct BattlefieldScorePacket {
+    pub lion_score: u16,
+    pub eagle_score: u16,
+}
+
+/// Sent by the client to the map server as a response to a
+/// [`GuildInvitePacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016B)]
+pub struct GuildInviteResponsePacket {
+    pub guild_id: u32,
+    pub accept: u32,
+}
+
+/// Sent by the map server to the client when another player wants to trade
+/// with the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01F4)]
+pub struct TradeRequestPacket {
+    #[length(24)]
+    pub name: String,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum TradeRequestResponse {
+    #[numeric_value(3)]
+    Accept,
+    #[numeric_value(4)]
+    Reject,
+}
+
+/// Sent by the client to the map server as a response to a
+/// [`TradeRequestPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E6)]
+pub struct TradeRequestResponsePacket {
+    pub response: TradeRequestResponse,
+}
+
+/// Sent by the client to the map server when the player wants to trade with
+/// another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E4)]
+pub struct RequestTradePacket {
+    pub account_id: AccountId,
+}
+
+/// Sent by the client to the map server when the player wants to invite
+/// another player to their party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02C4)]
+pub struct RequestPartyInvitePacket {
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the client to the map server when the player wants to invite
+/// another player to their guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0168)]
+pub struct RequestGuildInvitePacket {
+    pub account_id: AccountId,
+    pub inviter_account_id: AccountId,
+    pub inviter_character_id: CharacterId,
+}
+
+/// Sent by the client to the map server to get the emblem of a guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0151)]
+pub struct RequestGuildEmblemPacket {
+    pub guild_id: u32,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildEmblemPacket`]. The emblem is a zlib compressed bitmap.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0152)]
+#[variable_length]
+pub struct GuildEmblemPacket {
+    pub guild_id: u32,
+    pub emblem_version: u32,
+    #[repeating_remaining]
+    pub emblem_data: Vec<u8>,
+}
+
+/// Sent by the client to the map server when the player wants to see the
+/// equipment of another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02D6)]
+pub struct RequestEquipmentWindowPacket {
+    pub account_id: AccountId,
+}
+
+/// Sent by the client to the map server when the player uses an item from
+/// their inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0439)]
+pub struct RequestUseItemPacket {
+    pub inventory_index: InventoryIndex,
+    pub account_id: AccountId,
+}
+
+/// Sent by the client to the map server when the player drops an item from
+/// their inventory on the ground.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0363)]
+pub struct RequestDropItemPacket {
+    pub inventory_index: InventoryIndex,
+    pub amount: u16,
+}
+
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct ReputationEntry {